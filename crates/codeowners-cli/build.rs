@@ -0,0 +1,87 @@
+//! Records build metadata reported by `codeowners-validator --version --json`.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=CODEOWNERS_GIT_COMMIT");
+    println!("cargo:rerun-if-env-changed=CODEOWNERS_BUILD_PROFILE");
+
+    println!(
+        "cargo:rustc-env=CODEOWNERS_BUILD_TARGET={}",
+        env::var("TARGET").unwrap_or_default()
+    );
+    println!(
+        "cargo:rustc-env=CODEOWNERS_BUILD_PROFILE={}",
+        build_profile()
+    );
+
+    if let Some(commit) = git_commit() {
+        println!("cargo:rustc-env=CODEOWNERS_GIT_COMMIT={commit}");
+    }
+
+    rerun_if_head_moves(Path::new("../../.git"));
+}
+
+/// Rebuilds when HEAD moves, if building from a git checkout.
+///
+/// HEAD usually names a branch, whose commit is stored in its loose ref file
+/// or, once git packs refs, in `packed-refs`, so new commits on the same
+/// branch leave HEAD itself unchanged.
+fn rerun_if_head_moves(git_dir: &Path) {
+    let head = git_dir.join("HEAD");
+    let Ok(content) = fs::read_to_string(&head) else {
+        return;
+    };
+    println!("cargo:rerun-if-changed={}", head.display());
+
+    if let Some(reference) = content.trim().strip_prefix("ref: ") {
+        let reference = git_dir.join(reference);
+        if reference.exists() {
+            println!("cargo:rerun-if-changed={}", reference.display());
+        }
+    }
+    let packed_refs = git_dir.join("packed-refs");
+    if packed_refs.exists() {
+        println!("cargo:rerun-if-changed={}", packed_refs.display());
+    }
+}
+
+/// Returns the name of the cargo profile being built, e.g. `dist`.
+///
+/// Cargo's `PROFILE` only distinguishes `debug` from `release`, so custom
+/// profiles such as `dist` are read from the output directory instead,
+/// which is `<target-dir>/[<triple>/]<profile>/build/<package>/out`.
+fn build_profile() -> String {
+    if let Ok(profile) = env::var("CODEOWNERS_BUILD_PROFILE") {
+        return profile;
+    }
+
+    env::var("OUT_DIR")
+        .ok()
+        .and_then(|out_dir| {
+            let profile_dir = Path::new(&out_dir).ancestors().nth(3)?;
+            Some(profile_dir.file_name()?.to_str()?.to_string())
+        })
+        .or_else(|| env::var("PROFILE").ok())
+        .unwrap_or_default()
+}
+
+/// Returns the commit being built, preferring an explicit override so
+/// builds from source archives can still record their provenance.
+fn git_commit() -> Option<String> {
+    if let Ok(commit) = env::var("CODEOWNERS_GIT_COMMIT") {
+        return Some(commit).filter(|c| !c.is_empty());
+    }
+
+    let output = Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    let commit = String::from_utf8(output.stdout).ok()?;
+    Some(commit.trim().to_string()).filter(|c| !c.is_empty())
+}
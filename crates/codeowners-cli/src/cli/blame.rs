@@ -0,0 +1,228 @@
+//! Ownership history for `codeowners-validator blame`.
+//!
+//! Walks the history of the CODEOWNERS file to find the commit that gave a
+//! path its current owners, who made it, and who owned the path before,
+//! answering audit questions like "since when does @org/payments own this?".
+
+use codeowners_validator_core::matching::owning_rule;
+use codeowners_validator_core::parse::{ParserConfig, parse_codeowners_with_config};
+use codeowners_validator_core::vcs::{CommitInfo, Vcs, VcsError};
+use colored::Colorize;
+use serde::Serialize;
+use std::fmt::Write;
+
+/// The rule owning a path at one revision.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RuleOwnership {
+    /// The rule's line number.
+    pub line: usize,
+    /// The rule's pattern.
+    pub pattern: String,
+    /// The rule's owners, as written.
+    pub owners: Vec<String>,
+}
+
+/// Returns true if both revisions give the path the same rule and owners.
+///
+/// Rules moving to another line, or owners changing case, do not count as
+/// a change.
+fn same_ownership(a: Option<&RuleOwnership>, b: Option<&RuleOwnership>) -> bool {
+    match (a, b) {
+        (None, None) => true,
+        (Some(a), Some(b)) => {
+            a.pattern == b.pattern
+                && a.owners.len() == b.owners.len()
+                && a.owners
+                    .iter()
+                    .zip(&b.owners)
+                    .all(|(a, b)| a.eq_ignore_ascii_case(b))
+        }
+        _ => false,
+    }
+}
+
+/// When a path got its current owners.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OwnershipBlame {
+    /// The path, relative to the repository root.
+    pub path: String,
+    /// The CODEOWNERS file, relative to the repository root.
+    pub codeowners_path: String,
+    /// The rule owning the path at the newest commit, or `None` if no rule
+    /// does.
+    pub current: Option<RuleOwnership>,
+    /// The commit that gave the path its current ownership, or `None` if
+    /// the CODEOWNERS file was never committed.
+    pub changed_in: Option<CommitInfo>,
+    /// The rule owning the path before that commit, or `None` if no rule
+    /// did or the commit created the CODEOWNERS file.
+    pub previous: Option<RuleOwnership>,
+}
+
+/// Finds the commit that gave `path` its current ownership.
+///
+/// Each commit changing the CODEOWNERS file is parsed, newest first, until
+/// one gives the path a different rule or owners.
+pub fn blame(
+    vcs: &dyn Vcs,
+    codeowners_path: &str,
+    path: &str,
+    parser_config: &ParserConfig,
+) -> Result<OwnershipBlame, VcsError> {
+    let ownership_at = |commit: &CommitInfo| -> Result<Option<RuleOwnership>, VcsError> {
+        let Some(content) = vcs.read_file(&commit.id, codeowners_path)? else {
+            return Ok(None);
+        };
+        let file = parse_codeowners_with_config(&content, parser_config).ast;
+        Ok(owning_rule(&file, path).map(|rule| RuleOwnership {
+            line: rule.line.span.line,
+            pattern: rule.pattern.text.clone(),
+            owners: rule
+                .owners
+                .iter()
+                .map(|owner| owner.as_str().into_owned())
+                .collect(),
+        }))
+    };
+
+    let mut blame = OwnershipBlame {
+        path: path.to_string(),
+        codeowners_path: codeowners_path.to_string(),
+        current: None,
+        changed_in: None,
+        previous: None,
+    };
+    let mut history = vcs.file_history(codeowners_path)?.into_iter();
+    let Some(mut changed_in) = history.next() else {
+        return Ok(blame);
+    };
+
+    blame.current = ownership_at(&changed_in)?;
+    for commit in history {
+        let ownership = ownership_at(&commit)?;
+        if !same_ownership(ownership.as_ref(), blame.current.as_ref()) {
+            blame.previous = ownership;
+            break;
+        }
+        changed_in = commit;
+    }
+    blame.changed_in = Some(changed_in);
+    Ok(blame)
+}
+
+/// Describes the owners of a rule, e.g. `@org/core (line 3: /src/)`.
+fn describe(ownership: Option<&RuleOwnership>) -> String {
+    match ownership {
+        Some(rule) if rule.owners.is_empty() => {
+            format!("no owners (line {}: {})", rule.line, rule.pattern)
+        }
+        Some(rule) => format!(
+            "{} (line {}: {})",
+            rule.owners.join(" "),
+            rule.line,
+            rule.pattern
+        ),
+        None => "no rule".to_string(),
+    }
+}
+
+/// Renders the blame as a few lines of text.
+pub fn render_human(blame: &OwnershipBlame, use_colors: bool) -> String {
+    let mut output = format!("{}\n", blame.path);
+    let Some(commit) = &blame.changed_in else {
+        let _ = writeln!(
+            output,
+            "  {} has no committed history",
+            blame.codeowners_path
+        );
+        return output;
+    };
+
+    let _ = writeln!(output, "  Owned by  {}", describe(blame.current.as_ref()));
+    let id = commit.id.get(..12).unwrap_or(&commit.id);
+    let id = if use_colors {
+        id.yellow().to_string()
+    } else {
+        id.to_string()
+    };
+    let date = commit.date.split('T').next().unwrap_or(&commit.date);
+    let _ = writeln!(
+        output,
+        "  Since     {} {} by {} <{}>: {}",
+        id, date, commit.author, commit.email, commit.summary
+    );
+    let _ = writeln!(output, "  Before    {}", describe(blame.previous.as_ref()));
+    output
+}
+
+/// Renders the blame as pretty-printed JSON.
+pub fn render_json(blame: &OwnershipBlame) -> String {
+    serde_json::to_string_pretty(blame).expect("ownership blame is always serializable")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codeowners_validator_core::vcs::GitVcs;
+    use std::fs;
+    use std::path::Path;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn commit(dir: &Path, codeowners: &str, message: &str) {
+        fs::write(dir.join("CODEOWNERS"), codeowners).unwrap();
+        for args in [&["add", "."][..], &["commit", "-qm", message]] {
+            let status = Command::new("git")
+                .arg("-C")
+                .arg(dir)
+                .args(["-c", "user.name=Alice", "-c", "user.email=a@example.com"])
+                .args(args)
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {:?} failed", args);
+        }
+    }
+
+    #[test]
+    fn finds_commit_that_changed_the_owners() {
+        let dir = TempDir::new().unwrap();
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(dir.path())
+            .args(["init", "-q"])
+            .status()
+            .unwrap();
+        assert!(status.success());
+        commit(dir.path(), "* @org/core\n", "Add CODEOWNERS");
+        commit(
+            dir.path(),
+            "* @org/core\n/src/ @org/platform\n",
+            "Hand src to platform",
+        );
+        commit(
+            dir.path(),
+            "# Owners\n* @org/core\n/src/ @org/Platform\n/docs/ @docs\n",
+            "Add docs owners",
+        );
+
+        let vcs = GitVcs::new(dir.path());
+        let config = ParserConfig::new();
+        let src = blame(&vcs, "CODEOWNERS", "src/lib.rs", &config).unwrap();
+        assert_eq!(src.current.as_ref().unwrap().line, 3);
+        assert_eq!(
+            src.changed_in.as_ref().unwrap().summary,
+            "Hand src to platform"
+        );
+        assert_eq!(src.previous.as_ref().unwrap().owners, ["@org/core"]);
+
+        let rendered = render_human(&src, false);
+        assert!(rendered.contains("  Owned by  @org/Platform (line 3: /src/)\n"));
+        assert!(rendered.contains("by Alice <a@example.com>: Hand src to platform\n"));
+        assert!(rendered.ends_with("  Before    @org/core (line 1: *)\n"));
+
+        let first = blame(&vcs, "CODEOWNERS", "README.md", &config).unwrap();
+        assert_eq!(first.changed_in.unwrap().summary, "Add CODEOWNERS");
+        assert_eq!(first.previous, None);
+    }
+}
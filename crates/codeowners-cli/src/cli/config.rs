@@ -3,14 +3,29 @@
 //! This module converts CLI arguments into the library's configuration types
 //! and handles GitHub authentication setup.
 
-use super::{Args, CheckKind, ExperimentalCheckKind, FailureLevel};
+use super::{Args, CheckKind, ExperimentalCheckKind, FailureLevel, OutputFormat};
+use clap::ValueEnum;
+use codeowners_validator_core::config::{CONFIG_FILE_NAME, Config as ProjectConfig};
+#[cfg(feature = "plugins")]
+use codeowners_validator_core::plugin::{PluginCheck, PluginManifest, WasmRuntime};
+use codeowners_validator_core::validate::ERROR_CODES;
 #[cfg(test)]
 use codeowners_validator_core::validate::Severity;
+use codeowners_validator_core::validate::baseline::Baseline;
 use codeowners_validator_core::validate::checks::CheckConfig;
+use codeowners_validator_core::validate::idp::IdpDirectory;
+use codeowners_validator_core::validate::lockfile::{LOCKFILE_NAME, OwnersLock};
+use codeowners_validator_core::validate::manifest::OwnershipManifest;
+use codeowners_validator_core::vcs::{GitTreeSource, detect_vcs};
 use jsonwebtoken::EncodingKey;
 use octocrab::Octocrab;
 use octocrab::models::{AppId, InstallationId};
+use secrecy::ExposeSecret;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
+#[cfg(feature = "plugins")]
+use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
 
 /// Errors that can occur during configuration.
@@ -63,6 +78,9 @@ pub struct ValidatedConfig {
     pub repo_path: std::path::PathBuf,
     /// Path to the CODEOWNERS file.
     pub codeowners_path: std::path::PathBuf,
+    /// Content of the CODEOWNERS file when it is read from the tree of
+    /// `--git-rev` rather than from `codeowners_path`.
+    pub codeowners_content: Option<String>,
     /// Configuration for the check runner.
     pub check_config: CheckConfig,
     /// Which standard checks to run.
@@ -71,12 +89,33 @@ pub struct ValidatedConfig {
     pub experimental_checks: Vec<ExperimentalCheckKind>,
     /// Failure level for determining exit code.
     pub failure_level: FailureLevel,
-    /// Whether to output JSON.
-    pub json_output: bool,
+    /// Format of the validation output.
+    pub output_format: OutputFormat,
+    /// Whether to apply available fixes to the CODEOWNERS file.
+    pub fix: bool,
+    /// Whether to show available fixes as a diff without applying them.
+    pub fix_dry_run: bool,
+    /// Whether to report the paths the not-owned check skips.
+    pub explain_skips: bool,
+    /// Time budget for the whole run.
+    pub time_budget: Option<Duration>,
+    /// Path of the baseline of known issues.
+    pub baseline_path: Option<std::path::PathBuf>,
+    /// The baseline read from `baseline_path`, or `None` if the file does
+    /// not exist yet and is created by this run.
+    pub baseline: Option<Baseline>,
+    /// Path of the owners lockfile, if one is used.
+    pub owners_lock_path: Option<std::path::PathBuf>,
+    /// Whether to pin the lockfile's paths to their current owners.
+    pub update_owners_lock: bool,
+    /// Plugin checks loaded from the `--plugins` manifest.
+    #[cfg(feature = "plugins")]
+    pub plugins: Vec<PluginCheck>,
 }
 
 impl ValidatedConfig {
-    /// Creates a validated configuration from CLI arguments.
+    /// Creates a validated configuration from CLI arguments, and the project
+    /// configuration file for options they leave unset.
     pub fn from_args(args: &Args) -> Result<Self, ConfigError> {
         let repo_path = args.repository_path.canonicalize().map_err(|e| {
             ConfigError::Invalid(format!(
@@ -86,19 +125,63 @@ impl ValidatedConfig {
             ))
         })?;
 
-        // Find the CODEOWNERS file
-        let codeowners_path = find_codeowners_file(&repo_path)?;
+        // Find the CODEOWNERS file, in the revision's tree with --git-rev
+        let (codeowners_path, codeowners_content) = match args.git_rev {
+            Some(ref rev) => {
+                if args.fix || args.fix_dry_run {
+                    return Err(ConfigError::Invalid(
+                        "--fix and --fix-dry-run need a checkout and cannot be used with --git-rev"
+                            .to_string(),
+                    ));
+                }
+                let tree = GitTreeSource::new(&repo_path, rev);
+                tree.verify()
+                    .map_err(|e| ConfigError::Invalid(format!("--git-rev: {}", e)))?;
+                let found = tree
+                    .find_codeowners()
+                    .map_err(|e| ConfigError::ReadCodeowners(e.to_string()))?;
+                let Some((path, content)) = found else {
+                    return Err(ConfigError::ReadCodeowners(format!(
+                        "CODEOWNERS file not found at '{}'. Searched in: .github/CODEOWNERS, CODEOWNERS, docs/CODEOWNERS",
+                        rev
+                    )));
+                };
+                (repo_path.join(path), Some(content))
+            }
+            None => (find_codeowners_file(&repo_path)?, None),
+        };
+
+        let mut project = load_project_config(args, &repo_path)?.unwrap_or_default();
+        if !project.branches.is_empty()
+            && let Some(branch) = args
+                .branch
+                .clone()
+                .or_else(|| detect_vcs(&repo_path)?.current_branch())
+        {
+            project = project.for_branch(&branch);
+        }
+        let checks = match (&args.checks, &project.checks) {
+            (None, Some(names)) => parse_check_names(names, "checks")?,
+            _ => args.effective_checks(),
+        };
+        let experimental_checks = match (&args.experimental_checks, &project.experimental_checks) {
+            (None, Some(names)) => parse_check_names(names, "experimental_checks")?,
+            _ => args.effective_experimental_checks(),
+        };
+        let failure_level = args
+            .check_failure_level
+            .or(project.failure_level.map(FailureLevel::from))
+            .unwrap_or_default();
 
         // Validate that owners check has required config
-        let checks = args.effective_checks();
         if checks.contains(&CheckKind::Owners) && args.owner_checker_repository.is_none() {
             return Err(ConfigError::MissingRequired(
                 "OWNER_CHECKER_REPOSITORY is required when 'owners' check is enabled".to_string(),
             ));
         }
 
-        // Build check config
-        let mut check_config = CheckConfig::new();
+        // Build check config, starting from the project configuration file
+        let mut check_config = project.apply(CheckConfig::new());
 
         if let Some(ref ignored) = args.owner_checker_ignored_owners {
             check_config = check_config.with_ignored_owners(ignored.iter().cloned().collect());
@@ -106,24 +189,161 @@ impl ValidatedConfig {
 
         check_config = check_config
             .with_owners_must_be_teams(args.owner_checker_owners_must_be_teams)
-            .with_allow_unowned_patterns(args.owner_checker_allow_unowned_patterns);
+            .with_allow_unowned_patterns(args.owner_checker_allow_unowned_patterns)
+            .with_reject_email_owners(args.owner_checker_reject_email_owners)
+            .with_derive_team_mapping(args.owner_checker_derive_team_mapping)
+            .with_check_empty_teams(args.owner_checker_check_empty_teams)
+            .with_check_team_hierarchy(args.owner_checker_check_team_hierarchy)
+            .with_force_respect_gitignore(args.force_respect_gitignore)
+            .with_tracked_files_only(args.tracked_files_only)
+            .with_non_utf8_policy(args.non_utf8_paths.into())
+            .with_symlink_policy(args.symlinks.into())
+            .with_question_mark(args.question_mark.into())
+            .with_pattern_syntax(args.pattern_syntax.into())
+            .with_review_warning_days(args.review_warning_days)
+            .with_repeated_owners_threshold(args.repeated_owners_threshold)
+            .with_max_rules(args.max_rules)
+            .with_max_rules_per_section(args.max_rules_per_section)
+            .with_max_file_lines(args.max_file_lines);
+
+        if let Some(ref mappings) = args.owner_checker_team_mapping {
+            check_config = check_config.with_team_mapping(parse_team_mapping(mappings)?);
+        }
+
+        if let Some(ref domains) = args.owner_checker_trusted_email_domains {
+            check_config =
+                check_config.with_trusted_email_domains(domains.iter().cloned().collect());
+        }
+
+        if let Some(min) = args.owner_checker_min_team_size {
+            check_config = check_config.with_min_team_size(min);
+        }
+
+        if let Some(max) = args.max_api_requests {
+            check_config = check_config.with_max_api_requests(max);
+        }
+
+        if let Some(depth) = args.max_walk_depth {
+            check_config = check_config.with_max_walk_depth(depth);
+        }
+
+        if let Some(max) = args.max_walk_files {
+            check_config = check_config.with_max_walk_files(max);
+        }
+
+        if let Some(secs) = args.walk_timeout {
+            check_config = check_config.with_walk_timeout(Duration::from_secs(secs));
+        }
 
         if let Some(ref patterns) = args.not_owned_checker_skip_patterns {
             check_config = check_config.with_skip_patterns(patterns.clone());
         }
 
+        if let Some(ref codes) = args.suppress_codes {
+            let codes: HashSet<String> = codes.iter().map(|code| code.to_uppercase()).collect();
+            if let Some(unknown) = codes
+                .iter()
+                .find(|code| !ERROR_CODES.contains(&code.as_str()))
+            {
+                return Err(ConfigError::Invalid(format!(
+                    "unknown error code '{}' in SUPPRESS_CODES, expected e.g. 'CO004'",
+                    unknown
+                )));
+            }
+            check_config = check_config.with_suppressed_codes(codes);
+        }
+
+        if let Some(ref teams) = args.admin_teams {
+            check_config = check_config.with_admin_teams(teams.iter().cloned().collect());
+        }
+
         if let Some(ref repo) = args.owner_checker_repository {
             check_config = check_config.with_repository(repo.clone());
         }
 
+        if let Some(ref rev) = args.git_rev {
+            check_config = check_config.with_git_rev(rev.clone());
+        }
+
+        if let Some(ref path) = args.changed_files {
+            check_config = check_config.with_changed_files(load_changed_files(path)?);
+        }
+
+        match args.idp_export {
+            Some(ref path) => {
+                let directory = load_idp_export(path)?.with_min_members(args.idp_min_members);
+                check_config = check_config.with_idp_directory(directory);
+            }
+            None if experimental_checks.contains(&ExperimentalCheckKind::IdpGroups) => {
+                return Err(ConfigError::MissingRequired(
+                    "IDP_EXPORT is required when 'idp-groups' check is enabled".to_string(),
+                ));
+            }
+            None => {}
+        }
+
+        match args.ownership_manifest {
+            Some(ref path) => {
+                check_config = check_config.with_ownership_manifest(load_manifest(path)?);
+            }
+            None if experimental_checks.contains(&ExperimentalCheckKind::OwnershipManifest) => {
+                return Err(ConfigError::MissingRequired(
+                    "OWNERSHIP_MANIFEST is required when 'ownership-manifest' check is enabled"
+                        .to_string(),
+                ));
+            }
+            None => {}
+        }
+
+        let owners_lock_path = args.owners_lock.clone().or_else(|| {
+            let path = repo_path.join(LOCKFILE_NAME);
+            path.is_file().then_some(path)
+        });
+        match owners_lock_path {
+            Some(ref path) => {
+                check_config = check_config.with_owners_lock(load_owners_lock(path)?);
+            }
+            None if args.update_owners_lock
+                || experimental_checks.contains(&ExperimentalCheckKind::OwnersLock) =>
+            {
+                return Err(ConfigError::MissingRequired(format!(
+                    "OWNERS_LOCK (or a {} file in the repository) is required when 'owners-lock' \
+                     check is enabled or --update-owners-lock is set",
+                    LOCKFILE_NAME
+                )));
+            }
+            None => {}
+        }
+
         Ok(Self {
             repo_path,
             codeowners_path,
+            codeowners_content,
             check_config,
             checks,
-            experimental_checks: args.effective_experimental_checks(),
-            failure_level: args.check_failure_level,
-            json_output: args.json,
+            experimental_checks,
+            failure_level,
+            output_format: args.effective_format(),
+            fix: args.fix,
+            fix_dry_run: args.fix_dry_run,
+            explain_skips: args.explain_skips,
+            time_budget: args.time_budget.map(Duration::from_secs),
+            baseline_path: args.baseline.clone(),
+            baseline: args
+                .baseline
+                .as_deref()
+                .map(load_baseline)
+                .transpose()?
+                .flatten(),
+            owners_lock_path,
+            update_owners_lock: args.update_owners_lock,
+            #[cfg(feature = "plugins")]
+            plugins: args
+                .plugins
+                .as_deref()
+                .map(load_plugins)
+                .transpose()?
+                .unwrap_or_default(),
         })
     }
 
@@ -140,6 +360,149 @@ impl ValidatedConfig {
     }
 }
 
+/// Loads the project configuration file given with `--config`, or the
+/// repository's own, if it has one.
+fn load_project_config(
+    args: &Args,
+    repo_path: &Path,
+) -> Result<Option<ProjectConfig>, ConfigError> {
+    let (path, config) = match args.config {
+        Some(ref path) => (path.clone(), ProjectConfig::from_path(path).map(Some)),
+        None => (
+            repo_path.join(CONFIG_FILE_NAME),
+            ProjectConfig::discover(repo_path),
+        ),
+    };
+    config.map_err(|e| ConfigError::Invalid(format!("{} ('{}')", e, path.display())))
+}
+
+/// Parses the check names listed under `key` in the project configuration
+/// file.
+fn parse_check_names<T: ValueEnum>(names: &[String], key: &str) -> Result<Vec<T>, ConfigError> {
+    names
+        .iter()
+        .map(|name| {
+            T::from_str(name, true).map_err(|_| {
+                ConfigError::Invalid(format!(
+                    "unknown check '{}' in '{}' of the configuration file",
+                    name, key
+                ))
+            })
+        })
+        .collect()
+}
+
+/// Loads an identity provider group export, choosing the format by extension.
+fn load_idp_export(path: &Path) -> Result<IdpDirectory, ConfigError> {
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        ConfigError::Invalid(format!(
+            "failed to read IdP export '{}': {}",
+            path.display(),
+            e
+        ))
+    })?;
+    let is_csv = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+    let directory = if is_csv {
+        IdpDirectory::from_csv(&content)
+    } else {
+        IdpDirectory::from_json(&content)
+    };
+    directory.map_err(|e| ConfigError::Invalid(format!("{} ('{}')", e, path.display())))
+}
+
+/// Loads a JSON ownership manifest.
+fn load_manifest(path: &Path) -> Result<OwnershipManifest, ConfigError> {
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        ConfigError::Invalid(format!(
+            "failed to read ownership manifest '{}': {}",
+            path.display(),
+            e
+        ))
+    })?;
+    OwnershipManifest::from_json(&content)
+        .map_err(|e| ConfigError::Invalid(format!("{} ('{}')", e, path.display())))
+}
+
+/// Loads a list of changed paths, one per line, from a file or from stdin
+/// if `path` is `-`.
+fn load_changed_files(path: &Path) -> Result<Vec<String>, ConfigError> {
+    let content = if path == Path::new("-") {
+        std::io::read_to_string(std::io::stdin())
+    } else {
+        std::fs::read_to_string(path)
+    }
+    .map_err(|e| {
+        ConfigError::Invalid(format!(
+            "failed to read changed files '{}': {}",
+            path.display(),
+            e
+        ))
+    })?;
+    Ok(content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Loads a JSON owners lockfile.
+fn load_owners_lock(path: &Path) -> Result<OwnersLock, ConfigError> {
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        ConfigError::Invalid(format!(
+            "failed to read owners lockfile '{}': {}",
+            path.display(),
+            e
+        ))
+    })?;
+    OwnersLock::from_json(&content)
+        .map_err(|e| ConfigError::Invalid(format!("{} ('{}')", e, path.display())))
+}
+
+/// Loads a JSON baseline, or returns `None` if the file does not exist.
+fn load_baseline(path: &Path) -> Result<Option<Baseline>, ConfigError> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => {
+            return Err(ConfigError::Invalid(format!(
+                "failed to read baseline '{}': {}",
+                path.display(),
+                e
+            )));
+        }
+    };
+    Baseline::from_json(&content)
+        .map(Some)
+        .map_err(|e| ConfigError::Invalid(format!("{} ('{}')", e, path.display())))
+}
+
+/// Loads the plugin checks a manifest lists, to run in the wasmi sandbox.
+#[cfg(feature = "plugins")]
+fn load_plugins(path: &Path) -> Result<Vec<PluginCheck>, ConfigError> {
+    PluginManifest::from_path(path)
+        .and_then(|manifest| manifest.load(Arc::new(WasmRuntime::new())))
+        .map_err(|e| ConfigError::Invalid(format!("--plugins: {}", e)))
+}
+
+/// Parses `user=team` mapping entries into a user-to-team map.
+fn parse_team_mapping(entries: &[String]) -> Result<HashMap<String, String>, ConfigError> {
+    entries
+        .iter()
+        .map(|entry| match entry.split_once('=') {
+            Some((user, team)) if !user.trim().is_empty() && !team.trim().is_empty() => {
+                Ok((user.trim().to_string(), team.trim().to_string()))
+            }
+            _ => Err(ConfigError::Invalid(format!(
+                "team mapping '{}' must be in 'user=team' format",
+                entry
+            ))),
+        })
+        .collect()
+}
+
 /// Finds the CODEOWNERS file in the repository.
 ///
 /// Searches in the following locations (in order):
@@ -176,7 +539,7 @@ pub async fn create_octocrab(args: &Args) -> Result<Option<Octocrab>, ConfigErro
         let private_key = args.github_app_private_key.as_ref().unwrap();
 
         // Create encoding key from PEM
-        let key = EncodingKey::from_rsa_pem(private_key.as_bytes())
+        let key = EncodingKey::from_rsa_pem(private_key.expose_secret().as_bytes())
             .map_err(|e| ConfigError::GitHubAuth(format!("invalid private key: {}", e)))?;
 
         // Build app-authenticated client
@@ -205,7 +568,7 @@ pub async fn create_octocrab(args: &Args) -> Result<Option<Octocrab>, ConfigErro
                 .map_err(|e| ConfigError::GitHubAuth(format!("invalid base URL: {}", e)))?;
         }
         let client = builder
-            .personal_token(token.clone())
+            .personal_token(token.expose_secret().to_string())
             .build()
             .map_err(|e| ConfigError::GitHubAuth(format!("failed to build client: {}", e)))?;
 
@@ -395,4 +758,407 @@ mod tests {
         let result = ValidatedConfig::from_args(&args);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_validated_config_max_api_requests() {
+        let dir = create_test_repo();
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "--repository-path",
+            dir.path().to_str().unwrap(),
+            "--checks",
+            "syntax",
+            "--max-api-requests",
+            "10",
+        ]);
+        let config = ValidatedConfig::from_args(&args).unwrap();
+        assert_eq!(config.check_config.max_api_requests, Some(10));
+    }
+
+    #[test]
+    fn test_validated_config_changed_files() {
+        let dir = create_test_repo();
+        let list = dir.path().join("changed.txt");
+        fs::write(&list, "src/lib.rs\n\n  docs/guide.md\n").unwrap();
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "--repository-path",
+            dir.path().to_str().unwrap(),
+            "--checks",
+            "syntax",
+            "--changed-files",
+            list.to_str().unwrap(),
+        ]);
+        let config = ValidatedConfig::from_args(&args).unwrap();
+        assert_eq!(
+            config.check_config.changed_files,
+            Some(vec!["src/lib.rs".to_string(), "docs/guide.md".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_validated_config_git_rev() {
+        let dir = create_test_repo();
+        let repo = dir.path().to_str().unwrap();
+        let git = |args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .args([
+                    "-C",
+                    repo,
+                    "-c",
+                    "user.name=Test",
+                    "-c",
+                    "user.email=t@example.com",
+                ])
+                .args(args)
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {:?} failed", args);
+        };
+        git(&["init", "-q"]);
+        git(&["add", "."]);
+        git(&["commit", "-qm", "one"]);
+        fs::write(dir.path().join(".github/CODEOWNERS"), "* @changed\n").unwrap();
+
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "--repository-path",
+            repo,
+            "--checks",
+            "syntax",
+            "--git-rev",
+            "HEAD",
+        ]);
+        let config = ValidatedConfig::from_args(&args).unwrap();
+        assert_eq!(config.codeowners_content.as_deref(), Some("* @owner\n"));
+        assert!(config.codeowners_path.ends_with(".github/CODEOWNERS"));
+        assert_eq!(config.check_config.git_rev.as_deref(), Some("HEAD"));
+
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "--repository-path",
+            repo,
+            "--git-rev",
+            "HEAD",
+            "--fix",
+        ]);
+        assert!(ValidatedConfig::from_args(&args).is_err());
+
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "--repository-path",
+            repo,
+            "--git-rev",
+            "no-such-rev",
+        ]);
+        assert_eq!(
+            ValidatedConfig::from_args(&args).unwrap_err().to_string(),
+            "invalid configuration: --git-rev: unknown revision 'no-such-rev'"
+        );
+    }
+
+    #[test]
+    fn test_validated_config_suppress_codes() {
+        let dir = create_test_repo();
+        let repo = dir.path().to_str().unwrap();
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "--repository-path",
+            repo,
+            "--checks",
+            "syntax",
+            "--suppress-codes",
+            "co004,CO013",
+        ]);
+        let config = ValidatedConfig::from_args(&args).unwrap();
+        assert!(config.check_config.suppressed_codes.contains("CO004"));
+        assert!(config.check_config.suppressed_codes.contains("CO013"));
+
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "--repository-path",
+            repo,
+            "--checks",
+            "syntax",
+            "--suppress-codes",
+            "CO999",
+        ]);
+        let error = ValidatedConfig::from_args(&args).unwrap_err();
+        assert!(error.to_string().contains("unknown error code 'CO999'"));
+    }
+
+    #[test]
+    fn test_validated_config_idp_export() {
+        let dir = create_test_repo();
+        let repo = dir.path().to_str().unwrap();
+        let export = dir.path().join("groups.csv");
+        fs::write(&export, "group,member\nplatform,alice\nplatform,bob\n").unwrap();
+
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "--repository-path",
+            repo,
+            "--checks",
+            "syntax",
+            "--experimental-checks",
+            "idp-groups",
+            "--idp-export",
+            export.to_str().unwrap(),
+            "--idp-min-members",
+            "2",
+        ]);
+        let config = ValidatedConfig::from_args(&args).unwrap();
+        let directory = config.check_config.idp_directory.unwrap();
+        assert_eq!(directory.min_members(), 2);
+        assert_eq!(
+            directory.group_for_team("org", "platform").unwrap().members,
+            2
+        );
+
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "--repository-path",
+            repo,
+            "--checks",
+            "syntax",
+            "--experimental-checks",
+            "idp-groups",
+        ]);
+        let error = ValidatedConfig::from_args(&args).unwrap_err();
+        assert!(error.to_string().contains("IDP_EXPORT"));
+    }
+
+    #[test]
+    fn test_validated_config_ownership_manifest() {
+        let dir = create_test_repo();
+        let repo = dir.path().to_str().unwrap();
+        let manifest = dir.path().join("ownership.json");
+        fs::write(
+            &manifest,
+            r#"[{"path": "/src/", "teams": ["@org/core"], "sla": "1d"}]"#,
+        )
+        .unwrap();
+
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "--repository-path",
+            repo,
+            "--checks",
+            "syntax",
+            "--experimental-checks",
+            "ownership-manifest",
+            "--ownership-manifest",
+            manifest.to_str().unwrap(),
+        ]);
+        let config = ValidatedConfig::from_args(&args).unwrap();
+        assert_eq!(config.check_config.ownership_manifest.unwrap().len(), 1);
+
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "--repository-path",
+            repo,
+            "--checks",
+            "syntax",
+            "--experimental-checks",
+            "ownership-manifest",
+        ]);
+        let error = ValidatedConfig::from_args(&args).unwrap_err();
+        assert!(error.to_string().contains("OWNERSHIP_MANIFEST"));
+    }
+
+    #[test]
+    fn test_validated_config_owners_lock() {
+        let dir = create_test_repo();
+        let repo = dir.path().to_str().unwrap();
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "--repository-path",
+            repo,
+            "--checks",
+            "syntax",
+            "--experimental-checks",
+            "owners-lock",
+        ]);
+        let error = ValidatedConfig::from_args(&args).unwrap_err();
+        assert!(error.to_string().contains("OWNERS_LOCK"));
+
+        // The lockfile at the repository root is used by default
+        fs::write(
+            dir.path().join(LOCKFILE_NAME),
+            r#"{"version": 1, "paths": [{"path": "src/main.rs", "owners": ["@org/core"]}]}"#,
+        )
+        .unwrap();
+        let config = ValidatedConfig::from_args(&args).unwrap();
+        assert_eq!(config.check_config.owners_lock.unwrap().len(), 1);
+        assert_eq!(
+            config.owners_lock_path,
+            Some(dir.path().canonicalize().unwrap().join(LOCKFILE_NAME))
+        );
+    }
+
+    #[test]
+    fn test_validated_config_baseline() {
+        let dir = create_test_repo();
+        let repo = dir.path().to_str().unwrap();
+        let baseline = dir.path().join("baseline.json");
+        let args = |path: &Path| {
+            Args::parse_from([
+                "codeowners-validator",
+                "--repository-path",
+                repo,
+                "--checks",
+                "syntax",
+                "--baseline",
+                path.to_str().unwrap(),
+            ])
+        };
+
+        // A missing baseline is created by the run
+        let config = ValidatedConfig::from_args(&args(&baseline)).unwrap();
+        assert_eq!(config.baseline_path.as_deref(), Some(baseline.as_path()));
+        assert!(config.baseline.is_none());
+
+        fs::write(&baseline, Baseline::new().to_json()).unwrap();
+        let config = ValidatedConfig::from_args(&args(&baseline)).unwrap();
+        assert!(config.baseline.unwrap().is_empty());
+
+        fs::write(&baseline, "{}").unwrap();
+        let error = ValidatedConfig::from_args(&args(&baseline)).unwrap_err();
+        assert!(error.to_string().contains("invalid JSON baseline"));
+    }
+
+    #[cfg(feature = "plugins")]
+    #[test]
+    fn test_validated_config_plugins() {
+        use codeowners_validator_core::validate::checks::Check;
+
+        let dir = create_test_repo();
+        let repo = dir.path().to_str().unwrap();
+        let manifest = dir.path().join("plugins.json");
+        let args = |path: &Path| {
+            Args::parse_from([
+                "codeowners-validator",
+                "--repository-path",
+                repo,
+                "--checks",
+                "syntax",
+                "--plugins",
+                path.to_str().unwrap(),
+            ])
+        };
+
+        fs::write(dir.path().join("no_bots.wasm"), b"\0asm\x01\0\0\0").unwrap();
+        fs::write(
+            &manifest,
+            r#"{"plugins": [{"name": "no-bots", "path": "no_bots.wasm"}]}"#,
+        )
+        .unwrap();
+        let config = ValidatedConfig::from_args(&args(&manifest)).unwrap();
+        assert_eq!(config.plugins.len(), 1);
+        assert_eq!(config.plugins[0].name(), "no-bots");
+
+        let error = ValidatedConfig::from_args(&args(&dir.path().join("missing.json")))
+            .unwrap_err()
+            .to_string();
+        assert!(error.starts_with("invalid configuration: --plugins: failed to read"));
+    }
+
+    #[test]
+    fn test_validated_config_project_file() {
+        let dir = create_test_repo();
+        let repo = dir.path().to_str().unwrap();
+        fs::write(
+            dir.path().join(CONFIG_FILE_NAME),
+            "checks = [\"syntax\", \"duppatterns\"]\n\
+             experimental_checks = [\"notowned\"]\n\
+             ignored_owners = [\"@ghost\"]\n\
+             failure_level = \"error\"\n\
+             [severity]\nCO013 = \"error\"\n",
+        )
+        .unwrap();
+
+        let args = Args::parse_from(["codeowners-validator", "--repository-path", repo]);
+        let config = ValidatedConfig::from_args(&args).unwrap();
+        assert_eq!(
+            config.checks,
+            vec![CheckKind::Syntax, CheckKind::Duppatterns]
+        );
+        assert_eq!(
+            config.experimental_checks,
+            vec![ExperimentalCheckKind::Notowned]
+        );
+        assert_eq!(config.failure_level, FailureLevel::Error);
+        assert!(config.check_config.ignored_owners.contains("@ghost"));
+        assert_eq!(
+            config.check_config.severity_overrides.get("CO013"),
+            Some(&Severity::Error)
+        );
+
+        // Flags take precedence over the file
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "--repository-path",
+            repo,
+            "--checks",
+            "files",
+            "--check-failure-level",
+            "warning",
+            "--owner-checker-ignored-owners",
+            "@bot",
+        ]);
+        let config = ValidatedConfig::from_args(&args).unwrap();
+        assert_eq!(config.checks, vec![CheckKind::Files]);
+        assert_eq!(config.failure_level, FailureLevel::Warning);
+        assert!(!config.check_config.ignored_owners.contains("@ghost"));
+
+        fs::write(dir.path().join(CONFIG_FILE_NAME), "checks = [\"typo\"]\n").unwrap();
+        let args = Args::parse_from(["codeowners-validator", "--repository-path", repo]);
+        let error = ValidatedConfig::from_args(&args).unwrap_err();
+        assert!(error.to_string().contains("unknown check 'typo'"));
+    }
+
+    #[test]
+    fn test_validated_config_branch_sections() {
+        let dir = create_test_repo();
+        let repo = dir.path().to_str().unwrap();
+        fs::create_dir_all(dir.path().join(".git")).unwrap();
+        fs::write(
+            dir.path().join(".git/HEAD"),
+            "ref: refs/heads/release/1.0\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join(CONFIG_FILE_NAME),
+            "checks = [\"syntax\"]\n\n[[branches]]\npattern = \"release/*\"\nchecks = [\"files\"]\n",
+        )
+        .unwrap();
+
+        // The checked-out branch, unless another is given
+        let args = Args::parse_from(["codeowners-validator", "--repository-path", repo]);
+        let config = ValidatedConfig::from_args(&args).unwrap();
+        assert_eq!(config.checks, vec![CheckKind::Files]);
+
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "--repository-path",
+            repo,
+            "--branch",
+            "feature/x",
+        ]);
+        let config = ValidatedConfig::from_args(&args).unwrap();
+        assert_eq!(config.checks, vec![CheckKind::Syntax]);
+    }
+
+    #[test]
+    fn test_parse_team_mapping() {
+        let mapping =
+            parse_team_mapping(&["@alice=@org/team".to_string(), "bob = @org/b".to_string()])
+                .unwrap();
+        assert_eq!(mapping.get("@alice"), Some(&"@org/team".to_string()));
+        assert_eq!(mapping.get("bob"), Some(&"@org/b".to_string()));
+
+        assert!(parse_team_mapping(&["@alice".to_string()]).is_err());
+        assert!(parse_team_mapping(&["=@org/team".to_string()]).is_err());
+    }
 }
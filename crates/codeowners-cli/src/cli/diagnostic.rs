@@ -0,0 +1,178 @@
+//! Source snippets for findings in human output.
+//!
+//! Findings carry the span of the offending text, so human output can show
+//! it in context, the way compilers do. Snippets are rendered with
+//! codespan-reporting:
+//!
+//! ```text
+//!   [WARN] CO004 line 3: duplicate pattern '*.rs' (first defined on line 2)
+//!       --> .github/CODEOWNERS:3:1
+//!       |
+//!     3 | *.rs @other-team
+//!       | ^^^^
+//!       |
+//!       = help: Remove '*.rs @rust-team'
+//! ```
+//!
+//! The help note describes the fix `--fix` would apply, if there is one.
+
+use codeowners_validator_core::validate::{Severity, ValidationError};
+use codespan_reporting::diagnostic::{Diagnostic, Label, Severity as LabelSeverity};
+use codespan_reporting::files::SimpleFile;
+use codespan_reporting::term::termcolor::{Ansi, Color, ColorSpec};
+use codespan_reporting::term::{self, Chars, Config, Styles, StylesWriter};
+
+/// The CODEOWNERS file findings point into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Source {
+    /// Path of the file relative to the repository root.
+    pub path: String,
+    /// Content of the file.
+    pub text: String,
+}
+
+impl Source {
+    /// Creates a source from a path and the file content.
+    pub fn new(path: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            text: text.into(),
+        }
+    }
+
+    /// Returns the byte range of a span's text, widened to character
+    /// boundaries and clamped to the end of the file.
+    fn range(&self, offset: usize, end: usize) -> std::ops::Range<usize> {
+        let start = self.text.floor_char_boundary(offset);
+        let end = self.text.ceil_char_boundary(end.max(offset));
+        start..end
+    }
+}
+
+/// Colors matching the finding headers: blue gutters, and carets in the
+/// color of the finding's severity.
+fn styles() -> Styles {
+    let bold = |color| ColorSpec::new().set_fg(Some(color)).set_bold(true).clone();
+    Styles {
+        primary_label_error: bold(Color::Red),
+        primary_label_warning: bold(Color::Yellow),
+        line_number: bold(Color::Blue),
+        source_border: bold(Color::Blue),
+        note_bullet: bold(Color::Blue),
+        ..Styles::default()
+    }
+}
+
+/// Renders the snippet of source a finding points at, indented by `indent`,
+/// with carets colored by the finding's effective `severity`.
+///
+/// The header line of the rendered diagnostic is left out, since the finding
+/// itself is already printed above the snippet.
+pub fn render_snippet(
+    error: &ValidationError,
+    severity: Severity,
+    source: &Source,
+    indent: &str,
+    use_colors: bool,
+) -> String {
+    let span = error.span();
+    let file = SimpleFile::new(source.path.as_str(), source.text.as_str());
+    let severity = match severity {
+        Severity::Error => LabelSeverity::Error,
+        Severity::Warning => LabelSeverity::Warning,
+    };
+    let mut diagnostic = Diagnostic::new(severity)
+        .with_code(error.code())
+        .with_label(Label::primary(
+            (),
+            source.range(span.offset, span.end_offset()),
+        ));
+    if let Some(fix) = error.fix() {
+        diagnostic = diagnostic.with_note(format!("help: {}", fix.describe(&source.text)));
+    }
+
+    let config = Config {
+        chars: Chars::ascii(),
+        ..Config::default()
+    };
+    let mut buffer = Vec::new();
+    let rendered = if use_colors {
+        let styles = styles();
+        let mut writer = StylesWriter::new(Ansi::new(&mut buffer), &styles);
+        term::emit_to_write_style(&mut writer, &config, &file, &diagnostic)
+    } else {
+        term::emit_to_io_write(&mut buffer, &config, &file, &diagnostic)
+    };
+    if rendered.is_err() {
+        return String::new();
+    }
+
+    let rendered = String::from_utf8_lossy(&buffer);
+    let mut output = String::new();
+    for line in rendered.trim_end().lines().skip(1) {
+        output.push_str(indent);
+        output.push_str(line.trim_end());
+        output.push('\n');
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codeowners_validator_core::parse::Span;
+
+    const SOURCE: &str = "* @core\n*.rs @Alice\n*.md @alice\n";
+
+    fn source() -> Source {
+        Source::new(".github/CODEOWNERS", SOURCE)
+    }
+
+    #[test]
+    fn underlines_span() {
+        let error = ValidationError::file_not_owned("src/main.rs", Span::new(10, 2, 3, 1));
+        assert_eq!(
+            render_snippet(&error, error.severity(), &source(), "", false),
+            "  --> .github/CODEOWNERS:2:3\n  |\n2 | *.rs @Alice\n  |   ^\n"
+        );
+    }
+
+    #[test]
+    fn shows_fix_as_help() {
+        let error =
+            ValidationError::inconsistent_owner_case("@Alice", Span::new(13, 2, 6, 6), "@alice", 3);
+        let snippet = render_snippet(&error, error.severity(), &source(), "  ", false);
+        assert!(snippet.contains("  2 | *.rs @Alice\n    |      ^^^^^^\n"));
+        assert!(snippet.ends_with("    = help: Replace '@Alice' with '@alice'\n"));
+    }
+
+    #[test]
+    fn multiline_span() {
+        let source = Source::new("CODEOWNERS", "a @x\nb @y\nc @z\n");
+        let span = Span::new(2, 1, 3, 10).with_end(3, 3);
+        let error = ValidationError::generated_region_modified("gen", "h1", "h2", span);
+        assert_eq!(
+            render_snippet(&error, error.severity(), &source, "", false),
+            "  --> CODEOWNERS:1:3\n  |\n1 |   a @x\n  | /---^\n2 | | b @y\n3 | | c @z\n  | \\--^\n"
+        );
+    }
+
+    #[test]
+    fn non_ascii_line() {
+        let source = Source::new("CODEOWNERS", "* @core\ndocs/été/ @doc-team\n");
+        // Starts inside the two-byte 'é', which is widened to the whole character
+        let error = ValidationError::file_not_owned("docs/été/", Span::new(14, 2, 6, 5));
+        assert_eq!(
+            render_snippet(&error, error.severity(), &source, "", false),
+            "  --> CODEOWNERS:2:6\n  |\n2 | docs/été/ @doc-team\n  |      ^^^^\n"
+        );
+    }
+
+    #[test]
+    fn span_past_end_of_file() {
+        let error = ValidationError::file_not_owned("src/main.rs", Span::new(400, 9, 1, 4));
+        let snippet = render_snippet(&error, error.severity(), &source(), "", false);
+        // Clamped to the empty last line instead of panicking
+        assert!(snippet.ends_with("4 |\n  | ^\n"));
+    }
+}
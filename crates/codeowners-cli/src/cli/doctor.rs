@@ -0,0 +1,498 @@
+//! Environment diagnosis for `codeowners-validator doctor`.
+//!
+//! The doctor runs a series of independent checks against the local
+//! environment and the configured GitHub API, and prints a pass/fail line
+//! for each with a hint on how to fix failures. It never validates the
+//! CODEOWNERS rules themselves.
+
+use super::Args;
+use super::config::{ConfigError, ValidatedConfig, create_octocrab};
+use clap::ValueEnum;
+use colored::Colorize;
+use http::StatusCode;
+use octocrab::Octocrab;
+use std::fmt::Write;
+use std::path::Path;
+use std::process::Command;
+
+/// Token scopes that grant read access to organization teams.
+const TEAM_SCOPES: &[&str] = &["read:org", "write:org", "admin:org"];
+
+/// Hint shown when the GitHub API cannot be reached.
+const REACHABILITY_HINT: &str =
+    "Check network access and GITHUB_BASE_URL (GitHub Enterprise uses https://HOST/api/v3)";
+
+/// Outcome of a single diagnostic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The check passed.
+    Pass,
+    /// The check found something that may cause problems.
+    Warn,
+    /// The check found a problem that prevents validation.
+    Fail,
+    /// The check does not apply to this configuration.
+    Skip,
+}
+
+impl Status {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Pass => "PASS",
+            Self::Warn => "WARN",
+            Self::Fail => "FAIL",
+            Self::Skip => "SKIP",
+        }
+    }
+}
+
+/// The result of a single diagnostic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Short name of what was checked.
+    pub name: &'static str,
+    /// Outcome of the check.
+    pub status: Status,
+    /// What was found.
+    pub message: String,
+    /// How to fix a warning or failure.
+    pub hint: Option<String>,
+}
+
+impl Diagnostic {
+    fn new(name: &'static str, status: Status, message: impl Into<String>) -> Self {
+        Self {
+            name,
+            status,
+            message: message.into(),
+            hint: None,
+        }
+    }
+
+    fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+}
+
+/// The results of all diagnostics.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DoctorReport {
+    /// Diagnostics in the order they ran.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl DoctorReport {
+    /// Returns true if any diagnostic failed.
+    pub fn has_failures(&self) -> bool {
+        self.diagnostics.iter().any(|d| d.status == Status::Fail)
+    }
+
+    /// Renders the report for the terminal.
+    pub fn render(&self, use_colors: bool) -> String {
+        let mut output = String::new();
+
+        for diagnostic in &self.diagnostics {
+            let label = format!("[{}]", diagnostic.status.label());
+            let label = if use_colors {
+                match diagnostic.status {
+                    Status::Pass => label.green().bold().to_string(),
+                    Status::Warn => label.yellow().bold().to_string(),
+                    Status::Fail => label.red().bold().to_string(),
+                    Status::Skip => label.dimmed().to_string(),
+                }
+            } else {
+                label
+            };
+            let _ = writeln!(
+                output,
+                "{} {}: {}",
+                label, diagnostic.name, diagnostic.message
+            );
+            if let Some(hint) = &diagnostic.hint {
+                let _ = writeln!(output, "       hint: {}", hint);
+            }
+        }
+
+        let failures = self
+            .diagnostics
+            .iter()
+            .filter(|d| d.status == Status::Fail)
+            .count();
+        let _ = writeln!(output);
+        let _ = if failures == 0 {
+            writeln!(output, "✓ No problems found")
+        } else {
+            writeln!(output, "✗ {} check(s) failed", failures)
+        };
+
+        output
+    }
+}
+
+/// Runs all diagnostics for the given arguments.
+pub async fn run_doctor(args: &Args) -> DoctorReport {
+    let mut report = DoctorReport::default();
+    report
+        .diagnostics
+        .push(check_codeowners(&args.repository_path));
+    report.diagnostics.push(check_git(&args.repository_path));
+    report.diagnostics.push(check_config(args));
+    report.diagnostics.extend(check_github(args).await);
+    report
+}
+
+/// Checks that a CODEOWNERS file can be found and read.
+fn check_codeowners(repo_path: &Path) -> Diagnostic {
+    const NAME: &str = "CODEOWNERS file";
+
+    if !repo_path.is_dir() {
+        return Diagnostic::new(
+            NAME,
+            Status::Fail,
+            format!(
+                "repository path '{}' is not a directory",
+                repo_path.display()
+            ),
+        )
+        .with_hint("Pass the repository root with --repository-path or REPOSITORY_PATH");
+    }
+
+    let Some(path) = codeowners_validator_core::find_codeowners_file(repo_path) else {
+        return Diagnostic::new(
+            NAME,
+            Status::Fail,
+            format!("not found in '{}'", repo_path.display()),
+        )
+        .with_hint("Create .github/CODEOWNERS, CODEOWNERS or docs/CODEOWNERS");
+    };
+
+    let display = path.strip_prefix(repo_path).unwrap_or(&path).display();
+    match std::fs::read_to_string(&path) {
+        Ok(_) => Diagnostic::new(NAME, Status::Pass, format!("found {}", display)),
+        Err(e) => Diagnostic::new(
+            NAME,
+            Status::Fail,
+            format!("found {} but it cannot be read: {}", display, e),
+        )
+        .with_hint("Check the file's permissions and that it is valid UTF-8"),
+    }
+}
+
+/// Checks that git is installed and the repository path is a work tree.
+fn check_git(repo_path: &Path) -> Diagnostic {
+    const NAME: &str = "git";
+
+    let version = match Command::new("git").arg("--version").output() {
+        Ok(output) if output.status.success() => {
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        }
+        _ => {
+            return Diagnostic::new(NAME, Status::Warn, "git is not installed")
+                .with_hint("Install git to verify that the repository path is a git checkout");
+        }
+    };
+
+    let in_work_tree = Command::new("git")
+        .arg("-C")
+        .arg(repo_path)
+        .args(["rev-parse", "--is-inside-work-tree"])
+        .output()
+        .is_ok_and(|output| output.status.success());
+
+    if in_work_tree {
+        Diagnostic::new(NAME, Status::Pass, version)
+    } else {
+        Diagnostic::new(
+            NAME,
+            Status::Warn,
+            format!("{}; '{}' is not a git work tree", version, repo_path.display()),
+        )
+        .with_hint(".gitignore rules are only applied inside a git checkout; point --repository-path at one")
+    }
+}
+
+/// Checks that the options and environment variables form a valid configuration.
+fn check_config(args: &Args) -> Diagnostic {
+    const NAME: &str = "configuration";
+
+    match ValidatedConfig::from_args(args) {
+        Ok(config) => {
+            let checks: Vec<_> = config
+                .checks
+                .iter()
+                .filter_map(|c| c.to_possible_value())
+                .map(|v| v.get_name().to_string())
+                .collect();
+            Diagnostic::new(
+                NAME,
+                Status::Pass,
+                format!("valid (checks: {})", checks.join(", ")),
+            )
+        }
+        // Already reported by the CODEOWNERS diagnostic
+        Err(ConfigError::ReadCodeowners(_)) => {
+            Diagnostic::new(NAME, Status::Skip, "requires a CODEOWNERS file")
+        }
+        Err(e) => Diagnostic::new(NAME, Status::Fail, e.to_string())
+            .with_hint("Fix the option or environment variable named above"),
+    }
+}
+
+/// Checks API reachability and the configured credentials.
+async fn check_github(args: &Args) -> Vec<Diagnostic> {
+    const API: &str = "GitHub API";
+    const AUTH: &str = "GitHub credentials";
+
+    let client = match create_octocrab(args).await {
+        Ok(Some(client)) => client,
+        Ok(None) => {
+            let auth = if args.effective_checks().contains(&super::CheckKind::Owners) {
+                Diagnostic::new(AUTH, Status::Warn, "not configured")
+                    .with_hint("Set GITHUB_ACCESS_TOKEN or GitHub App credentials; without them owners are reported as unchecked")
+            } else {
+                Diagnostic::new(AUTH, Status::Skip, "not configured (owners check disabled)")
+            };
+            let reachable = match unauthenticated(args) {
+                Ok(client) => check_reachable(&client).await,
+                Err(e) => Diagnostic::new(API, Status::Fail, e).with_hint(REACHABILITY_HINT),
+            };
+            return vec![reachable, auth];
+        }
+        Err(e) => {
+            return vec![
+                Diagnostic::new(API, Status::Skip, "requires a working client"),
+                Diagnostic::new(AUTH, Status::Fail, e.to_string())
+                    .with_hint("Check GITHUB_BASE_URL and the GitHub App private key"),
+            ];
+        }
+    };
+
+    let reachable = check_reachable(&client).await;
+    if reachable.status == Status::Fail {
+        let auth = Diagnostic::new(AUTH, Status::Skip, "API is unreachable");
+        return vec![reachable, auth];
+    }
+
+    let auth = if args.has_github_app_auth() {
+        check_app_installation(&client).await
+    } else {
+        check_token(&client).await
+    };
+    vec![reachable, auth]
+}
+
+/// Builds a client without credentials for the reachability check.
+fn unauthenticated(args: &Args) -> Result<Octocrab, String> {
+    let invalid =
+        |e: octocrab::Error| format!("invalid base URL '{}': {}", args.github_base_url, e);
+    Octocrab::builder()
+        .base_uri(args.github_base_url.as_str())
+        .map_err(invalid)?
+        .build()
+        .map_err(invalid)
+}
+
+/// Checks that the API at the configured base URL answers requests.
+async fn check_reachable(client: &Octocrab) -> Diagnostic {
+    const NAME: &str = "GitHub API";
+
+    match client._get("/meta").await {
+        Ok(response) if response.status().is_success() => {
+            Diagnostic::new(NAME, Status::Pass, "reachable")
+        }
+        Ok(response) => Diagnostic::new(
+            NAME,
+            Status::Fail,
+            format!("/meta returned {}", response.status()),
+        )
+        .with_hint(REACHABILITY_HINT),
+        Err(e) => Diagnostic::new(
+            NAME,
+            Status::Fail,
+            format!("request failed: {}", describe(&e)),
+        )
+        .with_hint(REACHABILITY_HINT),
+    }
+}
+
+/// Checks that a personal access token is accepted and has the needed scopes.
+async fn check_token(client: &Octocrab) -> Diagnostic {
+    const NAME: &str = "GitHub credentials";
+
+    match client._get("/user").await {
+        Ok(response) if response.status().is_success() => {
+            let scopes = response
+                .headers()
+                .get("x-oauth-scopes")
+                .and_then(|value| value.to_str().ok());
+            check_scopes(scopes)
+        }
+        Ok(response) if response.status() == StatusCode::UNAUTHORIZED => {
+            Diagnostic::new(NAME, Status::Fail, "token was rejected (401 Unauthorized)")
+                .with_hint("The token is invalid or expired; generate a new one")
+        }
+        Ok(response) => Diagnostic::new(
+            NAME,
+            Status::Fail,
+            format!("/user returned {}", response.status()),
+        )
+        .with_hint("Make sure the token belongs to a user, not an app installation"),
+        Err(e) => Diagnostic::new(
+            NAME,
+            Status::Fail,
+            format!("request failed: {}", describe(&e)),
+        ),
+    }
+}
+
+/// Checks that a GitHub App installation token can be obtained and used.
+async fn check_app_installation(client: &Octocrab) -> Diagnostic {
+    const NAME: &str = "GitHub credentials";
+    const HINT: &str = "Check GITHUB_APP_ID, GITHUB_APP_INSTALLATION_ID and that the app is installed on the organization";
+
+    match client._get("/installation/repositories?per_page=1").await {
+        Ok(response) if response.status().is_success() => {
+            Diagnostic::new(NAME, Status::Pass, "GitHub App installation token is valid")
+        }
+        Ok(response) => Diagnostic::new(
+            NAME,
+            Status::Fail,
+            format!("installation request returned {}", response.status()),
+        )
+        .with_hint(HINT),
+        Err(e) => Diagnostic::new(
+            NAME,
+            Status::Fail,
+            format!("failed to get installation token: {}", describe(&e)),
+        )
+        .with_hint(HINT),
+    }
+}
+
+/// Returns the first line of an octocrab error, without its backtrace.
+fn describe(error: &octocrab::Error) -> String {
+    let message = error.to_string();
+    message.lines().next().unwrap_or_default().to_string()
+}
+
+/// Evaluates the `X-OAuth-Scopes` header of a classic token.
+///
+/// Fine-grained tokens do not report scopes, so their permissions cannot be
+/// verified here.
+fn check_scopes(scopes: Option<&str>) -> Diagnostic {
+    const NAME: &str = "GitHub credentials";
+
+    let Some(scopes) = scopes else {
+        return Diagnostic::new(
+            NAME,
+            Status::Pass,
+            "token is valid (fine-grained; scopes are not reported)",
+        );
+    };
+
+    let scopes: Vec<_> = scopes
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect();
+    let listed = if scopes.is_empty() {
+        "none".to_string()
+    } else {
+        scopes.join(", ")
+    };
+
+    if scopes.iter().any(|s| TEAM_SCOPES.contains(s)) {
+        Diagnostic::new(
+            NAME,
+            Status::Pass,
+            format!("token is valid (scopes: {})", listed),
+        )
+    } else {
+        Diagnostic::new(
+            NAME,
+            Status::Warn,
+            format!("token is valid but lacks read:org (scopes: {})", listed),
+        )
+        .with_hint("Add the read:org scope so team owners can be verified")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn codeowners_diagnostic() {
+        let dir = TempDir::new().unwrap();
+        let missing = check_codeowners(dir.path());
+        assert_eq!(missing.status, Status::Fail);
+        assert!(missing.hint.is_some());
+
+        fs::create_dir(dir.path().join(".github")).unwrap();
+        fs::write(dir.path().join(".github/CODEOWNERS"), "* @owner\n").unwrap();
+        let found = check_codeowners(dir.path());
+        assert_eq!(found.status, Status::Pass);
+        assert!(found.message.contains("CODEOWNERS"));
+
+        let not_dir = check_codeowners(&dir.path().join("nope"));
+        assert_eq!(not_dir.status, Status::Fail);
+    }
+
+    #[test]
+    fn config_diagnostic() {
+        let dir = TempDir::new().unwrap();
+        let repo = dir.path().to_str().unwrap();
+        let args = Args::parse_from(["codeowners-validator", "--repository-path", repo]);
+        assert_eq!(check_config(&args).status, Status::Skip);
+
+        fs::write(dir.path().join("CODEOWNERS"), "* @owner\n").unwrap();
+        let diagnostic = check_config(&args);
+        assert_eq!(diagnostic.status, Status::Fail);
+        assert!(diagnostic.message.contains("OWNER_CHECKER_REPOSITORY"));
+
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "--repository-path",
+            repo,
+            "--checks",
+            "files,syntax",
+        ]);
+        let diagnostic = check_config(&args);
+        assert_eq!(diagnostic.status, Status::Pass);
+        assert!(diagnostic.message.contains("files, syntax"));
+    }
+
+    #[test]
+    fn scopes_diagnostic() {
+        assert_eq!(check_scopes(None).status, Status::Pass);
+        assert_eq!(check_scopes(Some("repo, read:org")).status, Status::Pass);
+
+        let missing = check_scopes(Some("repo"));
+        assert_eq!(missing.status, Status::Warn);
+        assert!(missing.hint.unwrap().contains("read:org"));
+        assert!(check_scopes(Some("")).message.contains("scopes: none"));
+    }
+
+    #[test]
+    fn report_rendering() {
+        let report = DoctorReport {
+            diagnostics: vec![
+                Diagnostic::new("git", Status::Pass, "git version 2.43.0"),
+                Diagnostic::new("CODEOWNERS file", Status::Fail, "not found")
+                    .with_hint("Create one"),
+            ],
+        };
+        assert!(report.has_failures());
+        assert_eq!(
+            report.render(false),
+            "[PASS] git: git version 2.43.0\n\
+             [FAIL] CODEOWNERS file: not found\n       hint: Create one\n\n\
+             ✗ 1 check(s) failed\n"
+        );
+        assert!(!DoctorReport::default().has_failures());
+    }
+}
@@ -0,0 +1,271 @@
+//! Effective-owners report for `codeowners-validator report effective-owners`.
+//!
+//! Expands team owners into their current members and lists, for each
+//! directory, the individuals effectively responsible for its files.
+//! Incident-response tooling needs people to page, not teams to look up.
+
+use super::graph::directory_of;
+use codeowners_validator_core::CodeownersFile;
+use codeowners_validator_core::matching::OwnersResolver;
+use codeowners_validator_core::parse::Owner;
+use codeowners_validator_core::validate::github_client::GithubClient;
+use colored::Colorize;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::Write;
+use tracing::{debug, warn};
+
+/// The individuals responsible for one directory.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct DirectoryOwners {
+    /// The directory, as in the ownership graph (e.g. `src/core/`, or `/`
+    /// for the repository root).
+    pub directory: String,
+    /// Users and team members owning files in the directory, as `@login`,
+    /// and email owners, sorted.
+    pub individuals: BTreeSet<String>,
+    /// Teams owning files in the directory whose members could not be read.
+    pub unresolved_teams: BTreeSet<String>,
+}
+
+/// The individuals effectively responsible for each directory.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct EffectiveOwners {
+    /// Directories with owned files, sorted.
+    pub directories: Vec<DirectoryOwners>,
+}
+
+impl EffectiveOwners {
+    /// Builds the report from the effective owners of `files`.
+    ///
+    /// Files are grouped by their first `depth` directory components, and
+    /// unowned files are left out. `members` maps lowercased team owners
+    /// (e.g. `@org/core`) to the logins of their members; teams missing from
+    /// it are reported as unresolved.
+    pub fn build(
+        file: &CodeownersFile,
+        files: &[String],
+        depth: usize,
+        members: &HashMap<String, Vec<String>>,
+    ) -> Self {
+        let resolver = OwnersResolver::from_file(file);
+        let mut directories: BTreeMap<String, DirectoryOwners> = BTreeMap::new();
+
+        for path in files {
+            let Some(owners) = resolver.owners_for(path) else {
+                continue;
+            };
+            let directory = directory_of(path, depth);
+            let entry = directories
+                .entry(directory.clone())
+                .or_insert_with(|| DirectoryOwners {
+                    directory,
+                    ..Default::default()
+                });
+            for owner in owners {
+                match owner {
+                    Owner::Team { .. } => match members.get(&owner.normalized()) {
+                        Some(logins) => entry
+                            .individuals
+                            .extend(logins.iter().map(|login| format!("@{}", login))),
+                        None => {
+                            entry.unresolved_teams.insert(owner.as_str().into_owned());
+                        }
+                    },
+                    Owner::User { .. } | Owner::Email { .. } => {
+                        entry.individuals.insert(owner.as_str().into_owned());
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Self {
+            directories: directories.into_values().collect(),
+        }
+    }
+}
+
+/// Looks up the members of every team owner in `file`.
+///
+/// Returns the members keyed by lowercased team owner. Teams whose
+/// membership cannot be read are left out with a warning.
+pub async fn expand_teams(
+    client: &dyn GithubClient,
+    file: &CodeownersFile,
+) -> HashMap<String, Vec<String>> {
+    let teams: BTreeMap<String, (&str, &str)> = file
+        .extract_rules()
+        .into_iter()
+        .flat_map(|(_, owners)| owners)
+        .filter_map(|owner| match owner {
+            Owner::Team { org, team, .. } => {
+                Some((owner.normalized(), (org.as_str(), team.as_str())))
+            }
+            _ => None,
+        })
+        .collect();
+
+    let mut members = HashMap::new();
+    for (owner, (org, team)) in teams {
+        match client.team_members(org, team).await {
+            Ok(Some(logins)) => {
+                debug!("Team {} has {} member(s)", owner, logins.len());
+                members.insert(owner, logins);
+            }
+            Ok(None) => warn!("Cannot read members of {}", owner),
+            Err(e) => warn!("Cannot read members of {}: {}", owner, e),
+        }
+    }
+    members
+}
+
+/// Renders the report as a table, one row per directory.
+pub fn render_human(report: &EffectiveOwners, use_colors: bool) -> String {
+    let mut output = String::from("Individuals effectively owning each directory\n");
+    if report.directories.is_empty() {
+        output.push_str("No directory has owned files\n");
+        return output;
+    }
+
+    let width = report
+        .directories
+        .iter()
+        .map(|directory| directory.directory.len())
+        .max()
+        .unwrap_or_default();
+    for directory in &report.directories {
+        let individuals: Vec<_> = directory.individuals.iter().map(String::as_str).collect();
+        let mut row = if individuals.is_empty() {
+            "no individuals".to_string()
+        } else {
+            individuals.join(", ")
+        };
+        if !directory.unresolved_teams.is_empty() {
+            let teams: Vec<_> = directory
+                .unresolved_teams
+                .iter()
+                .map(String::as_str)
+                .collect();
+            let unresolved = format!("(unresolved: {})", teams.join(", "));
+            let unresolved = if use_colors {
+                unresolved.yellow().to_string()
+            } else {
+                unresolved
+            };
+            row = format!("{} {}", row, unresolved);
+        }
+        let _ = writeln!(output, "{:<width$}  {}", directory.directory, row);
+    }
+    output
+}
+
+/// Renders the report as pretty-printed JSON.
+pub fn render_json(report: &EffectiveOwners) -> String {
+    serde_json::to_string_pretty(report).expect("effective owners are always serializable")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use codeowners_validator_core::parse::parse_codeowners;
+    use codeowners_validator_core::validate::github_client::{
+        GithubClientError, TeamExistsResult, UserExistsResult,
+    };
+
+    /// A client that knows the members of `@org/core` only.
+    struct MembersClient;
+
+    #[async_trait]
+    impl GithubClient for MembersClient {
+        async fn user_exists(
+            &self,
+            _username: &str,
+        ) -> Result<UserExistsResult, GithubClientError> {
+            Ok(UserExistsResult::Exists)
+        }
+
+        async fn team_exists(
+            &self,
+            _org: &str,
+            _team: &str,
+        ) -> Result<TeamExistsResult, GithubClientError> {
+            Ok(TeamExistsResult::Exists)
+        }
+
+        async fn team_members(
+            &self,
+            _org: &str,
+            team: &str,
+        ) -> Result<Option<Vec<String>>, GithubClientError> {
+            Ok(team
+                .eq_ignore_ascii_case("core")
+                .then(|| vec!["bob".to_string(), "alice".to_string()]))
+        }
+    }
+
+    fn files() -> Vec<String> {
+        [
+            "README.md",
+            "src/lib.rs",
+            "src/ops/deploy.rs",
+            "docs/guide.md",
+        ]
+        .map(String::from)
+        .to_vec()
+    }
+
+    async fn report() -> EffectiveOwners {
+        let file = parse_codeowners(
+            "* @org/Core\n/src/ops/ @org/core @carol oncall@example.com\n/docs/ @org/secret\n",
+        )
+        .ast;
+        let members = expand_teams(&MembersClient, &file).await;
+        EffectiveOwners::build(&file, &files(), 1, &members)
+    }
+
+    #[tokio::test]
+    async fn expands_teams_per_directory() {
+        let report = report().await;
+        let rows: Vec<_> = report
+            .directories
+            .iter()
+            .map(|d| {
+                (
+                    d.directory.as_str(),
+                    d.individuals.iter().cloned().collect::<Vec<_>>(),
+                    d.unresolved_teams.len(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                ("/", vec!["@alice".to_string(), "@bob".to_string()], 0),
+                ("docs/", vec![], 1),
+                (
+                    "src/",
+                    ["@alice", "@bob", "@carol", "oncall@example.com"]
+                        .map(String::from)
+                        .to_vec(),
+                    0
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn table_rendering() {
+        let report = report().await;
+        assert_eq!(
+            render_human(&report, false),
+            "Individuals effectively owning each directory\n\
+             /      @alice, @bob\n\
+             docs/  no individuals (unresolved: @org/secret)\n\
+             src/   @alice, @bob, @carol, oncall@example.com\n"
+        );
+        let json: serde_json::Value = serde_json::from_str(&render_json(&report)).unwrap();
+        assert_eq!(json["directories"][1]["unresolved_teams"][0], "@org/secret");
+    }
+}
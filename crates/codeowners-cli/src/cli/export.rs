@@ -0,0 +1,141 @@
+//! CSV export of rules and resolved ownership for `report csv`.
+//!
+//! The export is meant for spreadsheet-based audits: either one row per
+//! CODEOWNERS rule with the number of files it matches and owns, or one row
+//! per repository file with the owners it resolves to. Both include the
+//! owning rule's `escalation` annotation, for incident tooling.
+
+use super::render::escape_csv;
+use codeowners_validator_core::CodeownersFile;
+use codeowners_validator_core::matching::{Rule, RuleSet};
+use codeowners_validator_core::parse::{Escalation, Owner};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// Header of the per-rule export.
+const RULES_HEADER: &str = "line,pattern,owners,matched_files,effective_files,escalation";
+
+/// Header of the per-file export.
+const FILES_HEADER: &str = "path,owners,line,pattern,escalation";
+
+/// Renders one row per rule, in file order.
+///
+/// `files` are the repository files, relative to the root.
+pub fn render_rules(file: &CodeownersFile, files: &[String]) -> String {
+    let rules = RuleSet::from_file(file);
+    let escalations = file.escalations();
+    let mut output = format!("{}\n", RULES_HEADER);
+
+    for coverage in rules.coverage(files.iter().map(String::as_str)) {
+        let _ = writeln!(
+            output,
+            "{},{},{},{},{},{}",
+            coverage.rule.line_number(),
+            escape_csv(&coverage.rule.pattern.text),
+            escape_csv(&join_owners(coverage.rule.owners)),
+            coverage.matched_files,
+            coverage.effective_files,
+            escape_csv(escalation_of(&escalations, coverage.rule.line_number()))
+        );
+    }
+    output
+}
+
+/// Renders one row per file with the rule that owns it.
+///
+/// Files no rule matches have empty owner, line, pattern and escalation
+/// columns.
+pub fn render_files(file: &CodeownersFile, files: &[String]) -> String {
+    let rules = RuleSet::from_file(file);
+    let escalations = file.escalations();
+    let mut output = format!("{}\n", FILES_HEADER);
+
+    for path in files {
+        let (owners, line, pattern, escalation) = match rules.last_match(path) {
+            Some(Rule {
+                line,
+                pattern,
+                owners,
+            }) => (
+                join_owners(owners),
+                line.span.line.to_string(),
+                pattern.text.as_str(),
+                escalation_of(&escalations, line.span.line),
+            ),
+            None => (String::new(), String::new(), "", ""),
+        };
+        let _ = writeln!(
+            output,
+            "{},{},{},{},{}",
+            escape_csv(path),
+            escape_csv(&owners),
+            line,
+            escape_csv(pattern),
+            escape_csv(escalation)
+        );
+    }
+    output
+}
+
+/// Returns the escalation annotation of the rule on `line`, as written, or
+/// an empty string.
+fn escalation_of(escalations: &BTreeMap<usize, Escalation>, line: usize) -> &str {
+    escalations
+        .get(&line)
+        .map_or("", |escalation| escalation.value.as_str())
+}
+
+/// Joins owners with spaces, as they are written in CODEOWNERS.
+fn join_owners(owners: &[Owner]) -> String {
+    owners
+        .iter()
+        .map(Owner::as_str)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::simulate::parser_config;
+    use codeowners_validator_core::parse::parse_codeowners_with_config;
+
+    const CODEOWNERS: &str =
+        "* @default\n# escalation: pagerduty:rust\n*.rs @rust @org/core\n/docs/ @docs\n/vendor/\n";
+
+    fn files() -> Vec<String> {
+        ["README.md", "src/lib.rs", "vendor/a.rs"]
+            .iter()
+            .map(|f| f.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn rule_rows() {
+        let file = parse_codeowners_with_config(CODEOWNERS, &parser_config()).ast;
+        assert_eq!(
+            render_rules(&file, &files()),
+            "line,pattern,owners,matched_files,effective_files,escalation\n\
+             1,*,@default,3,1,\n\
+             3,*.rs,@rust @org/core,2,1,pagerduty:rust\n\
+             4,/docs/,@docs,0,0,\n\
+             5,/vendor/,,1,1,\n"
+        );
+    }
+
+    #[test]
+    fn file_rows() {
+        let file = parse_codeowners_with_config(
+            "/src/ @core # escalation: opsgenie:core\n",
+            &parser_config(),
+        )
+        .ast;
+        assert_eq!(
+            render_files(&file, &files()),
+            "path,owners,line,pattern,escalation\n\
+             README.md,,,,\n\
+             src/lib.rs,@core,1,/src/,opsgenie:core\n\
+             vendor/a.rs,,,,\n"
+        );
+    }
+}
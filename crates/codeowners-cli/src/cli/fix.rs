@@ -0,0 +1,188 @@
+//! Reporting of applied fixes for `--fix` and `--fix-dry-run`.
+//!
+//! Fixes come from the findings themselves (see
+//! [`ValidationError::fix`](codeowners_validator_core::validate::ValidationError::fix)).
+//! `--fix` lists each change after writing the file; `--fix-dry-run` shows
+//! the changes as a unified diff instead of writing them.
+
+use codeowners_validator_core::validate::Fix;
+use colored::Colorize;
+use std::fmt::Write;
+
+/// Renders one line per fix, e.g. `  line 3: Remove '*.rs @alice'`.
+///
+/// `fixes` must be the fixes that apply to `source`, in source order (see
+/// [`applicable_fixes`](codeowners_validator_core::validate::applicable_fixes)).
+pub fn render_summary(source: &str, fixes: &[&Fix]) -> String {
+    let mut output = String::new();
+    for fix in fixes {
+        let _ = writeln!(output, "  line {}: {}", fix.span.line, fix.describe(source));
+    }
+    output
+}
+
+/// A run of whole source lines changed by one or more fixes.
+struct Hunk<'a> {
+    start: usize,
+    end: usize,
+    fixes: Vec<&'a Fix>,
+}
+
+/// Returns the offset of the start of the line containing `offset`.
+fn line_start(source: &str, offset: usize) -> usize {
+    source[..offset]
+        .rfind('\n')
+        .map_or(0, |newline| newline + 1)
+}
+
+/// Returns the offset just past the line break ending the line containing
+/// `offset`, or the end of the source. An offset at the start of a line
+/// ends the previous one.
+fn line_end(source: &str, offset: usize) -> usize {
+    if offset > 0 && source.as_bytes()[offset - 1] == b'\n' {
+        return offset;
+    }
+    source[offset..]
+        .find('\n')
+        .map_or(source.len(), |newline| offset + newline + 1)
+}
+
+/// Groups fixes into hunks of the whole lines they change.
+fn hunks<'a>(source: &str, fixes: &[&'a Fix]) -> Vec<Hunk<'a>> {
+    let mut hunks: Vec<Hunk> = Vec::new();
+    for fix in fixes {
+        let start = line_start(source, fix.span.offset);
+        let end = line_end(source, fix.span.end_offset()).max(start);
+        match hunks.last_mut() {
+            Some(hunk) if start < hunk.end => {
+                hunk.end = hunk.end.max(end);
+                hunk.fixes.push(fix);
+            }
+            _ => hunks.push(Hunk {
+                start,
+                end,
+                fixes: vec![fix],
+            }),
+        }
+    }
+    hunks
+}
+
+/// Formats a hunk range, e.g. `3,2`; an empty range names the line before it.
+fn range(start: usize, count: usize) -> String {
+    if count == 0 {
+        format!("{},0", start - 1)
+    } else {
+        format!("{},{}", start, count)
+    }
+}
+
+/// Renders the fixes as a unified diff of `path`, without context lines.
+///
+/// `fixes` must be the fixes that apply to `source`, in source order.
+pub fn render_diff(path: &str, source: &str, fixes: &[&Fix], use_colors: bool) -> String {
+    let mut output = String::new();
+    let _ = writeln!(output, "--- {}", path);
+    let _ = writeln!(output, "+++ {}", path);
+
+    let mut delta: isize = 0;
+    for hunk in hunks(source, fixes) {
+        let old = &source[hunk.start..hunk.end];
+        let mut new = old.to_string();
+        for fix in hunk.fixes.iter().rev() {
+            let start = fix.span.offset - hunk.start;
+            new.replace_range(start..start + fix.span.length, &fix.replacement_text);
+        }
+
+        let old_start = source[..hunk.start].matches('\n').count() + 1;
+        let old_count = old.lines().count();
+        let new_count = new.lines().count();
+        let new_start = old_start.saturating_add_signed(delta);
+        delta += new_count as isize - old_count as isize;
+
+        let header = format!(
+            "@@ -{} +{} @@",
+            range(old_start, old_count),
+            range(new_start, new_count)
+        );
+        let _ = writeln!(
+            output,
+            "{}",
+            if use_colors {
+                header.cyan().to_string()
+            } else {
+                header
+            }
+        );
+        for (sign, text) in old
+            .lines()
+            .map(|line| ('-', line))
+            .chain(new.lines().map(|line| ('+', line)))
+        {
+            let line = format!("{}{}", sign, text);
+            let line = match (use_colors, sign) {
+                (false, _) => line,
+                (true, '-') => line.red().to_string(),
+                (true, _) => line.green().to_string(),
+            };
+            let _ = writeln!(output, "{}", line);
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codeowners_validator_core::parse::Span;
+    use codeowners_validator_core::validate::applicable_fixes;
+
+    const SOURCE: &str = "* @core\n*.rs @Alice\n/docs/ @docs\n*.md @alice\n";
+
+    fn fixes() -> Vec<Fix> {
+        vec![
+            // Remove the '/docs/' line
+            Fix::replace(Span::new(20, 3, 1, 13), ""),
+            // '@Alice' -> '@alice'
+            Fix::replace(Span::new(13, 2, 6, 6), "@alice"),
+        ]
+    }
+
+    #[test]
+    fn summary_lists_fixes_in_source_order() {
+        let fixes = fixes();
+        let applicable = applicable_fixes(SOURCE, &fixes);
+        assert_eq!(
+            render_summary(SOURCE, &applicable),
+            "  line 2: Replace '@Alice' with '@alice'\n  line 3: Remove '/docs/ @docs'\n"
+        );
+    }
+
+    #[test]
+    fn diff_hunk_per_change() {
+        let fixes = fixes();
+        let applicable = applicable_fixes(SOURCE, &fixes);
+        assert_eq!(
+            render_diff(".github/CODEOWNERS", SOURCE, &applicable, false),
+            "--- .github/CODEOWNERS\n+++ .github/CODEOWNERS\n\
+             @@ -2,1 +2,1 @@\n-*.rs @Alice\n+*.rs @alice\n\
+             @@ -3,1 +2,0 @@\n-/docs/ @docs\n"
+        );
+    }
+
+    #[test]
+    fn diff_tracks_line_offsets() {
+        let source = "a @x\nb @y\nc @z\nd @w\n";
+        let fixes = vec![
+            Fix::replace(Span::new(0, 1, 1, 5), ""),
+            Fix::replace(Span::new(17, 4, 3, 2), "@v"),
+        ];
+        let applicable = applicable_fixes(source, &fixes);
+        assert_eq!(
+            render_diff("CODEOWNERS", source, &applicable, false),
+            "--- CODEOWNERS\n+++ CODEOWNERS\n\
+             @@ -1,1 +0,0 @@\n-a @x\n\
+             @@ -4,1 +3,1 @@\n-d @w\n+d @v\n"
+        );
+    }
+}
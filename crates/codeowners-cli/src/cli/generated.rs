@@ -0,0 +1,192 @@
+//! Generated region verification for `codeowners-validator verify-generated`.
+//!
+//! Recomputes the content hash of each generated region (see
+//! [`codeowners_validator_core::parse::Provenance`]) and compares it with the
+//! recorded `generated-hash` comment. Generator pipelines can refresh the
+//! recorded hashes with `--update-hash`.
+
+use codeowners_validator_core::CodeownersFile;
+use codeowners_validator_core::parse::{GeneratedRegion, Provenance, Span};
+use codeowners_validator_core::validate::Fix;
+use colored::Colorize;
+use serde::Serialize;
+use std::fmt::Write;
+
+/// Verification state of a generated region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RegionState {
+    /// The content matches the recorded hash.
+    Verified,
+    /// The content was edited since the hash was recorded.
+    Modified,
+    /// The region has no recorded hash.
+    Unhashed,
+}
+
+/// The verification result of a single generated region.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RegionStatus {
+    /// The generating tool.
+    pub generator: String,
+    /// Line number of the `generated-by` comment.
+    pub line: usize,
+    /// Whether the region matches its recorded hash.
+    pub state: RegionState,
+    /// The hash recorded in the file.
+    pub recorded_hash: Option<String>,
+    /// The hash of the current content.
+    pub computed_hash: String,
+}
+
+/// Verifies every generated region in the file.
+pub fn verify(file: &CodeownersFile) -> Vec<RegionStatus> {
+    file.generated_regions()
+        .iter()
+        .map(|region| {
+            let state = match region.is_modified(file) {
+                Some(false) => RegionState::Verified,
+                Some(true) => RegionState::Modified,
+                None => RegionState::Unhashed,
+            };
+            RegionStatus {
+                generator: region.generator.clone(),
+                line: file.lines[region.header].span.line,
+                state,
+                recorded_hash: region.hash.clone(),
+                computed_hash: region.compute_hash(file),
+            }
+        })
+        .collect()
+}
+
+/// Returns the fixes that record the current hash of every region whose
+/// hash is missing or stale.
+///
+/// Missing hash comments are inserted directly after the `generated-by` line.
+pub fn hash_fixes(file: &CodeownersFile) -> Vec<Fix> {
+    file.generated_regions()
+        .iter()
+        .filter(|region| region.is_modified(file) != Some(false))
+        .map(|region| hash_fix(file, region))
+        .collect()
+}
+
+/// Builds the fix recording the current hash of one region.
+fn hash_fix(file: &CodeownersFile, region: &GeneratedRegion) -> Fix {
+    let comment = Provenance::GeneratedHash {
+        hash: region.compute_hash(file),
+    }
+    .to_string();
+
+    match region.hash_line {
+        Some(index) => Fix::replace(file.lines[index].span, comment),
+        None => {
+            let header = file.lines[region.header].span;
+            let end = header.offset + header.length;
+            Fix::replace(
+                Span::point(end, header.line, header.column + header.length),
+                format!("\n{}", comment),
+            )
+        }
+    }
+}
+
+/// Renders verification results for the terminal.
+pub fn render_human(statuses: &[RegionStatus], use_colors: bool) -> String {
+    if statuses.is_empty() {
+        return "No generated regions found\n".to_string();
+    }
+
+    let mut output = String::new();
+    for status in statuses {
+        let (label, detail) = match status.state {
+            RegionState::Verified => ("ok", String::new()),
+            RegionState::Modified => (
+                "modified",
+                format!(
+                    " (expected {}, found {})",
+                    status.recorded_hash.as_deref().unwrap_or_default(),
+                    status.computed_hash
+                ),
+            ),
+            RegionState::Unhashed => ("unhashed", " (no generated-hash comment)".to_string()),
+        };
+        let label = match (use_colors, status.state) {
+            (false, _) => label.to_string(),
+            (true, RegionState::Verified) => label.green().to_string(),
+            (true, RegionState::Modified) => label.red().bold().to_string(),
+            (true, RegionState::Unhashed) => label.yellow().to_string(),
+        };
+        let _ = writeln!(
+            output,
+            "line {}: region generated by '{}': {}{}",
+            status.line, status.generator, label, detail
+        );
+    }
+
+    output
+}
+
+/// Renders verification results as pretty-printed JSON.
+pub fn render_json(statuses: &[RegionStatus]) -> String {
+    serde_json::to_string_pretty(statuses).expect("region statuses are always serializable")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codeowners_validator_core::parse::parse_codeowners;
+    use codeowners_validator_core::validate::apply_fixes;
+
+    const SOURCE: &str = "\
+* @default
+# generated-by: gen v1
+/api/ @org/api
+# end-generated
+# generated-by: gen v2
+# generated-hash: sha256:stale
+/web/ @org/web
+";
+
+    #[test]
+    fn update_then_verify() {
+        let file = parse_codeowners(SOURCE).ast;
+        let states: Vec<_> = verify(&file).iter().map(|s| s.state).collect();
+        assert_eq!(states, [RegionState::Unhashed, RegionState::Modified]);
+
+        let updated = apply_fixes(SOURCE, &hash_fixes(&file));
+        let lines: Vec<_> = updated.lines().collect();
+        assert_eq!(lines[1], "# generated-by: gen v1");
+        assert!(lines[2].starts_with("# generated-hash: sha256:"));
+        assert!(lines[6].starts_with("# generated-hash: sha256:"));
+        assert_ne!(lines[6], "# generated-hash: sha256:stale");
+
+        let file = parse_codeowners(&updated).ast;
+        assert!(
+            verify(&file)
+                .iter()
+                .all(|s| s.state == RegionState::Verified)
+        );
+        assert!(hash_fixes(&file).is_empty());
+    }
+
+    #[test]
+    fn human_rendering() {
+        let file = parse_codeowners(SOURCE).ast;
+        let rendered = render_human(&verify(&file), false);
+        assert!(rendered.starts_with(
+            "line 2: region generated by 'gen v1': unhashed (no generated-hash comment)\n\
+             line 5: region generated by 'gen v2': modified (expected sha256:stale, found sha256:"
+        ));
+        assert_eq!(render_human(&[], false), "No generated regions found\n");
+    }
+
+    #[test]
+    fn json_rendering() {
+        let file = parse_codeowners(SOURCE).ast;
+        let json: serde_json::Value = serde_json::from_str(&render_json(&verify(&file))).unwrap();
+        assert_eq!(json[0]["state"], "unhashed");
+        assert_eq!(json[1]["recorded_hash"], "sha256:stale");
+    }
+}
@@ -3,6 +3,7 @@
 //! This module provides the octocrab-based implementation of the GithubClient trait
 //! for use in the CLI.
 
+use super::redact::redact_patterns;
 use async_trait::async_trait;
 use codeowners_validator_core::validate::github_client::{
     GithubClient, GithubClientError, TeamExistsResult, UserExistsResult,
@@ -50,10 +51,10 @@ impl GithubClient for OctocrabClient {
                         StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
                             Ok(UserExistsResult::Unauthorized)
                         }
-                        _ => Err(GithubClientError::ApiError(e.to_string())),
+                        _ => Err(GithubClientError::ApiError(redact_patterns(&e.to_string()))),
                     }
                 } else {
-                    Err(GithubClientError::ApiError(e.to_string()))
+                    Err(GithubClientError::ApiError(redact_patterns(&e.to_string())))
                 }
             }
         }
@@ -73,12 +74,72 @@ impl GithubClient for OctocrabClient {
                         StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
                             Ok(TeamExistsResult::Unauthorized)
                         }
-                        _ => Err(GithubClientError::ApiError(e.to_string())),
+                        _ => Err(GithubClientError::ApiError(redact_patterns(&e.to_string()))),
                     }
                 } else {
-                    Err(GithubClientError::ApiError(e.to_string()))
+                    Err(GithubClientError::ApiError(redact_patterns(&e.to_string())))
                 }
             }
         }
     }
+
+    async fn team_member_count(
+        &self,
+        org: &str,
+        team: &str,
+    ) -> Result<Option<usize>, GithubClientError> {
+        // With one member per page, the number of pages is the member count
+        match self.0.teams(org).members(team).per_page(1).send().await {
+            Ok(page) => Ok(Some(
+                page.number_of_pages()
+                    .map_or(page.items.len(), |pages| pages as usize),
+            )),
+            Err(e) => match extract_status_code(&e) {
+                Some(StatusCode::NOT_FOUND | StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN) => {
+                    Ok(None)
+                }
+                _ => Err(GithubClientError::ApiError(redact_patterns(&e.to_string()))),
+            },
+        }
+    }
+
+    async fn team_members(
+        &self,
+        org: &str,
+        team: &str,
+    ) -> Result<Option<Vec<String>>, GithubClientError> {
+        let members = match self.0.teams(org).members(team).per_page(100).send().await {
+            Ok(page) => self.0.all_pages(page).await,
+            Err(e) => Err(e),
+        };
+        match members {
+            Ok(members) => Ok(Some(
+                members.into_iter().map(|member| member.login).collect(),
+            )),
+            Err(e) => match extract_status_code(&e) {
+                Some(StatusCode::NOT_FOUND | StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN) => {
+                    Ok(None)
+                }
+                _ => Err(GithubClientError::ApiError(redact_patterns(&e.to_string()))),
+            },
+        }
+    }
+
+    async fn team_parent(
+        &self,
+        org: &str,
+        team: &str,
+    ) -> Result<Option<String>, GithubClientError> {
+        // octocrab's team model has no parent field, so read the raw response
+        let route = format!("/orgs/{}/teams/{}", org, team);
+        match self.0.get::<serde_json::Value, _, ()>(route, None).await {
+            Ok(team) => Ok(team["parent"]["slug"].as_str().map(str::to_string)),
+            Err(e) => match extract_status_code(&e) {
+                Some(StatusCode::NOT_FOUND | StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN) => {
+                    Ok(None)
+                }
+                _ => Err(GithubClientError::ApiError(redact_patterns(&e.to_string()))),
+            },
+        }
+    }
 }
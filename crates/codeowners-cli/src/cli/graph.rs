@@ -0,0 +1,226 @@
+//! Ownership graph export for `codeowners-validator report graph`.
+//!
+//! Builds a bipartite graph of owners and directories, where each edge is
+//! weighted by the number of files in the directory the owner owns. Rendered
+//! as DOT or GraphML, it shows the ownership topology of an organization;
+//! directories with a single owner are highlighted as single points of failure.
+
+use super::render::escape_xml;
+use codeowners_validator_core::CodeownersFile;
+use codeowners_validator_core::matching::OwnersResolver;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+/// Default number of path components that make up a directory node.
+pub const DEFAULT_DEPTH: usize = 2;
+
+/// Node name of files at the repository root.
+const ROOT_DIRECTORY: &str = "/";
+
+/// A bipartite graph of owners and the directories they own files in.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OwnershipGraph {
+    /// Number of files owned, keyed by (owner, directory).
+    pub edges: BTreeMap<(String, String), usize>,
+}
+
+impl OwnershipGraph {
+    /// Builds the graph from the effective owners of `files`.
+    ///
+    /// Files are grouped by their first `depth` directory components.
+    /// Unowned files are left out.
+    pub fn build(file: &CodeownersFile, files: &[String], depth: usize) -> Self {
+        let resolver = OwnersResolver::from_file(file);
+        let mut edges = BTreeMap::new();
+
+        for path in files {
+            let Some(owners) = resolver.owners_for(path) else {
+                continue;
+            };
+            let directory = directory_of(path, depth);
+            for owner in owners {
+                *edges
+                    .entry((owner.as_str().to_string(), directory.clone()))
+                    .or_default() += 1;
+            }
+        }
+
+        Self { edges }
+    }
+
+    /// Returns the owners, sorted.
+    pub fn owners(&self) -> BTreeSet<&str> {
+        self.edges.keys().map(|(owner, _)| owner.as_str()).collect()
+    }
+
+    /// Returns the directories with their number of owners, sorted.
+    pub fn directories(&self) -> BTreeMap<&str, usize> {
+        let mut directories = BTreeMap::new();
+        for (_, directory) in self.edges.keys() {
+            *directories.entry(directory.as_str()).or_default() += 1;
+        }
+        directories
+    }
+}
+
+/// Returns the directory node for a file path, truncated to `depth` components.
+pub fn directory_of(path: &str, depth: usize) -> String {
+    let components: Vec<_> = path.split('/').collect();
+    let directories = &components[..components.len() - 1];
+    if directories.is_empty() || depth == 0 {
+        return ROOT_DIRECTORY.to_string();
+    }
+    format!("{}/", directories[..depth.min(directories.len())].join("/"))
+}
+
+/// Escapes text for a quoted DOT identifier.
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Renders the graph in Graphviz DOT format.
+pub fn render_dot(graph: &OwnershipGraph) -> String {
+    let mut dot = String::from("graph ownership {\n  rankdir=LR;\n");
+
+    for owner in graph.owners() {
+        let owner = escape_dot(owner);
+        writeln!(
+            dot,
+            "  \"owner:{owner}\" [label=\"{owner}\", shape=ellipse];"
+        )
+        .unwrap();
+    }
+    for (directory, owners) in graph.directories() {
+        let directory = escape_dot(directory);
+        let highlight = if owners == 1 { ", color=red" } else { "" };
+        writeln!(
+            dot,
+            "  \"dir:{directory}\" [label=\"{directory}\", shape=box{highlight}];"
+        )
+        .unwrap();
+    }
+    for ((owner, directory), files) in &graph.edges {
+        writeln!(
+            dot,
+            "  \"owner:{}\" -- \"dir:{}\" [label=\"{files}\", weight={files}];",
+            escape_dot(owner),
+            escape_dot(directory)
+        )
+        .unwrap();
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+/// Renders the graph as GraphML.
+pub fn render_graphml(graph: &OwnershipGraph) -> String {
+    let mut xml = String::new();
+    writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#).unwrap();
+    writeln!(
+        xml,
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#
+    )
+    .unwrap();
+    for (id, target, name, kind) in [
+        ("kind", "node", "kind", "string"),
+        ("label", "node", "label", "string"),
+        ("owners", "node", "owners", "int"),
+        ("files", "edge", "files", "int"),
+    ] {
+        writeln!(
+            xml,
+            r#"  <key id="{id}" for="{target}" attr.name="{name}" attr.type="{kind}"/>"#
+        )
+        .unwrap();
+    }
+    writeln!(xml, r#"  <graph id="ownership" edgedefault="undirected">"#).unwrap();
+
+    for owner in graph.owners() {
+        let owner = escape_xml(owner);
+        writeln!(
+            xml,
+            r#"    <node id="owner:{owner}"><data key="kind">owner</data><data key="label">{owner}</data></node>"#
+        )
+        .unwrap();
+    }
+    for (directory, owners) in graph.directories() {
+        let directory = escape_xml(directory);
+        writeln!(
+            xml,
+            r#"    <node id="dir:{directory}"><data key="kind">directory</data><data key="label">{directory}</data><data key="owners">{owners}</data></node>"#
+        )
+        .unwrap();
+    }
+    for ((owner, directory), files) in &graph.edges {
+        writeln!(
+            xml,
+            r#"    <edge source="owner:{}" target="dir:{}"><data key="files">{files}</data></edge>"#,
+            escape_xml(owner),
+            escape_xml(directory)
+        )
+        .unwrap();
+    }
+
+    writeln!(xml, "  </graph>").unwrap();
+    writeln!(xml, "</graphml>").unwrap();
+    xml
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codeowners_validator_core::parse::parse_codeowners;
+
+    fn fixture_graph(depth: usize) -> OwnershipGraph {
+        let file = parse_codeowners("* @default\n/src/ @core @org/rust\n").ast;
+        let files: Vec<String> = ["README.md", "src/lib.rs", "src/cli/main.rs", "docs/a.md"]
+            .iter()
+            .map(|f| f.to_string())
+            .collect();
+        OwnershipGraph::build(&file, &files, depth)
+    }
+
+    #[test]
+    fn edges_are_weighted_by_files() {
+        let graph = fixture_graph(1);
+        let edges: Vec<_> = graph
+            .edges
+            .iter()
+            .map(|((owner, dir), files)| (owner.as_str(), dir.as_str(), *files))
+            .collect();
+        assert_eq!(
+            edges,
+            [
+                ("@core", "src/", 2),
+                ("@default", "/", 1),
+                ("@default", "docs/", 1),
+                ("@org/rust", "src/", 2),
+            ]
+        );
+
+        let directories: Vec<_> = graph.directories().into_iter().collect();
+        assert_eq!(directories, [("/", 1), ("docs/", 1), ("src/", 2)]);
+        assert!(fixture_graph(2).directories().contains_key("src/cli/"));
+    }
+
+    #[test]
+    fn dot_rendering() {
+        let dot = render_dot(&fixture_graph(1));
+        assert!(dot.starts_with("graph ownership {\n"));
+        assert!(dot.contains("  \"dir:docs/\" [label=\"docs/\", shape=box, color=red];\n"));
+        assert!(dot.contains("  \"dir:src/\" [label=\"src/\", shape=box];\n"));
+        assert!(dot.contains("  \"owner:@core\" -- \"dir:src/\" [label=\"2\", weight=2];\n"));
+        assert!(dot.ends_with("}\n"));
+    }
+
+    #[test]
+    fn graphml_rendering() {
+        let xml = render_graphml(&fixture_graph(1));
+        assert!(xml.contains(r#"<node id="owner:@org/rust"><data key="kind">owner</data>"#));
+        assert!(xml.contains(
+            r#"<edge source="owner:@default" target="dir:/"><data key="files">1</data></edge>"#
+        ));
+        assert!(xml.ends_with("</graphml>\n"));
+    }
+}
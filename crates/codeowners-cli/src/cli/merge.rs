@@ -0,0 +1,350 @@
+//! Merge-readiness prediction for `codeowners-validator can-merge`.
+//!
+//! Fetches the changed files and reviews of a pull request, and reports which
+//! code owner approvals branch protection still requires before it can merge.
+//! The author's own approval does not count, and an approval by a team member
+//! counts for the team when its membership can be read.
+
+use codeowners_validator_core::CodeownersFile;
+use codeowners_validator_core::review::{OwnerSet, required_owners};
+use colored::Colorize;
+use octocrab::Octocrab;
+use octocrab::models::pulls::ReviewState;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use tracing::{debug, warn};
+
+/// The state of a pull request relevant to code owner review.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PullRequest {
+    /// The pull request number.
+    pub number: u64,
+    /// Login of the author, without a leading `@`.
+    pub author: String,
+    /// Changed paths, including the previous path of renamed files.
+    pub changed_files: Vec<String>,
+    /// Owners whose approval is in effect, e.g. `@user` or `@org/team`.
+    pub approvers: Vec<String>,
+}
+
+/// Approvals still required for some changed files.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Outstanding {
+    /// Owners of which one must approve. Empty if only the author owns the
+    /// files, so no approval can satisfy the requirement.
+    pub owners: Vec<String>,
+    /// Changed files with these owners.
+    pub files: Vec<String>,
+}
+
+/// Whether a pull request has every required code owner approval.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MergeReadiness {
+    /// The pull request number.
+    pub number: u64,
+    /// Login of the author.
+    pub author: String,
+    /// Owners whose approval is in effect.
+    pub approvers: Vec<String>,
+    /// Number of distinct approvals the changed files require.
+    pub required: usize,
+    /// Requirements no approver satisfies.
+    pub outstanding: Vec<Outstanding>,
+}
+
+impl MergeReadiness {
+    /// Computes the outstanding approvals of a pull request.
+    pub fn evaluate(file: &CodeownersFile, pr: &PullRequest) -> Self {
+        let required = required_owners(pr.changed_files.iter().map(String::as_str), file)
+            .without_author(&pr.author);
+        let outstanding = required
+            .missing(pr.approvers.iter().map(String::as_str))
+            .into_iter()
+            .map(|requirement| Outstanding {
+                owners: requirement.owners.clone(),
+                files: requirement.paths.clone(),
+            })
+            .collect();
+
+        Self {
+            number: pr.number,
+            author: pr.author.clone(),
+            approvers: pr.approvers.clone(),
+            required: required.requirements().len(),
+            outstanding,
+        }
+    }
+
+    /// Returns true if no required approval is outstanding.
+    pub fn is_ready(&self) -> bool {
+        self.outstanding.is_empty()
+    }
+}
+
+/// Returns the reviewers whose latest review is an approval, as `@login`.
+///
+/// Reviews must be in the order they were submitted. A later review
+/// requesting changes, or a dismissal, withdraws an approval; comments leave
+/// it in place.
+pub fn latest_approvers(reviews: impl IntoIterator<Item = (String, ReviewState)>) -> Vec<String> {
+    let mut approved: BTreeMap<String, bool> = BTreeMap::new();
+    for (login, state) in reviews {
+        match state {
+            ReviewState::Approved => {
+                approved.insert(login.to_lowercase(), true);
+            }
+            ReviewState::ChangesRequested | ReviewState::Dismissed => {
+                approved.insert(login.to_lowercase(), false);
+            }
+            _ => {}
+        }
+    }
+
+    approved
+        .into_iter()
+        .filter(|(_, approved)| *approved)
+        .map(|(login, _)| format!("@{}", login))
+        .collect()
+}
+
+/// Fetches the author, changed files and approvals of a pull request.
+pub async fn fetch_pull_request(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    number: u64,
+) -> octocrab::Result<PullRequest> {
+    let pulls = client.pulls(owner, repo);
+
+    let author = pulls
+        .get(number)
+        .await?
+        .user
+        .map(|user| user.login)
+        .unwrap_or_default();
+
+    let files = client.all_pages(pulls.list_files(number).await?).await?;
+    let mut changed_files = Vec::with_capacity(files.len());
+    for entry in files {
+        changed_files.push(entry.filename);
+        changed_files.extend(entry.previous_filename);
+    }
+
+    let reviews = pulls.list_reviews(number).per_page(100u8).send().await?;
+    let reviews = client.all_pages(reviews).await?;
+    let approvers = latest_approvers(
+        reviews
+            .into_iter()
+            .filter_map(|review| Some((review.user?.login, review.state?))),
+    );
+    debug!(
+        "Pull request #{}: {} changed file(s), {} approval(s)",
+        number,
+        changed_files.len(),
+        approvers.len()
+    );
+
+    Ok(PullRequest {
+        number,
+        author,
+        changed_files,
+        approvers,
+    })
+}
+
+/// Returns the required teams that have an approval from one of their members.
+///
+/// Teams whose membership cannot be read are skipped with a warning, so their
+/// requirements stay outstanding unless the team is an approver itself.
+pub async fn team_approvals(
+    client: &Octocrab,
+    required: &OwnerSet,
+    approvers: &[String],
+) -> Vec<String> {
+    let mut teams = Vec::new();
+
+    for owner in required.owners() {
+        let Some((org, team)) = owner
+            .strip_prefix('@')
+            .and_then(|owner| owner.split_once('/'))
+        else {
+            continue;
+        };
+
+        let members = match client.teams(org).members(team).per_page(100).send().await {
+            Ok(page) => client.all_pages(page).await,
+            Err(e) => Err(e),
+        };
+        match members {
+            Ok(members) => {
+                if members.iter().any(|member| {
+                    approvers.iter().any(|approver| {
+                        approver
+                            .trim_start_matches('@')
+                            .eq_ignore_ascii_case(&member.login)
+                    })
+                }) {
+                    teams.push(owner);
+                }
+            }
+            Err(e) => warn!(
+                "Cannot read members of {}: {}",
+                owner,
+                e.to_string().lines().next().unwrap_or_default()
+            ),
+        }
+    }
+
+    teams
+}
+
+/// Renders the outstanding approvals for the terminal.
+pub fn render_human(readiness: &MergeReadiness, use_colors: bool) -> String {
+    let mut output = String::new();
+
+    if readiness.is_ready() {
+        let message = format!(
+            "Pull request #{} has all {} required code owner approval(s)",
+            readiness.number, readiness.required
+        );
+        let _ = writeln!(
+            output,
+            "{}",
+            if use_colors {
+                message.green().to_string()
+            } else {
+                message
+            }
+        );
+        return output;
+    }
+
+    let message = format!(
+        "Pull request #{} by @{} is missing {} of {} required code owner approval(s)",
+        readiness.number,
+        readiness.author,
+        readiness.outstanding.len(),
+        readiness.required
+    );
+    let _ = writeln!(
+        output,
+        "{}",
+        if use_colors {
+            message.red().to_string()
+        } else {
+            message
+        }
+    );
+
+    for outstanding in &readiness.outstanding {
+        if outstanding.owners.is_empty() {
+            let _ = writeln!(
+                output,
+                "  Only the author owns these files, so no approval counts:"
+            );
+        } else {
+            let _ = writeln!(
+                output,
+                "  Needs approval from one of {}:",
+                outstanding.owners.join(", ")
+            );
+        }
+        for file in &outstanding.files {
+            let _ = writeln!(output, "    {}", file);
+        }
+    }
+    output
+}
+
+/// Renders the merge readiness as pretty-printed JSON.
+pub fn render_json(readiness: &MergeReadiness) -> String {
+    let mut value = serde_json::to_value(readiness).expect("readiness is always serializable");
+    value["ready"] = readiness.is_ready().into();
+    serde_json::to_string_pretty(&value).expect("readiness is always serializable")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::simulate::parser_config;
+    use codeowners_validator_core::parse::parse_codeowners_with_config;
+
+    const CODEOWNERS: &str = "* @org/core\n/docs/ @org/docs @alice\n/private/ @bob\n";
+
+    fn pull_request(approvers: &[&str]) -> PullRequest {
+        PullRequest {
+            number: 7,
+            author: "bob".to_string(),
+            changed_files: ["src/lib.rs", "docs/guide.md", "private/key"]
+                .iter()
+                .map(|f| f.to_string())
+                .collect(),
+            approvers: approvers.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+
+    fn evaluate(approvers: &[&str]) -> MergeReadiness {
+        let file = parse_codeowners_with_config(CODEOWNERS, &parser_config()).ast;
+        MergeReadiness::evaluate(&file, &pull_request(approvers))
+    }
+
+    #[test]
+    fn latest_review_wins() {
+        let approvers = latest_approvers([
+            ("alice".to_string(), ReviewState::Approved),
+            ("carol".to_string(), ReviewState::Approved),
+            ("Alice".to_string(), ReviewState::Commented),
+            ("carol".to_string(), ReviewState::ChangesRequested),
+            ("dave".to_string(), ReviewState::Commented),
+        ]);
+        assert_eq!(approvers, ["@alice"]);
+    }
+
+    #[test]
+    fn outstanding_approvals() {
+        let readiness = evaluate(&["@alice"]);
+        assert_eq!(readiness.required, 3);
+        assert!(!readiness.is_ready());
+        assert_eq!(
+            readiness.outstanding,
+            [
+                Outstanding {
+                    owners: vec!["@org/core".to_string()],
+                    files: vec!["src/lib.rs".to_string()],
+                },
+                // Only the author owns private/, so no approval counts
+                Outstanding {
+                    owners: vec![],
+                    files: vec!["private/key".to_string()],
+                },
+            ]
+        );
+
+        assert_eq!(
+            render_human(&readiness, false),
+            "Pull request #7 by @bob is missing 2 of 3 required code owner approval(s)\n\
+             \x20 Needs approval from one of @org/core:\n\
+             \x20   src/lib.rs\n\
+             \x20 Only the author owns these files, so no approval counts:\n\
+             \x20   private/key\n"
+        );
+    }
+
+    #[test]
+    fn ready_to_merge() {
+        let file = parse_codeowners_with_config(CODEOWNERS, &parser_config()).ast;
+        let mut pr = pull_request(&["@org/core", "@ALICE"]);
+        pr.changed_files.pop();
+        let readiness = MergeReadiness::evaluate(&file, &pr);
+
+        assert!(readiness.is_ready());
+        assert_eq!(
+            render_human(&readiness, false),
+            "Pull request #7 has all 2 required code owner approval(s)\n"
+        );
+        let json: serde_json::Value = serde_json::from_str(&render_json(&readiness)).unwrap();
+        assert_eq!(json["ready"], true);
+        assert_eq!(json["outstanding"].as_array().unwrap().len(), 0);
+    }
+}
@@ -4,33 +4,63 @@
 //! environment variable support, matching the configuration options
 //! from the Go version of the codeowners-validator.
 
+pub mod blame;
 pub mod config;
+pub mod diagnostic;
+pub mod doctor;
+pub mod effective_owners;
+pub mod export;
+pub mod fix;
+pub mod generated;
 pub mod github;
+pub mod graph;
+pub mod merge;
 pub mod output;
+pub mod owned;
+pub mod patch;
+pub mod redact;
+pub mod render;
+pub mod review_load;
+pub mod simulate;
+pub mod targets;
+pub mod version;
 
-use clap::{Parser, ValueEnum};
+use clap::{Parser, Subcommand, ValueEnum};
+use codeowners_validator_core::matching::{PatternSyntax, QuestionMark};
+use codeowners_validator_core::validate::Severity;
+use codeowners_validator_core::validate::file_walker::{
+    FileWalkerConfig, NonUtf8Policy, SymlinkPolicy,
+};
+use secrecy::SecretString;
 use std::path::PathBuf;
+use std::time::Duration;
 
 /// CODEOWNERS file validator - validates GitHub CODEOWNERS files.
 ///
 /// Ensures the correctness of your CODEOWNERS file by running various
-/// checks against it. Supports both human-readable and JSON output formats.
+/// checks against it. Supports human-readable, JSON, SARIF, JUnit, and GitHub
+/// Actions annotation output formats, and a CSV export of ownership.
 #[derive(Parser, Debug)]
 #[command(name = "codeowners-validator")]
-#[command(version, about, long_about = None)]
+#[command(version, about, long_about = None, disable_version_flag = true)]
 pub struct Args {
+    /// Subcommand to run instead of validation.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Path to the repository root.
-    #[arg(long, env = "REPOSITORY_PATH", default_value = ".")]
+    #[arg(long, global = true, env = "REPOSITORY_PATH", default_value = ".")]
     pub repository_path: PathBuf,
 
     /// GitHub personal access token for owner validation.
     /// Required if the 'owners' check is enabled.
-    #[arg(long, env = "GITHUB_ACCESS_TOKEN")]
-    pub github_access_token: Option<String>,
+    #[arg(long, global = true, env = "GITHUB_ACCESS_TOKEN", value_parser = parse_secret, hide_env_values = true)]
+    pub github_access_token: Option<SecretString>,
 
     /// GitHub base URL for API requests (for GitHub Enterprise).
     #[arg(
         long,
+        global = true,
         env = "GITHUB_BASE_URL",
         default_value = "https://api.github.com/"
     )]
@@ -41,35 +71,49 @@ pub struct Args {
     pub github_upload_url: Option<String>,
 
     /// GitHub App ID for authentication (alternative to access token).
-    #[arg(long, env = "GITHUB_APP_ID")]
+    #[arg(long, global = true, env = "GITHUB_APP_ID")]
     pub github_app_id: Option<u64>,
 
     /// GitHub App Installation ID (required when using App authentication).
-    #[arg(long, env = "GITHUB_APP_INSTALLATION_ID")]
+    #[arg(long, global = true, env = "GITHUB_APP_INSTALLATION_ID")]
     pub github_app_installation_id: Option<u64>,
 
     /// GitHub App private key in PEM format (required when using App authentication).
-    #[arg(long, env = "GITHUB_APP_PRIVATE_KEY")]
-    pub github_app_private_key: Option<String>,
+    #[arg(long, global = true, env = "GITHUB_APP_PRIVATE_KEY", value_parser = parse_secret, hide_env_values = true)]
+    pub github_app_private_key: Option<SecretString>,
 
     /// Comma-separated list of checks to run.
     /// Possible values: files, owners, duppatterns, syntax
-    #[arg(long, env = "CHECKS", value_delimiter = ',')]
+    #[arg(long, global = true, env = "CHECKS", value_delimiter = ',')]
     pub checks: Option<Vec<CheckKind>>,
 
     /// Comma-separated list of experimental checks to run.
-    /// Possible values: notowned, avoid-shadowing
+    /// Possible values: notowned, avoid-shadowing, case-collision, generated-regions,
+    /// idp-groups, review-by, extension-override, anchoring, gitlab-sections,
+    /// maintainability, complexity, ownership-manifest, self-ownership, owners-lock,
+    /// escalation, annotations
     #[arg(long, env = "EXPERIMENTAL_CHECKS", value_delimiter = ',')]
     pub experimental_checks: Option<Vec<ExperimentalCheckKind>>,
 
     /// Failure level for validation issues.
-    /// 'warning' treats both errors and warnings as failures.
+    /// 'warning' (the default) treats both errors and warnings as failures.
     /// 'error' only treats errors as failures.
-    #[arg(long, env = "CHECK_FAILURE_LEVEL", default_value = "warning")]
-    pub check_failure_level: FailureLevel,
+    #[arg(long, env = "CHECK_FAILURE_LEVEL")]
+    pub check_failure_level: Option<FailureLevel>,
+
+    /// Path to a project configuration file. Defaults to
+    /// '.codeowners-validator.toml' at the repository root, if it exists;
+    /// flags and environment variables take precedence over it.
+    #[arg(long, env = "CONFIG_FILE")]
+    pub config: Option<PathBuf>,
+
+    /// Branch whose '[[branches]]' sections of the project configuration
+    /// file apply. Defaults to the branch checked out in the repository.
+    #[arg(long, env = "BRANCH")]
+    pub branch: Option<String>,
 
     /// Repository in 'owner/repo' format for owner validation.
-    #[arg(long, env = "OWNER_CHECKER_REPOSITORY")]
+    #[arg(long, global = true, env = "OWNER_CHECKER_REPOSITORY")]
     pub owner_checker_repository: Option<String>,
 
     /// Comma-separated list of owners to ignore during validation.
@@ -92,19 +136,397 @@ pub struct Args {
     )]
     pub owner_checker_owners_must_be_teams: bool,
 
+    /// Comma-separated user-to-team mappings (e.g. '@alice=@org/team') used to
+    /// suggest replacements when owners must be teams.
+    #[arg(long, env = "OWNER_CHECKER_TEAM_MAPPING", value_delimiter = ',')]
+    pub owner_checker_team_mapping: Option<Vec<String>>,
+
+    /// Suggest replacing a user with the one team listed in the file that
+    /// they are a member of. Costs one extra API request per listed team.
+    #[arg(
+        long,
+        env = "OWNER_CHECKER_DERIVE_TEAM_MAPPING",
+        default_value = "false"
+    )]
+    pub owner_checker_derive_team_mapping: bool,
+
+    /// Comma-separated list of trusted email domains for email owners.
+    #[arg(
+        long,
+        env = "OWNER_CHECKER_TRUSTED_EMAIL_DOMAINS",
+        value_delimiter = ','
+    )]
+    pub owner_checker_trusted_email_domains: Option<Vec<String>>,
+
+    /// Reject all email owners.
+    #[arg(
+        long,
+        env = "OWNER_CHECKER_REJECT_EMAIL_OWNERS",
+        default_value = "false"
+    )]
+    pub owner_checker_reject_email_owners: bool,
+
+    /// Report teams without members, which can never review. Costs one extra
+    /// GitHub API request per team.
+    #[arg(long, env = "OWNER_CHECKER_CHECK_EMPTY_TEAMS", default_value = "false")]
+    pub owner_checker_check_empty_teams: bool,
+
+    /// Warn about teams with fewer members than this. Costs one extra GitHub
+    /// API request per team.
+    #[arg(long, env = "OWNER_CHECKER_MIN_TEAM_SIZE")]
+    pub owner_checker_min_team_size: Option<usize>,
+
+    /// Warn about rules naming a parent team along with one of its nested
+    /// child teams. Costs one extra GitHub API request per team and
+    /// ancestor on rules with several teams.
+    #[arg(
+        long,
+        env = "OWNER_CHECKER_CHECK_TEAM_HIERARCHY",
+        default_value = "false"
+    )]
+    pub owner_checker_check_team_hierarchy: bool,
+
+    /// Maximum number of GitHub API requests the owners check may make.
+    /// Owners beyond this budget are reported as unchecked warnings.
+    #[arg(long, env = "MAX_API_REQUESTS")]
+    pub max_api_requests: Option<usize>,
+
+    /// Time budget for the whole run, in seconds. Checks that have not
+    /// started when the budget runs out, or that are still waiting on the
+    /// GitHub API, are reported as not run instead of failing the run.
+    #[arg(long, env = "TIME_BUDGET")]
+    pub time_budget: Option<u64>,
+
     /// Comma-separated patterns to skip in the not-owned checker.
     #[arg(long, env = "NOT_OWNED_CHECKER_SKIP_PATTERNS", value_delimiter = ',')]
     pub not_owned_checker_skip_patterns: Option<Vec<String>>,
 
-    /// Output validation results as JSON instead of human-readable format.
-    #[arg(long, short = 'j')]
+    /// Path to a JSON baseline of known issues. If the file does not exist,
+    /// it is created with every current issue; otherwise issues recorded in
+    /// it are ignored, so only new issues are reported.
+    #[arg(long, env = "BASELINE")]
+    pub baseline: Option<PathBuf>,
+
+    /// Path to a JSON manifest of WebAssembly plugin checks, run after the
+    /// built-in checks and reported under their own names. Plugins run
+    /// sandboxed, with no file system or network access and bounded memory
+    /// and fuel.
+    #[cfg(feature = "plugins")]
+    #[arg(long, env = "PLUGINS")]
+    pub plugins: Option<PathBuf>,
+
+    /// Comma-separated error codes (e.g. 'CO004,CO013') whose findings are
+    /// dropped from the results.
+    #[arg(long, env = "SUPPRESS_CODES", value_delimiter = ',')]
+    pub suppress_codes: Option<Vec<String>>,
+
+    /// Respect .gitignore when listing files even without a .git directory,
+    /// e.g. in exported tarballs or CI checkouts.
+    #[arg(long, env = "FORCE_RESPECT_GITIGNORE", default_value = "false")]
+    pub force_respect_gitignore: bool,
+
+    /// List the files git tracks instead of walking the file system, so
+    /// untracked build artifacts don't affect the files and notowned
+    /// checks, and dirty worktrees give the same results as clean ones.
+    #[arg(long, env = "TRACKED_FILES_ONLY", default_value = "false")]
+    pub tracked_files_only: bool,
+
+    /// Validate a git revision (e.g. 'origin/main') instead of the checkout:
+    /// the CODEOWNERS file and the files the files and notowned checks see
+    /// are read from the revision's tree, so bare repositories work too.
+    #[arg(long, env = "GIT_REV")]
+    pub git_rev: Option<String>,
+
+    /// File listing the paths a change touches, one per line (e.g. from
+    /// 'git diff --name-only'), or '-' to read them from stdin. The notowned
+    /// check then only checks those paths instead of the whole repository.
+    #[arg(long, env = "CHANGED_FILES")]
+    pub changed_files: Option<PathBuf>,
+
+    /// How file names that are not valid UTF-8 are handled: 'warn' reports
+    /// each with a CO029 warning, 'lossy' matches them with invalid bytes
+    /// replaced, 'skip' leaves them out.
+    #[arg(long, env = "NON_UTF8_PATHS", default_value = "warn")]
+    pub non_utf8_paths: NonUtf8Mode,
+
+    /// How symbolic links are handled when listing files: 'skip' leaves
+    /// them out, 'follow' lists the files behind them, 'report' lists the
+    /// links and reports each with a CO037 warning.
+    #[arg(long, env = "SYMLINKS", default_value = "skip")]
+    pub symlinks: SymlinkMode,
+
+    /// How many directories deep to walk when listing files.
+    #[arg(long, env = "MAX_WALK_DEPTH")]
+    pub max_walk_depth: Option<usize>,
+
+    /// How many files to list at most when walking the repository. Walks
+    /// that reach the limit stop, and the files and notowned checks report
+    /// the incomplete listing as CO038.
+    #[arg(long, env = "MAX_WALK_FILES")]
+    pub max_walk_files: Option<usize>,
+
+    /// How long walking the repository may take, in seconds, e.g. when it
+    /// contains a mounted network share. Walks that run out of time are
+    /// abandoned instead of hanging, and reported as CO038.
+    #[arg(long, env = "WALK_TIMEOUT")]
+    pub walk_timeout: Option<u64>,
+
+    /// Print the paths the not-owned check skips, and the hidden rule or
+    /// ignore file that excluded each of them, to stderr.
+    #[arg(long)]
+    pub explain_skips: bool,
+
+    /// Path to an identity provider group export (SCIM JSON, or CSV with a
+    /// '.csv' extension) for the idp-groups check.
+    #[arg(long, env = "IDP_EXPORT")]
+    pub idp_export: Option<PathBuf>,
+
+    /// Minimum number of members an identity provider group needs to back
+    /// an owner team.
+    #[arg(long, env = "IDP_MIN_MEMBERS", default_value = "1")]
+    pub idp_min_members: usize,
+
+    /// Path to a JSON ownership manifest declaring the teams that must own
+    /// paths, for the ownership-manifest check.
+    #[arg(long, env = "OWNERSHIP_MANIFEST")]
+    pub ownership_manifest: Option<PathBuf>,
+
+    /// Comma-separated teams of which the self-ownership check requires at
+    /// least one to own the CODEOWNERS file, e.g. '@org/platform'.
+    #[arg(long, env = "ADMIN_TEAMS", value_delimiter = ',')]
+    pub admin_teams: Option<Vec<String>>,
+
+    /// Path to a JSON owners lockfile pinning the owners of critical paths,
+    /// for the owners-lock check. Defaults to 'codeowners.lock' in the
+    /// repository, if present.
+    #[arg(long, env = "OWNERS_LOCK")]
+    pub owners_lock: Option<PathBuf>,
+
+    /// Pin the paths in the owners lockfile to their current owners and
+    /// rewrite it before validating.
+    #[arg(long)]
+    pub update_owners_lock: bool,
+
+    /// Days before a review-by date that the review-by check starts warning.
+    #[arg(long, env = "REVIEW_WARNING_DAYS", default_value = "30")]
+    pub review_warning_days: u32,
+
+    /// Number of rules sharing an owner list before the maintainability
+    /// check reports it.
+    #[arg(long, env = "REPEATED_OWNERS_THRESHOLD", default_value = "3")]
+    pub repeated_owners_threshold: usize,
+
+    /// Maximum number of rules before the complexity check warns.
+    #[arg(long, env = "MAX_RULES", default_value = "500")]
+    pub max_rules: usize,
+
+    /// Maximum number of rules in a GitLab section before the complexity
+    /// check warns.
+    #[arg(long, env = "MAX_RULES_PER_SECTION", default_value = "100")]
+    pub max_rules_per_section: usize,
+
+    /// Maximum number of lines before the complexity check warns.
+    #[arg(long, env = "MAX_FILE_LINES", default_value = "1000")]
+    pub max_file_lines: usize,
+
+    /// How '?' in patterns is interpreted by the checks.
+    #[arg(long, env = "QUESTION_MARK", default_value = "wildcard")]
+    pub question_mark: QuestionMarkMode,
+
+    /// Pattern syntax the syntax check accepts. 'extended' allows '[abc]'
+    /// character classes, which GitHub rejects but GitLab supports; 'gitlab'
+    /// also accepts '!pattern' exclusions.
+    #[arg(long, env = "PATTERN_SYNTAX", default_value = "github")]
+    pub pattern_syntax: PatternSyntaxMode,
+
+    /// Apply available fixes to the CODEOWNERS file: remove duplicate
+    /// patterns and rules matching no files, normalize owner casing, and
+    /// more.
+    #[arg(long)]
+    pub fix: bool,
+
+    /// Show the fixes '--fix' would apply as a diff, without writing the
+    /// CODEOWNERS file.
+    #[arg(long, conflicts_with = "fix")]
+    pub fix_dry_run: bool,
+
+    /// Print the parsed CODEOWNERS file as a JSON syntax tree and exit
+    /// without running any checks.
+    #[arg(long)]
+    pub emit_ast: bool,
+
+    /// Output format for validation results.
+    #[arg(long, env = "OUTPUT_FORMAT", default_value = "human", global = true)]
+    pub format: OutputFormat,
+
+    /// Output validation results as JSON (shorthand for '--format json').
+    #[arg(long, short = 'j', global = true)]
     pub json: bool,
 
+    /// Print version information and exit (with '--json', print build metadata).
+    #[arg(long, short = 'V')]
+    pub version: bool,
+
     /// Increase verbosity level (-v for debug, -vv for trace).
     #[arg(long, short = 'v', action = clap::ArgAction::Count)]
     pub verbose: u8,
 }
 
+/// Subcommands.
+#[derive(Subcommand, Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Diagnose the environment: CODEOWNERS discovery, git, configuration,
+    /// GitHub API reachability and credentials.
+    Doctor,
+    /// Report which rule would own each path, whether or not it exists yet.
+    SimulatePath {
+        /// Paths relative to the repository root.
+        #[arg(required = true)]
+        paths: Vec<String>,
+
+        /// Exit with a failure code if any path would be unowned.
+        #[arg(long)]
+        require_owner: bool,
+    },
+    /// Verify that hash-guarded generated regions were not edited by hand.
+    VerifyGenerated {
+        /// Record the current hash of each generated region instead of
+        /// verifying it.
+        #[arg(long)]
+        update_hash: bool,
+    },
+    /// Format the CODEOWNERS file: consistent spacing, comment spacing and a
+    /// trailing newline.
+    Fmt {
+        /// Exit with a failure code if the file is not formatted, instead of
+        /// rewriting it.
+        #[arg(long)]
+        check: bool,
+
+        /// Align the owners of consecutive rules into a column.
+        #[arg(long)]
+        align: bool,
+    },
+    /// List every file whose owners include the given user, team or email.
+    FilesForOwner {
+        /// The owner, e.g. '@user', '@org/team' or 'user@example.com'.
+        owner: String,
+    },
+    /// Predict whether a pull request has every required code owner approval
+    /// and list who still needs to approve (requires GitHub authentication
+    /// and '--owner-checker-repository').
+    CanMerge {
+        /// The pull request number.
+        #[arg(long)]
+        pr: u64,
+    },
+    /// Validate the CODEOWNERS file a unified diff produces, without a
+    /// checkout: the patch is applied to the base content and the offline
+    /// checks run on the result.
+    ValidatePatch {
+        /// The patch, e.g. from 'git diff', or '-' to read it from stdin.
+        patch: PathBuf,
+
+        /// File with the CODEOWNERS content before the change. Defaults to
+        /// the patched path under '--repository-path'.
+        #[arg(long)]
+        base: Option<PathBuf>,
+
+        /// Fetch the content before the change at this git ref from GitHub
+        /// (requires GitHub authentication and '--owner-checker-repository').
+        #[arg(long, conflicts_with = "base")]
+        base_ref: Option<String>,
+
+        /// Only report findings on lines the patch adds or changes.
+        #[arg(long)]
+        changed_only: bool,
+    },
+    /// Report when the rule owning a path last changed in the history of the
+    /// CODEOWNERS file, who changed it, and who owned the path before.
+    Blame {
+        /// The path, relative to the repository root.
+        path: String,
+    },
+    /// Rewrite the patterns under one directory to another, e.g. after a
+    /// directory restructure, and print the changes as a diff. Fails without
+    /// writing if a rewritten pattern matches no files.
+    MovePrefix {
+        /// The directory patterns currently start with, e.g. '/old/dir/'.
+        #[arg(long)]
+        from: String,
+
+        /// The directory to move the patterns to, e.g. '/new/dir/'.
+        #[arg(long)]
+        to: String,
+
+        /// Print the diff without writing the CODEOWNERS file.
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Generate reports about the ownership of the repository.
+    Report {
+        #[command(subcommand)]
+        report: ReportCommand,
+    },
+}
+
+/// Reports of the `report` subcommand.
+#[derive(Subcommand, Debug, Clone, PartialEq, Eq)]
+pub enum ReportCommand {
+    /// Emit a graph of owners and the directories they own files in, weighted
+    /// by file count.
+    Graph {
+        /// Number of leading path components that make up a directory node.
+        #[arg(long, default_value_t = graph::DEFAULT_DEPTH)]
+        depth: usize,
+
+        /// Graph format.
+        #[arg(long, default_value = "dot")]
+        graph_format: GraphFormat,
+    },
+    /// Export rules or resolved file ownership as CSV, for spreadsheet-based
+    /// audits.
+    Csv {
+        /// Rows of the export: one per rule, or one per file with its
+        /// resolved owners.
+        #[arg(long, default_value = "rules")]
+        rows: CsvRows,
+    },
+    /// Compare how often each owner would have been requested for review on
+    /// past merged pull requests under the current and a proposed CODEOWNERS.
+    ReviewLoad {
+        /// The proposed CODEOWNERS file.
+        #[arg(long)]
+        proposed: PathBuf,
+
+        /// JSON history of pull requests to replay: an array of objects with
+        /// 'number', 'author' and 'files'. Without it, recent merged pull
+        /// requests are fetched from GitHub.
+        #[arg(long)]
+        history: Option<PathBuf>,
+
+        /// Number of recent merged pull requests to fetch from GitHub.
+        #[arg(long, default_value_t = review_load::DEFAULT_MERGED_PRS, conflicts_with = "history")]
+        merged_prs: usize,
+    },
+    /// List the individuals effectively responsible for each directory, with
+    /// team owners expanded into their current members (requires GitHub
+    /// authentication).
+    EffectiveOwners {
+        /// Number of leading path components that make up a directory.
+        #[arg(long, default_value_t = graph::DEFAULT_DEPTH)]
+        depth: usize,
+    },
+    /// Report the owners of each build target, and targets whose sources
+    /// span multiple owner domains.
+    Targets {
+        /// JSON object mapping build target labels (e.g. from a Bazel or
+        /// Buck query) to the paths of their source files.
+        #[arg(long)]
+        targets_file: PathBuf,
+    },
+}
+
 /// Standard validation checks.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 #[value(rename_all = "lowercase")]
@@ -124,6 +546,16 @@ impl CheckKind {
     pub fn all() -> Vec<Self> {
         vec![Self::Files, Self::Owners, Self::Duppatterns, Self::Syntax]
     }
+
+    /// Returns the name results are reported under.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Files => "files",
+            Self::Owners => "owners",
+            Self::Duppatterns => "duppatterns",
+            Self::Syntax => "syntax",
+        }
+    }
 }
 
 /// Experimental validation checks.
@@ -134,6 +566,206 @@ pub enum ExperimentalCheckKind {
     Notowned,
     /// Check for patterns that shadow earlier patterns.
     AvoidShadowing,
+    /// Check for patterns that differ only in letter case.
+    CaseCollision,
+    /// Check for hand edits to generated regions.
+    GeneratedRegions,
+    /// Check team owners against an identity provider group export.
+    IdpGroups,
+    /// Check for rules past or near their review-by date.
+    ReviewBy,
+    /// Check for extension rules overridden by later directory rules.
+    ExtensionOverride,
+    /// Check for unanchored directory patterns that also match nested directories.
+    Anchoring,
+    /// Check GitLab section approval counts and default owners.
+    GitlabSections,
+    /// Check for owner lists repeated across many rules.
+    Maintainability,
+    /// Check the file against rule and line count budgets.
+    Complexity,
+    /// Check that paths are owned by exactly the teams an ownership manifest declares.
+    OwnershipManifest,
+    /// Check that the CODEOWNERS file itself is owned, optionally by an admin team.
+    SelfOwnership,
+    /// Check that the owners of paths pinned in an owners lockfile did not change.
+    OwnersLock,
+    /// Check that escalation annotations name a 'provider:policy'.
+    Escalation,
+    /// Check the spelling and placement of 'codeowners:' directive annotations.
+    Annotations,
+}
+
+impl ExperimentalCheckKind {
+    /// Returns the name results are reported under.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Notowned => "notowned",
+            Self::AvoidShadowing => "avoid-shadowing",
+            Self::CaseCollision => "case-collision",
+            Self::GeneratedRegions => "generated-regions",
+            Self::IdpGroups => "idp-groups",
+            Self::ReviewBy => "review-by",
+            Self::ExtensionOverride => "extension-override",
+            Self::Anchoring => "anchoring",
+            Self::GitlabSections => "gitlab-sections",
+            Self::Maintainability => "maintainability",
+            Self::Complexity => "complexity",
+            Self::OwnershipManifest => "ownership-manifest",
+            Self::SelfOwnership => "self-ownership",
+            Self::OwnersLock => "owners-lock",
+            Self::Escalation => "escalation",
+            Self::Annotations => "annotations",
+        }
+    }
+}
+
+/// Interpretation of `?` in CODEOWNERS patterns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+#[value(rename_all = "lowercase")]
+pub enum QuestionMarkMode {
+    /// Match any single character, as gitignore does.
+    #[default]
+    Wildcard,
+    /// Match a literal '?' character.
+    Literal,
+    /// Match as a wildcard, but warn about every use.
+    Unsupported,
+}
+
+impl From<QuestionMarkMode> for QuestionMark {
+    fn from(mode: QuestionMarkMode) -> Self {
+        match mode {
+            QuestionMarkMode::Wildcard => Self::Wildcard,
+            QuestionMarkMode::Literal => Self::Literal,
+            QuestionMarkMode::Unsupported => Self::Unsupported,
+        }
+    }
+}
+
+/// Handling of file names that are not valid UTF-8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+#[value(rename_all = "lowercase")]
+pub enum NonUtf8Mode {
+    /// List them, and report each with a warning instead of checking it.
+    #[default]
+    Warn,
+    /// List them with invalid bytes replaced, and check them.
+    Lossy,
+    /// Leave them out.
+    Skip,
+}
+
+impl From<NonUtf8Mode> for NonUtf8Policy {
+    fn from(mode: NonUtf8Mode) -> Self {
+        match mode {
+            NonUtf8Mode::Warn => Self::Warn,
+            NonUtf8Mode::Lossy => Self::Lossy,
+            NonUtf8Mode::Skip => Self::Skip,
+        }
+    }
+}
+
+/// Handling of symbolic links.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+#[value(rename_all = "lowercase")]
+pub enum SymlinkMode {
+    /// Leave them out.
+    #[default]
+    Skip,
+    /// Follow them and list the files behind them.
+    Follow,
+    /// List the links, and report each with a warning instead of checking it.
+    Report,
+}
+
+impl From<SymlinkMode> for SymlinkPolicy {
+    fn from(mode: SymlinkMode) -> Self {
+        match mode {
+            SymlinkMode::Skip => Self::Skip,
+            SymlinkMode::Follow => Self::Follow,
+            SymlinkMode::Report => Self::Report,
+        }
+    }
+}
+
+/// Pattern syntax accepted by the syntax check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+#[value(rename_all = "lowercase")]
+pub enum PatternSyntaxMode {
+    /// The syntax GitHub supports.
+    #[default]
+    Github,
+    /// GitHub's syntax plus '[...]' character classes.
+    Extended,
+    /// Extended syntax plus '!pattern' exclusions, as in GitLab.
+    Gitlab,
+}
+
+impl From<PatternSyntaxMode> for PatternSyntax {
+    fn from(mode: PatternSyntaxMode) -> Self {
+        match mode {
+            PatternSyntaxMode::Github => Self::GitHub,
+            PatternSyntaxMode::Extended => Self::Extended,
+            PatternSyntaxMode::Gitlab => Self::GitLab,
+        }
+    }
+}
+
+/// Output format for validation results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+#[value(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// Human-readable console output.
+    #[default]
+    Human,
+    /// JSON grouped by check.
+    Json,
+    /// Newline-delimited JSON: one object per issue, written as soon as
+    /// each check finishes, then a summary.
+    Ndjson,
+    /// SARIF 2.1.0 log for code scanning tools.
+    Sarif,
+    /// JUnit XML report for CI test dashboards.
+    Junit,
+    /// Checkstyle XML for review bots such as reviewdog and Danger.
+    Checkstyle,
+    /// TAP (Test Anything Protocol) stream for `prove` and other TAP harnesses.
+    Tap,
+    /// GitHub Actions workflow annotations.
+    Github,
+    /// CSV with one finding per row, for spreadsheets.
+    Csv,
+}
+
+impl OutputFormat {
+    /// Returns true for formats meant to be consumed by other tools,
+    /// where logs and colors would corrupt the output.
+    pub fn is_machine_readable(self) -> bool {
+        self != Self::Human
+    }
+}
+
+/// Format of the ownership graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+#[value(rename_all = "lowercase")]
+pub enum GraphFormat {
+    /// Graphviz DOT.
+    #[default]
+    Dot,
+    /// GraphML, for tools such as Gephi and yEd.
+    Graphml,
+}
+
+/// Rows of the CSV ownership export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+#[value(rename_all = "lowercase")]
+pub enum CsvRows {
+    /// One row per rule with its matched and effective file counts.
+    #[default]
+    Rules,
+    /// One row per file with the owners it resolves to.
+    Files,
 }
 
 /// Failure level for validation issues.
@@ -147,6 +779,20 @@ pub enum FailureLevel {
     Error,
 }
 
+impl From<Severity> for FailureLevel {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::Warning => Self::Warning,
+            Severity::Error => Self::Error,
+        }
+    }
+}
+
+/// Parses a credential argument so it is never printed by `Debug`.
+fn parse_secret(value: &str) -> Result<SecretString, std::convert::Infallible> {
+    Ok(SecretString::from(value))
+}
+
 impl Args {
     /// Returns the checks to run, defaulting to all standard checks.
     pub fn effective_checks(&self) -> Vec<CheckKind> {
@@ -158,6 +804,23 @@ impl Args {
         self.experimental_checks.clone().unwrap_or_default()
     }
 
+    /// Applies the walk limits to a file walker configuration.
+    pub fn walk_limits(&self, walker: FileWalkerConfig) -> FileWalkerConfig {
+        walker
+            .with_max_depth(self.max_walk_depth)
+            .with_max_files(self.max_walk_files)
+            .with_timeout(self.walk_timeout.map(Duration::from_secs))
+    }
+
+    /// Returns the output format, honoring the `--json` shorthand.
+    pub fn effective_format(&self) -> OutputFormat {
+        if self.json {
+            OutputFormat::Json
+        } else {
+            self.format
+        }
+    }
+
     /// Returns true if a specific check should be run.
     #[cfg(test)]
     pub fn should_run_check(&self, check: CheckKind) -> bool {
@@ -209,24 +872,65 @@ mod tests {
         let args = Args::parse_from([
             "codeowners-validator",
             "--experimental-checks",
-            "notowned,avoid-shadowing",
+            "notowned,avoid-shadowing,case-collision,generated-regions,idp-groups,review-by,extension-override,anchoring,gitlab-sections,maintainability,complexity,ownership-manifest,self-ownership,owners-lock,escalation,annotations",
         ]);
         let checks = args.effective_experimental_checks();
-        assert_eq!(checks.len(), 2);
+        assert_eq!(checks.len(), 16);
         assert!(checks.contains(&ExperimentalCheckKind::Notowned));
         assert!(checks.contains(&ExperimentalCheckKind::AvoidShadowing));
+        assert!(checks.contains(&ExperimentalCheckKind::CaseCollision));
+        assert!(checks.contains(&ExperimentalCheckKind::GeneratedRegions));
+        assert!(checks.contains(&ExperimentalCheckKind::IdpGroups));
+        assert!(checks.contains(&ExperimentalCheckKind::ReviewBy));
+        assert!(checks.contains(&ExperimentalCheckKind::ExtensionOverride));
+        assert!(checks.contains(&ExperimentalCheckKind::Anchoring));
+        assert!(checks.contains(&ExperimentalCheckKind::GitlabSections));
+        assert!(checks.contains(&ExperimentalCheckKind::Maintainability));
+        assert!(checks.contains(&ExperimentalCheckKind::Complexity));
+        assert!(checks.contains(&ExperimentalCheckKind::OwnershipManifest));
+        assert!(checks.contains(&ExperimentalCheckKind::SelfOwnership));
+        assert!(checks.contains(&ExperimentalCheckKind::OwnersLock));
+        assert!(checks.contains(&ExperimentalCheckKind::Escalation));
+        assert!(checks.contains(&ExperimentalCheckKind::Annotations));
+    }
+
+    #[test]
+    fn test_admin_teams() {
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "--admin-teams",
+            "@org/platform,@org/security",
+        ]);
+        assert_eq!(
+            args.admin_teams,
+            Some(vec![
+                "@org/platform".to_string(),
+                "@org/security".to_string()
+            ])
+        );
     }
 
     #[test]
     fn test_default_failure_level() {
         let args = Args::parse_from(["codeowners-validator"]);
-        assert_eq!(args.check_failure_level, FailureLevel::Warning);
+        assert_eq!(args.check_failure_level, None);
+        assert!(args.config.is_none());
     }
 
     #[test]
     fn test_error_failure_level() {
         let args = Args::parse_from(["codeowners-validator", "--check-failure-level", "error"]);
-        assert_eq!(args.check_failure_level, FailureLevel::Error);
+        assert_eq!(args.check_failure_level, Some(FailureLevel::Error));
+    }
+
+    #[test]
+    fn test_config_file() {
+        let args = Args::parse_from(["codeowners-validator", "--config", "ci/validator.toml"]);
+        assert_eq!(args.config, Some(PathBuf::from("ci/validator.toml")));
+        assert_eq!(FailureLevel::from(Severity::Error), FailureLevel::Error);
+
+        let args = Args::parse_from(["codeowners-validator", "--branch", "release/1.0"]);
+        assert_eq!(args.branch.as_deref(), Some("release/1.0"));
     }
 
     #[test]
@@ -238,6 +942,291 @@ mod tests {
         assert!(args.json);
     }
 
+    #[test]
+    fn test_emit_ast_flag() {
+        let args = Args::parse_from(["codeowners-validator"]);
+        assert!(!args.emit_ast);
+
+        let args = Args::parse_from(["codeowners-validator", "--emit-ast"]);
+        assert!(args.emit_ast);
+    }
+
+    #[test]
+    fn test_doctor_subcommand() {
+        let args = Args::parse_from(["codeowners-validator"]);
+        assert_eq!(args.command, None);
+
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "doctor",
+            "--repository-path",
+            "/repo",
+            "--github-base-url",
+            "https://ghe.example.com/api/v3",
+        ]);
+        assert_eq!(args.command, Some(Command::Doctor));
+        assert_eq!(args.repository_path, PathBuf::from("/repo"));
+        assert_eq!(args.github_base_url, "https://ghe.example.com/api/v3");
+    }
+
+    #[test]
+    fn test_simulate_path_subcommand() {
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "simulate-path",
+            "services/new/main.go",
+            "docs/",
+            "--require-owner",
+        ]);
+        assert_eq!(
+            args.command,
+            Some(Command::SimulatePath {
+                paths: vec!["services/new/main.go".to_string(), "docs/".to_string()],
+                require_owner: true,
+            })
+        );
+
+        assert!(Args::try_parse_from(["codeowners-validator", "simulate-path"]).is_err());
+    }
+
+    #[test]
+    fn test_verify_generated_subcommand() {
+        let args = Args::parse_from(["codeowners-validator", "verify-generated", "--update-hash"]);
+        assert_eq!(
+            args.command,
+            Some(Command::VerifyGenerated { update_hash: true })
+        );
+    }
+
+    #[test]
+    fn test_fmt_subcommand() {
+        let args = Args::parse_from(["codeowners-validator", "fmt"]);
+        assert_eq!(
+            args.command,
+            Some(Command::Fmt {
+                check: false,
+                align: false
+            })
+        );
+
+        let args = Args::parse_from(["codeowners-validator", "fmt", "--check", "--align"]);
+        assert_eq!(
+            args.command,
+            Some(Command::Fmt {
+                check: true,
+                align: true
+            })
+        );
+    }
+
+    #[test]
+    fn test_files_for_owner_subcommand() {
+        let args = Args::parse_from(["codeowners-validator", "files-for-owner", "@org/team"]);
+        assert_eq!(
+            args.command,
+            Some(Command::FilesForOwner {
+                owner: "@org/team".to_string()
+            })
+        );
+
+        assert!(Args::try_parse_from(["codeowners-validator", "files-for-owner"]).is_err());
+    }
+
+    #[test]
+    fn test_blame_subcommand() {
+        let args = Args::parse_from(["codeowners-validator", "blame", "src/lib.rs"]);
+        assert_eq!(
+            args.command,
+            Some(Command::Blame {
+                path: "src/lib.rs".to_string()
+            })
+        );
+
+        assert!(Args::try_parse_from(["codeowners-validator", "blame"]).is_err());
+    }
+
+    #[test]
+    fn test_move_prefix_subcommand() {
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "move-prefix",
+            "--from",
+            "/old/dir/",
+            "--to",
+            "/new/dir/",
+            "--dry-run",
+        ]);
+        assert_eq!(
+            args.command,
+            Some(Command::MovePrefix {
+                from: "/old/dir/".to_string(),
+                to: "/new/dir/".to_string(),
+                dry_run: true,
+            })
+        );
+
+        assert!(
+            Args::try_parse_from(["codeowners-validator", "move-prefix", "--from", "/old/"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_patch_subcommand() {
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "validate-patch",
+            "-",
+            "--base-ref",
+            "main",
+            "--changed-only",
+        ]);
+        assert_eq!(
+            args.command,
+            Some(Command::ValidatePatch {
+                patch: PathBuf::from("-"),
+                base: None,
+                base_ref: Some("main".to_string()),
+                changed_only: true,
+            })
+        );
+
+        assert!(
+            Args::try_parse_from([
+                "codeowners-validator",
+                "validate-patch",
+                "pr.diff",
+                "--base",
+                "CODEOWNERS",
+                "--base-ref",
+                "main",
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_version_flag() {
+        let args = Args::parse_from(["codeowners-validator", "--version", "--json"]);
+        assert!(args.version);
+        assert_eq!(args.effective_format(), OutputFormat::Json);
+
+        let args = Args::parse_from(["codeowners-validator", "-V"]);
+        assert!(args.version);
+    }
+
+    #[test]
+    fn test_output_format() {
+        let args = Args::parse_from(["codeowners-validator"]);
+        assert_eq!(args.effective_format(), OutputFormat::Human);
+
+        let args = Args::parse_from(["codeowners-validator", "--format", "sarif"]);
+        assert_eq!(args.effective_format(), OutputFormat::Sarif);
+
+        let args = Args::parse_from(["codeowners-validator", "--format", "junit", "--json"]);
+        assert_eq!(args.effective_format(), OutputFormat::Json);
+
+        let args = Args::parse_from(["codeowners-validator", "report", "graph"]);
+        assert_eq!(
+            args.command,
+            Some(Command::Report {
+                report: ReportCommand::Graph {
+                    depth: 2,
+                    graph_format: GraphFormat::Dot,
+                }
+            })
+        );
+
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "report",
+            "graph",
+            "--graph-format",
+            "graphml",
+        ]);
+        assert_eq!(
+            args.command,
+            Some(Command::Report {
+                report: ReportCommand::Graph {
+                    depth: 2,
+                    graph_format: GraphFormat::Graphml,
+                }
+            })
+        );
+        assert!(Args::try_parse_from(["codeowners-validator", "--format", "dot"]).is_err());
+
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "report",
+            "review-load",
+            "--proposed",
+            "CODEOWNERS.new",
+            "--history",
+            "prs.json",
+        ]);
+        assert_eq!(
+            args.command,
+            Some(Command::Report {
+                report: ReportCommand::ReviewLoad {
+                    proposed: PathBuf::from("CODEOWNERS.new"),
+                    history: Some(PathBuf::from("prs.json")),
+                    merged_prs: 100,
+                }
+            })
+        );
+
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "report",
+            "targets",
+            "--targets-file",
+            "targets.json",
+        ]);
+        assert_eq!(
+            args.command,
+            Some(Command::Report {
+                report: ReportCommand::Targets {
+                    targets_file: PathBuf::from("targets.json"),
+                }
+            })
+        );
+
+        let args = Args::parse_from(["codeowners-validator", "--format", "csv"]);
+        assert_eq!(args.effective_format(), OutputFormat::Csv);
+        assert_eq!(args.command, None);
+
+        let args = Args::parse_from(["codeowners-validator", "report", "csv", "--rows", "files"]);
+        assert_eq!(
+            args.command,
+            Some(Command::Report {
+                report: ReportCommand::Csv {
+                    rows: CsvRows::Files
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_pattern_syntax() {
+        let args = Args::parse_from(["codeowners-validator"]);
+        assert_eq!(
+            PatternSyntax::from(args.pattern_syntax),
+            PatternSyntax::GitHub
+        );
+
+        let args = Args::parse_from(["codeowners-validator", "--pattern-syntax", "extended"]);
+        assert_eq!(
+            PatternSyntax::from(args.pattern_syntax),
+            PatternSyntax::Extended
+        );
+
+        let args = Args::parse_from(["codeowners-validator", "--pattern-syntax", "gitlab"]);
+        assert_eq!(
+            PatternSyntax::from(args.pattern_syntax),
+            PatternSyntax::GitLab
+        );
+    }
+
     #[test]
     fn test_verbose_flag() {
         let args = Args::parse_from(["codeowners-validator"]);
@@ -282,6 +1271,158 @@ mod tests {
         assert!(ignored.contains(&"@bot".to_string()));
     }
 
+    #[test]
+    fn test_trusted_email_domains() {
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "--owner-checker-trusted-email-domains",
+            "example.com,corp.example.com",
+        ]);
+        let domains = args.owner_checker_trusted_email_domains.unwrap();
+        assert_eq!(domains, vec!["example.com", "corp.example.com"]);
+        assert!(!args.owner_checker_reject_email_owners);
+    }
+
+    #[test]
+    fn test_fix_flags() {
+        let args = Args::parse_from(["codeowners-validator", "--fix-dry-run"]);
+        assert!(args.fix_dry_run);
+        assert!(!args.fix);
+
+        let result = Args::try_parse_from(["codeowners-validator", "--fix", "--fix-dry-run"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_time_budget() {
+        let args = Args::parse_from(["codeowners-validator"]);
+        assert_eq!(args.time_budget, None);
+
+        let args = Args::parse_from(["codeowners-validator", "--time-budget", "30"]);
+        assert_eq!(args.time_budget, Some(30));
+    }
+
+    #[test]
+    fn test_baseline() {
+        let args = Args::parse_from(["codeowners-validator"]);
+        assert_eq!(args.baseline, None);
+
+        let args = Args::parse_from(["codeowners-validator", "--baseline", "baseline.json"]);
+        assert_eq!(args.baseline, Some(PathBuf::from("baseline.json")));
+    }
+
+    #[test]
+    fn test_force_respect_gitignore() {
+        let args = Args::parse_from(["codeowners-validator"]);
+        assert!(!args.force_respect_gitignore);
+
+        let args = Args::parse_from(["codeowners-validator", "--force-respect-gitignore"]);
+        assert!(args.force_respect_gitignore);
+    }
+
+    #[test]
+    fn test_tracked_files_only() {
+        let args = Args::parse_from(["codeowners-validator"]);
+        assert!(!args.tracked_files_only);
+
+        let args = Args::parse_from(["codeowners-validator", "--tracked-files-only"]);
+        assert!(args.tracked_files_only);
+
+        let args = Args::parse_from(["codeowners-validator", "--git-rev", "origin/main"]);
+        assert_eq!(args.git_rev.as_deref(), Some("origin/main"));
+
+        let args = Args::parse_from(["codeowners-validator", "--changed-files", "-"]);
+        assert_eq!(args.changed_files, Some(PathBuf::from("-")));
+        assert!(!args.explain_skips);
+
+        let args = Args::parse_from(["codeowners-validator", "--explain-skips"]);
+        assert!(args.explain_skips);
+    }
+
+    #[test]
+    fn test_non_utf8_paths() {
+        let args = Args::parse_from(["codeowners-validator"]);
+        assert_eq!(args.non_utf8_paths, NonUtf8Mode::Warn);
+
+        let args = Args::parse_from(["codeowners-validator", "--non-utf8-paths", "skip"]);
+        assert_eq!(args.non_utf8_paths, NonUtf8Mode::Skip);
+        assert_eq!(
+            NonUtf8Policy::from(args.non_utf8_paths),
+            NonUtf8Policy::Skip
+        );
+    }
+
+    #[test]
+    fn test_symlinks() {
+        let args = Args::parse_from(["codeowners-validator"]);
+        assert_eq!(args.symlinks, SymlinkMode::Skip);
+
+        let args = Args::parse_from(["codeowners-validator", "--symlinks", "follow"]);
+        assert_eq!(SymlinkPolicy::from(args.symlinks), SymlinkPolicy::Follow);
+    }
+
+    #[test]
+    fn test_walk_limits() {
+        let args = Args::parse_from(["codeowners-validator"]);
+        assert_eq!(
+            args.walk_limits(FileWalkerConfig::new()),
+            FileWalkerConfig::new()
+        );
+
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "--max-walk-depth",
+            "8",
+            "--max-walk-files",
+            "100000",
+            "--walk-timeout",
+            "60",
+        ]);
+        let walker = args.walk_limits(FileWalkerConfig::new());
+        assert_eq!(walker.max_depth, Some(8));
+        assert_eq!(walker.max_files, Some(100_000));
+        assert_eq!(walker.timeout, Some(Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn test_check_empty_teams() {
+        let args = Args::parse_from(["codeowners-validator"]);
+        assert!(!args.owner_checker_check_empty_teams);
+
+        let args = Args::parse_from(["codeowners-validator", "--owner-checker-check-empty-teams"]);
+        assert!(args.owner_checker_check_empty_teams);
+    }
+
+    #[test]
+    fn test_min_team_size() {
+        let args = Args::parse_from(["codeowners-validator"]);
+        assert_eq!(args.owner_checker_min_team_size, None);
+
+        let args = Args::parse_from(["codeowners-validator", "--owner-checker-min-team-size", "3"]);
+        assert_eq!(args.owner_checker_min_team_size, Some(3));
+    }
+
+    #[test]
+    fn test_check_team_hierarchy() {
+        let args = Args::parse_from(["codeowners-validator"]);
+        assert!(!args.owner_checker_check_team_hierarchy);
+
+        let args = Args::parse_from([
+            "codeowners-validator",
+            "--owner-checker-check-team-hierarchy",
+        ]);
+        assert!(args.owner_checker_check_team_hierarchy);
+    }
+
+    #[test]
+    fn test_max_api_requests() {
+        let args = Args::parse_from(["codeowners-validator"]);
+        assert_eq!(args.max_api_requests, None);
+
+        let args = Args::parse_from(["codeowners-validator", "--max-api-requests", "50"]);
+        assert_eq!(args.max_api_requests, Some(50));
+    }
+
     #[test]
     fn test_should_run_check() {
         let args = Args::parse_from(["codeowners-validator", "--checks", "syntax"]);
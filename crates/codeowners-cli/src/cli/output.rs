@@ -1,13 +1,66 @@
 //! Output formatting for the CLI.
 //!
-//! This module provides human-readable and JSON output formatters for validation results.
-
-use codeowners_validator_core::validate::{Severity, ValidationError, ValidationResult};
+//! This module provides human-readable, JSON and NDJSON output formatters for
+//! validation results.
+
+use super::diagnostic::{Source, render_snippet};
+use codeowners_validator_core::parse::CodeownersFile;
+use codeowners_validator_core::validate::baseline::{Baseline, BaselineIssue};
+use codeowners_validator_core::validate::checks::ComplexityStats;
+use codeowners_validator_core::validate::{Fix, Severity, ValidationError, ValidationResult};
 use colored::Colorize;
 use serde::Serialize;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::io::Write;
 
+/// Reason recorded for checks skipped because the time budget ran out.
+pub const BUDGET_EXCEEDED: &str = "budget exceeded";
+
+/// The outcome of a check.
+///
+/// A check that could not finish, such as the owners check when the GitHub
+/// API is unreachable, is errored rather than passed, even if it produced
+/// no findings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum CheckStatus {
+    /// The check ran and found nothing.
+    Passed,
+    /// The check ran and reported findings.
+    Failed,
+    /// The check did not run.
+    Skipped {
+        /// Why the check did not run.
+        reason: String,
+    },
+    /// The check did not finish, so its findings may be incomplete.
+    Errored {
+        /// What went wrong.
+        error: String,
+    },
+}
+
+impl CheckStatus {
+    /// Returns the status name, e.g. `skipped`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Passed => "passed",
+            Self::Failed => "failed",
+            Self::Skipped { .. } => "skipped",
+            Self::Errored { .. } => "errored",
+        }
+    }
+
+    /// Describes the status, e.g. `not run (budget exceeded)`.
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Skipped { reason } => format!("not run ({})", reason),
+            Self::Errored { error } => format!("errored: {}", error),
+            status => status.name().to_string(),
+        }
+    }
+}
+
 /// JSON output format matching the Go version.
 #[derive(Debug, Serialize)]
 pub struct JsonOutput {
@@ -24,6 +77,57 @@ pub struct JsonOutput {
     /// Avoid-shadowing check results (experimental).
     #[serde(rename = "avoid-shadowing")]
     pub avoid_shadowing: Vec<JsonIssue>,
+    /// Case-collision check results (experimental).
+    #[serde(rename = "case-collision")]
+    pub case_collision: Vec<JsonIssue>,
+    /// Generated-regions check results (experimental).
+    #[serde(rename = "generated-regions")]
+    pub generated_regions: Vec<JsonIssue>,
+    /// IdP-groups check results (experimental).
+    #[serde(rename = "idp-groups")]
+    pub idp_groups: Vec<JsonIssue>,
+    /// Review-by check results (experimental).
+    #[serde(rename = "review-by")]
+    pub review_by: Vec<JsonIssue>,
+    /// Extension-override check results (experimental).
+    #[serde(rename = "extension-override")]
+    pub extension_override: Vec<JsonIssue>,
+    /// Anchoring check results (experimental).
+    pub anchoring: Vec<JsonIssue>,
+    /// GitLab-sections check results (experimental).
+    #[serde(rename = "gitlab-sections")]
+    pub gitlab_sections: Vec<JsonIssue>,
+    /// Maintainability check results (experimental).
+    pub maintainability: Vec<JsonIssue>,
+    /// Complexity check results (experimental).
+    pub complexity: Vec<JsonIssue>,
+    /// Ownership-manifest check results (experimental).
+    #[serde(rename = "ownership-manifest")]
+    pub ownership_manifest: Vec<JsonIssue>,
+    /// Self-ownership check results (experimental).
+    #[serde(rename = "self-ownership")]
+    pub self_ownership: Vec<JsonIssue>,
+    /// Owners-lock check results (experimental).
+    #[serde(rename = "owners-lock")]
+    pub owners_lock: Vec<JsonIssue>,
+    /// Escalation check results (experimental).
+    pub escalation: Vec<JsonIssue>,
+    /// Annotations check results (experimental).
+    pub annotations: Vec<JsonIssue>,
+    /// Results of checks without a field of their own, such as custom
+    /// checks, by check name.
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Vec<JsonIssue>>,
+    /// Size counts of the file, when the complexity check ran.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<ComplexityStats>,
+    /// Number of known issues ignored because of the baseline, when one is
+    /// used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub baselined: Option<usize>,
+    /// Status of each check that was requested.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub checks: BTreeMap<String, CheckStatus>,
 }
 
 impl Default for JsonOutput {
@@ -42,12 +146,38 @@ impl JsonOutput {
             owners: Vec::new(),
             notowned: Vec::new(),
             avoid_shadowing: Vec::new(),
+            case_collision: Vec::new(),
+            generated_regions: Vec::new(),
+            idp_groups: Vec::new(),
+            review_by: Vec::new(),
+            extension_override: Vec::new(),
+            anchoring: Vec::new(),
+            gitlab_sections: Vec::new(),
+            maintainability: Vec::new(),
+            complexity: Vec::new(),
+            ownership_manifest: Vec::new(),
+            self_ownership: Vec::new(),
+            owners_lock: Vec::new(),
+            escalation: Vec::new(),
+            annotations: Vec::new(),
+            other: BTreeMap::new(),
+            stats: None,
+            baselined: None,
+            checks: BTreeMap::new(),
         }
     }
 
     /// Adds issues from a validation result to the appropriate check category.
-    pub fn add_check_results(&mut self, check_name: &str, result: &ValidationResult) {
-        let issues: Vec<JsonIssue> = result.errors.iter().map(JsonIssue::from).collect();
+    ///
+    /// Issues are fingerprinted when the CODEOWNERS `file` they are about is
+    /// given.
+    pub fn add_check_results(
+        &mut self,
+        check_name: &str,
+        result: &ValidationResult,
+        file: Option<&CodeownersFile>,
+    ) {
+        let issues = JsonIssue::from_result(check_name, result, file);
 
         match check_name {
             "syntax" => self.syntax.extend(issues),
@@ -56,7 +186,25 @@ impl JsonOutput {
             "owners" => self.owners.extend(issues),
             "notowned" => self.notowned.extend(issues),
             "avoid-shadowing" | "shadowing" => self.avoid_shadowing.extend(issues),
-            _ => {} // Unknown check name, ignore
+            "case-collision" => self.case_collision.extend(issues),
+            "generated-regions" => self.generated_regions.extend(issues),
+            "idp-groups" => self.idp_groups.extend(issues),
+            "review-by" => self.review_by.extend(issues),
+            "extension-override" => self.extension_override.extend(issues),
+            "anchoring" => self.anchoring.extend(issues),
+            "gitlab-sections" => self.gitlab_sections.extend(issues),
+            "maintainability" => self.maintainability.extend(issues),
+            "complexity" => self.complexity.extend(issues),
+            "ownership-manifest" => self.ownership_manifest.extend(issues),
+            "self-ownership" => self.self_ownership.extend(issues),
+            "owners-lock" => self.owners_lock.extend(issues),
+            "escalation" => self.escalation.extend(issues),
+            "annotations" => self.annotations.extend(issues),
+            name => self
+                .other
+                .entry(name.to_string())
+                .or_default()
+                .extend(issues),
         }
     }
 
@@ -70,38 +218,129 @@ impl JsonOutput {
 /// A single issue in JSON format.
 #[derive(Debug, Serialize)]
 pub struct JsonIssue {
+    /// Stable code of the issue, e.g. `CO004`.
+    pub code: &'static str,
     /// Line number where the issue occurred.
     pub line: usize,
     /// Column number where the issue occurred.
     pub column: usize,
+    /// Line number where the issue ends.
+    pub end_line: usize,
+    /// Column number just past the end of the issue.
+    pub end_column: usize,
     /// Human-readable message.
     pub message: String,
     /// Severity of the issue.
     pub severity: Severity,
+    /// Fingerprint of the issue that does not depend on line numbers, when
+    /// the CODEOWNERS file is known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+}
+
+impl JsonIssue {
+    /// Converts the issues of a check's result, with their effective
+    /// severity, fingerprinted when the CODEOWNERS `file` is given.
+    fn from_result(
+        check_name: &str,
+        result: &ValidationResult,
+        file: Option<&CodeownersFile>,
+    ) -> Vec<Self> {
+        result
+            .errors
+            .iter()
+            .map(|error| Self {
+                severity: result.severity_of(error),
+                fingerprint: file
+                    .map(|file| BaselineIssue::new(check_name, error, file).fingerprint()),
+                ..Self::from(error)
+            })
+            .collect()
+    }
 }
 
 impl From<&ValidationError> for JsonIssue {
     fn from(error: &ValidationError) -> Self {
         let span = error.span();
         Self {
+            code: error.code(),
             line: span.line,
             column: span.column,
+            end_line: span.end_line,
+            end_column: span.end_column,
             message: error.to_string(),
             severity: error.severity(),
+            fingerprint: None,
         }
     }
 }
 
+/// A line of NDJSON output.
+///
+/// Each check's issues are followed by a record with its status, and the
+/// output ends with a summary, so consumers can tell a finished run from an
+/// interrupted one.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum NdjsonRecord<'a> {
+    /// An issue reported by a check.
+    Issue {
+        /// The check that reported the issue.
+        check: &'a str,
+        /// The issue.
+        #[serde(flatten)]
+        issue: JsonIssue,
+    },
+    /// The outcome of a check, after its issues.
+    Check {
+        /// The check.
+        check: &'a str,
+        /// Its outcome.
+        #[serde(flatten)]
+        status: CheckStatus,
+    },
+    /// Totals over all checks, last.
+    Summary {
+        /// Number of errors.
+        errors: usize,
+        /// Number of warnings.
+        warnings: usize,
+        /// Number of known issues a baseline left out, if one was used.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        baselined: Option<usize>,
+    },
+}
+
+impl NdjsonRecord<'_> {
+    /// Writes the record as one line of JSON.
+    pub fn write<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        serde_json::to_writer(&mut *writer, self)?;
+        writeln!(writer)
+    }
+}
+
 /// Output formatter for human-readable console output.
 pub struct HumanOutput<W: Write> {
     writer: W,
     use_colors: bool,
+    source: Option<Source>,
 }
 
 impl<W: Write> HumanOutput<W> {
     /// Creates a new human output formatter.
     pub fn new(writer: W, use_colors: bool) -> Self {
-        Self { writer, use_colors }
+        Self {
+            writer,
+            use_colors,
+            source: None,
+        }
+    }
+
+    /// Sets the CODEOWNERS file findings point into, so each issue is
+    /// followed by a snippet of the offending source.
+    pub fn with_source(mut self, source: Option<Source>) -> Self {
+        self.source = source;
+        self
     }
 
     /// Writes a header for a check.
@@ -121,22 +360,37 @@ impl<W: Write> HumanOutput<W> {
         check_name: &str,
         result: &ValidationResult,
     ) -> std::io::Result<()> {
-        if result.errors.is_empty() {
+        if result.errors.is_empty() && result.internal_error.is_none() {
             return Ok(());
         }
 
         self.write_check_header(check_name)?;
 
         for error in &result.errors {
-            self.write_issue(error)?;
+            self.write_issue(error, result.severity_of(error))?;
+        }
+        if let Some(error) = &result.internal_error {
+            self.write_errored(error)?;
         }
 
         Ok(())
     }
 
-    /// Writes a single issue.
-    pub fn write_issue(&mut self, error: &ValidationError) -> std::io::Result<()> {
-        let severity = error.severity();
+    /// Writes why a check did not finish.
+    pub fn write_errored(&mut self, error: &str) -> std::io::Result<()> {
+        if self.use_colors {
+            writeln!(self.writer, "  {} {}", "[ERRORED]".red().bold(), error)
+        } else {
+            writeln!(self.writer, "  [ERRORED] {}", error)
+        }
+    }
+
+    /// Writes a single issue with its effective severity.
+    pub fn write_issue(
+        &mut self,
+        error: &ValidationError,
+        severity: Severity,
+    ) -> std::io::Result<()> {
         let message = error.to_string();
 
         let label = match severity {
@@ -149,14 +403,39 @@ impl<W: Write> HumanOutput<W> {
                 Severity::Error => format!("[{}]", label).red().bold(),
                 Severity::Warning => format!("[{}]", label).yellow().bold(),
             };
-            writeln!(self.writer, "  {} {}", colored_label, message)?;
+            writeln!(
+                self.writer,
+                "  {} {} {}",
+                colored_label,
+                error.code().dimmed(),
+                message
+            )?;
         } else {
-            writeln!(self.writer, "  [{}] {}", label, message)?;
+            writeln!(self.writer, "  [{}] {} {}", label, error.code(), message)?;
+        }
+        if let Some(source) = &self.source {
+            let snippet = render_snippet(error, severity, source, "    ", self.use_colors);
+            write!(self.writer, "{}", snippet)?;
         }
 
         Ok(())
     }
 
+    /// Writes a check that did not run.
+    pub fn write_skipped(&mut self, check_name: &str, reason: &str) -> std::io::Result<()> {
+        self.write_check_header(check_name)?;
+        if self.use_colors {
+            writeln!(
+                self.writer,
+                "  {} not run ({})",
+                "[SKIP]".yellow().bold(),
+                reason
+            )
+        } else {
+            writeln!(self.writer, "  [SKIP] not run ({})", reason)
+        }
+    }
+
     /// Writes a summary of all validation results.
     pub fn write_summary(
         &mut self,
@@ -187,6 +466,70 @@ impl<W: Write> HumanOutput<W> {
         Ok(())
     }
 
+    /// Writes the summary of a run where some checks were skipped or
+    /// errored, listing them by name.
+    pub fn write_incomplete_summary(
+        &mut self,
+        total_errors: usize,
+        total_warnings: usize,
+        skipped: &[&str],
+        errored: &[&str],
+    ) -> std::io::Result<()> {
+        if total_errors > 0 || total_warnings > 0 {
+            self.write_summary(total_errors, total_warnings)?;
+        } else {
+            let message = "! No issues found, but not every check completed";
+            if self.use_colors {
+                writeln!(self.writer, "\n{}", message.yellow().bold())?;
+            } else {
+                writeln!(self.writer, "\n{}", message)?;
+            }
+        }
+        if !skipped.is_empty() {
+            writeln!(
+                self.writer,
+                "  {} check(s) not run: {}",
+                skipped.len(),
+                skipped.join(", ")
+            )?;
+        }
+        if !errored.is_empty() {
+            writeln!(
+                self.writer,
+                "  {} check(s) errored: {}",
+                errored.len(),
+                errored.join(", ")
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Writes the size counts of the file.
+    pub fn write_stats(&mut self, stats: &ComplexityStats) -> std::io::Result<()> {
+        write!(
+            self.writer,
+            "  {} rule(s) in {} line(s)",
+            stats.rules, stats.lines
+        )?;
+        if stats.sections > 0 {
+            write!(
+                self.writer,
+                ", {} section(s) (largest has {} rule(s))",
+                stats.sections, stats.largest_section
+            )?;
+        }
+        writeln!(self.writer)
+    }
+
+    /// Writes how many known issues the baseline ignored.
+    pub fn write_baselined(&mut self, count: usize) -> std::io::Result<()> {
+        writeln!(
+            self.writer,
+            "  {} known issue(s) ignored by the baseline",
+            count
+        )
+    }
+
     /// Writes a startup error.
     pub fn write_error(&mut self, message: &str) -> std::io::Result<()> {
         if self.use_colors {
@@ -203,6 +546,10 @@ impl<W: Write> HumanOutput<W> {
 pub struct ValidationResults {
     results: HashMap<String, ValidationResult>,
     order: Vec<String>,
+    stats: Option<ComplexityStats>,
+    baselined: Option<usize>,
+    skipped: HashMap<String, String>,
+    file: Option<CodeownersFile>,
 }
 
 impl ValidationResults {
@@ -220,6 +567,86 @@ impl ValidationResults {
         self.results.entry(name).or_default().merge(result);
     }
 
+    /// Sets the size counts of the file, shown in the summary.
+    pub fn set_stats(&mut self, stats: ComplexityStats) {
+        self.stats = Some(stats);
+    }
+
+    /// Sets the CODEOWNERS file the results are about, so machine-readable
+    /// formats include a fingerprint for each issue.
+    pub fn set_file(&mut self, file: CodeownersFile) {
+        self.file = Some(file);
+    }
+
+    /// Returns the fingerprint of an issue reported by `check`, or `None`
+    /// if the file is not known.
+    pub fn fingerprint(&self, check: &str, error: &ValidationError) -> Option<String> {
+        self.file
+            .as_ref()
+            .map(|file| BaselineIssue::new(check, error, file).fingerprint())
+    }
+
+    /// Returns a baseline of all current issues.
+    pub fn to_baseline(&self, file: &CodeownersFile) -> Baseline {
+        let mut baseline = Baseline::new();
+        for (name, result) in self.iter() {
+            baseline.add(name, result, file);
+        }
+        baseline
+    }
+
+    /// Removes the issues known to `baseline`, and records how many were
+    /// removed for the summary.
+    pub fn apply_baseline(&mut self, baseline: &Baseline, file: &CodeownersFile) {
+        let removed = self
+            .results
+            .iter_mut()
+            .map(|(name, result)| baseline.filter(name, result, file))
+            .sum();
+        self.baselined = Some(removed);
+    }
+
+    /// Records a check that did not run, and why.
+    pub fn add_skipped(&mut self, check_name: impl Into<String>, reason: impl Into<String>) {
+        let name = check_name.into();
+        if !self.order.contains(&name) {
+            self.order.push(name.clone());
+        }
+        self.skipped.insert(name, reason.into());
+    }
+
+    /// Returns the status of a check, or `None` if it was never recorded.
+    pub fn status(&self, check_name: &str) -> Option<CheckStatus> {
+        if let Some(reason) = self.skipped.get(check_name) {
+            return Some(CheckStatus::Skipped {
+                reason: reason.clone(),
+            });
+        }
+        let result = self.results.get(check_name)?;
+        Some(match &result.internal_error {
+            Some(error) => CheckStatus::Errored {
+                error: error.clone(),
+            },
+            None if result.errors.is_empty() => CheckStatus::Passed,
+            None => CheckStatus::Failed,
+        })
+    }
+
+    /// Iterates over the status of every check, in order.
+    pub fn statuses(&self) -> impl Iterator<Item = (&str, CheckStatus)> {
+        self.order
+            .iter()
+            .filter_map(|name| self.status(name).map(|status| (name.as_str(), status)))
+    }
+
+    /// Returns the names of checks with a status matching `filter`, in order.
+    fn names_where(&self, filter: impl Fn(&CheckStatus) -> bool) -> Vec<&str> {
+        self.statuses()
+            .filter(|(_, status)| filter(status))
+            .map(|(name, _)| name)
+            .collect()
+    }
+
     /// Returns the total number of errors.
     pub fn total_errors(&self) -> usize {
         self.results.values().map(|r| r.errors_only().count()).sum()
@@ -243,6 +670,11 @@ impl ValidationResults {
         self.total_warnings() > 0
     }
 
+    /// Returns the fixes available across all results, in check order.
+    pub fn fixes(&self) -> impl Iterator<Item = Fix> + '_ {
+        self.iter().flat_map(|(_, result)| result.fixes())
+    }
+
     /// Iterates over results in order.
     pub fn iter(&self) -> impl Iterator<Item = (&str, &ValidationResult)> {
         self.order
@@ -250,26 +682,121 @@ impl ValidationResults {
             .filter_map(|name| self.results.get(name).map(|r| (name.as_str(), r)))
     }
 
-    /// Writes results in human-readable format.
-    pub fn write_human<W: Write>(&self, writer: &mut W, use_colors: bool) -> std::io::Result<()> {
-        let mut output = HumanOutput::new(writer, use_colors);
+    /// Writes results in human-readable format, with a snippet of the
+    /// source for each issue when `source` is given.
+    pub fn write_human<W: Write>(
+        &self,
+        writer: &mut W,
+        use_colors: bool,
+        source: Option<Source>,
+    ) -> std::io::Result<()> {
+        let mut output = HumanOutput::new(writer, use_colors).with_source(source);
 
         for (name, result) in self.iter() {
             output.write_check_results(name, result)?;
         }
+        for name in &self.order {
+            if let Some(reason) = self.skipped.get(name) {
+                output.write_skipped(name, reason)?;
+            }
+        }
 
-        output.write_summary(self.total_errors(), self.total_warnings())?;
+        let skipped = self.names_where(|status| matches!(status, CheckStatus::Skipped { .. }));
+        let errored = self.names_where(|status| matches!(status, CheckStatus::Errored { .. }));
+        if skipped.is_empty() && errored.is_empty() {
+            output.write_summary(self.total_errors(), self.total_warnings())?;
+        } else {
+            output.write_incomplete_summary(
+                self.total_errors(),
+                self.total_warnings(),
+                &skipped,
+                &errored,
+            )?;
+        }
+        if let Some(count) = self.baselined.filter(|&count| count > 0) {
+            output.write_baselined(count)?;
+        }
+        if let Some(stats) = &self.stats {
+            output.write_stats(stats)?;
+        }
 
         Ok(())
     }
 
+    /// Writes the issues and status of one check as NDJSON, so they can be
+    /// streamed as soon as the check finishes.
+    ///
+    /// Issues recorded in `baseline` are left out, and with `include_issues`
+    /// false all of them are, e.g. while a baseline is being created that
+    /// will record every issue. A check whose issues were all left out
+    /// passed, as in the other formats once the baseline is applied.
+    pub fn write_ndjson_check<W: Write>(
+        &self,
+        writer: &mut W,
+        check_name: &str,
+        baseline: Option<&Baseline>,
+        include_issues: bool,
+    ) -> std::io::Result<()> {
+        let Some(mut status) = self.status(check_name) else {
+            return Ok(());
+        };
+        if let Some(result) = self.results.get(check_name) {
+            let mut result = result.clone();
+            if !include_issues {
+                result.errors.clear();
+            } else if let (Some(baseline), Some(file)) = (baseline, &self.file) {
+                baseline.filter(check_name, &mut result, file);
+            }
+            for issue in JsonIssue::from_result(check_name, &result, self.file.as_ref()) {
+                NdjsonRecord::Issue {
+                    check: check_name,
+                    issue,
+                }
+                .write(writer)?;
+            }
+            if status == CheckStatus::Failed && result.errors.is_empty() {
+                status = CheckStatus::Passed;
+            }
+        }
+        NdjsonRecord::Check {
+            check: check_name,
+            status,
+        }
+        .write(writer)
+    }
+
+    /// Writes the NDJSON summary record.
+    pub fn write_ndjson_summary<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        NdjsonRecord::Summary {
+            errors: self.total_errors(),
+            warnings: self.total_warnings(),
+            baselined: self.baselined,
+        }
+        .write(writer)
+    }
+
+    /// Writes results in NDJSON format: every check's records, in order,
+    /// then the summary.
+    pub fn write_ndjson<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        for name in &self.order {
+            self.write_ndjson_check(writer, name, None, true)?;
+        }
+        self.write_ndjson_summary(writer)
+    }
+
     /// Writes results in JSON format.
     pub fn write_json<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
         let mut json_output = JsonOutput::new();
 
         for (name, result) in self.iter() {
-            json_output.add_check_results(name, result);
+            json_output.add_check_results(name, result, self.file.as_ref());
         }
+        json_output.stats = self.stats;
+        json_output.baselined = self.baselined;
+        json_output.checks = self
+            .statuses()
+            .map(|(name, status)| (name.to_string(), status))
+            .collect();
 
         json_output.write(writer)
     }
@@ -313,7 +840,7 @@ mod tests {
         let mut result = ValidationResult::new();
         result.add_error(ValidationError::duplicate_pattern("*.rs", test_span(), 1));
 
-        output.add_check_results("duppatterns", &result);
+        output.add_check_results("duppatterns", &result, None);
 
         assert_eq!(output.duppatterns.len(), 1);
         assert!(output.syntax.is_empty());
@@ -324,7 +851,7 @@ mod tests {
         let mut output = JsonOutput::new();
         let mut result = ValidationResult::new();
         result.add_error(ValidationError::duplicate_pattern("*.rs", test_span(), 1));
-        output.add_check_results("syntax", &result);
+        output.add_check_results("syntax", &result, None);
 
         let mut buf = Vec::new();
         output.write(&mut buf).unwrap();
@@ -334,13 +861,35 @@ mod tests {
         assert_eq!(json["syntax"].as_array().unwrap().len(), 1);
     }
 
+    #[test]
+    fn test_json_output_custom_check() {
+        let mut output = JsonOutput::new();
+        let mut result = ValidationResult::new();
+        result.add_error(ValidationError::custom_check_finding(
+            "no-bots",
+            "bot owner @deploy[bot]",
+            Severity::Warning,
+            test_span(),
+        ));
+        output.add_check_results("no-bots", &result, None);
+        output.add_check_results("license-headers", &ValidationResult::new(), None);
+
+        let mut buf = Vec::new();
+        output.write(&mut buf).unwrap();
+
+        let json: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+        assert_eq!(json["no-bots"][0]["code"], "CO032");
+        assert_eq!(json["no-bots"][0]["severity"], "warning");
+        assert_eq!(json["license-headers"], serde_json::json!([]));
+    }
+
     #[test]
     fn test_human_output_no_colors() {
         let mut buf = Vec::new();
         let mut output = HumanOutput::new(&mut buf, false);
 
         let error = ValidationError::duplicate_pattern("*.rs", test_span(), 1);
-        output.write_issue(&error).unwrap();
+        output.write_issue(&error, error.severity()).unwrap();
 
         let text = String::from_utf8(buf).unwrap();
         assert!(text.contains("[WARN]"));
@@ -400,4 +949,150 @@ mod tests {
         assert!(text.contains("2 error(s)"));
         assert!(text.contains("3 warning(s)"));
     }
+
+    #[test]
+    fn test_results_apply_baseline() {
+        let file = codeowners_validator_core::parse::parse_codeowners("*.rs @a\n*.rs @b\n").ast;
+        let duplicate = || ValidationError::duplicate_pattern("*.rs", Span::new(8, 2, 1, 4), 1);
+        let mut results = ValidationResults::new();
+        results.add(
+            "duppatterns",
+            ValidationResult::with_errors(vec![duplicate()]),
+        );
+        let baseline = results.to_baseline(&file);
+        assert_eq!(baseline.len(), 1);
+
+        results.add(
+            "syntax",
+            ValidationResult::with_errors(vec![ValidationError::invalid_owner_format(
+                "a",
+                "missing @",
+                Span::new(5, 1, 6, 1),
+            )]),
+        );
+        results.apply_baseline(&baseline, &file);
+        assert_eq!(results.total_warnings(), 0);
+        assert_eq!(results.total_errors(), 1);
+
+        let mut buf = Vec::new();
+        results.write_human(&mut buf, false, None).unwrap();
+        let text = String::from_utf8(buf).unwrap();
+        assert!(text.contains("1 known issue(s) ignored by the baseline"));
+
+        let mut buf = Vec::new();
+        results.write_json(&mut buf).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+        assert_eq!(json["baselined"], 1);
+    }
+
+    #[test]
+    fn test_ndjson_check_leaves_out_baselined_issues() {
+        let file = codeowners_validator_core::parse::parse_codeowners("*.rs @a\n*.rs @b\n").ast;
+        let duplicate = ValidationError::duplicate_pattern("*.rs", Span::new(8, 2, 1, 4), 1);
+        let mut results = ValidationResults::new();
+        results.add(
+            "duppatterns",
+            ValidationResult::with_errors(vec![duplicate]),
+        );
+        results.set_file(file.clone());
+        let baseline = results.to_baseline(&file);
+
+        let ndjson = |baseline, include_issues| {
+            let mut buf = Vec::new();
+            results
+                .write_ndjson_check(&mut buf, "duppatterns", baseline, include_issues)
+                .unwrap();
+            String::from_utf8(buf).unwrap()
+        };
+        let streamed = ndjson(None, true);
+        assert_eq!(streamed.lines().count(), 2);
+        assert!(streamed.starts_with(r#"{"type":"issue","check":"duppatterns","code":"CO004""#));
+        assert!(streamed.ends_with("\"status\":\"failed\"}\n"));
+
+        let passed = "{\"type\":\"check\",\"check\":\"duppatterns\",\"status\":\"passed\"}\n";
+        assert_eq!(ndjson(Some(&baseline), true), passed);
+        assert_eq!(ndjson(None, false), passed);
+    }
+
+    #[test]
+    fn test_results_with_stats() {
+        let mut results = ValidationResults::new();
+        results.add("complexity", ValidationResult::new());
+        results.set_stats(ComplexityStats {
+            lines: 12,
+            rules: 8,
+            sections: 2,
+            largest_section: 5,
+        });
+
+        let mut buf = Vec::new();
+        results.write_human(&mut buf, false, None).unwrap();
+        let text = String::from_utf8(buf).unwrap();
+        assert!(text.contains("8 rule(s) in 12 line(s), 2 section(s) (largest has 5 rule(s))"));
+
+        let mut buf = Vec::new();
+        results.write_json(&mut buf).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+        assert_eq!(json["stats"]["rules"], 8);
+    }
+
+    #[test]
+    fn test_results_check_statuses() {
+        let mut results = ValidationResults::new();
+        results.add("syntax", ValidationResult::new());
+        results.add_skipped("files", BUDGET_EXCEEDED);
+        let mut owners = ValidationResult::new();
+        owners.set_internal_error("2 owner(s) could not be checked: API unreachable");
+        results.add("owners", owners);
+        assert_eq!(results.status("syntax"), Some(CheckStatus::Passed));
+        assert_eq!(
+            results.status("files").unwrap().describe(),
+            "not run (budget exceeded)"
+        );
+        assert_eq!(results.status("owners").unwrap().name(), "errored");
+        assert_eq!(results.status("notowned"), None);
+        assert!(!results.has_errors());
+
+        let mut buf = Vec::new();
+        results.write_human(&mut buf, false, None).unwrap();
+        let text = String::from_utf8(buf).unwrap();
+        assert!(text.contains("==> files\n  [SKIP] not run (budget exceeded)"));
+        assert!(
+            text.contains(
+                "==> owners\n  [ERRORED] 2 owner(s) could not be checked: API unreachable"
+            )
+        );
+        assert!(text.contains("No issues found, but not every check completed"));
+        assert!(text.contains("1 check(s) not run: files"));
+        assert!(text.contains("1 check(s) errored: owners"));
+        assert!(!text.contains("is valid"));
+
+        let mut buf = Vec::new();
+        results.write_json(&mut buf).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+        assert_eq!(json["checks"]["syntax"]["status"], "passed");
+        assert_eq!(json["checks"]["files"]["status"], "skipped");
+        assert_eq!(json["checks"]["files"]["reason"], BUDGET_EXCEEDED);
+        assert_eq!(
+            json["checks"]["owners"]["error"],
+            "2 owner(s) could not be checked: API unreachable"
+        );
+    }
+
+    #[test]
+    fn test_validation_results_fixes() {
+        let mut results = ValidationResults::new();
+        let mut r1 = ValidationResult::new();
+        r1.add_error(ValidationError::owner_must_be_team_with_suggestion(
+            "@user",
+            "@org/team",
+            test_span(),
+        ));
+        r1.add_error(ValidationError::owner_must_be_team("@other", test_span()));
+        results.add("owners", r1);
+
+        let fixes: Vec<_> = results.fixes().collect();
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(fixes[0].replacement_text, "@org/team");
+    }
 }
@@ -0,0 +1,72 @@
+//! Reverse ownership lookup for `codeowners-validator files-for-owner`.
+//!
+//! Lists every file in the repository whose effective owners include a given
+//! user, team or email, e.g. to audit what a departing engineer owns.
+
+use colored::Colorize;
+use serde::Serialize;
+use std::fmt::Write;
+
+/// The files owned by a single owner.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OwnedFiles {
+    /// The owner, as given on the command line.
+    pub owner: String,
+    /// Files owned by the owner, relative to the repository root.
+    pub files: Vec<String>,
+}
+
+/// Renders the owned files for the terminal, one per line.
+pub fn render_human(owned: &OwnedFiles, use_colors: bool) -> String {
+    if owned.files.is_empty() {
+        let message = format!("No files owned by {}", owned.owner);
+        return if use_colors {
+            format!("{}\n", message.yellow())
+        } else {
+            format!("{}\n", message)
+        };
+    }
+
+    let mut output = String::new();
+    for file in &owned.files {
+        let _ = writeln!(output, "{}", file);
+    }
+    output
+}
+
+/// Renders the owned files as pretty-printed JSON.
+pub fn render_json(owned: &OwnedFiles) -> String {
+    serde_json::to_string_pretty(owned).expect("owned files are always serializable")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn owned(files: &[&str]) -> OwnedFiles {
+        OwnedFiles {
+            owner: "@org/core".to_string(),
+            files: files.iter().map(|f| f.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn human_rendering() {
+        assert_eq!(
+            render_human(&owned(&["src/lib.rs", "src/main.rs"]), false),
+            "src/lib.rs\nsrc/main.rs\n"
+        );
+        assert_eq!(
+            render_human(&owned(&[]), false),
+            "No files owned by @org/core\n"
+        );
+    }
+
+    #[test]
+    fn json_rendering() {
+        let json: serde_json::Value =
+            serde_json::from_str(&render_json(&owned(&["src/lib.rs"]))).unwrap();
+        assert_eq!(json["owner"], "@org/core");
+        assert_eq!(json["files"][0], "src/lib.rs");
+    }
+}
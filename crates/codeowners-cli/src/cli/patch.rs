@@ -0,0 +1,131 @@
+//! Patch validation for `codeowners-validator validate-patch`.
+//!
+//! Applies the CODEOWNERS part of a unified diff to the base content and
+//! runs the offline checks on the result, so a pull request can be
+//! validated from its patch without a checkout.
+
+use codeowners_validator_core::matching::PatternSyntax;
+use codeowners_validator_core::parse::{ParserConfig, parse_codeowners_with_config};
+use codeowners_validator_core::patch::PatchedFile;
+use codeowners_validator_core::validate::checks::{
+    AvoidShadowingCheck, Check, CheckConfig, CheckContext, DupPatternsCheck, SyntaxCheck,
+    run_isolated,
+};
+use http::StatusCode;
+use octocrab::Octocrab;
+use std::io::{self, Read};
+use std::path::Path;
+
+use super::output::ValidationResults;
+
+/// Reads a patch from `path`, or from stdin if it is `-`.
+pub fn read_patch(path: &Path) -> io::Result<String> {
+    if path == Path::new("-") {
+        let mut patch = String::new();
+        io::stdin().read_to_string(&mut patch)?;
+        Ok(patch)
+    } else {
+        std::fs::read_to_string(path)
+    }
+}
+
+/// Fetches the content of `path` at `reference` from the GitHub contents
+/// API, or `None` if the file does not exist there.
+pub async fn fetch_base(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    path: &str,
+    reference: &str,
+) -> octocrab::Result<Option<String>> {
+    match client
+        .repos(owner, repo)
+        .get_content()
+        .path(path)
+        .r#ref(reference)
+        .send()
+        .await
+    {
+        Ok(mut content) => Ok(content
+            .take_items()
+            .into_iter()
+            .next()
+            .and_then(|item| item.decoded_content())),
+        Err(octocrab::Error::GitHub { source, .. })
+            if source.status_code == StatusCode::NOT_FOUND =>
+        {
+            Ok(None)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Runs the checks that need neither the repository nor GitHub on the
+/// patched file.
+///
+/// With `changed_only`, findings on lines the patch did not add or change
+/// are left out, so pre-existing issues do not fail the pull request.
+pub fn validate_patched(
+    patched: &PatchedFile,
+    pattern_syntax: PatternSyntax,
+    changed_only: bool,
+) -> ValidationResults {
+    let file = parse_codeowners_with_config(
+        &patched.content,
+        &ParserConfig::new()
+            .with_dialect(pattern_syntax.into())
+            .with_pattern_syntax(pattern_syntax),
+    )
+    .ast;
+    let config = CheckConfig::new().with_pattern_syntax(pattern_syntax);
+    let ctx = CheckContext::new(&file, Path::new("."), &config);
+    let checks: [&dyn Check; 3] = [
+        &SyntaxCheck::new(),
+        &DupPatternsCheck::new(),
+        &AvoidShadowingCheck::new(),
+    ];
+
+    let mut results = ValidationResults::new();
+    for check in checks {
+        let mut result = run_isolated(check, &ctx);
+        if changed_only {
+            result
+                .errors
+                .retain(|error| patched.is_changed(error.line()));
+        }
+        results.add(check.name(), result);
+    }
+    results.set_file(file);
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codeowners_validator_core::patch::{codeowners_patch, parse_patch};
+
+    const DIFF: &str = "\
+--- a/CODEOWNERS
++++ b/CODEOWNERS
+@@ -1,2 +1,3 @@
+ * @org/core
+ *.rs rust-team
++/docs/ docs-team
+";
+
+    #[test]
+    fn filters_findings_to_changed_lines() {
+        let patches = parse_patch(DIFF).unwrap();
+        let patched = codeowners_patch(&patches)
+            .unwrap()
+            .apply("* @org/core\n*.rs rust-team\n")
+            .unwrap();
+
+        let all = validate_patched(&patched, PatternSyntax::GitHub, false);
+        assert_eq!(all.total_errors(), 2);
+
+        // Only the invalid owner on the added line 3 is reported
+        let changed = validate_patched(&patched, PatternSyntax::GitHub, true);
+        assert_eq!(changed.total_errors(), 1);
+    }
+}
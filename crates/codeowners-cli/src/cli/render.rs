@@ -0,0 +1,739 @@
+//! Rendering of validation results in every supported output format.
+//!
+//! [`render`] is the single entry point that turns collected results into
+//! the text written to stdout, so the CLI and any other frontend produce
+//! identical output for the same findings.
+
+use super::OutputFormat;
+use super::diagnostic::Source;
+use super::output::{CheckStatus, ValidationResults};
+use codeowners_validator_core::validate::{Severity, ValidationError};
+use serde_json::json;
+use std::fmt::Write;
+
+/// Tool name reported in machine-readable formats.
+const TOOL_NAME: &str = "codeowners-validator";
+
+/// Project homepage reported in SARIF output.
+const TOOL_URI: &str = "https://github.com/donicrosby/codeowners-validator-rs";
+
+/// Name of the finding fingerprints in SARIF `partialFingerprints`.
+const FINGERPRINT_NAME: &str = "codeownersIssue/v1";
+
+/// Options that affect how results are rendered.
+#[derive(Debug, Clone)]
+pub struct RenderOptions {
+    /// Path of the CODEOWNERS file relative to the repository root,
+    /// used as the location of every finding.
+    pub codeowners_path: String,
+    /// Whether to use ANSI colors (human format only).
+    pub use_colors: bool,
+    /// Content of the CODEOWNERS file; when set, human output shows the
+    /// source each finding points at.
+    pub source: Option<String>,
+}
+
+impl RenderOptions {
+    /// Creates render options for the given CODEOWNERS path, without colors.
+    pub fn new(codeowners_path: impl Into<String>) -> Self {
+        Self {
+            codeowners_path: codeowners_path.into(),
+            use_colors: false,
+            source: None,
+        }
+    }
+
+    /// Sets whether ANSI colors are used.
+    pub fn with_colors(mut self, use_colors: bool) -> Self {
+        self.use_colors = use_colors;
+        self
+    }
+
+    /// Sets the content of the CODEOWNERS file, for source snippets.
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+}
+
+/// Renders validation results in the requested format.
+pub fn render(
+    results: &ValidationResults,
+    format: OutputFormat,
+    options: &RenderOptions,
+) -> String {
+    match format {
+        OutputFormat::Human => {
+            let mut buf = Vec::new();
+            let source = options
+                .source
+                .as_ref()
+                .map(|text| Source::new(&options.codeowners_path, text));
+            results
+                .write_human(&mut buf, options.use_colors, source)
+                .expect("writing to a Vec cannot fail");
+            String::from_utf8_lossy(&buf).into_owned()
+        }
+        OutputFormat::Json => {
+            let mut buf = Vec::new();
+            results
+                .write_json(&mut buf)
+                .expect("writing to a Vec cannot fail");
+            String::from_utf8_lossy(&buf).into_owned()
+        }
+        OutputFormat::Ndjson => {
+            let mut buf = Vec::new();
+            results
+                .write_ndjson(&mut buf)
+                .expect("writing to a Vec cannot fail");
+            String::from_utf8_lossy(&buf).into_owned()
+        }
+        OutputFormat::Sarif => render_sarif(results, options),
+        OutputFormat::Junit => render_junit(results, options),
+        OutputFormat::Checkstyle => render_checkstyle(results, options),
+        OutputFormat::Tap => render_tap(results, options),
+        OutputFormat::Github => render_github(results, options),
+        OutputFormat::Csv => render_csv(results, options),
+    }
+}
+
+/// Returns the lowercase severity name used in machine-readable formats.
+fn severity_name(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    }
+}
+
+/// Renders results as a SARIF 2.1.0 log.
+fn render_sarif(results: &ValidationResults, options: &RenderOptions) -> String {
+    let rules: Vec<_> = results
+        .iter()
+        .map(|(name, _)| json!({ "id": name, "name": name }))
+        .collect();
+
+    let sarif_results: Vec<_> = results
+        .iter()
+        .flat_map(|(name, result)| {
+            result
+                .errors
+                .iter()
+                .map(move |error| (name, error, result.severity_of(error)))
+        })
+        .map(|(name, error, severity)| {
+            let span = error.span();
+            let mut region = json!({
+                "startLine": span.line,
+                "startColumn": span.column,
+            });
+            if span.is_multiline() {
+                region["endLine"] = json!(span.end_line);
+            }
+            if span.length > 0 {
+                region["endColumn"] = json!(span.end_column);
+            }
+            let mut sarif_result = json!({
+                "ruleId": name,
+                "level": severity_name(severity),
+                "message": { "text": error.to_string() },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": options.codeowners_path },
+                        "region": region,
+                    }
+                }],
+            });
+            if let Some(fingerprint) = results.fingerprint(name, error) {
+                sarif_result["partialFingerprints"] = json!({ FINGERPRINT_NAME: fingerprint });
+            }
+            sarif_result
+        })
+        .collect();
+
+    let mut log = json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": TOOL_NAME,
+                    "informationUri": TOOL_URI,
+                    "rules": rules,
+                }
+            },
+            "results": sarif_results,
+        }],
+    });
+
+    // Skipped and errored checks make the run incomplete
+    let incomplete = incomplete_checks(results);
+    if !incomplete.is_empty() {
+        let notifications: Vec<_> = incomplete
+            .iter()
+            .map(|(name, status)| {
+                let level = match status {
+                    CheckStatus::Errored { .. } => "error",
+                    _ => "warning",
+                };
+                json!({
+                    "level": level,
+                    "message": { "text": format!("{}: {}", name, status.describe()) },
+                    "descriptor": { "id": name },
+                })
+            })
+            .collect();
+        log["runs"][0]["invocations"] = json!([{
+            "executionSuccessful": false,
+            "toolExecutionNotifications": notifications,
+        }]);
+    }
+
+    let mut output = serde_json::to_string_pretty(&log).expect("SARIF log is valid JSON");
+    output.push('\n');
+    output
+}
+
+/// Returns the checks that were skipped or errored, in order.
+fn incomplete_checks(results: &ValidationResults) -> Vec<(&str, CheckStatus)> {
+    results
+        .statuses()
+        .filter(|(_, status)| {
+            matches!(
+                status,
+                CheckStatus::Skipped { .. } | CheckStatus::Errored { .. }
+            )
+        })
+        .collect()
+}
+
+/// Escapes text for use in XML attribute values.
+pub(super) fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Renders results as a JUnit XML report with one test suite per check.
+///
+/// A check without findings is reported as a single passing test case, and
+/// a check that did not finish gets an extra test case with an error.
+fn render_junit(results: &ValidationResults, options: &RenderOptions) -> String {
+    let total_findings: usize = results.iter().map(|(_, r)| r.errors.len()).sum();
+    let total_errors = results
+        .iter()
+        .filter(|(_, r)| r.internal_error.is_some())
+        .count();
+    let skipped = results
+        .statuses()
+        .filter_map(|(name, status)| match status {
+            CheckStatus::Skipped { reason } => Some((name, reason)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let total_tests: usize = results
+        .iter()
+        .map(|(_, r)| (r.errors.len() + usize::from(r.internal_error.is_some())).max(1))
+        .sum::<usize>()
+        + skipped.len();
+    let path = escape_xml(&options.codeowners_path);
+
+    let mut xml = String::new();
+    writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#).unwrap();
+    writeln!(
+        xml,
+        r#"<testsuites name="{TOOL_NAME}" tests="{total_tests}" failures="{total_findings}" errors="{total_errors}">"#
+    )
+    .unwrap();
+
+    for (name, result) in results.iter() {
+        let findings = result.errors.len();
+        let errors = usize::from(result.internal_error.is_some());
+        writeln!(
+            xml,
+            r#"  <testsuite name="{name}" tests="{}" failures="{findings}" errors="{errors}">"#,
+            (findings + errors).max(1)
+        )
+        .unwrap();
+
+        if findings + errors == 0 {
+            writeln!(xml, r#"    <testcase classname="{name}" name="{name}"/>"#).unwrap();
+        }
+        if let Some(error) = &result.internal_error {
+            writeln!(xml, r#"    <testcase classname="{name}" name="{name}">"#).unwrap();
+            writeln!(xml, r#"      <error message="{}"/>"#, escape_xml(error)).unwrap();
+            writeln!(xml, "    </testcase>").unwrap();
+        }
+        for error in &result.errors {
+            let span = error.span();
+            writeln!(
+                xml,
+                r#"    <testcase classname="{name}" name="{path}:{}:{}">"#,
+                span.line, span.column
+            )
+            .unwrap();
+            writeln!(
+                xml,
+                r#"      <failure type="{}" message="{}"/>"#,
+                severity_name(result.severity_of(error)),
+                escape_xml(&error.to_string())
+            )
+            .unwrap();
+            writeln!(xml, "    </testcase>").unwrap();
+        }
+
+        writeln!(xml, "  </testsuite>").unwrap();
+    }
+
+    for (name, reason) in skipped {
+        writeln!(
+            xml,
+            r#"  <testsuite name="{name}" tests="1" failures="0" skipped="1">"#
+        )
+        .unwrap();
+        writeln!(xml, r#"    <testcase classname="{name}" name="{name}">"#).unwrap();
+        writeln!(
+            xml,
+            r#"      <skipped message="not run ({})"/>"#,
+            escape_xml(&reason)
+        )
+        .unwrap();
+        writeln!(xml, "    </testcase>").unwrap();
+        writeln!(xml, "  </testsuite>").unwrap();
+    }
+
+    writeln!(xml, "</testsuites>").unwrap();
+    xml
+}
+
+/// Renders results as checkstyle XML, which review bots such as reviewdog
+/// and Danger ingest.
+///
+/// Every finding is an `error` element of the CODEOWNERS file, with the
+/// check and error code as its `source`. Skipped and errored checks are
+/// elements without a position.
+fn render_checkstyle(results: &ValidationResults, options: &RenderOptions) -> String {
+    let mut xml = String::new();
+    writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#).unwrap();
+    writeln!(xml, r#"<checkstyle version="4.3">"#).unwrap();
+    writeln!(
+        xml,
+        r#"  <file name="{}">"#,
+        escape_xml(&options.codeowners_path)
+    )
+    .unwrap();
+
+    for (name, result) in results.iter() {
+        for error in &result.errors {
+            let span = error.span();
+            writeln!(
+                xml,
+                r#"    <error line="{}" column="{}" severity="{}" message="{}" source="{TOOL_NAME}.{name}.{}"/>"#,
+                span.line,
+                span.column,
+                severity_name(result.severity_of(error)),
+                escape_xml(&error.to_string()),
+                error.code()
+            )
+            .unwrap();
+        }
+    }
+    for (name, status) in incomplete_checks(results) {
+        let severity = match status {
+            CheckStatus::Errored { .. } => "error",
+            _ => "warning",
+        };
+        writeln!(
+            xml,
+            r#"    <error severity="{severity}" message="{name}: {}" source="{TOOL_NAME}.{name}"/>"#,
+            escape_xml(&status.describe())
+        )
+        .unwrap();
+    }
+
+    writeln!(xml, "  </file>").unwrap();
+    writeln!(xml, "</checkstyle>").unwrap();
+    xml
+}
+
+/// Renders results as a TAP version 14 stream for `prove` and other TAP
+/// harnesses.
+///
+/// Each check is one test point, failing if it reported errors or did not
+/// finish; skipped checks carry a `SKIP` directive. Every issue follows its
+/// check's test point as a diagnostic line.
+fn render_tap(results: &ValidationResults, options: &RenderOptions) -> String {
+    let statuses: Vec<_> = results.statuses().collect();
+    let mut tap = String::new();
+    writeln!(tap, "TAP version 14").unwrap();
+    writeln!(tap, "1..{}", statuses.len()).unwrap();
+
+    for (number, (name, status)) in statuses.into_iter().enumerate() {
+        let number = number + 1;
+        let result = results
+            .iter()
+            .find(|(check, _)| *check == name)
+            .map(|(_, result)| result);
+        match &status {
+            CheckStatus::Skipped { reason } => {
+                writeln!(tap, "ok {number} - {name} # SKIP {reason}").unwrap();
+                continue;
+            }
+            CheckStatus::Errored { .. } => writeln!(tap, "not ok {number} - {name}").unwrap(),
+            _ if result.is_some_and(|r| r.errors_only().next().is_some()) => {
+                writeln!(tap, "not ok {number} - {name}").unwrap()
+            }
+            _ => writeln!(tap, "ok {number} - {name}").unwrap(),
+        }
+
+        for (error, result) in result
+            .into_iter()
+            .flat_map(|r| r.errors.iter().map(move |e| (e, r)))
+        {
+            let span = error.span();
+            writeln!(
+                tap,
+                "# {}:{}:{}: {} {} {}",
+                options.codeowners_path,
+                span.line,
+                span.column,
+                severity_name(result.severity_of(error)),
+                error.code(),
+                error
+            )
+            .unwrap();
+        }
+        if let CheckStatus::Errored { .. } = status {
+            writeln!(tap, "# {}", status.describe()).unwrap();
+        }
+    }
+    tap
+}
+
+/// Escapes data for a GitHub Actions workflow command message.
+fn escape_github_data(text: &str) -> String {
+    text.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escapes a GitHub Actions workflow command property value.
+fn escape_github_property(text: &str) -> String {
+    escape_github_data(text)
+        .replace(':', "%3A")
+        .replace(',', "%2C")
+}
+
+/// Formats a single finding as a GitHub Actions annotation.
+fn github_annotation(
+    check: &str,
+    error: &ValidationError,
+    severity: Severity,
+    options: &RenderOptions,
+) -> String {
+    let span = error.span();
+    let mut properties = format!(
+        "file={},line={},col={}",
+        escape_github_property(&options.codeowners_path),
+        span.line,
+        span.column
+    );
+    if span.is_multiline() {
+        write!(properties, ",endLine={}", span.end_line).unwrap();
+    }
+    if span.length > 0 {
+        write!(properties, ",endColumn={}", span.end_column).unwrap();
+    }
+    write!(properties, ",title={}", escape_github_property(check)).unwrap();
+
+    format!(
+        "::{} {}::{}",
+        severity_name(severity),
+        properties,
+        escape_github_data(&error.to_string())
+    )
+}
+
+/// Renders results as GitHub Actions workflow annotations, one per line.
+fn render_github(results: &ValidationResults, options: &RenderOptions) -> String {
+    let mut output = String::new();
+    for (name, result) in results.iter() {
+        for error in &result.errors {
+            let severity = result.severity_of(error);
+            writeln!(
+                output,
+                "{}",
+                github_annotation(name, error, severity, options)
+            )
+            .unwrap();
+        }
+    }
+    for (name, status) in incomplete_checks(results) {
+        let level = match status {
+            CheckStatus::Errored { .. } => "error",
+            _ => "warning",
+        };
+        writeln!(
+            output,
+            "::{} title={}::{}",
+            level,
+            escape_github_property(name),
+            escape_github_data(&status.describe())
+        )
+        .unwrap();
+    }
+    output
+}
+
+/// Quotes a CSV field if it contains a delimiter, quote or line break.
+pub(super) fn escape_csv(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+/// Renders results as CSV, one finding per row.
+fn render_csv(results: &ValidationResults, options: &RenderOptions) -> String {
+    let mut output = String::from("file,line,column,severity,check,message\n");
+    for (name, result) in results.iter() {
+        for error in &result.errors {
+            let span = error.span();
+            writeln!(
+                output,
+                "{},{},{},{},{},{}",
+                escape_csv(&options.codeowners_path),
+                span.line,
+                span.column,
+                severity_name(result.severity_of(error)),
+                escape_csv(name),
+                escape_csv(&error.to_string())
+            )
+            .unwrap();
+        }
+    }
+    // Rows for skipped and errored checks have no position, and their
+    // status in place of a severity
+    for (name, status) in incomplete_checks(results) {
+        writeln!(
+            output,
+            "{},,,{},{},{}",
+            escape_csv(&options.codeowners_path),
+            status.name(),
+            escape_csv(name),
+            escape_csv(&status.describe())
+        )
+        .unwrap();
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codeowners_validator_core::parse::{Span, parse_codeowners};
+    use codeowners_validator_core::validate::ValidationResult;
+    use codeowners_validator_core::validate::checks::{
+        Check, CheckConfig, CheckContext, DupPatternsCheck, SyntaxCheck,
+    };
+    use std::path::Path;
+
+    /// CODEOWNERS content of the fixture repository used by the golden tests.
+    const FIXTURE: &str = "\
+# Fixture for output format tests
+*.rs @rust-team
+*.rs @other-team
+/docs/ @acme/docs team-<a>
+/scripts/ @ops \"quoted\"@example.com
+";
+
+    /// Runs the fixture through a fixed set of checks.
+    fn fixture_results() -> ValidationResults {
+        let file = parse_codeowners(FIXTURE).ast;
+        let config = CheckConfig::new();
+        let ctx = CheckContext::new(&file, Path::new("/repo"), &config);
+
+        let mut results = ValidationResults::new();
+        results.add("syntax", SyntaxCheck::new().run(&ctx));
+        results.add("duppatterns", DupPatternsCheck::new().run(&ctx));
+        results.add("files", ValidationResult::new());
+        results.set_file(file);
+        results
+    }
+
+    fn render_fixture(format: OutputFormat) -> String {
+        render(
+            &fixture_results(),
+            format,
+            &RenderOptions::new(".github/CODEOWNERS"),
+        )
+    }
+
+    #[test]
+    fn golden_human() {
+        insta::assert_snapshot!(render_fixture(OutputFormat::Human));
+    }
+
+    #[test]
+    fn golden_human_snippets() {
+        let options = RenderOptions::new(".github/CODEOWNERS").with_source(FIXTURE);
+        insta::assert_snapshot!(render(&fixture_results(), OutputFormat::Human, &options));
+    }
+
+    #[test]
+    fn golden_json() {
+        insta::assert_snapshot!(render_fixture(OutputFormat::Json));
+    }
+
+    #[test]
+    fn golden_ndjson() {
+        insta::assert_snapshot!(render_fixture(OutputFormat::Ndjson));
+    }
+
+    #[test]
+    fn golden_sarif() {
+        insta::assert_snapshot!(render_fixture(OutputFormat::Sarif));
+    }
+
+    #[test]
+    fn golden_junit() {
+        insta::assert_snapshot!(render_fixture(OutputFormat::Junit));
+    }
+
+    #[test]
+    fn golden_checkstyle() {
+        insta::assert_snapshot!(render_fixture(OutputFormat::Checkstyle));
+    }
+
+    #[test]
+    fn golden_tap() {
+        insta::assert_snapshot!(render_fixture(OutputFormat::Tap));
+    }
+
+    #[test]
+    fn golden_github() {
+        insta::assert_snapshot!(render_fixture(OutputFormat::Github));
+    }
+
+    #[test]
+    fn golden_csv() {
+        insta::assert_snapshot!(render_fixture(OutputFormat::Csv));
+    }
+
+    #[test]
+    fn sarif_is_valid_json() {
+        let value: serde_json::Value =
+            serde_json::from_str(&render_fixture(OutputFormat::Sarif)).unwrap();
+        assert_eq!(value["version"], "2.1.0");
+    }
+
+    #[test]
+    fn multiline_span_end_positions() {
+        let span = Span::new(10, 2, 1, 40).with_end(5, 16);
+        let mut result = ValidationResult::new();
+        result.add_error(ValidationError::generated_region_modified(
+            "gen", "sha256:0", "sha256:1", span,
+        ));
+        let mut results = ValidationResults::new();
+        results.add("generated-regions", result);
+        let options = RenderOptions::new(".github/CODEOWNERS");
+
+        let sarif: serde_json::Value =
+            serde_json::from_str(&render(&results, OutputFormat::Sarif, &options)).unwrap();
+        let region = &sarif["runs"][0]["results"][0]["locations"][0]["physicalLocation"]["region"];
+        assert_eq!(region["startLine"], 2);
+        assert_eq!(region["endLine"], 5);
+        assert_eq!(region["endColumn"], 16);
+
+        let github = render(&results, OutputFormat::Github, &options);
+        assert!(github.contains("line=2,col=1,endLine=5,endColumn=16"));
+    }
+
+    #[test]
+    fn github_property_escaping() {
+        assert_eq!(escape_github_property("a:b,c%"), "a%3Ab%2Cc%25");
+        assert_eq!(escape_github_data("line1\nline2"), "line1%0Aline2");
+    }
+
+    #[test]
+    fn csv_rows() {
+        let csv = render_fixture(OutputFormat::Csv);
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some("file,line,column,severity,check,message")
+        );
+        let findings: usize = fixture_results()
+            .iter()
+            .map(|(_, result)| result.errors.len())
+            .sum();
+        assert_eq!(lines.count(), findings);
+        assert_eq!(escape_csv("a,\"b\""), "\"a,\"\"b\"\"\"");
+    }
+
+    #[test]
+    fn incomplete_checks() {
+        let mut results = fixture_results();
+        results.add_skipped("owners", "budget exceeded");
+        let mut files = ValidationResult::new();
+        files.set_internal_error("walk failed");
+        results.add("files", files);
+        let options = RenderOptions::new(".github/CODEOWNERS");
+
+        let sarif: serde_json::Value =
+            serde_json::from_str(&render(&results, OutputFormat::Sarif, &options)).unwrap();
+        let invocation = &sarif["runs"][0]["invocations"][0];
+        assert_eq!(invocation["executionSuccessful"], false);
+        let notifications = &invocation["toolExecutionNotifications"];
+        assert_eq!(notifications[0]["level"], "error");
+        assert_eq!(
+            notifications[0]["message"]["text"],
+            "files: errored: walk failed"
+        );
+        assert_eq!(
+            notifications[1]["message"]["text"],
+            "owners: not run (budget exceeded)"
+        );
+
+        let junit = render(&results, OutputFormat::Junit, &options);
+        assert!(junit.contains(r#"<testsuite name="owners" tests="1" failures="0" skipped="1">"#));
+        assert!(junit.contains(r#"<skipped message="not run (budget exceeded)"/>"#));
+        assert!(junit.contains(r#"<testsuite name="files" tests="1" failures="0" errors="1">"#));
+        assert!(junit.contains(r#"<error message="walk failed"/>"#));
+
+        let checkstyle = render(&results, OutputFormat::Checkstyle, &options);
+        assert!(checkstyle.contains(
+            r#"<error severity="error" message="files: errored: walk failed" source="codeowners-validator.files"/>"#
+        ));
+        assert!(checkstyle.contains(
+            r#"<error severity="warning" message="owners: not run (budget exceeded)" source="codeowners-validator.owners"/>"#
+        ));
+
+        let tap = render(&results, OutputFormat::Tap, &options);
+        assert!(tap.contains("not ok 3 - files\n# errored: walk failed\n"));
+        assert!(tap.ends_with("ok 4 - owners # SKIP budget exceeded\n"));
+
+        let github = render(&results, OutputFormat::Github, &options);
+        assert!(github.contains("::error title=files::errored: walk failed\n"));
+        assert!(github.ends_with("::warning title=owners::not run (budget exceeded)\n"));
+
+        let csv = render(&results, OutputFormat::Csv, &options);
+        assert!(csv.contains(".github/CODEOWNERS,,,errored,files,errored: walk failed\n"));
+        assert!(csv.ends_with(".github/CODEOWNERS,,,skipped,owners,not run (budget exceeded)\n"));
+    }
+
+    #[test]
+    fn xml_escaping() {
+        assert_eq!(escape_xml("<a & 'b'>"), "&lt;a &amp; &apos;b&apos;&gt;");
+    }
+}
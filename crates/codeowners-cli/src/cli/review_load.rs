@@ -0,0 +1,258 @@
+//! Review load simulation for `codeowners-validator report review-load`.
+//!
+//! Replays a history of merged pull requests against the current CODEOWNERS
+//! file and a proposed one, counting how often each owner would have been
+//! requested for review. Comparing the counts shows how a change shifts
+//! review load before it is merged.
+
+use codeowners_validator_core::CodeownersFile;
+use codeowners_validator_core::review::required_owners;
+use colored::Colorize;
+use octocrab::Octocrab;
+use octocrab::params::{Direction, State, pulls::Sort};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use tracing::debug;
+
+/// Default number of merged pull requests fetched for the simulation.
+pub const DEFAULT_MERGED_PRS: usize = 100;
+
+/// A merged pull request in the review history.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoricalPullRequest {
+    /// The pull request number.
+    pub number: u64,
+    /// Login of the author, who is never requested for review.
+    #[serde(default)]
+    pub author: String,
+    /// Changed paths, relative to the repository root.
+    pub files: Vec<String>,
+}
+
+/// Review requests of one owner under both CODEOWNERS files.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OwnerLoad {
+    /// The owner, lowercased.
+    pub owner: String,
+    /// Pull requests the owner is requested on with the current file.
+    pub current: usize,
+    /// Pull requests the owner would be requested on with the proposed file.
+    pub proposed: usize,
+}
+
+impl OwnerLoad {
+    /// Returns the change in review requests.
+    pub fn change(&self) -> i64 {
+        self.proposed as i64 - self.current as i64
+    }
+}
+
+/// The simulated review load of a history of pull requests.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReviewLoad {
+    /// Number of pull requests replayed.
+    pub pull_requests: usize,
+    /// Owners by the size of their change, largest first.
+    pub owners: Vec<OwnerLoad>,
+}
+
+impl ReviewLoad {
+    /// Counts the review requests of each owner under both files.
+    ///
+    /// Every owner of a changed file is requested once per pull request,
+    /// except the author.
+    pub fn simulate(
+        current: &CodeownersFile,
+        proposed: &CodeownersFile,
+        history: &[HistoricalPullRequest],
+    ) -> Self {
+        let mut counts: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+
+        for pr in history {
+            let files = || pr.files.iter().map(String::as_str);
+            for owner in required_owners(files(), current)
+                .without_author(&pr.author)
+                .owners()
+            {
+                counts.entry(owner).or_default().0 += 1;
+            }
+            for owner in required_owners(files(), proposed)
+                .without_author(&pr.author)
+                .owners()
+            {
+                counts.entry(owner).or_default().1 += 1;
+            }
+        }
+
+        let mut owners: Vec<OwnerLoad> = counts
+            .into_iter()
+            .map(|(owner, (current, proposed))| OwnerLoad {
+                owner,
+                current,
+                proposed,
+            })
+            .collect();
+        // Stable sort keeps owners with equal changes in name order
+        owners.sort_by_key(|load| std::cmp::Reverse(load.change().abs()));
+
+        Self {
+            pull_requests: history.len(),
+            owners,
+        }
+    }
+}
+
+/// Parses a JSON review history: an array of `{"number", "author", "files"}`.
+pub fn parse_history(json: &str) -> serde_json::Result<Vec<HistoricalPullRequest>> {
+    serde_json::from_str(json)
+}
+
+/// Fetches the most recently updated merged pull requests and their files.
+pub async fn fetch_history(
+    client: &Octocrab,
+    owner: &str,
+    repo: &str,
+    count: usize,
+) -> octocrab::Result<Vec<HistoricalPullRequest>> {
+    let pulls = client.pulls(owner, repo);
+    let mut page = pulls
+        .list()
+        .state(State::Closed)
+        .sort(Sort::Updated)
+        .direction(Direction::Descending)
+        .per_page(100u8)
+        .send()
+        .await?;
+
+    let mut merged = Vec::new();
+    loop {
+        merged.extend(
+            page.take_items()
+                .into_iter()
+                .filter(|pr| pr.merged_at.is_some()),
+        );
+        if merged.len() >= count {
+            break;
+        }
+        match client.get_page(&page.next).await? {
+            Some(next) => page = next,
+            None => break,
+        }
+    }
+    merged.truncate(count);
+
+    let mut history = Vec::with_capacity(merged.len());
+    for pr in merged {
+        let files = client.all_pages(pulls.list_files(pr.number).await?).await?;
+        debug!(
+            "Pull request #{}: {} changed file(s)",
+            pr.number,
+            files.len()
+        );
+        history.push(HistoricalPullRequest {
+            number: pr.number,
+            author: pr.user.map(|user| user.login).unwrap_or_default(),
+            files: files.into_iter().map(|entry| entry.filename).collect(),
+        });
+    }
+    Ok(history)
+}
+
+/// Renders the review load as a table, one row per owner.
+pub fn render_human(load: &ReviewLoad, use_colors: bool) -> String {
+    let mut output = format!(
+        "Review requests across {} pull request(s), current vs proposed CODEOWNERS\n",
+        load.pull_requests
+    );
+    if load.owners.is_empty() {
+        output.push_str("No owner would be requested for review\n");
+        return output;
+    }
+
+    let width = load
+        .owners
+        .iter()
+        .map(|load| load.owner.len())
+        .max()
+        .unwrap_or_default()
+        .max("OWNER".len());
+    let _ = writeln!(
+        output,
+        "{:<width$}  {:>8}  {:>8}  {:>8}",
+        "OWNER", "CURRENT", "PROPOSED", "CHANGE"
+    );
+    for owner in &load.owners {
+        let change = match owner.change() {
+            0 => format!("{:>8}", 0),
+            delta if !use_colors => format!("{:>+8}", delta),
+            delta if delta > 0 => format!("{:>+8}", delta).red().to_string(),
+            delta => format!("{:>+8}", delta).green().to_string(),
+        };
+        let _ = writeln!(
+            output,
+            "{:<width$}  {:>8}  {:>8}  {}",
+            owner.owner, owner.current, owner.proposed, change
+        );
+    }
+    output
+}
+
+/// Renders the review load as pretty-printed JSON.
+pub fn render_json(load: &ReviewLoad) -> String {
+    serde_json::to_string_pretty(load).expect("review load is always serializable")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codeowners_validator_core::parse::parse_codeowners;
+
+    fn history() -> Vec<HistoricalPullRequest> {
+        parse_history(
+            r#"[
+                {"number": 1, "author": "alice", "files": ["src/lib.rs", "docs/guide.md"]},
+                {"number": 2, "author": "bob", "files": ["docs/api.md"]},
+                {"number": 3, "files": ["src/main.rs"]}
+            ]"#,
+        )
+        .unwrap()
+    }
+
+    fn simulate() -> ReviewLoad {
+        let current = parse_codeowners("* @org/core\n/docs/ @org/core @alice\n").ast;
+        let proposed = parse_codeowners("* @org/core\n/docs/ @org/docs\n").ast;
+        ReviewLoad::simulate(&current, &proposed, &history())
+    }
+
+    #[test]
+    fn counts_requests_per_owner() {
+        let load = simulate();
+        assert_eq!(load.pull_requests, 3);
+
+        let counts: Vec<_> = load
+            .owners
+            .iter()
+            .map(|load| (load.owner.as_str(), load.current, load.proposed))
+            .collect();
+        // alice is not requested on her own pull request
+        assert_eq!(
+            counts,
+            [("@org/docs", 0, 2), ("@alice", 1, 0), ("@org/core", 3, 2),]
+        );
+    }
+
+    #[test]
+    fn table_rendering() {
+        assert_eq!(
+            render_human(&simulate(), false),
+            "Review requests across 3 pull request(s), current vs proposed CODEOWNERS\n\
+             OWNER       CURRENT  PROPOSED    CHANGE\n\
+             @org/docs         0         2        +2\n\
+             @alice            1         0        -1\n\
+             @org/core         3         2        -1\n"
+        );
+        let json: serde_json::Value = serde_json::from_str(&render_json(&simulate())).unwrap();
+        assert_eq!(json["owners"][0]["owner"], "@org/docs");
+    }
+}
@@ -0,0 +1,204 @@
+//! Ownership simulation for `codeowners-validator simulate-path`.
+//!
+//! Reports which CODEOWNERS rule would own each given path, and the
+//! escalation policy that rule is annotated with. The paths do not need to
+//! exist, which helps when planning new files and directories.
+
+use codeowners_validator_core::CodeownersFile;
+use codeowners_validator_core::matching::{RuleSet, normalize_repo_path};
+use codeowners_validator_core::parse::{Escalation, ParserConfig};
+use colored::Colorize;
+use serde::Serialize;
+use std::fmt::Write;
+
+/// The simulated ownership of a single path.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SimulatedPath {
+    /// The path, relative to the repository root.
+    pub path: String,
+    /// Whether the path would have at least one owner.
+    pub owned: bool,
+    /// Pattern of the rule that would own the path.
+    pub pattern: Option<String>,
+    /// Line number of that rule.
+    pub line: Option<usize>,
+    /// Owners assigned by that rule.
+    pub owners: Vec<String>,
+    /// The rule's `escalation` annotation, if it has one.
+    pub escalation: Option<Escalation>,
+}
+
+/// Returns the parser configuration for simulation.
+///
+/// Rules without owners are kept, since on GitHub they remove ownership from
+/// the paths they match, and inline comments are kept for their annotations.
+pub fn parser_config() -> ParserConfig {
+    ParserConfig::new()
+        .with_allow_unowned_patterns(true)
+        .with_inline_comments(true)
+}
+
+/// Simulates ownership of each path against the CODEOWNERS rules.
+pub fn simulate(file: &CodeownersFile, paths: &[String]) -> Vec<SimulatedPath> {
+    let rules = RuleSet::from_file(file);
+    let escalations = file.escalations();
+    paths
+        .iter()
+        .map(|path| {
+            let path = normalize_repo_path(path).into_owned();
+            match rules.last_match(&path) {
+                Some(rule) => SimulatedPath {
+                    owned: rule.is_owned(),
+                    pattern: Some(rule.pattern.text.clone()),
+                    line: Some(rule.line.span.line),
+                    owners: rule.owners.iter().map(ToString::to_string).collect(),
+                    escalation: escalations.get(&rule.line.span.line).cloned(),
+                    path,
+                },
+                None => SimulatedPath {
+                    path,
+                    owned: false,
+                    pattern: None,
+                    line: None,
+                    owners: Vec::new(),
+                    escalation: None,
+                },
+            }
+        })
+        .collect()
+}
+
+/// Renders simulation results for the terminal.
+pub fn render_human(results: &[SimulatedPath], use_colors: bool) -> String {
+    let mut output = String::new();
+
+    for result in results {
+        let path = if use_colors {
+            result.path.bold().to_string()
+        } else {
+            result.path.clone()
+        };
+        let ownership = match (&result.pattern, result.line) {
+            (Some(pattern), Some(line)) if result.owned => format!(
+                "owned by {} (rule '{}' on line {})",
+                result.owners.join(" "),
+                pattern,
+                line
+            ),
+            (Some(pattern), Some(line)) => format!(
+                "unowned (rule '{}' on line {} has no owners)",
+                pattern, line
+            ),
+            _ => "unowned (no matching rule)".to_string(),
+        };
+        let ownership = match (use_colors, result.owned) {
+            (true, true) => ownership.green().to_string(),
+            (true, false) => ownership.yellow().to_string(),
+            (false, _) => ownership,
+        };
+        let _ = writeln!(output, "{}: {}", path, ownership);
+    }
+
+    output
+}
+
+/// Renders simulation results as pretty-printed JSON.
+pub fn render_json(results: &[SimulatedPath]) -> String {
+    serde_json::to_string_pretty(results).expect("simulation results are always serializable")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codeowners_validator_core::parse::parse_codeowners_with_config;
+
+    const CODEOWNERS: &str = "* @default\n/services/ @org/platform\n/services/legacy/\n";
+
+    fn run(paths: &[&str]) -> Vec<SimulatedPath> {
+        let file = parse_codeowners_with_config(CODEOWNERS, &parser_config()).ast;
+        let paths: Vec<String> = paths.iter().map(|p| p.to_string()).collect();
+        simulate(&file, &paths)
+    }
+
+    #[test]
+    fn simulates_owned_and_unowned_paths() {
+        let results = run(&["./services/new/main.go", "services/legacy/old.go"]);
+
+        assert_eq!(
+            results[0],
+            SimulatedPath {
+                path: "services/new/main.go".to_string(),
+                owned: true,
+                pattern: Some("/services/".to_string()),
+                line: Some(2),
+                owners: vec!["@org/platform".to_string()],
+                escalation: None,
+            }
+        );
+        assert!(!results[1].owned);
+        assert_eq!(results[1].line, Some(3));
+    }
+
+    #[test]
+    fn unmatched_path_is_unowned() {
+        let file = parse_codeowners_with_config("/docs/ @docs\n", &parser_config()).ast;
+        let results = simulate(&file, &["src/lib.rs".to_string()]);
+        assert!(!results[0].owned);
+        assert_eq!(results[0].pattern, None);
+    }
+
+    #[test]
+    fn human_rendering() {
+        let mut results = run(&["services/api/", "services/legacy/x.go"]);
+        results.push(SimulatedPath {
+            path: "nowhere".to_string(),
+            owned: false,
+            pattern: None,
+            line: None,
+            owners: Vec::new(),
+            escalation: None,
+        });
+        assert_eq!(
+            render_human(&results, false),
+            "services/api/: owned by @org/platform (rule '/services/' on line 2)\n\
+             services/legacy/x.go: unowned (rule '/services/legacy/' on line 3 has no owners)\n\
+             nowhere: unowned (no matching rule)\n"
+        );
+    }
+
+    #[test]
+    fn json_rendering() {
+        let json: serde_json::Value =
+            serde_json::from_str(&render_json(&run(&["README.md"]))).unwrap();
+        assert_eq!(json[0]["path"], "README.md");
+        assert_eq!(json[0]["owned"], true);
+        assert_eq!(json[0]["owners"][0], "@default");
+        assert_eq!(json[0]["escalation"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn reports_escalation_policy() {
+        let file = parse_codeowners_with_config(
+            "# escalation: pagerduty:platform
+/services/ @org/platform
+/docs/ @docs # escalation: opsgenie:docs
+",
+            &parser_config(),
+        )
+        .ast;
+        let results = simulate(
+            &file,
+            &["services/api.go".to_string(), "docs/a.md".to_string()],
+        );
+        let target = results[0].escalation.as_ref().unwrap().target.as_ref();
+        assert_eq!(target.unwrap().to_string(), "pagerduty:platform");
+        assert_eq!(
+            results[1].escalation.as_ref().unwrap().value,
+            "opsgenie:docs"
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&render_json(&results)).unwrap();
+        assert_eq!(json[0]["escalation"]["target"]["provider"], "pagerduty");
+        assert_eq!(json[0]["escalation"]["target"]["policy"], "platform");
+    }
+}
@@ -0,0 +1,180 @@
+//! Build target ownership for `codeowners-validator report targets`.
+//!
+//! Maps CODEOWNERS rules onto the source files of monorepo build targets,
+//! e.g. from a Bazel or Buck query, and reports the owners of each target.
+//! A target whose sources have different owner lists spans several owner
+//! domains: changing it needs reviews from all of them, which usually means
+//! the target should be split or the rules aligned.
+
+use codeowners_validator_core::CodeownersFile;
+use codeowners_validator_core::matching::OwnersResolver;
+use colored::Colorize;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+/// The owners of one build target.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct TargetOwners {
+    /// The target label, e.g. `//src/core:lib`.
+    pub target: String,
+    /// The distinct owner lists of the target's owned sources, sorted. Each
+    /// list is one owner domain.
+    pub domains: BTreeSet<Vec<String>>,
+    /// Sources of the target that no rule owns.
+    pub unowned: Vec<String>,
+}
+
+impl TargetOwners {
+    /// Returns true if the target's sources have more than one owner list.
+    pub fn spans_domains(&self) -> bool {
+        self.domains.len() > 1
+    }
+}
+
+/// The owners of every build target.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct TargetReport {
+    /// Targets, sorted by label.
+    pub targets: Vec<TargetOwners>,
+}
+
+impl TargetReport {
+    /// Builds the report from build targets mapped to their source paths,
+    /// relative to the repository root.
+    pub fn build(file: &CodeownersFile, targets: &BTreeMap<String, Vec<String>>) -> Self {
+        let resolver = OwnersResolver::from_file(file);
+        let targets = targets
+            .iter()
+            .map(|(target, sources)| {
+                let mut owners = TargetOwners {
+                    target: target.clone(),
+                    ..Default::default()
+                };
+                for source in sources {
+                    let path = source.trim_start_matches("./").trim_start_matches('/');
+                    match resolver.owners_for(path) {
+                        Some(list) => {
+                            owners.domains.insert(
+                                list.iter()
+                                    .map(|owner| owner.as_str().into_owned())
+                                    .collect(),
+                            );
+                        }
+                        None => owners.unowned.push(source.clone()),
+                    }
+                }
+                owners
+            })
+            .collect();
+        Self { targets }
+    }
+
+    /// Returns the targets spanning more than one owner domain.
+    pub fn spanning(&self) -> impl Iterator<Item = &TargetOwners> {
+        self.targets.iter().filter(|target| target.spans_domains())
+    }
+}
+
+/// Parses a JSON object mapping build target labels to the paths of their
+/// source files.
+pub fn parse_targets(json: &str) -> serde_json::Result<BTreeMap<String, Vec<String>>> {
+    serde_json::from_str(json)
+}
+
+/// Renders the report as a list of targets with their owners.
+pub fn render_human(report: &TargetReport, use_colors: bool) -> String {
+    let mut output = String::from("Owners of each build target\n");
+    if report.targets.is_empty() {
+        output.push_str("No build targets\n");
+        return output;
+    }
+
+    let width = report
+        .targets
+        .iter()
+        .map(|target| target.target.len())
+        .max()
+        .unwrap_or_default();
+    for target in &report.targets {
+        let domains: Vec<_> = target
+            .domains
+            .iter()
+            .map(|owners| owners.join(" "))
+            .collect();
+        let mut row = if domains.is_empty() {
+            "no owners".to_string()
+        } else {
+            domains.join(" | ")
+        };
+        if !target.unowned.is_empty() {
+            let _ = write!(row, " ({} unowned source(s))", target.unowned.len());
+        }
+        if target.spans_domains() {
+            let spans = format!("[spans {} owner domains]", target.domains.len());
+            let spans = if use_colors {
+                spans.yellow().to_string()
+            } else {
+                spans
+            };
+            row = format!("{} {}", row, spans);
+        }
+        let _ = writeln!(output, "{:<width$}  {}", target.target, row);
+    }
+
+    let spanning = report.spanning().count();
+    if spanning > 0 {
+        let _ = writeln!(output, "{} target(s) span multiple owner domains", spanning);
+    }
+    output
+}
+
+/// Renders the report as pretty-printed JSON.
+pub fn render_json(report: &TargetReport) -> String {
+    serde_json::to_string_pretty(report).expect("target report is always serializable")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codeowners_validator_core::parse::parse_codeowners;
+
+    fn report() -> TargetReport {
+        let file = parse_codeowners("/src/core/ @org/core\n/src/ops/ @org/ops @carol\n").ast;
+        let targets = parse_targets(
+            r#"{
+                "//src/core:lib": ["src/core/lib.rs", "src/core/util.rs"],
+                "//src:all": ["src/core/lib.rs", "./src/ops/deploy.rs", "BUILD"]
+            }"#,
+        )
+        .unwrap();
+        TargetReport::build(&file, &targets)
+    }
+
+    #[test]
+    fn finds_targets_spanning_owner_domains() {
+        let report = report();
+        let spanning: Vec<_> = report.spanning().map(|t| t.target.as_str()).collect();
+        assert_eq!(spanning, ["//src:all"]);
+
+        let all = &report.targets[1];
+        assert_eq!(all.unowned, ["BUILD"]);
+        assert_eq!(
+            all.domains.iter().cloned().collect::<Vec<_>>(),
+            [vec!["@org/core"], vec!["@org/ops", "@carol"]]
+        );
+    }
+
+    #[test]
+    fn table_rendering() {
+        assert_eq!(
+            render_human(&report(), false),
+            "Owners of each build target\n\
+             //src/core:lib  @org/core\n\
+             //src:all       @org/core | @org/ops @carol (1 unowned source(s)) [spans 2 owner domains]\n\
+             1 target(s) span multiple owner domains\n"
+        );
+        let json: serde_json::Value = serde_json::from_str(&render_json(&report())).unwrap();
+        assert_eq!(json["targets"][1]["domains"][0][0], "@org/core");
+    }
+}
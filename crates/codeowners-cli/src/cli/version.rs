@@ -0,0 +1,86 @@
+//! Version and build metadata output.
+//!
+//! `--version` prints the usual one-line version string; combined with
+//! `--json` it prints the build metadata recorded by the build script so
+//! container images and CI steps can record the provenance of the binary.
+
+use serde::Serialize;
+
+/// Metadata describing how this binary was built.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BuildInfo {
+    /// Binary name.
+    pub name: &'static str,
+    /// Package version.
+    pub version: &'static str,
+    /// Git commit the binary was built from, if known.
+    pub git_commit: Option<&'static str>,
+    /// Target triple, e.g. `x86_64-unknown-linux-musl`.
+    pub target: &'static str,
+    /// Cargo profile the binary was built with, e.g. `debug`, `release` or
+    /// `dist`.
+    pub profile: &'static str,
+    /// Enabled optional cargo features.
+    pub features: Vec<&'static str>,
+    /// TLS implementation used for GitHub API requests.
+    pub tls: &'static str,
+}
+
+impl BuildInfo {
+    /// Returns the metadata of the running binary.
+    pub fn current() -> Self {
+        Self {
+            name: "codeowners-validator",
+            version: env!("CARGO_PKG_VERSION"),
+            git_commit: option_env!("CODEOWNERS_GIT_COMMIT"),
+            target: env!("CODEOWNERS_BUILD_TARGET"),
+            profile: env!("CODEOWNERS_BUILD_PROFILE"),
+            features: Self::features(),
+            tls: "rustls",
+        }
+    }
+
+    fn features() -> Vec<&'static str> {
+        let mut features = Vec::new();
+        if cfg!(feature = "generate") {
+            features.push("generate");
+        }
+        features
+    }
+
+    /// Renders the one-line human-readable version.
+    pub fn to_human(&self) -> String {
+        format!("{} {}", self.name, self.version)
+    }
+
+    /// Renders the metadata as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("build info is always serializable")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_info_json() {
+        let info = BuildInfo::current();
+        assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+        assert!(!info.target.is_empty());
+
+        let json: serde_json::Value = serde_json::from_str(&info.to_json()).unwrap();
+        assert_eq!(json["name"], "codeowners-validator");
+        assert_eq!(json["tls"], "rustls");
+        assert!(json["features"].is_array());
+        assert!(json.get("git_commit").is_some());
+    }
+
+    #[test]
+    fn build_info_human() {
+        assert_eq!(
+            BuildInfo::current().to_human(),
+            format!("codeowners-validator {}", env!("CARGO_PKG_VERSION"))
+        );
+    }
+}
@@ -4,32 +4,85 @@
 
 use clap::Parser;
 use std::io::{self, IsTerminal, Write};
+use std::path::Path;
 use std::process::ExitCode as StdExitCode;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 use tokio::signal;
 use tracing::{Level, debug, error, info, warn};
 use tracing_subscriber::EnvFilter;
 
 mod cli;
 
-use cli::config::{ExitCode, ValidatedConfig, create_octocrab};
+use cli::blame;
+use cli::config::{ExitCode, ValidatedConfig, create_octocrab, find_codeowners_file};
+use cli::doctor::run_doctor;
+use cli::effective_owners::{self, EffectiveOwners};
+use cli::export;
+use cli::fix;
+use cli::generated::{self, RegionState};
 use cli::github::OctocrabClient;
-use cli::output::{HumanOutput, ValidationResults};
-use cli::{Args, CheckKind, ExperimentalCheckKind};
-use codeowners_validator_core::parse::parse_codeowners;
+use cli::graph::{self, OwnershipGraph};
+use cli::merge::{self, MergeReadiness};
+use cli::output::{BUDGET_EXCEEDED, HumanOutput, ValidationResults};
+use cli::owned::{self, OwnedFiles};
+use cli::patch;
+use cli::redact::{RedactingMakeWriter, Redactor};
+use cli::render::{RenderOptions, render};
+use cli::review_load::{self, ReviewLoad};
+use cli::simulate::{parser_config, render_human, render_json, simulate};
+use cli::targets::{self, TargetReport};
+use cli::version::BuildInfo;
+use cli::{
+    Args, CheckKind, Command, CsvRows, ExperimentalCheckKind, FailureLevel, GraphFormat,
+    OutputFormat, ReportCommand,
+};
+use codeowners_validator_core::format::{FormatOptions, format_codeowners};
+use codeowners_validator_core::matching;
+use codeowners_validator_core::parse::{
+    ParserConfig, parse_codeowners, parse_codeowners_with_config,
+};
+use codeowners_validator_core::patch::{codeowners_patch, parse_patch};
+use codeowners_validator_core::review::required_owners;
+#[cfg(feature = "plugins")]
+use codeowners_validator_core::validate::checks::Check;
 use codeowners_validator_core::validate::checks::{
-    AvoidShadowingCheck, Check, CheckContext, DupPatternsCheck, FilesCheck, NotOwnedCheck,
-    SyntaxCheck,
+    AnchoringCheck, AnnotationsCheck, AvoidShadowingCheck, CaseCollisionCheck, CheckContext,
+    ComplexityCheck, ComplexityStats, DupPatternsCheck, EscalationCheck, ExtensionOverrideCheck,
+    FilesCheck, GeneratedRegionsCheck, GitLabSectionsCheck, IdpGroupsCheck, MaintainabilityCheck,
+    NotOwnedCheck, OwnersLockCheck, OwnershipManifestCheck, ReviewByCheck, SelfOwnershipCheck,
+    SyntaxCheck, run_isolated,
 };
+use codeowners_validator_core::validate::file_walker::{
+    FileWalkerConfig, explain_skips, list_files,
+};
+use codeowners_validator_core::validate::github_client::CachingGithubClient;
+use codeowners_validator_core::validate::{Fix, applicable_fixes, apply_fixes};
+use codeowners_validator_core::vcs::detect_vcs;
 
 #[tokio::main]
 async fn main() -> StdExitCode {
     // Parse command-line arguments
     let args = Args::parse();
 
-    // Initialize tracing
-    init_tracing(args.verbose, args.json);
+    if args.version {
+        let info = BuildInfo::current();
+        if args.effective_format() == OutputFormat::Json {
+            println!("{}", info.to_json());
+        } else {
+            println!("{}", info.to_human());
+        }
+        return StdExitCode::SUCCESS;
+    }
+
+    // Initialize tracing, keeping credentials out of log output
+    let redactor = Arc::new(Redactor::from_args(&args));
+    init_tracing(
+        args.verbose,
+        args.effective_format().is_machine_readable(),
+        Arc::clone(&redactor),
+    );
 
     // Set up signal handling for graceful shutdown
     let terminated = Arc::new(AtomicBool::new(false));
@@ -60,7 +113,7 @@ async fn main() -> StdExitCode {
     });
 
     // Run the validator
-    let exit_code = run(args, &terminated).await;
+    let exit_code = run(args, &redactor, &terminated).await;
 
     // Check if we were terminated by signal
     if terminated.load(Ordering::SeqCst) {
@@ -71,9 +124,9 @@ async fn main() -> StdExitCode {
 }
 
 /// Initialize tracing based on verbosity level.
-fn init_tracing(verbosity: u8, json_output: bool) {
-    // Don't output logs when using JSON output mode
-    if json_output {
+fn init_tracing(verbosity: u8, machine_readable: bool, redactor: Arc<Redactor>) {
+    // Don't output logs when producing machine-readable output
+    if machine_readable {
         return;
     }
 
@@ -94,32 +147,114 @@ fn init_tracing(verbosity: u8, json_output: bool) {
         .with_env_filter(filter)
         .with_target(false)
         .with_ansi(io::stderr().is_terminal())
+        .with_writer(RedactingMakeWriter::new(redactor))
         .init();
 }
 
 /// Run the validator with the given arguments.
-async fn run(args: Args, terminated: &AtomicBool) -> ExitCode {
+async fn run(args: Args, redactor: &Redactor, terminated: &AtomicBool) -> ExitCode {
+    match &args.command {
+        Some(Command::Doctor) => return doctor(&args, redactor).await,
+        Some(Command::SimulatePath {
+            paths,
+            require_owner,
+        }) => return simulate_paths(&args, paths, *require_owner),
+        Some(Command::VerifyGenerated { update_hash }) => {
+            return verify_generated(&args, *update_hash);
+        }
+        Some(Command::Fmt { check, align }) => return fmt(&args, *check, *align),
+        Some(Command::FilesForOwner { owner }) => return files_for_owner(&args, owner),
+        Some(Command::Blame { path }) => return blame_report(&args, path),
+        Some(Command::CanMerge { pr }) => return can_merge(&args, *pr, redactor).await,
+        Some(Command::ValidatePatch {
+            patch,
+            base,
+            base_ref,
+            changed_only,
+        }) => {
+            return validate_patch(
+                &args,
+                patch,
+                base.as_deref(),
+                base_ref.as_deref(),
+                *changed_only,
+                redactor,
+            )
+            .await;
+        }
+        Some(Command::MovePrefix { from, to, dry_run }) => {
+            return move_prefix(&args, from, to, *dry_run);
+        }
+        Some(Command::Report {
+            report:
+                ReportCommand::Graph {
+                    depth,
+                    graph_format,
+                },
+        }) => return graph_report(&args, *depth, *graph_format),
+        Some(Command::Report {
+            report: ReportCommand::Csv { rows },
+        }) => return export_csv(&args, *rows),
+        Some(Command::Report {
+            report:
+                ReportCommand::ReviewLoad {
+                    proposed,
+                    history,
+                    merged_prs,
+                },
+        }) => {
+            return review_load_report(&args, proposed, history.as_deref(), *merged_prs, redactor)
+                .await;
+        }
+        Some(Command::Report {
+            report: ReportCommand::EffectiveOwners { depth },
+        }) => return effective_owners_report(&args, *depth, redactor).await,
+        Some(Command::Report {
+            report: ReportCommand::Targets { targets_file },
+        }) => return targets_report(&args, targets_file),
+        None => {}
+    }
+
+    if args.emit_ast {
+        return emit_ast(&args);
+    }
+
+    let started = Instant::now();
     let mut stdout = io::stdout().lock();
     let mut stderr = io::stderr().lock();
 
     // Validate configuration
-    let config = match ValidatedConfig::from_args(&args) {
+    let mut config = match ValidatedConfig::from_args(&args) {
         Ok(config) => config,
         Err(e) => {
-            let use_colors = !args.json && io::stdout().is_terminal();
-            write_error(&mut stderr, &e.to_string(), use_colors);
+            let use_colors =
+                !args.effective_format().is_machine_readable() && io::stdout().is_terminal();
+            write_error(&mut stderr, &redactor.redact(&e.to_string()), use_colors);
             return ExitCode::StartupFailure;
         }
     };
 
-    let use_colors = !config.json_output && io::stdout().is_terminal();
+    let use_colors = !config.output_format.is_machine_readable() && io::stdout().is_terminal();
+    let render_options = RenderOptions::new(
+        config
+            .codeowners_path
+            .strip_prefix(&config.repo_path)
+            .unwrap_or(&config.codeowners_path)
+            .to_string_lossy()
+            .replace('\\', "/"),
+    )
+    .with_colors(use_colors);
 
     debug!("Validated configuration: {:?}", config);
     info!("Repository path: {}", config.repo_path.display());
     info!("CODEOWNERS file: {}", config.codeowners_path.display());
 
     // Read and parse CODEOWNERS file
-    let codeowners_content = match std::fs::read_to_string(&config.codeowners_path) {
+    let codeowners_content = match config
+        .codeowners_content
+        .clone()
+        .map_or_else(|| std::fs::read_to_string(&config.codeowners_path), Ok)
+    {
         Ok(content) => content,
         Err(e) => {
             write_error(
@@ -135,11 +270,33 @@ async fn run(args: Args, terminated: &AtomicBool) -> ExitCode {
         }
     };
 
-    let parse_result = parse_codeowners(&codeowners_content);
+    let render_options = render_options.with_source(&codeowners_content);
+
+    let syntax = config.check_config.pattern_syntax;
+    let parser_config = ParserConfig::new()
+        .with_dialect(syntax.into())
+        .with_pattern_syntax(syntax);
+    let parse_result = parse_codeowners_with_config(&codeowners_content, &parser_config);
 
     if !parse_result.is_ok() {
+        // Parse errors that carry fixes (e.g. line continuations) can still be repaired
+        if config.fix || config.fix_dry_run {
+            let fixes: Vec<_> = parse_result.errors.iter().filter_map(|e| e.fix()).collect();
+            if !fixes.is_empty()
+                && let Err(code) = write_fixes(
+                    &config,
+                    &codeowners_content,
+                    &fixes,
+                    &mut stderr,
+                    use_colors,
+                )
+            {
+                return code;
+            }
+        }
+
         // Report parse errors
-        if config.json_output {
+        if config.output_format.is_machine_readable() {
             let mut results = ValidationResults::new();
             let validation_result = codeowners_validator_core::ValidationResult::new();
             for error in &parse_result.errors {
@@ -147,8 +304,9 @@ async fn run(args: Args, terminated: &AtomicBool) -> ExitCode {
                 warn!("Parse error: {}", error);
             }
             results.add("parse", validation_result);
-            if let Err(e) = results.write_json(&mut stdout) {
-                error!("Failed to write JSON output: {}", e);
+            let rendered = render(&results, config.output_format, &render_options);
+            if let Err(e) = write!(stdout, "{}", rendered) {
+                error!("Failed to write output: {}", e);
             }
         } else {
             let mut output = HumanOutput::new(&mut stderr, use_colors);
@@ -165,12 +323,48 @@ async fn run(args: Args, terminated: &AtomicBool) -> ExitCode {
         return ExitCode::Terminated;
     }
 
+    // Pin the lockfile's paths to their current owners
+    if config.update_owners_lock
+        && let (Some(path), Some(lock)) = (
+            &config.owners_lock_path,
+            config.check_config.owners_lock.as_mut(),
+        )
+    {
+        let resolver = matching::OwnersResolver::with_syntax(
+            &parse_result.ast,
+            config.check_config.question_mark,
+            config.check_config.pattern_syntax,
+        );
+        let changed = lock.update(&resolver);
+        if let Err(e) = std::fs::write(path, lock.to_json()) {
+            write_error(
+                &mut stderr,
+                &format!(
+                    "Failed to write owners lockfile '{}': {}",
+                    path.display(),
+                    e
+                ),
+                use_colors,
+            );
+            return ExitCode::StartupFailure;
+        }
+        if !config.output_format.is_machine_readable() {
+            let _ = writeln!(
+                stderr,
+                "Updated owners lockfile {}: {} of {} path(s) changed",
+                path.display(),
+                changed,
+                lock.len()
+            );
+        }
+    }
+
     // Create GitHub client if needed
     let octocrab = if config.checks.contains(&CheckKind::Owners) {
         match create_octocrab(&args).await {
             Ok(client) => client.map(OctocrabClient::new),
             Err(e) => {
-                write_error(&mut stderr, &e.to_string(), use_colors);
+                write_error(&mut stderr, &redactor.redact(&e.to_string()), use_colors);
                 return ExitCode::StartupFailure;
             }
         }
@@ -180,32 +374,46 @@ async fn run(args: Args, terminated: &AtomicBool) -> ExitCode {
 
     // Run validation checks
     let mut results = ValidationResults::new();
+    results.set_file(parse_result.ast.clone());
     let ctx = CheckContext::new(&parse_result.ast, &config.repo_path, &config.check_config);
 
+    // Time left in the budget, if any; `Some(ZERO)` once it has run out
+    let remaining = || {
+        config
+            .time_budget
+            .map(|budget| budget.saturating_sub(started.elapsed()))
+    };
+
     // Run standard checks
     for check_kind in &config.checks {
         if terminated.load(Ordering::SeqCst) {
             return ExitCode::Terminated;
         }
+        if remaining() == Some(Duration::ZERO) {
+            warn!("Time budget exceeded, skipping {} check", check_kind.name());
+            results.add_skipped(check_kind.name(), BUDGET_EXCEEDED);
+            stream_check(&mut stdout, &results, check_kind.name(), &config);
+            continue;
+        }
 
         let (name, result) = match check_kind {
             CheckKind::Syntax => {
                 info!("Running syntax check...");
-                ("syntax", SyntaxCheck::new().run(&ctx))
+                ("syntax", run_isolated(&SyntaxCheck::new(), &ctx))
             }
             CheckKind::Duppatterns => {
                 info!("Running duplicate patterns check...");
-                ("duppatterns", DupPatternsCheck::new().run(&ctx))
+                ("duppatterns", run_isolated(&DupPatternsCheck::new(), &ctx))
             }
             CheckKind::Files => {
                 info!("Running files check...");
-                ("files", FilesCheck::new().run(&ctx))
+                ("files", run_isolated(&FilesCheck::new(), &ctx))
             }
             CheckKind::Owners => {
                 if let Some(ref octo) = octocrab {
                     info!("Running owners check...");
                     use codeowners_validator_core::validate::checks::{
-                        AsyncCheck, AsyncCheckContext, OwnersCheck,
+                        AsyncCheckContext, OwnersCheck, run_isolated_async,
                     };
                     let async_ctx = AsyncCheckContext::new(
                         &parse_result.ast,
@@ -213,16 +421,36 @@ async fn run(args: Args, terminated: &AtomicBool) -> ExitCode {
                         &config.check_config,
                         octo,
                     );
-                    ("owners", OwnersCheck::new().run(&async_ctx).await)
+                    let check = OwnersCheck::new();
+                    let run = run_isolated_async(&check, &async_ctx);
+                    let result = match remaining() {
+                        Some(remaining) => tokio::time::timeout(remaining, run).await,
+                        None => Ok(run.await),
+                    };
+                    match result {
+                        Ok(result) => ("owners", result),
+                        Err(_) => {
+                            warn!("Time budget exceeded while running owners check");
+                            results.add_skipped("owners", BUDGET_EXCEEDED);
+                            stream_check(&mut stdout, &results, "owners", &config);
+                            continue;
+                        }
+                    }
                 } else {
                     warn!("Skipping owners check: no GitHub authentication configured");
+                    results.add_skipped("owners", "no GitHub authentication configured");
+                    stream_check(&mut stdout, &results, "owners", &config);
                     continue;
                 }
             }
         };
 
+        let mut result = result;
+        result.suppress(&config.check_config.suppressed_codes);
+        result.override_severities(&config.check_config.severity_overrides);
         debug!("Check '{}' found {} issue(s)", name, result.errors.len());
         results.add(name, result);
+        stream_check(&mut stdout, &results, name, &config);
     }
 
     // Run experimental checks
@@ -230,37 +458,1179 @@ async fn run(args: Args, terminated: &AtomicBool) -> ExitCode {
         if terminated.load(Ordering::SeqCst) {
             return ExitCode::Terminated;
         }
+        if remaining() == Some(Duration::ZERO) {
+            warn!("Time budget exceeded, skipping {} check", check_kind.name());
+            results.add_skipped(check_kind.name(), BUDGET_EXCEEDED);
+            stream_check(&mut stdout, &results, check_kind.name(), &config);
+            continue;
+        }
 
         let (name, result) = match check_kind {
             ExperimentalCheckKind::Notowned => {
                 info!("Running not-owned check (experimental)...");
-                ("notowned", NotOwnedCheck::new().run(&ctx))
+                if config.explain_skips {
+                    write_skips(&config, &mut stderr);
+                }
+                ("notowned", run_isolated(&NotOwnedCheck::new(), &ctx))
             }
             ExperimentalCheckKind::AvoidShadowing => {
                 info!("Running avoid-shadowing check (experimental)...");
-                ("avoid-shadowing", AvoidShadowingCheck::new().run(&ctx))
+                (
+                    "avoid-shadowing",
+                    run_isolated(&AvoidShadowingCheck::new(), &ctx),
+                )
+            }
+            ExperimentalCheckKind::CaseCollision => {
+                info!("Running case-collision check (experimental)...");
+                (
+                    "case-collision",
+                    run_isolated(&CaseCollisionCheck::new(), &ctx),
+                )
+            }
+            ExperimentalCheckKind::GeneratedRegions => {
+                info!("Running generated-regions check (experimental)...");
+                (
+                    "generated-regions",
+                    run_isolated(&GeneratedRegionsCheck::new(), &ctx),
+                )
+            }
+            ExperimentalCheckKind::IdpGroups => {
+                info!("Running idp-groups check (experimental)...");
+                ("idp-groups", run_isolated(&IdpGroupsCheck::new(), &ctx))
+            }
+            ExperimentalCheckKind::ReviewBy => {
+                info!("Running review-by check (experimental)...");
+                ("review-by", run_isolated(&ReviewByCheck::new(), &ctx))
+            }
+            ExperimentalCheckKind::ExtensionOverride => {
+                info!("Running extension-override check (experimental)...");
+                (
+                    "extension-override",
+                    run_isolated(&ExtensionOverrideCheck::new(), &ctx),
+                )
+            }
+            ExperimentalCheckKind::Anchoring => {
+                info!("Running anchoring check (experimental)...");
+                ("anchoring", run_isolated(&AnchoringCheck::new(), &ctx))
+            }
+            ExperimentalCheckKind::GitlabSections => {
+                info!("Running gitlab-sections check (experimental)...");
+                (
+                    "gitlab-sections",
+                    run_isolated(&GitLabSectionsCheck::new(), &ctx),
+                )
+            }
+            ExperimentalCheckKind::Maintainability => {
+                info!("Running maintainability check (experimental)...");
+                (
+                    "maintainability",
+                    run_isolated(&MaintainabilityCheck::new(), &ctx),
+                )
+            }
+            ExperimentalCheckKind::Complexity => {
+                info!("Running complexity check (experimental)...");
+                results.set_stats(ComplexityStats::of(&parse_result.ast));
+                ("complexity", run_isolated(&ComplexityCheck::new(), &ctx))
+            }
+            ExperimentalCheckKind::OwnershipManifest => {
+                info!("Running ownership-manifest check (experimental)...");
+                (
+                    "ownership-manifest",
+                    run_isolated(&OwnershipManifestCheck::new(), &ctx),
+                )
+            }
+            ExperimentalCheckKind::SelfOwnership => {
+                info!("Running self-ownership check (experimental)...");
+                (
+                    "self-ownership",
+                    run_isolated(&SelfOwnershipCheck::new(), &ctx),
+                )
+            }
+            ExperimentalCheckKind::OwnersLock => {
+                info!("Running owners-lock check (experimental)...");
+                ("owners-lock", run_isolated(&OwnersLockCheck::new(), &ctx))
+            }
+            ExperimentalCheckKind::Escalation => {
+                info!("Running escalation check (experimental)...");
+                ("escalation", run_isolated(&EscalationCheck::new(), &ctx))
+            }
+            ExperimentalCheckKind::Annotations => {
+                info!("Running annotations check (experimental)...");
+                ("annotations", run_isolated(&AnnotationsCheck::new(), &ctx))
             }
         };
 
+        let mut result = result;
+        result.suppress(&config.check_config.suppressed_codes);
+        result.override_severities(&config.check_config.severity_overrides);
         debug!("Check '{}' found {} issue(s)", name, result.errors.len());
         results.add(name, result);
+        stream_check(&mut stdout, &results, name, &config);
     }
 
-    // Output results
-    if config.json_output {
-        if let Err(e) = results.write_json(&mut stdout) {
-            error!("Failed to write JSON output: {}", e);
-            return ExitCode::StartupFailure;
+    // Run plugin checks
+    #[cfg(feature = "plugins")]
+    for check in &config.plugins {
+        let name = check.name();
+        if terminated.load(Ordering::SeqCst) {
+            return ExitCode::Terminated;
         }
-    } else if let Err(e) = results.write_human(&mut stdout, use_colors) {
+        if remaining() == Some(Duration::ZERO) {
+            warn!("Time budget exceeded, skipping {} plugin", name);
+            results.add_skipped(name, BUDGET_EXCEEDED);
+            stream_check(&mut stdout, &results, name, &config);
+            continue;
+        }
+
+        info!("Running {} plugin...", name);
+        let mut result = run_isolated(check, &ctx);
+        result.suppress(&config.check_config.suppressed_codes);
+        result.override_severities(&config.check_config.severity_overrides);
+        debug!("Check '{}' found {} issue(s)", name, result.errors.len());
+        results.add(name, result);
+        stream_check(&mut stdout, &results, name, &config);
+    }
+
+    // Ignore known issues, creating the baseline on the first run
+    if let Some(path) = &config.baseline_path {
+        let created;
+        let baseline = match &config.baseline {
+            Some(baseline) => baseline,
+            None => {
+                created = results.to_baseline(&parse_result.ast);
+                if let Err(e) = std::fs::write(path, created.to_json()) {
+                    write_error(
+                        &mut stderr,
+                        &format!("Failed to write baseline '{}': {}", path.display(), e),
+                        use_colors,
+                    );
+                    return ExitCode::StartupFailure;
+                }
+                if !config.output_format.is_machine_readable() {
+                    let _ = writeln!(
+                        stderr,
+                        "Created baseline {} with {} known issue(s)",
+                        path.display(),
+                        created.len()
+                    );
+                }
+                &created
+            }
+        };
+        results.apply_baseline(baseline, &parse_result.ast);
+    }
+
+    // Output results; NDJSON has streamed everything but the summary
+    let written = if config.output_format == OutputFormat::Ndjson {
+        results.write_ndjson_summary(&mut stdout)
+    } else {
+        let rendered = render(&results, config.output_format, &render_options);
+        write!(stdout, "{}", rendered)
+    };
+    if let Err(e) = written {
         error!("Failed to write output: {}", e);
         return ExitCode::StartupFailure;
     }
 
+    // Apply fixes if requested
+    if config.fix || config.fix_dry_run {
+        let fixes: Vec<_> = results.fixes().collect();
+        if !fixes.is_empty()
+            && let Err(code) = write_fixes(
+                &config,
+                &codeowners_content,
+                &fixes,
+                &mut stderr,
+                use_colors,
+            )
+        {
+            return code;
+        }
+    }
+
     // Determine exit code
     config.exit_code_for_results(results.has_errors(), results.has_warnings())
 }
 
+/// Write the NDJSON records of a check that just finished, so consumers see
+/// them without waiting for the other checks. Other formats are written
+/// once all checks finish.
+fn stream_check<W: Write>(
+    stdout: &mut W,
+    results: &ValidationResults,
+    check_name: &str,
+    config: &ValidatedConfig,
+) {
+    if config.output_format != OutputFormat::Ndjson {
+        return;
+    }
+    // A baseline created by this run records, and so hides, every issue
+    let creating_baseline = config.baseline_path.is_some() && config.baseline.is_none();
+    let written = results
+        .write_ndjson_check(
+            stdout,
+            check_name,
+            config.baseline.as_ref(),
+            !creating_baseline,
+        )
+        .and_then(|()| stdout.flush());
+    if let Err(e) = written {
+        error!("Failed to write output: {}", e);
+    }
+}
+
+/// Run the environment diagnostics and print the report.
+async fn doctor(args: &Args, redactor: &Redactor) -> ExitCode {
+    let report = run_doctor(args).await;
+    let rendered = redactor.redact(&report.render(io::stdout().is_terminal()));
+    let _ = write!(io::stdout(), "{}", rendered);
+
+    if report.has_failures() {
+        ExitCode::StartupFailure
+    } else {
+        ExitCode::Success
+    }
+}
+
+/// Report which rule would own each path.
+fn simulate_paths(args: &Args, paths: &[String], require_owner: bool) -> ExitCode {
+    let mut stderr = io::stderr().lock();
+    let use_colors = !args.effective_format().is_machine_readable() && io::stdout().is_terminal();
+
+    let content = match find_codeowners_file(&args.repository_path)
+        .and_then(|path| std::fs::read_to_string(path).map_err(Into::into))
+    {
+        Ok(content) => content,
+        Err(e) => {
+            write_error(&mut stderr, &e.to_string(), use_colors);
+            return ExitCode::StartupFailure;
+        }
+    };
+
+    let parse_result = parse_codeowners_with_config(&content, &parser_config());
+    for error in &parse_result.errors {
+        warn!("Parse error: {}", error);
+    }
+
+    let results = simulate(&parse_result.ast, paths);
+    let rendered = if args.effective_format() == OutputFormat::Json {
+        format!("{}\n", render_json(&results))
+    } else {
+        render_human(&results, use_colors)
+    };
+    let _ = write!(io::stdout(), "{}", rendered);
+
+    if require_owner && results.iter().any(|result| !result.owned) {
+        ExitCode::ValidationFailed
+    } else {
+        ExitCode::Success
+    }
+}
+
+/// Verify generated regions, or record their current hashes.
+fn verify_generated(args: &Args, update_hash: bool) -> ExitCode {
+    let mut stderr = io::stderr().lock();
+    let use_colors = !args.effective_format().is_machine_readable() && io::stdout().is_terminal();
+
+    let (path, content) = match find_codeowners_file(&args.repository_path).and_then(|path| {
+        let content = std::fs::read_to_string(&path)?;
+        Ok((path, content))
+    }) {
+        Ok(found) => found,
+        Err(e) => {
+            write_error(&mut stderr, &e.to_string(), use_colors);
+            return ExitCode::StartupFailure;
+        }
+    };
+
+    let file = parse_codeowners(&content).ast;
+
+    if update_hash {
+        let fixes = generated::hash_fixes(&file);
+        if !fixes.is_empty()
+            && let Err(e) = std::fs::write(&path, apply_fixes(&content, &fixes))
+        {
+            write_error(
+                &mut stderr,
+                &format!(
+                    "Failed to write CODEOWNERS file '{}': {}",
+                    path.display(),
+                    e
+                ),
+                use_colors,
+            );
+            return ExitCode::StartupFailure;
+        }
+        let _ = writeln!(
+            stderr,
+            "Updated {} generated region hash(es) in {}",
+            fixes.len(),
+            path.display()
+        );
+        return ExitCode::Success;
+    }
+
+    let statuses = generated::verify(&file);
+    let rendered = if args.effective_format() == OutputFormat::Json {
+        format!("{}\n", generated::render_json(&statuses))
+    } else {
+        generated::render_human(&statuses, use_colors)
+    };
+    let _ = write!(io::stdout(), "{}", rendered);
+
+    if statuses
+        .iter()
+        .any(|status| status.state == RegionState::Modified)
+    {
+        ExitCode::ValidationFailed
+    } else {
+        ExitCode::Success
+    }
+}
+
+/// Format the CODEOWNERS file, or with `check`, verify that it is formatted.
+fn fmt(args: &Args, check: bool, align: bool) -> ExitCode {
+    let mut stderr = io::stderr().lock();
+    let use_colors = !args.effective_format().is_machine_readable() && io::stdout().is_terminal();
+
+    let (path, content) = match find_codeowners_file(&args.repository_path).and_then(|path| {
+        let content = std::fs::read_to_string(&path)?;
+        Ok((path, content))
+    }) {
+        Ok(found) => found,
+        Err(e) => {
+            write_error(&mut stderr, &e.to_string(), use_colors);
+            return ExitCode::StartupFailure;
+        }
+    };
+
+    let syntax: matching::PatternSyntax = args.pattern_syntax.into();
+    let options = FormatOptions::new()
+        .with_align_owners(align)
+        .with_dialect(syntax.into());
+    let formatted = format_codeowners(&content, &options);
+
+    if formatted == content {
+        debug!("{} is already formatted", path.display());
+        return ExitCode::Success;
+    }
+
+    if check {
+        let _ = writeln!(
+            stderr,
+            "{} is not formatted; run 'codeowners-validator fmt' to fix it",
+            path.display()
+        );
+        return ExitCode::ValidationFailed;
+    }
+
+    if let Err(e) = std::fs::write(&path, formatted) {
+        write_error(
+            &mut stderr,
+            &format!(
+                "Failed to write CODEOWNERS file '{}': {}",
+                path.display(),
+                e
+            ),
+            use_colors,
+        );
+        return ExitCode::StartupFailure;
+    }
+    let _ = writeln!(stderr, "Formatted {}", path.display());
+    ExitCode::Success
+}
+
+/// List every file whose owners include the given owner.
+fn files_for_owner(args: &Args, owner: &str) -> ExitCode {
+    let mut stderr = io::stderr().lock();
+    let use_colors = !args.effective_format().is_machine_readable() && io::stdout().is_terminal();
+
+    let content = match find_codeowners_file(&args.repository_path)
+        .and_then(|path| std::fs::read_to_string(path).map_err(Into::into))
+    {
+        Ok(content) => content,
+        Err(e) => {
+            write_error(&mut stderr, &e.to_string(), use_colors);
+            return ExitCode::StartupFailure;
+        }
+    };
+
+    let parse_result = parse_codeowners_with_config(&content, &parser_config());
+    for error in &parse_result.errors {
+        warn!("Parse error: {}", error);
+    }
+
+    let owned = OwnedFiles {
+        owner: owner.to_string(),
+        files: matching::files_for_owner(&parse_result.ast, &args.repository_path, owner),
+    };
+    let rendered = if args.effective_format() == OutputFormat::Json {
+        format!("{}\n", owned::render_json(&owned))
+    } else {
+        owned::render_human(&owned, use_colors)
+    };
+    let _ = write!(io::stdout(), "{}", rendered);
+
+    ExitCode::Success
+}
+
+/// Report when the rule owning `path` last changed in the CODEOWNERS history.
+fn blame_report(args: &Args, path: &str) -> ExitCode {
+    let mut stderr = io::stderr().lock();
+    let use_colors = !args.effective_format().is_machine_readable() && io::stdout().is_terminal();
+
+    let codeowners_path = match find_codeowners_file(&args.repository_path) {
+        Ok(path) => path,
+        Err(e) => {
+            write_error(&mut stderr, &e.to_string(), use_colors);
+            return ExitCode::StartupFailure;
+        }
+    };
+    let Some(vcs) = detect_vcs(&args.repository_path) else {
+        write_error(
+            &mut stderr,
+            "'blame' needs the repository to be a git repository",
+            use_colors,
+        );
+        return ExitCode::StartupFailure;
+    };
+
+    let codeowners_path = codeowners_path
+        .strip_prefix(&args.repository_path)
+        .unwrap_or(&codeowners_path)
+        .to_string_lossy()
+        .replace('\\', "/");
+    let path = path.trim_start_matches("./").trim_start_matches('/');
+    let blame = match blame::blame(&*vcs, &codeowners_path, path, &parser_config()) {
+        Ok(blame) => blame,
+        Err(e) => {
+            write_error(&mut stderr, &e.to_string(), use_colors);
+            return ExitCode::StartupFailure;
+        }
+    };
+
+    let rendered = if args.effective_format() == OutputFormat::Json {
+        format!("{}\n", blame::render_json(&blame))
+    } else {
+        blame::render_human(&blame, use_colors)
+    };
+    let _ = write!(io::stdout(), "{}", rendered);
+
+    ExitCode::Success
+}
+
+/// Rewrite the patterns under one directory to another and show the diff.
+fn move_prefix(args: &Args, from: &str, to: &str, dry_run: bool) -> ExitCode {
+    let mut stderr = io::stderr().lock();
+    let use_colors = !args.effective_format().is_machine_readable() && io::stdout().is_terminal();
+
+    if from.trim_matches('/').is_empty() || to.trim_matches('/').is_empty() {
+        write_error(
+            &mut stderr,
+            "'--from' and '--to' must name directories below the repository root",
+            use_colors,
+        );
+        return ExitCode::StartupFailure;
+    }
+
+    let (path, content) = match find_codeowners_file(&args.repository_path).and_then(|path| {
+        let content = std::fs::read_to_string(&path)?;
+        Ok((path, content))
+    }) {
+        Ok(found) => found,
+        Err(e) => {
+            write_error(&mut stderr, &e.to_string(), use_colors);
+            return ExitCode::StartupFailure;
+        }
+    };
+
+    let syntax: matching::PatternSyntax = args.pattern_syntax.into();
+    let parser_config = ParserConfig::new()
+        .with_dialect(syntax.into())
+        .with_pattern_syntax(syntax);
+    let parse_result = parse_codeowners_with_config(&content, &parser_config);
+    for error in &parse_result.errors {
+        warn!("Parse error: {}", error);
+    }
+
+    let mut file = parse_result.ast;
+    let moves = file.move_prefix(from, to);
+    if moves.is_empty() {
+        let _ = writeln!(stderr, "No anchored patterns start with '{}'", from);
+        return ExitCode::Success;
+    }
+
+    let files = list_files(
+        &args.repository_path,
+        &args
+            .walk_limits(FileWalkerConfig::for_files_check())
+            .with_force_respect_gitignore(args.force_respect_gitignore)
+            .with_symlinks(args.symlinks.into())
+            .with_tracked_only(args.tracked_files_only),
+    );
+    let unmatched: Vec<_> = moves
+        .iter()
+        .filter(|moved| {
+            !matching::Pattern::new(&moved.to)
+                .is_some_and(|pattern| files.iter().any(|file| pattern.matches(file)))
+        })
+        .collect();
+
+    let fixes: Vec<_> = moves
+        .iter()
+        .map(|moved| Fix::replace(moved.from.span, &moved.to))
+        .collect();
+    let applicable = applicable_fixes(&content, &fixes);
+    let display_path = path
+        .strip_prefix(&args.repository_path)
+        .unwrap_or(&path)
+        .to_string_lossy()
+        .replace('\\', "/");
+    let _ = write!(
+        io::stdout(),
+        "{}",
+        fix::render_diff(&display_path, &content, &applicable, use_colors)
+    );
+
+    if !unmatched.is_empty() {
+        for moved in &unmatched {
+            write_error(
+                &mut stderr,
+                &format!(
+                    "line {}: '{}' would match no files",
+                    moved.from.span.line, moved.to
+                ),
+                use_colors,
+            );
+        }
+        let _ = writeln!(
+            stderr,
+            "Not writing {}: {} moved pattern(s) match no files",
+            path.display(),
+            unmatched.len()
+        );
+        return ExitCode::ValidationFailed;
+    }
+    if dry_run {
+        return ExitCode::Success;
+    }
+
+    if let Err(e) = std::fs::write(&path, apply_fixes(&content, &fixes)) {
+        write_error(
+            &mut stderr,
+            &format!(
+                "Failed to write CODEOWNERS file '{}': {}",
+                path.display(),
+                e
+            ),
+            use_colors,
+        );
+        return ExitCode::StartupFailure;
+    }
+    let _ = writeln!(
+        stderr,
+        "Moved {} pattern(s) in {}",
+        moves.len(),
+        path.display()
+    );
+    ExitCode::Success
+}
+
+/// Validate the CODEOWNERS file a patch produces.
+async fn validate_patch(
+    args: &Args,
+    patch_path: &Path,
+    base: Option<&Path>,
+    base_ref: Option<&str>,
+    changed_only: bool,
+    redactor: &Redactor,
+) -> ExitCode {
+    let mut stderr = io::stderr().lock();
+    let use_colors = !args.effective_format().is_machine_readable() && io::stdout().is_terminal();
+
+    let patches = match patch::read_patch(patch_path)
+        .map_err(|e| e.to_string())
+        .and_then(|content| parse_patch(&content).map_err(|e| e.to_string()))
+    {
+        Ok(patches) => patches,
+        Err(e) => {
+            write_error(
+                &mut stderr,
+                &format!("Failed to read patch '{}': {}", patch_path.display(), e),
+                use_colors,
+            );
+            return ExitCode::StartupFailure;
+        }
+    };
+    let Some(file_patch) = codeowners_patch(&patches) else {
+        debug!("The patch does not touch a CODEOWNERS file");
+        return ExitCode::Success;
+    };
+
+    let base_content = match (base, base_ref, file_patch.old_path.as_deref()) {
+        (Some(base), _, _) => std::fs::read_to_string(base)
+            .map_err(|e| format!("Failed to read base '{}': {}", base.display(), e)),
+        (None, _, None) => Ok(String::new()),
+        (None, Some(reference), Some(old_path)) => {
+            let Some((owner, repo)) = args
+                .owner_checker_repository
+                .as_deref()
+                .and_then(|repository| repository.split_once('/'))
+            else {
+                write_error(
+                    &mut stderr,
+                    "'--base-ref' requires --owner-checker-repository in 'owner/repo' format",
+                    use_colors,
+                );
+                return ExitCode::StartupFailure;
+            };
+            match create_octocrab(args).await {
+                Ok(Some(client)) => patch::fetch_base(&client, owner, repo, old_path, reference)
+                    .await
+                    .map(Option::unwrap_or_default)
+                    .map_err(|e| {
+                        redactor.redact(&format!(
+                            "Failed to fetch {} at '{}': {}",
+                            old_path,
+                            reference,
+                            e.to_string().lines().next().unwrap_or_default()
+                        ))
+                    }),
+                Ok(None) => Err("'--base-ref' requires GitHub authentication \
+                     (--github-access-token or GitHub App credentials)"
+                    .to_string()),
+                Err(e) => Err(redactor.redact(&e.to_string())),
+            }
+        }
+        (None, None, Some(old_path)) => {
+            let path = args.repository_path.join(old_path);
+            std::fs::read_to_string(&path)
+                .map_err(|e| format!("Failed to read base '{}': {}", path.display(), e))
+        }
+    };
+    let patched = match base_content
+        .and_then(|content| file_patch.apply(&content).map_err(|e| e.to_string()))
+    {
+        Ok(patched) => patched,
+        Err(e) => {
+            write_error(&mut stderr, &e, use_colors);
+            return ExitCode::StartupFailure;
+        }
+    };
+
+    let results = patch::validate_patched(&patched, args.pattern_syntax.into(), changed_only);
+    let render_options = RenderOptions::new(file_patch.path().unwrap_or_default())
+        .with_colors(use_colors)
+        .with_source(&patched.content);
+    let _ = write!(
+        io::stdout(),
+        "{}",
+        render(&results, args.effective_format(), &render_options)
+    );
+
+    let failure_level = args.check_failure_level.unwrap_or_default();
+    if results.has_errors() || (failure_level == FailureLevel::Warning && results.has_warnings()) {
+        ExitCode::ValidationFailed
+    } else {
+        ExitCode::Success
+    }
+}
+
+/// Report the code owner approvals a pull request still needs.
+async fn can_merge(args: &Args, number: u64, redactor: &Redactor) -> ExitCode {
+    let mut stderr = io::stderr().lock();
+    let use_colors = !args.effective_format().is_machine_readable() && io::stdout().is_terminal();
+
+    let Some((owner, repo)) = args
+        .owner_checker_repository
+        .as_deref()
+        .and_then(|repository| repository.split_once('/'))
+    else {
+        write_error(
+            &mut stderr,
+            "'can-merge' requires --owner-checker-repository in 'owner/repo' format",
+            use_colors,
+        );
+        return ExitCode::StartupFailure;
+    };
+
+    let client = match create_octocrab(args).await {
+        Ok(Some(client)) => client,
+        Ok(None) => {
+            write_error(
+                &mut stderr,
+                "'can-merge' requires GitHub authentication (--github-access-token or GitHub App credentials)",
+                use_colors,
+            );
+            return ExitCode::StartupFailure;
+        }
+        Err(e) => {
+            write_error(&mut stderr, &redactor.redact(&e.to_string()), use_colors);
+            return ExitCode::StartupFailure;
+        }
+    };
+
+    let content = match find_codeowners_file(&args.repository_path)
+        .and_then(|path| std::fs::read_to_string(path).map_err(Into::into))
+    {
+        Ok(content) => content,
+        Err(e) => {
+            write_error(&mut stderr, &e.to_string(), use_colors);
+            return ExitCode::StartupFailure;
+        }
+    };
+
+    let parse_result = parse_codeowners_with_config(&content, &parser_config());
+    for error in &parse_result.errors {
+        warn!("Parse error: {}", error);
+    }
+
+    let mut pr = match merge::fetch_pull_request(&client, owner, repo, number).await {
+        Ok(pr) => pr,
+        Err(e) => {
+            let message = format!(
+                "Failed to fetch pull request #{}: {}",
+                number,
+                e.to_string().lines().next().unwrap_or_default()
+            );
+            write_error(&mut stderr, &redactor.redact(&message), use_colors);
+            return ExitCode::StartupFailure;
+        }
+    };
+
+    // Count approvals by team members for the teams they belong to
+    let required = required_owners(
+        pr.changed_files.iter().map(String::as_str),
+        &parse_result.ast,
+    );
+    let teams = merge::team_approvals(&client, &required, &pr.approvers).await;
+    pr.approvers.extend(teams);
+
+    let readiness = MergeReadiness::evaluate(&parse_result.ast, &pr);
+    let rendered = if args.effective_format() == OutputFormat::Json {
+        format!("{}\n", merge::render_json(&readiness))
+    } else {
+        merge::render_human(&readiness, use_colors)
+    };
+    let _ = write!(io::stdout(), "{}", rendered);
+
+    if readiness.is_ready() {
+        ExitCode::Success
+    } else {
+        ExitCode::ValidationFailed
+    }
+}
+
+/// Export rules or resolved file ownership as CSV.
+fn export_csv(args: &Args, rows: CsvRows) -> ExitCode {
+    let mut stderr = io::stderr().lock();
+
+    let content = match find_codeowners_file(&args.repository_path)
+        .and_then(|path| std::fs::read_to_string(path).map_err(Into::into))
+    {
+        Ok(content) => content,
+        Err(e) => {
+            write_error(&mut stderr, &e.to_string(), false);
+            return ExitCode::StartupFailure;
+        }
+    };
+
+    let parse_result = parse_codeowners_with_config(&content, &parser_config());
+    for error in &parse_result.errors {
+        warn!("Parse error: {}", error);
+    }
+
+    let mut files = list_files(
+        &args.repository_path,
+        &args
+            .walk_limits(FileWalkerConfig::for_not_owned_check())
+            .with_force_respect_gitignore(args.force_respect_gitignore)
+            .with_non_utf8(args.non_utf8_paths.into())
+            .with_symlinks(args.symlinks.into())
+            .with_tracked_only(args.tracked_files_only),
+    );
+    files.sort_unstable();
+
+    let rendered = match rows {
+        CsvRows::Rules => export::render_rules(&parse_result.ast, &files),
+        CsvRows::Files => export::render_files(&parse_result.ast, &files),
+    };
+    let _ = write!(io::stdout(), "{}", rendered);
+
+    ExitCode::Success
+}
+
+/// Print the parsed CODEOWNERS file as a JSON syntax tree.
+///
+/// Lines that fail to parse appear as `invalid` lines, and make the exit
+/// code report a validation failure.
+fn emit_ast(args: &Args) -> ExitCode {
+    let mut stderr = io::stderr().lock();
+
+    let content = match find_codeowners_file(&args.repository_path)
+        .and_then(|path| std::fs::read_to_string(path).map_err(Into::into))
+    {
+        Ok(content) => content,
+        Err(e) => {
+            write_error(&mut stderr, &e.to_string(), false);
+            return ExitCode::StartupFailure;
+        }
+    };
+
+    // Inline comments are kept so inline annotations reach the exported tree
+    let syntax: matching::PatternSyntax = args.pattern_syntax.into();
+    let parser_config = ParserConfig::new()
+        .with_dialect(syntax.into())
+        .with_pattern_syntax(syntax)
+        .with_inline_comments(true);
+    let parse_result = parse_codeowners_with_config(&content, &parser_config);
+    for error in &parse_result.errors {
+        warn!("Parse error: {}", error);
+    }
+
+    let ast = serde_json::to_string_pretty(&parse_result.ast)
+        .expect("syntax tree is always serializable");
+    let _ = writeln!(io::stdout(), "{}", ast);
+
+    if parse_result.is_ok() {
+        ExitCode::Success
+    } else {
+        ExitCode::ValidationFailed
+    }
+}
+
+/// Emit the ownership graph as DOT or GraphML.
+fn graph_report(args: &Args, depth: usize, format: GraphFormat) -> ExitCode {
+    let mut stderr = io::stderr().lock();
+
+    let render: fn(&OwnershipGraph) -> String = match format {
+        GraphFormat::Dot => graph::render_dot,
+        GraphFormat::Graphml => graph::render_graphml,
+    };
+
+    let content = match find_codeowners_file(&args.repository_path)
+        .and_then(|path| std::fs::read_to_string(path).map_err(Into::into))
+    {
+        Ok(content) => content,
+        Err(e) => {
+            write_error(&mut stderr, &e.to_string(), false);
+            return ExitCode::StartupFailure;
+        }
+    };
+
+    let parse_result = parse_codeowners_with_config(&content, &parser_config());
+    for error in &parse_result.errors {
+        warn!("Parse error: {}", error);
+    }
+
+    let mut files = list_files(
+        &args.repository_path,
+        &args
+            .walk_limits(FileWalkerConfig::for_not_owned_check())
+            .with_force_respect_gitignore(args.force_respect_gitignore)
+            .with_non_utf8(args.non_utf8_paths.into())
+            .with_symlinks(args.symlinks.into())
+            .with_tracked_only(args.tracked_files_only),
+    );
+    files.sort_unstable();
+
+    let graph = OwnershipGraph::build(&parse_result.ast, &files, depth);
+    let _ = write!(io::stdout(), "{}", render(&graph));
+
+    ExitCode::Success
+}
+
+/// List the individuals effectively responsible for each directory.
+async fn effective_owners_report(args: &Args, depth: usize, redactor: &Redactor) -> ExitCode {
+    let mut stderr = io::stderr().lock();
+    let use_colors = !args.effective_format().is_machine_readable() && io::stdout().is_terminal();
+
+    let content = match find_codeowners_file(&args.repository_path)
+        .and_then(|path| std::fs::read_to_string(path).map_err(Into::into))
+    {
+        Ok(content) => content,
+        Err(e) => {
+            write_error(&mut stderr, &e.to_string(), use_colors);
+            return ExitCode::StartupFailure;
+        }
+    };
+
+    let client = match create_octocrab(args).await {
+        Ok(Some(client)) => CachingGithubClient::new(OctocrabClient::new(client)),
+        Ok(None) => {
+            write_error(
+                &mut stderr,
+                "expanding teams into their members requires GitHub authentication",
+                use_colors,
+            );
+            return ExitCode::StartupFailure;
+        }
+        Err(e) => {
+            write_error(&mut stderr, &redactor.redact(&e.to_string()), use_colors);
+            return ExitCode::StartupFailure;
+        }
+    };
+
+    let parse_result = parse_codeowners_with_config(&content, &parser_config());
+    for error in &parse_result.errors {
+        warn!("Parse error: {}", error);
+    }
+
+    let mut files = list_files(
+        &args.repository_path,
+        &args
+            .walk_limits(FileWalkerConfig::for_not_owned_check())
+            .with_force_respect_gitignore(args.force_respect_gitignore)
+            .with_non_utf8(args.non_utf8_paths.into())
+            .with_symlinks(args.symlinks.into())
+            .with_tracked_only(args.tracked_files_only),
+    );
+    files.sort_unstable();
+
+    let members = effective_owners::expand_teams(&client, &parse_result.ast).await;
+    let report = EffectiveOwners::build(&parse_result.ast, &files, depth, &members);
+    let rendered = if args.effective_format() == OutputFormat::Json {
+        format!("{}\n", effective_owners::render_json(&report))
+    } else {
+        effective_owners::render_human(&report, use_colors)
+    };
+    let _ = write!(io::stdout(), "{}", rendered);
+
+    ExitCode::Success
+}
+
+/// Report the owners of each build target.
+fn targets_report(args: &Args, targets_file: &Path) -> ExitCode {
+    let mut stderr = io::stderr().lock();
+    let use_colors = !args.effective_format().is_machine_readable() && io::stdout().is_terminal();
+
+    let content = match find_codeowners_file(&args.repository_path)
+        .and_then(|path| std::fs::read_to_string(path).map_err(Into::into))
+    {
+        Ok(content) => content,
+        Err(e) => {
+            write_error(&mut stderr, &e.to_string(), use_colors);
+            return ExitCode::StartupFailure;
+        }
+    };
+    let targets = match std::fs::read_to_string(targets_file)
+        .map_err(|e| e.to_string())
+        .and_then(|json| targets::parse_targets(&json).map_err(|e| e.to_string()))
+    {
+        Ok(targets) => targets,
+        Err(e) => {
+            write_error(
+                &mut stderr,
+                &format!("Failed to read targets '{}': {}", targets_file.display(), e),
+                use_colors,
+            );
+            return ExitCode::StartupFailure;
+        }
+    };
+
+    let parse_result = parse_codeowners_with_config(&content, &parser_config());
+    for error in &parse_result.errors {
+        warn!("Parse error: {}", error);
+    }
+
+    let report = TargetReport::build(&parse_result.ast, &targets);
+    let rendered = if args.effective_format() == OutputFormat::Json {
+        format!("{}\n", targets::render_json(&report))
+    } else {
+        targets::render_human(&report, use_colors)
+    };
+    let _ = write!(io::stdout(), "{}", rendered);
+
+    ExitCode::Success
+}
+
+/// Compare review requests on past pull requests under a proposed CODEOWNERS.
+async fn review_load_report(
+    args: &Args,
+    proposed: &Path,
+    history: Option<&Path>,
+    merged_prs: usize,
+    redactor: &Redactor,
+) -> ExitCode {
+    let mut stderr = io::stderr().lock();
+    let use_colors = !args.effective_format().is_machine_readable() && io::stdout().is_terminal();
+
+    let current = match find_codeowners_file(&args.repository_path)
+        .and_then(|path| std::fs::read_to_string(path).map_err(Into::into))
+    {
+        Ok(content) => content,
+        Err(e) => {
+            write_error(&mut stderr, &e.to_string(), use_colors);
+            return ExitCode::StartupFailure;
+        }
+    };
+    let proposed = match std::fs::read_to_string(proposed) {
+        Ok(content) => content,
+        Err(e) => {
+            write_error(
+                &mut stderr,
+                &format!(
+                    "Failed to read proposed CODEOWNERS file '{}': {}",
+                    proposed.display(),
+                    e
+                ),
+                use_colors,
+            );
+            return ExitCode::StartupFailure;
+        }
+    };
+
+    let history = match history {
+        Some(path) => {
+            match std::fs::read_to_string(path)
+                .map_err(|e| e.to_string())
+                .and_then(|json| review_load::parse_history(&json).map_err(|e| e.to_string()))
+            {
+                Ok(history) => history,
+                Err(e) => {
+                    write_error(
+                        &mut stderr,
+                        &format!("Failed to read history '{}': {}", path.display(), e),
+                        use_colors,
+                    );
+                    return ExitCode::StartupFailure;
+                }
+            }
+        }
+        None => {
+            let Some((owner, repo)) = args
+                .owner_checker_repository
+                .as_deref()
+                .and_then(|repository| repository.split_once('/'))
+            else {
+                write_error(
+                    &mut stderr,
+                    "fetching pull requests requires --owner-checker-repository in 'owner/repo' format (or pass --history)",
+                    use_colors,
+                );
+                return ExitCode::StartupFailure;
+            };
+            let client = match create_octocrab(args).await {
+                Ok(Some(client)) => client,
+                Ok(None) => {
+                    write_error(
+                        &mut stderr,
+                        "fetching pull requests requires GitHub authentication (or pass --history)",
+                        use_colors,
+                    );
+                    return ExitCode::StartupFailure;
+                }
+                Err(e) => {
+                    write_error(&mut stderr, &redactor.redact(&e.to_string()), use_colors);
+                    return ExitCode::StartupFailure;
+                }
+            };
+            match review_load::fetch_history(&client, owner, repo, merged_prs).await {
+                Ok(history) => history,
+                Err(e) => {
+                    let message = format!(
+                        "Failed to fetch merged pull requests: {}",
+                        e.to_string().lines().next().unwrap_or_default()
+                    );
+                    write_error(&mut stderr, &redactor.redact(&message), use_colors);
+                    return ExitCode::StartupFailure;
+                }
+            }
+        }
+    };
+
+    let current = parse_codeowners_with_config(&current, &parser_config());
+    let proposed = parse_codeowners_with_config(&proposed, &parser_config());
+    for error in current.errors.iter().chain(&proposed.errors) {
+        warn!("Parse error: {}", error);
+    }
+
+    let load = ReviewLoad::simulate(&current.ast, &proposed.ast, &history);
+    let rendered = if args.effective_format() == OutputFormat::Json {
+        format!("{}\n", review_load::render_json(&load))
+    } else {
+        review_load::render_human(&load, use_colors)
+    };
+    let _ = write!(io::stdout(), "{}", rendered);
+
+    ExitCode::Success
+}
+
+/// Write the paths the not-owned check skips, and why, to stderr.
+fn write_skips<W: Write>(config: &ValidatedConfig, stderr: &mut W) {
+    let walker = FileWalkerConfig::for_not_owned_check()
+        .with_force_respect_gitignore(config.check_config.force_respect_gitignore)
+        .with_symlinks(config.check_config.symlink_policy)
+        .with_max_depth(config.check_config.max_walk_depth);
+    let skipped = explain_skips(&config.repo_path, &walker);
+    let _ = writeln!(
+        stderr,
+        "The not-owned check skips {} path(s):",
+        skipped.len()
+    );
+    for path in &skipped {
+        let _ = writeln!(stderr, "  {}", path);
+    }
+}
+
+/// Apply fixes to the CODEOWNERS content and write the result back to disk,
+/// listing each change; with `--fix-dry-run`, show the changes as a diff
+/// instead.
+fn write_fixes<W: Write>(
+    config: &ValidatedConfig,
+    content: &str,
+    fixes: &[Fix],
+    stderr: &mut W,
+    use_colors: bool,
+) -> Result<(), ExitCode> {
+    let applicable = applicable_fixes(content, fixes);
+    let path = config
+        .codeowners_path
+        .strip_prefix(&config.repo_path)
+        .unwrap_or(&config.codeowners_path)
+        .to_string_lossy()
+        .replace('\\', "/");
+
+    if config.fix_dry_run {
+        let _ = writeln!(
+            stderr,
+            "Would apply {} fix(es) to {}:",
+            applicable.len(),
+            config.codeowners_path.display()
+        );
+        let _ = write!(
+            stderr,
+            "{}",
+            fix::render_diff(&path, content, &applicable, use_colors)
+        );
+        return Ok(());
+    }
+
+    let fixed = apply_fixes(content, fixes);
+    if let Err(e) = std::fs::write(&config.codeowners_path, fixed) {
+        write_error(
+            stderr,
+            &format!(
+                "Failed to write CODEOWNERS file '{}': {}",
+                config.codeowners_path.display(),
+                e
+            ),
+            use_colors,
+        );
+        return Err(ExitCode::StartupFailure);
+    }
+    if !config.output_format.is_machine_readable() {
+        let _ = writeln!(
+            stderr,
+            "Applied {} fix(es) to {}:",
+            applicable.len(),
+            config.codeowners_path.display()
+        );
+        let _ = write!(stderr, "{}", fix::render_summary(content, &applicable));
+    }
+    Ok(())
+}
+
 /// Write an error message to the writer.
 fn write_error<W: Write>(writer: &mut W, message: &str, use_colors: bool) {
     if use_colors {
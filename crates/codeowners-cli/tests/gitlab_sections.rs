@@ -0,0 +1,48 @@
+//! Runs the CLI on a GitLab CODEOWNERS file with sections.
+//!
+//! Sections are only parsed in the GitLab dialect, which the CLI derives
+//! from `--pattern-syntax gitlab`.
+
+use serde_json::Value;
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+#[test]
+fn gitlab_sections_are_parsed_and_checked() {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir_all(dir.path().join("docs")).unwrap();
+    fs::create_dir_all(dir.path().join("src")).unwrap();
+    fs::write(dir.path().join("docs/index.md"), "").unwrap();
+    fs::write(dir.path().join("src/main.rs"), "").unwrap();
+    fs::write(
+        dir.path().join("CODEOWNERS"),
+        "[Docs][0] @org/docs\n/docs/ @org/docs\n[Backend][2]\n/src/ @org/backend\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_codeowners-validator"))
+        .arg("--repository-path")
+        .arg(dir.path())
+        .args([
+            "--checks",
+            "syntax",
+            "--experimental-checks",
+            "gitlab-sections",
+        ])
+        .args(["--pattern-syntax", "gitlab", "--format", "json"])
+        .env_remove("CHECKS")
+        .env_remove("EXPERIMENTAL_CHECKS")
+        .env_remove("GITHUB_ACCESS_TOKEN")
+        .output()
+        .expect("failed to run codeowners-validator");
+    let json: Value = serde_json::from_slice(&output.stdout).expect("CLI produced invalid JSON");
+
+    // The header without owners is a section, not a rule without owners
+    assert_eq!(json["syntax"], Value::Array(Vec::new()));
+    let sections = json["gitlab-sections"].as_array().unwrap();
+    assert_eq!(sections.len(), 1, "{sections:?}");
+    assert_eq!(sections[0]["code"], "CO024");
+    assert_eq!(sections[0]["line"], 1);
+    assert_eq!(output.status.code(), Some(3));
+}
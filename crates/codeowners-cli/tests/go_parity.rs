@@ -0,0 +1,243 @@
+//! Differential tests against the original Go codeowners-validator.
+//!
+//! Runs the Go binary and this crate's CLI over the same sample repositories
+//! and compares the findings each reports, keyed by check, line and severity.
+//! Messages are worded differently between the tools and are not compared.
+//!
+//! The harness is opt-in. Set `GO_CODEOWNERS_VALIDATOR` to the path of the Go
+//! binary to enable it. Differences are written to a parity report (by
+//! default `target/tmp/go-parity-report.md`, or `GO_PARITY_REPORT`) rather
+//! than failing the test, unless `GO_PARITY_STRICT=1` is set.
+
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Environment variable pointing to the Go codeowners-validator binary.
+const GO_BIN_ENV: &str = "GO_CODEOWNERS_VALIDATOR";
+
+/// Environment variable overriding where the parity report is written.
+const REPORT_ENV: &str = "GO_PARITY_REPORT";
+
+/// Environment variable that turns any difference into a test failure.
+const STRICT_ENV: &str = "GO_PARITY_STRICT";
+
+/// Checks both tools run without GitHub access.
+const CHECKS: &str = "files,duppatterns,syntax";
+
+/// A finding reduced to the parts both tools report the same way.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Finding {
+    check: String,
+    line: usize,
+    severity: String,
+}
+
+impl std::fmt::Display for Finding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} line {} ({})", self.check, self.line, self.severity)
+    }
+}
+
+/// Maps a Go checker title to this crate's check name.
+fn go_check_name(title: &str) -> String {
+    let title = title.to_lowercase();
+    let name = if title.contains("not owned") {
+        "notowned"
+    } else if title.contains("syntax") {
+        "syntax"
+    } else if title.contains("duplicate") {
+        "duppatterns"
+    } else if title.contains("file exist") {
+        "files"
+    } else if title.contains("shadow") {
+        "avoid-shadowing"
+    } else if title.contains("owner") {
+        "owners"
+    } else {
+        return title;
+    };
+    name.to_string()
+}
+
+/// Parses the Go tool's console output.
+///
+/// Checks start with `==> Executing <title> (<duration>)` and each finding
+/// is printed as `[err] line N: ...` or `[war] line N: ...`.
+fn parse_go_output(output: &str) -> BTreeSet<Finding> {
+    let mut findings = BTreeSet::new();
+    let mut check = String::new();
+
+    for line in output.lines().map(str::trim) {
+        if let Some(rest) = line.strip_prefix("==> Executing ") {
+            let title = rest.rsplit_once(" (").map_or(rest, |(title, _)| title);
+            check = go_check_name(title);
+            continue;
+        }
+
+        let severity = if line.starts_with("[err]") {
+            "error"
+        } else if line.starts_with("[war]") {
+            "warning"
+        } else {
+            continue;
+        };
+        let line_num = line[5..]
+            .trim_start()
+            .strip_prefix("line ")
+            .and_then(|rest| rest.split(':').next())
+            .and_then(|num| num.trim().parse().ok())
+            .unwrap_or(0);
+
+        findings.insert(Finding {
+            check: check.clone(),
+            line: line_num,
+            severity: severity.to_string(),
+        });
+    }
+
+    findings
+}
+
+/// Parses this crate's JSON output.
+fn parse_rust_output(output: &str) -> BTreeSet<Finding> {
+    let json: Value = serde_json::from_str(output).expect("CLI produced invalid JSON");
+    let mut findings = BTreeSet::new();
+
+    for (check, issues) in json.as_object().into_iter().flatten() {
+        for issue in issues.as_array().into_iter().flatten() {
+            findings.insert(Finding {
+                check: check.clone(),
+                line: issue["line"].as_u64().unwrap_or(0) as usize,
+                severity: issue["severity"].as_str().unwrap_or("").to_string(),
+            });
+        }
+    }
+
+    findings
+}
+
+/// Runs the Go binary on a sample repository.
+fn run_go(go_bin: &Path, repo: &Path) -> BTreeSet<Finding> {
+    let output = Command::new(go_bin)
+        .env("REPOSITORY_PATH", repo)
+        .env("CHECKS", CHECKS)
+        .env_remove("EXPERIMENTAL_CHECKS")
+        .env_remove("GITHUB_ACCESS_TOKEN")
+        .output()
+        .expect("failed to run Go codeowners-validator");
+    parse_go_output(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Runs this crate's CLI on a sample repository.
+fn run_rust(repo: &Path) -> BTreeSet<Finding> {
+    let output = Command::new(env!("CARGO_BIN_EXE_codeowners-validator"))
+        .arg("--repository-path")
+        .arg(repo)
+        .args(["--checks", CHECKS, "--format", "json"])
+        .env_remove("EXPERIMENTAL_CHECKS")
+        .env_remove("GITHUB_ACCESS_TOKEN")
+        .output()
+        .expect("failed to run codeowners-validator");
+    parse_rust_output(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Returns the sample repositories shared with the core corpus tests.
+fn samples() -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../codeowners-validator-core/tests/corpus/samples");
+    let mut samples: Vec<PathBuf> = fs::read_dir(dir)
+        .expect("missing core corpus samples")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .collect();
+    samples.sort();
+    samples
+}
+
+#[test]
+fn parity_with_go_validator() {
+    let Some(go_bin) = std::env::var_os(GO_BIN_ENV).map(PathBuf::from) else {
+        return;
+    };
+
+    let mut report = String::from("# Go parity report\n");
+    let mut differing = 0;
+
+    for sample in samples() {
+        let name = sample.file_name().unwrap().to_string_lossy().into_owned();
+        let go = run_go(&go_bin, &sample);
+        let rust = run_rust(&sample);
+
+        let only_go: Vec<_> = go.difference(&rust).collect();
+        let only_rust: Vec<_> = rust.difference(&go).collect();
+
+        writeln!(report, "\n## {name}\n").unwrap();
+        if only_go.is_empty() && only_rust.is_empty() {
+            writeln!(report, "identical ({} findings)", go.len()).unwrap();
+            continue;
+        }
+
+        differing += 1;
+        for finding in only_go {
+            writeln!(report, "- only Go: {finding}").unwrap();
+        }
+        for finding in only_rust {
+            writeln!(report, "- only Rust: {finding}").unwrap();
+        }
+    }
+
+    let report_path = std::env::var_os(REPORT_ENV).map_or_else(
+        || Path::new(env!("CARGO_TARGET_TMPDIR")).join("go-parity-report.md"),
+        PathBuf::from,
+    );
+    fs::write(&report_path, &report).expect("failed to write parity report");
+    println!("{report}");
+    println!("parity report written to {}", report_path.display());
+
+    if std::env::var_os(STRICT_ENV).is_some_and(|v| v == "1") {
+        assert_eq!(differing, 0, "findings differ from the Go validator");
+    }
+}
+
+#[test]
+fn parses_go_output() {
+    let output = "\
+==> Executing Duplicated Pattern Checker (1.2ms)
+    [war] line 3: Pattern \"*.rs\" is defined 2 times in lines:
+==> Executing Valid Syntax Checker (0.1ms)
+    [err] line 4: Owner 'team-a' does not look like an email
+==> Executing [Experimental] Not Owned File Checker (3ms)
+";
+    let findings = parse_go_output(output);
+    assert_eq!(
+        findings.into_iter().collect::<Vec<_>>(),
+        vec![
+            Finding {
+                check: "duppatterns".to_string(),
+                line: 3,
+                severity: "warning".to_string(),
+            },
+            Finding {
+                check: "syntax".to_string(),
+                line: 4,
+                severity: "error".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn parses_rust_output() {
+    let output = r#"{"syntax": [{"line": 2, "column": 1, "message": "m", "severity": "error"}], "files": []}"#;
+    let findings = parse_rust_output(output);
+    assert_eq!(findings.len(), 1);
+    assert!(findings.contains(&Finding {
+        check: "syntax".to_string(),
+        line: 2,
+        severity: "error".to_string(),
+    }));
+}
@@ -42,6 +42,7 @@ Example with type annotations:
 """
 
 from codeowners_validator._codeowners_validator import (
+    Validator,
     __version__,
     parse_codeowners,
     validate_codeowners,
@@ -73,6 +74,8 @@ __all__ = [
     "parse_codeowners",
     "validate_codeowners",
     "generate_codeowners_fixture",
+    # Classes
+    "Validator",
     # Types (available at runtime)
     "AstDict",
     "CheckConfigDict",
@@ -1,6 +1,8 @@
 """Type stubs for the codeowners_validator native module."""
 
 from collections.abc import Awaitable
+from os import PathLike
+from types import TracebackType
 from typing import Literal, Protocol, TypedDict
 
 __version__: str
@@ -12,11 +14,13 @@ class SpanDict(TypedDict):
     line: int
     column: int
     length: int
+    end_line: int
+    end_column: int
 
 class OwnerDict(TypedDict):
     """An owner entry in a CODEOWNERS rule."""
 
-    type: Literal["user", "team", "email"]
+    type: Literal["user", "team", "email", "invalid"]
     text: str
     span: SpanDict
     # For user type
@@ -26,6 +30,9 @@ class OwnerDict(TypedDict):
     team: str  # only present for team
     # For email type
     email: str  # only present for email
+    # For invalid type
+    raw: str  # only present for invalid
+    reason: str  # only present for invalid
 
 class PatternDict(TypedDict):
     """A file pattern in a CODEOWNERS rule."""
@@ -36,12 +43,16 @@ class PatternDict(TypedDict):
 class LineKindDict(TypedDict, total=False):
     """The content of a line in a CODEOWNERS file."""
 
-    type: Literal["blank", "comment", "rule", "invalid"]
+    type: Literal["blank", "comment", "rule", "section", "invalid"]
     # For comment type
     content: str
     # For rule type
     pattern: PatternDict
     owners: list[OwnerDict]
+    # For section type (GitLab), along with owners
+    name: str
+    optional: bool
+    approvals: int | None  # None if missing or not a positive integer
     # For invalid type
     raw: str
     error: str
@@ -51,6 +62,9 @@ class LineDict(TypedDict):
 
     kind: LineKindDict
     span: SpanDict
+    # Owners not to request reviews from automatically (empty for all of the
+    # rule's owners), or None without a `codeowners: no-auto-request` annotation
+    no_auto_request: list[str] | None
 
 class AstDict(TypedDict):
     """The parsed AST of a CODEOWNERS file."""
@@ -68,11 +82,16 @@ class IssueDict(TypedDict):
     """A validation issue."""
 
     path: str
+    code: str
     span: SpanDict | None
     message: str
     severity: Literal["error", "warning"]
 
-class ValidationResultDict(TypedDict):
+class _ValidationResultExtras(TypedDict, total=False):
+    # Set when a check could not finish, e.g. because the GitHub client raised
+    internal_error: str
+
+class ValidationResultDict(_ValidationResultExtras):
     """The result of validating a CODEOWNERS file."""
 
     syntax: list[IssueDict]
@@ -88,7 +107,38 @@ class CheckConfigDict(TypedDict, total=False):
     owners_must_be_teams: bool
     allow_unowned_patterns: bool
     skip_patterns: list[str]
+    force_respect_gitignore: bool
+    tracked_files_only: bool
+    git_rev: str
+    changed_files: list[str]
+    non_utf8_policy: Literal["warn", "lossy", "skip"]
+    symlink_policy: Literal["skip", "follow", "report"]
+    max_walk_depth: int
+    excluded_dirs: list[str]
+    max_walk_files: int
+    walk_timeout: float
     repository: str
+    max_api_requests: int
+    trusted_email_domains: list[str]
+    reject_email_owners: bool
+    team_mapping: dict[str, str]
+    derive_team_mapping: bool
+    question_mark: Literal["wildcard", "literal", "unsupported"]
+    pattern_syntax: Literal["github", "extended", "gitlab"]
+    idp_export: str
+    idp_min_members: int
+    ownership_manifest: str
+    review_warning_days: int
+    repeated_owners_threshold: int
+    max_rules: int
+    max_rules_per_section: int
+    max_file_lines: int
+    suppressed_codes: list[str]
+    admin_teams: list[str]
+    owners_lock: str
+    check_empty_teams: bool
+    min_team_size: int
+    check_team_hierarchy: bool
 
 class GithubClientProtocol(Protocol):
     """Protocol for GitHub client implementations.
@@ -177,6 +227,15 @@ class GithubClientProtocol(Protocol):
         """
         ...
 
+    # Optional: implement ``team_member_count(org, team) -> int | None`` (sync or
+    # async) to let the owners check report empty and undersized teams when the
+    # ``check_empty_teams`` or ``min_team_size`` config options are set. Return
+    # None if the membership cannot be determined.
+    #
+    # Optional: implement ``team_parent(org, team) -> str | None`` (sync or async)
+    # returning the slug of a nested team's parent, to let the owners check warn
+    # about redundant parent teams when ``check_team_hierarchy`` is set.
+
 def parse_codeowners(content: str) -> ParseResultDict:
     """Parse a CODEOWNERS file content and return the parsed AST.
 
@@ -220,7 +279,51 @@ async def validate_codeowners(
             - owners_must_be_teams: Whether owners must be teams (bool)
             - allow_unowned_patterns: Whether to allow patterns without owners (bool)
             - skip_patterns: List of patterns to skip for not-owned check
+            - force_respect_gitignore: Respect .gitignore even without a .git directory (bool)
+            - tracked_files_only: List the files git tracks instead of walking the file
+              system (bool)
+            - git_rev: List files from the tree of this git revision, e.g. in a bare
+              repository (str)
+            - changed_files: Paths a change touches; the not-owned check only checks
+              these (list of str)
+            - non_utf8_policy: How file names that are not valid UTF-8 are handled: "warn"
+              (default), "lossy" or "skip"
+            - symlink_policy: How symbolic links are handled: "skip" (default), "follow" or
+              "report"
+            - max_walk_depth: How many directories deep to list files (int)
+            - excluded_dirs: Directories left out of file listings (list of str)
+            - max_walk_files: How many files to list at most when walking; walks that
+              reach it stop with a CO038 warning (int)
+            - walk_timeout: How many seconds walking may take; walks that run out of
+              time are abandoned with a CO038 warning (float)
             - repository: Repository in "owner/repo" format
+            - max_api_requests: Maximum number of GitHub API calls for the owners check (int)
+            - trusted_email_domains: List of trusted domains for email owners
+            - reject_email_owners: Whether to reject all email owners (bool)
+            - team_mapping: Dict mapping users to replacement teams (e.g. {"@alice": "@org/team"})
+            - derive_team_mapping: Suggest the one team listed in the file that a user is a
+              member of (bool, requires team_members on the github_client)
+            - question_mark: How '?' is read: "wildcard" (default), "literal" or "unsupported"
+            - pattern_syntax: "github" (default), "extended" to accept [abc] character classes,
+              or "gitlab" to also accept !pattern exclusions
+            - idp_export: Path to an IdP group export (SCIM JSON, or CSV with a .csv extension)
+            - idp_min_members: Minimum members an IdP group needs to back a team (int, default 1)
+            - ownership_manifest: Path to a JSON ownership manifest for the ownership-manifest check
+            - review_warning_days: Days before a review-by date to start warning (int, default 30)
+            - repeated_owners_threshold: Rules sharing an owner list before it is reported (int, default 3)
+            - max_rules: Rules in the file before the complexity check warns (int, default 500)
+            - max_rules_per_section: Rules in a section before the complexity check warns (int, default 100)
+            - max_file_lines: Lines in the file before the complexity check warns (int, default 1000)
+            - suppressed_codes: List of error codes whose issues are dropped (e.g. ["CO004"])
+            - admin_teams: List of teams of which one must own the CODEOWNERS file, for the
+              self-ownership check
+            - owners_lock: Path to a JSON owners lockfile for the owners-lock check
+            - check_empty_teams: Whether to report teams without members (bool, requires
+              team_member_count on the github_client)
+            - min_team_size: Warn about teams with fewer members (int, requires
+              team_member_count on the github_client)
+            - check_team_hierarchy: Warn about rules naming a parent team along with one
+              of its child teams (bool, requires team_parent on the github_client)
         checks: Optional list of checks to run. Valid values:
             - "syntax": Check for syntax errors
             - "files": Check that patterns match files
@@ -228,6 +331,24 @@ async def validate_codeowners(
             - "owners": Verify owners exist on GitHub (requires github_client)
             - "notowned": Check for files not covered by any rule (experimental)
             - "avoid-shadowing": Check for shadowed patterns (experimental)
+            - "case-collision": Check for patterns differing only in case (experimental)
+            - "generated-regions": Check for hand edits to generated regions (experimental)
+            - "idp-groups": Check teams against an IdP group export (experimental, requires idp_export)
+            - "review-by": Check for rules past or near their review-by date (experimental)
+            - "extension-override": Check for extension rules overridden by later directory rules (experimental)
+            - "anchoring": Check for unanchored directory patterns that also match nested directories (experimental)
+            - "gitlab-sections": Check GitLab section approval counts and default owners (experimental)
+            - "maintainability": Check for owner lists repeated across many rules (experimental)
+            - "complexity": Check the file against rule and line count budgets (experimental)
+            - "ownership-manifest": Check paths are owned by exactly the teams a manifest declares
+              (experimental, requires ownership_manifest)
+            - "self-ownership": Check the CODEOWNERS file itself is owned, optionally by an
+              admin team (experimental)
+            - "owners-lock": Check the owners of paths pinned in a lockfile did not change
+              (experimental, requires owners_lock)
+            - "escalation": Check escalation annotations name a 'provider:policy' (experimental)
+            - "annotations": Check the spelling and placement of 'codeowners:' directive
+              annotations (experimental)
         github_client: Optional GitHub client object implementing the GithubClientProtocol.
             Required for the "owners" check. Must have methods:
             user_exists(username) -> bool,
@@ -235,11 +356,12 @@ async def validate_codeowners(
 
     Returns:
         A dictionary with check results grouped by check name, where each entry contains:
-        - List of issues, each with: span, message, severity
+        - List of issues, each with: code, span, message, severity
 
     Raises:
         FileNotFoundError: If no CODEOWNERS file is found in the repository.
         IOError: If the CODEOWNERS file cannot be read.
+        ValueError: If ``git_rev`` does not name a commit.
 
     Example:
         >>> import asyncio
@@ -261,6 +383,131 @@ async def validate_codeowners(
     """
     ...
 
+class Validator:
+    """A reusable CODEOWNERS validator bound to one repository.
+
+    Repository file listings, parsed rules and owner lookups are cached
+    between calls to ``validate()``, so bots validating many pull requests
+    against the same checkout avoid repeating expensive work. Call
+    ``clear_cache()`` after the checkout changes, or ``reload()`` to apply a
+    new configuration. ``watch()`` reloads automatically when the CODEOWNERS
+    file or the project configuration changes. Used as a context manager,
+    the caches are dropped and watching stops on exit.
+
+    Args:
+        repo_path: Path to the repository root directory.
+        config: Optional configuration dictionary (see ``validate_codeowners``).
+        checks: Optional list of checks to run (see ``validate_codeowners``).
+        github_client: Optional GitHub client used by the "owners" check.
+
+    Raises:
+        ValueError: If ``git_rev`` does not name a commit.
+
+    Example:
+        >>> with Validator("/path/to/repo", github_client=client) as validator:
+        ...     for content in pull_request_contents:
+        ...         result = await validator.validate(content)
+    """
+
+    def __init__(
+        self,
+        repo_path: str,
+        config: CheckConfigDict | None = None,
+        checks: list[str] | None = None,
+        github_client: GithubClientProtocol | None = None,
+    ) -> None: ...
+    @property
+    def repo_path(self) -> str:
+        """The repository root this validator is bound to."""
+        ...
+
+    @property
+    def generation(self) -> int:
+        """How many times the validator has been reloaded."""
+        ...
+
+    async def validate(self, content: str | None = None) -> ValidationResultDict:
+        """Validate CODEOWNERS content against the repository.
+
+        Args:
+            content: CODEOWNERS content to validate. Defaults to the
+                repository's own CODEOWNERS file.
+
+        Returns:
+            The same dictionary as ``validate_codeowners``.
+
+        Raises:
+            FileNotFoundError: If no content is given and the repository has
+                no CODEOWNERS file.
+        """
+        ...
+
+    def reload(
+        self,
+        config: CheckConfigDict | None = None,
+        checks: list[str] | None = None,
+    ) -> int:
+        """Replace the configuration and checks, keeping the GitHub client.
+
+        Validations already running finish with the previous configuration.
+        Caches start empty after a reload.
+
+        Args:
+            config: New configuration dictionary (see ``validate_codeowners``).
+            checks: New list of checks to run (see ``validate_codeowners``).
+
+        Returns:
+            The reload generation, starting at 1 for the first reload.
+
+        Raises:
+            ValueError: If ``git_rev`` does not name a commit, or the project
+                configuration file given to ``watch()`` is invalid.
+        """
+        ...
+
+    def watch(self, config_file: str | PathLike[str] | None = None) -> int:
+        """Reload automatically when CODEOWNERS or the project configuration changes.
+
+        The project configuration file is applied beneath the ``config``
+        dictionary from then on, and the validator reloads once right away so
+        it takes effect. An invalid file is logged on later changes and the
+        previous configuration stays in use. Calling ``watch()`` again
+        replaces the previous watch.
+
+        Args:
+            config_file: Project configuration file. Defaults to
+                ``.codeowners-validator.toml`` in the repository root.
+
+        Returns:
+            The reload generation after the initial reload.
+
+        Raises:
+            ValueError: If the project configuration file is invalid.
+            OSError: If the files cannot be watched.
+        """
+        ...
+
+    def unwatch(self) -> None:
+        """Stop reloading on file changes. The current configuration is kept."""
+        ...
+
+    @property
+    def watching(self) -> bool:
+        """Whether the validator reloads on file changes."""
+        ...
+
+    def clear_cache(self) -> None:
+        """Drop cached file listings, parsed rules and owner lookups."""
+        ...
+
+    def __enter__(self) -> Validator: ...
+    def __exit__(
+        self,
+        exc_type: type[BaseException] | None,
+        exc_value: BaseException | None,
+        traceback: TracebackType | None,
+    ) -> bool: ...
+
 def generate_codeowners_fixture(
     num_rules: int = 100,
     num_comments: int = 20,
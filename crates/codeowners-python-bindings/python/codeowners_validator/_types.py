@@ -15,12 +15,14 @@ class SpanDict(TypedDict):
     line: int
     column: int
     length: int
+    end_line: int
+    end_column: int
 
 
 class OwnerDict(TypedDict):
     """An owner entry in a CODEOWNERS rule."""
 
-    type: Literal["user", "team", "email"]
+    type: Literal["user", "team", "email", "invalid"]
     text: str
     span: SpanDict
     # For user type
@@ -30,6 +32,9 @@ class OwnerDict(TypedDict):
     team: str  # only present for team
     # For email type
     email: str  # only present for email
+    # For invalid type
+    raw: str  # only present for invalid
+    reason: str  # only present for invalid
 
 
 class PatternDict(TypedDict):
@@ -42,12 +47,16 @@ class PatternDict(TypedDict):
 class LineKindDict(TypedDict, total=False):
     """The content of a line in a CODEOWNERS file."""
 
-    type: Literal["blank", "comment", "rule", "invalid"]
+    type: Literal["blank", "comment", "rule", "section", "invalid"]
     # For comment type
     content: str
     # For rule type
     pattern: PatternDict
     owners: list[OwnerDict]
+    # For section type (GitLab), along with owners
+    name: str
+    optional: bool
+    approvals: int | None  # None if missing or not a positive integer
     # For invalid type
     raw: str
     error: str
@@ -58,6 +67,9 @@ class LineDict(TypedDict):
 
     kind: LineKindDict
     span: SpanDict
+    # Owners not to request reviews from automatically (empty for all of the
+    # rule's owners), or None without a `codeowners: no-auto-request` annotation
+    no_auto_request: list[str] | None
 
 
 class AstDict(TypedDict):
@@ -77,12 +89,18 @@ class ParseResultDict(TypedDict):
 class IssueDict(TypedDict):
     """A validation issue."""
 
+    code: str
     span: SpanDict | None
     message: str
     severity: Literal["error", "warning"]
 
 
-class ValidationResultDict(TypedDict):
+class _ValidationResultExtras(TypedDict, total=False):
+    # Set when a check could not finish, e.g. because the GitHub client raised
+    internal_error: str
+
+
+class ValidationResultDict(_ValidationResultExtras):
     """The result of validating a CODEOWNERS file."""
 
     syntax: list[IssueDict]
@@ -99,7 +117,22 @@ class CheckConfigDict(TypedDict, total=False):
     owners_must_be_teams: bool
     allow_unowned_patterns: bool
     skip_patterns: list[str]
+    force_respect_gitignore: bool
+    tracked_files_only: bool
+    git_rev: str
+    changed_files: list[str]
+    symlink_policy: Literal["skip", "follow", "report"]
+    max_walk_depth: int
+    excluded_dirs: list[str]
+    max_walk_files: int
+    walk_timeout: float
     repository: str
+    max_api_requests: int
+    trusted_email_domains: list[str]
+    reject_email_owners: bool
+    team_mapping: dict[str, str]
+    derive_team_mapping: bool
+    suppressed_codes: list[str]
 
 
 class GithubClientProtocol(Protocol):
@@ -10,9 +10,15 @@ use codeowners_validator_core::validate::github_client::{
 use log::debug;
 use pyo3::prelude::*;
 
-/// Helper function to convert PyErr to GithubClientError.
-fn py_err_to_github_err(e: PyErr) -> GithubClientError {
-    GithubClientError::Other(e.to_string())
+/// Converts an exception raised by a Python client method into a
+/// [`GithubClientError::Callback`], keeping the formatted traceback.
+fn py_err_to_github_err(method: &str, e: PyErr) -> GithubClientError {
+    let traceback = Python::attach(|py| e.traceback(py).and_then(|tb| tb.format().ok()));
+    GithubClientError::Callback {
+        method: method.to_string(),
+        exception: e.to_string(),
+        traceback,
+    }
 }
 
 /// A Python-based GitHub client that delegates to a Python object.
@@ -28,6 +34,11 @@ impl PyGithubClient {
         Self { client }
     }
 
+    /// Returns true if the Python client implements the given method.
+    fn has_method(&self, method_name: &str) -> bool {
+        Python::attach(|py| self.client.bind(py).hasattr(method_name).unwrap_or(false))
+    }
+
     /// Helper to call a Python method (sync or async) and get the result.
     /// For async methods, this uses pyo3-async-runtimes to properly await the coroutine.
     async fn call_python_method_async(
@@ -54,7 +65,10 @@ impl PyGithubClient {
                     let client = client.bind(py);
 
                     // Check if the method exists
-                    if !client.hasattr(&method_name).map_err(py_err_to_github_err)? {
+                    if !client
+                        .hasattr(&method_name)
+                        .map_err(|e| py_err_to_github_err(&method_name, e))?
+                    {
                         return Err(GithubClientError::Other(format!(
                             "GitHub client does not have {} method",
                             method_name
@@ -63,21 +77,23 @@ impl PyGithubClient {
 
                     // Build args tuple
                     let py_args = pyo3::types::PyTuple::new(py, args.iter().map(|s| s.as_str()))
-                        .map_err(py_err_to_github_err)?;
+                        .map_err(|e| py_err_to_github_err(&method_name, e))?;
 
                     // Call the method
                     let result = client
                         .call_method1(&method_name, py_args)
-                        .map_err(py_err_to_github_err)?;
+                        .map_err(|e| py_err_to_github_err(&method_name, e))?;
 
                     // Check if it's a coroutine
-                    let is_coroutine = result.hasattr("__await__").map_err(py_err_to_github_err)?;
+                    let is_coroutine = result
+                        .hasattr("__await__")
+                        .map_err(|e| py_err_to_github_err(&method_name, e))?;
 
                     if is_coroutine {
                         // Convert the coroutine to a future inside the GIL
                         debug!("Python method returned a coroutine, awaiting...");
                         let future = pyo3_async_runtimes::tokio::into_future(result)
-                            .map_err(py_err_to_github_err)?;
+                            .map_err(|e| py_err_to_github_err(&method_name, e))?;
                         Ok((Some(Box::pin(future)), None))
                     } else {
                         // It's a regular value, return it directly
@@ -90,7 +106,7 @@ impl PyGithubClient {
         if let Some(future) = maybe_future {
             future
                 .await
-                .map_err(|e| GithubClientError::Other(e.to_string()))
+                .map_err(|e| py_err_to_github_err(&method_name, e))
         } else {
             Ok(maybe_result.expect("Either future or result should be Some"))
         }
@@ -179,6 +195,62 @@ impl GithubClient for PyGithubClient {
         debug!("Team '{}/{}' check result: {:?}", org, team, parsed);
         parsed
     }
+
+    async fn team_member_count(
+        &self,
+        org: &str,
+        team: &str,
+    ) -> Result<Option<usize>, GithubClientError> {
+        // The method is optional; without it team membership is unknown
+        if !self.has_method("team_member_count") {
+            debug!("GitHub client has no team_member_count method");
+            return Ok(None);
+        }
+
+        let result = self
+            .call_python_method_async("team_member_count", vec![org.to_string(), team.to_string()])
+            .await?;
+
+        // Parse the result - an int, or None if membership is unknown
+        let parsed = Python::attach(|py| {
+            let result = result.bind(py);
+            result.extract::<Option<usize>>().map_err(|_| {
+                GithubClientError::Other(
+                    "team_member_count returned an unexpected type".to_string(),
+                )
+            })
+        });
+
+        debug!("Team '{}/{}' member count: {:?}", org, team, parsed);
+        parsed
+    }
+
+    async fn team_parent(
+        &self,
+        org: &str,
+        team: &str,
+    ) -> Result<Option<String>, GithubClientError> {
+        // The method is optional; without it teams are treated as top-level
+        if !self.has_method("team_parent") {
+            debug!("GitHub client has no team_parent method");
+            return Ok(None);
+        }
+
+        let result = self
+            .call_python_method_async("team_parent", vec![org.to_string(), team.to_string()])
+            .await?;
+
+        // Parse the result - the parent's slug, or None for top-level teams
+        let parsed = Python::attach(|py| {
+            let result = result.bind(py);
+            result.extract::<Option<String>>().map_err(|_| {
+                GithubClientError::Other("team_parent returned an unexpected type".to_string())
+            })
+        });
+
+        debug!("Team '{}/{}' parent: {:?}", org, team, parsed);
+        parsed
+    }
 }
 
 // Safety: PyGithubClient is Send + Sync because it only contains a PyObject
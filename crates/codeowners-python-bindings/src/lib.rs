@@ -2,16 +2,18 @@
 //!
 //! This crate provides Python bindings using PyO3 for the codeowners-validator-core library.
 
-use log::{debug, info};
+use log::{debug, info, warn};
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 use std::collections::HashMap;
 
 mod github_client;
 mod types;
+mod validator;
 
 use github_client::PyGithubClient;
 use types::{PyIssue, PyLine};
+use validator::Validator;
 
 /// Parse a CODEOWNERS file content and return the parsed AST.
 ///
@@ -81,7 +83,51 @@ fn parse_codeowners(py: Python<'_>, content: &str) -> PyResult<Py<PyDict>> {
 ///         - owners_must_be_teams: Whether owners must be teams (bool)
 ///         - allow_unowned_patterns: Whether to allow patterns without owners (bool)
 ///         - skip_patterns: List of patterns to skip for not-owned check
+///         - force_respect_gitignore: Respect .gitignore even without a .git directory (bool)
+///         - tracked_files_only: List the files git tracks instead of walking the file
+///           system (bool)
+///         - git_rev: List files from the tree of this git revision, e.g. in a bare
+///           repository (str)
+///         - changed_files: Paths a change touches; the not-owned check only checks
+///           these (list of str)
+///         - non_utf8_policy: How file names that are not valid UTF-8 are handled: "warn"
+///           (default), "lossy" or "skip"
+///         - symlink_policy: How symbolic links are handled: "skip" (default), "follow" or
+///           "report"
+///         - max_walk_depth: How many directories deep to list files (int)
+///         - excluded_dirs: Directories left out of file listings (list of str)
+///         - max_walk_files: How many files to list at most when walking; walks that
+///           reach it stop with a CO038 warning (int)
+///         - walk_timeout: How many seconds walking may take; walks that run out of
+///           time are abandoned with a CO038 warning (float)
 ///         - repository: Repository in "owner/repo" format
+///         - max_api_requests: Maximum number of GitHub API calls for the owners check (int)
+///         - trusted_email_domains: List of trusted domains for email owners
+///         - reject_email_owners: Whether to reject all email owners (bool)
+///         - check_empty_teams: Whether to report teams without members (bool, requires
+///           team_member_count on the github_client)
+///         - min_team_size: Warn about teams with fewer members (int, requires
+///           team_member_count on the github_client)
+///         - check_team_hierarchy: Warn about rules naming a parent team along with one of
+///           its child teams (bool, requires team_parent on the github_client)
+///         - team_mapping: Dict mapping users to replacement teams (e.g. {"@alice": "@org/team"})
+///         - derive_team_mapping: Suggest the one team listed in the file that a user is a
+///           member of (bool, requires team_members on the github_client)
+///         - question_mark: How '?' is read: "wildcard" (default), "literal" or "unsupported"
+///         - pattern_syntax: "github" (default), "extended" to accept [abc] character classes,
+///           or "gitlab" to also accept !pattern exclusions
+///         - idp_export: Path to an IdP group export (SCIM JSON, or CSV with a .csv extension)
+///         - idp_min_members: Minimum members an IdP group needs to back a team (int, default 1)
+///         - ownership_manifest: Path to a JSON ownership manifest for the ownership-manifest check
+///         - review_warning_days: Days before a review-by date to start warning (int, default 30)
+///         - repeated_owners_threshold: Rules sharing an owner list before it is reported (int, default 3)
+///         - max_rules: Rules in the file before the complexity check warns (int, default 500)
+///         - max_rules_per_section: Rules in a section before the complexity check warns (int, default 100)
+///         - max_file_lines: Lines in the file before the complexity check warns (int, default 1000)
+///         - suppressed_codes: List of error codes whose issues are dropped (e.g. ["CO004"])
+///         - admin_teams: List of teams of which one must own the CODEOWNERS file, for the
+///           self-ownership check
+///         - owners_lock: Path to a JSON owners lockfile for the owners-lock check
 ///     checks: Optional list of checks to run. Valid values:
 ///         - "syntax": Check for syntax errors
 ///         - "files": Check that patterns match files
@@ -89,18 +135,42 @@ fn parse_codeowners(py: Python<'_>, content: &str) -> PyResult<Py<PyDict>> {
 ///         - "owners": Verify owners exist on GitHub (requires github_client)
 ///         - "notowned": Check for files not covered by any rule (experimental)
 ///         - "avoid-shadowing": Check for shadowed patterns (experimental)
+///         - "case-collision": Check for patterns differing only in case (experimental)
+///         - "generated-regions": Check for hand edits to generated regions (experimental)
+///         - "idp-groups": Check teams against an IdP group export (experimental, requires idp_export)
+///         - "review-by": Check for rules past or near their review-by date (experimental)
+///         - "extension-override": Check for extension rules overridden by later directory rules (experimental)
+///         - "anchoring": Check for unanchored directory patterns that also match nested directories (experimental)
+///         - "gitlab-sections": Check GitLab section approval counts and default owners (experimental)
+///         - "maintainability": Check for owner lists repeated across many rules (experimental)
+///         - "complexity": Check the file against rule and line count budgets (experimental)
+///         - "ownership-manifest": Check paths are owned by exactly the teams a manifest declares
+///           (experimental, requires ownership_manifest)
+///         - "self-ownership": Check the CODEOWNERS file itself is owned, optionally by an
+///           admin team (experimental)
+///         - "owners-lock": Check the owners of paths pinned in a lockfile did not change
+///           (experimental, requires owners_lock)
+///         - "escalation": Check escalation annotations name a 'provider:policy' (experimental)
+///         - "annotations": Check the spelling and placement of 'codeowners:' directive
+///           annotations (experimental)
 ///     github_client: Optional GitHub client object implementing the GithubClientProtocol.
 ///         Required for the "owners" check. Must have methods:
 ///         user_exists(username) -> bool,
 ///         team_exists(org, team) -> Literal["exists", "not_found", "unauthorized"]
+///         It may also have team_member_count(org, team) -> int | None, used when
+///         check_empty_teams or min_team_size is set, and team_parent(org, team) -> str | None,
+///         used when check_team_hierarchy is set.
 ///
 /// Returns:
 ///     A dictionary with check results grouped by check name, where each entry contains:
-///     - List of issues, each with: span, message, severity
+///     - List of issues, each with: code, span, message, severity
+///     When a check could not finish, for example because the GitHub client raised,
+///     `internal_error` describes why; the run must then be treated as failed.
 ///
 /// Raises:
 ///     FileNotFoundError: If no CODEOWNERS file is found in the repository.
 ///     IOError: If the CODEOWNERS file cannot be read.
+///     ValueError: If `git_rev` does not name a commit.
 ///
 /// Example:
 ///     >>> import asyncio
@@ -139,11 +209,7 @@ fn validate_codeowners<'py>(
     // Create the async coroutine
     let repo_path = repo_path.to_string();
     let github_client = github_client.map(|c| c.unbind());
-    let config_dict: Option<HashMap<String, Py<PyAny>>> = config.map(|c| {
-        c.iter()
-            .filter_map(|(k, v)| k.extract::<String>().ok().map(|key| (key, v.unbind())))
-            .collect()
-    });
+    let config_dict = config_map(config);
 
     pyo3_async_runtimes::tokio::future_into_py(py, async move {
         validate_codeowners_impl(&repo_path, config_dict.as_ref(), checks, github_client).await
@@ -156,38 +222,41 @@ async fn validate_codeowners_impl(
     checks: Option<Vec<String>>,
     github_client: Option<Py<PyAny>>,
 ) -> PyResult<Py<PyDict>> {
-    use codeowners_validator_core::validate::checks::{CheckRunner, OwnersCheck};
-
     debug!("validate_codeowners_impl starting for: {}", repo_path);
 
     let repo_path_buf = std::path::Path::new(repo_path);
 
     // Find the CODEOWNERS file
     debug!("Searching for CODEOWNERS file in: {}", repo_path);
-    let codeowners_path =
-        codeowners_validator_core::find_codeowners_file(repo_path_buf).ok_or_else(|| {
-            pyo3::exceptions::PyFileNotFoundError::new_err(format!(
-            "CODEOWNERS file not found in repository '{}'. Searched in: .github/CODEOWNERS, CODEOWNERS, docs/CODEOWNERS",
-            repo_path
-        ))
-        })?;
+    let codeowners_path = find_codeowners(repo_path_buf)?;
 
     info!("Found CODEOWNERS file at: {}", codeowners_path.display());
 
     // Read the CODEOWNERS file
-    let content = std::fs::read_to_string(&codeowners_path).map_err(|e| {
-        pyo3::exceptions::PyIOError::new_err(format!(
-            "Failed to read CODEOWNERS file '{}': {}",
-            codeowners_path.display(),
-            e
-        ))
-    })?;
+    let content = read_codeowners(&codeowners_path)?;
 
     debug!("Read CODEOWNERS file: {} bytes", content.len());
 
-    // Parse the content
+    // Build check config from Python dict
+    let check_config = Python::attach(|py| {
+        build_check_config(
+            py,
+            config,
+            codeowners_validator_core::validate::checks::CheckConfig::new(),
+        )
+    });
+
+    verify_git_rev(repo_path_buf, &check_config)
+        .map_err(pyo3::exceptions::PyValueError::new_err)?;
+
+    // Parse the content in the dialect of the pattern syntax, accepting
+    // exclusions and sections if it allows them
     debug!("Parsing CODEOWNERS content");
-    let parse_result = codeowners_validator_core::parse::parse_codeowners(&content);
+    let parser_config = codeowners_validator_core::parse::ParserConfig::new()
+        .with_dialect(check_config.pattern_syntax.into())
+        .with_pattern_syntax(check_config.pattern_syntax);
+    let parse_result =
+        codeowners_validator_core::parse::parse_codeowners_with_config(&content, &parser_config);
 
     debug!(
         "Parsing complete: {} lines, {} parse errors",
@@ -195,10 +264,115 @@ async fn validate_codeowners_impl(
         parse_result.errors.len()
     );
 
-    // Build check config from Python dict
-    let check_config = Python::attach(|py| match config {
+    // Determine which checks to run
+    let checks_to_run = checks.unwrap_or_else(|| default_checks(github_client.is_some()));
+
+    info!("Running checks: {:?}", checks_to_run);
+
+    let (runner, run_owners) = build_runner(&checks_to_run, github_client.is_some());
+
+    // Run all checks using CheckRunner
+    debug!("Starting check execution (owners check: {})", run_owners);
+
+    let validation_result = if run_owners {
+        debug!("Using GitHub client for owner verification");
+        let py_client =
+            Python::attach(|py| PyGithubClient::new(github_client.as_ref().unwrap().clone_ref(py)));
+        runner
+            .run_all(
+                &parse_result.ast,
+                repo_path_buf,
+                &check_config,
+                Some(
+                    &py_client
+                        as &dyn codeowners_validator_core::validate::github_client::GithubClient,
+                ),
+            )
+            .await
+    } else {
+        runner
+            .run_all(&parse_result.ast, repo_path_buf, &check_config, None)
+            .await
+    };
+
+    info!(
+        "Validation complete: {} total issues found",
+        validation_result.errors.len()
+    );
+    if let Some(error) = &validation_result.internal_error {
+        warn!("Validation incomplete: {}", error);
+    }
+
+    let relative_path = relative_codeowners_path(repo_path_buf, &codeowners_path);
+    Python::attach(|py| results_to_dict(py, &validation_result, &relative_path))
+}
+
+/// Rejects a `git_rev` option that does not name a commit, so a typo is
+/// reported instead of every file check listing nothing.
+fn verify_git_rev(
+    repo_path: &std::path::Path,
+    config: &codeowners_validator_core::validate::checks::CheckConfig,
+) -> Result<(), String> {
+    let Some(rev) = &config.git_rev else {
+        return Ok(());
+    };
+    codeowners_validator_core::vcs::GitTreeSource::new(repo_path, rev)
+        .verify()
+        .map_err(|e| format!("git_rev: {}", e))
+}
+
+/// Locates the CODEOWNERS file in one of the standard locations.
+fn find_codeowners(repo_path: &std::path::Path) -> PyResult<std::path::PathBuf> {
+    codeowners_validator_core::find_codeowners_file(repo_path).ok_or_else(|| {
+        pyo3::exceptions::PyFileNotFoundError::new_err(format!(
+            "CODEOWNERS file not found in repository '{}'. Searched in: .github/CODEOWNERS, CODEOWNERS, docs/CODEOWNERS",
+            repo_path.display()
+        ))
+    })
+}
+
+/// Reads a CODEOWNERS file, mapping I/O failures to `IOError`.
+fn read_codeowners(codeowners_path: &std::path::Path) -> PyResult<String> {
+    std::fs::read_to_string(codeowners_path).map_err(|e| {
+        pyo3::exceptions::PyIOError::new_err(format!(
+            "Failed to read CODEOWNERS file '{}': {}",
+            codeowners_path.display(),
+            e
+        ))
+    })
+}
+
+/// Returns the CODEOWNERS path relative to the repository root.
+fn relative_codeowners_path(
+    repo_path: &std::path::Path,
+    codeowners_path: &std::path::Path,
+) -> String {
+    codeowners_path
+        .strip_prefix(repo_path)
+        .unwrap_or(codeowners_path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Copies a Python config dict into owned key/value pairs.
+fn config_map(config: Option<&Bound<'_, PyDict>>) -> Option<HashMap<String, Py<PyAny>>> {
+    config.map(|c| {
+        c.iter()
+            .filter_map(|(k, v)| k.extract::<String>().ok().map(|key| (key, v.unbind())))
+            .collect()
+    })
+}
+
+/// Builds the check configuration from a Python config dict, on top of
+/// `base`.
+fn build_check_config(
+    py: Python<'_>,
+    config: Option<&HashMap<String, Py<PyAny>>>,
+    base: codeowners_validator_core::validate::checks::CheckConfig,
+) -> codeowners_validator_core::validate::checks::CheckConfig {
+    match config {
         Some(cfg) => {
-            let mut config = codeowners_validator_core::validate::checks::CheckConfig::new();
+            let mut config = base;
 
             if let Some(obj) = cfg.get("ignored_owners")
                 && let Ok(list) = obj.bind(py).extract::<Vec<String>>()
@@ -220,49 +394,291 @@ async fn validate_codeowners_impl(
             {
                 config = config.with_skip_patterns(list);
             }
+            if let Some(obj) = cfg.get("force_respect_gitignore")
+                && let Ok(val) = obj.bind(py).extract::<bool>()
+            {
+                config = config.with_force_respect_gitignore(val);
+            }
+            if let Some(obj) = cfg.get("tracked_files_only")
+                && let Ok(val) = obj.bind(py).extract::<bool>()
+            {
+                config = config.with_tracked_files_only(val);
+            }
+            if let Some(obj) = cfg.get("git_rev")
+                && let Ok(val) = obj.bind(py).extract::<String>()
+            {
+                config = config.with_git_rev(val);
+            }
+            if let Some(obj) = cfg.get("changed_files")
+                && let Ok(list) = obj.bind(py).extract::<Vec<String>>()
+            {
+                config = config.with_changed_files(list);
+            }
+            if let Some(obj) = cfg.get("non_utf8_policy")
+                && let Ok(val) = obj.bind(py).extract::<String>()
+            {
+                use codeowners_validator_core::validate::file_walker::NonUtf8Policy;
+                let policy = match val.as_str() {
+                    "lossy" => NonUtf8Policy::Lossy,
+                    "skip" => NonUtf8Policy::Skip,
+                    _ => NonUtf8Policy::Warn,
+                };
+                config = config.with_non_utf8_policy(policy);
+            }
+            if let Some(obj) = cfg.get("symlink_policy")
+                && let Ok(val) = obj.bind(py).extract::<String>()
+            {
+                use codeowners_validator_core::validate::file_walker::SymlinkPolicy;
+                let policy = match val.as_str() {
+                    "follow" => SymlinkPolicy::Follow,
+                    "report" => SymlinkPolicy::Report,
+                    _ => SymlinkPolicy::Skip,
+                };
+                config = config.with_symlink_policy(policy);
+            }
+            if let Some(obj) = cfg.get("max_walk_depth")
+                && let Ok(val) = obj.bind(py).extract::<usize>()
+            {
+                config = config.with_max_walk_depth(val);
+            }
+            if let Some(obj) = cfg.get("excluded_dirs")
+                && let Ok(list) = obj.bind(py).extract::<Vec<String>>()
+            {
+                config = config.with_excluded_dirs(list);
+            }
+            if let Some(obj) = cfg.get("max_walk_files")
+                && let Ok(val) = obj.bind(py).extract::<usize>()
+            {
+                config = config.with_max_walk_files(val);
+            }
+            if let Some(obj) = cfg.get("walk_timeout")
+                && let Ok(val) = obj.bind(py).extract::<f64>()
+                && let Ok(timeout) = std::time::Duration::try_from_secs_f64(val)
+            {
+                config = config.with_walk_timeout(timeout);
+            }
             if let Some(obj) = cfg.get("repository")
                 && let Ok(val) = obj.bind(py).extract::<String>()
             {
                 config = config.with_repository(val);
             }
+            if let Some(obj) = cfg.get("max_api_requests")
+                && let Ok(val) = obj.bind(py).extract::<usize>()
+            {
+                config = config.with_max_api_requests(val);
+            }
+            if let Some(obj) = cfg.get("trusted_email_domains")
+                && let Ok(list) = obj.bind(py).extract::<Vec<String>>()
+            {
+                config = config.with_trusted_email_domains(list.into_iter().collect());
+            }
+            if let Some(obj) = cfg.get("reject_email_owners")
+                && let Ok(val) = obj.bind(py).extract::<bool>()
+            {
+                config = config.with_reject_email_owners(val);
+            }
+            if let Some(obj) = cfg.get("check_empty_teams")
+                && let Ok(val) = obj.bind(py).extract::<bool>()
+            {
+                config = config.with_check_empty_teams(val);
+            }
+            if let Some(obj) = cfg.get("min_team_size")
+                && let Ok(val) = obj.bind(py).extract::<usize>()
+            {
+                config = config.with_min_team_size(val);
+            }
+            if let Some(obj) = cfg.get("check_team_hierarchy")
+                && let Ok(val) = obj.bind(py).extract::<bool>()
+            {
+                config = config.with_check_team_hierarchy(val);
+            }
+            if let Some(obj) = cfg.get("review_warning_days")
+                && let Ok(val) = obj.bind(py).extract::<u32>()
+            {
+                config = config.with_review_warning_days(val);
+            }
+            if let Some(obj) = cfg.get("repeated_owners_threshold")
+                && let Ok(val) = obj.bind(py).extract::<usize>()
+            {
+                config = config.with_repeated_owners_threshold(val);
+            }
+            if let Some(obj) = cfg.get("max_rules")
+                && let Ok(val) = obj.bind(py).extract::<usize>()
+            {
+                config = config.with_max_rules(val);
+            }
+            if let Some(obj) = cfg.get("max_rules_per_section")
+                && let Ok(val) = obj.bind(py).extract::<usize>()
+            {
+                config = config.with_max_rules_per_section(val);
+            }
+            if let Some(obj) = cfg.get("max_file_lines")
+                && let Ok(val) = obj.bind(py).extract::<usize>()
+            {
+                config = config.with_max_file_lines(val);
+            }
+            if let Some(obj) = cfg.get("suppressed_codes")
+                && let Ok(list) = obj.bind(py).extract::<Vec<String>>()
+            {
+                config = config.with_suppressed_codes(list.into_iter().collect());
+            }
+            if let Some(obj) = cfg.get("admin_teams")
+                && let Ok(list) = obj.bind(py).extract::<Vec<String>>()
+            {
+                config = config.with_admin_teams(list.into_iter().collect());
+            }
+            if let Some(obj) = cfg.get("team_mapping")
+                && let Ok(map) = obj.bind(py).extract::<HashMap<String, String>>()
+            {
+                config = config.with_team_mapping(map);
+            }
+            if let Some(obj) = cfg.get("derive_team_mapping")
+                && let Ok(val) = obj.bind(py).extract::<bool>()
+            {
+                config = config.with_derive_team_mapping(val);
+            }
+            if let Some(obj) = cfg.get("question_mark")
+                && let Ok(val) = obj.bind(py).extract::<String>()
+            {
+                use codeowners_validator_core::matching::QuestionMark;
+                let question_mark = match val.as_str() {
+                    "literal" => QuestionMark::Literal,
+                    "unsupported" => QuestionMark::Unsupported,
+                    _ => QuestionMark::Wildcard,
+                };
+                config = config.with_question_mark(question_mark);
+            }
+            if let Some(obj) = cfg.get("pattern_syntax")
+                && let Ok(val) = obj.bind(py).extract::<String>()
+            {
+                use codeowners_validator_core::matching::PatternSyntax;
+                let pattern_syntax = match val.as_str() {
+                    "extended" => PatternSyntax::Extended,
+                    "gitlab" => PatternSyntax::GitLab,
+                    _ => PatternSyntax::GitHub,
+                };
+                config = config.with_pattern_syntax(pattern_syntax);
+            }
+            if let Some(obj) = cfg.get("idp_export")
+                && let Ok(path) = obj.bind(py).extract::<std::path::PathBuf>()
+            {
+                use codeowners_validator_core::validate::idp::IdpDirectory;
+                let is_csv = path
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+                let directory = std::fs::read_to_string(&path)
+                    .map_err(|e| e.to_string())
+                    .and_then(|content| {
+                        if is_csv {
+                            IdpDirectory::from_csv(&content)
+                        } else {
+                            IdpDirectory::from_json(&content)
+                        }
+                        .map_err(|e| e.to_string())
+                    });
+                match directory {
+                    Ok(mut directory) => {
+                        if let Some(obj) = cfg.get("idp_min_members")
+                            && let Ok(min) = obj.bind(py).extract::<usize>()
+                        {
+                            directory = directory.with_min_members(min);
+                        }
+                        config = config.with_idp_directory(directory);
+                    }
+                    Err(e) => warn!("Ignoring IdP export '{}': {}", path.display(), e),
+                }
+            }
+            if let Some(obj) = cfg.get("ownership_manifest")
+                && let Ok(path) = obj.bind(py).extract::<std::path::PathBuf>()
+            {
+                use codeowners_validator_core::validate::manifest::OwnershipManifest;
+                let manifest = std::fs::read_to_string(&path)
+                    .map_err(|e| e.to_string())
+                    .and_then(|content| {
+                        OwnershipManifest::from_json(&content).map_err(|e| e.to_string())
+                    });
+                match manifest {
+                    Ok(manifest) => config = config.with_ownership_manifest(manifest),
+                    Err(e) => warn!("Ignoring ownership manifest '{}': {}", path.display(), e),
+                }
+            }
+            if let Some(obj) = cfg.get("owners_lock")
+                && let Ok(path) = obj.bind(py).extract::<std::path::PathBuf>()
+            {
+                use codeowners_validator_core::validate::lockfile::OwnersLock;
+                let lock = std::fs::read_to_string(&path)
+                    .map_err(|e| e.to_string())
+                    .and_then(|content| OwnersLock::from_json(&content).map_err(|e| e.to_string()));
+                match lock {
+                    Ok(lock) => config = config.with_owners_lock(lock),
+                    Err(e) => warn!("Ignoring owners lockfile '{}': {}", path.display(), e),
+                }
+            }
             config
         }
-        None => codeowners_validator_core::validate::checks::CheckConfig::new(),
-    });
-
-    // Determine which checks to run
-    let checks_to_run = checks.unwrap_or_else(|| {
-        let mut default = vec![
-            "syntax".to_string(),
-            "files".to_string(),
-            "duppatterns".to_string(),
-        ];
-        // Only include owners check by default if github_client is provided
-        if github_client.is_some() {
-            default.push("owners".to_string());
-        }
-        default
-    });
+        None => base,
+    }
+}
 
-    info!("Running checks: {:?}", checks_to_run);
+/// Returns the checks run when the caller does not choose any.
+fn default_checks(has_github_client: bool) -> Vec<String> {
+    let mut default = vec![
+        "syntax".to_string(),
+        "files".to_string(),
+        "duppatterns".to_string(),
+    ];
+    // Only include owners check by default if github_client is provided
+    if has_github_client {
+        default.push("owners".to_string());
+    }
+    default
+}
 
-    // Build CheckRunner with requested checks
+/// Builds a runner for the named checks.
+///
+/// Returns the runner and whether the owners check was added, which only
+/// happens when a GitHub client is available.
+fn build_runner(
+    checks: &[String],
+    has_github_client: bool,
+) -> (
+    codeowners_validator_core::validate::checks::CheckRunner,
+    bool,
+) {
     use codeowners_validator_core::validate::checks::{
-        AvoidShadowingCheck, DupPatternsCheck, FilesCheck, NotOwnedCheck, SyntaxCheck,
+        AnchoringCheck, AnnotationsCheck, AvoidShadowingCheck, CaseCollisionCheck, CheckRunner,
+        ComplexityCheck, DupPatternsCheck, EscalationCheck, ExtensionOverrideCheck, FilesCheck,
+        GeneratedRegionsCheck, GitLabSectionsCheck, IdpGroupsCheck, MaintainabilityCheck,
+        NotOwnedCheck, OwnersCheck, OwnersLockCheck, OwnershipManifestCheck, ReviewByCheck,
+        SelfOwnershipCheck, SyntaxCheck,
     };
 
     let mut runner = CheckRunner::new();
     let mut run_owners = false;
 
-    for check_name in &checks_to_run {
+    for check_name in checks {
         match check_name.as_str() {
             "syntax" => runner.add_check(SyntaxCheck::new()),
             "files" => runner.add_check(FilesCheck::new()),
             "duppatterns" => runner.add_check(DupPatternsCheck::new()),
             "notowned" => runner.add_check(NotOwnedCheck::new()),
             "avoid-shadowing" | "shadowing" => runner.add_check(AvoidShadowingCheck::new()),
+            "case-collision" => runner.add_check(CaseCollisionCheck::new()),
+            "generated-regions" => runner.add_check(GeneratedRegionsCheck::new()),
+            "idp-groups" => runner.add_check(IdpGroupsCheck::new()),
+            "review-by" => runner.add_check(ReviewByCheck::new()),
+            "extension-override" => runner.add_check(ExtensionOverrideCheck::new()),
+            "anchoring" => runner.add_check(AnchoringCheck::new()),
+            "gitlab-sections" => runner.add_check(GitLabSectionsCheck::new()),
+            "maintainability" => runner.add_check(MaintainabilityCheck::new()),
+            "complexity" => runner.add_check(ComplexityCheck::new()),
+            "ownership-manifest" => runner.add_check(OwnershipManifestCheck::new()),
+            "self-ownership" => runner.add_check(SelfOwnershipCheck::new()),
+            "owners-lock" => runner.add_check(OwnersLockCheck::new()),
+            "escalation" => runner.add_check(EscalationCheck::new()),
+            "annotations" => runner.add_check(AnnotationsCheck::new()),
             "owners" => {
-                if github_client.is_some() {
+                if has_github_client {
                     runner.add_async_check(OwnersCheck::new());
                     run_owners = true;
                 }
@@ -272,136 +688,268 @@ async fn validate_codeowners_impl(
         };
     }
 
-    // Run all checks using CheckRunner
-    debug!("Starting check execution (owners check: {})", run_owners);
-
-    let validation_result = if run_owners {
-        debug!("Using GitHub client for owner verification");
-        let py_client =
-            Python::attach(|py| PyGithubClient::new(github_client.as_ref().unwrap().clone_ref(py)));
-        runner
-            .run_all(
-                &parse_result.ast,
-                repo_path_buf,
-                &check_config,
-                Some(
-                    &py_client
-                        as &dyn codeowners_validator_core::validate::github_client::GithubClient,
-                ),
-            )
-            .await
-    } else {
-        runner
-            .run_all(&parse_result.ast, repo_path_buf, &check_config, None)
-            .await
-    };
-
-    info!(
-        "Validation complete: {} total issues found",
-        validation_result.errors.len()
-    );
+    (runner, run_owners)
+}
 
-    // Convert results to Python dict
-    // Group errors by check name based on the error type
-    Python::attach(|py| {
-        let result_dict = PyDict::new(py);
-
-        // Initialize empty lists for all possible checks
-        for check_name in &[
-            "syntax",
-            "files",
-            "duppatterns",
-            "owners",
-            "notowned",
-            "avoid-shadowing",
-        ] {
-            let empty_list: Vec<HashMap<String, Py<PyAny>>> = vec![];
-            result_dict.set_item(*check_name, empty_list)?;
-        }
+/// Converts validation results to the Python result dict, grouping issues
+/// by the check that reports them.
+fn results_to_dict(
+    py: Python<'_>,
+    validation_result: &codeowners_validator_core::validate::ValidationResult,
+    relative_path: &str,
+) -> PyResult<Py<PyDict>> {
+    let result_dict = PyDict::new(py);
+
+    // Initialize empty lists for all possible checks
+    for check_name in &[
+        "syntax",
+        "files",
+        "duppatterns",
+        "owners",
+        "notowned",
+        "avoid-shadowing",
+        "case-collision",
+        "generated-regions",
+        "idp-groups",
+        "review-by",
+        "extension-override",
+        "anchoring",
+        "gitlab-sections",
+        "maintainability",
+        "complexity",
+        "ownership-manifest",
+        "self-ownership",
+        "owners-lock",
+        "escalation",
+        "annotations",
+    ] {
+        let empty_list: Vec<HashMap<String, Py<PyAny>>> = vec![];
+        result_dict.set_item(*check_name, empty_list)?;
+    }
 
-        // Group errors by their source check
-        use codeowners_validator_core::validate::ValidationError;
-
-        let mut syntax_errors = Vec::new();
-        let mut files_errors = Vec::new();
-        let mut duppatterns_errors = Vec::new();
-        let mut owners_errors = Vec::new();
-        let mut notowned_errors = Vec::new();
-        let mut shadowing_errors = Vec::new();
-
-        for error in &validation_result.errors {
-            match error {
-                ValidationError::InvalidPatternSyntax { .. }
-                | ValidationError::InvalidOwnerFormat { .. }
-                | ValidationError::UnsupportedPatternSyntax { .. } => {
-                    syntax_errors.push(error);
-                }
-                ValidationError::PatternNotMatching { .. } => {
-                    files_errors.push(error);
-                }
-                ValidationError::DuplicatePattern { .. } => {
-                    duppatterns_errors.push(error);
-                }
-                ValidationError::OwnerNotFound { .. }
-                | ValidationError::InsufficientAuthorization { .. }
-                | ValidationError::OwnerMustBeTeam { .. } => {
-                    owners_errors.push(error);
-                }
-                ValidationError::FileNotOwned { .. } => {
-                    notowned_errors.push(error);
-                }
-                ValidationError::PatternShadowed { .. } => {
-                    shadowing_errors.push(error);
-                }
+    // Group errors by their source check
+    use codeowners_validator_core::validate::ValidationError;
+
+    let mut syntax_errors = Vec::new();
+    let mut files_errors = Vec::new();
+    let mut duppatterns_errors = Vec::new();
+    let mut owners_errors = Vec::new();
+    let mut notowned_errors = Vec::new();
+    let mut shadowing_errors = Vec::new();
+    let mut case_collision_errors = Vec::new();
+    let mut generated_errors = Vec::new();
+    let mut idp_errors = Vec::new();
+    let mut review_by_errors = Vec::new();
+    let mut extension_override_errors = Vec::new();
+    let mut anchoring_errors = Vec::new();
+    let mut sections_errors = Vec::new();
+    let mut maintainability_errors = Vec::new();
+    let mut complexity_errors = Vec::new();
+    let mut manifest_errors = Vec::new();
+    let mut self_ownership_errors = Vec::new();
+    let mut owners_lock_errors = Vec::new();
+    let mut escalation_errors = Vec::new();
+    let mut annotations_errors = Vec::new();
+    let mut custom_errors: HashMap<&str, Vec<&ValidationError>> = HashMap::new();
+
+    for error in &validation_result.errors {
+        match error {
+            ValidationError::InvalidPatternSyntax { .. }
+            | ValidationError::InvalidOwnerFormat { .. }
+            | ValidationError::UnsupportedPatternSyntax { .. }
+            | ValidationError::UntrustedEmailDomain { .. }
+            | ValidationError::EmailOwnerNotAllowed { .. } => {
+                syntax_errors.push(error);
+            }
+            ValidationError::PatternNotMatching { .. }
+            | ValidationError::AmbiguousQuestionMark { .. } => {
+                files_errors.push(error);
+            }
+            ValidationError::DuplicatePattern { .. }
+            | ValidationError::DuplicateOwner { .. }
+            | ValidationError::InconsistentOwnerCase { .. } => {
+                duppatterns_errors.push(error);
             }
+            ValidationError::OwnerNotFound { .. }
+            | ValidationError::InsufficientAuthorization { .. }
+            | ValidationError::OwnerTeamEmpty { .. }
+            | ValidationError::OwnerTeamTooSmall { .. }
+            | ValidationError::RedundantParentTeam { .. }
+            | ValidationError::OwnerMustBeTeam { .. }
+            | ValidationError::OwnerNotChecked { .. } => {
+                owners_errors.push(error);
+            }
+            ValidationError::FileNotOwned { .. }
+            | ValidationError::NonUtf8Path { .. }
+            | ValidationError::SymlinkPath { .. }
+            | ValidationError::IncompleteListing { .. } => {
+                notowned_errors.push(error);
+            }
+            ValidationError::PatternShadowed { .. } => {
+                shadowing_errors.push(error);
+            }
+            ValidationError::PatternCaseCollision { .. } => {
+                case_collision_errors.push(error);
+            }
+            ValidationError::GeneratedRegionModified { .. } => {
+                generated_errors.push(error);
+            }
+            ValidationError::IdpGroupMismatch { .. } => {
+                idp_errors.push(error);
+            }
+            ValidationError::OwnershipReviewDue { .. } => {
+                review_by_errors.push(error);
+            }
+            ValidationError::ExtensionRuleOverridden { .. } => {
+                extension_override_errors.push(error);
+            }
+            ValidationError::UnanchoredDirectory { .. } => {
+                anchoring_errors.push(error);
+            }
+            ValidationError::InvalidSectionApprovals { .. } => {
+                sections_errors.push(error);
+            }
+            ValidationError::RepeatedOwnerGroup { .. } => {
+                maintainability_errors.push(error);
+            }
+            ValidationError::ComplexityBudgetExceeded { .. } => {
+                complexity_errors.push(error);
+            }
+            ValidationError::OwnershipManifestMismatch { .. } => {
+                manifest_errors.push(error);
+            }
+            ValidationError::CodeownersNotOwned { .. }
+            | ValidationError::CodeownersNotAdminOwned { .. } => {
+                self_ownership_errors.push(error);
+            }
+            ValidationError::PinnedOwnersChanged { .. } => {
+                owners_lock_errors.push(error);
+            }
+            ValidationError::InvalidEscalation { .. } => {
+                escalation_errors.push(error);
+            }
+            ValidationError::InvalidAnnotation { .. } => {
+                annotations_errors.push(error);
+            }
+            ValidationError::CustomCheckFinding { check, .. } => {
+                custom_errors.entry(check.as_str()).or_default().push(error);
+            }
+            // Kinds of errors this module does not know are grouped by code
+            _ => custom_errors.entry(error.code()).or_default().push(error),
         }
+    }
 
-        debug!(
-            "Issues by category - syntax: {}, files: {}, duppatterns: {}, owners: {}, notowned: {}, shadowing: {}",
-            syntax_errors.len(),
-            files_errors.len(),
-            duppatterns_errors.len(),
-            owners_errors.len(),
-            notowned_errors.len(),
-            shadowing_errors.len()
-        );
-
-        // Calculate the relative path of the CODEOWNERS file to the repo root
-        let relative_path = codeowners_path
-            .strip_prefix(repo_path_buf)
-            .unwrap_or(&codeowners_path)
-            .to_string_lossy()
-            .to_string();
-
-        // Convert each group to Python
-        let convert_errors = |errors: Vec<&ValidationError>,
-                              py: Python<'_>,
-                              path: &str|
-         -> PyResult<Vec<Py<PyAny>>> {
-            errors
-                .iter()
-                .map(|e| PyIssue::new(e, path.to_string()).to_py(py))
-                .collect()
-        };
+    debug!(
+        "Issues by category - syntax: {}, files: {}, duppatterns: {}, owners: {}, notowned: {}, shadowing: {}, case-collision: {}, generated-regions: {}, idp-groups: {}, review-by: {}, extension-override: {}, anchoring: {}, gitlab-sections: {}, maintainability: {}, complexity: {}, ownership-manifest: {}, self-ownership: {}, owners-lock: {}, escalation: {}, annotations: {}",
+        syntax_errors.len(),
+        files_errors.len(),
+        duppatterns_errors.len(),
+        owners_errors.len(),
+        notowned_errors.len(),
+        shadowing_errors.len(),
+        case_collision_errors.len(),
+        generated_errors.len(),
+        idp_errors.len(),
+        review_by_errors.len(),
+        extension_override_errors.len(),
+        anchoring_errors.len(),
+        sections_errors.len(),
+        maintainability_errors.len(),
+        complexity_errors.len(),
+        manifest_errors.len(),
+        self_ownership_errors.len(),
+        owners_lock_errors.len(),
+        escalation_errors.len(),
+        annotations_errors.len()
+    );
 
-        result_dict.set_item("syntax", convert_errors(syntax_errors, py, &relative_path)?)?;
-        result_dict.set_item("files", convert_errors(files_errors, py, &relative_path)?)?;
-        result_dict.set_item(
-            "duppatterns",
-            convert_errors(duppatterns_errors, py, &relative_path)?,
-        )?;
-        result_dict.set_item("owners", convert_errors(owners_errors, py, &relative_path)?)?;
-        result_dict.set_item(
-            "notowned",
-            convert_errors(notowned_errors, py, &relative_path)?,
-        )?;
-        result_dict.set_item(
-            "avoid-shadowing",
-            convert_errors(shadowing_errors, py, &relative_path)?,
-        )?;
-
-        Ok(result_dict.into())
-    })
+    // Convert each group to Python
+    let convert_errors = |errors: Vec<&ValidationError>,
+                          py: Python<'_>,
+                          path: &str|
+     -> PyResult<Vec<Py<PyAny>>> {
+        errors
+            .iter()
+            .map(|e| PyIssue::new(e, path.to_string(), validation_result.severity_of(e)).to_py(py))
+            .collect()
+    };
+
+    result_dict.set_item("syntax", convert_errors(syntax_errors, py, relative_path)?)?;
+    result_dict.set_item("files", convert_errors(files_errors, py, relative_path)?)?;
+    result_dict.set_item(
+        "duppatterns",
+        convert_errors(duppatterns_errors, py, relative_path)?,
+    )?;
+    result_dict.set_item("owners", convert_errors(owners_errors, py, relative_path)?)?;
+    result_dict.set_item(
+        "notowned",
+        convert_errors(notowned_errors, py, relative_path)?,
+    )?;
+    result_dict.set_item(
+        "avoid-shadowing",
+        convert_errors(shadowing_errors, py, relative_path)?,
+    )?;
+    result_dict.set_item(
+        "case-collision",
+        convert_errors(case_collision_errors, py, relative_path)?,
+    )?;
+    result_dict.set_item(
+        "generated-regions",
+        convert_errors(generated_errors, py, relative_path)?,
+    )?;
+    result_dict.set_item("idp-groups", convert_errors(idp_errors, py, relative_path)?)?;
+    result_dict.set_item(
+        "review-by",
+        convert_errors(review_by_errors, py, relative_path)?,
+    )?;
+    result_dict.set_item(
+        "extension-override",
+        convert_errors(extension_override_errors, py, relative_path)?,
+    )?;
+    result_dict.set_item(
+        "anchoring",
+        convert_errors(anchoring_errors, py, relative_path)?,
+    )?;
+    result_dict.set_item(
+        "gitlab-sections",
+        convert_errors(sections_errors, py, relative_path)?,
+    )?;
+    result_dict.set_item(
+        "maintainability",
+        convert_errors(maintainability_errors, py, relative_path)?,
+    )?;
+    result_dict.set_item(
+        "complexity",
+        convert_errors(complexity_errors, py, relative_path)?,
+    )?;
+    result_dict.set_item(
+        "ownership-manifest",
+        convert_errors(manifest_errors, py, relative_path)?,
+    )?;
+    result_dict.set_item(
+        "self-ownership",
+        convert_errors(self_ownership_errors, py, relative_path)?,
+    )?;
+    result_dict.set_item(
+        "owners-lock",
+        convert_errors(owners_lock_errors, py, relative_path)?,
+    )?;
+    result_dict.set_item(
+        "escalation",
+        convert_errors(escalation_errors, py, relative_path)?,
+    )?;
+    result_dict.set_item(
+        "annotations",
+        convert_errors(annotations_errors, py, relative_path)?,
+    )?;
+    for (check_name, errors) in custom_errors {
+        result_dict.set_item(check_name, convert_errors(errors, py, relative_path)?)?;
+    }
+    if let Some(error) = &validation_result.internal_error {
+        result_dict.set_item("internal_error", error)?;
+    }
+
+    Ok(result_dict.into())
 }
 
 /// Generate a random CODEOWNERS file for benchmarking.
@@ -442,6 +990,7 @@ fn _codeowners_validator(m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     m.add_function(wrap_pyfunction!(parse_codeowners, m)?)?;
     m.add_function(wrap_pyfunction!(validate_codeowners, m)?)?;
+    m.add_class::<Validator>()?;
 
     #[cfg(feature = "generate")]
     m.add_function(wrap_pyfunction!(generate_codeowners_fixture, m)?)?;
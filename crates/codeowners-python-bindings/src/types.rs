@@ -13,6 +13,8 @@ pub struct PySpan {
     pub line: usize,
     pub column: usize,
     pub length: usize,
+    pub end_line: usize,
+    pub end_column: usize,
 }
 
 impl From<&Span> for PySpan {
@@ -22,6 +24,8 @@ impl From<&Span> for PySpan {
             line: span.line,
             column: span.column,
             length: span.length,
+            end_line: span.end_line,
+            end_column: span.end_column,
         }
     }
 }
@@ -46,6 +50,12 @@ pub enum PyOwner {
         text: String,
         span: PySpan,
     },
+    Invalid {
+        raw: String,
+        reason: String,
+        text: String,
+        span: PySpan,
+    },
 }
 
 impl From<&Owner> for PyOwner {
@@ -67,6 +77,18 @@ impl From<&Owner> for PyOwner {
                 text: email.clone(),
                 span: PySpan::from(span),
             },
+            Owner::Invalid { raw, reason, span } => PyOwner::Invalid {
+                raw: raw.clone(),
+                reason: reason.clone(),
+                text: raw.clone(),
+                span: PySpan::from(span),
+            },
+            _ => PyOwner::Invalid {
+                raw: owner.to_string(),
+                reason: "unsupported owner kind".to_string(),
+                text: owner.to_string(),
+                span: PySpan::from(owner.span()),
+            },
         }
     }
 }
@@ -99,6 +121,12 @@ pub enum PyLineKind {
         pattern: PyPattern,
         owners: Vec<PyOwner>,
     },
+    Section {
+        name: String,
+        optional: bool,
+        approvals: Option<u32>,
+        owners: Vec<PyOwner>,
+    },
     Invalid {
         raw: String,
         error: String,
@@ -116,10 +144,26 @@ impl From<&LineKind> for PyLineKind {
                 pattern: PyPattern::from(pattern),
                 owners: owners.iter().map(PyOwner::from).collect(),
             },
+            LineKind::Section {
+                name,
+                optional,
+                approvals,
+                owners,
+                ..
+            } => PyLineKind::Section {
+                name: name.clone(),
+                optional: *optional,
+                approvals: approvals.as_ref().and_then(|approvals| approvals.count()),
+                owners: owners.iter().map(PyOwner::from).collect(),
+            },
             LineKind::Invalid { raw, error } => PyLineKind::Invalid {
                 raw: raw.clone(),
                 error: error.clone(),
             },
+            _ => PyLineKind::Invalid {
+                raw: String::new(),
+                error: "unsupported line kind".to_string(),
+            },
         }
     }
 }
@@ -129,6 +173,9 @@ impl From<&LineKind> for PyLineKind {
 pub struct PyLine {
     pub kind: PyLineKind,
     pub span: PySpan,
+    /// Owners of a rule not to request reviews from automatically, empty for
+    /// all of them; `None` without a `no-auto-request` annotation.
+    pub no_auto_request: Option<Vec<String>>,
 }
 
 impl From<&Line> for PyLine {
@@ -136,6 +183,10 @@ impl From<&Line> for PyLine {
         Self {
             kind: PyLineKind::from(&line.kind),
             span: PySpan::from(&line.span),
+            no_auto_request: line
+                .no_auto_request
+                .as_ref()
+                .map(|annotation| annotation.owners.clone()),
         }
     }
 }
@@ -170,19 +221,22 @@ impl From<Severity> for PySeverity {
 #[derive(Debug, Clone, Serialize)]
 pub struct PyIssue {
     pub path: String,
+    pub code: &'static str,
     pub span: PySpan,
     pub message: String,
     pub severity: PySeverity,
 }
 
 impl PyIssue {
-    /// Creates a new PyIssue from a ValidationError and a path.
-    pub fn new(error: &ValidationError, path: String) -> Self {
+    /// Creates a new PyIssue from a ValidationError, a path and the severity
+    /// the error is reported with.
+    pub fn new(error: &ValidationError, path: String, severity: Severity) -> Self {
         Self {
             path,
+            code: error.code(),
             span: PySpan::from(error.span()),
             message: error.to_string(),
-            severity: PySeverity::from(error.severity()),
+            severity: PySeverity::from(severity),
         }
     }
 }
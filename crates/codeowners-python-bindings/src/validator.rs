@@ -0,0 +1,348 @@
+//! Long-lived validator that reuses expensive state across validations.
+//!
+//! Bots validating many pull requests against the same repository checkout
+//! can keep one [`Validator`] instead of calling `validate_codeowners` for
+//! each revision. The validator wraps a core [`ValidationEngine`], which keeps
+//! the check configuration, the repository file listings, parsed rules and
+//! the GitHub owner lookups between calls. `reload()` swaps in a new engine
+//! without disturbing validations already running, and `watch()` does the
+//! same whenever the CODEOWNERS file or the project configuration changes.
+
+use crate::github_client::PyGithubClient;
+use crate::{
+    build_check_config, build_runner, config_map, default_checks, find_codeowners, read_codeowners,
+    relative_codeowners_path, results_to_dict, verify_git_rev,
+};
+use codeowners_validator_core::config::{
+    CONFIG_FILE_NAME, Config as ProjectConfig, current_branch,
+};
+use codeowners_validator_core::validate::checks::CheckConfig;
+use codeowners_validator_core::validate::watch::EngineWatcher;
+use codeowners_validator_core::validate::{SharedEngine, ValidationEngine};
+use log::{debug, info};
+use pyo3::exceptions::{PyOSError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Path reported for issues when the repository has no CODEOWNERS file.
+const DEFAULT_CODEOWNERS_PATH: &str = "CODEOWNERS";
+
+/// Everything a validator needs to rebuild its engine.
+///
+/// Shared with the watch thread, which rebuilds the engine from whatever
+/// options were last given to `reload()`.
+struct EngineOptions {
+    repo_path: PathBuf,
+    config: Option<HashMap<String, Py<PyAny>>>,
+    checks: Option<Vec<String>>,
+    github_client: Option<Py<PyAny>>,
+    /// Project configuration file applied beneath `config`, set by `watch()`.
+    config_file: Option<PathBuf>,
+}
+
+impl EngineOptions {
+    /// Builds an engine from the Python-facing options.
+    fn build(&self, py: Python<'_>) -> Result<ValidationEngine, String> {
+        let base = match self.project_config()? {
+            Some(project) => project.apply(CheckConfig::new()),
+            None => CheckConfig::new(),
+        };
+        let config = build_check_config(py, self.config.as_ref(), base);
+        verify_git_rev(&self.repo_path, &config)?;
+        let has_client = self.github_client.is_some();
+        let checks = self
+            .checks
+            .clone()
+            .unwrap_or_else(|| default_checks(has_client));
+        let (runner, run_owners) = build_runner(&checks, has_client);
+        let mut engine = ValidationEngine::new(&self.repo_path, runner).with_config(config);
+
+        // The client is only needed when the owners check runs
+        if let Some(client) = self.github_client.as_ref().filter(|_| run_owners) {
+            engine = engine.with_github_client(PyGithubClient::new(client.clone_ref(py)));
+        }
+
+        debug!(
+            "Validator checks: {:?} (owners check: {})",
+            checks, run_owners
+        );
+        Ok(engine)
+    }
+
+    /// Reads the project configuration file, if one is set and exists,
+    /// narrowed to the checked-out branch.
+    fn project_config(&self) -> Result<Option<ProjectConfig>, String> {
+        let Some(path) = self.config_file.as_ref().filter(|path| path.exists()) else {
+            return Ok(None);
+        };
+        let project =
+            ProjectConfig::from_path(path).map_err(|e| format!("{} ('{}')", e, path.display()))?;
+        Ok(Some(match current_branch(&self.repo_path) {
+            Some(branch) => project.for_branch(&branch),
+            None => project,
+        }))
+    }
+
+    /// Files whose changes trigger a rebuild while watching.
+    fn watched_files(&self) -> Vec<PathBuf> {
+        let mut files = match codeowners_validator_core::find_codeowners_file(&self.repo_path) {
+            Some(path) => vec![path],
+            // Pick up a CODEOWNERS file created in any supported location
+            None => ["CODEOWNERS", ".github/CODEOWNERS", "docs/CODEOWNERS"]
+                .iter()
+                .map(|name| self.repo_path.join(name))
+                .filter(|path| path.parent().is_some_and(Path::is_dir))
+                .collect(),
+        };
+        files.extend(self.config_file.clone());
+        files
+    }
+}
+
+/// Validates content, or the repository's CODEOWNERS file when none is given.
+async fn validate_with(
+    engine: Arc<ValidationEngine>,
+    content: Option<String>,
+) -> PyResult<Py<PyDict>> {
+    let repo_path = engine.repo_path();
+    let codeowners_path = codeowners_validator_core::find_codeowners_file(repo_path);
+    let content = match content {
+        Some(content) => content,
+        None => read_codeowners(&find_codeowners(repo_path)?)?,
+    };
+    let relative_path = codeowners_path.as_ref().map_or_else(
+        || DEFAULT_CODEOWNERS_PATH.to_string(),
+        |path| relative_codeowners_path(repo_path, path),
+    );
+
+    let validation_result = engine.validate(&content).await;
+
+    info!(
+        "Validation complete: {} total issues found",
+        validation_result.errors.len()
+    );
+    Python::attach(|py| results_to_dict(py, &validation_result, &relative_path))
+}
+
+/// A reusable CODEOWNERS validator bound to one repository.
+///
+/// Args:
+///     repo_path: Path to the repository root directory.
+///     config: Optional configuration dictionary (see `validate_codeowners`).
+///     checks: Optional list of checks to run (see `validate_codeowners`).
+///     github_client: Optional GitHub client used by the "owners" check.
+///
+/// Raises:
+///     ValueError: If `git_rev` does not name a commit.
+///
+/// Repository file listings, parsed rules and owner lookups are cached
+/// between calls to `validate()`. Call `clear_cache()` after the checkout
+/// changes, or `reload()` to apply a new configuration. `watch()` reloads
+/// automatically when the CODEOWNERS file or the project configuration
+/// changes. Used as a context manager, the caches are dropped and watching
+/// stops on exit.
+///
+/// Example:
+///     >>> with Validator("/path/to/repo") as validator:
+///     ...     result = await validator.validate("*.rs @rustacean\\n")
+#[pyclass(module = "codeowners_validator._codeowners_validator")]
+pub struct Validator {
+    repo_path: PathBuf,
+    options: Arc<Mutex<EngineOptions>>,
+    engine: Arc<SharedEngine>,
+    watcher: Mutex<Option<EngineWatcher>>,
+}
+
+impl Validator {
+    fn options(&self) -> MutexGuard<'_, EngineOptions> {
+        self.options
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[pymethods]
+impl Validator {
+    #[new]
+    #[pyo3(signature = (repo_path, config=None, checks=None, github_client=None))]
+    fn new(
+        py: Python<'_>,
+        repo_path: &str,
+        config: Option<&Bound<'_, PyDict>>,
+        checks: Option<Vec<String>>,
+        github_client: Option<Bound<'_, PyAny>>,
+    ) -> PyResult<Self> {
+        info!("Creating Validator for repo: {}", repo_path);
+
+        let options = EngineOptions {
+            repo_path: PathBuf::from(repo_path),
+            config: config_map(config),
+            checks,
+            github_client: github_client.map(Bound::unbind),
+            config_file: None,
+        };
+        let engine = options.build(py).map_err(PyValueError::new_err)?;
+
+        Ok(Self {
+            repo_path: options.repo_path.clone(),
+            options: Arc::new(Mutex::new(options)),
+            engine: Arc::new(SharedEngine::new(engine)),
+            watcher: Mutex::new(None),
+        })
+    }
+
+    /// Validate CODEOWNERS content against the repository.
+    ///
+    /// Args:
+    ///     content: CODEOWNERS content to validate. Defaults to the
+    ///         repository's own CODEOWNERS file.
+    ///
+    /// Returns:
+    ///     An awaitable resolving to the same dictionary as `validate_codeowners`.
+    #[pyo3(signature = (content=None))]
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        content: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let engine = self.engine.current();
+        pyo3_async_runtimes::tokio::future_into_py(py, validate_with(engine, content))
+    }
+
+    /// Replace the configuration and checks, keeping the GitHub client.
+    ///
+    /// Validations already running finish with the previous configuration.
+    /// Caches start empty after a reload. While watching, later automatic
+    /// reloads keep the new configuration.
+    ///
+    /// Args:
+    ///     config: New configuration dictionary (see `validate_codeowners`).
+    ///     checks: New list of checks to run (see `validate_codeowners`).
+    ///
+    /// Returns:
+    ///     The reload generation, starting at 1 for the first reload.
+    ///
+    /// Raises:
+    ///     ValueError: If `git_rev` does not name a commit, or the project
+    ///         configuration file given to `watch()` is invalid.
+    #[pyo3(signature = (config=None, checks=None))]
+    fn reload(
+        &self,
+        py: Python<'_>,
+        config: Option<&Bound<'_, PyDict>>,
+        checks: Option<Vec<String>>,
+    ) -> PyResult<u64> {
+        let mut options = self.options();
+        options.config = config_map(config);
+        options.checks = checks;
+        let engine = options.build(py).map_err(PyValueError::new_err)?;
+        Ok(self.engine.replace(engine))
+    }
+
+    /// Reload automatically when the CODEOWNERS file or the project
+    /// configuration changes on disk.
+    ///
+    /// The project configuration file is applied beneath the `config`
+    /// dictionary from then on, and the validator reloads once right away so
+    /// it takes effect. An invalid file is logged on later changes and the
+    /// previous configuration stays in use. Calling `watch()` again replaces
+    /// the previous watch.
+    ///
+    /// Args:
+    ///     config_file: Project configuration file. Defaults to
+    ///         `.codeowners-validator.toml` in the repository root.
+    ///
+    /// Returns:
+    ///     The reload generation after the initial reload.
+    ///
+    /// Raises:
+    ///     ValueError: If the project configuration file is invalid.
+    ///     OSError: If the files cannot be watched.
+    #[pyo3(signature = (config_file=None))]
+    fn watch(&self, py: Python<'_>, config_file: Option<PathBuf>) -> PyResult<u64> {
+        let (engine, files) = {
+            let mut options = self.options();
+            options.config_file =
+                Some(config_file.unwrap_or_else(|| self.repo_path.join(CONFIG_FILE_NAME)));
+            let engine = options.build(py).map_err(PyValueError::new_err)?;
+            (engine, options.watched_files())
+        };
+        let generation = self.engine.replace(engine);
+
+        let options = Arc::clone(&self.options);
+        let watcher = EngineWatcher::spawn(Arc::clone(&self.engine), files, move || {
+            Python::attach(|py| {
+                options
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner())
+                    .build(py)
+            })
+        })
+        .map_err(|e| PyOSError::new_err(e.to_string()))?;
+
+        info!("Watching {:?} for changes", watcher.files());
+        *self
+            .watcher
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(watcher);
+        Ok(generation)
+    }
+
+    /// Stop reloading on file changes. The current configuration is kept.
+    fn unwatch(&self) {
+        let watcher = self
+            .watcher
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take();
+        if watcher.is_some() {
+            debug!("Stopped watching for changes");
+        }
+    }
+
+    /// Whether the validator reloads on file changes.
+    #[getter]
+    fn watching(&self) -> bool {
+        self.watcher
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .is_some()
+    }
+
+    /// Drop cached file listings, parsed rules and owner lookups.
+    fn clear_cache(&self) {
+        debug!("Clearing Validator caches");
+        self.engine.current().clear_cache();
+    }
+
+    /// The repository root this validator is bound to.
+    #[getter]
+    fn repo_path(&self) -> String {
+        self.repo_path.to_string_lossy().into_owned()
+    }
+
+    /// How many times the validator has been reloaded.
+    #[getter]
+    fn generation(&self) -> u64 {
+        self.engine.generation()
+    }
+
+    fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    #[pyo3(signature = (_exc_type=None, _exc_value=None, _traceback=None))]
+    fn __exit__(
+        &self,
+        _exc_type: Option<Bound<'_, PyAny>>,
+        _exc_value: Option<Bound<'_, PyAny>>,
+        _traceback: Option<Bound<'_, PyAny>>,
+    ) -> bool {
+        self.unwatch();
+        self.clear_cache();
+        false
+    }
+}
@@ -16,7 +16,7 @@ from codeowners_validator import parse_codeowners, validate_codeowners
 
 # Check profiles
 STANDARD_CHECKS = ["syntax", "duppatterns", "files"]
-EXPERIMENTAL_CHECKS = ["notowned", "avoid-shadowing"]
+EXPERIMENTAL_CHECKS = ["notowned", "avoid-shadowing", "case-collision", "generated-regions"]
 ALL_CHECKS = STANDARD_CHECKS + EXPERIMENTAL_CHECKS
 
 
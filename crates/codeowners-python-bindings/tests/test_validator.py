@@ -1,6 +1,8 @@
 """Tests for the codeowners_validator package."""
 
+import asyncio
 import tempfile
+import time
 from collections.abc import Generator
 from pathlib import Path
 from typing import TYPE_CHECKING, Literal
@@ -261,6 +263,17 @@ class TestValidateCodeowners:
             # Don't create a CODEOWNERS file
             await validate_codeowners(tmpdir)
 
+    @pytest.mark.asyncio
+    async def test_validate_unknown_git_rev(self, temp_repo: str) -> None:
+        """Test that a git_rev naming no commit is rejected."""
+        from codeowners_validator import Validator, validate_codeowners
+
+        write_codeowners(temp_repo, "* @owner\n")
+        with pytest.raises(ValueError, match="unknown revision 'no-such-rev'"):
+            await validate_codeowners(temp_repo, config={"git_rev": "no-such-rev"})
+        with pytest.raises(ValueError, match="unknown revision 'no-such-rev'"):
+            Validator(temp_repo, config={"git_rev": "no-such-rev"})
+
 
 class TestValidateWithGithub:
     """Tests for validate_codeowners with github_client."""
@@ -293,6 +306,37 @@ class TestValidateWithGithub:
         assert len(result["owners"]) > 0
         assert any("ghostuser" in issue["message"] for issue in result["owners"])
 
+    @pytest.mark.asyncio
+    async def test_validate_client_raises(self, temp_repo: str) -> None:
+        """Test that an exception raised by the client fails the owners check."""
+        from codeowners_validator import validate_codeowners
+
+        class RaisingGithubClient(MockGithubClient):
+            def user_exists(self, username: str) -> bool:
+                raise TimeoutError("GitHub is down")
+
+        write_codeowners(temp_repo, "*.rs @validuser\n")
+
+        result = await validate_codeowners(temp_repo, github_client=RaisingGithubClient())
+
+        assert result["owners"] == []
+        assert result["internal_error"] == (
+            "1 owner(s) could not be checked: GitHub client error: "
+            "user_exists() raised TimeoutError: GitHub is down"
+        )
+
+    @pytest.mark.asyncio
+    async def test_validate_user_exists_no_internal_error(self, temp_repo: str) -> None:
+        """Test that a run that finishes has no internal error."""
+        from codeowners_validator import validate_codeowners
+
+        write_codeowners(temp_repo, "*.rs @validuser\n")
+        client = MockGithubClient(existing_users={"validuser"})
+
+        result = await validate_codeowners(temp_repo, github_client=client)
+
+        assert "internal_error" not in result
+
     @pytest.mark.asyncio
     async def test_validate_team_exists(self, temp_repo: str) -> None:
         """Test validation with a team that exists."""
@@ -366,6 +410,99 @@ class TestValidateWithGithub:
         assert len(result["owners"]) == 0  # Should be ignored
 
 
+class TestValidatorClass:
+    """Tests for the long-lived Validator class."""
+
+    @pytest.mark.asyncio
+    async def test_validate_content(self, temp_repo: str) -> None:
+        """Test validating content that is not on disk."""
+        from codeowners_validator import Validator
+
+        with Validator(temp_repo) as validator:
+            result = await validator.validate("*.rs @rustacean\n/missing/ @docs\n")
+
+        assert len(result["syntax"]) == 0
+        assert len(result["files"]) == 1
+
+    @pytest.mark.asyncio
+    async def test_validate_defaults_to_repo_file(self, temp_repo: str) -> None:
+        """Test that validate() reads the repository CODEOWNERS file by default."""
+        from codeowners_validator import Validator
+
+        write_codeowners(temp_repo, "*.rs @rustacean\n*.rs @other\n")
+        validator = Validator(temp_repo)
+        result = await validator.validate()
+
+        assert len(result["duppatterns"]) > 0
+        assert result["duppatterns"][0]["path"] == ".github/CODEOWNERS"
+
+    @pytest.mark.asyncio
+    async def test_owner_lookups_cached(self, temp_repo: str) -> None:
+        """Test that owners are looked up once across validations."""
+        from codeowners_validator import Validator
+
+        client = MockGithubClient(existing_users={"validuser"})
+        with Validator(temp_repo, github_client=client) as validator:
+            await validator.validate("*.rs @validuser\n")
+            await validator.validate("*.md @validuser\n")
+            assert client.user_calls == ["validuser"]
+
+            validator.clear_cache()
+            await validator.validate("*.rs @validuser\n")
+            assert client.user_calls == ["validuser", "validuser"]
+
+    @pytest.mark.asyncio
+    async def test_file_index_cleared(self, temp_repo: str) -> None:
+        """Test that clear_cache() picks up new repository files."""
+        from codeowners_validator import Validator
+
+        validator = Validator(temp_repo, checks=["files"])
+        result = await validator.validate("/new/ @team\n")
+        assert len(result["files"]) == 1
+
+        Path(temp_repo, "new").mkdir()
+        Path(temp_repo, "new/file.txt").touch()
+        validator.clear_cache()
+        result = await validator.validate("/new/ @team\n")
+        assert len(result["files"]) == 0
+
+    @pytest.mark.asyncio
+    async def test_reload_applies_new_checks(self, temp_repo: str) -> None:
+        """Test that reload() swaps the checks used by later validations."""
+        from codeowners_validator import Validator
+
+        validator = Validator(temp_repo, checks=["files"])
+        result = await validator.validate("/missing/ @team\n")
+        assert len(result["files"]) == 1
+
+        assert validator.reload(checks=["syntax"]) == 1
+        assert validator.generation == 1
+        result = await validator.validate("/missing/ @team\n")
+        assert len(result["files"]) == 0
+
+    @pytest.mark.asyncio
+    async def test_watch_reloads_on_config_change(self, temp_repo: str) -> None:
+        """Test that watch() reloads when the project configuration changes."""
+        from codeowners_validator import Validator
+
+        config_file = Path(temp_repo, ".codeowners-validator.toml")
+        with Validator(temp_repo, checks=["files"]) as validator:
+            assert validator.watch() == 1
+            assert validator.watching
+            result = await validator.validate("/missing/ @team\n")
+            assert result["files"][0]["severity"] == "warning"
+
+            config_file.write_text('[severity]\nCO007 = "error"\n')
+            deadline = time.monotonic() + 10
+            while validator.generation < 2 and time.monotonic() < deadline:
+                await asyncio.sleep(0.05)
+            assert validator.generation == 2
+            result = await validator.validate("/missing/ @team\n")
+            assert result["files"][0]["severity"] == "error"
+
+        assert not validator.watching
+
+
 class TestIssueFormat:
     """Tests for the format of validation issues."""
 
@@ -390,7 +527,14 @@ class TestIssueFormat:
             assert "span" in issue
             span = issue["span"]
             assert isinstance(span, dict)
-            assert span.keys() == {"offset", "line", "column", "length"}
+            assert span.keys() == {
+                "offset",
+                "line",
+                "column",
+                "length",
+                "end_line",
+                "end_column",
+            }
 
     @pytest.mark.asyncio
     async def test_issue_has_path_field(self, temp_repo: str) -> None:
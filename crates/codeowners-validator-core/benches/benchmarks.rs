@@ -6,7 +6,9 @@
 //!   cargo bench -- "parsing"
 //!   cargo bench -- "checks/standard"
 //!   cargo bench -- "checks/experimental"
+//!   cargo bench -- "resolution"
 
+use codeowners_validator_core::matching::OwnersResolver;
 use codeowners_validator_core::parse::parse_codeowners;
 use codeowners_validator_core::validate::checks::{
     AvoidShadowingCheck, Check, CheckConfig, CheckContext, DupPatternsCheck, FilesCheck,
@@ -97,11 +99,37 @@ fn bench_experimental_checks(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark resolving owners of many paths with one compiled resolver
+fn bench_resolution(c: &mut Criterion) {
+    let mut group = c.benchmark_group("resolution");
+    let paths: Vec<String> = (0..50_000)
+        .map(|i| format!("src/module_{}/sub_{}/file_{}.rs", i % 100, i % 7, i))
+        .collect();
+    let paths: Vec<&str> = paths.iter().map(String::as_str).collect();
+    group.throughput(Throughput::Elements(paths.len() as u64));
+
+    for (name, content) in fixtures() {
+        let parsed = parse_codeowners(content);
+        let resolver = OwnersResolver::from_file(&parsed.ast);
+
+        group.bench_with_input(BenchmarkId::new("sequential", name), &paths, |b, paths| {
+            b.iter(|| resolver.owners_for_many(paths.iter().copied()))
+        });
+
+        #[cfg(feature = "parallel")]
+        group.bench_with_input(BenchmarkId::new("parallel", name), &paths, |b, paths| {
+            b.iter(|| resolver.par_owners_for_many(paths))
+        });
+    }
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_parsing,
     bench_parsing_extended,
     bench_standard_checks,
-    bench_experimental_checks
+    bench_experimental_checks,
+    bench_resolution
 );
 criterion_main!(benches);
@@ -0,0 +1,435 @@
+//! Project configuration files.
+//!
+//! Settings shared by every run in a repository can be kept in a
+//! `.codeowners-validator.toml` file at its root, instead of repeating them
+//! as flags or environment variables in each CI pipeline:
+//!
+//! ```toml
+//! checks = ["files", "duppatterns", "syntax"]
+//! experimental_checks = ["notowned"]
+//! ignored_owners = ["@ghost"]
+//! skip_patterns = ["vendor/*"]
+//! failure_level = "error"
+//!
+//! # Severities replacing the defaults, by error code
+//! [severity]
+//! CO013 = "error"
+//! CO007 = "warning"
+//!
+//! # Settings of a custom check, by check name
+//! [settings.no-bots]
+//! suffix = "[bot]"
+//!
+//! # Stricter settings for release branches
+//! [[branches]]
+//! pattern = "release/*"
+//! checks = ["files", "duppatterns", "syntax", "owners"]
+//! failure_level = "warning"
+//! ```
+//!
+//! Every key is optional. Check names are not validated here, since the
+//! checks available depend on the caller.
+//!
+//! Each `[[branches]]` section applies to branches whose name matches its
+//! glob `pattern`, where `*` stays within one `/`-separated segment and `**`
+//! spans several. [`Config::for_branch`] resolves them: the keys a matching
+//! section sets replace those of the file, in file order, so a later section
+//! wins over an earlier one. [`current_branch`] finds the branch checked out
+//! in a repository.
+
+use crate::validate::checks::CheckConfig;
+use crate::validate::{ERROR_CODES, Severity};
+use globset::GlobBuilder;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// The name of the project configuration file, at the repository root.
+pub const CONFIG_FILE_NAME: &str = ".codeowners-validator.toml";
+
+/// Errors that can occur while reading a project configuration file.
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    /// The file could not be read.
+    #[error("failed to read '{}': {source}", path.display())]
+    Io {
+        /// The path of the file.
+        path: PathBuf,
+        /// The underlying error.
+        source: std::io::Error,
+    },
+
+    /// The file is not valid TOML, or has unknown keys or invalid values.
+    #[error("invalid TOML: {0}")]
+    Toml(#[from] toml::de::Error),
+
+    /// A severity override names an unknown error code.
+    #[error("unknown error code '{0}' in [severity], expected e.g. 'CO004'")]
+    UnknownCode(String),
+
+    /// A branch section has an invalid pattern.
+    #[error("invalid branch pattern '{pattern}': {source}")]
+    BranchPattern {
+        /// The pattern.
+        pattern: String,
+        /// The underlying error.
+        source: globset::Error,
+    },
+}
+
+/// Settings read from a project configuration file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Names of the standard checks to run.
+    pub checks: Option<Vec<String>>,
+    /// Names of the experimental checks to run.
+    pub experimental_checks: Option<Vec<String>>,
+    /// Owners to ignore during validation.
+    pub ignored_owners: Option<Vec<String>>,
+    /// Patterns of files the not-owned check skips.
+    pub skip_patterns: Option<Vec<String>>,
+    /// Severities replacing the default severity of findings, by error code.
+    pub severity: BTreeMap<String, Severity>,
+    /// The lowest severity that fails validation.
+    pub failure_level: Option<Severity>,
+    /// Settings of individual checks, by check name.
+    pub settings: BTreeMap<String, serde_json::Value>,
+    /// Settings for branches whose names match a pattern, in file order.
+    pub branches: Vec<BranchConfig>,
+}
+
+/// Settings that apply only to branches whose names match a pattern.
+///
+/// Keys left unset keep the value of the file, or of an earlier matching
+/// section.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BranchConfig {
+    /// The glob the branch name must match, e.g. `release/*`.
+    pub pattern: String,
+    /// Names of the standard checks to run.
+    pub checks: Option<Vec<String>>,
+    /// Names of the experimental checks to run.
+    pub experimental_checks: Option<Vec<String>>,
+    /// Owners to ignore during validation.
+    pub ignored_owners: Option<Vec<String>>,
+    /// Patterns of files the not-owned check skips.
+    pub skip_patterns: Option<Vec<String>>,
+    /// Severities replacing the default severity of findings, by error
+    /// code, in addition to those of the file.
+    pub severity: BTreeMap<String, Severity>,
+    /// The lowest severity that fails validation.
+    pub failure_level: Option<Severity>,
+}
+
+impl BranchConfig {
+    /// Returns true if `branch` matches the pattern.
+    ///
+    /// Patterns are validated when the file is parsed, so an invalid one
+    /// matches nothing.
+    pub fn matches(&self, branch: &str) -> bool {
+        compile_branch_pattern(&self.pattern).is_ok_and(|glob| glob.is_match(branch))
+    }
+}
+
+/// Compiles a branch pattern, where `*` does not match `/`.
+fn compile_branch_pattern(pattern: &str) -> Result<globset::GlobMatcher, ConfigError> {
+    GlobBuilder::new(pattern)
+        .literal_separator(true)
+        .build()
+        .map(|glob| glob.compile_matcher())
+        .map_err(|source| ConfigError::BranchPattern {
+            pattern: pattern.to_string(),
+            source,
+        })
+}
+
+/// Uppercases the error codes of severity overrides, and rejects unknown
+/// ones.
+fn normalize_severity(
+    severity: BTreeMap<String, Severity>,
+) -> Result<BTreeMap<String, Severity>, ConfigError> {
+    severity
+        .into_iter()
+        .map(|(code, severity)| {
+            let code = code.to_uppercase();
+            if ERROR_CODES.contains(&code.as_str()) {
+                Ok((code, severity))
+            } else {
+                Err(ConfigError::UnknownCode(code))
+            }
+        })
+        .collect()
+}
+
+impl Config {
+    /// Parses a TOML configuration.
+    pub fn from_toml(content: &str) -> Result<Self, ConfigError> {
+        let mut config: Self = toml::from_str(content)?;
+        config.severity = normalize_severity(std::mem::take(&mut config.severity))?;
+        for branch in &mut config.branches {
+            compile_branch_pattern(&branch.pattern)?;
+            branch.severity = normalize_severity(std::mem::take(&mut branch.severity))?;
+        }
+        Ok(config)
+    }
+
+    /// Reads a TOML configuration file.
+    pub fn from_path(path: &Path) -> Result<Self, ConfigError> {
+        let content = std::fs::read_to_string(path).map_err(|source| ConfigError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::from_toml(&content)
+    }
+
+    /// Reads the [`CONFIG_FILE_NAME`] file of a repository, or returns
+    /// `None` if it has none.
+    pub fn discover(repo_path: &Path) -> Result<Option<Self>, ConfigError> {
+        let path = repo_path.join(CONFIG_FILE_NAME);
+        if path.is_file() {
+            Self::from_path(&path).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns the settings for `branch`, with the sections whose pattern
+    /// matches it applied in file order.
+    pub fn for_branch(&self, branch: &str) -> Self {
+        let mut config = Self {
+            branches: Vec::new(),
+            ..self.clone()
+        };
+        for section in self
+            .branches
+            .iter()
+            .filter(|section| section.matches(branch))
+        {
+            if let Some(checks) = &section.checks {
+                config.checks = Some(checks.clone());
+            }
+            if let Some(checks) = &section.experimental_checks {
+                config.experimental_checks = Some(checks.clone());
+            }
+            if let Some(owners) = &section.ignored_owners {
+                config.ignored_owners = Some(owners.clone());
+            }
+            if let Some(patterns) = &section.skip_patterns {
+                config.skip_patterns = Some(patterns.clone());
+            }
+            config.severity.extend(section.severity.clone());
+            config.failure_level = section.failure_level.or(config.failure_level);
+        }
+        config
+    }
+
+    /// Applies the settings that belong to the checks to `config`.
+    ///
+    /// Settings absent from the file leave `config` unchanged, so apply the
+    /// file first and explicit options after it to let them take precedence.
+    pub fn apply(&self, mut config: CheckConfig) -> CheckConfig {
+        if let Some(owners) = &self.ignored_owners {
+            config = config.with_ignored_owners(owners.iter().cloned().collect());
+        }
+        if let Some(patterns) = &self.skip_patterns {
+            config = config.with_skip_patterns(patterns.clone());
+        }
+        if !self.severity.is_empty() {
+            let overrides: HashMap<_, _> = self
+                .severity
+                .iter()
+                .map(|(code, severity)| (code.clone(), *severity))
+                .collect();
+            config = config.with_severity_overrides(overrides);
+        }
+        for (check, settings) in &self.settings {
+            config = config.with_check_settings(check.clone(), settings.clone());
+        }
+        config
+    }
+}
+
+/// Returns the branch checked out in the repository containing `repo_path`,
+/// read from git's `HEAD`, or `None` if `HEAD` is detached or there is no
+/// repository.
+///
+/// Linked worktrees, whose `.git` is a file pointing to the git directory,
+/// are supported.
+pub fn current_branch(repo_path: &Path) -> Option<String> {
+    let dot_git = repo_path
+        .ancestors()
+        .map(|dir| dir.join(".git"))
+        .find(|path| path.exists())?;
+    let git_dir = if dot_git.is_file() {
+        let content = std::fs::read_to_string(&dot_git).ok()?;
+        let target = content.strip_prefix("gitdir:")?.trim();
+        dot_git.parent()?.join(target)
+    } else {
+        dot_git
+    };
+    let head = std::fs::read_to_string(git_dir.join("HEAD")).ok()?;
+    head.trim()
+        .strip_prefix("ref: refs/heads/")
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn parses_all_keys() {
+        let config = Config::from_toml(
+            r#"
+checks = ["files", "syntax"]
+experimental_checks = ["notowned"]
+ignored_owners = ["@ghost"]
+skip_patterns = ["vendor/*"]
+failure_level = "error"
+
+[severity]
+co013 = "error"
+
+[settings.no-bots]
+suffix = "[bot]"
+allowed = ["@release[bot]"]
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.checks,
+            Some(vec!["files".to_string(), "syntax".to_string()])
+        );
+        assert_eq!(config.failure_level, Some(Severity::Error));
+        assert_eq!(config.severity.get("CO013"), Some(&Severity::Error));
+
+        let check_config = config.apply(CheckConfig::new());
+        assert!(check_config.ignored_owners.contains("@ghost"));
+        assert_eq!(check_config.skip_patterns, vec!["vendor/*".to_string()]);
+        assert_eq!(
+            check_config.severity_overrides.get("CO013"),
+            Some(&Severity::Error)
+        );
+        assert_eq!(
+            check_config.settings_for("no-bots"),
+            Some(&serde_json::json!({ "suffix": "[bot]", "allowed": ["@release[bot]"] }))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_files() {
+        assert!(matches!(
+            Config::from_toml("unknown = 1\n"),
+            Err(ConfigError::Toml(_))
+        ));
+        assert!(matches!(
+            Config::from_toml("failure_level = \"fatal\"\n"),
+            Err(ConfigError::Toml(_))
+        ));
+        let error = Config::from_toml("[severity]\nCO999 = \"error\"\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unknown error code 'CO999' in [severity], expected e.g. 'CO004'"
+        );
+    }
+
+    #[test]
+    fn resolves_branch_sections() {
+        let config = Config::from_toml(
+            r#"
+checks = ["syntax"]
+failure_level = "error"
+
+[[branches]]
+pattern = "release/*"
+checks = ["syntax", "owners"]
+failure_level = "warning"
+
+[branches.severity]
+CO013 = "error"
+
+[[branches]]
+pattern = "release/legacy-*"
+checks = ["syntax"]
+"#,
+        )
+        .unwrap();
+
+        let release = config.for_branch("release/2.0");
+        assert_eq!(
+            release.checks,
+            Some(vec!["syntax".to_string(), "owners".to_string()])
+        );
+        assert_eq!(release.failure_level, Some(Severity::Warning));
+        assert_eq!(release.severity.get("CO013"), Some(&Severity::Error));
+        assert!(release.branches.is_empty());
+
+        // Later sections win, and `*` does not cross a '/'
+        let legacy = config.for_branch("release/legacy-1");
+        assert_eq!(legacy.checks, Some(vec!["syntax".to_string()]));
+        assert_eq!(legacy.failure_level, Some(Severity::Warning));
+        assert_eq!(
+            config.for_branch("release/2.0/hotfix").checks,
+            config.checks
+        );
+        assert_eq!(
+            config.for_branch("main").failure_level,
+            Some(Severity::Error)
+        );
+
+        let error = Config::from_toml("[[branches]]\npattern = \"release/[\"\n").unwrap_err();
+        assert!(matches!(error, ConfigError::BranchPattern { .. }));
+        let error =
+            Config::from_toml("[[branches]]\npattern = \"*\"\nseverity = { co999 = \"error\" }\n")
+                .unwrap_err();
+        assert!(matches!(error, ConfigError::UnknownCode(code) if code == "CO999"));
+    }
+
+    #[test]
+    fn reads_current_branch() {
+        let dir = TempDir::new().unwrap();
+        let repo = dir.path().join("repo");
+        fs::create_dir_all(repo.join(".git")).unwrap();
+        fs::create_dir_all(repo.join("src")).unwrap();
+        fs::write(repo.join(".git/HEAD"), "ref: refs/heads/release/1.0\n").unwrap();
+        assert_eq!(
+            current_branch(&repo.join("src")).as_deref(),
+            Some("release/1.0")
+        );
+
+        fs::write(
+            repo.join(".git/HEAD"),
+            "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b\n",
+        )
+        .unwrap();
+        assert_eq!(current_branch(&repo), None);
+
+        // A linked worktree
+        let worktree = dir.path().join("worktree");
+        let git_dir = repo.join(".git/worktrees/feature");
+        fs::create_dir_all(&worktree).unwrap();
+        fs::create_dir_all(&git_dir).unwrap();
+        fs::write(git_dir.join("HEAD"), "ref: refs/heads/feature/x\n").unwrap();
+        fs::write(
+            worktree.join(".git"),
+            format!("gitdir: {}\n", git_dir.display()),
+        )
+        .unwrap();
+        assert_eq!(current_branch(&worktree).as_deref(), Some("feature/x"));
+    }
+
+    #[test]
+    fn discovers_repository_file() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(Config::discover(dir.path()).unwrap(), None);
+
+        fs::write(dir.path().join(CONFIG_FILE_NAME), "checks = []\n").unwrap();
+        let config = Config::discover(dir.path()).unwrap().unwrap();
+        assert_eq!(config.checks, Some(Vec::new()));
+    }
+}
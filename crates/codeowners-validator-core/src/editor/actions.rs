@@ -0,0 +1,165 @@
+//! Code actions built from validation fixes.
+
+use crate::validate::{Fix, ValidationError, applicable_fixes};
+
+/// An editor action that applies one or more fixes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeAction {
+    /// Short title shown to the user.
+    pub title: String,
+    /// The fixes to apply, as non-overlapping text replacements.
+    pub fixes: Vec<Fix>,
+    /// The finding this action resolves, if it targets a single one.
+    pub diagnostic: Option<ValidationError>,
+}
+
+/// Computes code actions for findings on the given line (1-based).
+///
+/// Each fixable finding on the line gets its own action, and each candidate
+/// fix of an ambiguous finding one action per candidate. When the file has
+/// more than one fixable finding, a final "Fix all" action applies them all,
+/// the same set the CLI `--fix` applies; candidates are never part of it.
+pub fn code_actions(input: &str, errors: &[ValidationError], line: usize) -> Vec<CodeAction> {
+    let mut actions: Vec<CodeAction> = errors
+        .iter()
+        .filter(|error| error.line() == line)
+        .flat_map(|error| {
+            error
+                .fix()
+                .into_iter()
+                .chain(error.candidate_fixes())
+                .map(|fix| CodeAction {
+                    title: fix.describe(input),
+                    fixes: vec![fix],
+                    diagnostic: Some(error.clone()),
+                })
+        })
+        .collect();
+
+    let fixes: Vec<Fix> = errors.iter().filter_map(ValidationError::fix).collect();
+    let all: Vec<Fix> = applicable_fixes(input, &fixes)
+        .into_iter()
+        .cloned()
+        .collect();
+    if !actions.is_empty() && all.len() > 1 {
+        actions.push(CodeAction {
+            title: format!("Fix all {} auto-fixable issues", all.len()),
+            fixes: all,
+            diagnostic: None,
+        });
+    }
+
+    actions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::Span;
+    use crate::validate::apply_fixes;
+
+    #[test]
+    fn action_per_fixable_finding() {
+        let input = "*.rs @alice\n*.md @bob\n";
+        let errors = vec![
+            ValidationError::owner_must_be_team_with_suggestion(
+                "@alice",
+                "@org/rust",
+                Span::new(5, 1, 6, 6),
+            ),
+            ValidationError::owner_must_be_team("@bob", Span::new(17, 2, 6, 4)),
+        ];
+
+        let actions = code_actions(input, &errors, 1);
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].title, "Replace '@alice' with '@org/rust'");
+        assert_eq!(
+            apply_fixes(input, &actions[0].fixes),
+            "*.rs @org/rust\n*.md @bob\n"
+        );
+
+        // The unfixable finding yields no action
+        assert!(code_actions(input, &errors, 2).is_empty());
+    }
+
+    #[test]
+    fn action_per_candidate_fix() {
+        let input = "build/ @release\n";
+        let errors = vec![
+            ValidationError::unanchored_directory("build/", "'web/build/'", Span::new(0, 1, 1, 6)),
+            ValidationError::owner_must_be_team_with_suggestion(
+                "@release",
+                "@org/release",
+                Span::new(7, 1, 8, 8),
+            ),
+        ];
+
+        let titles: Vec<_> = code_actions(input, &errors, 1)
+            .into_iter()
+            .map(|action| action.title)
+            .collect();
+        assert_eq!(
+            titles,
+            [
+                "Replace 'build/' with '/build/'",
+                "Replace 'build/' with '**/build/'",
+                "Replace '@release' with '@org/release'",
+            ]
+        );
+    }
+
+    #[test]
+    fn fix_all_action() {
+        let input = "*.rs @alice\n*.md @bob\n";
+        let errors = vec![
+            ValidationError::owner_must_be_team_with_suggestion(
+                "@alice",
+                "@org/a",
+                Span::new(5, 1, 6, 6),
+            ),
+            ValidationError::owner_must_be_team_with_suggestion(
+                "@bob",
+                "@org/b",
+                Span::new(17, 2, 6, 4),
+            ),
+        ];
+
+        let actions = code_actions(input, &errors, 2);
+        assert_eq!(actions.len(), 2);
+        assert!(actions[1].diagnostic.is_none());
+        assert_eq!(
+            apply_fixes(input, &actions[1].fixes),
+            "*.rs @org/a\n*.md @org/b\n"
+        );
+    }
+
+    #[test]
+    fn fix_all_skips_overlapping_fixes() {
+        let input = "*.rs @alice\n*.rs @bob\n";
+        let errors = vec![
+            ValidationError::owner_must_be_team_with_suggestion(
+                "@alice",
+                "@org/a",
+                Span::new(5, 1, 6, 6),
+            ),
+            ValidationError::duplicate_pattern_with_removal(
+                "*.rs",
+                Span::new(12, 2, 1, 4),
+                1,
+                Span::new(0, 1, 1, 12),
+            ),
+            ValidationError::owner_must_be_team_with_suggestion(
+                "@bob",
+                "@org/b",
+                Span::new(17, 2, 6, 4),
+            ),
+        ];
+
+        // The owner fix on the deleted rule is superseded, as with `--fix`
+        let actions = code_actions(input, &errors, 2);
+        let fix_all = actions.last().unwrap();
+        assert_eq!(fix_all.title, "Fix all 2 auto-fixable issues");
+        assert_eq!(fix_all.fixes.len(), 2);
+        assert_eq!(apply_fixes(input, &fix_all.fixes), "*.rs @org/b\n");
+    }
+}
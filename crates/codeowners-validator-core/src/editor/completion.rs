@@ -0,0 +1,290 @@
+//! Completion candidates for owners and path patterns.
+
+use crate::parse::lexer::split_lines;
+use crate::parse::{LineKind, Span, TokenKind, lex_line, parse_codeowners};
+use crate::validate::file_walker::{FileWalkerConfig, list_files};
+use std::collections::BTreeSet;
+use std::path::Path;
+
+/// What a completion item inserts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CompletionKind {
+    /// A GitHub user (`@user`).
+    User,
+    /// A GitHub team (`@org/team`).
+    Team,
+    /// An email owner.
+    Email,
+    /// A directory path segment (ends with `/`).
+    Directory,
+    /// A file path.
+    File,
+}
+
+/// A single completion candidate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletionItem {
+    /// The text to insert.
+    pub label: String,
+    /// What the label refers to.
+    pub kind: CompletionKind,
+    /// The source region the label replaces (the partial token under the cursor).
+    pub replace: Span,
+}
+
+/// Known owners and repository paths that completions are drawn from.
+///
+/// Frontends typically fill this once (e.g. from cached GitHub org members and
+/// teams, and from the repository file index) and reuse it across requests.
+/// Owners already present in the CODEOWNERS file are always offered too.
+#[derive(Debug, Clone, Default)]
+pub struct CompletionIndex {
+    owners: BTreeSet<String>,
+    paths: Vec<String>,
+}
+
+impl CompletionIndex {
+    /// Creates an empty index.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an index of the files and directories in a repository.
+    pub fn from_repo(repo_path: &Path) -> Self {
+        let config = FileWalkerConfig::for_files_check().with_directories(true);
+        Self::new().with_paths(list_files(repo_path, &config))
+    }
+
+    /// Adds known owners (e.g. `@user`, `@org/team`, `dev@example.com`).
+    pub fn with_owners(mut self, owners: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.owners.extend(owners.into_iter().map(Into::into));
+        self
+    }
+
+    /// Adds repository paths relative to the repository root.
+    pub fn with_paths(mut self, paths: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.paths.extend(paths.into_iter().map(Into::into));
+        self.paths.sort();
+        self.paths.dedup();
+        self
+    }
+}
+
+/// Computes completions at a byte offset in a CODEOWNERS file.
+///
+/// In owner position, known owners matching the typed prefix are offered. In
+/// pattern position, the next path segment of matching repository paths is
+/// offered. Nothing is offered inside comments.
+///
+/// An offset inside a line terminator or a multibyte character completes at
+/// the end of the line, or the start of the character, instead.
+pub fn complete(input: &str, offset: usize, index: &CompletionIndex) -> Vec<CompletionItem> {
+    let Some((line_num, line_start, line)) = line_at(input, offset) else {
+        return Vec::new();
+    };
+    let column = line.floor_char_boundary((offset - line_start).min(line.len()));
+    let offset = line_start + column;
+
+    let mut in_owner_position = false;
+    let mut prefix = "";
+    let mut replace = Span::point(offset, line_num, column + 1);
+
+    for token in lex_line(line) {
+        let start = token.span.offset;
+        let end = start + token.span.length;
+        if start > column {
+            break;
+        }
+        match token.kind {
+            TokenKind::Comment => return Vec::new(),
+            TokenKind::Pattern | TokenKind::Owner if column <= end => {
+                in_owner_position = token.kind == TokenKind::Owner;
+                prefix = &token.text[..column - start];
+                replace = Span::new(line_start + start, line_num, start + 1, token.span.length);
+                break;
+            }
+            TokenKind::Pattern | TokenKind::Owner => in_owner_position = true,
+            TokenKind::Whitespace => {}
+        }
+    }
+
+    if in_owner_position {
+        complete_owner(input, prefix, replace, index)
+    } else {
+        complete_path(prefix, replace, index)
+    }
+}
+
+/// Finds the line containing `offset`, returning its number, start offset, and text.
+///
+/// Offsets on a line's terminator, including the `\r` and `\n` of `\r\n`,
+/// belong to that line.
+fn line_at(input: &str, offset: usize) -> Option<(usize, usize, &str)> {
+    let mut count = 0;
+    for (idx, (line_start, line)) in split_lines(input).enumerate() {
+        let line_end = line_start + line.len();
+        let terminator = if input[line_end..].starts_with("\r\n") {
+            2
+        } else {
+            usize::from(input[line_end..].starts_with('\n'))
+        };
+        if offset <= line_end || offset < line_end + terminator {
+            return Some((idx + 1, line_start, line));
+        }
+        count = idx + 1;
+    }
+    // Cursor on the empty line after a trailing newline
+    (offset == input.len()).then(|| (count + 1, offset, ""))
+}
+
+fn complete_owner(
+    input: &str,
+    prefix: &str,
+    replace: Span,
+    index: &CompletionIndex,
+) -> Vec<CompletionItem> {
+    let mut candidates = index.owners.clone();
+    for line in &parse_codeowners(input).ast.lines {
+        if let LineKind::Rule { owners, .. } = &line.kind {
+            candidates.extend(
+                owners
+                    .iter()
+                    .filter(|o| !o.is_invalid())
+                    .map(|o| o.as_str().into_owned()),
+            );
+        }
+    }
+
+    let prefix_lower = prefix.to_lowercase();
+    candidates
+        .into_iter()
+        .filter(|owner| owner != prefix && owner.to_lowercase().starts_with(&prefix_lower))
+        .map(|owner| {
+            let kind = if !owner.starts_with('@') {
+                CompletionKind::Email
+            } else if owner.contains('/') {
+                CompletionKind::Team
+            } else {
+                CompletionKind::User
+            };
+            CompletionItem {
+                label: owner,
+                kind,
+                replace,
+            }
+        })
+        .collect()
+}
+
+fn complete_path(prefix: &str, replace: Span, index: &CompletionIndex) -> Vec<CompletionItem> {
+    let (root, relative) = match prefix.strip_prefix('/') {
+        Some(rest) => ("/", rest),
+        None => ("", prefix),
+    };
+    // Complete the segment after the last typed slash
+    let dir_len = relative.rfind('/').map_or(0, |i| i + 1);
+
+    let mut seen = BTreeSet::new();
+    for path in &index.paths {
+        let Some(rest) = path.strip_prefix(relative) else {
+            continue;
+        };
+        let segment_end = rest.find('/');
+        let kind = if segment_end.is_some() {
+            CompletionKind::Directory
+        } else {
+            CompletionKind::File
+        };
+        let end = relative.len() + segment_end.map_or(rest.len(), |i| i + 1);
+        seen.insert((kind, format!("{}{}", root, &path[..end])));
+    }
+
+    seen.into_iter()
+        .filter(|(_, label)| label != prefix && label.len() > root.len() + dir_len)
+        .map(|(kind, label)| CompletionItem {
+            label,
+            kind,
+            replace,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(items: &[CompletionItem]) -> Vec<&str> {
+        items.iter().map(|i| i.label.as_str()).collect()
+    }
+
+    fn index() -> CompletionIndex {
+        CompletionIndex::new()
+            .with_owners(["@octocat", "@org/web", "@org/api", "ops@example.com"])
+            .with_paths([
+                "src/main.rs",
+                "src/lib.rs",
+                "src/cli/mod.rs",
+                "docs/README.md",
+            ])
+    }
+
+    #[test]
+    fn owner_prefix_completion() {
+        let input = "*.rs @org/\n";
+        let items = complete(input, 10, &index());
+        assert_eq!(labels(&items), vec!["@org/api", "@org/web"]);
+        assert!(items.iter().all(|i| i.kind == CompletionKind::Team));
+        assert_eq!(items[0].replace, Span::new(5, 1, 6, 5));
+    }
+
+    #[test]
+    fn owner_completion_includes_file_owners() {
+        let input = "*.md @docs-team\n*.rs \n";
+        let items = complete(input, 21, &CompletionIndex::new());
+        assert_eq!(labels(&items), vec!["@docs-team"]);
+        assert_eq!(items[0].replace, Span::point(21, 2, 6));
+    }
+
+    #[test]
+    fn path_segment_completion() {
+        let items = complete("/src/", 5, &index());
+        assert_eq!(
+            labels(&items),
+            vec!["/src/cli/", "/src/lib.rs", "/src/main.rs"]
+        );
+        assert_eq!(items[0].kind, CompletionKind::Directory);
+    }
+
+    #[test]
+    fn path_completion_on_empty_line() {
+        let items = complete("*.rs @octocat\n", 14, &index());
+        assert_eq!(labels(&items), vec!["docs/", "src/"]);
+    }
+
+    #[test]
+    fn completion_with_crlf_line_endings() {
+        let input = "a @b\r\nc @d\r\n";
+        // On the `\r` and `\n` of the first line
+        for offset in [4, 5] {
+            let items = complete(input, offset, &CompletionIndex::new());
+            assert_eq!(labels(&items), Vec::<&str>::new());
+        }
+        let items = complete(input, 9, &CompletionIndex::new().with_owners(["@dev"]));
+        assert_eq!(labels(&items), vec!["@b", "@d", "@dev"]);
+        assert_eq!(items[0].replace, Span::new(8, 2, 3, 2));
+    }
+
+    #[test]
+    fn completion_inside_multibyte_character() {
+        let index = CompletionIndex::new().with_paths(["dé/a.md", "dx/b.md"]);
+        // Offset 3 is inside `é`, so the cursor is taken to be before it
+        let items = complete("/dé @b\n", 3, &index);
+        assert_eq!(labels(&items), vec!["/dx/", "/dé/"]);
+        assert_eq!(items[0].replace, Span::new(0, 1, 1, 4));
+    }
+
+    #[test]
+    fn no_completion_in_comment() {
+        assert!(complete("# @org", 6, &index()).is_empty());
+    }
+}
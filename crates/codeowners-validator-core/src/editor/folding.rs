@@ -0,0 +1,124 @@
+//! Folding ranges for comment blocks and sections.
+
+use crate::parse::{CodeownersFile, LineKind, parse_codeowners};
+
+/// The kind of a foldable region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FoldingRangeKind {
+    /// Two or more consecutive comment lines.
+    Comment,
+    /// A comment header followed by the rules beneath it, up to the next
+    /// blank line.
+    Section,
+}
+
+/// A foldable range of lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FoldingRange {
+    /// First line of the range (1-based).
+    pub start_line: usize,
+    /// Last line of the range (1-based, inclusive).
+    pub end_line: usize,
+    /// What the range contains.
+    pub kind: FoldingRangeKind,
+}
+
+/// Computes folding ranges for a CODEOWNERS file.
+///
+/// Ranges are returned sorted by start line; a section may contain a
+/// comment range that starts on the same line.
+pub fn folding_ranges(input: &str) -> Vec<FoldingRange> {
+    folding_ranges_for_file(&parse_codeowners(input).ast)
+}
+
+/// Computes folding ranges for an already-parsed CODEOWNERS file.
+pub fn folding_ranges_for_file(file: &CodeownersFile) -> Vec<FoldingRange> {
+    let mut ranges = Vec::new();
+
+    // Split the file into blocks of consecutive non-blank lines.
+    let mut blocks: Vec<Vec<(usize, bool)>> = Vec::new();
+    let mut current: Vec<(usize, bool)> = Vec::new();
+    for line in &file.lines {
+        match &line.kind {
+            LineKind::Blank => {
+                if !current.is_empty() {
+                    blocks.push(std::mem::take(&mut current));
+                }
+            }
+            kind => current.push((line.span.line, matches!(kind, LineKind::Comment { .. }))),
+        }
+    }
+    if !current.is_empty() {
+        blocks.push(current);
+    }
+
+    for block in blocks {
+        let (first_line, starts_with_comment) = block[0];
+        let last_line = block[block.len() - 1].0;
+
+        if starts_with_comment && block.iter().any(|(_, is_comment)| !is_comment) {
+            ranges.push(FoldingRange {
+                start_line: first_line,
+                end_line: last_line,
+                kind: FoldingRangeKind::Section,
+            });
+        }
+
+        // Runs of consecutive comment lines within the block
+        for run in block.chunk_by(|a, b| a.1 == b.1) {
+            if run[0].1 && run.len() > 1 {
+                ranges.push(FoldingRange {
+                    start_line: run[0].0,
+                    end_line: run[run.len() - 1].0,
+                    kind: FoldingRangeKind::Comment,
+                });
+            }
+        }
+    }
+
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn section_and_comment_block() {
+        let input = "# Frontend\n# owned by web\n*.js @web\n*.css @web\n\n# Lone\n";
+        let ranges = folding_ranges(input);
+        assert_eq!(
+            ranges,
+            vec![
+                FoldingRange {
+                    start_line: 1,
+                    end_line: 4,
+                    kind: FoldingRangeKind::Section,
+                },
+                FoldingRange {
+                    start_line: 1,
+                    end_line: 2,
+                    kind: FoldingRangeKind::Comment,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rules_without_header_do_not_fold() {
+        assert!(folding_ranges("*.rs @a\n*.md @b\n").is_empty());
+    }
+
+    #[test]
+    fn comment_run_after_rules() {
+        let ranges = folding_ranges("*.rs @a\n# one\n# two\n");
+        assert_eq!(
+            ranges,
+            vec![FoldingRange {
+                start_line: 2,
+                end_line: 3,
+                kind: FoldingRangeKind::Comment,
+            }]
+        );
+    }
+}
@@ -0,0 +1,191 @@
+//! Hover information for rule lines.
+
+use crate::matching::Pattern as MatchPattern;
+use crate::parse::{LineKind, Owner, parse_codeowners};
+use std::fmt::Write;
+
+/// Maximum number of matched files listed in [`HoverInfo::sample_files`].
+const SAMPLE_SIZE: usize = 5;
+
+/// A later rule that takes over some of a rule's files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverridingRule {
+    /// Line number of the later rule (1-based).
+    pub line: usize,
+    /// The later rule's pattern.
+    pub pattern: String,
+    /// Number of this rule's files the later rule takes over.
+    pub files: usize,
+}
+
+/// Ownership details for the rule under the cursor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HoverInfo {
+    /// Line number of the rule (1-based).
+    pub line: usize,
+    /// The rule's pattern.
+    pub pattern: String,
+    /// Human-readable descriptions of the rule's owners.
+    pub owners: Vec<String>,
+    /// Number of repository files the pattern matches.
+    pub matched_files: usize,
+    /// Number of matched files this rule actually owns (no later rule matches them).
+    pub effective_files: usize,
+    /// A few of the matched files, in index order.
+    pub sample_files: Vec<String>,
+    /// Later rules that override this one for some of its files.
+    pub overridden_by: Vec<OverridingRule>,
+}
+
+impl HoverInfo {
+    /// Renders the hover as Markdown.
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("**`{}`** (line {})\n\n", self.pattern, self.line);
+        if self.owners.is_empty() {
+            out.push_str("No owners\n");
+        } else {
+            out.push_str("Owners:\n");
+            for owner in &self.owners {
+                let _ = writeln!(out, "- {}", owner);
+            }
+        }
+        let _ = writeln!(
+            out,
+            "\nMatches {} file(s), owns {}",
+            self.matched_files, self.effective_files
+        );
+        for file in &self.sample_files {
+            let _ = writeln!(out, "- `{}`", file);
+        }
+        if self.matched_files > self.sample_files.len() {
+            let _ = writeln!(
+                out,
+                "- ... and {} more",
+                self.matched_files - self.sample_files.len()
+            );
+        }
+        if !self.overridden_by.is_empty() {
+            out.push_str("\nOverridden by:\n");
+            for rule in &self.overridden_by {
+                let _ = writeln!(
+                    out,
+                    "- line {}: `{}` ({} file(s))",
+                    rule.line, rule.pattern, rule.files
+                );
+            }
+        }
+        out
+    }
+}
+
+/// Describes an owner for display.
+fn describe_owner(owner: &Owner) -> String {
+    match owner {
+        Owner::User { name, .. } => format!("user @{}", name),
+        Owner::Team { org, team, .. } => format!("team @{}/{}", org, team),
+        Owner::Email { email, .. } => format!("email {}", email),
+        Owner::Invalid { raw, reason, .. } => format!("invalid owner {} ({})", raw, reason),
+    }
+}
+
+/// Computes hover information for the rule on the given line.
+///
+/// `paths` is the repository file index (paths relative to the root). Returns
+/// `None` if the line is not a rule.
+pub fn hover(input: &str, line: usize, paths: &[String]) -> Option<HoverInfo> {
+    let file = parse_codeowners(input).ast;
+    let rules: Vec<_> = file
+        .lines
+        .iter()
+        .filter_map(|l| match &l.kind {
+            LineKind::Rule { pattern, owners } => Some((l.span.line, pattern, owners)),
+            _ => None,
+        })
+        .collect();
+
+    let position = rules.iter().position(|(l, _, _)| *l == line)?;
+    let (_, pattern, owners) = rules[position];
+
+    let matcher = MatchPattern::new(&pattern.text);
+    let matched: Vec<&String> = match &matcher {
+        Some(m) => paths.iter().filter(|p| m.matches(p)).collect(),
+        None => Vec::new(),
+    };
+
+    // Later rules win, so any later match takes the file away from this rule
+    let mut overridden_by = Vec::new();
+    let mut taken = vec![false; matched.len()];
+    for (later_line, later_pattern, _) in &rules[position + 1..] {
+        let Some(later) = MatchPattern::new(&later_pattern.text) else {
+            continue;
+        };
+        let mut files = 0;
+        for (i, path) in matched.iter().enumerate() {
+            if later.matches(path) {
+                files += 1;
+                taken[i] = true;
+            }
+        }
+        if files > 0 {
+            overridden_by.push(OverridingRule {
+                line: *later_line,
+                pattern: later_pattern.text.clone(),
+                files,
+            });
+        }
+    }
+
+    Some(HoverInfo {
+        line,
+        pattern: pattern.text.clone(),
+        owners: owners.iter().map(describe_owner).collect(),
+        matched_files: matched.len(),
+        effective_files: taken.iter().filter(|t| !**t).count(),
+        sample_files: matched
+            .iter()
+            .take(SAMPLE_SIZE)
+            .map(|p| p.to_string())
+            .collect(),
+        overridden_by,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths() -> Vec<String> {
+        ["src/main.rs", "src/lib.rs", "src/api/mod.rs", "README.md"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn hover_on_rule_with_override() {
+        let input = "*.rs @rust dev@example.com\n/src/api/ @org/api\n";
+        let info = hover(input, 1, &paths()).unwrap();
+
+        assert_eq!(info.pattern, "*.rs");
+        assert_eq!(info.owners, vec!["user @rust", "email dev@example.com"]);
+        assert_eq!(info.matched_files, 3);
+        assert_eq!(info.effective_files, 2);
+        assert_eq!(
+            info.overridden_by,
+            vec![OverridingRule {
+                line: 2,
+                pattern: "/src/api/".to_string(),
+                files: 1,
+            }]
+        );
+
+        let markdown = info.to_markdown();
+        assert!(markdown.contains("Matches 3 file(s), owns 2"));
+        assert!(markdown.contains("line 2: `/src/api/`"));
+    }
+
+    #[test]
+    fn hover_on_non_rule_line() {
+        assert!(hover("# comment\n*.rs @rust\n", 1, &paths()).is_none());
+    }
+}
@@ -0,0 +1,35 @@
+//! Editor-facing language features for CODEOWNERS files.
+//!
+//! This module computes the data behind editor integrations such as
+//! semantic highlighting, code folding, completion, hover, and code actions. It is protocol-agnostic: a
+//! language server (or any other frontend) converts these results into
+//! its own wire types.
+//!
+//! # Example
+//!
+//! ```rust
+//! use codeowners_validator_core::editor::{SemanticTokenKind, semantic_tokens};
+//!
+//! let tokens = semantic_tokens("*.rs @org/team # Rust\n");
+//! let kinds: Vec<_> = tokens.iter().map(|t| t.kind).collect();
+//! assert_eq!(
+//!     kinds,
+//!     vec![
+//!         SemanticTokenKind::Pattern,
+//!         SemanticTokenKind::Team,
+//!         SemanticTokenKind::Comment,
+//!     ]
+//! );
+//! ```
+
+mod actions;
+mod completion;
+mod folding;
+mod hover;
+mod tokens;
+
+pub use actions::{CodeAction, code_actions};
+pub use completion::{CompletionIndex, CompletionItem, CompletionKind, complete};
+pub use folding::{FoldingRange, FoldingRangeKind, folding_ranges, folding_ranges_for_file};
+pub use hover::{HoverInfo, OverridingRule, hover};
+pub use tokens::{SemanticToken, SemanticTokenKind, semantic_tokens};
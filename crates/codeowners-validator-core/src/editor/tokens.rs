@@ -0,0 +1,87 @@
+//! Semantic tokens for syntax highlighting.
+
+use crate::parse::{OwnerKind, Span, TokenKind, lex};
+
+/// The highlighting category of a semantic token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SemanticTokenKind {
+    /// A rule pattern.
+    Pattern,
+    /// A GitHub user owner (`@user`).
+    User,
+    /// A GitHub team owner (`@org/team`).
+    Team,
+    /// An email owner.
+    Email,
+    /// An owner token that could not be classified.
+    InvalidOwner,
+    /// A comment.
+    Comment,
+}
+
+/// A highlighted region of a CODEOWNERS file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SemanticToken {
+    /// The highlighting category.
+    pub kind: SemanticTokenKind,
+    /// Location of the token.
+    pub span: Span,
+}
+
+/// Computes semantic tokens for a CODEOWNERS file, in source order.
+///
+/// Whitespace is not reported. Spans are absolute and never cross lines.
+pub fn semantic_tokens(input: &str) -> Vec<SemanticToken> {
+    lex(input)
+        .filter_map(|token| {
+            let kind = match token.kind {
+                TokenKind::Whitespace => return None,
+                TokenKind::Pattern => SemanticTokenKind::Pattern,
+                TokenKind::Comment => SemanticTokenKind::Comment,
+                TokenKind::Owner => match token.owner_kind()? {
+                    OwnerKind::User(_) => SemanticTokenKind::User,
+                    OwnerKind::Team { .. } => SemanticTokenKind::Team,
+                    OwnerKind::Email(_) => SemanticTokenKind::Email,
+                    OwnerKind::Unknown(_) => SemanticTokenKind::InvalidOwner,
+                },
+            };
+            Some(SemanticToken {
+                kind,
+                span: token.span,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(input: &str) -> Vec<SemanticTokenKind> {
+        semantic_tokens(input).iter().map(|t| t.kind).collect()
+    }
+
+    #[test]
+    fn owners_are_classified() {
+        assert_eq!(
+            kinds("/src/ @dev @org/team dev@example.com bogus\n"),
+            vec![
+                SemanticTokenKind::Pattern,
+                SemanticTokenKind::User,
+                SemanticTokenKind::Team,
+                SemanticTokenKind::Email,
+                SemanticTokenKind::InvalidOwner,
+            ]
+        );
+    }
+
+    #[test]
+    fn comments_and_blank_lines() {
+        let tokens = semantic_tokens("# header\n\n*.md @docs # trailing\n");
+        assert_eq!(tokens.len(), 4);
+        assert_eq!(tokens[0].kind, SemanticTokenKind::Comment);
+        assert_eq!(tokens[0].span.line, 1);
+        assert_eq!(tokens[3].kind, SemanticTokenKind::Comment);
+        assert_eq!(tokens[3].span.line, 3);
+    }
+}
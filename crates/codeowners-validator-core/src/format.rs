@@ -0,0 +1,272 @@
+//! Formatter for CODEOWNERS files.
+//!
+//! Formatting normalizes a file without changing what it means:
+//!
+//! - tokens of rules and section headers are separated by a single space,
+//!   or, with [`FormatOptions::with_align_owners`], owners are aligned into
+//!   a column across each block of consecutive rules;
+//! - comments start with `# ` and inline comments are separated by ` # `;
+//! - trailing whitespace is removed, runs of blank lines are collapsed into
+//!   one, and the file ends with exactly one newline.
+//!
+//! Invalid lines are kept as written, and generated regions are left
+//! untouched, since the tools that own them rewrite them anyway.
+//!
+//! # Example
+//!
+//! ```rust
+//! use codeowners_validator_core::format::{FormatOptions, format_codeowners, is_formatted};
+//!
+//! let input = "#Owners\n*   @org/core\n/docs/ @docs   # docs team\n\n\n";
+//! let options = FormatOptions::new();
+//!
+//! let formatted = format_codeowners(input, &options);
+//! assert_eq!(formatted, "# Owners\n* @org/core\n/docs/ @docs # docs team\n");
+//! assert!(is_formatted(&formatted, &options));
+//!
+//! let aligned = format_codeowners(input, &options.with_align_owners(true));
+//! assert_eq!(aligned, "# Owners\n*      @org/core\n/docs/ @docs # docs team\n");
+//! ```
+
+use crate::parse::{
+    CodeownersFile, Dialect, Line, LineKind, ParserConfig, parse_codeowners_with_config,
+};
+use std::collections::HashSet;
+
+/// Options for formatting a CODEOWNERS file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FormatOptions {
+    /// Align the owners of consecutive rules into a column.
+    pub align_owners: bool,
+    /// The syntax dialect of the file.
+    pub dialect: Dialect,
+}
+
+impl FormatOptions {
+    /// Creates the default options: single spaces, GitHub syntax.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether owners of consecutive rules are aligned into a column.
+    pub fn with_align_owners(mut self, value: bool) -> Self {
+        self.align_owners = value;
+        self
+    }
+
+    /// Sets the syntax dialect of the file.
+    pub fn with_dialect(mut self, dialect: Dialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+}
+
+/// Formats a CODEOWNERS file.
+///
+/// Formatting is idempotent: formatting the output again returns it as is.
+pub fn format_codeowners(input: &str, options: &FormatOptions) -> String {
+    let config = ParserConfig::new()
+        .with_dialect(options.dialect)
+        .with_inline_comments(true)
+        .with_allow_unowned_patterns(true);
+    let file = parse_codeowners_with_config(input, &config).ast;
+    let generated = generated_lines(&file);
+
+    let mut lines: Vec<String> = file
+        .lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            if generated.contains(&index) {
+                return raw_text(input, line).to_string();
+            }
+            match &line.kind {
+                LineKind::Comment { content } => format!("#{}", comment_text(content)),
+                LineKind::Invalid { .. } => raw_text(input, line).trim_end().to_string(),
+                _ => {
+                    let mut line = line.clone();
+                    line.inline_comment = line.inline_comment.as_deref().map(comment_text);
+                    line.to_string()
+                }
+            }
+        })
+        .collect();
+
+    if options.align_owners {
+        let mut start = 0;
+        while start < file.lines.len() {
+            let is_aligned =
+                |index: usize| file.lines[index].is_rule() && !generated.contains(&index);
+            let mut end = start;
+            while end < file.lines.len() && is_aligned(end) {
+                end += 1;
+            }
+            if end > start {
+                align(&file.lines[start..end], &mut lines[start..end]);
+            }
+            start = end + 1;
+        }
+    }
+
+    let mut output = String::with_capacity(input.len());
+    let mut blank = true;
+    for (index, text) in lines.iter().enumerate() {
+        let is_blank = text.trim().is_empty() && !generated.contains(&index);
+        if is_blank && blank {
+            continue;
+        }
+        blank = is_blank;
+        if !is_blank {
+            output.push_str(text);
+        }
+        output.push('\n');
+    }
+    while output.ends_with("\n\n") {
+        output.pop();
+    }
+    if output == "\n" {
+        output.clear();
+    }
+    output
+}
+
+/// Returns true if the input is already formatted.
+pub fn is_formatted(input: &str, options: &FormatOptions) -> bool {
+    format_codeowners(input, options) == input
+}
+
+/// Returns the indices of lines in generated regions, from each header to
+/// its `end-generated` comment.
+fn generated_lines(file: &CodeownersFile) -> HashSet<usize> {
+    file.generated_regions()
+        .iter()
+        .flat_map(|region| {
+            let end = (region.end + 1).min(file.lines.len());
+            region.header..end
+        })
+        .collect()
+}
+
+/// Returns the source text of a line.
+fn raw_text<'a>(input: &'a str, line: &Line) -> &'a str {
+    &input[line.span.offset..line.span.end_offset()]
+}
+
+/// Formats the text of a comment after its `#`, separating it from the
+/// `#` with a space.
+///
+/// Comments made of `#` characters, such as banners, are kept as is.
+fn comment_text(content: &str) -> String {
+    let content = content.trim_end();
+    if content.is_empty() || content.starts_with(['#', ' ', '\t']) {
+        content.to_string()
+    } else {
+        format!(" {}", content)
+    }
+}
+
+/// Aligns the owners of a block of rules into a column.
+fn align(rules: &[Line], lines: &mut [String]) {
+    let width = rules
+        .iter()
+        .filter_map(|line| match &line.kind {
+            LineKind::Rule { pattern, owners } if !owners.is_empty() => {
+                Some(pattern.text.chars().count())
+            }
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0);
+
+    for (line, text) in rules.iter().zip(lines.iter_mut()) {
+        let LineKind::Rule { pattern, owners } = &line.kind else {
+            continue;
+        };
+        if owners.is_empty() {
+            continue;
+        }
+        let mut aligned = format!("{:width$}", pattern.text, width = width);
+        aligned.push_str(&text[pattern.text.len()..]);
+        *text = aligned;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_spacing() {
+        let input = "\n\n  #Owners  \n*.rs\t@rust   @alice  \n\n\n\n/docs/ @docs#team\n/old/\n\n";
+        let formatted = format_codeowners(input, &FormatOptions::new());
+        assert_eq!(
+            formatted,
+            "# Owners\n*.rs @rust @alice\n\n/docs/ @docs # team\n/old/\n"
+        );
+        assert!(is_formatted(&formatted, &FormatOptions::new()));
+        assert!(!is_formatted(input, &FormatOptions::new()));
+    }
+
+    #[test]
+    fn aligns_owners_per_block() {
+        let options = FormatOptions::new().with_align_owners(true);
+        let input = "* @core\n/services/api/ @api\n/none/\n\n/a/ @a\n/bb/ @b\n";
+        let formatted = format_codeowners(input, &options);
+        assert_eq!(
+            formatted,
+            "*              @core\n/services/api/ @api\n/none/\n\n/a/  @a\n/bb/ @b\n"
+        );
+        assert_eq!(format_codeowners(&formatted, &options), formatted);
+    }
+
+    #[test]
+    fn keeps_comment_banners_and_invalid_lines() {
+        let input = "#####\n## Section\n#\n/ok/ @ok\n/bad/ not-an-owner   \n";
+        assert_eq!(
+            format_codeowners(input, &FormatOptions::new()),
+            "#####\n## Section\n#\n/ok/ @ok\n/bad/ not-an-owner\n"
+        );
+    }
+
+    #[test]
+    fn gitlab_sections() {
+        let options = FormatOptions::new().with_dialect(Dialect::GitLab);
+        let input = "^[Docs][2]   @docs   @writers\n/docs/   @docs\n";
+        assert_eq!(
+            format_codeowners(input, &options),
+            "^[Docs][2] @docs @writers\n/docs/ @docs\n"
+        );
+    }
+
+    #[test]
+    fn leaves_generated_regions_untouched() {
+        let input = "\
+*   @core
+# generated-by: sync-tool
+/gen/   @gen
+
+#comment
+# end-generated
+/after/   @after
+";
+        assert_eq!(
+            format_codeowners(input, &FormatOptions::new()),
+            "\
+* @core
+# generated-by: sync-tool
+/gen/   @gen
+
+#comment
+# end-generated
+/after/ @after
+"
+        );
+    }
+
+    #[test]
+    fn empty_file() {
+        assert_eq!(format_codeowners("", &FormatOptions::new()), "");
+        assert_eq!(format_codeowners("\n\n  \n", &FormatOptions::new()), "");
+        assert_eq!(format_codeowners("* @a", &FormatOptions::new()), "* @a\n");
+    }
+}
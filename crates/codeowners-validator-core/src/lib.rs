@@ -49,26 +49,46 @@
 //!
 //! # Modules
 //!
+//! - [`prelude`]: The semver-stable subset of the API
 //! - [`parse`]: Parser for CODEOWNERS files
 //! - [`validate`]: Validation rules for parsed files
 //! - [`matching`]: Pattern matching for CODEOWNERS files
+//! - [`review`]: Code owner review requirements for changed files
+//! - [`editor`]: Editor-facing features (semantic tokens, folding, completion, hover, code actions)
+//! - [`format`]: Formatter for CODEOWNERS files
+//! - [`config`]: Project configuration files (`.codeowners-validator.toml`)
+//! - [`vcs`]: Version control systems (changed files, files at a revision)
+//! - [`patch`]: Unified diffs touching CODEOWNERS, applied without a checkout
+//! - `plugin`: Validation checks loaded from WASM plugins (requires the `plugins` feature)
 
 use std::path::{Path, PathBuf};
 
+pub mod config;
+pub mod editor;
+pub mod format;
 pub mod matching;
 pub mod parse;
+pub mod patch;
+pub mod prelude;
+pub mod review;
 pub mod validate;
+pub mod vcs;
+
+#[cfg(feature = "plugins")]
+pub mod plugin;
 
 #[cfg(feature = "generate")]
+#[doc(hidden)]
 pub mod generate;
 
 #[cfg(feature = "generate")]
 pub use generate::{GeneratorConfig, generate, generate_ast};
 
 // Re-export commonly used types at the crate root
+pub use config::Config;
 pub use parse::{CodeownersFile, ParseResult, parse_codeowners};
 pub use validate::checks::{
-    AsyncCheck, AsyncCheckContext, Check, CheckConfig, CheckContext, CheckRunner,
+    AsyncCheck, AsyncCheckContext, Check, CheckConfig, CheckContext, CheckDescriptor, CheckRunner,
 };
 pub use validate::{ValidationResult, validate_syntax};
 
@@ -0,0 +1,120 @@
+//! Explanations of why paths match patterns.
+//!
+//! Debugging CODEOWNERS ordering usually means finding every rule that
+//! matches a path and the one that wins. [`Pattern::explain`] describes a
+//! single match, and [`OwnersResolver::explain_path`] walks all rules.
+//!
+//! [`OwnersResolver::explain_path`]: super::OwnersResolver::explain_path
+
+use super::{Pattern, Rule, normalize_repo_path};
+
+/// Why a path did or did not match a pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchExplanation {
+    /// The original pattern.
+    pub pattern: String,
+    /// The path, without a leading slash.
+    pub path: String,
+    /// The glob the pattern was compiled to.
+    pub glob: String,
+    /// Whether the pattern is anchored to the repository root.
+    pub anchored: bool,
+    /// Whether the pattern matches only directories.
+    pub directory_only: bool,
+    /// Whether the path matched.
+    pub matched: bool,
+    /// A human-readable reason for the outcome.
+    pub reason: String,
+}
+
+impl Pattern {
+    /// Explains whether and why the pattern matches a path.
+    ///
+    /// The path should be relative to the repository root; it is normalized
+    /// with [`normalize_repo_path`].
+    pub fn explain(&self, path: &str) -> MatchExplanation {
+        let path = normalize_repo_path(path);
+        let glob = self.matcher.glob().glob().to_string();
+        let matched = self.matcher.is_match(&*path);
+
+        let reason = if !matched {
+            format!("path does not match glob '{}'", glob)
+        } else if self.directory_only {
+            "path is inside a directory the pattern matches".to_string()
+        } else if glob.starts_with("**/") && !self.original.starts_with("**/") {
+            "pattern has no slash, so it matches at any depth".to_string()
+        } else {
+            "path matches the pattern relative to the repository root".to_string()
+        };
+
+        MatchExplanation {
+            pattern: self.original.clone(),
+            path: path.to_string(),
+            glob,
+            anchored: self.anchored,
+            directory_only: self.directory_only,
+            matched,
+            reason,
+        }
+    }
+}
+
+/// How a single rule was evaluated against a path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleExplanation<'a> {
+    /// The rule.
+    pub rule: Rule<'a>,
+    /// Why the rule's pattern did or did not match.
+    pub explanation: MatchExplanation,
+}
+
+/// How the owner of a path was determined.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathExplanation<'a> {
+    /// The path, without a leading slash.
+    pub path: String,
+    /// Every rule considered, in file order.
+    pub rules: Vec<RuleExplanation<'a>>,
+    /// Index into `rules` of the winning rule: the last one that matched.
+    pub winner: Option<usize>,
+}
+
+impl<'a> PathExplanation<'a> {
+    /// Returns the rule that owns the path, if any rule matched.
+    pub fn winning_rule(&self) -> Option<&RuleExplanation<'a>> {
+        self.rules.get(self.winner?)
+    }
+
+    /// Returns the rules whose patterns matched, in file order.
+    pub fn matching_rules(&self) -> impl Iterator<Item = &RuleExplanation<'a>> {
+        self.rules.iter().filter(|rule| rule.explanation.matched)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explain_pattern_matches() {
+        let explanation = Pattern::new("*.rs").unwrap().explain("/src/lib.rs");
+        assert!(explanation.matched);
+        assert_eq!(explanation.path, "src/lib.rs");
+        assert_eq!(explanation.glob, "**/*.rs");
+        assert_eq!(
+            explanation.reason,
+            "pattern has no slash, so it matches at any depth"
+        );
+
+        let explanation = Pattern::new("/docs/").unwrap().explain("docs/a/b.md");
+        assert!(explanation.matched && explanation.anchored && explanation.directory_only);
+        assert_eq!(
+            explanation.reason,
+            "path is inside a directory the pattern matches"
+        );
+
+        let explanation = Pattern::new("src/*.rs").unwrap().explain("lib/src/main.rs");
+        assert!(!explanation.matched);
+        assert_eq!(explanation.reason, "path does not match glob 'src/*.rs'");
+    }
+}
@@ -8,8 +8,98 @@
 //! - `/` at the start anchors to the repository root
 //! - `/` at the end matches only directories
 //! - Patterns without a leading `/` match anywhere in the path
+//! - `?` matches any single character except `/`, unless configured
+//!   otherwise with [`QuestionMark`]
+//! - `[abc]` matches one of the listed characters, as in gitignore; whether
+//!   such patterns are accepted is set by [`PatternSyntax`]
+//! - `!pattern` excludes matching paths from ownership, with
+//!   [`PatternSyntax::GitLab`] only
+//! - `\ ` matches a literal space, so paths with spaces can be written
+//!
+//! When several rules match a path, the last one wins. [`RuleSet`] compiles
+//! all rules of a file once and returns the matching rules, and
+//! [`OwnersResolver`] uses it to answer ownership queries.
+//! [`OwnersResolver::explain_path`] shows how the winning rule was chosen.
+//!
+//! Paths are normalized with [`normalize_repo_path`] before matching, so
+//! on Windows, paths such as `src\main.rs` match like `src/main.rs`.
+
+mod explain;
+mod path;
+mod resolver;
+mod rule_set;
+
+pub use explain::{MatchExplanation, PathExplanation, RuleExplanation};
+pub use path::{normalize_repo_path, to_repo_path};
+pub use resolver::OwnersResolver;
+pub use rule_set::{Rule, RuleCoverage, RuleSet};
+
+use crate::parse::{CodeownersFile, Line, Owner};
+use crate::validate::file_walker::{FileWalkerConfig, list_files};
+use globset::{Glob, GlobBuilder, GlobMatcher, GlobSet, GlobSetBuilder};
+use std::path::Path;
 
-use globset::{GlobBuilder, GlobMatcher, GlobSet, GlobSetBuilder};
+/// How `?` in a pattern is interpreted.
+///
+/// gitignore, which GitHub's matcher follows, treats `?` as a wildcard for
+/// one character. Parts of GitHub's documentation describe it as a literal
+/// character instead, so the interpretation is configurable.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum QuestionMark {
+    /// `?` matches any single character except `/`.
+    #[default]
+    Wildcard,
+    /// `?` matches only a literal `?`.
+    Literal,
+    /// `?` is reported by the syntax check, and matched as a wildcard.
+    Unsupported,
+}
+
+/// Which pattern syntax a CODEOWNERS file may use.
+///
+/// GitHub rejects character classes such as `[abc]`, but GitLab and some
+/// other tools accept them. Character classes are matched the same way in
+/// every mode; the mode decides whether the syntax check reports them.
+///
+/// GitLab also supports `!pattern` exclusions: a path matching any exclusion
+/// requires no code owner approval, wherever the exclusion appears in the
+/// file. Other modes report such patterns and match them literally.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PatternSyntax {
+    /// The syntax GitHub supports. Character classes are reported.
+    #[default]
+    GitHub,
+    /// GitHub's syntax plus `[...]` character classes.
+    Extended,
+    /// GitLab's syntax: character classes and `!pattern` exclusions.
+    GitLab,
+}
+
+impl PatternSyntax {
+    /// Returns true if `[...]` character classes are accepted.
+    pub fn allows_character_classes(self) -> bool {
+        matches!(self, Self::Extended | Self::GitLab)
+    }
+
+    /// Returns true if `!pattern` exclusions are accepted.
+    pub fn allows_negation(self) -> bool {
+        self == Self::GitLab
+    }
+
+    /// Splits an exclusion pattern into the pattern it excludes.
+    ///
+    /// Returns the pattern without its `!` and `true` for exclusions, or the
+    /// pattern unchanged and `false` if it is not one or exclusions are not
+    /// accepted.
+    pub fn split_negation(self, pattern: &str) -> (&str, bool) {
+        match pattern.strip_prefix('!') {
+            Some(excluded) if self.allows_negation() => (excluded, true),
+            _ => (pattern, false),
+        }
+    }
+}
 
 /// A compiled CODEOWNERS pattern that can match file paths.
 #[derive(Debug, Clone)]
@@ -29,19 +119,18 @@ impl Pattern {
     ///
     /// Returns `None` if the pattern is invalid.
     pub fn new(pattern: &str) -> Option<Self> {
-        let original = pattern.to_string();
-        let (glob_pattern, anchored, directory_only) = normalize_pattern(pattern);
+        Self::with_question_mark(pattern, QuestionMark::default())
+    }
 
-        // Use literal_separator to ensure * doesn't match /
-        let glob = GlobBuilder::new(&glob_pattern)
-            .literal_separator(true)
-            .build()
-            .ok()?;
-        let matcher = glob.compile_matcher();
+    /// Compiles a CODEOWNERS pattern, interpreting `?` as configured.
+    ///
+    /// Returns `None` if the pattern is invalid.
+    pub fn with_question_mark(pattern: &str, question_mark: QuestionMark) -> Option<Self> {
+        let (glob, anchored, directory_only) = compile_glob(pattern, question_mark)?;
 
         Some(Self {
-            original,
-            matcher,
+            original: pattern.to_string(),
+            matcher: glob.compile_matcher(),
             anchored,
             directory_only,
         })
@@ -64,11 +153,10 @@ impl Pattern {
 
     /// Checks if this pattern matches the given path.
     ///
-    /// The path should be relative to the repository root and use forward slashes.
+    /// The path should be relative to the repository root; it is normalized
+    /// with [`normalize_repo_path`].
     pub fn matches(&self, path: &str) -> bool {
-        // Normalize path to not have leading slash
-        let path = path.strip_prefix('/').unwrap_or(path);
-        self.matcher.is_match(path)
+        self.matcher.is_match(&*normalize_repo_path(path))
     }
 
     /// Checks if this pattern matches the given path, considering directory status.
@@ -91,6 +179,9 @@ impl Pattern {
 }
 
 /// A set of compiled patterns for efficient matching.
+///
+/// Matches only identify patterns. Use [`RuleSet`] to get the matching rules
+/// of a CODEOWNERS file, with their lines and owners.
 #[derive(Debug, Clone)]
 pub struct PatternSet {
     /// The glob set for batch matching.
@@ -101,18 +192,21 @@ pub struct PatternSet {
 
 impl PatternSet {
     /// Creates a new pattern set from a list of pattern strings.
+    ///
+    /// Returns `None` if any pattern is invalid.
     pub fn new(patterns: &[&str]) -> Option<Self> {
+        Self::with_question_mark(patterns, QuestionMark::default())
+    }
+
+    /// Creates a new pattern set, interpreting `?` as configured.
+    ///
+    /// Returns `None` if any pattern is invalid.
+    pub fn with_question_mark(patterns: &[&str], question_mark: QuestionMark) -> Option<Self> {
         let mut builder = GlobSetBuilder::new();
         let mut compiled_patterns = Vec::with_capacity(patterns.len());
 
         for pattern_str in patterns {
-            // Normalize once, reuse for both GlobSet and Pattern
-            let (glob_pattern, anchored, directory_only) = normalize_pattern(pattern_str);
-
-            let glob = GlobBuilder::new(&glob_pattern)
-                .literal_separator(true)
-                .build()
-                .ok()?;
+            let (glob, anchored, directory_only) = compile_glob(pattern_str, question_mark)?;
 
             // Clone for the GlobSet, use original for the Pattern's matcher
             builder.add(glob.clone());
@@ -136,8 +230,7 @@ impl PatternSet {
 
     /// Returns indices of all patterns that match the given path.
     pub fn matches(&self, path: &str) -> Vec<usize> {
-        let path = path.strip_prefix('/').unwrap_or(path);
-        self.glob_set.matches(path)
+        self.glob_set.matches(&*normalize_repo_path(path))
     }
 
     /// Returns the last (most recent) pattern that matches the path.
@@ -150,8 +243,7 @@ impl PatternSet {
 
     /// Returns true if any pattern matches the path.
     pub fn is_match(&self, path: &str) -> bool {
-        let path = path.strip_prefix('/').unwrap_or(path);
-        self.glob_set.is_match(path)
+        self.glob_set.is_match(&*normalize_repo_path(path))
     }
 
     /// Returns the number of patterns in the set.
@@ -165,6 +257,99 @@ impl PatternSet {
     }
 }
 
+/// The rule that owns a path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OwningRule<'a> {
+    /// The rule's line in the CODEOWNERS file.
+    pub line: &'a Line,
+    /// The rule's pattern.
+    pub pattern: &'a crate::parse::Pattern,
+    /// The rule's owners, which may be empty.
+    pub owners: &'a [Owner],
+}
+
+impl OwningRule<'_> {
+    /// Returns true if the rule assigns at least one owner.
+    ///
+    /// A matching rule without owners leaves the path unowned.
+    pub fn is_owned(&self) -> bool {
+        !self.owners.is_empty()
+    }
+}
+
+/// Finds the rule that owns a path: the last rule whose pattern matches it.
+///
+/// The path is relative to the repository root and does not need to exist,
+/// so this also answers who would own a file before it is created. Rules
+/// with invalid patterns are ignored. Rules without owners only appear in
+/// files parsed with [`ParserConfig::allow_unowned_patterns`] set.
+///
+/// [`ParserConfig::allow_unowned_patterns`]: crate::parse::ParserConfig::allow_unowned_patterns
+///
+/// Every call compiles the rules; to look up many paths, compile them once
+/// into a [`RuleSet`] and use [`RuleSet::last_match`].
+pub fn owning_rule<'a>(file: &'a CodeownersFile, path: &str) -> Option<OwningRule<'a>> {
+    RuleSet::from_file(file).last_match(path).map(Into::into)
+}
+
+/// Lists every file in a repository whose owners include `owner`.
+///
+/// Walks the repository like the `notowned` check does: hidden files are
+/// included and `.gitignore` is respected. Ownership follows GitHub's
+/// precedence (see [`OwnersResolver`]), and owners are compared
+/// case-insensitively. Paths are relative to `repo_path` and sorted.
+///
+/// Useful for audits, e.g. finding everything a departing engineer owns.
+pub fn files_for_owner(file: &CodeownersFile, repo_path: &Path, owner: &str) -> Vec<String> {
+    let mut files = list_files(repo_path, &FileWalkerConfig::for_not_owned_check());
+    files.sort_unstable();
+
+    let resolver = OwnersResolver::from_file(file);
+    let owned = resolver.files_owned_by(owner, files.iter().map(String::as_str));
+    owned.into_iter().map(str::to_string).collect()
+}
+
+/// Compiles a CODEOWNERS pattern to a glob.
+///
+/// Returns (glob, is_anchored, is_directory_only), or `None` if the pattern is invalid.
+fn compile_glob(pattern: &str, question_mark: QuestionMark) -> Option<(Glob, bool, bool)> {
+    let (mut glob_pattern, anchored, directory_only) = normalize_pattern(pattern);
+    if glob_pattern.contains("\\ ") {
+        glob_pattern = unescape_spaces(&glob_pattern);
+    }
+    if question_mark == QuestionMark::Literal {
+        glob_pattern = glob_pattern.replace('?', "[?]");
+    }
+
+    // Use literal_separator to ensure * doesn't match /
+    let glob = GlobBuilder::new(&glob_pattern)
+        .literal_separator(true)
+        .build()
+        .ok()?;
+
+    Some((glob, anchored, directory_only))
+}
+
+/// Replaces escaped spaces (`\ `) with literal spaces.
+///
+/// Other escapes are kept, including an escaped backslash before a space.
+fn unescape_spaces(pattern: &str) -> String {
+    let mut unescaped = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('\\', Some(' ')) => {}
+            ('\\', Some(&next)) => {
+                unescaped.push(c);
+                unescaped.push(next);
+                chars.next();
+            }
+            _ => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
 /// Normalizes a CODEOWNERS pattern to a glob pattern.
 ///
 /// Returns (glob_pattern, is_anchored, is_directory_only).
@@ -250,6 +435,50 @@ fn calculate_specificity(pattern: &str) -> u32 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn pattern_question_mark() {
+        let wildcard = Pattern::new("file?.txt").unwrap();
+        assert!(wildcard.matches("file1.txt"));
+        assert!(wildcard.matches("file?.txt"));
+        assert!(!wildcard.matches("file.txt"));
+
+        let literal = Pattern::with_question_mark("file?.txt", QuestionMark::Literal).unwrap();
+        assert!(literal.matches("file?.txt"));
+        assert!(!literal.matches("file1.txt"));
+
+        let unsupported =
+            Pattern::with_question_mark("file?.txt", QuestionMark::Unsupported).unwrap();
+        assert!(unsupported.matches("file1.txt"));
+    }
+
+    #[test]
+    fn owning_rule_uses_last_match() {
+        let config = crate::parse::ParserConfig::new().with_allow_unowned_patterns(true);
+        let file = crate::parse::parse_codeowners_with_config(
+            "* @default\n/src/ @core\n/src/generated/\n*.md @docs\n",
+            &config,
+        )
+        .ast;
+
+        let rule = owning_rule(&file, "src/new/mod.rs").unwrap();
+        assert_eq!(rule.pattern.text, "/src/");
+        assert_eq!(rule.line.span.line, 2);
+        assert!(rule.is_owned());
+
+        assert_eq!(
+            owning_rule(&file, "src/README.md").unwrap().pattern.text,
+            "*.md"
+        );
+        assert_eq!(owning_rule(&file, "build.rs").unwrap().pattern.text, "*");
+
+        // A matching rule without owners leaves the path unowned
+        let unowned = owning_rule(&file, "src/generated/api.rs").unwrap();
+        assert!(!unowned.is_owned());
+
+        let empty = crate::parse::parse_codeowners("/docs/ @docs\n").ast;
+        assert!(owning_rule(&empty, "src/main.rs").is_none());
+    }
+
     #[test]
     fn pattern_simple_wildcard() {
         let pattern = Pattern::new("*.rs").unwrap();
@@ -300,6 +529,16 @@ mod tests {
         assert!(!pattern.matches("other/docs/README.md"));
     }
 
+    #[test]
+    fn pattern_escaped_space() {
+        let pattern = Pattern::new("docs/my\\ folder/").unwrap();
+        assert!(pattern.matches("docs/my folder/guide.md"));
+        assert!(!pattern.matches("docs/my\\ folder/guide.md"));
+
+        let pattern = Pattern::new("*\\ copy.txt").unwrap();
+        assert!(pattern.matches("src/notes copy.txt"));
+    }
+
     #[test]
     fn pattern_unanchored_with_slash() {
         let pattern = Pattern::new("docs/*.md").unwrap();
@@ -339,6 +578,17 @@ mod tests {
         assert!(!set.is_match("main.txt"));
     }
 
+    #[test]
+    fn pattern_set_question_mark() {
+        let set =
+            PatternSet::with_question_mark(&["file?.txt", "my\\ docs/"], QuestionMark::Literal)
+                .unwrap();
+        assert!(set.is_match("file?.txt"));
+        assert!(!set.is_match("file1.txt"));
+        assert!(set.is_match("my docs/a.md"));
+        assert!(PatternSet::new(&["*.rs", "[z-a]"]).is_none());
+    }
+
     #[test]
     fn pattern_set_last_match() {
         let set = PatternSet::new(&["*", "*.rs", "/src/*.rs"]).unwrap();
@@ -382,4 +632,24 @@ mod tests {
         assert!(dir);
         assert_eq!(p, "src/lib/**");
     }
+
+    #[test]
+    fn files_for_owner_walks_repository() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join("src/vendor")).unwrap();
+        for file in ["README.md", "src/lib.rs", "src/vendor/x.c", ".editorconfig"] {
+            std::fs::File::create(dir.path().join(file)).unwrap();
+        }
+
+        let file =
+            crate::parse::parse_codeowners("* @alice\n/src/ @org/core\n/src/vendor/ @alice\n").ast;
+        assert_eq!(
+            files_for_owner(&file, dir.path(), "@alice"),
+            [".editorconfig", "README.md", "src/vendor/x.c"]
+        );
+        assert_eq!(
+            files_for_owner(&file, dir.path(), "@org/core"),
+            ["src/lib.rs"]
+        );
+    }
 }
@@ -0,0 +1,99 @@
+//! Normalization of repository paths.
+//!
+//! Patterns are matched against paths relative to the repository root,
+//! separated by forward slashes. Paths from the file system on Windows may
+//! use backslashes, and paths typed by users may start with `./` or `/`;
+//! matched as is, they match no rule at all.
+
+use std::borrow::Cow;
+use std::path::{Component, Path};
+
+/// Normalizes a path relative to the repository root for matching.
+///
+/// On Windows, backslashes become forward slashes; elsewhere a backslash is
+/// a legal file name character and is kept. A leading `./` or `/` is
+/// removed. Paths that are already normalized are returned as is, without
+/// allocating.
+///
+/// # Example
+///
+/// ```rust
+/// use codeowners_validator_core::matching::normalize_repo_path;
+///
+/// assert_eq!(normalize_repo_path("./docs/guide.md"), "docs/guide.md");
+/// assert_eq!(normalize_repo_path("/README.md"), "README.md");
+/// if cfg!(windows) {
+///     assert_eq!(normalize_repo_path(r"src\main.rs"), "src/main.rs");
+/// }
+/// ```
+pub fn normalize_repo_path(path: &str) -> Cow<'_, str> {
+    if cfg!(windows) && path.contains('\\') {
+        Cow::Owned(trim_root(&path.replace('\\', "/")).to_string())
+    } else {
+        Cow::Borrowed(trim_root(path))
+    }
+}
+
+/// Removes a leading `./` or `/`.
+fn trim_root(path: &str) -> &str {
+    path.trim_start_matches("./").trim_start_matches('/')
+}
+
+/// Converts a file system path relative to the repository root into a
+/// repository path, joining its components with forward slashes whatever
+/// the platform's separator.
+///
+/// Returns `None` if the path is not valid UTF-8, or is not relative.
+pub fn to_repo_path(path: &Path) -> Option<String> {
+    let mut parts = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(part) => parts.push(part.to_str()?),
+            Component::CurDir => {}
+            Component::Prefix(_) | Component::RootDir | Component::ParentDir => return None,
+        }
+    }
+    Some(parts.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_paths() {
+        assert_eq!(normalize_repo_path("./src/main.rs"), "src/main.rs");
+        assert_eq!(normalize_repo_path("/docs/"), "docs/");
+        assert!(matches!(
+            normalize_repo_path("src/main.rs"),
+            Cow::Borrowed("src/main.rs")
+        ));
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn normalizes_windows_paths() {
+        assert_eq!(normalize_repo_path(r"src\lib\mod.rs"), "src/lib/mod.rs");
+        assert_eq!(normalize_repo_path(r".\src\main.rs"), "src/main.rs");
+        assert_eq!(normalize_repo_path(r"\docs\"), "docs/");
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn keeps_backslashes_in_file_names() {
+        assert_eq!(normalize_repo_path(r"src\main.rs"), r"src\main.rs");
+        assert_eq!(normalize_repo_path(r"./a\b"), r"a\b");
+    }
+
+    #[test]
+    fn converts_relative_paths() {
+        let path = Path::new("src").join("cli").join("main.rs");
+        assert_eq!(to_repo_path(&path).as_deref(), Some("src/cli/main.rs"));
+        assert_eq!(
+            to_repo_path(Path::new("./docs/guide.md")).as_deref(),
+            Some("docs/guide.md")
+        );
+        assert_eq!(to_repo_path(Path::new("/etc/passwd")), None);
+        assert_eq!(to_repo_path(Path::new("../outside")), None);
+    }
+}
@@ -0,0 +1,319 @@
+//! Ownership resolution for CODEOWNERS files.
+
+use super::{
+    OwningRule, PathExplanation, Pattern, PatternSyntax, QuestionMark, RuleExplanation, RuleSet,
+    normalize_repo_path,
+};
+use crate::parse::{CodeownersFile, Owner};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Resolves the owners of paths using GitHub's precedence rules.
+///
+/// All rule patterns are compiled once into a [`RuleSet`]. For each path,
+/// the last matching rule in the file wins, and a winning rule without owners
+/// leaves the path unowned. Rules with invalid patterns are ignored. With
+/// [`PatternSyntax::GitLab`], paths matching a `!pattern` exclusion are
+/// unowned.
+///
+/// # Example
+///
+/// ```rust
+/// use codeowners_validator_core::matching::OwnersResolver;
+/// use codeowners_validator_core::parse::parse_codeowners;
+///
+/// let file = parse_codeowners("* @default\n/src/ @org/core\n").ast;
+/// let resolver = OwnersResolver::from_file(&file);
+///
+/// let owners = resolver.owners_for("src/main.rs").unwrap();
+/// assert_eq!(owners[0].as_str(), "@org/core");
+/// ```
+#[derive(Debug, Clone)]
+pub struct OwnersResolver<'a> {
+    /// The compiled rules.
+    rules: RuleSet<'a>,
+}
+
+impl<'a> OwnersResolver<'a> {
+    /// Compiles the rules of a CODEOWNERS file.
+    pub fn from_file(file: &'a CodeownersFile) -> Self {
+        Self::with_question_mark(file, QuestionMark::default())
+    }
+
+    /// Compiles the rules of a CODEOWNERS file, interpreting `?` as configured.
+    pub fn with_question_mark(file: &'a CodeownersFile, question_mark: QuestionMark) -> Self {
+        Self::with_syntax(file, question_mark, PatternSyntax::default())
+    }
+
+    /// Compiles the rules of a CODEOWNERS file, interpreting `?` and
+    /// `!pattern` exclusions as configured.
+    pub fn with_syntax(
+        file: &'a CodeownersFile,
+        question_mark: QuestionMark,
+        pattern_syntax: PatternSyntax,
+    ) -> Self {
+        Self {
+            rules: RuleSet::with_syntax(file, question_mark, pattern_syntax),
+        }
+    }
+
+    /// Returns the compiled rules.
+    pub fn rule_set(&self) -> &RuleSet<'a> {
+        &self.rules
+    }
+
+    /// Returns the rule that owns a path: the last rule whose pattern matches.
+    ///
+    /// The path is relative to the repository root and does not need to exist.
+    pub fn rule_for(&self, path: &str) -> Option<OwningRule<'a>> {
+        self.resolve(path, &mut Vec::new())
+    }
+
+    /// Returns the owners of a path.
+    ///
+    /// Returns `None` if no rule matches the path, or if the last matching
+    /// rule has no owners.
+    pub fn owners_for(&self, path: &str) -> Option<&'a [Owner]> {
+        owners_of(self.rule_for(path))
+    }
+
+    /// Returns the owners of each path, in order.
+    ///
+    /// Equivalent to calling [`owners_for`](Self::owners_for) per path, but
+    /// reuses one match buffer across all of them.
+    pub fn owners_for_many<'p>(
+        &self,
+        paths: impl IntoIterator<Item = &'p str>,
+    ) -> Vec<Option<&'a [Owner]>> {
+        let mut matches = Vec::new();
+        paths
+            .into_iter()
+            .map(|path| owners_of(self.resolve(path, &mut matches)))
+            .collect()
+    }
+
+    /// Returns the owners of each path, in order, resolving paths in parallel.
+    #[cfg(feature = "parallel")]
+    pub fn par_owners_for_many(&self, paths: &[&str]) -> Vec<Option<&'a [Owner]>> {
+        paths
+            .par_iter()
+            .map_init(Vec::new, |matches, path| {
+                owners_of(self.resolve(path, matches))
+            })
+            .collect()
+    }
+
+    /// Returns the paths whose owners include `owner`, in order.
+    ///
+    /// Owners are compared case-insensitively in their written form, e.g.
+    /// `@user`, `@org/team` or `user@example.com`.
+    pub fn files_owned_by<'p>(
+        &self,
+        owner: &str,
+        paths: impl IntoIterator<Item = &'p str>,
+    ) -> Vec<&'p str> {
+        let mut matches = Vec::new();
+        paths
+            .into_iter()
+            .filter(|path| {
+                owners_of(self.resolve(path, &mut matches)).is_some_and(|owners| {
+                    owners
+                        .iter()
+                        .any(|candidate| candidate.as_str().eq_ignore_ascii_case(owner))
+                })
+            })
+            .collect()
+    }
+
+    /// Explains how the owner of a path is determined.
+    ///
+    /// Lists every rule with whether and why its pattern matches the path,
+    /// and which matching rule wins. Each pattern is matched on its own, so
+    /// this is slower than [`rule_for`](Self::rule_for) and meant for
+    /// debugging rule order.
+    pub fn explain_path(&self, path: &str) -> PathExplanation<'a> {
+        let rules: Vec<_> = self
+            .rules
+            .rules()
+            .iter()
+            .filter_map(|rule| {
+                let pattern =
+                    Pattern::with_question_mark(&rule.pattern.text, self.rules.question_mark())?;
+                Some(RuleExplanation {
+                    rule: *rule,
+                    explanation: pattern.explain(path),
+                })
+            })
+            .collect();
+        let winner = if self.rules.is_excluded(path) {
+            None
+        } else {
+            rules.iter().rposition(|rule| rule.explanation.matched)
+        };
+
+        PathExplanation {
+            path: normalize_repo_path(path).into_owned(),
+            rules,
+            winner,
+        }
+    }
+
+    /// Returns the number of compiled rules.
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Returns true if no rules were compiled.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Finds the owning rule, using `matches` as scratch space.
+    fn resolve(&self, path: &str, matches: &mut Vec<usize>) -> Option<OwningRule<'a>> {
+        self.rules.last_match_into(path, matches).map(Into::into)
+    }
+}
+
+/// Returns the owners assigned by a rule, treating an empty list as unowned.
+fn owners_of(rule: Option<OwningRule<'_>>) -> Option<&[Owner]> {
+    rule.map(|rule| rule.owners)
+        .filter(|owners| !owners.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matching::owning_rule;
+    use crate::parse::{ParserConfig, parse_codeowners, parse_codeowners_with_config};
+
+    fn owners(resolver: &OwnersResolver, path: &str) -> Option<Vec<String>> {
+        resolver
+            .owners_for(path)
+            .map(|owners| owners.iter().map(ToString::to_string).collect())
+    }
+
+    #[test]
+    fn last_match_wins() {
+        let file = parse_codeowners("* @default\n*.rs @rust\n/src/ @core\n/docs/ @docs\n").ast;
+        let resolver = OwnersResolver::from_file(&file);
+
+        assert_eq!(resolver.len(), 4);
+        assert_eq!(owners(&resolver, "src/lib.rs"), Some(vec!["@core".into()]));
+        assert_eq!(owners(&resolver, "build.rs"), Some(vec!["@rust".into()]));
+        assert_eq!(
+            owners(&resolver, "/docs/guide.md"),
+            Some(vec!["@docs".into()])
+        );
+        assert_eq!(
+            owners(&resolver, "README.md"),
+            Some(vec!["@default".into()])
+        );
+        assert_eq!(resolver.rule_for("src/lib.rs").unwrap().line.span.line, 3);
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn windows_paths() {
+        let file = parse_codeowners("* @default\n/src/cli/ @cli\n*.md @docs\n").ast;
+        let resolver = OwnersResolver::from_file(&file);
+
+        assert_eq!(
+            owners(&resolver, r"src\cli\main.rs"),
+            Some(vec!["@cli".into()])
+        );
+        assert_eq!(
+            owners(&resolver, r".\docs\guide.md"),
+            Some(vec!["@docs".into()])
+        );
+        let explanation = resolver.explain_path(r"src\cli\main.rs");
+        assert_eq!(explanation.path, "src/cli/main.rs");
+        assert_eq!(explanation.winner, Some(1));
+    }
+
+    #[test]
+    fn unowned_paths() {
+        let config = ParserConfig::new().with_allow_unowned_patterns(true);
+        let file = parse_codeowners_with_config("/src/ @core\n/src/vendor/\n", &config).ast;
+        let resolver = OwnersResolver::from_file(&file);
+
+        assert_eq!(owners(&resolver, "README.md"), None);
+        assert_eq!(owners(&resolver, "src/vendor/lib.c"), None);
+        assert!(!resolver.rule_for("src/vendor/lib.c").unwrap().is_owned());
+        assert!(OwnersResolver::from_file(&CodeownersFile::default()).is_empty());
+    }
+
+    #[test]
+    fn batch_resolution() {
+        let file = parse_codeowners("* @default\n/src/ @core\n").ast;
+        let resolver = OwnersResolver::from_file(&file);
+        let paths = ["src/lib.rs", "README.md", "src/bin/main.rs"];
+
+        let batch = resolver.owners_for_many(paths);
+        let single: Vec<_> = paths.iter().map(|p| resolver.owners_for(p)).collect();
+        assert_eq!(batch, single);
+        assert_eq!(batch[0].unwrap()[0].as_str(), "@core");
+
+        #[cfg(feature = "parallel")]
+        assert_eq!(resolver.par_owners_for_many(&paths), single);
+    }
+
+    #[test]
+    fn files_owned_by_owner() {
+        let file =
+            parse_codeowners("* @default\n/src/ @org/core @alice\n/src/vendor/ dev@example.com\n")
+                .ast;
+        let resolver = OwnersResolver::from_file(&file);
+        let paths = ["README.md", "src/lib.rs", "src/vendor/x.c", "src/main.rs"];
+
+        assert_eq!(
+            resolver.files_owned_by("@Org/Core", paths),
+            ["src/lib.rs", "src/main.rs"]
+        );
+        assert_eq!(
+            resolver.files_owned_by("dev@example.com", paths),
+            ["src/vendor/x.c"]
+        );
+        assert!(resolver.files_owned_by("@nobody", paths).is_empty());
+    }
+
+    #[test]
+    fn explain_path_lists_every_rule() {
+        let file = parse_codeowners("* @default\n/docs/ @docs\n*.rs @rust\n/src/ @core\n").ast;
+        let resolver = OwnersResolver::from_file(&file);
+
+        let explanation = resolver.explain_path("/src/lib.rs");
+        assert_eq!(explanation.path, "src/lib.rs");
+        assert_eq!(explanation.rules.len(), 4);
+        let matched: Vec<_> = explanation
+            .matching_rules()
+            .map(|rule| rule.rule.line_number())
+            .collect();
+        assert_eq!(matched, [1, 3, 4]);
+        assert_eq!(
+            explanation.winning_rule().map(|rule| rule.rule),
+            resolver.rule_set().last_match("src/lib.rs")
+        );
+        assert!(!explanation.rules[1].explanation.matched);
+
+        let file = parse_codeowners("/docs/ @docs\n").ast;
+        let explanation = OwnersResolver::from_file(&file).explain_path("README.md");
+        assert_eq!(explanation.winner, None);
+        assert!(explanation.winning_rule().is_none());
+    }
+
+    #[test]
+    fn agrees_with_owning_rule() {
+        let file = parse_codeowners("* @a\ndocs/ @b\n/docs/api/*.md @c\n**/test/ @d\n").ast;
+        let resolver = OwnersResolver::from_file(&file);
+
+        for path in [
+            "x.md",
+            "docs/x.md",
+            "docs/api/x.md",
+            "docs/api/v1/x.md",
+            "a/test/t.rs",
+        ] {
+            assert_eq!(resolver.rule_for(path), owning_rule(&file, path), "{path}");
+        }
+    }
+}
@@ -0,0 +1,322 @@
+//! Compiled rule sets for CODEOWNERS files.
+
+use super::{OwningRule, PatternSyntax, QuestionMark, compile_glob, normalize_repo_path};
+use crate::parse::{CodeownersFile, Line, LineKind, Owner, Pattern, Span};
+use globset::{Candidate, GlobSet, GlobSetBuilder};
+
+/// A rule of a CODEOWNERS file, as returned by [`RuleSet`] matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule<'a> {
+    /// The rule's line in the CODEOWNERS file.
+    pub line: &'a Line,
+    /// The rule's pattern.
+    pub pattern: &'a Pattern,
+    /// The rule's owners, which may be empty.
+    pub owners: &'a [Owner],
+}
+
+impl Rule<'_> {
+    /// Returns the 1-based line number of the rule.
+    pub fn line_number(&self) -> usize {
+        self.line.span.line
+    }
+
+    /// Returns the location of the whole rule line.
+    pub fn span(&self) -> Span {
+        self.line.span
+    }
+
+    /// Returns true if the rule assigns at least one owner.
+    pub fn is_owned(&self) -> bool {
+        !self.owners.is_empty()
+    }
+}
+
+impl<'a> From<Rule<'a>> for OwningRule<'a> {
+    fn from(rule: Rule<'a>) -> Self {
+        OwningRule {
+            line: rule.line,
+            pattern: rule.pattern,
+            owners: rule.owners,
+        }
+    }
+}
+
+/// How many files a rule matches and owns, as returned by [`RuleSet::coverage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleCoverage<'a> {
+    /// The rule.
+    pub rule: Rule<'a>,
+    /// Number of files the rule's pattern matches.
+    pub matched_files: usize,
+    /// Number of files the rule owns, i.e. where no later rule also matches.
+    pub effective_files: usize,
+}
+
+/// The rules of a CODEOWNERS file, compiled for matching.
+///
+/// Like [`PatternSet`](super::PatternSet), all patterns are matched in one
+/// pass, but matches carry the full rule: its line, pattern and owners.
+/// Rules with invalid patterns are left out, and so are `!pattern`
+/// exclusions under [`PatternSyntax::GitLab`], which are compiled separately.
+///
+/// # Example
+///
+/// ```rust
+/// use codeowners_validator_core::matching::RuleSet;
+/// use codeowners_validator_core::parse::parse_codeowners;
+///
+/// let file = parse_codeowners("* @default\n/src/ @org/core\n").ast;
+/// let rules = RuleSet::from_file(&file);
+///
+/// let rule = rules.last_match("src/main.rs").unwrap();
+/// assert_eq!(rule.line_number(), 2);
+/// assert_eq!(rule.owners[0].as_str(), "@org/core");
+/// ```
+#[derive(Debug, Clone)]
+pub struct RuleSet<'a> {
+    /// Compiled patterns, indexed like `rules`.
+    glob_set: GlobSet,
+    /// Compiled exclusion patterns.
+    exclusions: GlobSet,
+    /// Rules in file order.
+    rules: Vec<Rule<'a>>,
+    /// How `?` in patterns is interpreted.
+    question_mark: QuestionMark,
+    /// The accepted pattern syntax.
+    pattern_syntax: PatternSyntax,
+}
+
+impl<'a> RuleSet<'a> {
+    /// Compiles the rules of a CODEOWNERS file.
+    pub fn from_file(file: &'a CodeownersFile) -> Self {
+        Self::with_question_mark(file, QuestionMark::default())
+    }
+
+    /// Compiles the rules of a CODEOWNERS file, interpreting `?` as configured.
+    pub fn with_question_mark(file: &'a CodeownersFile, question_mark: QuestionMark) -> Self {
+        Self::with_syntax(file, question_mark, PatternSyntax::default())
+    }
+
+    /// Compiles the rules of a CODEOWNERS file, interpreting `?` and
+    /// `!pattern` exclusions as configured.
+    pub fn with_syntax(
+        file: &'a CodeownersFile,
+        question_mark: QuestionMark,
+        pattern_syntax: PatternSyntax,
+    ) -> Self {
+        let mut builder = GlobSetBuilder::new();
+        let mut exclusions = GlobSetBuilder::new();
+        let mut rules = Vec::new();
+
+        for line in &file.lines {
+            let LineKind::Rule { pattern, owners } = &line.kind else {
+                continue;
+            };
+            let (text, excluded) = pattern_syntax.split_negation(&pattern.text);
+            let Some((glob, _, _)) = compile_glob(text, question_mark) else {
+                continue;
+            };
+            if excluded {
+                exclusions.add(glob);
+            } else {
+                builder.add(glob);
+                rules.push(Rule {
+                    line,
+                    pattern,
+                    owners,
+                });
+            }
+        }
+
+        // Every glob compiled on its own, so the sets build too
+        let glob_set = builder.build().unwrap_or_else(|_| GlobSet::empty());
+        let exclusions = exclusions.build().unwrap_or_else(|_| GlobSet::empty());
+
+        Self {
+            glob_set,
+            exclusions,
+            rules,
+            question_mark,
+            pattern_syntax,
+        }
+    }
+
+    /// Returns every rule whose pattern matches the path, in file order.
+    pub fn matches(&self, path: &str) -> Vec<Rule<'a>> {
+        let mut matches = Vec::new();
+        self.matches_into(path, &mut matches);
+        matches.into_iter().map(|index| self.rules[index]).collect()
+    }
+
+    /// Returns the last rule whose pattern matches the path.
+    ///
+    /// In CODEOWNERS, later rules take precedence. Returns `None` for paths
+    /// matching an exclusion.
+    pub fn last_match(&self, path: &str) -> Option<Rule<'a>> {
+        self.last_match_into(path, &mut Vec::new())
+    }
+
+    /// Returns true if a rule matches the path and no exclusion does, i.e.
+    /// [`last_match`](Self::last_match) would find a rule.
+    ///
+    /// Only answers whether the path is covered, which is cheaper than
+    /// finding the rule.
+    pub fn is_match(&self, path: &str) -> bool {
+        let path = normalize_repo_path(path);
+        self.glob_set.is_match(&*path)
+            && (self.exclusions.is_empty() || !self.exclusions.is_match(&*path))
+    }
+
+    /// Counts the files each rule matches and owns, in file order.
+    ///
+    /// A rule whose effective count is lower than its matched count is
+    /// partially overridden by later rules.
+    pub fn coverage<'p>(&self, paths: impl IntoIterator<Item = &'p str>) -> Vec<RuleCoverage<'a>> {
+        let mut coverage: Vec<_> = self
+            .rules
+            .iter()
+            .map(|&rule| RuleCoverage {
+                rule,
+                matched_files: 0,
+                effective_files: 0,
+            })
+            .collect();
+
+        let mut matches = Vec::new();
+        for path in paths {
+            self.matches_into(path, &mut matches);
+            for &index in &matches {
+                coverage[index].matched_files += 1;
+            }
+            if let Some(&last) = matches.last()
+                && !self.is_excluded(path)
+            {
+                coverage[last].effective_files += 1;
+            }
+        }
+
+        coverage
+    }
+
+    /// Returns the compiled rules, in file order.
+    pub fn rules(&self) -> &[Rule<'a>] {
+        &self.rules
+    }
+
+    /// Returns how `?` in patterns is interpreted.
+    pub fn question_mark(&self) -> QuestionMark {
+        self.question_mark
+    }
+
+    /// Returns the accepted pattern syntax.
+    pub fn pattern_syntax(&self) -> PatternSyntax {
+        self.pattern_syntax
+    }
+
+    /// Returns true if the path matches a `!pattern` exclusion.
+    pub fn is_excluded(&self, path: &str) -> bool {
+        !self.exclusions.is_empty() && self.exclusions.is_match(&*normalize_repo_path(path))
+    }
+
+    /// Returns the number of compiled rules.
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Returns true if no rules were compiled.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Finds the last matching rule, using `matches` as scratch space.
+    pub(super) fn last_match_into(&self, path: &str, matches: &mut Vec<usize>) -> Option<Rule<'a>> {
+        self.matches_into(path, matches);
+        if self.is_excluded(path) {
+            return None;
+        }
+        // Match indices are sorted, and a higher index is a later rule
+        Some(self.rules[*matches.last()?])
+    }
+
+    /// Collects the indices of matching rules into `matches`.
+    fn matches_into(&self, path: &str, matches: &mut Vec<usize>) {
+        let path = normalize_repo_path(path);
+        self.glob_set
+            .matches_candidate_into(&Candidate::new(&*path), matches);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{Dialect, ParserConfig, parse_codeowners, parse_codeowners_with_config};
+
+    #[test]
+    fn matches_carry_rules() {
+        let file = parse_codeowners("* @default\n\n# Rust\n*.rs @rust\n/src/ @core\n").ast;
+        let rules = RuleSet::from_file(&file);
+        assert_eq!(rules.len(), 3);
+
+        let matches = rules.matches("/src/lib.rs");
+        let lines: Vec<_> = matches.iter().map(Rule::line_number).collect();
+        assert_eq!(lines, [1, 4, 5]);
+        assert_eq!(matches[1].pattern.text, "*.rs");
+        assert_eq!(matches[1].span(), file.lines[3].span);
+
+        let last = rules.last_match("src/lib.rs").unwrap();
+        assert_eq!(last.owners[0].as_str(), "@core");
+        assert_eq!(rules.last_match("README.md").unwrap().line_number(), 1);
+    }
+
+    #[test]
+    fn coverage_counts_matched_and_effective_files() {
+        let file = parse_codeowners("* @default\n*.rs @rust\n/docs/ @docs\n").ast;
+        let rules = RuleSet::from_file(&file);
+
+        let coverage = rules.coverage(["src/lib.rs", "src/main.rs", "README.md"]);
+        let counts: Vec<_> = coverage
+            .iter()
+            .map(|c| (c.rule.line_number(), c.matched_files, c.effective_files))
+            .collect();
+        assert_eq!(counts, [(1, 3, 1), (2, 2, 2), (3, 0, 0)]);
+    }
+
+    #[test]
+    fn gitlab_exclusions() {
+        let config = ParserConfig::new().with_dialect(Dialect::GitLab);
+        let file = parse_codeowners_with_config("*.md @docs\n!/CHANGELOG.md\n", &config).ast;
+
+        let rules = RuleSet::with_syntax(&file, QuestionMark::Wildcard, PatternSyntax::GitLab);
+        assert_eq!(rules.len(), 1);
+        assert!(rules.is_excluded("CHANGELOG.md"));
+        assert_eq!(rules.last_match("CHANGELOG.md"), None);
+        assert!(!rules.is_match("CHANGELOG.md"));
+        assert!(rules.is_match("docs/CHANGELOG.md"));
+        assert_eq!(
+            rules.last_match("docs/CHANGELOG.md").unwrap().line_number(),
+            1
+        );
+
+        let coverage = rules.coverage(["README.md", "CHANGELOG.md"]);
+        assert_eq!(
+            (coverage[0].matched_files, coverage[0].effective_files),
+            (2, 1)
+        );
+
+        // Without GitLab syntax, the exclusion is an ordinary (literal) rule
+        let rules = RuleSet::from_file(&file);
+        assert_eq!(rules.len(), 2);
+        assert!(!rules.is_excluded("CHANGELOG.md"));
+    }
+
+    #[test]
+    fn empty_rule_set() {
+        let file = parse_codeowners("# only a comment\n").ast;
+        let rules = RuleSet::from_file(&file);
+        assert!(rules.is_empty());
+        assert!(rules.matches("a").is_empty());
+        assert!(!rules.is_match("a"));
+        assert_eq!(rules.last_match("a"), None);
+    }
+}
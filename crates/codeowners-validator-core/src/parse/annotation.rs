@@ -0,0 +1,500 @@
+//! Annotations for CODEOWNERS rules.
+//!
+//! Ownership entries can carry a date by which they must be re-certified,
+//! and the escalation policy incident tooling should page for their paths:
+//!
+//! ```text
+//! # review-by: 2025-12-31
+//! # escalation: pagerduty:payments
+//! /payments/ @org/payments
+//! /search/ @org/search # review-by: 2026-06-30
+//! ```
+//!
+//! Rules can also carry directives for review-assignment bots, such as
+//! `# codeowners: no-auto-request`, which the parser stores on the rule's
+//! [`Line`] so they are part of the exported syntax tree.
+//!
+//! An annotation applies to the rule directly below its comment block, or to
+//! the rule it trails as an inline comment (kept when the parser is
+//! configured with `attach_inline_comments`).
+
+use super::ast::{CodeownersFile, Line, LineKind};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Key of review annotation comments.
+const REVIEW_BY_KEY: &str = "review-by";
+
+/// Key of escalation annotation comments.
+const ESCALATION_KEY: &str = "escalation";
+
+/// Key of directive annotation comments, e.g. `# codeowners: no-auto-request`.
+pub const DIRECTIVE_KEY: &str = "codeowners";
+
+/// Directive asking review-assignment bots not to request reviews
+/// automatically.
+pub const NO_AUTO_REQUEST: &str = "no-auto-request";
+
+/// Returns the value of a `key: value` annotation in comment content, or
+/// `None` if the content is another comment or the value is empty.
+fn annotation_value<'a>(content: &'a str, key: &str) -> Option<&'a str> {
+    let (found, value) = content.trim().split_once(':')?;
+    let value = value.trim();
+    (found.trim() == key && !value.is_empty()).then_some(value)
+}
+
+/// A calendar date, as used by review annotations.
+///
+/// Dates are written as `YYYY-MM-DD` and compared as UTC calendar days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ReviewDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl ReviewDate {
+    /// Creates a date, returning `None` if it does not exist.
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap => 29,
+            2 => 28,
+            _ => return None,
+        };
+        (1..=days_in_month)
+            .contains(&day)
+            .then_some(Self { year, month, day })
+    }
+
+    /// Parses a `YYYY-MM-DD` date.
+    pub fn parse(value: &str) -> Option<Self> {
+        let mut parts = value.trim().splitn(3, '-');
+        let year = parts.next().filter(|part| part.len() == 4)?;
+        let month = parts.next().filter(|part| part.len() == 2)?;
+        let day = parts.next().filter(|part| part.len() == 2)?;
+        Self::new(year.parse().ok()?, month.parse().ok()?, day.parse().ok()?)
+    }
+
+    /// Returns the current UTC date.
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        Self::from_days((seconds / 86_400) as i64)
+    }
+
+    /// Creates the date a number of days after 1970-01-01.
+    pub fn from_days(days: i64) -> Self {
+        // Civil-from-days conversion on a calendar with years starting in March
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        } as u32;
+        let year = (year_of_era + era * 400 + i64::from(month <= 2)) as i32;
+
+        Self { year, month, day }
+    }
+
+    /// Returns the number of days since 1970-01-01.
+    pub fn days(&self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let month = i64::from(self.month);
+        let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5
+            + i64::from(self.day)
+            - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Returns the number of days from this date until `other`, negative if
+    /// `other` is earlier.
+    pub fn days_until(&self, other: ReviewDate) -> i64 {
+        other.days() - self.days()
+    }
+
+    /// Returns the year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month (1-12).
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month (1-31).
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+}
+
+impl Display for ReviewDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// A `review-by` annotation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewBy {
+    /// The annotation value, as written.
+    pub value: String,
+    /// The parsed date, or `None` if the value is not a valid date.
+    pub date: Option<ReviewDate>,
+}
+
+impl ReviewBy {
+    /// Parses comment content (without the leading `#`) as a review annotation.
+    pub fn parse(content: &str) -> Option<Self> {
+        let value = annotation_value(content, REVIEW_BY_KEY)?;
+        Some(Self {
+            value: value.to_string(),
+            date: ReviewDate::parse(value),
+        })
+    }
+}
+
+/// The escalation policy an `escalation` annotation points at, written as
+/// `provider:policy`, e.g. `pagerduty:payments`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct EscalationTarget {
+    /// The paging or incident tool, e.g. `pagerduty` or `opsgenie`.
+    pub provider: String,
+    /// The policy, schedule or service within the provider.
+    pub policy: String,
+}
+
+impl EscalationTarget {
+    /// Parses a `provider:policy` value, returning why it is invalid if it
+    /// is not one.
+    ///
+    /// Providers may contain ASCII letters, digits, `-` and `_`; policies
+    /// may contain anything but whitespace.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        let (provider, policy) = value
+            .trim()
+            .split_once(':')
+            .ok_or("expected 'provider:policy'")?;
+        if provider.is_empty() || policy.is_empty() {
+            return Err("expected 'provider:policy'");
+        }
+        if !provider
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err("the provider may only contain letters, digits, '-' and '_'");
+        }
+        if policy.chars().any(char::is_whitespace) {
+            return Err("the policy must not contain whitespace");
+        }
+
+        Ok(Self {
+            provider: provider.to_string(),
+            policy: policy.to_string(),
+        })
+    }
+}
+
+impl Display for EscalationTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.provider, self.policy)
+    }
+}
+
+/// An `escalation` annotation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Escalation {
+    /// The annotation value, as written.
+    pub value: String,
+    /// The parsed target, or `None` if the value is not `provider:policy`.
+    pub target: Option<EscalationTarget>,
+}
+
+impl Escalation {
+    /// Parses comment content (without the leading `#`) as an escalation
+    /// annotation.
+    pub fn parse(content: &str) -> Option<Self> {
+        let value = annotation_value(content, ESCALATION_KEY)?;
+        Some(Self {
+            value: value.to_string(),
+            target: EscalationTarget::parse(value).ok(),
+        })
+    }
+}
+
+/// A `codeowners: no-auto-request` annotation.
+///
+/// Review-assignment bots should not automatically request reviews from
+/// the listed owners of the rule, e.g.
+/// `# codeowners: no-auto-request @org/everyone`, or from any of its owners
+/// if none are listed. The owners stay code owners for branch protection.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NoAutoRequest {
+    /// The owners not to request, as written; empty for all of the rule's
+    /// owners.
+    pub owners: Vec<String>,
+}
+
+impl NoAutoRequest {
+    /// Parses comment content (without the leading `#`) as a
+    /// `no-auto-request` annotation.
+    pub fn parse(content: &str) -> Option<Self> {
+        let mut words = annotation_value(content, DIRECTIVE_KEY)?.split_whitespace();
+        (words.next()? == NO_AUTO_REQUEST).then(|| Self {
+            owners: words.map(str::to_string).collect(),
+        })
+    }
+
+    /// Returns true if the annotation applies to `owner`, compared
+    /// case-insensitively.
+    pub fn excludes(&self, owner: &str) -> bool {
+        self.owners.is_empty()
+            || self
+                .owners
+                .iter()
+                .any(|excluded| excluded.eq_ignore_ascii_case(owner))
+    }
+}
+
+impl Line {
+    /// Returns the review annotation of a comment line, or the inline review
+    /// annotation of a rule line.
+    pub fn review_by(&self) -> Option<ReviewBy> {
+        match &self.kind {
+            LineKind::Comment { content } => ReviewBy::parse(content),
+            LineKind::Rule { .. } => self.inline_comment.as_deref().and_then(ReviewBy::parse),
+            _ => None,
+        }
+    }
+
+    /// Returns the escalation annotation of a comment line, or the inline
+    /// escalation annotation of a rule line.
+    pub fn escalation(&self) -> Option<Escalation> {
+        match &self.kind {
+            LineKind::Comment { content } => Escalation::parse(content),
+            LineKind::Rule { .. } => self.inline_comment.as_deref().and_then(Escalation::parse),
+            _ => None,
+        }
+    }
+
+    /// Returns the `no-auto-request` annotation written on a comment line,
+    /// or inline on a rule line.
+    ///
+    /// Unlike the stored `no_auto_request` field, this only looks at the
+    /// line itself.
+    pub fn no_auto_request_annotation(&self) -> Option<NoAutoRequest> {
+        match &self.kind {
+            LineKind::Comment { content } => NoAutoRequest::parse(content),
+            LineKind::Rule { .. } => self
+                .inline_comment
+                .as_deref()
+                .and_then(NoAutoRequest::parse),
+            _ => None,
+        }
+    }
+}
+
+impl CodeownersFile {
+    /// Returns the review annotation attached to the rule at `index`.
+    ///
+    /// An inline annotation takes precedence over one in the comment block
+    /// directly above the rule.
+    pub fn review_by_of(&self, index: usize) -> Option<ReviewBy> {
+        self.annotation_of(index, Line::review_by)
+    }
+
+    /// Returns the escalation annotation attached to the rule at `index`.
+    ///
+    /// An inline annotation takes precedence over one in the comment block
+    /// directly above the rule.
+    pub fn escalation_of(&self, index: usize) -> Option<Escalation> {
+        self.annotation_of(index, Line::escalation)
+    }
+
+    /// Returns the escalation annotations of all annotated rules, keyed by
+    /// line number.
+    pub fn escalations(&self) -> BTreeMap<usize, Escalation> {
+        self.lines
+            .iter()
+            .enumerate()
+            .filter_map(|(index, line)| Some((line.span.line, self.escalation_of(index)?)))
+            .collect()
+    }
+
+    /// Returns the `no-auto-request` annotation attached to the rule at
+    /// `index`.
+    ///
+    /// An inline annotation takes precedence over one in the comment block
+    /// directly above the rule.
+    pub fn no_auto_request_of(&self, index: usize) -> Option<NoAutoRequest> {
+        self.annotation_of(index, Line::no_auto_request_annotation)
+    }
+
+    /// Stores the `no-auto-request` annotation of every rule on its line.
+    pub(crate) fn attach_no_auto_requests(&mut self) {
+        for index in 0..self.lines.len() {
+            self.lines[index].no_auto_request = self.no_auto_request_of(index);
+        }
+    }
+
+    /// Returns the annotation `annotation` finds on the rule at `index`, or
+    /// in the comment block directly above it.
+    fn annotation_of<T>(&self, index: usize, annotation: fn(&Line) -> Option<T>) -> Option<T> {
+        let line = self.lines.get(index)?;
+        if !line.is_rule() {
+            return None;
+        }
+
+        annotation(line).or_else(|| {
+            self.lines[..index]
+                .iter()
+                .rev()
+                .take_while(|line| line.is_comment())
+                .find_map(annotation)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{ParserConfig, parse_codeowners, parse_codeowners_with_config};
+
+    #[test]
+    fn review_dates() {
+        let date = ReviewDate::parse("2024-02-29").unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (2024, 2, 29));
+        assert_eq!(date.to_string(), "2024-02-29");
+        assert_eq!(ReviewDate::parse("2023-02-29"), None);
+        assert_eq!(ReviewDate::parse("2025-13-01"), None);
+        assert_eq!(ReviewDate::parse("2025-1-01"), None);
+        assert_eq!(ReviewDate::parse("soon"), None);
+
+        assert_eq!(ReviewDate::new(1970, 1, 1).unwrap().days(), 0);
+        for days in [-1, 0, 59, 365, 10_957, 20_000, 2_932_896] {
+            assert_eq!(ReviewDate::from_days(days).days(), days);
+        }
+        let end = ReviewDate::new(2025, 12, 31).unwrap();
+        assert_eq!(end.days_until(ReviewDate::new(2026, 3, 1).unwrap()), 60);
+        assert!(end < ReviewDate::new(2026, 1, 1).unwrap());
+    }
+
+    #[test]
+    fn review_annotations_attach_to_rules() {
+        let file = parse_codeowners(
+            "# review-by: 2025-12-31\n# Payments\n/payments/ @org/payments\n\n# review-by: later\n/search/ @org/search\n/docs/ @docs\n",
+        )
+        .ast;
+
+        assert_eq!(
+            file.review_by_of(2).and_then(|review| review.date),
+            ReviewDate::new(2025, 12, 31)
+        );
+        let invalid = file.review_by_of(5).unwrap();
+        assert_eq!(invalid.value, "later");
+        assert_eq!(invalid.date, None);
+        assert_eq!(file.review_by_of(6), None);
+        assert_eq!(file.review_by_of(0), None);
+    }
+
+    #[test]
+    fn inline_review_annotation() {
+        let config = ParserConfig::new().with_inline_comments(true);
+        let file = parse_codeowners_with_config(
+            "# review-by: 2025-01-01\n/a/ @a # review-by: 2026-01-01\n",
+            &config,
+        )
+        .ast;
+        assert_eq!(
+            file.review_by_of(1).and_then(|review| review.date),
+            ReviewDate::new(2026, 1, 1)
+        );
+    }
+
+    #[test]
+    fn escalation_targets() {
+        let target = EscalationTarget::parse("pagerduty:payments-primary").unwrap();
+        assert_eq!(target.provider, "pagerduty");
+        assert_eq!(target.policy, "payments-primary");
+        assert_eq!(target.to_string(), "pagerduty:payments-primary");
+        assert_eq!(
+            EscalationTarget::parse("opsgenie:team/db").unwrap().policy,
+            "team/db"
+        );
+        assert!(EscalationTarget::parse("payments").is_err());
+        assert!(EscalationTarget::parse(":payments").is_err());
+        assert!(EscalationTarget::parse("pager duty:payments").is_err());
+        assert!(EscalationTarget::parse("pagerduty:on call").is_err());
+    }
+
+    #[test]
+    fn escalation_annotations_attach_to_rules() {
+        let config = ParserConfig::new().with_inline_comments(true);
+        let file = parse_codeowners_with_config(
+            "# escalation: pagerduty:payments
+# review-by: 2025-12-31
+/payments/ @org/payments
+/search/ @org/search # escalation: search
+/docs/ @docs
+",
+            &config,
+        )
+        .ast;
+
+        let payments = file.escalation_of(2).unwrap();
+        assert_eq!(payments.value, "pagerduty:payments");
+        assert_eq!(payments.target.unwrap().policy, "payments");
+        assert_eq!(file.escalation_of(3).unwrap().target, None);
+        assert_eq!(file.escalation_of(4), None);
+        assert_eq!(
+            file.escalations().keys().copied().collect::<Vec<_>>(),
+            [3, 4]
+        );
+    }
+
+    #[test]
+    fn no_auto_request_annotations_are_stored_on_rules() {
+        let config = ParserConfig::new().with_inline_comments(true);
+        let file = parse_codeowners_with_config(
+            "# codeowners: no-auto-request
+/vendor/ @org/everyone
+/docs/ @docs @org/everyone # codeowners: no-auto-request @ORG/everyone
+# codeowners: no-auto-requests
+/src/ @core
+",
+            &config,
+        )
+        .ast;
+
+        let vendor = file.lines[1].no_auto_request.as_ref().unwrap();
+        assert!(vendor.owners.is_empty());
+        assert!(vendor.excludes("@org/everyone"));
+        let docs = file.lines[2].no_auto_request.as_ref().unwrap();
+        assert!(docs.excludes("@org/everyone"));
+        assert!(!docs.excludes("@docs"));
+        assert_eq!(file.lines[4].no_auto_request, None);
+        assert_eq!(file.lines[0].no_auto_request, None);
+
+        let json = serde_json::to_value(&file).unwrap();
+        assert_eq!(
+            json["lines"][2]["no_auto_request"]["owners"][0],
+            "@ORG/everyone"
+        );
+    }
+}
@@ -3,14 +3,16 @@
 //! This module defines the abstract syntax tree nodes that represent
 //! parsed CODEOWNERS file content.
 
+use super::annotation::NoAutoRequest;
 use super::span::Span;
+use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::fmt::{self, Display};
 
 /// Represents a pattern in a CODEOWNERS rule.
 ///
 /// Patterns follow a subset of gitignore syntax for matching file paths.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Pattern {
     /// The raw pattern text (e.g., "*.rs", "/src/**", "docs/").
     pub text: String,
@@ -26,6 +28,14 @@ impl Pattern {
             span,
         }
     }
+
+    /// Returns true if the pattern starts with `!`.
+    ///
+    /// Such patterns exclude paths under [`PatternSyntax::GitLab`](crate::matching::PatternSyntax::GitLab),
+    /// and are unsupported otherwise.
+    pub fn is_negation(&self) -> bool {
+        self.text.starts_with('!')
+    }
 }
 
 impl Display for Pattern {
@@ -34,10 +44,45 @@ impl Display for Pattern {
     }
 }
 
+/// The required approval count of a GitLab section, the `[n]` after its name.
+///
+/// The count is kept as written so the checks can report values that are
+/// not a positive integer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SectionApprovals {
+    /// The text between the brackets.
+    pub text: String,
+    /// Location of the text.
+    pub span: Span,
+}
+
+impl SectionApprovals {
+    /// Creates a new approval count with the given text and span.
+    pub fn new(text: impl Into<String>, span: Span) -> Self {
+        Self {
+            text: text.into(),
+            span,
+        }
+    }
+
+    /// Returns the count if it is a positive integer.
+    pub fn count(&self) -> Option<u32> {
+        self.text.parse().ok().filter(|count| *count > 0)
+    }
+}
+
+impl Display for SectionApprovals {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
 /// Represents an owner in a CODEOWNERS rule.
 ///
 /// Owners can be GitHub users, teams, or email addresses.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
 pub enum Owner {
     /// A GitHub user (e.g., "@username").
     User {
@@ -62,6 +107,18 @@ pub enum Owner {
         /// Location in the source file.
         span: Span,
     },
+    /// A token in owner position that is not a user, team, or email.
+    ///
+    /// The rest of the rule is still parsed, so checks that only look at
+    /// patterns keep working; the syntax check reports the bad token.
+    Invalid {
+        /// The raw token text.
+        raw: String,
+        /// Why the token is not a valid owner.
+        reason: String,
+        /// Location in the source file.
+        span: Span,
+    },
 }
 
 impl Owner {
@@ -90,25 +147,53 @@ impl Owner {
         }
     }
 
+    /// Creates a new invalid owner.
+    pub fn invalid(raw: impl Into<String>, reason: impl Into<String>, span: Span) -> Self {
+        Self::Invalid {
+            raw: raw.into(),
+            reason: reason.into(),
+            span,
+        }
+    }
+
+    /// Returns a case-insensitive key identifying this owner.
+    ///
+    /// GitHub user, organization, and team names are case-insensitive, and
+    /// email addresses are treated the same way, so `@Org/Team` and
+    /// `@org/team` produce the same key.
+    pub fn normalized(&self) -> String {
+        match self {
+            Owner::Invalid { raw, .. } => raw.clone(),
+            _ => self.as_str().to_lowercase(),
+        }
+    }
+
+    /// Returns true if this owner could not be classified.
+    pub fn is_invalid(&self) -> bool {
+        matches!(self, Owner::Invalid { .. })
+    }
+
     /// Returns the span of this owner.
     pub fn span(&self) -> &Span {
         match self {
             Owner::User { span, .. } => span,
             Owner::Team { span, .. } => span,
             Owner::Email { span, .. } => span,
+            Owner::Invalid { span, .. } => span,
         }
     }
 
     /// Returns the raw text representation of this owner.
     ///
     /// Returns a `Cow<str>` to avoid allocations when possible:
-    /// - For emails, returns a borrowed reference
+    /// - For emails and invalid owners, returns a borrowed reference
     /// - For users and teams, returns an owned formatted string
     pub fn as_str(&self) -> Cow<'_, str> {
         match self {
             Owner::User { name, .. } => Cow::Owned(format!("@{}", name)),
             Owner::Team { org, team, .. } => Cow::Owned(format!("@{}/{}", org, team)),
             Owner::Email { email, .. } => Cow::Borrowed(email),
+            Owner::Invalid { raw, .. } => Cow::Borrowed(raw),
         }
     }
 }
@@ -119,12 +204,15 @@ impl Display for Owner {
             Owner::User { name, .. } => write!(f, "@{}", name),
             Owner::Team { org, team, .. } => write!(f, "@{}/{}", org, team),
             Owner::Email { email, .. } => f.write_str(email),
+            Owner::Invalid { raw, .. } => f.write_str(raw),
         }
     }
 }
 
 /// Represents the kind of line in a CODEOWNERS file.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
 pub enum LineKind {
     /// A blank line (may contain only whitespace).
     Blank,
@@ -140,6 +228,22 @@ pub enum LineKind {
         /// The list of owners for files matching the pattern.
         owners: Vec<Owner>,
     },
+    /// A GitLab section header, e.g. `^[Documentation][2] @docs-team`.
+    ///
+    /// Only parsed with [`Dialect::GitLab`](super::Dialect::GitLab).
+    Section {
+        /// The section name, without brackets.
+        name: String,
+        /// Location of the name.
+        name_span: Span,
+        /// True for optional sections (`^[...]`), whose approval is not
+        /// required.
+        optional: bool,
+        /// Number of approvals required (`[...][n]`), if given.
+        approvals: Option<SectionApprovals>,
+        /// Default owners of the section's rules, possibly empty.
+        owners: Vec<Owner>,
+    },
     /// An invalid line that couldn't be parsed.
     Invalid {
         /// The raw line content.
@@ -150,18 +254,47 @@ pub enum LineKind {
 }
 
 /// Represents a single line in a CODEOWNERS file.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Lines gain fields as the parser learns more about them, so they are
+/// created with the constructors below rather than a struct literal.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct Line {
     /// The kind/content of this line.
     pub kind: LineKind,
     /// Location of the entire line in the source file.
     pub span: Span,
+    /// Trailing comment content (without the leading '#') on a rule line,
+    /// when the parser is configured to keep it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inline_comment: Option<String>,
+    /// The `codeowners: no-auto-request` annotation of a rule line, filled
+    /// in by the parser.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_auto_request: Option<NoAutoRequest>,
 }
 
 impl Line {
     /// Creates a new line with the given kind and span.
     pub fn new(kind: LineKind, span: Span) -> Self {
-        Self { kind, span }
+        Self {
+            kind,
+            span,
+            inline_comment: None,
+            no_auto_request: None,
+        }
+    }
+
+    /// Attaches a trailing comment to this line.
+    pub fn with_inline_comment(mut self, comment: impl Into<String>) -> Self {
+        self.inline_comment = Some(comment.into());
+        self
+    }
+
+    /// Attaches a `codeowners: no-auto-request` annotation to this line.
+    pub fn with_no_auto_request(mut self, annotation: NoAutoRequest) -> Self {
+        self.no_auto_request = Some(annotation);
+        self
     }
 
     /// Creates a blank line.
@@ -210,6 +343,11 @@ impl Line {
         matches!(self.kind, LineKind::Blank)
     }
 
+    /// Returns true if this is a section header.
+    pub fn is_section(&self) -> bool {
+        matches!(self.kind, LineKind::Section { .. })
+    }
+
     /// Returns true if this is an invalid line.
     pub fn is_invalid(&self) -> bool {
         matches!(self.kind, LineKind::Invalid { .. })
@@ -226,6 +364,31 @@ impl Display for Line {
                 for owner in owners {
                     write!(f, " {}", owner)?;
                 }
+                if let Some(comment) = &self.inline_comment {
+                    write!(f, " #{}", comment)?;
+                }
+                Ok(())
+            }
+            LineKind::Section {
+                name,
+                optional,
+                approvals,
+                owners,
+                ..
+            } => {
+                if *optional {
+                    f.write_str("^")?;
+                }
+                write!(f, "[{}]", name)?;
+                if let Some(approvals) = approvals {
+                    write!(f, "[{}]", approvals)?;
+                }
+                for owner in owners {
+                    write!(f, " {}", owner)?;
+                }
+                if let Some(comment) = &self.inline_comment {
+                    write!(f, " #{}", comment)?;
+                }
                 Ok(())
             }
             LineKind::Invalid { raw, .. } => f.write_str(raw),
@@ -234,7 +397,7 @@ impl Display for Line {
 }
 
 /// The complete AST for a CODEOWNERS file.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CodeownersFile {
     /// All lines in the file, in order.
     pub lines: Vec<Line>,
@@ -514,4 +677,27 @@ mod tests {
         let file = CodeownersFile::new(vec![]);
         assert_eq!(file.to_string(), "");
     }
+
+    #[test]
+    fn codeowners_file_serde_round_trip() {
+        let file = crate::parse::parse_codeowners(
+            "# Owners
+*.rs @alice @org/team user@example.com
+",
+        )
+        .ast;
+
+        let json = serde_json::to_value(&file).unwrap();
+        assert_eq!(json["lines"][0]["kind"]["type"], "comment");
+        let rule = &json["lines"][1]["kind"];
+        assert_eq!(rule["type"], "rule");
+        assert_eq!(rule["pattern"]["text"], "*.rs");
+        assert_eq!(rule["owners"][1]["type"], "team");
+        assert_eq!(rule["owners"][1]["org"], "org");
+        assert_eq!(rule["owners"][2]["email"], "user@example.com");
+        assert!(json["lines"][1].get("inline_comment").is_none());
+
+        let parsed: CodeownersFile = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, file);
+    }
 }
@@ -0,0 +1,463 @@
+//! Programmatic construction and editing of CODEOWNERS ASTs.
+//!
+//! Parsed lines carry spans into the source text, which makes building or
+//! changing an AST by hand error-prone. The builders here lay out every span
+//! to match the [`Display`](std::fmt::Display) output, so a file built in
+//! code renders with `to_string()` and parses back to the same AST.
+//!
+//! # Example
+//!
+//! ```rust
+//! use codeowners_validator_core::parse::{CodeownersFile, Line, parse_codeowners};
+//!
+//! let mut file = CodeownersFile::builder()
+//!     .comment("Backend")
+//!     .rule("/api/", ["@org/backend"])
+//!     .line(Line::rule_builder("*.rs").owner("@rustacean").build())
+//!     .build();
+//!
+//! file.lines[1].add_owner("@alice");
+//! file.renumber();
+//!
+//! let text = file.to_string();
+//! assert_eq!(text, "# Backend\n/api/ @org/backend @alice\n*.rs @rustacean\n");
+//! assert_eq!(parse_codeowners(&text).ast, file);
+//! ```
+
+use super::ast::{CodeownersFile, Line, LineKind, Pattern};
+use super::lexer::make_owner;
+use super::span::Span;
+
+/// Builds a [`CodeownersFile`] line by line.
+///
+/// Spans are assigned when [`build`](Self::build) is called, so lines can be
+/// added in any form.
+#[derive(Debug, Clone, Default)]
+pub struct CodeownersFileBuilder {
+    lines: Vec<Line>,
+}
+
+impl CodeownersFileBuilder {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a blank line.
+    pub fn blank(self) -> Self {
+        self.line(Line::blank(Span::default()))
+    }
+
+    /// Adds a comment line, rendered as `# text`.
+    pub fn comment(self, text: impl AsRef<str>) -> Self {
+        self.line(Line::comment(
+            format!(" {}", text.as_ref()),
+            Span::default(),
+        ))
+    }
+
+    /// Adds a rule line with the given pattern and owners.
+    pub fn rule<I>(self, pattern: impl Into<String>, owners: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.line(Line::rule_builder(pattern).owners(owners).build())
+    }
+
+    /// Adds a line as is; its spans are replaced when the file is built.
+    pub fn line(mut self, line: Line) -> Self {
+        self.lines.push(line);
+        self
+    }
+
+    /// Builds the file, laying out the spans of every line.
+    pub fn build(self) -> CodeownersFile {
+        let mut file = CodeownersFile::new(self.lines);
+        file.renumber();
+        file
+    }
+}
+
+/// Builds a rule [`Line`], created with [`Line::rule_builder`].
+#[derive(Debug, Clone)]
+pub struct RuleBuilder {
+    pattern: String,
+    owners: Vec<String>,
+    inline_comment: Option<String>,
+}
+
+impl RuleBuilder {
+    /// Adds an owner, e.g. `@user`, `@org/team` or an email address.
+    pub fn owner(mut self, owner: impl Into<String>) -> Self {
+        self.owners.push(owner.into());
+        self
+    }
+
+    /// Adds several owners.
+    pub fn owners<I>(mut self, owners: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.owners
+            .extend(owners.into_iter().map(|owner| owner.as_ref().to_string()));
+        self
+    }
+
+    /// Sets a trailing comment, without the leading `#`.
+    pub fn inline_comment(mut self, comment: impl Into<String>) -> Self {
+        self.inline_comment = Some(comment.into());
+        self
+    }
+
+    /// Builds the rule as the first line of a file.
+    ///
+    /// Adding it to a file with [`CodeownersFile::push_line`] or
+    /// [`CodeownersFileBuilder::line`] moves it into place.
+    pub fn build(self) -> Line {
+        let owners = self
+            .owners
+            .iter()
+            .map(|owner| make_owner(owner, Span::default()))
+            .collect();
+        let mut line = Line::rule(
+            Pattern::new(self.pattern, Span::default()),
+            owners,
+            Span::default(),
+        );
+        line.inline_comment = self.inline_comment;
+        line.layout(0, 1);
+        line
+    }
+}
+
+impl Line {
+    /// Starts building a rule line for the given pattern.
+    pub fn rule_builder(pattern: impl Into<String>) -> RuleBuilder {
+        RuleBuilder {
+            pattern: pattern.into(),
+            owners: Vec::new(),
+            inline_comment: None,
+        }
+    }
+
+    /// Appends an owner to a rule line.
+    ///
+    /// Returns false, leaving the line unchanged, if this is not a rule or
+    /// the owner is already listed (compared case-insensitively). The line's
+    /// spans are updated; call [`CodeownersFile::renumber`] to update the
+    /// lines after it.
+    pub fn add_owner(&mut self, owner: &str) -> bool {
+        let LineKind::Rule { owners, .. } = &mut self.kind else {
+            return false;
+        };
+        let owner = make_owner(owner, Span::default());
+        if owners
+            .iter()
+            .any(|existing| existing.normalized() == owner.normalized())
+        {
+            return false;
+        }
+        owners.push(owner);
+        self.layout(self.span.offset, self.span.line);
+        true
+    }
+
+    /// Removes every occurrence of an owner from a rule line, compared
+    /// case-insensitively.
+    ///
+    /// Returns false if this is not a rule or the owner is not listed. The
+    /// line's spans are updated; call [`CodeownersFile::renumber`] to update
+    /// the lines after it.
+    pub fn remove_owner(&mut self, owner: &str) -> bool {
+        let LineKind::Rule { owners, .. } = &mut self.kind else {
+            return false;
+        };
+        let key = make_owner(owner, Span::default()).normalized();
+        let before = owners.len();
+        owners.retain(|existing| existing.normalized() != key);
+        if owners.len() == before {
+            return false;
+        }
+        self.layout(self.span.offset, self.span.line);
+        true
+    }
+
+    /// Lays out the spans of this line to match its rendered text, placing
+    /// it at `offset` as line `number`.
+    fn layout(&mut self, offset: usize, number: usize) {
+        self.span = Span::new(offset, number, 1, self.to_string().len());
+        let at =
+            |column: usize, length: usize| Span::new(offset + column, number, column + 1, length);
+
+        let (mut column, owners) = match &mut self.kind {
+            LineKind::Rule { pattern, owners } => {
+                pattern.span = at(0, pattern.text.len());
+                (pattern.text.len(), owners)
+            }
+            LineKind::Section {
+                name,
+                name_span,
+                optional,
+                approvals,
+                owners,
+            } => {
+                let mut column = usize::from(*optional) + 1;
+                *name_span = at(column, name.len());
+                column += name.len() + 1;
+                if let Some(approvals) = approvals {
+                    approvals.span = at(column + 1, approvals.text.len());
+                    column += approvals.text.len() + 2;
+                }
+                (column, owners)
+            }
+            LineKind::Blank | LineKind::Comment { .. } | LineKind::Invalid { .. } => return,
+        };
+
+        for owner in owners.iter_mut() {
+            let text = owner.to_string();
+            column += 1;
+            *owner = make_owner(&text, at(column, text.len()));
+            column += text.len();
+        }
+    }
+}
+
+/// A pattern rewritten by [`CodeownersFile::move_prefix`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatternMove {
+    /// Index of the rule's line (0-based).
+    pub index: usize,
+    /// The pattern before the move, with its location in the source.
+    pub from: Pattern,
+    /// The new pattern text.
+    pub to: String,
+}
+
+/// Returns `pattern` with the directory `from` replaced by `to`, or `None`
+/// if the pattern is not anchored under `from`.
+///
+/// Both directories are given without leading or trailing slashes.
+fn moved_pattern(pattern: &str, from: &str, to: &str) -> Option<String> {
+    let rooted = pattern.starts_with('/');
+    let relative = pattern.strip_prefix('/').unwrap_or(pattern);
+    // Patterns like `docs/` match at any depth, not just below the root
+    if !rooted && !relative.trim_end_matches('/').contains('/') {
+        return None;
+    }
+
+    let rest = relative.strip_prefix(from)?;
+    if !rest.is_empty() && !rest.starts_with('/') {
+        return None;
+    }
+    let moved = format!("{}{}", to, rest);
+    let anchored = rooted || moved.trim_end_matches('/').contains('/');
+    Some(if anchored {
+        format!("{}{}", if rooted { "/" } else { "" }, moved)
+    } else {
+        format!("/{}", moved)
+    })
+}
+
+impl CodeownersFile {
+    /// Starts building a file line by line.
+    pub fn builder() -> CodeownersFileBuilder {
+        CodeownersFileBuilder::new()
+    }
+
+    /// Lays out the spans of every line to match the rendered file.
+    ///
+    /// Call this after editing lines in place; the line-editing methods on
+    /// the file do it themselves.
+    pub fn renumber(&mut self) {
+        let mut offset = 0;
+        for (index, line) in self.lines.iter_mut().enumerate() {
+            line.layout(offset, index + 1);
+            offset += line.span.length + 1;
+        }
+    }
+
+    /// Appends a line to the file.
+    pub fn push_line(&mut self, line: Line) {
+        self.lines.push(line);
+        self.renumber();
+    }
+
+    /// Inserts a line before the line at `index` (0-based).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the number of lines.
+    pub fn insert_line(&mut self, index: usize, line: Line) {
+        self.lines.insert(index, line);
+        self.renumber();
+    }
+
+    /// Removes and returns the line at `index` (0-based).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove_line(&mut self, index: usize) -> Line {
+        let line = self.lines.remove(index);
+        self.renumber();
+        line
+    }
+
+    /// Rewrites the patterns of rules under the directory `from` to start
+    /// with `to` instead, e.g. after a directory restructure.
+    ///
+    /// Both are directories relative to the repository root, with or
+    /// without surrounding slashes. Only patterns anchored to the root are
+    /// moved, since one like `docs/` matches at any depth. Patterns keep
+    /// their leading slash, and gain one if the move would unanchor them.
+    ///
+    /// Returns the rewritten patterns in file order, with their spans
+    /// before the move. Nothing is moved if either directory is the root.
+    pub fn move_prefix(&mut self, from: &str, to: &str) -> Vec<PatternMove> {
+        let (from, to) = (from.trim_matches('/'), to.trim_matches('/'));
+        if from.is_empty() || to.is_empty() {
+            return Vec::new();
+        }
+
+        let mut moves = Vec::new();
+        for (index, line) in self.lines.iter_mut().enumerate() {
+            let LineKind::Rule { pattern, .. } = &mut line.kind else {
+                continue;
+            };
+            if let Some(moved) = moved_pattern(&pattern.text, from, to) {
+                moves.push(PatternMove {
+                    index,
+                    from: pattern.clone(),
+                    to: moved.clone(),
+                });
+                pattern.text = moved;
+            }
+        }
+        if !moves.is_empty() {
+            self.renumber();
+        }
+        moves
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::Owner;
+    use crate::parse::{Dialect, ParserConfig, parse_codeowners, parse_codeowners_with_config};
+
+    fn rule_owners(line: &Line) -> Vec<String> {
+        match &line.kind {
+            LineKind::Rule { owners, .. } => owners.iter().map(Owner::to_string).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    #[test]
+    fn built_file_parses_back() {
+        let file = CodeownersFile::builder()
+            .comment("Owners")
+            .blank()
+            .rule("*", ["@org/core"])
+            .rule("/docs/", ["@alice", "docs@example.com"])
+            .build();
+
+        let text = file.to_string();
+        assert_eq!(
+            text,
+            "# Owners\n\n* @org/core\n/docs/ @alice docs@example.com\n"
+        );
+        assert_eq!(parse_codeowners(&text).ast, file);
+    }
+
+    #[test]
+    fn rule_builder_with_inline_comment() {
+        let line = Line::rule_builder("*.rs")
+            .owner("@rust")
+            .inline_comment(" language owners")
+            .build();
+        assert_eq!(line.to_string(), "*.rs @rust # language owners");
+        assert_eq!(line.span.length, 28);
+
+        let config = ParserConfig::new().with_inline_comments(true);
+        let file = CodeownersFile::builder().line(line).build();
+        assert_eq!(
+            parse_codeowners_with_config(&file.to_string(), &config).ast,
+            file
+        );
+    }
+
+    #[test]
+    fn add_and_remove_owners() {
+        let mut file = parse_codeowners("/api/ @alice @bob\n*.md @docs\n").ast;
+
+        assert!(file.lines[0].add_owner("@org/backend"));
+        assert!(!file.lines[0].add_owner("@ALICE"));
+        assert!(file.lines[0].remove_owner("@Bob"));
+        assert!(!file.lines[0].remove_owner("@carol"));
+        assert_eq!(rule_owners(&file.lines[0]), ["@alice", "@org/backend"]);
+
+        file.renumber();
+        assert_eq!(parse_codeowners(&file.to_string()).ast, file);
+    }
+
+    #[test]
+    fn line_editing_renumbers() {
+        let mut file = parse_codeowners("* @core\n/docs/ @docs\n").ast;
+        file.insert_line(1, Line::rule_builder("/api/").owner("@api").build());
+        file.push_line(Line::rule_builder("/web/").owner("@web").build());
+        let removed = file.remove_line(0);
+        assert_eq!(removed.to_string(), "* @core");
+
+        assert_eq!(file.to_string(), "/api/ @api\n/docs/ @docs\n/web/ @web\n");
+        assert_eq!(file.lines[2].span.line, 3);
+        assert_eq!(parse_codeowners(&file.to_string()).ast, file);
+    }
+
+    #[test]
+    fn move_prefix_rewrites_anchored_patterns() {
+        let mut file = parse_codeowners(
+            "/old/dir/ @a\nold/dir/*.rs @b\n/old/dirs/ @c\ndir/ @d\n/old/dir @e\nold/dir/x/ @f\n",
+        )
+        .ast;
+        let moves = file.move_prefix("/old/dir/", "new");
+
+        let rewritten: Vec<_> = moves
+            .iter()
+            .map(|m| (m.from.text.as_str(), m.to.as_str()))
+            .collect();
+        assert_eq!(
+            rewritten,
+            [
+                ("/old/dir/", "/new/"),
+                ("old/dir/*.rs", "new/*.rs"),
+                ("/old/dir", "/new"),
+                ("old/dir/x/", "new/x/"),
+            ]
+        );
+        assert_eq!(moves[1].from.span.line, 2);
+        assert_eq!(
+            file.to_string(),
+            "/new/ @a\nnew/*.rs @b\n/old/dirs/ @c\ndir/ @d\n/new @e\nnew/x/ @f\n"
+        );
+        assert_eq!(parse_codeowners(&file.to_string()).ast, file);
+
+        // A single directory left without a leading slash is anchored
+        let mut file = parse_codeowners("a/b/ @a\n").ast;
+        assert_eq!(file.move_prefix("a/b", "c")[0].to, "/c/");
+        assert!(file.move_prefix("/", "c").is_empty());
+    }
+
+    #[test]
+    fn section_spans() {
+        let config = ParserConfig::new().with_dialect(Dialect::GitLab);
+        let mut file = parse_codeowners_with_config("^[Docs][2] @a\n/docs/ @d\n", &config).ast;
+        file.insert_line(0, Line::comment(" Sections", Span::default()));
+        assert_eq!(
+            parse_codeowners_with_config(&file.to_string(), &config).ast,
+            file
+        );
+    }
+}
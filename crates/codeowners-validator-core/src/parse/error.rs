@@ -4,10 +4,12 @@
 //! along with their source locations.
 
 use super::span::Span;
+use crate::validate::Fix;
 use thiserror::Error;
 
 /// An error that occurred during parsing.
 #[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum ParseError {
     /// A line could not be parsed.
     #[error("line {line}: {message}")]
@@ -21,48 +23,82 @@ pub enum ParseError {
     },
 
     /// Expected an owner but found something else.
-    #[error("line {line}, column {column}: expected owner (e.g., @user, @org/team, or email)")]
+    #[error(
+        "line {line}, column {column}: expected owner starting with '@' or an email, found '{found}'{}",
+        hint(.suggestion)
+    )]
     ExpectedOwner {
         /// The line number (1-based).
         line: usize,
         /// The column number (1-based).
         column: usize,
+        /// The token that was found instead.
+        found: String,
+        /// A suggested correction, if one could be derived.
+        suggestion: Option<String>,
         /// Location in the source.
         span: Span,
     },
 
     /// Expected a pattern but found something else.
-    #[error("line {line}, column {column}: expected file pattern")]
+    #[error("line {line}, column {column}: expected file pattern, found '{found}'")]
     ExpectedPattern {
         /// The line number (1-based).
         line: usize,
         /// The column number (1-based).
         column: usize,
+        /// The token that was found instead.
+        found: String,
         /// Location in the source.
         span: Span,
     },
 
     /// A rule line has no owners.
-    #[error("line {line}: rule has no owners")]
+    #[error("line {line}: rule has no owners{}", hint(.suggestion))]
     MissingOwners {
         /// The line number (1-based).
         line: usize,
+        /// A suggested correction, if one could be derived.
+        suggestion: Option<String>,
         /// Location of the pattern without owners.
         span: Span,
     },
 
+    /// A rule was continued onto following lines with trailing backslashes.
+    #[error(
+        "line {line}: CODEOWNERS does not support line continuations; each rule must be on a single line (hint: merge into '{merged}')"
+    )]
+    LineContinuation {
+        /// The line number where the continued rule starts (1-based).
+        line: usize,
+        /// The rule with its continuation lines merged.
+        merged: String,
+        /// Location of all lines making up the continued rule.
+        span: Span,
+    },
+
     /// Unexpected content at end of line.
-    #[error("line {line}, column {column}: unexpected content")]
+    #[error("line {line}, column {column}: unexpected content '{found}'")]
     UnexpectedContent {
         /// The line number (1-based).
         line: usize,
         /// The column number (1-based).
         column: usize,
+        /// The unexpected text.
+        found: String,
         /// Location of the unexpected content.
         span: Span,
     },
 }
 
+/// Formats an optional suggestion as a trailing hint for error messages.
+fn hint(suggestion: &Option<String>) -> String {
+    suggestion
+        .as_deref()
+        .map(|s| format!(" (hint: {s})"))
+        .unwrap_or_default()
+}
+
 impl ParseError {
     /// Creates an invalid line error.
     pub fn invalid_line(message: impl Into<String>, span: Span) -> Self {
@@ -73,20 +109,23 @@ impl ParseError {
         }
     }
 
-    /// Creates an expected owner error.
-    pub fn expected_owner(span: Span) -> Self {
+    /// Creates an expected owner error for the token that was found instead.
+    pub fn expected_owner(found: impl Into<String>, span: Span) -> Self {
         Self::ExpectedOwner {
             line: span.line,
             column: span.column,
+            found: found.into(),
+            suggestion: None,
             span,
         }
     }
 
-    /// Creates an expected pattern error.
-    pub fn expected_pattern(span: Span) -> Self {
+    /// Creates an expected pattern error for the token that was found instead.
+    pub fn expected_pattern(found: impl Into<String>, span: Span) -> Self {
         Self::ExpectedPattern {
             line: span.line,
             column: span.column,
+            found: found.into(),
             span,
         }
     }
@@ -95,19 +134,72 @@ impl ParseError {
     pub fn missing_owners(span: Span) -> Self {
         Self::MissingOwners {
             line: span.line,
+            suggestion: None,
             span,
         }
     }
 
     /// Creates an unexpected content error.
-    pub fn unexpected_content(span: Span) -> Self {
+    pub fn unexpected_content(found: impl Into<String>, span: Span) -> Self {
         Self::UnexpectedContent {
             line: span.line,
             column: span.column,
+            found: found.into(),
+            span,
+        }
+    }
+
+    /// Creates a line continuation error.
+    pub fn line_continuation(merged: impl Into<String>, span: Span) -> Self {
+        Self::LineContinuation {
+            line: span.line,
+            merged: merged.into(),
             span,
         }
     }
 
+    /// Attaches a suggested correction to errors that can carry one.
+    ///
+    /// Errors without a suggestion field are returned unchanged.
+    pub fn with_suggestion(mut self, value: impl Into<String>) -> Self {
+        match &mut self {
+            ParseError::ExpectedOwner { suggestion, .. }
+            | ParseError::MissingOwners { suggestion, .. } => *suggestion = Some(value.into()),
+            ParseError::InvalidLine { .. }
+            | ParseError::ExpectedPattern { .. }
+            | ParseError::LineContinuation { .. }
+            | ParseError::UnexpectedContent { .. } => {}
+        }
+        self
+    }
+
+    /// Returns the suggested correction for this error, if any.
+    pub fn suggestion(&self) -> Option<&str> {
+        match self {
+            ParseError::ExpectedOwner { suggestion, .. }
+            | ParseError::MissingOwners { suggestion, .. } => suggestion.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the token that was found where something else was expected, if any.
+    pub fn found(&self) -> Option<&str> {
+        match self {
+            ParseError::ExpectedOwner { found, .. }
+            | ParseError::ExpectedPattern { found, .. }
+            | ParseError::UnexpectedContent { found, .. } => Some(found),
+            _ => None,
+        }
+    }
+
+    /// Returns a machine-applicable fix for this error, if one is available.
+    pub fn fix(&self) -> Option<Fix> {
+        match self {
+            ParseError::LineContinuation { merged, span, .. } => Some(Fix::replace(*span, merged)),
+            _ => None,
+        }
+    }
+
     /// Returns the span associated with this error.
     pub fn span(&self) -> &Span {
         match self {
@@ -115,6 +207,7 @@ impl ParseError {
             ParseError::ExpectedOwner { span, .. } => span,
             ParseError::ExpectedPattern { span, .. } => span,
             ParseError::MissingOwners { span, .. } => span,
+            ParseError::LineContinuation { span, .. } => span,
             ParseError::UnexpectedContent { span, .. } => span,
         }
     }
@@ -126,6 +219,7 @@ impl ParseError {
             ParseError::ExpectedOwner { line, .. } => *line,
             ParseError::ExpectedPattern { line, .. } => *line,
             ParseError::MissingOwners { line, .. } => *line,
+            ParseError::LineContinuation { line, .. } => *line,
             ParseError::UnexpectedContent { line, .. } => *line,
         }
     }
@@ -184,7 +278,7 @@ mod tests {
 
     #[test]
     fn parse_error_expected_owner() {
-        let error = ParseError::expected_owner(test_span());
+        let error = ParseError::expected_owner("team-a", test_span());
         assert!(matches!(
             error,
             ParseError::ExpectedOwner {
@@ -193,12 +287,34 @@ mod tests {
                 ..
             }
         ));
-        assert!(error.to_string().contains("expected owner"));
+        assert_eq!(error.found(), Some("team-a"));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 5: expected owner starting with '@' or an email, found 'team-a'"
+        );
+    }
+
+    #[test]
+    fn parse_error_suggestion_in_message() {
+        let error = ParseError::expected_owner("team-a", test_span())
+            .with_suggestion("did you mean '@team-a'?");
+        assert_eq!(error.suggestion(), Some("did you mean '@team-a'?"));
+        assert!(
+            error
+                .to_string()
+                .ends_with("found 'team-a' (hint: did you mean '@team-a'?)")
+        );
+    }
+
+    #[test]
+    fn parse_error_suggestion_ignored_without_field() {
+        let error = ParseError::expected_pattern("#", test_span()).with_suggestion("unused");
+        assert_eq!(error.suggestion(), None);
     }
 
     #[test]
     fn parse_error_expected_pattern() {
-        let error = ParseError::expected_pattern(test_span());
+        let error = ParseError::expected_pattern("#", test_span());
         assert!(matches!(
             error,
             ParseError::ExpectedPattern {
@@ -217,6 +333,18 @@ mod tests {
         assert!(error.to_string().contains("no owners"));
     }
 
+    #[test]
+    fn parse_error_line_continuation_fix() {
+        let error = ParseError::line_continuation("*.rs @a @b", test_span());
+        assert!(
+            error
+                .to_string()
+                .contains("does not support line continuations")
+        );
+        assert_eq!(error.fix(), Some(Fix::replace(test_span(), "*.rs @a @b")));
+        assert_eq!(ParseError::missing_owners(test_span()).fix(), None);
+    }
+
     #[test]
     fn parse_error_span() {
         let span = test_span();
@@ -2,12 +2,16 @@
 //!
 //! This module contains nom-based parsers for individual tokens
 //! like patterns, owners, and comments.
+//!
+//! As in gitignore, a backslash-escaped space (`\ `) is part of the pattern
+//! rather than a separator, so `docs/my\ folder/ @team` is one pattern.
 
 use nom::{
     IResult, Parser,
-    bytes::complete::take_while1,
+    bytes::complete::{take_while, take_while1},
     character::complete::{char, space0, space1},
-    combinator::rest,
+    combinator::{opt, rest},
+    sequence::delimited,
 };
 
 use super::ast::{Owner, Pattern};
@@ -18,6 +22,34 @@ fn is_pattern_char(c: char) -> bool {
     !c.is_whitespace() && c != '#'
 }
 
+/// Returns the byte length of the pattern at the start of `input`.
+///
+/// The pattern ends before the first whitespace or `#` that is not part of
+/// an escaped space.
+fn pattern_len(input: &str) -> usize {
+    let mut chars = input.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        // An escaped backslash cannot escape the space after it
+        if c == '\\' && matches!(chars.peek(), Some((_, ' ' | '\\'))) {
+            chars.next();
+        } else if !is_pattern_char(c) {
+            return index;
+        }
+    }
+    input.len()
+}
+
+/// Parses a pattern, keeping escaped spaces.
+fn pattern_text(input: &str) -> IResult<&str, &str> {
+    match pattern_len(input) {
+        0 => Err(nom::Err::Error(nom::error::Error::new(
+            input,
+            nom::error::ErrorKind::TakeWhile1,
+        ))),
+        len => Ok((&input[len..], &input[..len])),
+    }
+}
+
 /// Characters that can appear in an owner token.
 fn is_owner_char(c: char) -> bool {
     !c.is_whitespace() && c != '#'
@@ -57,6 +89,26 @@ pub struct PatternOnly<'a> {
     pub pattern_offset: usize,
 }
 
+/// Result of parsing a GitLab section header.
+#[derive(Debug, Clone)]
+pub struct SectionComponents<'a> {
+    /// The section name, without brackets.
+    pub name: &'a str,
+    /// Byte offset of the name within the line.
+    pub name_offset: usize,
+    /// True if the header starts with `^`.
+    pub optional: bool,
+    /// The approval count text from a trailing `[n]`, if any. It is not
+    /// checked to be a number.
+    pub approvals: Option<&'a str>,
+    /// Byte offset of the approval count within the line.
+    pub approvals_offset: usize,
+    /// List of default owner texts.
+    pub owners: Vec<&'a str>,
+    /// Byte offsets of each owner start within the line.
+    pub owner_offsets: Vec<usize>,
+}
+
 /// Parses just a pattern from a line (no owners required).
 ///
 /// Used when `allow_unowned_patterns` is enabled.
@@ -66,7 +118,7 @@ pub fn parse_pattern_only(input: &str) -> IResult<&str, PatternOnly<'_>> {
     let pattern_offset = leading_ws.len();
 
     // Parse pattern
-    let (rest, pattern) = take_while1(is_pattern_char)(after_ws)?;
+    let (rest, pattern) = pattern_text(after_ws)?;
 
     Ok((
         rest,
@@ -87,18 +139,82 @@ pub fn parse_rule_components(input: &str) -> IResult<&str, RuleComponents<'_>> {
     let pattern_offset = leading_ws.len();
 
     // Parse pattern
-    let (after_pattern, pattern) = take_while1(is_pattern_char)(after_ws)?;
+    let (after_pattern, pattern) = pattern_text(after_ws)?;
 
     // Parse separator
     let (after_sep, _) = space1(after_pattern)?;
 
     // Parse owners (one or more)
+    let owners_offset = input.len() - after_sep.len();
+    let (current, (owners, owner_offsets)) = parse_owner_list(after_sep, owners_offset)?;
+
+    if owners.is_empty() {
+        return Err(nom::Err::Error(nom::error::Error::new(
+            input,
+            nom::error::ErrorKind::Many1,
+        )));
+    }
+
+    Ok((
+        current,
+        RuleComponents {
+            pattern,
+            pattern_offset,
+            owners,
+            owner_offsets,
+        },
+    ))
+}
+
+/// Parses a GitLab section header: `[Name]`, optionally prefixed with `^`,
+/// followed by an approval count `[n]` and default owners.
+///
+/// The header must be followed by whitespace, a comment or the end of the
+/// line, so a pattern such as `[Dd]ocs/` is not mistaken for a header.
+pub fn parse_section_header(input: &str) -> IResult<&str, SectionComponents<'_>> {
+    let (after_ws, _) = space0(input)?;
+    let (after_caret, caret) = opt(char('^')).parse(after_ws)?;
+    let (after_name, name) =
+        delimited(char('['), take_while1(|c| c != ']'), char(']')).parse(after_caret)?;
+    let (after_header, approvals) =
+        opt(delimited(char('['), take_while(|c| c != ']'), char(']'))).parse(after_name)?;
+
+    if !(after_header.is_empty()
+        || after_header.starts_with(char::is_whitespace)
+        || after_header.starts_with('#'))
+    {
+        return Err(nom::Err::Error(nom::error::Error::new(
+            after_header,
+            nom::error::ErrorKind::Verify,
+        )));
+    }
+    let owners_offset = input.len() - after_header.len();
+    let (rest, (owners, owner_offsets)) = parse_owner_list(after_header, owners_offset)?;
+
+    Ok((
+        rest,
+        SectionComponents {
+            name,
+            name_offset: input.len() - after_caret.len() + 1,
+            optional: caret.is_some(),
+            approvals,
+            approvals_offset: input.len() - after_name.len() + 1,
+            owners,
+            owner_offsets,
+        },
+    ))
+}
+
+/// Parses zero or more whitespace-separated owners, up to a comment or the
+/// end of the line.
+///
+/// `offset` is the byte offset of `input` within the line; the returned
+/// offsets are relative to the line.
+fn parse_owner_list(input: &str, offset: usize) -> IResult<&str, (Vec<&str>, Vec<usize>)> {
     let mut owners = Vec::new();
     let mut owner_offsets = Vec::new();
-    let mut current = after_sep;
-    let mut current_offset = pattern_offset
-        + pattern.len()
-        + (after_sep.as_ptr() as usize - after_pattern.as_ptr() as usize);
+    let mut current = input;
+    let mut current_offset = offset;
 
     loop {
         // Skip whitespace before owner
@@ -118,22 +234,7 @@ pub fn parse_rule_components(input: &str) -> IResult<&str, RuleComponents<'_>> {
         current = after_owner;
     }
 
-    if owners.is_empty() {
-        return Err(nom::Err::Error(nom::error::Error::new(
-            input,
-            nom::error::ErrorKind::Many1,
-        )));
-    }
-
-    Ok((
-        current,
-        RuleComponents {
-            pattern,
-            pattern_offset,
-            owners,
-            owner_offsets,
-        },
-    ))
+    Ok((current, (owners, owner_offsets)))
 }
 
 /// Classifies an owner string into its type.
@@ -174,16 +275,36 @@ pub fn classify_owner(text: &str) -> OwnerKind<'_> {
     OwnerKind::Unknown(text)
 }
 
+/// Explains why an owner token could not be classified.
+///
+/// Intended for tokens where [`classify_owner`] returns [`OwnerKind::Unknown`].
+pub(crate) fn invalid_owner_reason(text: &str) -> &'static str {
+    match text.strip_prefix('@') {
+        Some("") => "expected a user or team name after '@'",
+        Some(rest) if rest.starts_with('/') => "organization name cannot be empty",
+        Some(rest) if rest.ends_with('/') => "team name cannot be empty",
+        Some(_) => "expected @user or @org/team",
+        None => "expected owner starting with '@' or an email address",
+    }
+}
+
+/// Suggests a correction for an owner token that could not be classified.
+///
+/// Returns `None` when no plausible fix can be derived from the token alone.
+pub(crate) fn owner_suggestion(text: &str) -> Option<String> {
+    if text.starts_with('@') || text.is_empty() {
+        return None;
+    }
+    Some(format!("did you mean '@{text}'?"))
+}
+
 /// Creates an Owner AST node from text and span.
 pub fn make_owner(text: &str, span: Span) -> Owner {
     match classify_owner(text) {
         OwnerKind::User(name) => Owner::user(name, span),
         OwnerKind::Team { org, team } => Owner::team(org, team, span),
         OwnerKind::Email(email) => Owner::email(email, span),
-        OwnerKind::Unknown(raw) => {
-            // Treat unknown as a user for now; validation will catch it
-            Owner::user(raw, span)
-        }
+        OwnerKind::Unknown(raw) => Owner::invalid(raw, invalid_owner_reason(raw), span),
     }
 }
 
@@ -192,6 +313,148 @@ pub fn make_pattern(text: &str, span: Span) -> Pattern {
     Pattern::new(text, span)
 }
 
+/// The kind of a lexical token in a CODEOWNERS line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    /// The pattern at the start of a rule.
+    Pattern,
+    /// An owner following the pattern.
+    Owner,
+    /// A comment, from `#` to the end of the line.
+    Comment,
+    /// A run of whitespace.
+    Whitespace,
+}
+
+/// A lexical token with its source text and location.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token<'a> {
+    /// The kind of token.
+    pub kind: TokenKind,
+    /// The token's source text.
+    pub text: &'a str,
+    /// Location of the token.
+    pub span: Span,
+}
+
+impl<'a> Token<'a> {
+    /// Classifies the token as an owner, if it is one.
+    pub fn owner_kind(&self) -> Option<OwnerKind<'a>> {
+        match self.kind {
+            TokenKind::Owner => Some(classify_owner(self.text)),
+            _ => None,
+        }
+    }
+}
+
+/// Iterator over the tokens of a single line.
+///
+/// Created by [`lex_line`] and [`lex`].
+#[derive(Debug, Clone)]
+pub struct LineTokens<'a> {
+    line: &'a str,
+    pos: usize,
+    line_num: usize,
+    line_offset: usize,
+    seen_pattern: bool,
+}
+
+impl<'a> LineTokens<'a> {
+    fn new(line: &'a str, line_num: usize, line_offset: usize) -> Self {
+        Self {
+            line,
+            pos: 0,
+            line_num,
+            line_offset,
+            seen_pattern: false,
+        }
+    }
+}
+
+impl<'a> Iterator for LineTokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = &self.line[self.pos..];
+        let first = remaining.chars().next()?;
+
+        let (kind, len) = if first.is_whitespace() {
+            let len = remaining
+                .find(|c: char| !c.is_whitespace())
+                .unwrap_or(remaining.len());
+            (TokenKind::Whitespace, len)
+        } else if first == '#' {
+            (TokenKind::Comment, remaining.len())
+        } else if self.seen_pattern {
+            let len = remaining
+                .find(|c: char| !is_owner_char(c))
+                .unwrap_or(remaining.len());
+            (TokenKind::Owner, len)
+        } else {
+            self.seen_pattern = true;
+            (TokenKind::Pattern, pattern_len(remaining))
+        };
+
+        let start = self.pos;
+        self.pos += len;
+        Some(Token {
+            kind,
+            text: &self.line[start..self.pos],
+            span: Span::new(self.line_offset + start, self.line_num, start + 1, len),
+        })
+    }
+}
+
+/// Splits a single CODEOWNERS line into tokens.
+///
+/// Spans are relative to the line: offsets start at 0 and the line number is 1.
+/// The first word is the pattern; every following word before a
+/// `#` is an owner. Tokens cover the whole line, so concatenating their text
+/// reproduces the input.
+///
+/// # Example
+///
+/// ```rust
+/// use codeowners_validator_core::parse::{TokenKind, lex_line};
+///
+/// let kinds: Vec<_> = lex_line("*.rs @owner").map(|t| t.kind).collect();
+/// assert_eq!(
+///     kinds,
+///     vec![TokenKind::Pattern, TokenKind::Whitespace, TokenKind::Owner]
+/// );
+/// ```
+pub fn lex_line(line: &str) -> LineTokens<'_> {
+    LineTokens::new(line, 1, 0)
+}
+
+/// Splits input into lines like [`str::lines`], pairing each line with the
+/// byte offset it starts at.
+///
+/// Offsets count the full line terminator, so they stay exact for `\r\n`
+/// line endings.
+pub(crate) fn split_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut offset = 0;
+    input.split_inclusive('\n').map(move |raw| {
+        let start = offset;
+        offset += raw.len();
+        let line = match raw.strip_suffix('\n') {
+            Some(line) => line.strip_suffix('\r').unwrap_or(line),
+            None => raw,
+        };
+        (start, line)
+    })
+}
+
+/// Splits a whole CODEOWNERS file into tokens with absolute spans.
+///
+/// Lines are split the same way as the parser does, so token spans line up
+/// with AST spans. Line terminators are not emitted as tokens.
+pub fn lex(input: &str) -> impl Iterator<Item = Token<'_>> {
+    split_lines(input)
+        .enumerate()
+        .flat_map(|(idx, (offset, line))| LineTokens::new(line, idx + 1, offset))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -298,6 +561,21 @@ mod tests {
         assert!(rest.contains('#'));
     }
 
+    #[test]
+    fn parse_rule_components_with_escaped_space() {
+        let (_, components) = parse_rule_components("docs/my\\ folder/ @team").unwrap();
+        assert_eq!(components.pattern, "docs/my\\ folder/");
+        assert_eq!(components.owners, vec!["@team"]);
+        assert_eq!(components.owner_offsets, vec![17]);
+
+        // An escaped backslash does not escape the following space
+        let (_, components) = parse_rule_components("a\\\\ @team").unwrap();
+        assert_eq!(components.pattern, "a\\\\");
+
+        let kinds: Vec<_> = lex_line("my\\ file @a").map(|t| t.text).collect();
+        assert_eq!(kinds, vec!["my\\ file", " ", "@a"]);
+    }
+
     #[test]
     fn parse_rule_components_no_owner_fails() {
         let result = parse_rule_components("*.rs");
@@ -320,6 +598,108 @@ mod tests {
         );
     }
 
+    #[test]
+    fn lex_line_rule_with_comment() {
+        let tokens: Vec<_> = lex_line("  /src/ @dev dev@example.com # note").collect();
+        let kinds: Vec<_> = tokens.iter().map(|t| t.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                TokenKind::Whitespace,
+                TokenKind::Pattern,
+                TokenKind::Whitespace,
+                TokenKind::Owner,
+                TokenKind::Whitespace,
+                TokenKind::Owner,
+                TokenKind::Whitespace,
+                TokenKind::Comment,
+            ]
+        );
+        assert_eq!(tokens[1].text, "/src/");
+        assert_eq!(tokens[1].span, Span::new(2, 1, 3, 5));
+        assert_eq!(tokens[7].text, "# note");
+        assert_eq!(
+            tokens[5].owner_kind(),
+            Some(OwnerKind::Email("dev@example.com"))
+        );
+        assert_eq!(tokens[1].owner_kind(), None);
+    }
+
+    #[test]
+    fn lex_line_comment_and_blank() {
+        let tokens: Vec<_> = lex_line("# just a comment").collect();
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].kind, TokenKind::Comment);
+
+        assert_eq!(lex_line("").count(), 0);
+        let tokens: Vec<_> = lex_line(" \t").collect();
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].kind, TokenKind::Whitespace);
+    }
+
+    #[test]
+    fn lex_line_roundtrips_text() {
+        let line = "docs/**  @org/team\t@user #c";
+        let text: String = lex_line(line).map(|t| t.text).collect();
+        assert_eq!(text, line);
+    }
+
+    #[test]
+    fn lex_file_uses_absolute_spans() {
+        let tokens: Vec<_> = lex("# header\n*.rs @owner\n")
+            .filter(|t| t.kind == TokenKind::Owner)
+            .collect();
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].span, Span::new(14, 2, 6, 6));
+    }
+
+    #[test]
+    fn lex_file_counts_crlf_terminators() {
+        let input = "# header\r\n*.rs @owner\r\n/docs/ @docs\r\n";
+        let owners: Vec<_> = lex(input).filter(|t| t.kind == TokenKind::Owner).collect();
+        assert_eq!(owners[0].span.offset, 15);
+        assert_eq!(owners[1].span.offset, 30);
+        for token in owners {
+            assert_eq!(
+                &input[token.span.offset..token.span.end_offset()],
+                token.text
+            );
+        }
+    }
+
+    #[test]
+    fn make_owner_unknown_is_invalid() {
+        let span = Span::new(0, 1, 1, 6);
+        let owner = make_owner("team-a", span);
+        assert!(matches!(owner, Owner::Invalid { raw, .. } if raw == "team-a"));
+    }
+
+    #[test]
+    fn invalid_owner_reasons() {
+        assert_eq!(
+            invalid_owner_reason("team-a"),
+            "expected owner starting with '@' or an email address"
+        );
+        assert_eq!(
+            invalid_owner_reason("@"),
+            "expected a user or team name after '@'"
+        );
+        assert_eq!(
+            invalid_owner_reason("@/core"),
+            "organization name cannot be empty"
+        );
+        assert_eq!(invalid_owner_reason("@org/"), "team name cannot be empty");
+    }
+
+    #[test]
+    fn owner_suggestions() {
+        assert_eq!(
+            owner_suggestion("team-a"),
+            Some("did you mean '@team-a'?".to_string())
+        );
+        assert_eq!(owner_suggestion("@org/"), None);
+    }
+
     #[test]
     fn make_owner_email() {
         let span = Span::new(0, 1, 1, 15);
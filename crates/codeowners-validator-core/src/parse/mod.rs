@@ -22,19 +22,29 @@
 //! }
 //! ```
 
+mod annotation;
 mod ast;
+mod builder;
 mod error;
-mod lexer;
+pub(crate) mod lexer;
 mod parser;
+mod provenance;
 pub mod span;
 
 // Re-export public types
-pub use ast::{CodeownersFile, Line, LineKind, Owner, Pattern};
+pub use annotation::{
+    DIRECTIVE_KEY, Escalation, EscalationTarget, NO_AUTO_REQUEST, NoAutoRequest, ReviewBy,
+    ReviewDate,
+};
+pub use ast::{CodeownersFile, Line, LineKind, Owner, Pattern, SectionApprovals};
+pub use builder::{CodeownersFileBuilder, PatternMove, RuleBuilder};
 pub use error::{ParseError, ParseResult};
 pub use parser::{
-    ParserConfig, parse_codeowners, parse_codeowners_strict, parse_codeowners_with_config,
+    Dialect, ParserConfig, RecoveryStrategy, parse_codeowners, parse_codeowners_strict,
+    parse_codeowners_with_config,
 };
+pub use provenance::{GeneratedRegion, Provenance, SourceLocation};
 pub use span::Span;
 
 // Re-export lexer utilities that may be useful for custom parsing
-pub use lexer::{OwnerKind, classify_owner};
+pub use lexer::{LineTokens, OwnerKind, Token, TokenKind, classify_owner, lex, lex_line};
@@ -3,24 +3,104 @@
 //! This module combines the lexer components to parse complete lines
 //! and entire CODEOWNERS files.
 
-use super::ast::{CodeownersFile, Line, Owner};
+use super::ast::{CodeownersFile, Line, LineKind, Owner, SectionApprovals};
 use super::error::{ParseError, ParseResult};
 use super::lexer::{
-    is_blank_line, make_owner, make_pattern, parse_comment_line, parse_pattern_only,
-    parse_rule_components,
+    is_blank_line, make_owner, make_pattern, owner_suggestion, parse_comment_line,
+    parse_pattern_only, parse_rule_components, parse_section_header, split_lines,
 };
 use super::span::Span;
+use crate::matching::{PatternSyntax, QuestionMark};
 use log::{debug, trace};
 
+/// The CODEOWNERS syntax flavor to parse.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Dialect {
+    /// GitHub CODEOWNERS syntax.
+    #[default]
+    GitHub,
+    /// GitLab CODEOWNERS syntax.
+    GitLab,
+}
+
+impl Dialect {
+    /// Returns how `?` is interpreted in this dialect's patterns.
+    ///
+    /// Both dialects treat `?` as a single-character wildcard: GitHub
+    /// follows gitignore, and GitLab matches with `fnmatch`.
+    pub fn question_mark(self) -> QuestionMark {
+        match self {
+            Self::GitHub | Self::GitLab => QuestionMark::Wildcard,
+        }
+    }
+
+    /// Returns the pattern syntax this dialect accepts.
+    ///
+    /// GitLab matches with `fnmatch`, which supports character classes, and
+    /// supports `!pattern` exclusions.
+    pub fn pattern_syntax(self) -> PatternSyntax {
+        match self {
+            Self::GitHub => PatternSyntax::GitHub,
+            Self::GitLab => PatternSyntax::GitLab,
+        }
+    }
+}
+
+impl From<PatternSyntax> for Dialect {
+    /// Returns the dialect of files written in a pattern syntax: GitLab for
+    /// GitLab patterns, GitHub otherwise.
+    fn from(syntax: PatternSyntax) -> Self {
+        match syntax {
+            PatternSyntax::GitLab => Self::GitLab,
+            PatternSyntax::GitHub | PatternSyntax::Extended => Self::GitHub,
+        }
+    }
+}
+
+/// How the parser proceeds after encountering an invalid line.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RecoveryStrategy {
+    /// Record the error, keep the line as [`LineKind::Invalid`](super::LineKind::Invalid),
+    /// and continue with the next line (lenient mode).
+    #[default]
+    SkipLine,
+    /// Stop at the first error (strict mode).
+    Abort,
+}
+
 /// Configuration options for the parser.
+///
+/// New options are added over time, so the config is built with
+/// [`ParserConfig::new`] and its `with_*` methods rather than a struct
+/// literal.
 #[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct ParserConfig {
-    /// If true, parsing stops at the first error (strict mode).
-    /// If false, errors are collected and parsing continues (lenient mode).
+    /// The syntax flavor to parse.
+    pub dialect: Dialect,
+    /// What to do when a line fails to parse.
+    pub recovery: RecoveryStrategy,
+    /// If true, parsing stops at the first error, like
+    /// [`RecoveryStrategy::Abort`].
+    #[deprecated(
+        since = "0.5.0",
+        note = "use `recovery` with `RecoveryStrategy::Abort` instead"
+    )]
     pub strict: bool,
+    /// Maximum number of errors to collect before giving up, when recovering.
+    /// `None` means no limit.
+    pub max_errors: Option<usize>,
+    /// If true, trailing `# ...` comments on rule lines are kept on the
+    /// [`Line`]'s `inline_comment` field.
+    pub attach_inline_comments: bool,
     /// If true, patterns without owners are allowed (creates rules with empty owner list).
     /// If false, patterns without owners are parse errors.
     pub allow_unowned_patterns: bool,
+    /// The pattern syntax accepted. With [`PatternSyntax::GitLab`],
+    /// `!pattern` exclusions are parsed as rules without owners.
+    pub pattern_syntax: PatternSyntax,
 }
 
 impl ParserConfig {
@@ -31,18 +111,50 @@ impl ParserConfig {
 
     /// Creates a strict mode parser config.
     pub fn strict() -> Self {
-        Self {
-            strict: true,
-            ..Default::default()
-        }
+        Self::new().with_recovery(RecoveryStrategy::Abort)
     }
 
     /// Creates a lenient mode parser config.
     pub fn lenient() -> Self {
-        Self {
-            strict: false,
-            ..Default::default()
-        }
+        Self::new().with_recovery(RecoveryStrategy::SkipLine)
+    }
+
+    /// Returns true if parsing stops at the first error.
+    pub fn is_strict(&self) -> bool {
+        #[allow(deprecated)]
+        let strict = self.strict;
+        strict || self.recovery == RecoveryStrategy::Abort
+    }
+
+    /// Sets the syntax dialect, and the pattern syntax it accepts.
+    pub fn with_dialect(mut self, dialect: Dialect) -> Self {
+        self.dialect = dialect;
+        self.pattern_syntax = dialect.pattern_syntax();
+        self
+    }
+
+    /// Sets the pattern syntax accepted.
+    pub fn with_pattern_syntax(mut self, pattern_syntax: PatternSyntax) -> Self {
+        self.pattern_syntax = pattern_syntax;
+        self
+    }
+
+    /// Sets the error recovery strategy.
+    pub fn with_recovery(mut self, recovery: RecoveryStrategy) -> Self {
+        self.recovery = recovery;
+        self
+    }
+
+    /// Sets the maximum number of errors collected before parsing stops.
+    pub fn with_max_errors(mut self, max: usize) -> Self {
+        self.max_errors = Some(max);
+        self
+    }
+
+    /// Sets whether inline comments are attached to rule lines.
+    pub fn with_inline_comments(mut self, value: bool) -> Self {
+        self.attach_inline_comments = value;
+        self
     }
 
     /// Sets whether unowned patterns are allowed.
@@ -52,6 +164,11 @@ impl ParserConfig {
     }
 }
 
+/// Extracts the content of a trailing `# ...` comment, if any.
+fn inline_comment(remaining: &str) -> Option<&str> {
+    remaining.trim_start().strip_prefix('#')
+}
+
 /// Parses a single line of a CODEOWNERS file.
 ///
 /// Returns the parsed Line AST node, or an error if the line is invalid.
@@ -73,9 +190,52 @@ fn parse_line(
         return Ok(Line::comment(comment_content, line_span));
     }
 
+    // GitLab section headers group the rules below them
+    if config.dialect == Dialect::GitLab
+        && let Ok((remaining, section)) = parse_section_header(line_text)
+    {
+        let name_span = Span::new(
+            line_offset + section.name_offset,
+            line_num,
+            section.name_offset + 1,
+            section.name.len(),
+        );
+        let owners = section
+            .owners
+            .iter()
+            .zip(section.owner_offsets.iter())
+            .map(|(owner_text, &offset)| {
+                let owner_span =
+                    Span::new(line_offset + offset, line_num, offset + 1, owner_text.len());
+                make_owner(owner_text, owner_span)
+            })
+            .collect();
+        let approvals = section.approvals.map(|text| {
+            let span = Span::new(
+                line_offset + section.approvals_offset,
+                line_num,
+                section.approvals_offset + 1,
+                text.len(),
+            );
+            SectionApprovals::new(text, span)
+        });
+        let kind = LineKind::Section {
+            name: section.name.to_string(),
+            name_span,
+            optional: section.optional,
+            approvals,
+            owners,
+        };
+        let mut line = Line::new(kind, line_span);
+        if config.attach_inline_comments {
+            line.inline_comment = inline_comment(remaining).map(str::to_string);
+        }
+        return Ok(line);
+    }
+
     // Try to parse as a rule line (pattern + owners)
     match parse_rule_components(line_text) {
-        Ok((_remaining, components)) => {
+        Ok((remaining, components)) => {
             // Create pattern span
             let pattern_span = Span::new(
                 line_offset + components.pattern_offset,
@@ -97,16 +257,33 @@ fn parse_line(
                 })
                 .collect();
 
-            Ok(Line::rule(pattern, owners, line_span))
+            // Strict mode treats an unclassifiable owner as a parse error
+            if config.is_strict()
+                && let Some(Owner::Invalid { raw, span, .. }) =
+                    owners.iter().find(|owner| owner.is_invalid())
+            {
+                let error = ParseError::expected_owner(raw.as_str(), *span);
+                return Err(match owner_suggestion(raw) {
+                    Some(suggestion) => error.with_suggestion(suggestion),
+                    None => error,
+                });
+            }
+
+            let mut line = Line::rule(pattern, owners, line_span);
+            if config.attach_inline_comments {
+                line.inline_comment = inline_comment(remaining).map(str::to_string);
+            }
+            Ok(line)
         }
         Err(_) => {
             // Check if it looks like a pattern with no owners
             let trimmed = line_text.trim();
             if !trimmed.is_empty() && !trimmed.starts_with('#') {
                 // It has content but no owners
-                if config.allow_unowned_patterns {
+                let exclusion = config.pattern_syntax.allows_negation() && trimmed.starts_with('!');
+                if config.allow_unowned_patterns || exclusion {
                     // Parse just the pattern and create a rule with empty owners
-                    if let Ok((_, pattern_only)) = parse_pattern_only(line_text) {
+                    if let Ok((remaining, pattern_only)) = parse_pattern_only(line_text) {
                         let pattern_span = Span::new(
                             line_offset + pattern_only.pattern_offset,
                             line_num,
@@ -114,12 +291,21 @@ fn parse_line(
                             pattern_only.pattern.len(),
                         );
                         let pattern = make_pattern(pattern_only.pattern, pattern_span);
-                        return Ok(Line::rule(pattern, Vec::new(), line_span));
+                        let mut line = Line::rule(pattern, Vec::new(), line_span);
+                        if config.attach_inline_comments {
+                            line.inline_comment = inline_comment(remaining).map(str::to_string);
+                        }
+                        return Ok(line);
                     }
                 }
                 // Not allowed or couldn't parse pattern - error
                 let error_span = Span::new(line_offset, line_num, 1, line_text.len());
-                Err(ParseError::missing_owners(error_span))
+                Err(
+                    ParseError::missing_owners(error_span).with_suggestion(format!(
+                        "add an owner after the pattern, e.g. '{} @org/team'",
+                        trimmed.split_whitespace().next().unwrap_or(trimmed)
+                    )),
+                )
             } else {
                 let error_span = Span::new(line_offset, line_num, 1, line_text.len());
                 Err(ParseError::invalid_line("could not parse line", error_span))
@@ -128,48 +314,115 @@ fn parse_line(
     }
 }
 
+/// Returns true if a non-comment line ends with a `\\` continuation marker.
+fn ends_with_continuation(line_text: &str) -> bool {
+    parse_comment_line(line_text).is_err() && line_text.trim_end().ends_with('\\')
+}
+
+/// Counts the lines following `lines[0]` that a trailing backslash joins onto it.
+fn continuation_len(lines: &[&str]) -> usize {
+    lines
+        .iter()
+        .take(lines.len().saturating_sub(1))
+        .take_while(|text| ends_with_continuation(text))
+        .count()
+}
+
+/// Builds the error for a rule split across lines with trailing backslashes.
+///
+/// The suggested merge joins the lines with single spaces.
+fn line_continuation_error(
+    group: &[&str],
+    line_num: usize,
+    offset: usize,
+    length: usize,
+) -> ParseError {
+    let merged = group
+        .iter()
+        .enumerate()
+        .map(|(i, text)| {
+            let text = if i == 0 { text.trim_end() } else { text.trim() };
+            text.strip_suffix('\\').unwrap_or(text).trim_end()
+        })
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    ParseError::line_continuation(merged, Span::new(offset, line_num, 1, length))
+}
+
 /// Parses a CODEOWNERS file with the given configuration.
 pub fn parse_codeowners_with_config(input: &str, config: &ParserConfig) -> ParseResult {
     debug!(
-        "Parsing CODEOWNERS file ({} bytes, strict={})",
+        "Parsing CODEOWNERS file ({} bytes, dialect={:?}, recovery={:?})",
         input.len(),
-        config.strict
+        config.dialect,
+        config.recovery
     );
     let mut lines = Vec::new();
     let mut errors = Vec::new();
-    let mut offset = 0;
 
-    for (line_idx, line_text) in input.lines().enumerate() {
+    let (line_offsets, source_lines): (Vec<usize>, Vec<&str>) = split_lines(input).unzip();
+    let mut line_idx = 0;
+
+    while line_idx < source_lines.len() {
+        let line_text = source_lines[line_idx];
         let line_num = line_idx + 1; // 1-based line numbers
+        let offset = line_offsets[line_idx];
+
+        // A trailing backslash joined with following lines is reported once
+        // for the whole group rather than as unrelated per-line errors.
+        let continued = continuation_len(&source_lines[line_idx..]);
+        let (result, group) = if continued > 0 {
+            let last = line_idx + continued;
+            let group = &source_lines[line_idx..=last];
+            let length = line_offsets[last] + source_lines[last].len() - offset;
+            (
+                Err(line_continuation_error(group, line_num, offset, length)),
+                group,
+            )
+        } else {
+            (
+                parse_line(line_text, line_num, offset, config),
+                &source_lines[line_idx..=line_idx],
+            )
+        };
 
-        match parse_line(line_text, line_num, offset, config) {
+        match result {
             Ok(line) => {
                 trace!("Line {}: parsed successfully", line_num);
                 lines.push(line);
             }
             Err(error) => {
                 debug!("Line {}: parse error - {}", line_num, error);
-                if config.strict {
+                if config.is_strict() {
                     // In strict mode, return immediately on first error
                     debug!("Strict mode: stopping at first error");
                     return ParseResult::with_errors(CodeownersFile::new(lines), vec![error]);
                 } else {
                     // In lenient mode, record the error and add an Invalid line
-                    let line_span = Span::new(offset, line_num, 1, line_text.len());
-                    lines.push(Line::invalid(line_text, error.to_string(), line_span));
+                    for (i, text) in group.iter().enumerate() {
+                        let line_span =
+                            Span::new(line_offsets[line_idx + i], line_num + i, 1, text.len());
+                        lines.push(Line::invalid(*text, error.to_string(), line_span));
+                    }
                     errors.push(error);
+
+                    if config.max_errors.is_some_and(|max| errors.len() >= max) {
+                        debug!("Reached maximum of {} errors, stopping", errors.len());
+                        return ParseResult::with_errors(CodeownersFile::new(lines), errors);
+                    }
                 }
             }
         }
 
-        // Update offset for next line (+1 for newline character)
-        offset += line_text.len() + 1;
+        line_idx += group.len();
     }
 
     // Handle case where file doesn't end with newline
     // (the lines iterator doesn't include the trailing newline)
 
-    let ast = CodeownersFile::new(lines);
+    let mut ast = CodeownersFile::new(lines);
+    ast.attach_no_auto_requests();
 
     debug!(
         "Parsing complete: {} lines, {} errors",
@@ -198,6 +451,62 @@ mod tests {
     use super::*;
     use crate::parse::{LineKind, Owner};
 
+    #[test]
+    fn parse_gitlab_sections() {
+        let config = ParserConfig::new().with_dialect(Dialect::GitLab);
+        let input =
+            "[Docs] @docs\n/docs/ @writers\n^[Optional Review][2] @a @org/b # later\n[Dd]ocs/ @x\n";
+        let result = parse_codeowners_with_config(input, &config);
+        assert!(result.is_ok(), "{:?}", result.errors);
+
+        match &result.ast.lines[0].kind {
+            LineKind::Section {
+                name,
+                name_span,
+                optional,
+                approvals,
+                owners,
+            } => {
+                assert_eq!(name, "Docs");
+                assert_eq!(
+                    (name_span.offset, name_span.column, name_span.length),
+                    (1, 2, 4)
+                );
+                assert!(!optional);
+                assert!(approvals.is_none());
+                assert_eq!(owners.len(), 1);
+            }
+            other => panic!("Expected section, got {other:?}"),
+        }
+        match &result.ast.lines[2].kind {
+            LineKind::Section {
+                name,
+                optional,
+                approvals,
+                owners,
+                ..
+            } => {
+                assert_eq!(name, "Optional Review");
+                assert!(optional);
+                let approvals = approvals.as_ref().unwrap();
+                assert_eq!(approvals.count(), Some(2));
+                assert_eq!(approvals.span.column, 20);
+                assert_eq!(owners[1].span().column, 26);
+            }
+            other => panic!("Expected section, got {other:?}"),
+        }
+        assert_eq!(
+            result.ast.lines[2].to_string(),
+            "^[Optional Review][2] @a @org/b"
+        );
+        // A character class followed by more pattern text is not a header
+        assert!(result.ast.lines[3].is_rule());
+        assert!(result.ast.lines[1].is_rule());
+
+        // Without the GitLab dialect, headers stay invalid lines
+        assert!(!parse_codeowners("[Docs]\n").is_ok());
+    }
+
     #[test]
     fn parse_empty_file() {
         let result = parse_codeowners("");
@@ -205,6 +514,22 @@ mod tests {
         assert_eq!(result.ast.lines.len(), 0);
     }
 
+    #[test]
+    fn crlf_spans_point_into_the_input() {
+        let input = "# header\r\n*.rs @owner\r\n/a/ \\\r\n  @b\r\n";
+        let result = parse_codeowners(input);
+        let LineKind::Rule { owners, .. } = &result.ast.lines[1].kind else {
+            panic!("expected a rule");
+        };
+        let span = owners[0].span();
+        assert_eq!(&input[span.offset..span.end_offset()], "@owner");
+
+        // Lines joined by a continuation span both lines, but not the final
+        // terminator
+        let span = result.errors[0].span();
+        assert_eq!(&input[span.offset..span.end_offset()], "/a/ \\\r\n  @b");
+    }
+
     #[test]
     fn parse_blank_lines() {
         let input = "\n   \n\t\n";
@@ -341,6 +666,74 @@ mod tests {
         // Strict mode should stop at first error
         assert!(result.has_errors());
         assert_eq!(result.errors.len(), 1);
+        assert_eq!(
+            result.errors[0].suggestion(),
+            Some("add an owner after the pattern, e.g. '*.rs @org/team'")
+        );
+    }
+
+    #[test]
+    fn parse_line_continuation() {
+        let input = "*.rs @dev \\\n    @org/team\n*.md @docs\n";
+        let result = parse_codeowners(input);
+
+        assert_eq!(result.errors.len(), 1);
+        assert!(matches!(
+            &result.errors[0],
+            ParseError::LineContinuation { line: 1, merged, .. } if merged == "*.rs @dev @org/team"
+        ));
+        assert_eq!(result.ast.lines.len(), 3);
+        assert!(result.ast.lines[0].is_invalid());
+        assert!(result.ast.lines[1].is_invalid());
+        assert_eq!(result.ast.lines[1].span.line, 2);
+        assert!(result.ast.lines[2].is_rule());
+        assert_eq!(result.ast.lines[2].span.offset, 26);
+
+        let fix = result.errors[0].fix().unwrap();
+        assert_eq!(
+            crate::validate::apply_fixes(input, &[fix]),
+            "*.rs @dev @org/team\n*.md @docs\n"
+        );
+    }
+
+    #[test]
+    fn parse_line_continuation_chain() {
+        let input = "/src/ \\\n  @a \\\n  @b\n";
+        let result = parse_codeowners(input);
+        assert_eq!(result.errors.len(), 1);
+        assert!(matches!(
+            &result.errors[0],
+            ParseError::LineContinuation { merged, .. } if merged == "/src/ @a @b"
+        ));
+        assert_eq!(result.ast.lines.len(), 3);
+    }
+
+    #[test]
+    fn trailing_backslash_on_last_line_not_continuation() {
+        let result = parse_codeowners("*.rs @dev \\");
+        assert!(!matches!(
+            result.errors.first(),
+            Some(ParseError::LineContinuation { .. })
+        ));
+    }
+
+    #[test]
+    fn comment_ending_in_backslash_not_continuation() {
+        let result = parse_codeowners("# see C:\\\n*.rs @dev\n");
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn parse_invalid_owner_strict() {
+        let result = parse_codeowners_strict("*.rs @dev team-a\n");
+        assert_eq!(result.errors.len(), 1);
+
+        let error = &result.errors[0];
+        assert!(matches!(
+            error,
+            ParseError::ExpectedOwner { found, column: 11, .. } if found == "team-a"
+        ));
+        assert_eq!(error.suggestion(), Some("did you mean '@team-a'?"));
     }
 
     #[test]
@@ -410,21 +803,32 @@ mod tests {
     #[test]
     fn config_default_is_lenient() {
         let config = ParserConfig::default();
-        assert!(!config.strict);
+        assert!(!config.is_strict());
         assert!(!config.allow_unowned_patterns);
     }
 
     #[test]
     fn config_strict_mode() {
         let config = ParserConfig::strict();
-        assert!(config.strict);
+        assert!(config.is_strict());
         assert!(!config.allow_unowned_patterns);
     }
 
+    #[test]
+    #[allow(deprecated)]
+    fn config_deprecated_strict_field_still_aborts() {
+        let mut config = ParserConfig::new();
+        config.strict = true;
+        assert!(config.is_strict());
+
+        let result = parse_codeowners_with_config("bad\n*.rs @a\nworse\n", &config);
+        assert_eq!(result.errors.len(), 1);
+    }
+
     #[test]
     fn config_lenient_mode() {
         let config = ParserConfig::lenient();
-        assert!(!config.strict);
+        assert!(!config.is_strict());
         assert!(!config.allow_unowned_patterns);
     }
 
@@ -461,6 +865,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_exclusions_with_gitlab_syntax() {
+        let input = "*.md @docs\n!README.md\n";
+        assert!(!parse_codeowners(input).is_ok());
+
+        let config = ParserConfig::new().with_dialect(Dialect::GitLab);
+        let result = parse_codeowners_with_config(input, &config);
+        assert!(result.is_ok());
+        if let LineKind::Rule { pattern, owners } = &result.ast.lines[1].kind {
+            assert!(pattern.is_negation());
+            assert!(owners.is_empty());
+        } else {
+            panic!("Expected exclusion rule");
+        }
+
+        // Other unowned patterns are still errors
+        assert!(!parse_codeowners_with_config("*.md\n", &config).is_ok());
+    }
+
     #[test]
     fn parse_unowned_pattern_when_not_allowed() {
         let config = ParserConfig::new().with_allow_unowned_patterns(false);
@@ -505,4 +928,70 @@ mod tests {
             panic!("Expected rule");
         }
     }
+
+    #[test]
+    fn config_builder() {
+        let config = ParserConfig::new()
+            .with_dialect(Dialect::GitLab)
+            .with_recovery(RecoveryStrategy::Abort)
+            .with_max_errors(3)
+            .with_inline_comments(true);
+        assert_eq!(config.dialect, Dialect::GitLab);
+        assert_eq!(config.pattern_syntax, PatternSyntax::GitLab);
+        assert!(config.is_strict());
+        assert_eq!(config.max_errors, Some(3));
+        assert!(config.attach_inline_comments);
+    }
+
+    #[test]
+    fn dialect_from_pattern_syntax() {
+        assert_eq!(Dialect::from(PatternSyntax::GitLab), Dialect::GitLab);
+        assert_eq!(Dialect::from(PatternSyntax::GitHub), Dialect::GitHub);
+        assert_eq!(Dialect::from(PatternSyntax::Extended), Dialect::GitHub);
+    }
+
+    #[test]
+    fn max_errors_stops_lenient_parsing() {
+        let config = ParserConfig::new().with_max_errors(2);
+        let input = "*.rs\n*.md\n*.js\n*.py @dev\n";
+        let result = parse_codeowners_with_config(input, &config);
+
+        assert_eq!(result.errors.len(), 2);
+        assert_eq!(result.ast.lines.len(), 2);
+    }
+
+    #[test]
+    fn inline_comments_attached_when_enabled() {
+        let input = "*.rs @dev # Rust files\n*.md @docs\n";
+
+        let result = parse_codeowners(input);
+        assert!(result.ast.lines[0].inline_comment.is_none());
+
+        let config = ParserConfig::new().with_inline_comments(true);
+        let result = parse_codeowners_with_config(input, &config);
+        assert_eq!(
+            result.ast.lines[0].inline_comment.as_deref(),
+            Some(" Rust files")
+        );
+        assert!(result.ast.lines[1].inline_comment.is_none());
+        assert_eq!(result.ast.lines[0].to_string(), "*.rs @dev # Rust files");
+    }
+
+    #[test]
+    fn parse_rule_keeps_valid_owners_around_invalid_one() {
+        let input = "*.rs @dev team-a @org/core\n";
+        let result = parse_codeowners(input);
+        assert!(result.is_ok());
+
+        if let LineKind::Rule { owners, .. } = &result.ast.lines[0].kind {
+            assert_eq!(owners.len(), 3);
+            assert!(matches!(&owners[0], Owner::User { name, .. } if name == "dev"));
+            assert!(
+                matches!(&owners[1], Owner::Invalid { raw, span, .. } if raw == "team-a" && span.column == 11)
+            );
+            assert!(matches!(&owners[2], Owner::Team { .. }));
+        } else {
+            panic!("Expected rule");
+        }
+    }
 }
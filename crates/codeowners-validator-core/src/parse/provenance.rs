@@ -0,0 +1,348 @@
+//! Provenance metadata for generated CODEOWNERS files.
+//!
+//! Organizations that generate CODEOWNERS record where rules came from in
+//! structured comments:
+//!
+//! ```text
+//! # generated-by: codeowners-gen v1.2
+//! # generated-hash: sha256:9f86d081...
+//! # source: teams/payments.yml:12
+//! /payments/ @org/payments
+//! # end-generated
+//! ```
+//!
+//! A `generated-by` comment opens a generated region, which runs until an
+//! `end-generated` comment, the next `generated-by` comment, or the end of the
+//! file. The optional `generated-hash` comment records the hash of the region
+//! content, so hand edits can be detected.
+
+use super::ast::{CodeownersFile, Line, LineKind};
+use super::span::Span;
+use sha2::{Digest, Sha256};
+use std::fmt::{self, Display};
+
+/// Prefix of generated region hashes.
+const HASH_PREFIX: &str = "sha256:";
+
+/// Where a rule was generated from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// Path of the source file, as written in the comment.
+    pub path: String,
+    /// Line number in the source file, if given.
+    pub line: Option<usize>,
+}
+
+impl Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{}:{}", self.path, line),
+            None => f.write_str(&self.path),
+        }
+    }
+}
+
+/// A structured provenance comment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Provenance {
+    /// `# generated-by: <generator>` opens a generated region.
+    GeneratedBy {
+        /// The generating tool, usually with its version.
+        generator: String,
+    },
+    /// `# generated-hash: sha256:<hex>` records the region's content hash.
+    GeneratedHash {
+        /// The recorded hash, including the `sha256:` prefix.
+        hash: String,
+    },
+    /// `# source: <path>[:<line>]` names the source of the following rules.
+    Source(SourceLocation),
+    /// `# end-generated` closes a generated region.
+    EndGenerated,
+}
+
+impl Provenance {
+    /// Parses comment content (without the leading `#`) as a provenance comment.
+    pub fn parse(content: &str) -> Option<Self> {
+        let content = content.trim();
+        if content == "end-generated" {
+            return Some(Self::EndGenerated);
+        }
+
+        let (key, value) = content.split_once(':')?;
+        let value = value.trim();
+        if value.is_empty() {
+            return None;
+        }
+
+        match key.trim() {
+            "generated-by" => Some(Self::GeneratedBy {
+                generator: value.to_string(),
+            }),
+            "generated-hash" => Some(Self::GeneratedHash {
+                hash: value.to_string(),
+            }),
+            "source" => {
+                let location = match value.rsplit_once(':') {
+                    Some((path, line)) if !path.is_empty() => match line.parse() {
+                        Ok(line) => SourceLocation {
+                            path: path.to_string(),
+                            line: Some(line),
+                        },
+                        Err(_) => SourceLocation {
+                            path: value.to_string(),
+                            line: None,
+                        },
+                    },
+                    _ => SourceLocation {
+                        path: value.to_string(),
+                        line: None,
+                    },
+                };
+                Some(Self::Source(location))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Display for Provenance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::GeneratedBy { generator } => write!(f, "# generated-by: {}", generator),
+            Self::GeneratedHash { hash } => write!(f, "# generated-hash: {}", hash),
+            Self::Source(location) => write!(f, "# source: {}", location),
+            Self::EndGenerated => f.write_str("# end-generated"),
+        }
+    }
+}
+
+/// A generated region of a CODEOWNERS file.
+///
+/// Line positions are indices into [`CodeownersFile::lines`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedRegion {
+    /// The generating tool named by the `generated-by` comment.
+    pub generator: String,
+    /// Index of the `generated-by` comment.
+    pub header: usize,
+    /// Index of the `generated-hash` comment, if present.
+    pub hash_line: Option<usize>,
+    /// The recorded hash, if present.
+    pub hash: Option<String>,
+    /// Index one past the last line of the region: the `end-generated`
+    /// comment, the next region's header, or the end of the file.
+    pub end: usize,
+}
+
+impl GeneratedRegion {
+    /// Returns the region content: every line after the header except the
+    /// hash comment.
+    pub fn content<'a>(&self, file: &'a CodeownersFile) -> impl Iterator<Item = &'a Line> {
+        let hash_line = self.hash_line;
+        file.lines[self.header + 1..self.end]
+            .iter()
+            .enumerate()
+            .filter(move |(offset, _)| Some(self.header + 1 + offset) != hash_line)
+            .map(|(_, line)| line)
+    }
+
+    /// Returns the span of the region, from its header to its
+    /// `end-generated` comment, or to its last line if it has none.
+    pub fn span(&self, file: &CodeownersFile) -> Span {
+        let header = file.lines[self.header].span;
+        let end_marker = file
+            .lines
+            .get(self.end)
+            .filter(|line| line.provenance() == Some(Provenance::EndGenerated));
+        match end_marker.or(file.lines[..self.end].last()) {
+            Some(last) => header.extend(&last.span),
+            None => header,
+        }
+    }
+
+    /// Computes the hash of the region content.
+    ///
+    /// Lines are hashed in their normalized form, so whitespace-only changes
+    /// do not count as edits.
+    pub fn compute_hash(&self, file: &CodeownersFile) -> String {
+        let mut hasher = Sha256::new();
+        for line in self.content(file) {
+            hasher.update(line.to_string().as_bytes());
+            hasher.update(b"\n");
+        }
+        format!("{}{:x}", HASH_PREFIX, hasher.finalize())
+    }
+
+    /// Returns whether the content no longer matches the recorded hash, or
+    /// `None` if no hash was recorded.
+    pub fn is_modified(&self, file: &CodeownersFile) -> Option<bool> {
+        let recorded = self.hash.as_deref()?;
+        Some(!recorded.eq_ignore_ascii_case(&self.compute_hash(file)))
+    }
+}
+
+impl Line {
+    /// Returns the provenance metadata of a comment line, if it has any.
+    pub fn provenance(&self) -> Option<Provenance> {
+        match &self.kind {
+            LineKind::Comment { content } => Provenance::parse(content),
+            _ => None,
+        }
+    }
+}
+
+impl CodeownersFile {
+    /// Returns the generated regions in the file, in order.
+    pub fn generated_regions(&self) -> Vec<GeneratedRegion> {
+        let mut regions: Vec<GeneratedRegion> = Vec::new();
+        let mut open: Option<GeneratedRegion> = None;
+
+        for (index, line) in self.lines.iter().enumerate() {
+            match line.provenance() {
+                Some(Provenance::GeneratedBy { generator }) => {
+                    if let Some(mut region) = open.take() {
+                        region.end = index;
+                        regions.push(region);
+                    }
+                    open = Some(GeneratedRegion {
+                        generator,
+                        header: index,
+                        hash_line: None,
+                        hash: None,
+                        end: self.lines.len(),
+                    });
+                }
+                Some(Provenance::GeneratedHash { hash }) => {
+                    if let Some(region) = open.as_mut()
+                        && region.hash.is_none()
+                    {
+                        region.hash_line = Some(index);
+                        region.hash = Some(hash);
+                    }
+                }
+                Some(Provenance::EndGenerated) => {
+                    if let Some(mut region) = open.take() {
+                        region.end = index;
+                        regions.push(region);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        regions.extend(open);
+        regions
+    }
+
+    /// Returns the `source` comment attached to the line at `index`.
+    ///
+    /// The source is taken from the comment block directly above the line.
+    pub fn source_of(&self, index: usize) -> Option<SourceLocation> {
+        self.lines[..index.min(self.lines.len())]
+            .iter()
+            .rev()
+            .take_while(|line| line.is_comment())
+            .find_map(|line| match line.provenance() {
+                Some(Provenance::Source(location)) => Some(location),
+                _ => None,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_codeowners;
+
+    const GENERATED: &str = "\
+* @default
+# generated-by: codeowners-gen v1.2
+# generated-hash: HASH
+# source: teams/payments.yml:12
+/payments/ @org/payments
+# source: teams/search.yml
+/search/ @org/search
+# end-generated
+/docs/ @docs
+";
+
+    fn with_hash(hash: &str) -> CodeownersFile {
+        parse_codeowners(&GENERATED.replace("HASH", hash)).ast
+    }
+
+    #[test]
+    fn parse_provenance_comments() {
+        assert_eq!(
+            Provenance::parse(" generated-by: codeowners-gen v1.2"),
+            Some(Provenance::GeneratedBy {
+                generator: "codeowners-gen v1.2".to_string()
+            })
+        );
+        assert_eq!(
+            Provenance::parse(" source: teams/payments.yml:12"),
+            Some(Provenance::Source(SourceLocation {
+                path: "teams/payments.yml".to_string(),
+                line: Some(12),
+            }))
+        );
+        assert_eq!(
+            Provenance::parse(" source: C:teams.yml"),
+            Some(Provenance::Source(SourceLocation {
+                path: "C:teams.yml".to_string(),
+                line: None,
+            }))
+        );
+        assert_eq!(
+            Provenance::parse(" end-generated "),
+            Some(Provenance::EndGenerated)
+        );
+        assert_eq!(Provenance::parse(" source:"), None);
+        assert_eq!(Provenance::parse(" regular comment"), None);
+    }
+
+    #[test]
+    fn generated_regions_and_sources() {
+        let file = with_hash("sha256:0");
+        let regions = file.generated_regions();
+
+        assert_eq!(regions.len(), 1);
+        assert_eq!(regions[0].generator, "codeowners-gen v1.2");
+        assert_eq!(regions[0].header, 1);
+        assert_eq!(regions[0].hash_line, Some(2));
+        assert_eq!(regions[0].end, 7);
+        assert_eq!(regions[0].content(&file).count(), 4);
+
+        assert_eq!(
+            file.source_of(4).map(|s| s.to_string()),
+            Some("teams/payments.yml:12".to_string())
+        );
+        assert_eq!(
+            file.source_of(6).map(|s| s.to_string()),
+            Some("teams/search.yml".to_string())
+        );
+        assert_eq!(file.source_of(8), None);
+    }
+
+    #[test]
+    fn detects_modified_region() {
+        let file = with_hash("sha256:0");
+        let hash = file.generated_regions()[0].compute_hash(&file);
+        assert!(hash.starts_with(HASH_PREFIX));
+
+        let file = with_hash(&hash);
+        assert_eq!(file.generated_regions()[0].is_modified(&file), Some(false));
+
+        let edited = with_hash(&hash)
+            .to_string()
+            .replace("@org/search", "@someone");
+        let edited = parse_codeowners(&edited).ast;
+        assert_eq!(
+            edited.generated_regions()[0].is_modified(&edited),
+            Some(true)
+        );
+
+        let unhashed = parse_codeowners("# generated-by: gen\n/a/ @a\n").ast;
+        assert_eq!(unhashed.generated_regions()[0].is_modified(&unhashed), None);
+    }
+}
@@ -1,14 +1,17 @@
 //! Span tracking for source location information.
 //!
 //! Provides a custom `Span` struct that tracks byte offset, line number, and column
-//! for precise error reporting in CODEOWNERS file parsing.
+//! for precise error reporting in CODEOWNERS file parsing. Spans may cross lines,
+//! e.g. to cover a whole generated region.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// Represents a location span in the source file.
 ///
-/// All positions are 1-based for human-readable error messages.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+/// All positions are 1-based for human-readable error messages. The end
+/// position is exclusive: `end_column` is the column just past the last
+/// character, as in SARIF and LSP ranges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Span {
     /// Byte offset from the start of the input (0-based).
     pub offset: usize,
@@ -18,19 +21,35 @@ pub struct Span {
     pub column: usize,
     /// Length of the span in bytes.
     pub length: usize,
+    /// Line number of the end of the span (1-based).
+    pub end_line: usize,
+    /// Column number just past the end of the span (1-based).
+    pub end_column: usize,
 }
 
 impl Span {
-    /// Creates a new span with the given position and length.
+    /// Creates a new single-line span with the given position and length.
     pub fn new(offset: usize, line: usize, column: usize, length: usize) -> Self {
         Self {
             offset,
             line,
             column,
             length,
+            end_line: line,
+            end_column: column + length,
         }
     }
 
+    /// Sets the end position of a span that crosses lines.
+    ///
+    /// The length is left unchanged, so it must already cover the bytes up
+    /// to the end position.
+    pub fn with_end(mut self, end_line: usize, end_column: usize) -> Self {
+        self.end_line = end_line;
+        self.end_column = end_column;
+        self
+    }
+
     /// Creates a zero-length span at the given position.
     pub fn point(offset: usize, line: usize, column: usize) -> Self {
         Self::new(offset, line, column, 0)
@@ -41,7 +60,13 @@ impl Span {
         self.offset + self.length
     }
 
-    /// Extends this span to include another span.
+    /// Returns true if the span ends on a later line than it starts.
+    pub fn is_multiline(&self) -> bool {
+        self.end_line > self.line
+    }
+
+    /// Extends this span to include another span, which may start on a
+    /// later line.
     pub fn extend(&self, other: &Span) -> Span {
         let end = other.offset + other.length;
         Span {
@@ -49,6 +74,8 @@ impl Span {
             line: self.line,
             column: self.column,
             length: end.saturating_sub(self.offset),
+            end_line: other.end_line,
+            end_column: other.end_column,
         }
     }
 }
@@ -63,6 +90,7 @@ impl Default for Span {
 ///
 /// This struct wraps a string slice and maintains current position information
 /// for use with nom parsers.
+#[doc(hidden)]
 #[derive(Debug, Clone, Copy)]
 pub struct SpanTracker<'a> {
     /// The remaining input to parse.
@@ -102,8 +130,12 @@ impl<'a> SpanTracker<'a> {
     }
 
     /// Creates a span from the current position with the given length.
+    ///
+    /// The span's end position accounts for any newlines it covers.
     pub fn span_of(&self, length: usize) -> Span {
-        Span::new(self.offset, self.line, self.column, length)
+        let mut end = *self;
+        end.advance(length);
+        Span::new(self.offset, self.line, self.column, length).with_end(end.line, end.column)
     }
 
     /// Advances the tracker by the given number of bytes.
@@ -200,6 +232,19 @@ mod tests {
         assert_eq!(extended.line, 1);
         assert_eq!(extended.column, 1);
         assert_eq!(extended.length, 13); // 0 to 13
+        assert_eq!((extended.end_line, extended.end_column), (1, 14));
+        assert!(!extended.is_multiline());
+    }
+
+    #[test]
+    fn span_extend_across_lines() {
+        let first = Span::new(0, 1, 1, 5);
+        let last = Span::new(20, 3, 1, 4);
+        let extended = first.extend(&last);
+
+        assert_eq!(extended.length, 24);
+        assert_eq!((extended.end_line, extended.end_column), (3, 5));
+        assert!(extended.is_multiline());
     }
 
     #[test]
@@ -274,6 +319,19 @@ mod tests {
         assert_eq!(span.line, 2);
         assert_eq!(span.column, 1);
         assert_eq!(span.length, 5);
+        assert_eq!((span.end_line, span.end_column), (2, 6));
+    }
+
+    #[test]
+    fn tracker_span_of_multiple_lines() {
+        let tracker = SpanTracker::new(
+            "hello
+world",
+        );
+        let span = tracker.span_of(8);
+        assert_eq!((span.line, span.column), (1, 1));
+        assert_eq!((span.end_line, span.end_column), (2, 3));
+        assert!(span.is_multiline());
     }
 
     #[test]
@@ -0,0 +1,402 @@
+//! Unified diffs touching CODEOWNERS.
+//!
+//! Pull request bots see a change as a patch, not as a checkout. This module
+//! parses unified diffs (as produced by `git diff` or the GitHub API), finds
+//! the part that changes the CODEOWNERS file, and applies it to the base
+//! content, so the new file can be validated without checking out the
+//! repository. Findings then point at lines of the new file, and
+//! [`PatchedFile::changed_lines`] tells which of them the patch added.
+//!
+//! # Example
+//!
+//! ```rust
+//! use codeowners_validator_core::patch::{codeowners_patch, parse_patch};
+//! use codeowners_validator_core::{parse_codeowners, validate_syntax};
+//!
+//! let base = "* @org/core\n";
+//! let diff = "\
+//! --- a/.github/CODEOWNERS
+//! +++ b/.github/CODEOWNERS
+//! @@ -1 +1,2 @@
+//!  * @org/core
+//! +/docs/ docs-team
+//! ";
+//!
+//! let patches = parse_patch(diff).unwrap();
+//! let patched = codeowners_patch(&patches).unwrap().apply(base).unwrap();
+//! assert_eq!(patched.content, "* @org/core\n/docs/ docs-team\n");
+//! assert!(patched.is_changed(2));
+//!
+//! // The invalid owner is reported on the line the patch added
+//! let result = validate_syntax(&parse_codeowners(&patched.content).ast);
+//! assert!(patched.is_changed(result.errors[0].line()));
+//! ```
+
+use std::collections::BTreeSet;
+use thiserror::Error;
+
+/// Paths of the CODEOWNERS file in a repository, in the order GitHub looks
+/// for them.
+pub const CODEOWNERS_PATHS: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// Errors that can occur while parsing or applying a patch.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum PatchError {
+    /// The patch is not a valid unified diff.
+    #[error("line {line} of the patch: {message}")]
+    Malformed {
+        /// The line of the patch (1-based).
+        line: usize,
+        /// What is wrong with it.
+        message: String,
+    },
+
+    /// A hunk does not match the content it is applied to.
+    #[error("patch does not apply: line {line} of the base content differs from the hunk")]
+    ContextMismatch {
+        /// The line of the base content (1-based).
+        line: usize,
+    },
+}
+
+/// A line of a hunk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HunkLine {
+    /// A line kept by the patch.
+    Context(String),
+    /// A line the patch removes.
+    Removed(String),
+    /// A line the patch adds.
+    Added(String),
+}
+
+/// A hunk of a unified diff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hunk {
+    /// First line of the hunk in the old file (1-based), or the line after
+    /// which lines are inserted when `old_lines` is 0.
+    pub old_start: usize,
+    /// Number of lines of the old file the hunk covers.
+    pub old_lines: usize,
+    /// First line of the hunk in the new file (1-based).
+    pub new_start: usize,
+    /// Number of lines of the new file the hunk covers.
+    pub new_lines: usize,
+    /// The lines of the hunk.
+    pub lines: Vec<HunkLine>,
+    /// Whether the new file has no newline after the hunk's last line.
+    pub missing_newline: bool,
+}
+
+/// The changes a patch makes to one file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FilePatch {
+    /// Path of the file before the change, or `None` if it is created.
+    pub old_path: Option<String>,
+    /// Path of the file after the change, or `None` if it is deleted.
+    pub new_path: Option<String>,
+    /// The hunks, in file order.
+    pub hunks: Vec<Hunk>,
+}
+
+/// The result of applying a [`FilePatch`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PatchedFile {
+    /// The new content of the file.
+    pub content: String,
+    /// Lines of the new content the patch added or changed (1-based).
+    pub changed_lines: BTreeSet<usize>,
+}
+
+impl PatchedFile {
+    /// Returns true if the patch added or changed `line` (1-based).
+    pub fn is_changed(&self, line: usize) -> bool {
+        self.changed_lines.contains(&line)
+    }
+}
+
+impl FilePatch {
+    /// Returns the path of the file after the change, or before it if the
+    /// file is deleted.
+    pub fn path(&self) -> Option<&str> {
+        self.new_path.as_deref().or(self.old_path.as_deref())
+    }
+
+    /// Returns true if the file is a CODEOWNERS file GitHub reads.
+    pub fn is_codeowners(&self) -> bool {
+        self.path()
+            .is_some_and(|path| CODEOWNERS_PATHS.contains(&path))
+    }
+
+    /// Applies the hunks to `base`, the content of the file before the
+    /// change (empty for a created file).
+    ///
+    /// Hunks must apply exactly; line endings are not compared.
+    pub fn apply(&self, base: &str) -> Result<PatchedFile, PatchError> {
+        let old: Vec<&str> = base.lines().collect();
+        let mut lines: Vec<&str> = Vec::with_capacity(old.len());
+        let mut changed_lines = BTreeSet::new();
+        let mut trailing_newline = base.is_empty() || base.ends_with('\n');
+        let mut next = 0;
+
+        for hunk in &self.hunks {
+            let start = if hunk.old_lines == 0 {
+                hunk.old_start
+            } else {
+                hunk.old_start.saturating_sub(1)
+            };
+            if start < next || start > old.len() {
+                return Err(PatchError::ContextMismatch { line: start.max(1) });
+            }
+            lines.extend(&old[next..start]);
+            next = start;
+
+            for line in &hunk.lines {
+                match line {
+                    HunkLine::Context(text) | HunkLine::Removed(text) => {
+                        let same = old
+                            .get(next)
+                            .is_some_and(|old| old.trim_end_matches('\r') == text);
+                        if !same {
+                            return Err(PatchError::ContextMismatch { line: next + 1 });
+                        }
+                        if matches!(line, HunkLine::Context(_)) {
+                            lines.push(old[next]);
+                        }
+                        next += 1;
+                    }
+                    HunkLine::Added(text) => {
+                        lines.push(text);
+                        changed_lines.insert(lines.len());
+                    }
+                }
+            }
+
+            if next == old.len() {
+                trailing_newline = !hunk.missing_newline;
+            }
+        }
+        lines.extend(&old[next..]);
+
+        let mut content = lines.join("\n");
+        if trailing_newline && !lines.is_empty() {
+            content.push('\n');
+        }
+        Ok(PatchedFile {
+            content,
+            changed_lines,
+        })
+    }
+}
+
+/// Returns the patch of the CODEOWNERS file, if the diff changes it.
+pub fn codeowners_patch(patches: &[FilePatch]) -> Option<&FilePatch> {
+    patches.iter().find(|patch| patch.is_codeowners())
+}
+
+/// Parses a unified diff, which may change several files.
+///
+/// Lines outside of file headers and hunks, such as `diff --git` and
+/// `index` lines or a commit message, are ignored.
+pub fn parse_patch(patch: &str) -> Result<Vec<FilePatch>, PatchError> {
+    let mut files: Vec<FilePatch> = Vec::new();
+    let mut lines = patch.lines().enumerate().peekable();
+
+    while let Some((index, line)) = lines.next() {
+        if let Some(old) = line.strip_prefix("--- ") {
+            let Some((_, new)) = lines.next_if(|(_, line)| line.starts_with("+++ ")) else {
+                return Err(malformed(index + 2, "expected a '+++' line"));
+            };
+            files.push(FilePatch {
+                old_path: header_path(old),
+                new_path: header_path(&new[4..]),
+                hunks: Vec::new(),
+            });
+        } else if line.starts_with("@@") {
+            let Some(file) = files.last_mut() else {
+                return Err(malformed(index + 1, "hunk before any file header"));
+            };
+            let mut hunk = parse_hunk_header(line).ok_or_else(|| {
+                malformed(index + 1, "expected a hunk header like '@@ -1,2 +1,3 @@'")
+            })?;
+
+            let (mut old_left, mut new_left) = (hunk.old_lines, hunk.new_lines);
+            while old_left > 0 || new_left > 0 {
+                let Some((index, line)) = lines.next() else {
+                    return Err(malformed(index + 1, "hunk ends early"));
+                };
+                // Editors and mail clients often strip the space of empty
+                // context lines
+                let (marker, text) = match line.chars().next() {
+                    Some(marker) => (marker, &line[1..]),
+                    None => (' ', ""),
+                };
+                let (hunk_line, old_used, new_used) = match marker {
+                    ' ' => (HunkLine::Context(text.to_string()), 1, 1),
+                    '-' => (HunkLine::Removed(text.to_string()), 1, 0),
+                    '+' => (HunkLine::Added(text.to_string()), 0, 1),
+                    '\\' => continue,
+                    _ => return Err(malformed(index + 1, "expected ' ', '-' or '+'")),
+                };
+                if old_used > old_left || new_used > new_left {
+                    return Err(malformed(index + 1, "hunk is longer than its header says"));
+                }
+                old_left -= old_used;
+                new_left -= new_used;
+                hunk.lines.push(hunk_line);
+            }
+
+            // A marker after the last line says it has no newline
+            if lines.next_if(|(_, line)| line.starts_with('\\')).is_some() {
+                hunk.missing_newline = !matches!(hunk.lines.last(), Some(HunkLine::Removed(_)));
+            }
+            file.hunks.push(hunk);
+        }
+    }
+
+    Ok(files)
+}
+
+/// Creates a malformed patch error.
+fn malformed(line: usize, message: &str) -> PatchError {
+    PatchError::Malformed {
+        line,
+        message: message.to_string(),
+    }
+}
+
+/// Returns the path of a `---` or `+++` header, without its `a/` or `b/`
+/// prefix, or `None` for `/dev/null`.
+fn header_path(header: &str) -> Option<String> {
+    // Timestamps follow a tab in diffs made by `diff -u`
+    let path = header.split('\t').next().unwrap_or_default().trim_end();
+    if path == "/dev/null" {
+        return None;
+    }
+    let path = path
+        .strip_prefix("a/")
+        .or_else(|| path.strip_prefix("b/"))
+        .unwrap_or(path);
+    Some(path.to_string())
+}
+
+/// Parses a hunk header like `@@ -1,2 +1,3 @@ context`.
+fn parse_hunk_header(line: &str) -> Option<Hunk> {
+    let ranges = line.strip_prefix("@@ ")?.split(" @@").next()?;
+    let (old, new) = ranges.split_once(' ')?;
+    let (old_start, old_lines) = parse_range(old.strip_prefix('-')?)?;
+    let (new_start, new_lines) = parse_range(new.strip_prefix('+')?)?;
+    Some(Hunk {
+        old_start,
+        old_lines,
+        new_start,
+        new_lines,
+        lines: Vec::new(),
+        missing_newline: false,
+    })
+}
+
+/// Parses a hunk range like `1,2`, or `1` for a single line.
+fn parse_range(range: &str) -> Option<(usize, usize)> {
+    match range.split_once(',') {
+        Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+        None => Some((range.parse().ok()?, 1)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: &str = "# Owners\n* @org/core\n/docs/ @org/docs\n/src/ @org/rust\n";
+
+    const DIFF: &str = "\
+diff --git a/.github/CODEOWNERS b/.github/CODEOWNERS
+index 1234567..89abcde 100644
+--- a/.github/CODEOWNERS
++++ b/.github/CODEOWNERS
+@@ -2,2 +2,3 @@
+ * @org/core
+-/docs/ @org/docs
++/docs/ @org/docs @org/writers
++/api/ @org/api
+@@ -4 +5,2 @@ /docs/ @org/docs
+ /src/ @org/rust
++/src/ @org/rust
+diff --git a/README.md b/README.md
+--- a/README.md
++++ b/README.md
+@@ -1 +1 @@
+-old
++new
+";
+
+    #[test]
+    fn parses_files_and_hunks() {
+        let patches = parse_patch(DIFF).unwrap();
+        assert_eq!(patches.len(), 2);
+        assert_eq!(patches[0].path(), Some(".github/CODEOWNERS"));
+        assert_eq!(patches[0].hunks.len(), 2);
+        assert_eq!(
+            (patches[0].hunks[1].old_start, patches[0].hunks[1].old_lines),
+            (4, 1)
+        );
+        assert_eq!(
+            patches[1].hunks[0].lines,
+            [
+                HunkLine::Removed("old".to_string()),
+                HunkLine::Added("new".to_string())
+            ]
+        );
+        assert_eq!(codeowners_patch(&patches), Some(&patches[0]));
+        assert_eq!(codeowners_patch(&patches[1..]), None);
+    }
+
+    #[test]
+    fn applies_hunks_and_tracks_changed_lines() {
+        let patches = parse_patch(DIFF).unwrap();
+        let patched = patches[0].apply(BASE).unwrap();
+        assert_eq!(
+            patched.content,
+            "# Owners\n* @org/core\n/docs/ @org/docs @org/writers\n/api/ @org/api\n/src/ @org/rust\n/src/ @org/rust\n"
+        );
+        assert_eq!(
+            patched.changed_lines.into_iter().collect::<Vec<_>>(),
+            [3, 4, 6]
+        );
+    }
+
+    #[test]
+    fn creates_files_and_handles_missing_newlines() {
+        let diff = "--- /dev/null\n+++ b/CODEOWNERS\n@@ -0,0 +1,2 @@\n+* @a\n+/b/ @b\n\\ No newline at end of file\n";
+        let patches = parse_patch(diff).unwrap();
+        assert_eq!(patches[0].old_path, None);
+        assert!(patches[0].is_codeowners());
+        assert_eq!(patches[0].apply("").unwrap().content, "* @a\n/b/ @b");
+    }
+
+    #[test]
+    fn rejects_patches_that_do_not_apply() {
+        let patches = parse_patch(DIFF).unwrap();
+        assert_eq!(
+            patches[0].apply("# Owners\n* @org/other\n"),
+            Err(PatchError::ContextMismatch { line: 2 })
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_patches() {
+        assert!(matches!(
+            parse_patch("@@ -1 +1 @@\n-a\n+b\n"),
+            Err(PatchError::Malformed { line: 1, .. })
+        ));
+        assert!(matches!(
+            parse_patch("--- a/CODEOWNERS\n+++ b/CODEOWNERS\n@@ -1,2 +1,2 @@\n a\n"),
+            Err(PatchError::Malformed { line: 3, .. })
+        ));
+        assert!(matches!(
+            parse_patch("--- a/CODEOWNERS\n+++ b/CODEOWNERS\n@@ -1 +1 @@\n*a\n"),
+            Err(PatchError::Malformed { line: 4, .. })
+        ));
+    }
+}
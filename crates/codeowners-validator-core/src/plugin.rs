@@ -0,0 +1,687 @@
+//! Validation checks loaded from WASM plugins.
+//!
+//! Organizations can ship internal policy checks as WebAssembly modules
+//! instead of forking the crate. A plugin manifest lists them:
+//!
+//! ```json
+//! {
+//!   "plugins": [
+//!     { "name": "no-bots", "path": "plugins/no_bots.wasm", "severity": "warning" },
+//!     { "name": "vendored-owners", "path": "plugins/vendored.wasm", "files": true,
+//!       "settings": { "owner": "@acme/vendoring" } }
+//!   ]
+//! }
+//! ```
+//!
+//! Paths are relative to the manifest. Each plugin becomes a
+//! [`PluginCheck`], added to a [`CheckRunner`](crate::validate::checks::CheckRunner)
+//! like any other check and reporting its findings as `CO032` under its
+//! name.
+//!
+//! # Plugin interface
+//!
+//! A plugin receives one JSON document and returns another. The input
+//! holds the parsed CODEOWNERS file (the same AST as `--emit-ast`), the
+//! repository's files when the plugin sets `"files": true`, and the
+//! plugin's `settings`:
+//!
+//! ```json
+//! { "file": { "lines": [...] }, "files": ["src/main.rs"], "settings": null }
+//! ```
+//!
+//! The output lists findings; `severity` defaults to the plugin's:
+//!
+//! ```json
+//! { "findings": [{ "line": 3, "message": "bot owner '@deploy[bot]'", "severity": "error" }] }
+//! ```
+//!
+//! # Runtimes
+//!
+//! Modules are executed by a [`PluginRuntime`]. [`WasmRuntime`] runs them
+//! with the wasmi interpreter in a sandbox: no imports are provided, so no
+//! WASI capabilities (no file system or network access), memory is
+//! bounded, and each run has a fuel budget, so a misbehaving plugin fails
+//! its own check instead of the run. A module for it exports:
+//!
+//! - `memory`, its linear memory;
+//! - `alloc(len: i32) -> i32`, returning where to write an input of `len`
+//!   bytes;
+//! - `check(ptr: i32, len: i32) -> i64`, reading the input there and
+//!   returning where its output is, with the pointer in the upper 32 bits
+//!   and the length in the lower 32.
+//!
+//! Applications can implement [`PluginRuntime`] on another engine instead.
+
+use crate::parse::{CodeownersFile, Span};
+use crate::validate::checks::{Check, CheckContext, CheckDescriptor};
+use crate::validate::file_walker::{FileWalkerConfig, ListError};
+use crate::validate::{Severity, ValidationError, ValidationResult};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use thiserror::Error;
+use wasmi::{Engine, Linker, Module, Store, StoreLimits, StoreLimitsBuilder};
+
+/// The magic number every WebAssembly module starts with.
+const WASM_MAGIC: &[u8] = b"\0asm";
+
+/// Errors that can occur while loading or running plugins.
+#[derive(Debug, Error)]
+pub enum PluginError {
+    /// A manifest or module could not be read.
+    #[error("failed to read '{}': {source}", path.display())]
+    Io {
+        /// The path of the file.
+        path: PathBuf,
+        /// The underlying error.
+        source: std::io::Error,
+    },
+
+    /// A manifest, or a plugin's output, is not valid JSON of the expected
+    /// shape.
+    #[error("invalid plugin JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// A manifest has an invalid entry.
+    #[error("invalid plugin manifest: {0}")]
+    Manifest(String),
+
+    /// A plugin file is not a WebAssembly module.
+    #[error("'{}' is not a WebAssembly module", path.display())]
+    NotWasm {
+        /// The path of the file.
+        path: PathBuf,
+    },
+
+    /// The runtime failed to execute a plugin.
+    #[error("plugin failed: {0}")]
+    Runtime(String),
+
+    /// The repository files a plugin asked for could not be listed.
+    #[error(transparent)]
+    Listing(#[from] ListError),
+}
+
+/// A plugin listed in a manifest.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PluginSpec {
+    /// The name the plugin's check is reported under.
+    pub name: String,
+    /// The path of the WebAssembly module.
+    pub path: PathBuf,
+    /// The severity of findings that do not set one.
+    #[serde(default = "default_severity")]
+    pub severity: Severity,
+    /// Whether the plugin receives the list of repository files.
+    #[serde(default)]
+    pub files: bool,
+    /// Settings passed to the plugin as is.
+    #[serde(default)]
+    pub settings: Option<serde_json::Value>,
+}
+
+fn default_severity() -> Severity {
+    Severity::Error
+}
+
+/// The serialized form of a manifest.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ManifestFile {
+    plugins: Vec<PluginSpec>,
+}
+
+/// A list of plugins, read from a plugin manifest.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PluginManifest {
+    plugins: Vec<PluginSpec>,
+}
+
+impl PluginManifest {
+    /// Parses a JSON plugin manifest, resolving relative plugin paths
+    /// against `base_dir`.
+    pub fn from_json(content: &str, base_dir: &Path) -> Result<Self, PluginError> {
+        let mut file: ManifestFile = serde_json::from_str(content)?;
+        for (index, plugin) in file.plugins.iter_mut().enumerate() {
+            let number = index + 1;
+            if plugin.name.is_empty() {
+                return Err(PluginError::Manifest(format!(
+                    "plugin {} has no name",
+                    number
+                )));
+            }
+            if !plugin
+                .name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-')
+            {
+                return Err(PluginError::Manifest(format!(
+                    "plugin {} has an invalid name '{}', expected letters, digits and '-'",
+                    number, plugin.name
+                )));
+            }
+            plugin.path = base_dir.join(&plugin.path);
+        }
+        Ok(Self {
+            plugins: file.plugins,
+        })
+    }
+
+    /// Reads a JSON plugin manifest, resolving plugin paths against its
+    /// directory.
+    pub fn from_path(path: &Path) -> Result<Self, PluginError> {
+        let content = std::fs::read_to_string(path).map_err(|source| PluginError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::from_json(&content, path.parent().unwrap_or(Path::new("")))
+    }
+
+    /// Returns the plugins, in manifest order.
+    pub fn plugins(&self) -> &[PluginSpec] {
+        &self.plugins
+    }
+
+    /// Reads every plugin's module and creates its check.
+    pub fn load(&self, runtime: Arc<dyn PluginRuntime>) -> Result<Vec<PluginCheck>, PluginError> {
+        self.plugins
+            .iter()
+            .map(|spec| PluginCheck::load(spec.clone(), Arc::clone(&runtime)))
+            .collect()
+    }
+}
+
+/// Executes WebAssembly plugins.
+///
+/// Implementations instantiate `module` in a sandbox, pass it `input`, and
+/// return the JSON it produces; how the document crosses the module
+/// boundary is up to the runtime and the plugins written for it.
+pub trait PluginRuntime: Send + Sync {
+    /// Runs a plugin module on a JSON input and returns its JSON output.
+    fn run(&self, module: &[u8], input: &str) -> Result<String, PluginError>;
+}
+
+/// The fuel a plugin run may consume by default; executing an instruction
+/// costs roughly one unit.
+pub const DEFAULT_FUEL: u64 = 1_000_000_000;
+
+/// The memory a plugin may use by default, in bytes.
+pub const DEFAULT_MEMORY_LIMIT: usize = 64 * 1024 * 1024;
+
+/// Runs plugins with the wasmi interpreter, following the interface
+/// described in the [module documentation](self#runtimes).
+///
+/// Every run gets a fresh instance with no imports, at most
+/// [`WasmRuntime::with_memory_limit`] bytes of memory and
+/// [`WasmRuntime::with_fuel`] fuel; a module that imports anything, grows
+/// its memory past the limit or runs out of fuel fails.
+#[derive(Debug, Clone)]
+pub struct WasmRuntime {
+    engine: Engine,
+    fuel: u64,
+    memory_limit: usize,
+}
+
+impl Default for WasmRuntime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WasmRuntime {
+    /// Creates a runtime with the default fuel and memory limits.
+    pub fn new() -> Self {
+        let mut config = wasmi::Config::default();
+        config.consume_fuel(true);
+        Self {
+            engine: Engine::new(&config),
+            fuel: DEFAULT_FUEL,
+            memory_limit: DEFAULT_MEMORY_LIMIT,
+        }
+    }
+
+    /// Sets the fuel each run may consume.
+    pub fn with_fuel(mut self, fuel: u64) -> Self {
+        self.fuel = fuel;
+        self
+    }
+
+    /// Sets the memory each run may use, in bytes.
+    pub fn with_memory_limit(mut self, bytes: usize) -> Self {
+        self.memory_limit = bytes;
+        self
+    }
+
+    fn try_run(&self, module: &[u8], input: &str) -> Result<String, wasmi::Error> {
+        let module = Module::new(&self.engine, module)?;
+        let limits = StoreLimitsBuilder::new()
+            .memory_size(self.memory_limit)
+            .instances(1)
+            .trap_on_grow_failure(true)
+            .build();
+        let mut store = Store::new(&self.engine, limits);
+        store.limiter(|limits: &mut StoreLimits| limits);
+        store.set_fuel(self.fuel)?;
+
+        // Nothing is defined in the linker, so modules importing anything,
+        // WASI included, fail to instantiate
+        let linker = Linker::<StoreLimits>::new(&self.engine);
+        let instance = linker.instantiate(&mut store, &module)?.start(&mut store)?;
+        let memory = instance
+            .get_memory(&store, "memory")
+            .ok_or_else(|| wasmi::Error::new("plugin does not export 'memory'"))?;
+        let alloc = instance.get_typed_func::<i32, i32>(&store, "alloc")?;
+        let check = instance.get_typed_func::<(i32, i32), i64>(&store, "check")?;
+
+        let len = i32::try_from(input.len())
+            .map_err(|_| wasmi::Error::new("plugin input is larger than 2 GiB"))?;
+        let ptr = alloc.call(&mut store, len)?;
+        memory.write(&mut store, ptr as u32 as usize, input.as_bytes())?;
+        let packed = check.call(&mut store, (ptr, len))? as u64;
+
+        // The plugin chooses the length, so it is checked against its memory
+        // before the host allocates anything
+        let (out_ptr, out_len) = ((packed >> 32) as usize, (packed & 0xffff_ffff) as usize);
+        let in_bounds = out_ptr
+            .checked_add(out_len)
+            .is_some_and(|end| end <= memory.data(&store).len());
+        if !in_bounds || out_len > self.memory_limit {
+            return Err(wasmi::Error::new(format!(
+                "plugin output of {} bytes at {} is outside its memory",
+                out_len, out_ptr
+            )));
+        }
+        let mut output = vec![0; out_len];
+        memory.read(&store, out_ptr, &mut output)?;
+        String::from_utf8(output).map_err(|_| wasmi::Error::new("plugin output is not UTF-8"))
+    }
+}
+
+impl PluginRuntime for WasmRuntime {
+    fn run(&self, module: &[u8], input: &str) -> Result<String, PluginError> {
+        self.try_run(module, input)
+            .map_err(|e| PluginError::Runtime(e.to_string()))
+    }
+}
+
+/// The document passed to a plugin.
+#[derive(Serialize)]
+struct PluginInput<'a> {
+    file: &'a CodeownersFile,
+    files: &'a [String],
+    settings: Option<&'a serde_json::Value>,
+}
+
+/// A finding reported by a plugin.
+#[derive(Deserialize)]
+struct PluginFinding {
+    line: usize,
+    message: String,
+    #[serde(default)]
+    severity: Option<Severity>,
+}
+
+/// The document a plugin returns.
+#[derive(Deserialize)]
+struct PluginOutput {
+    findings: Vec<PluginFinding>,
+}
+
+/// A check that runs a WebAssembly plugin.
+///
+/// Findings point at the whole line they name, or at the end of the file
+/// when the line does not exist. A plugin that fails, or returns invalid
+/// output, leaves its check errored.
+pub struct PluginCheck {
+    spec: PluginSpec,
+    module: Vec<u8>,
+    runtime: Arc<dyn PluginRuntime>,
+}
+
+impl std::fmt::Debug for PluginCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PluginCheck")
+            .field("spec", &self.spec)
+            .field("module", &format_args!("<{} bytes>", self.module.len()))
+            .field("runtime", &"<dyn PluginRuntime>")
+            .finish()
+    }
+}
+
+impl PluginCheck {
+    /// Creates a check from a plugin's module bytes.
+    pub fn new(
+        spec: PluginSpec,
+        module: Vec<u8>,
+        runtime: Arc<dyn PluginRuntime>,
+    ) -> Result<Self, PluginError> {
+        if !module.starts_with(WASM_MAGIC) {
+            return Err(PluginError::NotWasm { path: spec.path });
+        }
+        Ok(Self {
+            spec,
+            module,
+            runtime,
+        })
+    }
+
+    /// Reads a plugin's module from its path and creates its check.
+    pub fn load(spec: PluginSpec, runtime: Arc<dyn PluginRuntime>) -> Result<Self, PluginError> {
+        let module = std::fs::read(&spec.path).map_err(|source| PluginError::Io {
+            path: spec.path.clone(),
+            source,
+        })?;
+        Self::new(spec, module, runtime)
+    }
+
+    /// Runs the plugin and converts its findings.
+    fn try_run(&self, ctx: &CheckContext) -> Result<ValidationResult, PluginError> {
+        let files = if self.spec.files {
+            ctx.list_files(
+                &ctx.config.walker_config(
+                    FileWalkerConfig::for_not_owned_check()
+                        .with_force_respect_gitignore(ctx.config.force_respect_gitignore),
+                ),
+            )?
+        } else {
+            Default::default()
+        };
+        let settings = ctx
+            .config
+            .settings_for(&self.spec.name)
+            .or(self.spec.settings.as_ref());
+        let input = serde_json::to_string(&PluginInput {
+            file: ctx.file,
+            files: &files,
+            settings,
+        })?;
+
+        let output: PluginOutput = serde_json::from_str(&self.runtime.run(&self.module, &input)?)?;
+
+        let mut result = ValidationResult::new();
+        for finding in output.findings {
+            result.add_error(ValidationError::custom_check_finding(
+                &self.spec.name,
+                finding.message,
+                finding.severity.unwrap_or(self.spec.severity),
+                line_span(ctx.file, finding.line),
+            ));
+        }
+        Ok(result)
+    }
+}
+
+/// Returns the span of a whole line, or a point at the end of the file if
+/// the line does not exist.
+fn line_span(file: &CodeownersFile, line: usize) -> Span {
+    match file.lines.iter().find(|l| l.span.line == line) {
+        Some(l) => l.span,
+        None => file.lines.last().map_or_else(Span::default, |last| {
+            Span::point(
+                last.span.offset + last.span.length,
+                last.span.line,
+                last.span.column + last.span.length,
+            )
+        }),
+    }
+}
+
+impl Check for PluginCheck {
+    fn name(&self) -> &str {
+        &self.spec.name
+    }
+
+    fn descriptor(&self) -> CheckDescriptor {
+        CheckDescriptor::new(self.spec.name.clone()).with_default_severity(self.spec.severity)
+    }
+
+    fn run(&self, ctx: &CheckContext) -> ValidationResult {
+        self.try_run(ctx).unwrap_or_else(|e| {
+            let mut result = ValidationResult::new();
+            result.set_internal_error(format!("plugin '{}': {}", self.spec.name, e));
+            result
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_codeowners;
+    use crate::validate::checks::CheckConfig;
+    use std::fs;
+    use tempfile::TempDir;
+
+    /// A runtime that ignores the module and returns a fixed output, after
+    /// checking the input it was given.
+    struct FakeRuntime {
+        output: Result<&'static str, &'static str>,
+    }
+
+    impl PluginRuntime for FakeRuntime {
+        fn run(&self, module: &[u8], input: &str) -> Result<String, PluginError> {
+            assert!(module.starts_with(WASM_MAGIC));
+            let input: serde_json::Value = serde_json::from_str(input).unwrap();
+            assert!(input["file"]["lines"].is_array());
+            assert_eq!(input["settings"]["max"], 1);
+            self.output
+                .map(str::to_string)
+                .map_err(|e| PluginError::Runtime(e.to_string()))
+        }
+    }
+
+    fn spec() -> PluginSpec {
+        PluginSpec {
+            name: "no-bots".to_string(),
+            path: PathBuf::from("no_bots.wasm"),
+            severity: Severity::Warning,
+            files: false,
+            settings: Some(serde_json::json!({ "max": 1 })),
+        }
+    }
+
+    fn run_plugin(output: Result<&'static str, &'static str>) -> ValidationResult {
+        let runtime = Arc::new(FakeRuntime { output });
+        let check = PluginCheck::new(spec(), b"\0asm\x01\0\0\0".to_vec(), runtime).unwrap();
+        let file = parse_codeowners("* @org/core\n/deploy/ @deploy[bot]\n").ast;
+        let config = CheckConfig::new();
+        let ctx = CheckContext::new(&file, Path::new("/repo"), &config);
+        check.run(&ctx)
+    }
+
+    #[test]
+    fn parses_manifest() {
+        let manifest = PluginManifest::from_json(
+            r#"{"plugins": [
+                {"name": "no-bots", "path": "plugins/no_bots.wasm", "severity": "warning"},
+                {"name": "vendored", "path": "/opt/vendored.wasm", "files": true}
+            ]}"#,
+            Path::new("/repo"),
+        )
+        .unwrap();
+
+        let plugins = manifest.plugins();
+        assert_eq!(plugins.len(), 2);
+        assert_eq!(plugins[0].path, Path::new("/repo/plugins/no_bots.wasm"));
+        assert_eq!(plugins[0].severity, Severity::Warning);
+        assert_eq!(plugins[1].path, Path::new("/opt/vendored.wasm"));
+        assert_eq!(plugins[1].severity, Severity::Error);
+        assert!(plugins[1].files);
+    }
+
+    #[test]
+    fn rejects_invalid_manifests() {
+        for (content, message) in [
+            (
+                r#"{"plugins": [{"name": "", "path": "a.wasm"}]}"#,
+                "plugin 1 has no name",
+            ),
+            (
+                r#"{"plugins": [{"name": "no bots", "path": "a.wasm"}]}"#,
+                "invalid name 'no bots'",
+            ),
+            (r#"{"plugins": [{"name": "a"}]}"#, "missing field `path`"),
+        ] {
+            let error = PluginManifest::from_json(content, Path::new("")).unwrap_err();
+            assert!(error.to_string().contains(message), "{error}");
+        }
+    }
+
+    #[test]
+    fn loads_only_wasm_modules() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("no_bots.wasm"), b"\0asm\x01\0\0\0").unwrap();
+        fs::write(dir.path().join("script.sh"), b"#!/bin/sh\n").unwrap();
+        let runtime: Arc<dyn PluginRuntime> = Arc::new(FakeRuntime { output: Ok("") });
+
+        let manifest = PluginManifest::from_json(
+            r#"{"plugins": [{"name": "no-bots", "path": "no_bots.wasm"}]}"#,
+            dir.path(),
+        )
+        .unwrap();
+        let checks = manifest.load(Arc::clone(&runtime)).unwrap();
+        assert_eq!(checks[0].name(), "no-bots");
+
+        let manifest = PluginManifest::from_json(
+            r#"{"plugins": [{"name": "script", "path": "script.sh"}]}"#,
+            dir.path(),
+        )
+        .unwrap();
+        assert!(matches!(
+            manifest.load(Arc::clone(&runtime)),
+            Err(PluginError::NotWasm { .. })
+        ));
+
+        let manifest = PluginManifest::from_json(
+            r#"{"plugins": [{"name": "missing", "path": "missing.wasm"}]}"#,
+            dir.path(),
+        )
+        .unwrap();
+        assert!(matches!(
+            manifest.load(runtime),
+            Err(PluginError::Io { .. })
+        ));
+    }
+
+    #[test]
+    fn reports_plugin_findings() {
+        let result = run_plugin(Ok(r#"{"findings": [
+            {"line": 2, "message": "bot owner '@deploy[bot]'"},
+            {"line": 9, "message": "too many bots", "severity": "error"}
+        ]}"#));
+
+        assert_eq!(result.errors.len(), 2);
+        let error = &result.errors[0];
+        assert_eq!(error.code(), "CO032");
+        assert_eq!(error.severity(), Severity::Warning);
+        assert_eq!(error.to_string(), "line 2: bot owner '@deploy[bot]'");
+        assert_eq!(error.span().column, 1);
+
+        // Lines past the end point at the end of the file
+        assert_eq!(result.errors[1].severity(), Severity::Error);
+        assert_eq!(result.errors[1].line(), 2);
+    }
+
+    /// Compiles a plugin that returns its input, after running `body`.
+    fn echo_plugin(memory_pages: u32, body: &str) -> Vec<u8> {
+        wat::parse_str(format!(
+            r#"(module
+                (memory (export "memory") {memory_pages})
+                (func (export "alloc") (param i32) (result i32) (i32.const 16))
+                (func (export "check") (param $ptr i32) (param $len i32) (result i64)
+                    {body}
+                    (i64.or
+                        (i64.shl (i64.extend_i32_u (local.get $ptr)) (i64.const 32))
+                        (i64.extend_i32_u (local.get $len)))))"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn wasm_runtime_runs_plugins() {
+        let runtime = WasmRuntime::new();
+        let output = runtime.run(&echo_plugin(1, ""), r#"{"findings": []}"#);
+        assert_eq!(output.unwrap(), r#"{"findings": []}"#);
+
+        let findings = r#"{"findings": [{"line": 1, "message": "from wasm"}]}"#;
+        let module = wat::parse_str(format!(
+            r#"(module
+                (memory (export "memory") 1)
+                (data (i32.const 0) "{}")
+                (func (export "alloc") (param i32) (result i32) (i32.const 1024))
+                (func (export "check") (param i32 i32) (result i64) (i64.const {})))"#,
+            findings.replace('"', "\\\""),
+            findings.len()
+        ))
+        .unwrap();
+        let check = PluginCheck::new(spec(), module, Arc::new(runtime)).unwrap();
+        let file = parse_codeowners("* @org/core\n").ast;
+        let config = CheckConfig::new();
+        let result = check.run(&CheckContext::new(&file, Path::new("/repo"), &config));
+        assert!(
+            result.internal_error.is_none(),
+            "{:?}",
+            result.internal_error
+        );
+        assert_eq!(result.errors[0].to_string(), "line 1: from wasm");
+    }
+
+    #[test]
+    fn wasm_runtime_sandboxes_plugins() {
+        let runtime = WasmRuntime::new().with_fuel(10_000);
+        let error = |module: Vec<u8>| runtime.run(&module, "{}").unwrap_err().to_string();
+
+        // Runs out of fuel instead of hanging
+        assert!(error(echo_plugin(1, "(loop $spin (br $spin))")).contains("fuel"));
+
+        // No WASI, or any other host functions, to import
+        let wasi = wat::parse_str(
+            r#"(module
+                (import "wasi_snapshot_preview1" "fd_write"
+                    (func (param i32 i32 i32 i32) (result i32)))
+                (memory (export "memory") 1))"#,
+        )
+        .unwrap();
+        assert!(error(wasi).contains("wasi_snapshot_preview1"));
+
+        // Memory is capped, both up front and when growing
+        let runtime = WasmRuntime::new().with_memory_limit(4 * 65536);
+        assert!(runtime.run(&echo_plugin(8, ""), "{}").is_err());
+        let grow = echo_plugin(1, "(drop (memory.grow (i32.const 8)))");
+        assert!(runtime.run(&grow, "{}").is_err());
+        assert!(runtime.run(&echo_plugin(4, ""), "{}").is_ok());
+
+        // Missing exports are reported
+        let empty = wat::parse_str("(module)").unwrap();
+        assert!(error(empty).contains("memory"));
+
+        // Output lengths are checked before the host allocates them
+        let huge = wat::parse_str(
+            r#"(module
+                (memory (export "memory") 1)
+                (func (export "alloc") (param i32) (result i32) (i32.const 16))
+                (func (export "check") (param i32 i32) (result i64) (i64.const 0xffffffff)))"#,
+        )
+        .unwrap();
+        assert_eq!(
+            error(huge),
+            "plugin failed: plugin output of 4294967295 bytes at 0 is outside its memory"
+        );
+    }
+
+    #[test]
+    fn plugin_failures_error_the_check() {
+        let result = run_plugin(Err("out of fuel"));
+        assert!(result.errors.is_empty());
+        assert!(
+            result
+                .internal_error
+                .as_deref()
+                .is_some_and(|e| e.contains("plugin 'no-bots': plugin failed: out of fuel"))
+        );
+
+        let result = run_plugin(Ok("not json"));
+        assert!(result.internal_error.is_some());
+    }
+}
@@ -0,0 +1,46 @@
+//! The stable public API of this crate.
+//!
+//! Items re-exported here are covered by semantic versioning: they will not
+//! change incompatibly outside a major release. Other public items may still
+//! evolve between minor releases while larger features land, so downstream
+//! crates should prefer importing from the prelude.
+//!
+//! Enums re-exported here, such as [`ValidationError`] and [`LineKind`], are
+//! `#[non_exhaustive]`: new variants may be added in minor releases, so
+//! matches on them need a wildcard arm.
+//!
+//! # Example
+//!
+//! ```rust
+//! use codeowners_validator_core::prelude::*;
+//!
+//! let parsed = parse_codeowners("*.rs @rustacean\n");
+//! let file: &CodeownersFile = &parsed.ast;
+//!
+//! let result: ValidationResult = validate_syntax(file);
+//! for error in &result.errors {
+//!     let span: &Span = error.span();
+//!     println!("{}:{} {}", span.line, span.column, error);
+//! }
+//! ```
+
+pub use crate::find_codeowners_file;
+pub use crate::matching::{PatternSyntax, QuestionMark};
+pub use crate::parse::{
+    CodeownersFile, Dialect, Line, LineKind, Owner, ParseError, ParseResult, ParserConfig, Pattern,
+    RecoveryStrategy, Span, parse_codeowners, parse_codeowners_with_config,
+};
+pub use crate::validate::checks::{
+    AsyncCheck, AsyncCheckContext, Check, CheckConfig, CheckContext, CheckResults, CheckRunner,
+};
+pub use crate::validate::file_walker::{
+    FileListing, FileWalkerConfig, ListError, NonUtf8Policy, SymlinkPolicy, list_files,
+    try_list_files,
+};
+pub use crate::validate::github_client::{
+    CachingGithubClient, GithubClient, GithubClientError, TeamExistsResult, UserExistsResult,
+};
+pub use crate::validate::{
+    Fix, Severity, UnsupportedSyntax, ValidationEngine, ValidationError, ValidationResult,
+    apply_fixes, validate_syntax,
+};
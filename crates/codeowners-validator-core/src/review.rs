@@ -0,0 +1,291 @@
+//! Code owner review requirements for pull requests.
+//!
+//! With branch protection requiring code owner review, every changed file
+//! needs an approval from one of the owners of the rule that owns it. Merge
+//! bots can use [`required_owners`] to predict which approvals a pull request
+//! needs, and [`OwnerSet::without_author`] to apply GitHub's rule that an
+//! author's own approval does not count.
+//!
+//! Team membership is not known offline: a team requirement is reported as
+//! satisfiable, and an approval counts for a team only if the team itself is
+//! listed among the approvers.
+//!
+//! # Example
+//!
+//! ```rust
+//! use codeowners_validator_core::parse::parse_codeowners;
+//! use codeowners_validator_core::review::required_owners;
+//!
+//! let file = parse_codeowners("* @org/core\n/docs/ @alice\n").ast;
+//! let required = required_owners(["src/lib.rs", "docs/guide.md"], &file);
+//! assert_eq!(required.requirements().len(), 2);
+//!
+//! // Alice cannot approve her own pull request
+//! let required = required.without_author("alice");
+//! assert_eq!(required.unsatisfiable().count(), 1);
+//! assert!(!required.is_satisfied_by(["@org/core", "@alice"]));
+//! ```
+
+use crate::matching::{OwnersResolver, normalize_repo_path};
+use crate::parse::CodeownersFile;
+use std::collections::BTreeSet;
+
+/// Owners of which one must approve changes to some paths.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnerRequirement {
+    /// Owners, in the order they are written in the rule. An approval from
+    /// any of them satisfies the requirement.
+    pub owners: Vec<String>,
+    /// Changed paths with these owners, in the order given.
+    pub paths: Vec<String>,
+}
+
+impl OwnerRequirement {
+    /// Returns true if an approval from `owner` satisfies the requirement.
+    ///
+    /// Owners are compared case-insensitively.
+    pub fn accepts(&self, owner: &str) -> bool {
+        self.owners
+            .iter()
+            .any(|candidate| candidate.eq_ignore_ascii_case(owner))
+    }
+
+    /// Returns true if no remaining owner can approve.
+    pub fn is_unsatisfiable(&self) -> bool {
+        self.owners.is_empty()
+    }
+}
+
+/// The code owner approvals required by a set of changed paths.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OwnerSet {
+    /// One requirement per distinct owner list, in order of first appearance.
+    requirements: Vec<OwnerRequirement>,
+    /// Changed paths that need no code owner approval.
+    unowned: Vec<String>,
+}
+
+impl OwnerSet {
+    /// Returns the requirements, one per distinct list of owners.
+    pub fn requirements(&self) -> &[OwnerRequirement] {
+        &self.requirements
+    }
+
+    /// Returns the changed paths that no rule assigns owners to.
+    pub fn unowned_paths(&self) -> &[String] {
+        &self.unowned
+    }
+
+    /// Returns every owner that can satisfy at least one requirement, sorted
+    /// case-insensitively.
+    pub fn owners(&self) -> BTreeSet<String> {
+        self.requirements
+            .iter()
+            .flat_map(|requirement| requirement.owners.iter())
+            .map(|owner| owner.to_lowercase())
+            .collect()
+    }
+
+    /// Returns true if no code owner approval is required.
+    pub fn is_empty(&self) -> bool {
+        self.requirements.is_empty()
+    }
+
+    /// Removes the pull request author from every requirement.
+    ///
+    /// GitHub does not count an author's approval of their own pull request,
+    /// so requirements only the author could satisfy become unsatisfiable.
+    /// The author may be given with or without a leading `@`.
+    pub fn without_author(&self, author: &str) -> Self {
+        let author = format!("@{}", author.trim_start_matches('@'));
+        let requirements = self
+            .requirements
+            .iter()
+            .map(|requirement| OwnerRequirement {
+                owners: requirement
+                    .owners
+                    .iter()
+                    .filter(|owner| !owner.eq_ignore_ascii_case(&author))
+                    .cloned()
+                    .collect(),
+                paths: requirement.paths.clone(),
+            })
+            .collect();
+
+        Self {
+            requirements,
+            unowned: self.unowned.clone(),
+        }
+    }
+
+    /// Returns the requirements that no remaining owner can approve.
+    pub fn unsatisfiable(&self) -> impl Iterator<Item = &OwnerRequirement> {
+        self.requirements
+            .iter()
+            .filter(|requirement| requirement.is_unsatisfiable())
+    }
+
+    /// Returns the requirements none of `approvers` satisfies.
+    ///
+    /// Approvers are owners in their written form, e.g. `@user` or
+    /// `@org/team`; pass a team to count an approval by one of its members.
+    pub fn missing<'s, 'a>(
+        &'s self,
+        approvers: impl IntoIterator<Item = &'a str>,
+    ) -> Vec<&'s OwnerRequirement> {
+        let approvers: Vec<&str> = approvers.into_iter().collect();
+        self.requirements
+            .iter()
+            .filter(|requirement| {
+                !approvers
+                    .iter()
+                    .any(|approver| requirement.accepts(approver))
+            })
+            .collect()
+    }
+
+    /// Returns true if `approvers` satisfy every requirement.
+    pub fn is_satisfied_by<'a>(&self, approvers: impl IntoIterator<Item = &'a str>) -> bool {
+        self.missing(approvers).is_empty()
+    }
+}
+
+/// Computes the code owner approvals required by a list of changed paths.
+///
+/// Each path is owned by the last matching rule. Paths owned by rules with
+/// the same owners share one requirement, since one approval covers them.
+/// Paths without owners need no code owner approval.
+pub fn required_owners<'p>(
+    changed_paths: impl IntoIterator<Item = &'p str>,
+    file: &CodeownersFile,
+) -> OwnerSet {
+    let resolver = OwnersResolver::from_file(file);
+    let mut set = OwnerSet::default();
+    let mut keys: Vec<Vec<String>> = Vec::new();
+
+    for path in changed_paths {
+        let path = normalize_repo_path(path);
+        let owners = match resolver.owners_for(&path) {
+            Some(owners) if !owners.is_empty() => owners,
+            _ => {
+                set.unowned.push(path.to_string());
+                continue;
+            }
+        };
+
+        let mut key: Vec<String> = owners.iter().map(|owner| owner.normalized()).collect();
+        key.sort_unstable();
+        key.dedup();
+
+        match keys.iter().position(|existing| *existing == key) {
+            Some(index) => set.requirements[index].paths.push(path.to_string()),
+            None => {
+                keys.push(key);
+                let mut written: Vec<String> = Vec::new();
+                for owner in owners {
+                    let owner = owner.as_str();
+                    if !written.iter().any(|w| w.eq_ignore_ascii_case(&owner)) {
+                        written.push(owner.into_owned());
+                    }
+                }
+                set.requirements.push(OwnerRequirement {
+                    owners: written,
+                    paths: vec![path.to_string()],
+                });
+            }
+        }
+    }
+
+    set
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{ParserConfig, parse_codeowners, parse_codeowners_with_config};
+
+    const CODEOWNERS: &str = "\
+* @org/core
+*.md @alice @org/docs
+/vendor/
+/docs/ @org/docs @Alice
+/private/ @bob
+";
+
+    fn file() -> CodeownersFile {
+        parse_codeowners_with_config(
+            CODEOWNERS,
+            &ParserConfig::new().with_allow_unowned_patterns(true),
+        )
+        .ast
+    }
+
+    #[test]
+    fn groups_paths_by_owners() {
+        let required = required_owners(
+            [
+                "src/lib.rs",
+                "README.md",
+                "/docs/guide.md",
+                "vendor/lib.c",
+                "src/main.rs",
+            ],
+            &file(),
+        );
+
+        let requirements: Vec<_> = required
+            .requirements()
+            .iter()
+            .map(|r| (r.owners.clone(), r.paths.clone()))
+            .collect();
+        assert_eq!(
+            requirements,
+            [
+                (
+                    vec!["@org/core".to_string()],
+                    vec!["src/lib.rs".to_string(), "src/main.rs".to_string()]
+                ),
+                (
+                    vec!["@alice".to_string(), "@org/docs".to_string()],
+                    vec!["README.md".to_string(), "docs/guide.md".to_string()]
+                ),
+            ]
+        );
+        assert_eq!(required.unowned_paths(), ["vendor/lib.c"]);
+        assert_eq!(
+            required.owners().into_iter().collect::<Vec<_>>(),
+            ["@alice", "@org/core", "@org/docs"]
+        );
+    }
+
+    #[test]
+    fn approvals() {
+        let required = required_owners(["src/lib.rs", "README.md"], &file());
+        assert!(required.is_satisfied_by(["@org/core", "@ALICE"]));
+        assert_eq!(required.missing(["@org/core"]).len(), 1);
+        assert!(required_owners(["vendor/a"], &file()).is_empty());
+    }
+
+    #[test]
+    fn author_approval_does_not_count() {
+        let required = required_owners(["README.md", "private/key"], &file());
+        assert_eq!(required.unsatisfiable().count(), 0);
+
+        let required = required.without_author("bob");
+        let unsatisfiable: Vec<_> = required.unsatisfiable().collect();
+        assert_eq!(unsatisfiable.len(), 1);
+        assert_eq!(unsatisfiable[0].paths, ["private/key"]);
+
+        let required = required.without_author("@alice");
+        assert_eq!(required.requirements()[0].owners, ["@org/docs"]);
+        assert!(!required.is_satisfied_by(["@org/docs", "@bob"]));
+
+        let file = parse_codeowners("* @alice\n").ast;
+        assert!(
+            required_owners(["a"], &file)
+                .without_author("alice")
+                .requirements()[0]
+                .is_unsatisfiable()
+        );
+    }
+}
@@ -0,0 +1,293 @@
+//! Baselines of known issues.
+//!
+//! Adopting validation on a large, legacy CODEOWNERS file would fail on every
+//! existing issue. A baseline records the issues present when it was
+//! created, so later runs only report new ones:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "issues": [
+//!     { "check": "duppatterns", "code": "CO004", "pattern": "*.rs", "subject": "*.rs" },
+//!     { "check": "owners", "code": "CO008", "pattern": "/docs/", "subject": "@ghost" }
+//!   ]
+//! }
+//! ```
+//!
+//! Issues are keyed by check, error code, the pattern of the rule the issue
+//! is about and the issue's [subject](ValidationError::subject), not by line
+//! number, so the baseline survives unrelated edits that move rules
+//! around. The same key gives each issue a stable
+//! [fingerprint](BaselineIssue::fingerprint) for tools that track findings
+//! across runs, such as code scanning.
+
+use crate::parse::{CodeownersFile, LineKind};
+use crate::validate::{ValidationError, ValidationResult};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeSet;
+use thiserror::Error;
+
+/// The baseline format version written by this crate.
+pub const BASELINE_VERSION: u32 = 1;
+
+/// Errors that can occur while reading a baseline.
+#[derive(Debug, Error)]
+pub enum BaselineError {
+    /// The baseline could not be parsed.
+    #[error("invalid JSON baseline: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// The baseline was written in an unsupported format version.
+    #[error("unsupported baseline version {0}, expected {BASELINE_VERSION}")]
+    Version(u32),
+}
+
+/// The key of a known issue.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct BaselineIssue {
+    /// The check that reported the issue.
+    pub check: String,
+    /// The stable error code, e.g. `CO004`.
+    pub code: String,
+    /// The pattern of the rule the issue is about, if it is about one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    /// What the issue is about, e.g. an owner or a path.
+    pub subject: String,
+}
+
+impl BaselineIssue {
+    /// Creates the key of an issue reported by `check` on `file`.
+    pub fn new(check: impl Into<String>, error: &ValidationError, file: &CodeownersFile) -> Self {
+        // Owner errors are on the rule they are about. Other errors name their
+        // rule, if any: file-level findings are anchored at the end of the
+        // file, where the last rule is unrelated to them.
+        let pattern = match error.pattern() {
+            Some(pattern) => Some(pattern.to_string()),
+            None if error.owner().is_some() => file
+                .lines
+                .iter()
+                .find(|line| line.span.line == error.line())
+                .and_then(|line| match &line.kind {
+                    LineKind::Rule { pattern, .. } => Some(pattern.text.clone()),
+                    _ => None,
+                }),
+            None => None,
+        };
+        Self {
+            check: check.into(),
+            code: error.code().to_string(),
+            pattern,
+            subject: error.subject().to_string(),
+        }
+    }
+
+    /// Returns a hex SHA-256 hash of the key.
+    ///
+    /// Like the key, the fingerprint does not depend on line numbers, so it
+    /// stays the same when unrelated edits move the issue's rule.
+    pub fn fingerprint(&self) -> String {
+        let mut hasher = Sha256::new();
+        for field in [
+            self.check.as_str(),
+            self.code.as_str(),
+            self.pattern.as_deref().unwrap_or_default(),
+            self.subject.as_str(),
+        ] {
+            hasher.update(field.as_bytes());
+            hasher.update(b"\0");
+        }
+        format!("{:x}", hasher.finalize())
+    }
+}
+
+/// The serialized form of a baseline.
+#[derive(Serialize, Deserialize)]
+struct BaselineFile {
+    version: u32,
+    issues: BTreeSet<BaselineIssue>,
+}
+
+/// A set of known issues.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline {
+    issues: BTreeSet<BaselineIssue>,
+}
+
+impl Baseline {
+    /// Creates an empty baseline.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a JSON baseline.
+    pub fn from_json(content: &str) -> Result<Self, BaselineError> {
+        let file: BaselineFile = serde_json::from_str(content)?;
+        if file.version != BASELINE_VERSION {
+            return Err(BaselineError::Version(file.version));
+        }
+        Ok(Self {
+            issues: file.issues,
+        })
+    }
+
+    /// Serializes the baseline as pretty-printed JSON, with issues sorted so
+    /// the file diffs cleanly.
+    pub fn to_json(&self) -> String {
+        let file = BaselineFile {
+            version: BASELINE_VERSION,
+            issues: self.issues.clone(),
+        };
+        let mut json = serde_json::to_string_pretty(&file).expect("baseline is valid JSON");
+        json.push('\n');
+        json
+    }
+
+    /// Records the issues of a check's result.
+    pub fn add(&mut self, check: &str, result: &ValidationResult, file: &CodeownersFile) {
+        self.issues.extend(
+            result
+                .errors
+                .iter()
+                .map(|error| BaselineIssue::new(check, error, file)),
+        );
+    }
+
+    /// Returns true if an issue reported by `check` is known.
+    pub fn contains(&self, check: &str, error: &ValidationError, file: &CodeownersFile) -> bool {
+        self.issues
+            .contains(&BaselineIssue::new(check, error, file))
+    }
+
+    /// Removes known issues from a check's result and returns how many were
+    /// removed.
+    pub fn filter(
+        &self,
+        check: &str,
+        result: &mut ValidationResult,
+        file: &CodeownersFile,
+    ) -> usize {
+        let before = result.errors.len();
+        result
+            .errors
+            .retain(|error| !self.contains(check, error, file));
+        before - result.errors.len()
+    }
+
+    /// Returns the known issues, sorted.
+    pub fn issues(&self) -> impl Iterator<Item = &BaselineIssue> {
+        self.issues.iter()
+    }
+
+    /// Returns the number of known issues.
+    pub fn len(&self) -> usize {
+        self.issues.len()
+    }
+
+    /// Returns true if the baseline has no issues.
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{Span, parse_codeowners};
+    use crate::validate::checks::{Check, CheckConfig, CheckContext, DupPatternsCheck};
+    use std::path::Path;
+
+    fn duppatterns(content: &str) -> (CodeownersFile, ValidationResult) {
+        let file = parse_codeowners(content).ast;
+        let config = CheckConfig::new();
+        let result =
+            DupPatternsCheck::new().run(&CheckContext::new(&file, Path::new("/repo"), &config));
+        (file, result)
+    }
+
+    #[test]
+    fn filters_known_issues_after_rules_move() {
+        let (file, result) = duppatterns("*.rs @a\n*.rs @b\n");
+        let mut baseline = Baseline::new();
+        baseline.add("duppatterns", &result, &file);
+        assert_eq!(baseline.len(), 1);
+
+        // The same duplicate further down, and a new one
+        let (file, mut result) = duppatterns("* @core\n*.rs @a\n*.rs @b\n/docs/ @d\n/docs/ @e\n");
+        assert_eq!(baseline.filter("duppatterns", &mut result, &file), 1);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].subject(), "/docs/");
+    }
+
+    #[test]
+    fn fingerprints_ignore_line_numbers() {
+        let fingerprints = |content: &str| -> Vec<String> {
+            let (file, result) = duppatterns(content);
+            result
+                .errors
+                .iter()
+                .map(|error| BaselineIssue::new("duppatterns", error, &file).fingerprint())
+                .collect()
+        };
+        let before = fingerprints("*.rs @a\n*.rs @b\n");
+        let after = fingerprints("* @core\n*.rs @a\n*.rs @b\n/docs/ @d\n/docs/ @e\n");
+        assert_eq!(before[0].len(), 64);
+        assert_eq!(after[0], before[0]);
+        assert_ne!(after[1], before[0]);
+    }
+
+    #[test]
+    fn file_findings_ignore_appended_rules() {
+        let issue = |content: &str| {
+            let file = parse_codeowners(content).ast;
+            // Anchored at the end of the file, like the notowned check
+            let last = file.lines.last().unwrap().span;
+            let span = Span::point(
+                last.offset + last.length,
+                last.line,
+                last.column + last.length,
+            );
+            let error = ValidationError::file_not_owned("src/main.rs", span);
+            BaselineIssue::new("notowned", &error, &file)
+        };
+        let before = issue("/docs/ @d\n");
+        let after = issue("/docs/ @d\n/build/ @b\n");
+        assert_eq!(before.pattern, None);
+        assert_eq!(after.fingerprint(), before.fingerprint());
+    }
+
+    #[test]
+    fn owner_issues_keep_their_rule() {
+        let file = parse_codeowners("/docs/ @ghost\n").ast;
+        let span = match &file.lines[0].kind {
+            LineKind::Rule { owners, .. } => *owners[0].span(),
+            _ => unreachable!(),
+        };
+        let error = ValidationError::owner_not_found("@ghost", "not found", span);
+        let issue = BaselineIssue::new("owners", &error, &file);
+        assert_eq!(issue.pattern.as_deref(), Some("/docs/"));
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let (file, result) = duppatterns("*.rs @a\n*.rs @b\n");
+        let mut baseline = Baseline::new();
+        baseline.add("duppatterns", &result, &file);
+
+        let json = baseline.to_json();
+        assert!(json.contains(r#""code": "CO004""#));
+        assert!(json.contains(r#""pattern": "*.rs""#));
+        assert_eq!(Baseline::from_json(&json).unwrap(), baseline);
+    }
+
+    #[test]
+    fn rejects_other_versions() {
+        let error = Baseline::from_json(r#"{"version": 2, "issues": []}"#).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unsupported baseline version 2, expected 1"
+        );
+        assert!(Baseline::from_json("[]").is_err());
+    }
+}
@@ -0,0 +1,217 @@
+//! Anchoring mistake detection check.
+//!
+//! This check detects unanchored directory patterns such as `build/` that
+//! were likely meant to be anchored to the repository root.
+
+use super::{Check, CheckContext};
+use crate::parse::LineKind;
+use crate::validate::file_walker::FileWalkerConfig;
+use crate::validate::{ValidationError, ValidationResult};
+use log::debug;
+use std::collections::BTreeSet;
+
+/// Number of nested directories named in a finding.
+const MAX_LISTED_DIRECTORIES: usize = 3;
+
+/// A check that detects likely anchoring mistakes.
+///
+/// A pattern like `build/` has no leading slash, so it matches every
+/// directory named `build`, not just the one at the root:
+/// ```text
+/// build/     @release-team    # also owns tools/build/ and web/build/
+/// /build/    @release-team    # only the root build/ directory
+/// ```
+///
+/// The check reports unanchored single-segment directory patterns when the
+/// repository has a directory of that name at the root and also nested
+/// deeper, and suggests both the anchored form and the explicit `**/` form.
+#[derive(Debug, Clone, Default)]
+pub struct AnchoringCheck;
+
+impl AnchoringCheck {
+    /// Creates a new anchoring check.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+/// Returns the directory name of an unanchored single-segment directory
+/// pattern, such as `build` for `build/`.
+fn unanchored_directory(pattern: &str) -> Option<&str> {
+    let name = pattern.strip_suffix('/')?;
+    let is_literal =
+        !name.is_empty() && !name.starts_with('!') && !name.contains(['/', '*', '?', '[', '\\']);
+    is_literal.then_some(name)
+}
+
+/// Returns every directory containing at least one of the files, with a
+/// trailing slash.
+fn directories(files: &[String]) -> BTreeSet<&str> {
+    let mut directories = BTreeSet::new();
+    for file in files {
+        let mut end = file.len();
+        while let Some(slash) = file[..end].rfind('/') {
+            if !directories.insert(&file[..=slash]) {
+                break;
+            }
+            end = slash;
+        }
+    }
+    directories
+}
+
+impl Check for AnchoringCheck {
+    fn name(&self) -> &str {
+        "anchoring"
+    }
+
+    fn run(&self, ctx: &CheckContext) -> ValidationResult {
+        let mut result = ValidationResult::new();
+
+        let candidates: Vec<_> = ctx
+            .file
+            .lines
+            .iter()
+            .filter_map(|line| match &line.kind {
+                LineKind::Rule { pattern, .. } => {
+                    Some((pattern, unanchored_directory(&pattern.text)?))
+                }
+                _ => None,
+            })
+            .collect();
+
+        if candidates.is_empty() {
+            return result;
+        }
+
+        // Only walk the repository when there is something to cross-reference
+        let files = match ctx.list_files(&FileWalkerConfig::for_files_check()) {
+            Ok(files) => files,
+            Err(e) => {
+                result.set_internal_error(e.to_string());
+                return result;
+            }
+        };
+        let directories = directories(&files);
+
+        for (pattern, name) in candidates {
+            let root = format!("{}/", name);
+            if !directories.contains(root.as_str()) {
+                continue;
+            }
+            let suffix = format!("/{}/", name);
+            let nested: Vec<&str> = directories
+                .iter()
+                .copied()
+                .filter(|directory| directory.ends_with(&suffix))
+                .collect();
+            if nested.is_empty() {
+                continue;
+            }
+
+            debug!(
+                "Pattern '{}' matches {} nested directories",
+                pattern.text,
+                nested.len()
+            );
+            let mut listed: Vec<String> = nested
+                .iter()
+                .take(MAX_LISTED_DIRECTORIES)
+                .map(|directory| format!("'{}'", directory))
+                .collect();
+            if nested.len() > MAX_LISTED_DIRECTORIES {
+                listed.push(format!("{} more", nested.len() - MAX_LISTED_DIRECTORIES));
+            }
+            result.add_error(ValidationError::unanchored_directory(
+                &pattern.text,
+                listed.join(", "),
+                pattern.span,
+            ));
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_codeowners;
+    use crate::validate::checks::CheckConfig;
+    use std::fs::{self, File};
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    fn setup_test_dir(directories: &[&str]) -> TempDir {
+        let dir = TempDir::new().unwrap();
+        for directory in directories {
+            fs::create_dir_all(dir.path().join(directory)).unwrap();
+            File::create(dir.path().join(directory).join("out.txt")).unwrap();
+        }
+        dir
+    }
+
+    fn run_check(input: &str, repo_path: &Path) -> ValidationResult {
+        let file = parse_codeowners(input).ast;
+        let config = CheckConfig::new();
+        let ctx = CheckContext::new(&file, repo_path, &config);
+        AnchoringCheck::new().run(&ctx)
+    }
+
+    #[test]
+    fn reports_nested_directories() {
+        let dir = setup_test_dir(&["build", "tools/build", "a/build", "b/build", "c/build/x"]);
+        let result = run_check("build/ @release\n", dir.path());
+
+        assert_eq!(result.errors.len(), 1);
+        match &result.errors[0] {
+            ValidationError::UnanchoredDirectory {
+                line,
+                nested,
+                anchored,
+                recursive,
+                ..
+            } => {
+                assert_eq!(*line, 1);
+                assert_eq!(nested, "'a/build/', 'b/build/', 'c/build/', 1 more");
+                assert_eq!(anchored, "/build/");
+                assert_eq!(recursive, "**/build/");
+            }
+            _ => panic!("Expected UnanchoredDirectory error"),
+        }
+
+        let candidates = result.errors[0].candidate_fixes();
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].replacement_text, "/build/");
+        assert_eq!(candidates[1].replacement_text, "**/build/");
+    }
+
+    #[test]
+    fn ignores_unambiguous_patterns() {
+        // Only at the root, only nested, or already anchored
+        let dir = setup_test_dir(&["build", "docs/api", "src/api"]);
+        let input = "build/ @release\napi/ @api\n/build/ @release\nsrc/api/ @api\n";
+        assert!(run_check(input, dir.path()).is_ok());
+
+        let dir = setup_test_dir(&["build", "web/build"]);
+        assert!(run_check("/build/ @release\n**/build/ @web\n", dir.path()).is_ok());
+    }
+
+    #[test]
+    fn directory_patterns() {
+        assert_eq!(unanchored_directory("build/"), Some("build"));
+        assert_eq!(unanchored_directory("/build/"), None);
+        assert_eq!(unanchored_directory("src/build/"), None);
+        assert_eq!(unanchored_directory("build"), None);
+        assert_eq!(unanchored_directory("*/"), None);
+
+        let files = vec!["a/b/c.txt".to_string(), "a/d.txt".to_string()];
+        let directories: Vec<_> = directories(&files).into_iter().collect();
+        assert_eq!(directories, ["a/", "a/b/"]);
+    }
+
+    #[test]
+    fn check_name() {
+        assert_eq!(AnchoringCheck::new().name(), "anchoring");
+    }
+}
@@ -0,0 +1,201 @@
+//! Directive annotation check.
+//!
+//! This check verifies that `codeowners:` directive annotations are spelled
+//! correctly and attached to a rule, so review-assignment bots honour them.
+
+use super::{Check, CheckContext};
+use crate::parse::{CodeownersFile, DIRECTIVE_KEY, LineKind, NO_AUTO_REQUEST, NoAutoRequest};
+use crate::validate::{ValidationError, ValidationResult};
+
+/// A check that validates the spelling and placement of directive
+/// annotations.
+///
+/// Rules can be annotated with `# codeowners: no-auto-request` (see
+/// [`crate::parse::NoAutoRequest`]) so review-assignment bots skip
+/// requesting some of their owners. A misspelled or misplaced directive is
+/// silently ignored by the bots, so the check reports:
+///
+/// - comments keyed `codeowners:` with an unknown or missing directive
+///   (the key is reserved for directives)
+/// - `no-auto-request` written with a misspelled or missing key
+/// - directives whose comment block is not directly above a rule
+/// - owners listed in a directive that do not own the rule
+#[derive(Debug, Clone, Default)]
+pub struct AnnotationsCheck;
+
+impl AnnotationsCheck {
+    /// Creates a new annotations check.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+/// Returns `text` lowercased with everything but letters and digits removed,
+/// to recognize misspellings.
+fn squash(text: &str) -> String {
+    text.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Returns why comment content is a misspelled directive, or `None` if it is
+/// a well-formed directive or not a directive at all.
+fn spelling_problem(content: &str) -> Option<String> {
+    let (key, value) = content.split_once(':').unwrap_or(("", content));
+    if key.trim() == DIRECTIVE_KEY {
+        return match value.split_whitespace().next() {
+            None => Some(format!("missing directive, expected '{}'", NO_AUTO_REQUEST)),
+            Some(NO_AUTO_REQUEST) => None,
+            Some(directive) if squash(directive) == squash(NO_AUTO_REQUEST) => Some(format!(
+                "directive '{}' should be written '{}'",
+                directive, NO_AUTO_REQUEST
+            )),
+            Some(directive) => Some(format!(
+                "unknown directive '{}', expected '{}'",
+                directive, NO_AUTO_REQUEST
+            )),
+        };
+    }
+
+    // The directive with a misspelled key, e.g. `codeowner: no-auto-request`,
+    // or none at all
+    value
+        .split_whitespace()
+        .next()
+        .filter(|directive| squash(directive) == squash(NO_AUTO_REQUEST))
+        .map(|_| format!("expected '{}: {}'", DIRECTIVE_KEY, NO_AUTO_REQUEST))
+}
+
+/// Returns the index of the rule directly below the comment block holding
+/// the comment at `index`, if there is one.
+fn attached_rule(file: &CodeownersFile, index: usize) -> Option<usize> {
+    let below = index + 1;
+    let rule = below
+        + file.lines[below..]
+            .iter()
+            .position(|line| !line.is_comment())?;
+    file.lines[rule].is_rule().then_some(rule)
+}
+
+impl Check for AnnotationsCheck {
+    fn name(&self) -> &str {
+        "annotations"
+    }
+
+    fn run(&self, ctx: &CheckContext) -> ValidationResult {
+        let mut result = ValidationResult::new();
+
+        for (index, line) in ctx.file.lines.iter().enumerate() {
+            let (content, rule) = match &line.kind {
+                LineKind::Comment { content } => (content, attached_rule(ctx.file, index)),
+                LineKind::Rule { .. } => match &line.inline_comment {
+                    Some(comment) => (comment, Some(index)),
+                    None => continue,
+                },
+                _ => continue,
+            };
+            let annotation = content.trim();
+
+            if let Some(reason) = spelling_problem(annotation) {
+                result.add_error(ValidationError::invalid_annotation(
+                    annotation, reason, line.span,
+                ));
+                continue;
+            }
+            let Some(directive) = NoAutoRequest::parse(annotation) else {
+                continue;
+            };
+            let Some(LineKind::Rule { pattern, owners }) = rule.map(|i| &ctx.file.lines[i].kind)
+            else {
+                result.add_error(ValidationError::invalid_annotation(
+                    annotation,
+                    "not directly above a rule, so it applies to none",
+                    line.span,
+                ));
+                continue;
+            };
+
+            for excluded in &directive.owners {
+                if !owners
+                    .iter()
+                    .any(|owner| owner.as_str().eq_ignore_ascii_case(excluded))
+                {
+                    result.add_error(ValidationError::invalid_annotation(
+                        annotation,
+                        format!("'{}' is not an owner of rule '{}'", excluded, pattern.text),
+                        line.span,
+                    ));
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{ParserConfig, parse_codeowners_with_config};
+    use crate::validate::checks::CheckConfig;
+    use std::path::Path;
+
+    fn run_check(input: &str) -> ValidationResult {
+        let parser_config = ParserConfig::new().with_inline_comments(true);
+        let file = parse_codeowners_with_config(input, &parser_config).ast;
+        let config = CheckConfig::new();
+        let ctx = CheckContext::new(&file, Path::new("/repo"), &config);
+        AnnotationsCheck::new().run(&ctx)
+    }
+
+    #[test]
+    fn accepts_valid_annotations() {
+        let input = "\
+# CODEOWNERS for the monorepo
+# codeowners: no-auto-request
+# Vendored code
+/vendor/ @org/everyone
+/docs/ @docs @org/everyone # codeowners: no-auto-request @org/Everyone
+";
+        assert!(run_check(input).is_ok());
+    }
+
+    #[test]
+    fn reports_misspelled_and_misplaced_annotations() {
+        let input = "\
+# codeowners: no-auto-requst
+/a/ @a
+# codeowner: no-auto-request
+/b/ @b
+# codeowners: No_Auto_Request
+/c/ @c
+/d/ @d # codeowners: no-auto-request @e
+# codeowners: no-auto-request
+
+/f/ @f
+";
+        let result = run_check(input);
+        let messages: Vec<String> = result.errors.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "line 1: invalid annotation 'codeowners: no-auto-requst': \
+                 unknown directive 'no-auto-requst', expected 'no-auto-request'",
+                "line 3: invalid annotation 'codeowner: no-auto-request': \
+                 expected 'codeowners: no-auto-request'",
+                "line 5: invalid annotation 'codeowners: No_Auto_Request': \
+                 directive 'No_Auto_Request' should be written 'no-auto-request'",
+                "line 7: invalid annotation 'codeowners: no-auto-request @e': \
+                 '@e' is not an owner of rule '/d/'",
+                "line 8: invalid annotation 'codeowners: no-auto-request': \
+                 not directly above a rule, so it applies to none",
+            ]
+        );
+    }
+
+    #[test]
+    fn check_name() {
+        assert_eq!(AnnotationsCheck::new().name(), "annotations");
+    }
+}
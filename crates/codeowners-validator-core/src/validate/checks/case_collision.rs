@@ -0,0 +1,170 @@
+//! Pattern case-collision check.
+//!
+//! This check detects rules whose patterns differ only in letter case,
+//! such as `/Docs/` and `/docs/`.
+
+use super::{Check, CheckContext};
+use crate::matching::{Pattern, QuestionMark};
+use crate::parse::{LineKind, Span};
+use crate::validate::file_walker::FileWalkerConfig;
+use crate::validate::{ValidationError, ValidationResult};
+use log::debug;
+use std::collections::HashMap;
+
+/// A check that detects patterns differing only in case.
+///
+/// GitHub matches CODEOWNERS patterns case-sensitively, but contributors on
+/// case-insensitive filesystems (macOS, Windows) see both spellings as the
+/// same path. Two such rules usually mean one of them never applies. The
+/// check reports each later variant and, using the files actually present in
+/// the repository, which spelling matches real files.
+#[derive(Debug, Clone, Default)]
+pub struct CaseCollisionCheck;
+
+/// A rule pattern seen earlier in the file.
+struct SeenPattern<'a> {
+    text: &'a str,
+    line: usize,
+}
+
+impl CaseCollisionCheck {
+    /// Creates a new case-collision check.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Returns true if the pattern matches any of the given files.
+    fn matches_any(pattern: &str, question_mark: QuestionMark, files: &[String]) -> bool {
+        Pattern::with_question_mark(pattern, question_mark)
+            .is_some_and(|compiled| files.iter().any(|f| compiled.matches(f)))
+    }
+}
+
+impl Check for CaseCollisionCheck {
+    fn name(&self) -> &str {
+        "case-collision"
+    }
+
+    fn run(&self, ctx: &CheckContext) -> ValidationResult {
+        let mut result = ValidationResult::new();
+
+        // Find later patterns that differ only in case from an earlier one
+        let mut seen: HashMap<String, Vec<SeenPattern>> = HashMap::new();
+        let mut collisions: Vec<(&str, Span, &str, usize)> = Vec::new();
+
+        for line in &ctx.file.lines {
+            if let LineKind::Rule { pattern, .. } = &line.kind {
+                let variants = seen.entry(pattern.text.to_lowercase()).or_default();
+                if variants.iter().any(|v| v.text == pattern.text) {
+                    // Exact duplicates are reported by the duppatterns check
+                    continue;
+                }
+                if let Some(other) = variants.first() {
+                    collisions.push((&pattern.text, pattern.span, other.text, other.line));
+                }
+                variants.push(SeenPattern {
+                    text: &pattern.text,
+                    line: line.span.line,
+                });
+            }
+        }
+
+        if collisions.is_empty() {
+            return result;
+        }
+
+        // Only walk the repository when there is something to cross-reference
+        let files = match ctx.list_files(&FileWalkerConfig::for_files_check()) {
+            Ok(files) => files,
+            Err(e) => {
+                result.set_internal_error(e.to_string());
+                return result;
+            }
+        };
+        for (pattern, span, other_pattern, other_line) in collisions {
+            debug!(
+                "Pattern '{}' differs only in case from '{}'",
+                pattern, other_pattern
+            );
+            result.add_error(ValidationError::pattern_case_collision(
+                pattern,
+                span,
+                other_pattern,
+                other_line,
+                Self::matches_any(pattern, ctx.config.question_mark, &files),
+                Self::matches_any(other_pattern, ctx.config.question_mark, &files),
+            ));
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_codeowners;
+    use crate::validate::checks::CheckConfig;
+    use std::fs::{self, File};
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    fn setup_test_dir() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join("docs")).unwrap();
+        File::create(dir.path().join("docs/README.md")).unwrap();
+        dir
+    }
+
+    fn run_check(input: &str, repo_path: &Path) -> ValidationResult {
+        let file = parse_codeowners(input).ast;
+        let config = CheckConfig::new();
+        let ctx = CheckContext::new(&file, repo_path, &config);
+        CaseCollisionCheck::new().run(&ctx)
+    }
+
+    #[test]
+    fn no_collision() {
+        let dir = setup_test_dir();
+        let result = run_check("/docs/ @docs\n*.rs @rust\n", dir.path());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn collision_reports_matching_variant() {
+        let dir = setup_test_dir();
+        let result = run_check("/docs/ @docs\n/Docs/ @writers\n", dir.path());
+
+        assert_eq!(result.errors.len(), 1);
+        let error = &result.errors[0];
+        assert!(matches!(
+            error,
+            ValidationError::PatternCaseCollision {
+                line: 2,
+                other_line: 1,
+                matches_files: false,
+                other_matches_files: true,
+                ..
+            }
+        ));
+        assert!(
+            error
+                .to_string()
+                .contains("only '/docs/' matches files in the repository")
+        );
+    }
+
+    #[test]
+    fn exact_duplicates_ignored() {
+        let dir = setup_test_dir();
+        let result = run_check("/docs/ @a\n/docs/ @b\n", dir.path());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn each_later_variant_reported_once() {
+        let dir = setup_test_dir();
+        let result = run_check("/docs/ @a\n/Docs/ @b\n/DOCS/ @c\n/Docs/ @d\n", dir.path());
+        assert_eq!(result.errors.len(), 2);
+    }
+}
@@ -0,0 +1,238 @@
+//! File complexity budget check.
+//!
+//! This check warns when a CODEOWNERS file grows past configurable budgets
+//! for its number of rules, rules per section, and lines.
+
+use super::{Check, CheckContext};
+use crate::parse::{CodeownersFile, LineKind, Span};
+use crate::validate::{ValidationError, ValidationResult};
+use log::debug;
+use serde::Serialize;
+
+/// Maximum number of rules in a file, unless
+/// [`CheckConfig::max_rules`](super::CheckConfig::max_rules) is set.
+pub const DEFAULT_MAX_RULES: usize = 500;
+
+/// Maximum number of rules in a section, unless
+/// [`CheckConfig::max_rules_per_section`](super::CheckConfig::max_rules_per_section)
+/// is set.
+pub const DEFAULT_MAX_RULES_PER_SECTION: usize = 100;
+
+/// Maximum number of lines in a file, unless
+/// [`CheckConfig::max_file_lines`](super::CheckConfig::max_file_lines) is set.
+pub const DEFAULT_MAX_FILE_LINES: usize = 1000;
+
+/// Size counts of a CODEOWNERS file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct ComplexityStats {
+    /// Number of lines, including blank lines and comments.
+    pub lines: usize,
+    /// Number of rules.
+    pub rules: usize,
+    /// Number of sections (GitLab).
+    pub sections: usize,
+    /// Number of rules in the largest section.
+    pub largest_section: usize,
+}
+
+impl ComplexityStats {
+    /// Counts the lines, rules and sections of a file.
+    pub fn of(file: &CodeownersFile) -> Self {
+        let sections = sections(file);
+        Self {
+            lines: file.lines.len(),
+            rules: file
+                .lines
+                .iter()
+                .filter(|line| matches!(line.kind, LineKind::Rule { .. }))
+                .count(),
+            sections: sections.len(),
+            largest_section: sections
+                .iter()
+                .map(|section| section.rules)
+                .max()
+                .unwrap_or(0),
+        }
+    }
+}
+
+/// A section and the number of rules under it.
+struct Section<'a> {
+    name: &'a str,
+    span: Span,
+    rules: usize,
+}
+
+/// Returns the sections of a file with their rule counts.
+fn sections(file: &CodeownersFile) -> Vec<Section<'_>> {
+    let mut sections: Vec<Section> = Vec::new();
+    for line in &file.lines {
+        match &line.kind {
+            LineKind::Section {
+                name, name_span, ..
+            } => sections.push(Section {
+                name,
+                span: *name_span,
+                rules: 0,
+            }),
+            LineKind::Rule { .. } => {
+                if let Some(section) = sections.last_mut() {
+                    section.rules += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    sections
+}
+
+/// A check that enforces size budgets on the CODEOWNERS file.
+///
+/// Files with hundreds of rules are hard to review, and the last-match-wins
+/// rule makes each new rule harder to place correctly. The check warns when
+/// the file has more rules or lines than its budget, or when a GitLab section
+/// has more rules than the per-section budget, nudging teams to consolidate
+/// rules or split ownership into sections.
+///
+/// Each finding points at the first rule or line past the budget, or at the
+/// section header.
+#[derive(Debug, Clone, Default)]
+pub struct ComplexityCheck;
+
+impl ComplexityCheck {
+    /// Creates a new complexity check.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Check for ComplexityCheck {
+    fn name(&self) -> &str {
+        "complexity"
+    }
+
+    fn run(&self, ctx: &CheckContext) -> ValidationResult {
+        let mut result = ValidationResult::new();
+        let config = ctx.config;
+        let stats = ComplexityStats::of(ctx.file);
+        debug!("CODEOWNERS complexity: {:?}", stats);
+
+        let max_lines = config.max_file_lines.unwrap_or(DEFAULT_MAX_FILE_LINES);
+        if stats.lines > max_lines {
+            result.add_error(ValidationError::complexity_budget_exceeded(
+                "file",
+                stats.lines,
+                "lines",
+                max_lines,
+                ctx.file.lines[max_lines].span,
+            ));
+        }
+
+        let max_rules = config.max_rules.unwrap_or(DEFAULT_MAX_RULES);
+        if stats.rules > max_rules
+            && let Some(pattern) = ctx
+                .file
+                .lines
+                .iter()
+                .filter_map(|line| match &line.kind {
+                    LineKind::Rule { pattern, .. } => Some(pattern),
+                    _ => None,
+                })
+                .nth(max_rules)
+        {
+            result.add_error(ValidationError::complexity_budget_exceeded(
+                "file",
+                stats.rules,
+                "rules",
+                max_rules,
+                pattern.span,
+            ));
+        }
+
+        let max_section_rules = config
+            .max_rules_per_section
+            .unwrap_or(DEFAULT_MAX_RULES_PER_SECTION);
+        for section in sections(ctx.file) {
+            if section.rules > max_section_rules {
+                result.add_error(ValidationError::complexity_budget_exceeded(
+                    format!("section '{}'", section.name),
+                    section.rules,
+                    "rules",
+                    max_section_rules,
+                    section.span,
+                ));
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{Dialect, ParserConfig, parse_codeowners_with_config};
+    use crate::validate::checks::CheckConfig;
+    use std::path::PathBuf;
+
+    const CODEOWNERS: &str = "\
+# Owners
+* @org/core
+
+[Docs]
+/docs/ @docs
+/guides/ @docs
+
+[Api] @org/backend
+/api/ @api
+";
+
+    fn parse() -> CodeownersFile {
+        let config = ParserConfig::new().with_dialect(Dialect::GitLab);
+        parse_codeowners_with_config(CODEOWNERS, &config).ast
+    }
+
+    fn run_check(config: &CheckConfig) -> ValidationResult {
+        let file = parse();
+        let path = PathBuf::from("/repo");
+        let ctx = CheckContext::new(&file, &path, config);
+        ComplexityCheck::new().run(&ctx)
+    }
+
+    #[test]
+    fn counts_file_complexity() {
+        assert_eq!(
+            ComplexityStats::of(&parse()),
+            ComplexityStats {
+                lines: 9,
+                rules: 4,
+                sections: 2,
+                largest_section: 2,
+            }
+        );
+        assert!(run_check(&CheckConfig::new()).is_ok());
+    }
+
+    #[test]
+    fn reports_exceeded_budgets() {
+        let config = CheckConfig::new()
+            .with_max_file_lines(8)
+            .with_max_rules(3)
+            .with_max_rules_per_section(1);
+        let result = run_check(&config);
+
+        let messages: Vec<_> = result.errors.iter().map(|e| e.to_string()).collect();
+        assert_eq!(messages.len(), 3);
+        assert!(messages[0].starts_with("line 9: file has 9 lines, over the budget of 8"));
+        assert!(messages[1].starts_with("line 9: file has 4 rules, over the budget of 3"));
+        assert!(
+            messages[2].starts_with("line 4: section 'Docs' has 2 rules, over the budget of 1")
+        );
+        assert_eq!(result.errors[2].span().column, 2);
+    }
+
+    #[test]
+    fn check_name() {
+        assert_eq!(ComplexityCheck::new().name(), "complexity");
+    }
+}
@@ -1,16 +1,22 @@
 //! Duplicate pattern detection check.
 //!
-//! This check detects when the same pattern appears multiple times in a CODEOWNERS file.
+//! This check detects when the same pattern appears multiple times in a CODEOWNERS file,
+//! when the same owner is listed more than once on a single rule, and when an
+//! owner is spelled with different letter case across rules.
 
 use super::{Check, CheckContext};
-use crate::parse::LineKind;
+use crate::parse::{LineKind, Owner, Span};
+use crate::validate::fix::line_removal;
 use crate::validate::{ValidationError, ValidationResult};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// A check that detects duplicate patterns in CODEOWNERS files.
 ///
 /// Duplicate patterns can lead to confusion about ownership and
-/// may indicate copy-paste errors.
+/// may indicate copy-paste errors. Owners repeated on one line are compared
+/// case-insensitively (see [`Owner::normalized`]), and user and team owners
+/// spelled differently from their first occurrence, e.g. `@Alice` after
+/// `@alice`, are reported with a fix to the first spelling.
 #[derive(Debug, Clone, Default)]
 pub struct DupPatternsCheck;
 
@@ -21,31 +27,112 @@ impl DupPatternsCheck {
     }
 }
 
+impl DupPatternsCheck {
+    /// Reports owners repeated within a single rule.
+    fn check_duplicate_owners(pattern_span: Span, owners: &[Owner], result: &mut ValidationResult) {
+        let mut seen_owners = HashSet::new();
+        let mut previous_end = pattern_span.end_offset();
+
+        for owner in owners {
+            let span = *owner.span();
+            if !owner.is_invalid() && !seen_owners.insert(owner.normalized()) {
+                let removal = Span::new(
+                    previous_end,
+                    span.line,
+                    span.column - (span.offset - previous_end),
+                    span.end_offset() - previous_end,
+                );
+                result.add_error(ValidationError::duplicate_owner(
+                    owner.as_str(),
+                    span,
+                    removal,
+                ));
+            }
+            previous_end = span.end_offset();
+        }
+    }
+
+    /// Reports user and team owners spelled differently from their first
+    /// occurrence in the file.
+    fn check_owner_case(
+        owners: &[Owner],
+        spellings: &mut HashMap<String, (String, usize)>,
+        result: &mut ValidationResult,
+    ) {
+        let mut seen_owners = HashSet::new();
+        for owner in owners {
+            if !matches!(owner, Owner::User { .. } | Owner::Team { .. }) {
+                continue;
+            }
+            // Repeats on one line are reported as duplicate owners instead
+            let key = owner.normalized();
+            if !seen_owners.insert(key.clone()) {
+                continue;
+            }
+            let span = *owner.span();
+            let spelling = owner.as_str();
+            match spellings.get(&key) {
+                Some((canonical, canonical_line)) if *canonical != spelling => {
+                    result.add_error(ValidationError::inconsistent_owner_case(
+                        spelling,
+                        span,
+                        canonical,
+                        *canonical_line,
+                    ));
+                }
+                Some(_) => {}
+                None => {
+                    spellings.insert(key, (spelling.into_owned(), span.line));
+                }
+            }
+        }
+    }
+}
+
 impl Check for DupPatternsCheck {
-    fn name(&self) -> &'static str {
+    fn name(&self) -> &str {
         "duppatterns"
     }
 
     fn run(&self, ctx: &CheckContext) -> ValidationResult {
         let mut result = ValidationResult::new();
 
-        // Track patterns we've seen: pattern text -> (first line number, first span)
-        let mut seen: HashMap<&str, (usize, crate::parse::Span)> = HashMap::new();
+        // Track patterns we've seen: pattern text -> (first line number,
+        // index of the previous occurrence)
+        let mut seen: HashMap<&str, (usize, usize)> = HashMap::new();
+        // Index of the last GitLab section header; rules in different
+        // sections all apply, so only duplicates within one are removable
+        let mut section_start = 0;
+        // First spelling of each owner: normalized owner -> (spelling, line)
+        let mut spellings: HashMap<String, (String, usize)> = HashMap::new();
+
+        for (index, line) in ctx.file.lines.iter().enumerate() {
+            if line.is_section() {
+                section_start = index;
+            }
+            if let LineKind::Rule { pattern, owners } = &line.kind {
+                Self::check_duplicate_owners(pattern.span, owners, &mut result);
+                Self::check_owner_case(owners, &mut spellings, &mut result);
 
-        for line in &ctx.file.lines {
-            if let LineKind::Rule { pattern, .. } = &line.kind {
                 let pattern_text = pattern.text.as_str();
 
-                if let Some(&(first_line, _)) = seen.get(pattern_text) {
-                    // Found a duplicate
-                    result.add_error(ValidationError::duplicate_pattern(
-                        pattern_text,
-                        pattern.span,
-                        first_line,
-                    ));
+                if let Some((first_line, previous)) = seen.get_mut(pattern_text) {
+                    // Found a duplicate; the previous occurrence never applies
+                    let error = if *previous >= section_start {
+                        ValidationError::duplicate_pattern_with_removal(
+                            pattern_text,
+                            pattern.span,
+                            *first_line,
+                            line_removal(ctx.file, *previous),
+                        )
+                    } else {
+                        ValidationError::duplicate_pattern(pattern_text, pattern.span, *first_line)
+                    };
+                    result.add_error(error);
+                    *previous = index;
                 } else {
                     // First occurrence
-                    seen.insert(pattern_text, (line.span.line, pattern.span));
+                    seen.insert(pattern_text, (line.span.line, index));
                 }
             }
         }
@@ -96,6 +183,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn duplicate_fix_removes_previous_occurrence() {
+        use crate::validate::apply_fixes;
+
+        let input = "*.rs @a\n# docs\n*.rs @b\n*.rs @c\n";
+        let result = run_check(input);
+        assert_eq!(result.errors.len(), 2);
+
+        let fixes: Vec<_> = result.fixes().collect();
+        assert_eq!(fixes.len(), 2);
+        assert_eq!(apply_fixes(input, &fixes), "# docs\n*.rs @c\n");
+    }
+
+    #[test]
+    fn duplicates_across_sections_not_removable() {
+        use crate::parse::{Dialect, ParserConfig, parse_codeowners_with_config};
+
+        let config = ParserConfig::new().with_dialect(Dialect::GitLab);
+        let file = parse_codeowners_with_config("[A]\n*.rs @a\n[B]\n*.rs @b\n", &config).ast;
+        let path = PathBuf::from("/repo");
+        let config = CheckConfig::new();
+        let ctx = CheckContext::new(&file, &path, &config);
+        let result = DupPatternsCheck::new().run(&ctx);
+
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].fix(), None);
+    }
+
+    #[test]
+    fn inconsistent_owner_case() {
+        use crate::validate::apply_fixes;
+
+        let input = "*.rs @alice @Org/Team\n*.md @Alice @ALICE a@Example.com\n/docs/ @org/team a@example.com\n";
+        let result = run_check(input);
+        let messages: Vec<_> = result.errors.iter().map(|e| e.to_string()).collect();
+        assert_eq!(messages.len(), 3, "{messages:?}");
+        assert!(messages[0].starts_with("line 2: owner '@ALICE' is listed more than once"));
+        assert!(messages[1].starts_with("line 2: owner '@Alice' is spelled '@alice' on line 1"));
+        assert!(messages[2].starts_with("line 3: owner '@org/team' is spelled '@Org/Team'"));
+
+        let fixes: Vec<_> = result.fixes().collect();
+        assert_eq!(
+            apply_fixes(input, &fixes),
+            "*.rs @alice @Org/Team\n*.md @alice a@Example.com\n/docs/ @Org/Team a@example.com\n"
+        );
+    }
+
     #[test]
     fn multiple_duplicates() {
         let result = run_check("*.rs @a\n*.md @b\n*.rs @c\n*.md @d\n");
@@ -123,6 +257,35 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn duplicate_owner_on_line() {
+        let input = "*.rs @Org/Team @dev @org/team\n";
+        let result = run_check(input);
+        assert_eq!(result.errors.len(), 1);
+        assert!(matches!(
+            &result.errors[0],
+            ValidationError::DuplicateOwner { owner, .. } if owner == "@org/team"
+        ));
+
+        let fixes: Vec<_> = result.fixes().collect();
+        assert_eq!(
+            crate::validate::apply_fixes(input, &fixes),
+            "*.rs @Org/Team @dev\n"
+        );
+    }
+
+    #[test]
+    fn duplicate_email_owner_case_insensitive() {
+        let result = run_check("*.rs USER@EXAMPLE.COM user@example.com\n");
+        assert_eq!(result.errors.len(), 1);
+    }
+
+    #[test]
+    fn same_owner_on_different_lines_not_duplicate() {
+        let result = run_check("*.rs @dev\n*.md @dev\n");
+        assert!(result.is_ok());
+    }
+
     #[test]
     fn empty_file() {
         let result = run_check("");
@@ -0,0 +1,101 @@
+//! Escalation annotation check.
+//!
+//! This check verifies that `escalation` annotations name a policy incident
+//! tooling can resolve.
+
+use super::{Check, CheckContext};
+use crate::parse::{EscalationTarget, LineKind};
+use crate::validate::{ValidationError, ValidationResult};
+
+/// A check that validates the format of escalation annotations.
+///
+/// Rules can be annotated with `# escalation: provider:policy` (see
+/// [`crate::parse::Escalation`]) so incident tooling can map the paths they
+/// own to an escalation policy. Annotations that are not `provider:policy`
+/// would silently route pages nowhere, so they are reported as errors.
+/// Rules without an annotation are not reported.
+#[derive(Debug, Clone, Default)]
+pub struct EscalationCheck;
+
+impl EscalationCheck {
+    /// Creates a new escalation check.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Check for EscalationCheck {
+    fn name(&self) -> &str {
+        "escalation"
+    }
+
+    fn run(&self, ctx: &CheckContext) -> ValidationResult {
+        let mut result = ValidationResult::new();
+
+        for (index, line) in ctx.file.lines.iter().enumerate() {
+            let LineKind::Rule { pattern, .. } = &line.kind else {
+                continue;
+            };
+            let Some(escalation) = ctx.file.escalation_of(index) else {
+                continue;
+            };
+            if let Err(reason) = EscalationTarget::parse(&escalation.value) {
+                result.add_error(ValidationError::invalid_escalation(
+                    &pattern.text,
+                    escalation.value,
+                    reason,
+                    pattern.span,
+                ));
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_codeowners;
+    use crate::validate::checks::CheckConfig;
+    use std::path::Path;
+
+    fn run_check(input: &str) -> ValidationResult {
+        let file = parse_codeowners(input).ast;
+        let config = CheckConfig::new();
+        let ctx = CheckContext::new(&file, Path::new("/repo"), &config);
+        EscalationCheck::new().run(&ctx)
+    }
+
+    #[test]
+    fn accepts_valid_annotations() {
+        let input = "# escalation: pagerduty:payments\n/payments/ @org/payments\n/docs/ @docs\n";
+        assert!(run_check(input).is_ok());
+    }
+
+    #[test]
+    fn reports_invalid_annotations() {
+        let input = "\
+# escalation: payments
+/payments/ @org/payments
+# escalation: pager duty:search
+/search/ @org/search
+";
+        let result = run_check(input);
+        let messages: Vec<String> = result.errors.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "line 2: rule '/payments/' has an invalid escalation annotation 'payments': \
+                 expected 'provider:policy'",
+                "line 4: rule '/search/' has an invalid escalation annotation 'pager duty:search': \
+                 the provider may only contain letters, digits, '-' and '_'",
+            ]
+        );
+    }
+
+    #[test]
+    fn check_name() {
+        assert_eq!(EscalationCheck::new().name(), "escalation");
+    }
+}
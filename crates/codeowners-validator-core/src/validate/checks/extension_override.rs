@@ -0,0 +1,201 @@
+//! Extension rule override check.
+//!
+//! This check detects file-extension rules that later directory rules
+//! override for the files in those directories.
+
+use super::{Check, CheckContext};
+use crate::matching::Pattern;
+use crate::parse::{LineKind, Owner, Span};
+use crate::validate::{ValidationError, ValidationResult};
+use std::collections::BTreeSet;
+
+/// A check that detects extension rules overridden by broad directory rules.
+///
+/// Since the last matching rule wins, an extension rule only owns files that
+/// no later rule matches:
+/// ```text
+/// *.proto     @api-team
+/// /services/  @backend-team    # owns services/**/*.proto, not @api-team
+/// ```
+///
+/// Teams often expect the extension rule to win everywhere. The check warns
+/// once per extension rule, listing the later directory rules with different
+/// owners that take its files.
+#[derive(Debug, Clone, Default)]
+pub struct ExtensionOverrideCheck;
+
+impl ExtensionOverrideCheck {
+    /// Creates a new extension override check.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+/// A rule compiled for comparison.
+struct CompiledRule<'a> {
+    text: &'a str,
+    line: usize,
+    span: Span,
+    owners: BTreeSet<String>,
+    matcher: Pattern,
+}
+
+/// Returns the extension of a `*.ext` or `**/*.ext` pattern.
+fn extension_of(pattern: &str) -> Option<&str> {
+    let extension = pattern
+        .strip_prefix("**/")
+        .unwrap_or(pattern)
+        .strip_prefix("*.")?;
+    let is_literal = !extension.is_empty() && !extension.contains(['/', '*', '?', '[', '\\']);
+    is_literal.then_some(extension)
+}
+
+/// Returns the directory part of a pattern covering a whole directory, such
+/// as `/src/`, `docs/**` or `/lib/*`. Catch-all patterns return `""`.
+fn directory_of(pattern: &str) -> Option<&str> {
+    if extension_of(pattern).is_some() || pattern.starts_with('!') {
+        return None;
+    }
+    let directory = if let Some(directory) = pattern.strip_suffix("/**") {
+        directory
+    } else if let Some(directory) = pattern.strip_suffix("/*") {
+        directory
+    } else if let Some(directory) = pattern.strip_suffix('/') {
+        directory
+    } else if pattern == "*" || pattern == "**" {
+        ""
+    } else {
+        return None;
+    };
+    Some(directory.trim_start_matches('/'))
+}
+
+impl Check for ExtensionOverrideCheck {
+    fn name(&self) -> &str {
+        "extension-override"
+    }
+
+    fn run(&self, ctx: &CheckContext) -> ValidationResult {
+        let mut result = ValidationResult::new();
+
+        let rules: Vec<CompiledRule> = ctx
+            .file
+            .lines
+            .iter()
+            .filter_map(|line| match &line.kind {
+                LineKind::Rule { pattern, owners } => Some(CompiledRule {
+                    text: &pattern.text,
+                    line: line.span.line,
+                    span: pattern.span,
+                    owners: owners.iter().map(Owner::normalized).collect(),
+                    matcher: Pattern::with_question_mark(&pattern.text, ctx.config.question_mark)?,
+                }),
+                _ => None,
+            })
+            .collect();
+
+        for (i, rule) in rules.iter().enumerate() {
+            let Some(extension) = extension_of(rule.text) else {
+                continue;
+            };
+
+            let overriding: Vec<String> = rules[i + 1..]
+                .iter()
+                .filter(|later| later.owners != rule.owners)
+                .filter(|later| {
+                    let Some(directory) = directory_of(later.text) else {
+                        return false;
+                    };
+                    // A file directly inside the directory stands in for all
+                    // the files of that extension the later rule takes
+                    let sample = if directory.is_empty() {
+                        format!("x.{}", extension)
+                    } else {
+                        format!("{}/x.{}", directory.replace('*', "x"), extension)
+                    };
+                    rule.matcher.matches(&sample) && later.matcher.matches(&sample)
+                })
+                .map(|later| format!("'{}' (line {})", later.text, later.line))
+                .collect();
+
+            if overriding.is_empty() {
+                continue;
+            }
+
+            result.add_error(ValidationError::extension_rule_overridden(
+                rule.text,
+                overriding.join(", "),
+                rule.span,
+            ));
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_codeowners;
+    use crate::validate::checks::CheckConfig;
+    use std::path::PathBuf;
+
+    fn run_check(input: &str) -> ValidationResult {
+        let file = parse_codeowners(input).ast;
+        let path = PathBuf::from("/repo");
+        let config = CheckConfig::default();
+        let ctx = CheckContext::new(&file, &path, &config);
+        ExtensionOverrideCheck::new().run(&ctx)
+    }
+
+    #[test]
+    fn reports_overriding_directory_rules() {
+        let result = run_check(
+            "*.proto @api-team\n/services/ @backend\n/docs/** @docs\n/lib/* @api-team\n* @default\n",
+        );
+        assert_eq!(result.errors.len(), 1);
+        match &result.errors[0] {
+            ValidationError::ExtensionRuleOverridden {
+                line,
+                pattern,
+                overriding,
+                ..
+            } => {
+                assert_eq!(*line, 1);
+                assert_eq!(pattern, "*.proto");
+                // /lib/* has the same owners, so nothing changes there
+                assert_eq!(
+                    overriding,
+                    "'/services/' (line 2), '/docs/**' (line 3), '*' (line 5)"
+                );
+            }
+            _ => panic!("Expected ExtensionRuleOverridden error"),
+        }
+    }
+
+    #[test]
+    fn ignores_rules_that_do_not_override() {
+        // Earlier directory rules and later specific rules are fine
+        let result = run_check("/services/ @backend\n**/*.proto @api-team\n/services/a.proto @a\n");
+        assert!(result.is_ok());
+
+        let result = run_check("*.proto @api-team\n*.rs @rust\n/src/main.c @c\n");
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn extension_patterns() {
+        assert_eq!(extension_of("*.proto"), Some("proto"));
+        assert_eq!(extension_of("**/*.tar.gz"), Some("tar.gz"));
+        assert_eq!(extension_of("/src/*.rs"), None);
+        assert_eq!(extension_of("*.*"), None);
+        assert_eq!(directory_of("/src/"), Some("src"));
+        assert_eq!(directory_of("**"), Some(""));
+        assert_eq!(directory_of("/src/main.rs"), None);
+    }
+
+    #[test]
+    fn check_name() {
+        assert_eq!(ExtensionOverrideCheck::new().name(), "extension-override");
+    }
+}
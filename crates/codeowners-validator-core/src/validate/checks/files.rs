@@ -1,11 +1,15 @@
 //! Files existence check.
 //!
 //! This check verifies that patterns in CODEOWNERS actually match files in the repository.
+//! It also warns about patterns whose matches depend on whether `?` is read as a
+//! wildcard or a literal character.
 
+use super::notowned::NotOwnedCheck;
 use super::{Check, CheckContext};
-use crate::matching::Pattern;
+use crate::matching::{Pattern, QuestionMark};
 use crate::parse::LineKind;
-use crate::validate::file_walker::{FileWalkerConfig, list_files};
+use crate::validate::file_walker::{CodeownersIgnore, FileWalkerConfig};
+use crate::validate::fix::line_removal;
 use crate::validate::{ValidationError, ValidationResult};
 use log::{debug, trace};
 
@@ -15,6 +19,13 @@ use log::{debug, trace};
 /// - Typos in the pattern
 /// - Files that have been deleted
 /// - Incorrect path assumptions
+///
+/// Deleting such a rule does not change who owns any file today, so it is
+/// offered as a fix.
+///
+/// Patterns for paths the repository's `.codeownersignore` file ignores
+/// (see [`CodeownersIgnore`]) are not checked, e.g. rules for build output
+/// that is absent from a clean checkout.
 #[derive(Debug, Clone, Default)]
 pub struct FilesCheck;
 
@@ -28,10 +39,32 @@ impl FilesCheck {
     fn pattern_matches_any(pattern: &Pattern, files: &[String]) -> bool {
         files.iter().any(|file| pattern.matches(file))
     }
+
+    /// Counts the files a pattern matches under each reading of `?`.
+    ///
+    /// Returns `(wildcard, literal)` counts, or `None` if they are equal.
+    fn question_mark_ambiguity(pattern: &str, files: &[String]) -> Option<(usize, usize)> {
+        let count = |question_mark| {
+            Pattern::with_question_mark(pattern, question_mark).map_or(0, |compiled| {
+                files.iter().filter(|f| compiled.matches(f)).count()
+            })
+        };
+        let wildcard = count(QuestionMark::Wildcard);
+        let literal = count(QuestionMark::Literal);
+        (wildcard != literal).then_some((wildcard, literal))
+    }
+
+    /// Returns true if the ignore file ignores the paths a pattern names.
+    fn is_ignored(ignore: &CodeownersIgnore, pattern: &str) -> bool {
+        let path = pattern.trim_start_matches('/');
+        let is_dir = path.ends_with('/');
+        let path = path.trim_end_matches('/');
+        !path.is_empty() && ignore.is_ignored(path, is_dir)
+    }
 }
 
 impl Check for FilesCheck {
-    fn name(&self) -> &'static str {
+    fn name(&self) -> &str {
         "files"
     }
 
@@ -40,26 +73,64 @@ impl Check for FilesCheck {
         let mut result = ValidationResult::new();
 
         // List all files in the repository (excludes hidden, includes dirs)
-        let files = list_files(ctx.repo_path, &FileWalkerConfig::for_files_check());
+        let files = match ctx.list_files(
+            &ctx.config
+                .walker_config(FileWalkerConfig::for_files_check()),
+        ) {
+            Ok(files) => files,
+            Err(e) => {
+                result.set_internal_error(e.to_string());
+                return result;
+            }
+        };
+        let ignore = ctx.codeowners_ignore();
 
         // Check each pattern
-        for line in &ctx.file.lines {
+        for (index, line) in ctx.file.lines.iter().enumerate() {
             if let LineKind::Rule { pattern, .. } = &line.kind {
                 trace!("Checking pattern: {}", pattern.text);
-                // Compile the pattern
-                if let Some(compiled) = Pattern::new(&pattern.text)
+                // Compile the pattern, or the pattern an exclusion excludes
+                let (text, _) = ctx.config.pattern_syntax.split_negation(&pattern.text);
+                if ignore
+                    .as_ref()
+                    .is_some_and(|ignore| Self::is_ignored(ignore, text))
+                {
+                    trace!("Pattern '{}' is ignored", pattern.text);
+                    continue;
+                }
+                if let Some(compiled) = Pattern::with_question_mark(text, ctx.config.question_mark)
                     && !Self::pattern_matches_any(&compiled, &files)
                 {
                     debug!("Pattern '{}' does not match any files", pattern.text);
-                    result.add_error(ValidationError::pattern_not_matching(
+                    result.add_error(ValidationError::pattern_not_matching_with_removal(
                         &pattern.text,
                         pattern.span,
+                        line_removal(ctx.file, index),
                     ));
                 }
                 // If pattern compilation fails, that's a syntax error handled elsewhere
+
+                if text.contains('?')
+                    && let Some((wildcard, literal)) = Self::question_mark_ambiguity(text, &files)
+                {
+                    result.add_error(ValidationError::ambiguous_question_mark(
+                        &pattern.text,
+                        wildcard,
+                        literal,
+                        pattern.span,
+                    ));
+                }
             }
         }
 
+        // Patterns may only match files the walk did not reach
+        if let Some(reason) = &files.incomplete {
+            result.add_error(ValidationError::incomplete_listing(
+                reason,
+                NotOwnedCheck::eof_span(ctx.file),
+            ));
+        }
+
         debug!("Files check complete: {} errors found", result.errors.len());
         result
     }
@@ -70,6 +141,7 @@ mod tests {
     use super::*;
     use crate::parse::parse_codeowners;
     use crate::validate::checks::CheckConfig;
+    use crate::validate::file_walker::list_files;
     use std::fs::{self, File};
     use std::path::Path;
     use tempfile::TempDir;
@@ -96,6 +168,45 @@ mod tests {
         FilesCheck::new().run(&ctx)
     }
 
+    #[test]
+    fn unknown_git_rev_is_an_internal_error() {
+        let dir = setup_test_dir();
+        let status = std::process::Command::new("git")
+            .arg("-C")
+            .arg(dir.path())
+            .args(["init", "-q"])
+            .status()
+            .unwrap();
+        assert!(status.success());
+
+        let file = parse_codeowners("/missing/ @owner\n").ast;
+        let config = CheckConfig::new().with_git_rev("no-such-rev");
+        let ctx = CheckContext::new(&file, dir.path(), &config);
+        let result = FilesCheck::new().run(&ctx);
+
+        // Nothing was listed, so no pattern is reported as unmatched
+        assert!(result.errors.is_empty());
+        assert_eq!(
+            result.internal_error.as_deref(),
+            Some(
+                "failed to list the files of revision 'no-such-rev': unknown revision 'no-such-rev'"
+            )
+        );
+    }
+
+    #[test]
+    fn timed_out_walk_is_reported() {
+        let dir = setup_test_dir();
+        let file = parse_codeowners("/src/ @owner\n").ast;
+        let config = CheckConfig::new().with_walk_timeout(std::time::Duration::ZERO);
+        let ctx = CheckContext::new(&file, dir.path(), &config);
+        let result = FilesCheck::new().run(&ctx);
+
+        let codes: Vec<_> = result.errors.iter().map(|e| e.code()).collect();
+        assert!(codes.contains(&"CO038"));
+        assert!(result.internal_error.is_none());
+    }
+
     #[test]
     fn pattern_matches_existing_files() {
         let dir = setup_test_dir();
@@ -113,6 +224,17 @@ mod tests {
         assert!(result.is_ok(), "Pattern /src/ should match files in src/");
     }
 
+    #[test]
+    fn skips_patterns_in_codeownersignore() {
+        let dir = setup_test_dir();
+        fs::write(dir.path().join(".codeownersignore"), "build/\n*.log\n").unwrap();
+        let input = "/build/ @owner\n/web/build/** @owner\n*.log @owner\n/dist/ @owner\n";
+        let result = run_check(input, dir.path());
+
+        let subjects: Vec<_> = result.errors.iter().map(|e| e.subject()).collect();
+        assert_eq!(subjects, ["/dist/"]);
+    }
+
     #[test]
     fn pattern_not_matching_reports_error() {
         let dir = setup_test_dir();
@@ -172,6 +294,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn question_mark_ambiguity_warns() {
+        let dir = TempDir::new().unwrap();
+        File::create(dir.path().join("file1.txt")).unwrap();
+        File::create(dir.path().join("file?.txt")).unwrap();
+
+        let result = run_check("/file?.txt @owner\n", dir.path());
+        assert_eq!(result.errors.len(), 1);
+        match &result.errors[0] {
+            ValidationError::AmbiguousQuestionMark {
+                wildcard_matches,
+                literal_matches,
+                ..
+            } => {
+                assert_eq!(*wildcard_matches, 2);
+                assert_eq!(*literal_matches, 1);
+            }
+            other => panic!("Expected AmbiguousQuestionMark error, got {other:?}"),
+        }
+
+        // No warning when both readings match the same files
+        let dir = setup_test_dir();
+        let result = run_check("/missing?/ @owner\n", dir.path());
+        assert_eq!(result.errors.len(), 1);
+        assert!(matches!(
+            result.errors[0],
+            ValidationError::PatternNotMatching { .. }
+        ));
+    }
+
     #[test]
     fn empty_directory() {
         let dir = TempDir::new().unwrap();
@@ -180,6 +332,26 @@ mod tests {
         assert!(result.has_errors());
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn symlinked_paths_follow_the_policy() {
+        use crate::validate::file_walker::SymlinkPolicy;
+
+        let dir = setup_test_dir();
+        std::os::unix::fs::symlink(dir.path().join("docs"), dir.path().join("manual")).unwrap();
+        let file = parse_codeowners("/manual/README.md @owner\n/manual @owner\n").ast;
+        let run = |policy| {
+            let config = CheckConfig::new().with_symlink_policy(policy);
+            let ctx = CheckContext::new(&file, dir.path(), &config);
+            FilesCheck::new().run(&ctx).errors.len()
+        };
+
+        assert_eq!(run(SymlinkPolicy::Skip), 2);
+        assert_eq!(run(SymlinkPolicy::Follow), 0);
+        // The link itself is listed, but not what it points to
+        assert_eq!(run(SymlinkPolicy::Report), 1);
+    }
+
     #[test]
     fn list_files_basic() {
         let dir = setup_test_dir();
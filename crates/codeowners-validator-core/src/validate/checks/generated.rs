@@ -0,0 +1,109 @@
+//! Generated region check.
+//!
+//! This check detects hand edits to generated regions of CODEOWNERS.
+
+use super::{Check, CheckContext};
+use crate::validate::{ValidationError, ValidationResult};
+use log::debug;
+
+/// A check that forbids manual edits to generated regions.
+///
+/// Generated regions start with a `# generated-by:` comment (see
+/// [`crate::parse::Provenance`]). When a region records a
+/// `# generated-hash:`, its content is hashed again and any mismatch is
+/// reported, since the next generator run would silently discard the edit.
+/// Regions without a recorded hash are not checked.
+#[derive(Debug, Clone, Default)]
+pub struct GeneratedRegionsCheck;
+
+impl GeneratedRegionsCheck {
+    /// Creates a new generated region check.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Check for GeneratedRegionsCheck {
+    fn name(&self) -> &str {
+        "generated-regions"
+    }
+
+    fn run(&self, ctx: &CheckContext) -> ValidationResult {
+        let mut result = ValidationResult::new();
+
+        for region in ctx.file.generated_regions() {
+            let Some(expected) = region.hash.as_deref() else {
+                debug!(
+                    "Generated region from '{}' has no recorded hash",
+                    region.generator
+                );
+                continue;
+            };
+
+            if region.is_modified(ctx.file) == Some(true) {
+                result.add_error(ValidationError::generated_region_modified(
+                    &region.generator,
+                    expected,
+                    region.compute_hash(ctx.file),
+                    region.span(ctx.file),
+                ));
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_codeowners;
+    use crate::validate::checks::CheckConfig;
+    use std::path::PathBuf;
+
+    fn run_check(input: &str) -> ValidationResult {
+        let file = parse_codeowners(input).ast;
+        let path = PathBuf::from("/repo");
+        let config = CheckConfig::new();
+        let ctx = CheckContext::new(&file, &path, &config);
+        GeneratedRegionsCheck::new().run(&ctx)
+    }
+
+    fn generated(owner: &str, hash: &str) -> String {
+        format!(
+            "* @default\n# generated-by: gen v1\n# generated-hash: {hash}\n/api/ {owner}\n# end-generated\n"
+        )
+    }
+
+    #[test]
+    fn unmodified_region() {
+        let file = parse_codeowners(&generated("@org/api", "sha256:0")).ast;
+        let hash = file.generated_regions()[0].compute_hash(&file);
+
+        assert!(run_check(&generated("@org/api", &hash)).is_ok());
+    }
+
+    #[test]
+    fn modified_region() {
+        let file = parse_codeowners(&generated("@org/api", "sha256:0")).ast;
+        let hash = file.generated_regions()[0].compute_hash(&file);
+
+        let result = run_check(&generated("@someone", &hash));
+        assert_eq!(result.errors.len(), 1);
+        assert!(matches!(
+            &result.errors[0],
+            ValidationError::GeneratedRegionModified { line: 2, generator, expected_hash, .. }
+                if generator == "gen v1" && *expected_hash == hash
+        ));
+
+        // The span covers the region through its end marker
+        let span = result.errors[0].span();
+        assert_eq!((span.line, span.end_line), (2, 5));
+    }
+
+    #[test]
+    fn region_without_hash_is_skipped() {
+        let result = run_check("# generated-by: gen v1\n/api/ @org/api\n");
+        assert!(result.is_ok());
+    }
+}
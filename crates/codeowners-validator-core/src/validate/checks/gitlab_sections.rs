@@ -0,0 +1,125 @@
+//! GitLab section validation check.
+//!
+//! This check validates the approval counts and default owners of GitLab
+//! section headers such as `[Documentation][2] @docs-team`.
+
+use super::{Check, CheckContext};
+use crate::parse::LineKind;
+use crate::validate::syntax::validate_owner_syntax;
+use crate::validate::{ValidationError, ValidationResult};
+
+/// A check that validates GitLab section headers.
+///
+/// Sections are only parsed with [`Dialect::GitLab`](crate::parse::Dialect::GitLab),
+/// so the check finds nothing in GitHub CODEOWNERS files. It reports:
+/// - Approval counts that are not a positive integer, e.g. `[Docs][0]`
+/// - Default owners with an invalid format, as the syntax check does for
+///   rule owners
+#[derive(Debug, Clone, Default)]
+pub struct GitLabSectionsCheck;
+
+impl GitLabSectionsCheck {
+    /// Creates a new GitLab sections check.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Check for GitLabSectionsCheck {
+    fn name(&self) -> &str {
+        "gitlab-sections"
+    }
+
+    fn run(&self, ctx: &CheckContext) -> ValidationResult {
+        let mut result = ValidationResult::new();
+
+        for line in &ctx.file.lines {
+            let LineKind::Section {
+                name,
+                approvals,
+                owners,
+                ..
+            } = &line.kind
+            else {
+                continue;
+            };
+
+            if let Some(approvals) = approvals
+                && approvals.count().is_none()
+            {
+                result.add_error(ValidationError::invalid_section_approvals(
+                    name,
+                    &approvals.text,
+                    approvals.span,
+                ));
+            }
+            for owner in owners {
+                if let Some(error) = validate_owner_syntax(owner) {
+                    result.add_error(error);
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{Dialect, ParserConfig, parse_codeowners_with_config};
+    use crate::validate::checks::CheckConfig;
+    use std::path::PathBuf;
+
+    fn run_check(input: &str) -> ValidationResult {
+        let config = ParserConfig::new().with_dialect(Dialect::GitLab);
+        let file = parse_codeowners_with_config(input, &config).ast;
+        let path = PathBuf::from("/repo");
+        let config = CheckConfig::new();
+        let ctx = CheckContext::new(&file, &path, &config);
+        GitLabSectionsCheck::new().run(&ctx)
+    }
+
+    #[test]
+    fn valid_sections() {
+        let result = run_check("[Docs] @docs\n^[Backend][2] @org/backend user@example.com\n");
+        assert!(result.is_ok(), "{:?}", result.errors);
+    }
+
+    #[test]
+    fn invalid_approval_counts() {
+        let result = run_check("[Docs][0] @docs\n[API][two] @api\n[Web][] @web\n[Ok][3]\n");
+
+        let reported: Vec<_> = result
+            .errors
+            .iter()
+            .map(|error| match error {
+                ValidationError::InvalidSectionApprovals {
+                    line,
+                    approvals,
+                    span,
+                    ..
+                } => (*line, approvals.as_str(), span.column),
+                other => panic!("Expected InvalidSectionApprovals, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(reported, [(1, "0", 8), (2, "two", 7), (3, "", 7)]);
+    }
+
+    #[test]
+    fn invalid_default_owners() {
+        let result = run_check("[Docs][1] @docs @-bad @org/\n");
+        assert_eq!(result.errors.len(), 2);
+        assert!(
+            result
+                .errors
+                .iter()
+                .all(|error| matches!(error, ValidationError::InvalidOwnerFormat { line: 1, .. }))
+        );
+    }
+
+    #[test]
+    fn check_name() {
+        assert_eq!(GitLabSectionsCheck::new().name(), "gitlab-sections");
+    }
+}
@@ -0,0 +1,143 @@
+//! Identity provider group check.
+//!
+//! This check verifies that team owners are backed by identity provider groups.
+
+use super::{Check, CheckContext};
+use crate::parse::{LineKind, Owner};
+use crate::validate::{ValidationError, ValidationResult};
+use log::debug;
+
+/// A check that cross-checks team owners against an IdP group export.
+///
+/// For orgs whose GitHub teams are synced from an identity provider, every
+/// team referenced in CODEOWNERS should map to an active IdP group with at
+/// least [`IdpDirectory::min_members`] members. Otherwise the team is, or
+/// will become, unable to review. Runs offline against the export set with
+/// [`CheckConfig::with_idp_directory`], and does nothing without one.
+///
+/// [`IdpDirectory::min_members`]: crate::validate::idp::IdpDirectory::min_members
+/// [`CheckConfig::with_idp_directory`]: super::CheckConfig::with_idp_directory
+#[derive(Debug, Clone, Default)]
+pub struct IdpGroupsCheck;
+
+impl IdpGroupsCheck {
+    /// Creates a new IdP group check.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Check for IdpGroupsCheck {
+    fn name(&self) -> &str {
+        "idp-groups"
+    }
+
+    fn run(&self, ctx: &CheckContext) -> ValidationResult {
+        let mut result = ValidationResult::new();
+
+        let Some(directory) = &ctx.config.idp_directory else {
+            debug!("No IdP group export configured, skipping idp-groups check");
+            return result;
+        };
+
+        for line in &ctx.file.lines {
+            let LineKind::Rule { owners, .. } = &line.kind else {
+                continue;
+            };
+            for owner in owners {
+                let Owner::Team { org, team, span } = owner else {
+                    continue;
+                };
+                let owner_str = owner.as_str();
+                if ctx
+                    .config
+                    .ignored_owners
+                    .iter()
+                    .any(|ignored| ignored.eq_ignore_ascii_case(&owner_str))
+                {
+                    continue;
+                }
+
+                let reason = match directory.group_for_team(org, team) {
+                    None => "has no matching identity provider group".to_string(),
+                    Some(group) if !group.active => {
+                        format!("maps to inactive identity provider group '{}'", group.name)
+                    }
+                    Some(group) if group.members < directory.min_members() => format!(
+                        "maps to identity provider group '{}' with {} member(s), fewer than {}",
+                        group.name,
+                        group.members,
+                        directory.min_members()
+                    ),
+                    Some(_) => continue,
+                };
+                result.add_error(ValidationError::idp_group_mismatch(
+                    owner_str, reason, *span,
+                ));
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_codeowners;
+    use crate::validate::checks::CheckConfig;
+    use crate::validate::idp::{IdpDirectory, IdpGroup};
+    use std::collections::HashSet;
+    use std::path::PathBuf;
+
+    fn group(name: &str, active: bool, members: usize) -> IdpGroup {
+        IdpGroup {
+            name: name.to_string(),
+            active,
+            members,
+        }
+    }
+
+    fn run_check(input: &str, config: &CheckConfig) -> ValidationResult {
+        let file = parse_codeowners(input).ast;
+        let path = PathBuf::from("/repo");
+        let ctx = CheckContext::new(&file, &path, config);
+        IdpGroupsCheck::new().run(&ctx)
+    }
+
+    #[test]
+    fn reports_unbacked_teams() {
+        let directory = IdpDirectory::new([
+            group("platform", true, 3),
+            group("legacy", false, 3),
+            group("tiny", true, 1),
+        ])
+        .with_min_members(2);
+        let config = CheckConfig::new().with_idp_directory(directory);
+
+        let result = run_check(
+            "* @org/platform @alice\n/a/ @org/legacy\n/b/ @org/tiny\n/c/ @org/ghost\n",
+            &config,
+        );
+
+        let messages: Vec<String> = result.errors.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "line 2: team '@org/legacy' maps to inactive identity provider group 'legacy'",
+                "line 3: team '@org/tiny' maps to identity provider group 'tiny' with 1 member(s), fewer than 2",
+                "line 4: team '@org/ghost' has no matching identity provider group",
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_without_export_and_ignored_owners() {
+        assert!(run_check("* @org/ghost\n", &CheckConfig::new()).is_ok());
+
+        let config = CheckConfig::new()
+            .with_idp_directory(IdpDirectory::new([]))
+            .with_ignored_owners(HashSet::from(["@org/ghost".to_string()]));
+        assert!(run_check("* @org/ghost\n", &config).is_ok());
+    }
+}
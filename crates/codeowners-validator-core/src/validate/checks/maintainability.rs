@@ -0,0 +1,193 @@
+//! Maintainability check.
+//!
+//! This check detects owner lists repeated across many rules, which are
+//! easier to maintain as a single team or section.
+
+use super::{Check, CheckContext};
+use crate::matching::PatternSyntax;
+use crate::parse::{LineKind, Owner, Span};
+use crate::validate::{ValidationError, ValidationResult};
+use log::debug;
+
+/// Number of rules sharing an owner list before it is reported, unless
+/// [`CheckConfig::repeated_owners_threshold`](super::CheckConfig::repeated_owners_threshold)
+/// is set.
+pub const DEFAULT_REPEATED_OWNERS_THRESHOLD: usize = 3;
+
+/// A check that detects repeated multi-owner lists.
+///
+/// When the same group of owners is listed on many rules, adding or removing
+/// someone means editing every one of them, and a missed rule silently
+/// diverges:
+/// ```text
+/// /api/      @alice @bob @org/backend
+/// /worker/   @alice @bob @org/backend
+/// /jobs/     @alice @bob @org/backend
+/// ```
+///
+/// Lists of two or more owners that appear, in any order, on at least the
+/// threshold number of rules are reported once, at their first rule. For
+/// GitLab files ([`PatternSyntax::GitLab`]) the check suggests a section with
+/// default owners; for GitHub, which has no aliases, a team containing the
+/// owners.
+#[derive(Debug, Clone, Default)]
+pub struct MaintainabilityCheck;
+
+impl MaintainabilityCheck {
+    /// Creates a new maintainability check.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+/// Rules sharing one owner list.
+struct OwnerGroup<'a> {
+    key: Vec<String>,
+    owners: &'a [Owner],
+    lines: Vec<usize>,
+}
+
+impl Check for MaintainabilityCheck {
+    fn name(&self) -> &str {
+        "maintainability"
+    }
+
+    fn run(&self, ctx: &CheckContext) -> ValidationResult {
+        let mut result = ValidationResult::new();
+        let threshold = ctx
+            .config
+            .repeated_owners_threshold
+            .unwrap_or(DEFAULT_REPEATED_OWNERS_THRESHOLD);
+
+        let mut groups: Vec<OwnerGroup> = Vec::new();
+        for line in &ctx.file.lines {
+            let LineKind::Rule { owners, .. } = &line.kind else {
+                continue;
+            };
+            let mut key: Vec<String> = owners.iter().map(Owner::normalized).collect();
+            key.sort_unstable();
+            key.dedup();
+            if key.len() < 2 {
+                continue;
+            }
+
+            match groups.iter_mut().find(|group| group.key == key) {
+                Some(group) => group.lines.push(line.span.line),
+                None => groups.push(OwnerGroup {
+                    key,
+                    owners,
+                    lines: vec![line.span.line],
+                }),
+            }
+        }
+
+        for group in groups {
+            if group.lines.len() < threshold {
+                continue;
+            }
+            debug!(
+                "Owner list {:?} is repeated on {} rules",
+                group.key,
+                group.lines.len()
+            );
+
+            let owners: Vec<_> = group.owners.iter().map(Owner::as_str).collect();
+            let owners = owners.join(" ");
+            let suggestion = if ctx.config.pattern_syntax == PatternSyntax::GitLab {
+                format!(
+                    "group the rules under a section with default owners, e.g. '[Section] {}', and drop their owner lists",
+                    owners
+                )
+            } else {
+                "consider a team containing these owners, so membership changes in one place"
+                    .to_string()
+            };
+            let lines: Vec<_> = group.lines.iter().map(usize::to_string).collect();
+
+            let first = group.owners[0].span();
+            let last = group.owners[group.owners.len() - 1].span();
+            result.add_error(ValidationError::repeated_owner_group(
+                owners,
+                lines.join(", "),
+                suggestion,
+                Span::new(
+                    first.offset,
+                    first.line,
+                    first.column,
+                    last.end_offset() - first.offset,
+                ),
+            ));
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_codeowners;
+    use crate::validate::checks::CheckConfig;
+    use std::path::PathBuf;
+
+    const CODEOWNERS: &str = "\
+/api/ @alice @org/backend
+/worker/ @org/backend @Alice
+/docs/ @docs
+/jobs/ @alice @org/backend @alice
+/web/ @alice @bob
+/mobile/ @alice @bob
+";
+
+    fn run_check(config: &CheckConfig) -> ValidationResult {
+        let file = parse_codeowners(CODEOWNERS).ast;
+        let path = PathBuf::from("/repo");
+        let ctx = CheckContext::new(&file, &path, config);
+        MaintainabilityCheck::new().run(&ctx)
+    }
+
+    #[test]
+    fn reports_repeated_owner_lists() {
+        let result = run_check(&CheckConfig::new());
+        assert_eq!(result.errors.len(), 1);
+
+        match &result.errors[0] {
+            ValidationError::RepeatedOwnerGroup {
+                line,
+                owners,
+                lines,
+                suggestion,
+                span,
+            } => {
+                assert_eq!(*line, 1);
+                assert_eq!(owners, "@alice @org/backend");
+                assert_eq!(lines, "1, 2, 4");
+                assert!(suggestion.contains("team"));
+                assert_eq!((span.column, span.length), (7, 19));
+            }
+            other => panic!("Expected RepeatedOwnerGroup, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn threshold_and_gitlab_suggestion() {
+        let config = CheckConfig::new()
+            .with_repeated_owners_threshold(2)
+            .with_pattern_syntax(PatternSyntax::GitLab);
+        let result = run_check(&config);
+        assert_eq!(result.errors.len(), 2);
+        assert!(
+            result.errors[1]
+                .to_string()
+                .contains("e.g. '[Section] @alice @bob'")
+        );
+
+        let config = CheckConfig::new().with_repeated_owners_threshold(4);
+        assert!(run_check(&config).is_ok());
+    }
+
+    #[test]
+    fn check_name() {
+        assert_eq!(MaintainabilityCheck::new().name(), "maintainability");
+    }
+}
@@ -0,0 +1,249 @@
+//! Ownership manifest check.
+//!
+//! This check verifies that CODEOWNERS assigns exactly the teams an ownership
+//! manifest declares for each path.
+
+use super::notowned::NotOwnedCheck;
+use super::{Check, CheckContext};
+use crate::matching::OwnersResolver;
+use crate::parse::Owner;
+use crate::validate::file_walker::FileWalkerConfig;
+use crate::validate::manifest::ManifestEntry;
+use crate::validate::{ValidationError, ValidationResult};
+use log::debug;
+use std::collections::BTreeSet;
+
+/// A check that verifies ownership against a manifest.
+///
+/// For compliance regimes that audit ownership, an
+/// [`OwnershipManifest`](crate::validate::manifest::OwnershipManifest)
+/// declares which teams must own a path, and within what response SLA. The
+/// owners CODEOWNERS assigns must match the declared teams exactly: a
+/// missing team breaks the response guarantee, and an extra owner dilutes
+/// accountability.
+///
+/// File entries are resolved directly, whether or not the file exists.
+/// Directory entries cover every file in the directory, and are reported
+/// once, at the first file with the wrong owners. Findings point at the
+/// rule that owns the file, or at the end of the file when no rule does.
+/// Runs offline against the manifest set with
+/// [`CheckConfig::with_ownership_manifest`](super::CheckConfig::with_ownership_manifest),
+/// and does nothing without one.
+#[derive(Debug, Clone, Default)]
+pub struct OwnershipManifestCheck;
+
+impl OwnershipManifestCheck {
+    /// Creates a new ownership manifest check.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+/// Describes owners for a message, e.g. `@a, @b`.
+fn describe(owners: &BTreeSet<String>) -> String {
+    owners.iter().cloned().collect::<Vec<_>>().join(", ")
+}
+
+/// Describes the declared requirement of an entry.
+fn requirement(entry: &ManifestEntry, expected: &BTreeSet<String>) -> String {
+    match &entry.sla {
+        Some(sla) => format!("{} (SLA {})", describe(expected), sla),
+        None => describe(expected),
+    }
+}
+
+impl Check for OwnershipManifestCheck {
+    fn name(&self) -> &str {
+        "ownership-manifest"
+    }
+
+    fn run(&self, ctx: &CheckContext) -> ValidationResult {
+        let mut result = ValidationResult::new();
+
+        let Some(manifest) = &ctx.config.ownership_manifest else {
+            debug!("No ownership manifest configured, skipping ownership-manifest check");
+            return result;
+        };
+
+        let resolver = OwnersResolver::with_syntax(
+            ctx.file,
+            ctx.config.question_mark,
+            ctx.config.pattern_syntax,
+        );
+        let eof_span = NotOwnedCheck::eof_span(ctx.file);
+        let listing = if manifest.entries().iter().any(ManifestEntry::is_directory) {
+            ctx.list_files(
+                &ctx.config.walker_config(
+                    FileWalkerConfig::for_not_owned_check()
+                        .with_force_respect_gitignore(ctx.config.force_respect_gitignore),
+                ),
+            )
+        } else {
+            Ok(Default::default())
+        };
+        let files = match listing {
+            Ok(files) => files,
+            Err(e) => {
+                result.set_internal_error(e.to_string());
+                return result;
+            }
+        };
+
+        for entry in manifest.entries() {
+            let expected: BTreeSet<String> =
+                entry.teams.iter().map(|team| team.to_lowercase()).collect();
+
+            let paths: Vec<&str> = if entry.is_directory() {
+                files
+                    .iter()
+                    .map(String::as_str)
+                    .filter(|file| file.starts_with(entry.relative_path()))
+                    .collect()
+            } else {
+                vec![entry.relative_path()]
+            };
+
+            if paths.is_empty() {
+                result.add_error(ValidationError::ownership_manifest_mismatch(
+                    &entry.path,
+                    "matches no files in the repository",
+                    eof_span,
+                ));
+                continue;
+            }
+
+            let mut mismatches = paths.iter().filter_map(|path| {
+                let rule = resolver.rule_for(path);
+                let actual: BTreeSet<String> = rule
+                    .as_ref()
+                    .map(|rule| rule.owners.iter().map(Owner::normalized).collect())
+                    .unwrap_or_default();
+                (actual != expected).then_some((path, rule, actual))
+            });
+            let Some((path, rule, actual)) = mismatches.next() else {
+                continue;
+            };
+            let others = mismatches.count();
+
+            let mut reason = if actual.is_empty() {
+                format!(
+                    "must be owned by exactly {}, but '{}' has no owners",
+                    requirement(entry, &expected),
+                    path
+                )
+            } else {
+                let missing: BTreeSet<_> = expected.difference(&actual).cloned().collect();
+                let extra: BTreeSet<_> = actual.difference(&expected).cloned().collect();
+                let mut differences = Vec::new();
+                if !missing.is_empty() {
+                    differences.push(format!("missing {}", describe(&missing)));
+                }
+                if !extra.is_empty() {
+                    differences.push(format!("extra {}", describe(&extra)));
+                }
+                format!(
+                    "must be owned by exactly {}, but '{}' is owned by {} ({})",
+                    requirement(entry, &expected),
+                    path,
+                    describe(&actual),
+                    differences.join("; ")
+                )
+            };
+            if others > 0 {
+                reason.push_str(&format!(", as are {} more file(s)", others));
+            }
+
+            let span = rule.map_or(eof_span, |rule| rule.pattern.span);
+            result.add_error(ValidationError::ownership_manifest_mismatch(
+                &entry.path,
+                reason,
+                span,
+            ));
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_codeowners;
+    use crate::validate::checks::CheckConfig;
+    use crate::validate::manifest::OwnershipManifest;
+    use std::fs::{self, File};
+    use tempfile::TempDir;
+
+    const CODEOWNERS: &str = "\
+* @acme/core
+/services/payments/ @acme/payments
+/services/payments/legacy/ @acme/payments @alice
+/infra/prod.tf @acme/sre
+";
+
+    fn run_check(manifest: &str) -> ValidationResult {
+        let dir = TempDir::new().unwrap();
+        for file in [
+            "services/payments/api.rs",
+            "services/payments/legacy/old.rs",
+            "services/payments/legacy/older.rs",
+        ] {
+            let path = dir.path().join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            File::create(path).unwrap();
+        }
+
+        let file = parse_codeowners(CODEOWNERS).ast;
+        let config = CheckConfig::new()
+            .with_ownership_manifest(OwnershipManifest::from_json(manifest).unwrap());
+        let ctx = CheckContext::new(&file, dir.path(), &config);
+        OwnershipManifestCheck::new().run(&ctx)
+    }
+
+    #[test]
+    fn reports_mismatched_owners() {
+        let result = run_check(
+            r#"[
+                {"path": "/services/payments/", "teams": ["@Acme/Payments"], "sla": "4h"},
+                {"path": "/infra/prod.tf", "teams": ["@acme/sre", "@acme/security"]},
+                {"path": "/services/ledger/", "teams": ["@acme/ledger"]}
+            ]"#,
+        );
+        assert_eq!(result.errors.len(), 3);
+
+        let messages: Vec<_> = result.errors.iter().map(|e| e.to_string()).collect();
+        assert_eq!(
+            messages[0],
+            "line 3: ownership manifest path '/services/payments/' must be owned by exactly \
+             @acme/payments (SLA 4h), but 'services/payments/legacy/old.rs' is owned by \
+             @acme/payments, @alice (extra @alice), as are 1 more file(s)"
+        );
+        assert!(messages[1].starts_with("line 4: "));
+        assert!(messages[1].ends_with("(missing @acme/security)"));
+        assert!(messages[2].ends_with("matches no files in the repository"));
+    }
+
+    #[test]
+    fn accepts_matching_owners() {
+        let result = run_check(
+            r#"[
+                {"path": "/services/payments/api.rs", "teams": ["@acme/payments"]},
+                {"path": "/docs/new.md", "teams": ["@acme/core"]}
+            ]"#,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn skipped_without_manifest() {
+        let file = parse_codeowners(CODEOWNERS).ast;
+        let config = CheckConfig::new();
+        let ctx = CheckContext::new(&file, std::path::Path::new("/repo"), &config);
+        assert!(OwnershipManifestCheck::new().run(&ctx).is_ok());
+    }
+
+    #[test]
+    fn check_name() {
+        assert_eq!(OwnershipManifestCheck::new().name(), "ownership-manifest");
+    }
+}
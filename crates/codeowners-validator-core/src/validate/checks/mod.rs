@@ -2,31 +2,133 @@
 //!
 //! This module provides a trait-based system for implementing validation checks
 //! that can be composed and run together.
+//!
+//! Checks written outside this crate are registered with
+//! [`CheckRunner::add_check`] like the built-in ones. They describe
+//! themselves with a [`CheckDescriptor`], read their own settings with
+//! [`CheckConfig::settings_for`], and report findings with
+//! [`CheckDescriptor::finding`]:
+//!
+//! ```rust
+//! use codeowners_validator_core::validate::checks::{
+//!     Check, CheckConfig, CheckContext, CheckDescriptor, CheckRunner,
+//! };
+//! use codeowners_validator_core::validate::{Severity, ValidationResult};
+//! use codeowners_validator_core::parse_codeowners;
+//! use std::path::Path;
+//!
+//! struct NoBotsCheck;
+//!
+//! impl Check for NoBotsCheck {
+//!     fn name(&self) -> &str {
+//!         "no-bots"
+//!     }
+//!
+//!     fn descriptor(&self) -> CheckDescriptor {
+//!         CheckDescriptor::new("no-bots").with_default_severity(Severity::Warning)
+//!     }
+//!
+//!     fn run(&self, ctx: &CheckContext) -> ValidationResult {
+//!         let suffix = ctx
+//!             .config
+//!             .settings_for(self.name())
+//!             .and_then(|settings| settings["suffix"].as_str())
+//!             .unwrap_or("[bot]");
+//!         let mut result = ValidationResult::new();
+//!         for (_, owners) in ctx.file.extract_rules() {
+//!             for owner in owners.iter().filter(|o| o.as_str().ends_with(suffix)) {
+//!                 let message = format!("bot owner '{}'", owner.as_str());
+//!                 result.add_error(self.descriptor().finding(message, *owner.span()));
+//!             }
+//!         }
+//!         result
+//!     }
+//! }
+//!
+//! let mut runner = CheckRunner::new();
+//! runner.add_check(NoBotsCheck);
+//!
+//! let file = parse_codeowners("*.rs @deploy[bot]\n").ast;
+//! let result = runner.run_sync(&file, Path::new("."), &CheckConfig::new());
+//! assert_eq!(result.errors[0].code(), "CO032");
+//! assert_eq!(result.errors[0].severity(), Severity::Warning);
+//! ```
 
+mod anchoring;
+mod annotations;
+mod case_collision;
+mod complexity;
 mod duppatterns;
+mod escalation;
+mod extension_override;
 mod files;
+mod generated;
+mod gitlab_sections;
+mod idp;
+mod maintainability;
+mod manifest;
 mod notowned;
 mod owners;
+mod owners_lock;
+mod review_by;
+mod self_ownership;
 mod shadowing;
 mod syntax;
 
+pub use anchoring::AnchoringCheck;
+pub use annotations::AnnotationsCheck;
+pub use case_collision::CaseCollisionCheck;
+pub use complexity::{
+    ComplexityCheck, ComplexityStats, DEFAULT_MAX_FILE_LINES, DEFAULT_MAX_RULES,
+    DEFAULT_MAX_RULES_PER_SECTION,
+};
 pub use duppatterns::DupPatternsCheck;
+pub use escalation::EscalationCheck;
+pub use extension_override::ExtensionOverrideCheck;
 pub use files::FilesCheck;
+pub use generated::GeneratedRegionsCheck;
+pub use gitlab_sections::GitLabSectionsCheck;
+pub use idp::IdpGroupsCheck;
+pub use maintainability::{DEFAULT_REPEATED_OWNERS_THRESHOLD, MaintainabilityCheck};
+pub use manifest::OwnershipManifestCheck;
 pub use notowned::NotOwnedCheck;
 pub use owners::OwnersCheck;
+pub use owners_lock::OwnersLockCheck;
+pub use review_by::{DEFAULT_REVIEW_WARNING_DAYS, ReviewByCheck};
+pub use self_ownership::SelfOwnershipCheck;
 pub use shadowing::AvoidShadowingCheck;
 pub use syntax::SyntaxCheck;
 
-use crate::parse::CodeownersFile;
-use crate::validate::ValidationResult;
+use crate::matching::{PatternSyntax, QuestionMark};
+use crate::parse::{CodeownersFile, Span};
+use crate::validate::file_walker::{
+    CODEOWNERS_IGNORE_FILE, CodeownersIgnore, FileIndex, FileListing, FileWalkerConfig, ListError,
+    NonUtf8Policy, SymlinkPolicy, try_list_files,
+};
 use crate::validate::github_client::GithubClient;
+use crate::validate::idp::IdpDirectory;
+use crate::validate::lockfile::OwnersLock;
+use crate::validate::manifest::OwnershipManifest;
+use crate::validate::{Severity, ValidationError, ValidationResult};
+use crate::vcs::GitTreeSource;
 use async_trait::async_trait;
-use log::{debug, info};
-use std::collections::HashSet;
+use futures::FutureExt;
+use log::{debug, error, info};
+use std::any::Any;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::panic::{AssertUnwindSafe, catch_unwind};
 use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
 
 /// Configuration options for validation checks.
+///
+/// Options are added as checks grow, so the config is built with
+/// [`CheckConfig::new`] and its `with_*` methods rather than a struct
+/// literal.
 #[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct CheckConfig {
     /// Owners that should be skipped during validation.
     pub ignored_owners: HashSet<String>,
@@ -36,8 +138,103 @@ pub struct CheckConfig {
     pub allow_unowned_patterns: bool,
     /// Patterns to skip when checking for unowned files.
     pub skip_patterns: Vec<String>,
+    /// If true, checks that skip git-ignored files respect `.gitignore` even
+    /// when the repository has no `.git` directory.
+    pub force_respect_gitignore: bool,
+    /// How checks that list repository files handle file names that are
+    /// not valid UTF-8.
+    pub non_utf8_policy: NonUtf8Policy,
+    /// How checks that list repository files handle symbolic links.
+    pub symlink_policy: SymlinkPolicy,
+    /// If true, checks that list repository files list the files git
+    /// tracks instead of walking the file system, leaving out untracked
+    /// build artifacts.
+    pub tracked_files_only: bool,
+    /// A git revision (e.g. `origin/main`) whose tree checks that list
+    /// repository files list instead of the file system, for bare
+    /// repositories without a checkout.
+    pub git_rev: Option<String>,
+    /// How many directories deep checks that list repository files walk;
+    /// files directly in the repository root are at depth 1.
+    pub max_walk_depth: Option<usize>,
+    /// Directories, relative to the repository root, that checks listing
+    /// repository files leave out along with their contents.
+    pub excluded_dirs: Vec<String>,
+    /// How many files checks list at most when walking the repository.
+    /// Walks that reach it stop, and the files and notowned checks report
+    /// the incomplete listing.
+    pub max_walk_files: Option<usize>,
+    /// How long checks may spend walking the repository, e.g. one that
+    /// contains a mounted network share. Walks that run out of time are
+    /// abandoned instead of hanging, and the files and notowned checks
+    /// report the incomplete listing.
+    pub walk_timeout: Option<Duration>,
+    /// Paths changed by a pull request, relative to the repository root.
+    /// When set, the not-owned check only checks those that still exist
+    /// instead of listing the whole repository.
+    pub changed_files: Option<Vec<String>>,
     /// The repository in "owner/repo" format, used for owner validation.
     pub repository: Option<String>,
+    /// Maximum number of GitHub API requests the owners check may make.
+    /// Owners beyond the budget are reported as unchecked warnings.
+    pub max_api_requests: Option<usize>,
+    /// Email domains trusted for email owners. When non-empty, email owners
+    /// outside these domains produce warnings.
+    pub trusted_email_domains: HashSet<String>,
+    /// If true, all email owners are rejected.
+    pub reject_email_owners: bool,
+    /// Maps user owners (e.g. `@alice`) to the team that should replace them
+    /// when `owners_must_be_teams` is enabled, enabling an automatic fix.
+    pub team_mapping: HashMap<String, String>,
+    /// If true, users missing from `team_mapping` are mapped to the one team
+    /// listed in the file that they are a member of, looked up through
+    /// [`GithubClient::team_members`].
+    pub derive_team_mapping: bool,
+    /// How `?` in patterns is interpreted when matching files.
+    pub question_mark: QuestionMark,
+    /// Which pattern syntax the syntax check accepts.
+    pub pattern_syntax: PatternSyntax,
+    /// Identity provider groups that team owners are checked against.
+    pub idp_directory: Option<IdpDirectory>,
+    /// If true, the owners check looks up team members and reports teams
+    /// without any. Costs one extra GitHub API request per team.
+    pub check_empty_teams: bool,
+    /// Minimum number of members for team owners. Smaller teams produce
+    /// warnings. Costs one extra GitHub API request per team.
+    pub min_team_size: Option<usize>,
+    /// If true, the owners check looks up the parents of nested teams and
+    /// warns about rules naming a parent team along with one of its child
+    /// teams. Costs one extra GitHub API request per team and ancestor.
+    pub check_team_hierarchy: bool,
+    /// Days before a `review-by` date that rules start producing warnings.
+    /// Defaults to [`DEFAULT_REVIEW_WARNING_DAYS`].
+    pub review_warning_days: Option<u32>,
+    /// Number of rules sharing an owner list before the maintainability
+    /// check reports it. Defaults to [`DEFAULT_REPEATED_OWNERS_THRESHOLD`].
+    pub repeated_owners_threshold: Option<usize>,
+    /// Maximum number of rules before the complexity check warns.
+    /// Defaults to [`DEFAULT_MAX_RULES`].
+    pub max_rules: Option<usize>,
+    /// Maximum number of rules in a section before the complexity check
+    /// warns. Defaults to [`DEFAULT_MAX_RULES_PER_SECTION`].
+    pub max_rules_per_section: Option<usize>,
+    /// Maximum number of lines before the complexity check warns.
+    /// Defaults to [`DEFAULT_MAX_FILE_LINES`].
+    pub max_file_lines: Option<usize>,
+    /// Declared owners of paths that the ownership manifest check verifies.
+    pub ownership_manifest: Option<OwnershipManifest>,
+    /// Pinned owners of paths that the owners lockfile check verifies.
+    pub owners_lock: Option<OwnersLock>,
+    /// Teams, lowercased, of which the self-ownership check requires at
+    /// least one to own the CODEOWNERS file.
+    pub admin_teams: HashSet<String>,
+    /// Error codes (e.g. `CO004`) whose findings are dropped from results.
+    pub suppressed_codes: HashSet<String>,
+    /// Severities replacing the default severity of findings, by error code.
+    pub severity_overrides: HashMap<String, Severity>,
+    /// Settings of individual checks, by check name, for checks that have
+    /// settings of their own.
+    pub check_settings: HashMap<String, serde_json::Value>,
 }
 
 impl CheckConfig {
@@ -70,11 +267,288 @@ impl CheckConfig {
         self
     }
 
+    /// Sets whether `.gitignore` is respected without a `.git` directory.
+    pub fn with_force_respect_gitignore(mut self, value: bool) -> Self {
+        self.force_respect_gitignore = value;
+        self
+    }
+
+    /// Sets how file names that are not valid UTF-8 are handled.
+    pub fn with_non_utf8_policy(mut self, policy: NonUtf8Policy) -> Self {
+        self.non_utf8_policy = policy;
+        self
+    }
+
+    /// Sets how symbolic links are handled.
+    pub fn with_symlink_policy(mut self, policy: SymlinkPolicy) -> Self {
+        self.symlink_policy = policy;
+        self
+    }
+
+    /// Sets whether checks list only the files git tracks.
+    pub fn with_tracked_files_only(mut self, value: bool) -> Self {
+        self.tracked_files_only = value;
+        self
+    }
+
+    /// Sets the git revision whose tree checks list files from.
+    pub fn with_git_rev(mut self, rev: impl Into<String>) -> Self {
+        self.git_rev = Some(rev.into());
+        self
+    }
+
+    /// Sets how many directories deep checks walk the repository.
+    pub fn with_max_walk_depth(mut self, depth: usize) -> Self {
+        self.max_walk_depth = Some(depth);
+        self
+    }
+
+    /// Sets the directories that checks listing repository files leave out.
+    pub fn with_excluded_dirs(mut self, dirs: impl IntoIterator<Item = String>) -> Self {
+        self.excluded_dirs = dirs.into_iter().collect();
+        self
+    }
+
+    /// Sets how many files checks list at most when walking the repository.
+    pub fn with_max_walk_files(mut self, max: usize) -> Self {
+        self.max_walk_files = Some(max);
+        self
+    }
+
+    /// Sets how long checks may spend walking the repository.
+    pub fn with_walk_timeout(mut self, timeout: Duration) -> Self {
+        self.walk_timeout = Some(timeout);
+        self
+    }
+
+    /// Restricts the not-owned check to the given changed paths.
+    pub fn with_changed_files(mut self, paths: impl IntoIterator<Item = String>) -> Self {
+        self.changed_files = Some(paths.into_iter().collect());
+        self
+    }
+
     /// Sets the repository for owner validation.
     pub fn with_repository(mut self, repo: impl Into<String>) -> Self {
         self.repository = Some(repo.into());
         self
     }
+
+    /// Sets the maximum number of GitHub API requests for the owners check.
+    pub fn with_max_api_requests(mut self, max: usize) -> Self {
+        self.max_api_requests = Some(max);
+        self
+    }
+
+    /// Sets the trusted email domains.
+    pub fn with_trusted_email_domains(mut self, domains: HashSet<String>) -> Self {
+        self.trusted_email_domains = domains;
+        self
+    }
+
+    /// Sets whether email owners are rejected.
+    pub fn with_reject_email_owners(mut self, value: bool) -> Self {
+        self.reject_email_owners = value;
+        self
+    }
+
+    /// Sets the user-to-team mapping used to suggest team owners.
+    pub fn with_team_mapping(mut self, mapping: HashMap<String, String>) -> Self {
+        self.team_mapping = mapping;
+        self
+    }
+
+    /// Sets whether user-to-team replacements are derived from the
+    /// membership of the teams listed in the file.
+    pub fn with_derive_team_mapping(mut self, value: bool) -> Self {
+        self.derive_team_mapping = value;
+        self
+    }
+
+    /// Sets how `?` in patterns is interpreted.
+    pub fn with_question_mark(mut self, question_mark: QuestionMark) -> Self {
+        self.question_mark = question_mark;
+        self
+    }
+
+    /// Sets which pattern syntax the syntax check accepts.
+    pub fn with_pattern_syntax(mut self, pattern_syntax: PatternSyntax) -> Self {
+        self.pattern_syntax = pattern_syntax;
+        self
+    }
+
+    /// Sets the identity provider groups for the IdP group check.
+    pub fn with_idp_directory(mut self, directory: IdpDirectory) -> Self {
+        self.idp_directory = Some(directory);
+        self
+    }
+
+    /// Sets whether the owners check reports teams without members.
+    pub fn with_check_empty_teams(mut self, value: bool) -> Self {
+        self.check_empty_teams = value;
+        self
+    }
+
+    /// Sets the minimum number of members for team owners.
+    pub fn with_min_team_size(mut self, min: usize) -> Self {
+        self.min_team_size = Some(min);
+        self
+    }
+
+    /// Sets whether the owners check warns about redundant parent teams.
+    pub fn with_check_team_hierarchy(mut self, value: bool) -> Self {
+        self.check_team_hierarchy = value;
+        self
+    }
+
+    /// Sets how many days before a `review-by` date rules produce warnings.
+    pub fn with_review_warning_days(mut self, days: u32) -> Self {
+        self.review_warning_days = Some(days);
+        self
+    }
+
+    /// Sets how many rules may share an owner list before it is reported.
+    pub fn with_repeated_owners_threshold(mut self, threshold: usize) -> Self {
+        self.repeated_owners_threshold = Some(threshold);
+        self
+    }
+
+    /// Sets the maximum number of rules in the file.
+    pub fn with_max_rules(mut self, max: usize) -> Self {
+        self.max_rules = Some(max);
+        self
+    }
+
+    /// Sets the maximum number of rules in a section.
+    pub fn with_max_rules_per_section(mut self, max: usize) -> Self {
+        self.max_rules_per_section = Some(max);
+        self
+    }
+
+    /// Sets the maximum number of lines in the file.
+    pub fn with_max_file_lines(mut self, max: usize) -> Self {
+        self.max_file_lines = Some(max);
+        self
+    }
+
+    /// Sets the ownership manifest for the ownership manifest check.
+    pub fn with_ownership_manifest(mut self, manifest: OwnershipManifest) -> Self {
+        self.ownership_manifest = Some(manifest);
+        self
+    }
+
+    /// Sets the owners lockfile for the owners lockfile check.
+    pub fn with_owners_lock(mut self, lock: OwnersLock) -> Self {
+        self.owners_lock = Some(lock);
+        self
+    }
+
+    /// Sets the teams of which at least one must own the CODEOWNERS file.
+    /// Teams are case-insensitive.
+    pub fn with_admin_teams(mut self, teams: HashSet<String>) -> Self {
+        self.admin_teams = teams.iter().map(|team| team.to_lowercase()).collect();
+        self
+    }
+
+    /// Sets the error codes whose findings are dropped, e.g. `CO004`.
+    /// Codes are case-insensitive.
+    pub fn with_suppressed_codes(mut self, codes: HashSet<String>) -> Self {
+        self.suppressed_codes = codes.iter().map(|code| code.to_uppercase()).collect();
+        self
+    }
+
+    /// Sets the severities that replace the default severity of findings,
+    /// by error code. Codes are case-insensitive.
+    pub fn with_severity_overrides(mut self, overrides: HashMap<String, Severity>) -> Self {
+        self.severity_overrides = overrides
+            .into_iter()
+            .map(|(code, severity)| (code.to_uppercase(), severity))
+            .collect();
+        self
+    }
+
+    /// Sets the settings of the check named `check`, replacing any set
+    /// before.
+    pub fn with_check_settings(
+        mut self,
+        check: impl Into<String>,
+        settings: serde_json::Value,
+    ) -> Self {
+        self.check_settings.insert(check.into(), settings);
+        self
+    }
+
+    /// Returns the settings of the check named `check`, if any were set.
+    pub fn settings_for(&self, check: &str) -> Option<&serde_json::Value> {
+        self.check_settings.get(check)
+    }
+
+    /// Applies the traversal settings of this configuration to a check's
+    /// file walker configuration, e.g. [`FileWalkerConfig::for_files_check`].
+    pub fn walker_config(&self, walker: FileWalkerConfig) -> FileWalkerConfig {
+        walker
+            .with_non_utf8(self.non_utf8_policy)
+            .with_symlinks(self.symlink_policy)
+            .with_tracked_only(self.tracked_files_only)
+            .with_git_rev(self.git_rev.clone())
+            .with_max_depth(self.max_walk_depth)
+            .with_excluded_dirs(self.excluded_dirs.iter().map(String::as_str))
+            .with_max_files(self.max_walk_files)
+            .with_timeout(self.walk_timeout)
+    }
+}
+
+/// Describes a check to the tools that run it.
+///
+/// Built-in checks use the default descriptor; checks written outside this
+/// crate override [`Check::descriptor`] to choose the severity of their
+/// findings and to document their settings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CheckDescriptor {
+    /// The name of the check, as returned by [`Check::name`].
+    pub name: Cow<'static, str>,
+    /// The severity of the check's findings, unless overridden.
+    pub default_severity: Severity,
+    /// A JSON Schema of the check's settings (see
+    /// [`CheckConfig::settings_for`]), if it has any.
+    pub config_schema: Option<serde_json::Value>,
+}
+
+impl CheckDescriptor {
+    /// Creates a descriptor for a check whose findings are errors and that
+    /// has no settings.
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            default_severity: Severity::Error,
+            config_schema: None,
+        }
+    }
+
+    /// Sets the severity of the check's findings.
+    pub fn with_default_severity(mut self, severity: Severity) -> Self {
+        self.default_severity = severity;
+        self
+    }
+
+    /// Sets the JSON Schema of the check's settings.
+    pub fn with_config_schema(mut self, schema: serde_json::Value) -> Self {
+        self.config_schema = Some(schema);
+        self
+    }
+
+    /// Creates a finding of this check, with its default severity.
+    ///
+    /// Findings share the `CO032` code; severity overrides for that code
+    /// apply to every custom check.
+    pub fn finding(&self, message: impl Into<String>, span: Span) -> ValidationError {
+        ValidationError::custom_check_finding(
+            self.name.as_ref(),
+            message,
+            self.default_severity,
+            span,
+        )
+    }
 }
 
 /// Context provided to synchronous checks.
@@ -86,6 +560,8 @@ pub struct CheckContext<'a> {
     pub repo_path: &'a Path,
     /// Configuration options.
     pub config: &'a CheckConfig,
+    /// Shared cache of repository listings, if the caller keeps one.
+    pub file_index: Option<&'a FileIndex>,
 }
 
 impl<'a> CheckContext<'a> {
@@ -95,6 +571,44 @@ impl<'a> CheckContext<'a> {
             file,
             repo_path,
             config,
+            file_index: None,
+        }
+    }
+
+    /// Sets the file index used to list repository files.
+    pub fn with_file_index(mut self, index: &'a FileIndex) -> Self {
+        self.file_index = Some(index);
+        self
+    }
+
+    /// Lists repository files, using the file index when one is set.
+    ///
+    /// Checks record a failed listing as their
+    /// [internal error](ValidationResult::internal_error), and checks that
+    /// need every file report an incomplete one.
+    pub fn list_files(&self, config: &FileWalkerConfig) -> Result<Arc<FileListing>, ListError> {
+        match self.file_index {
+            Some(index) => index.list_files(self.repo_path, config),
+            None => try_list_files(self.repo_path, config).map(Arc::new),
+        }
+    }
+
+    /// Loads the repository's `.codeownersignore` file, from the tree of
+    /// [`CheckConfig::git_rev`] when one is set, or returns `None` if it has
+    /// none.
+    pub fn codeowners_ignore(&self) -> Option<CodeownersIgnore> {
+        let Some(rev) = &self.config.git_rev else {
+            return CodeownersIgnore::load(self.repo_path);
+        };
+        match GitTreeSource::new(self.repo_path, rev).read_file(CODEOWNERS_IGNORE_FILE) {
+            Ok(content) => content.as_deref().map(CodeownersIgnore::parse),
+            Err(e) => {
+                debug!(
+                    "Failed to read {} at '{}': {}",
+                    CODEOWNERS_IGNORE_FILE, rev, e
+                );
+                None
+            }
         }
     }
 }
@@ -142,7 +656,12 @@ impl std::fmt::Debug for AsyncCheckContext<'_> {
 /// A synchronous validation check.
 pub trait Check: Send + Sync {
     /// Returns the name of this check.
-    fn name(&self) -> &'static str;
+    fn name(&self) -> &str;
+
+    /// Returns the descriptor of this check.
+    fn descriptor(&self) -> CheckDescriptor {
+        CheckDescriptor::new(self.name().to_string())
+    }
 
     /// Runs the check and returns validation results.
     fn run(&self, ctx: &CheckContext) -> ValidationResult;
@@ -152,17 +671,66 @@ pub trait Check: Send + Sync {
 #[async_trait]
 pub trait AsyncCheck: Send + Sync {
     /// Returns the name of this check.
-    fn name(&self) -> &'static str;
+    fn name(&self) -> &str;
+
+    /// Returns the descriptor of this check.
+    fn descriptor(&self) -> CheckDescriptor {
+        CheckDescriptor::new(self.name().to_string())
+    }
 
     /// Runs the check asynchronously and returns validation results.
     async fn run(&self, ctx: &AsyncCheckContext<'_>) -> ValidationResult;
 }
 
+/// Returns the message of a panic payload.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic")
+}
+
+/// Converts a panic in a check into a result with an internal error.
+fn panicked(name: &str, payload: &(dyn Any + Send)) -> ValidationResult {
+    let message = panic_message(payload);
+    error!("Check '{}' panicked: {}", name, message);
+    let mut result = ValidationResult::new();
+    result.set_internal_error(format!("check '{}' panicked: {}", name, message));
+    result
+}
+
+/// Runs a check, isolating panics.
+///
+/// A bug that makes the check panic is reported as an internal error of
+/// the check (see [`ValidationResult::internal_error`]) instead of aborting
+/// the rest of the run.
+pub fn run_isolated(check: &dyn Check, ctx: &CheckContext) -> ValidationResult {
+    catch_unwind(AssertUnwindSafe(|| check.run(ctx)))
+        .unwrap_or_else(|payload| panicked(check.name(), payload.as_ref()))
+}
+
+/// Runs an asynchronous check, isolating panics like [`run_isolated`].
+pub async fn run_isolated_async(
+    check: &dyn AsyncCheck,
+    ctx: &AsyncCheckContext<'_>,
+) -> ValidationResult {
+    AssertUnwindSafe(check.run(ctx))
+        .catch_unwind()
+        .await
+        .unwrap_or_else(|payload| panicked(check.name(), payload.as_ref()))
+}
+
 /// Runs multiple validation checks and collects results.
+///
+/// Each check runs isolated (see [`run_isolated`]), so a check that panics
+/// leaves an internal error on the combined result and the others still run.
+/// The `*_by_check` methods keep each check's results apart instead.
 #[derive(Default)]
 pub struct CheckRunner {
     checks: Vec<Box<dyn Check>>,
     async_checks: Vec<Box<dyn AsyncCheck>>,
+    file_index: Option<Arc<FileIndex>>,
 }
 
 impl CheckRunner {
@@ -179,10 +747,44 @@ impl CheckRunner {
         runner.add_check(FilesCheck::new());
         runner.add_check(NotOwnedCheck::new());
         runner.add_check(AvoidShadowingCheck::new());
+        runner.add_check(CaseCollisionCheck::new());
+        runner.add_check(GeneratedRegionsCheck::new());
+        runner.add_check(IdpGroupsCheck::new());
+        runner.add_check(ReviewByCheck::new());
+        runner.add_check(ExtensionOverrideCheck::new());
+        runner.add_check(AnchoringCheck::new());
+        runner.add_check(GitLabSectionsCheck::new());
+        runner.add_check(MaintainabilityCheck::new());
+        runner.add_check(ComplexityCheck::new());
+        runner.add_check(OwnershipManifestCheck::new());
+        runner.add_check(SelfOwnershipCheck::new());
+        runner.add_check(OwnersLockCheck::new());
+        runner.add_check(EscalationCheck::new());
+        runner.add_check(AnnotationsCheck::new());
         runner.add_async_check(OwnersCheck::new());
         runner
     }
 
+    /// Shares a file index across runs so repository listings are reused.
+    pub fn with_file_index(mut self, index: Arc<FileIndex>) -> Self {
+        self.file_index = Some(index);
+        self
+    }
+
+    /// Creates the context passed to synchronous checks.
+    fn context<'a>(
+        &'a self,
+        file: &'a CodeownersFile,
+        repo_path: &'a Path,
+        config: &'a CheckConfig,
+    ) -> CheckContext<'a> {
+        let ctx = CheckContext::new(file, repo_path, config);
+        match &self.file_index {
+            Some(index) => ctx.with_file_index(index),
+            None => ctx,
+        }
+    }
+
     /// Adds a synchronous check.
     pub fn add_check<C: Check + 'static>(&mut self, check: C) {
         self.checks.push(Box::new(check));
@@ -193,6 +795,15 @@ impl CheckRunner {
         self.async_checks.push(Box::new(check));
     }
 
+    /// Returns the descriptors of all checks, synchronous ones first.
+    pub fn descriptors(&self) -> Vec<CheckDescriptor> {
+        self.checks
+            .iter()
+            .map(|check| check.descriptor())
+            .chain(self.async_checks.iter().map(|check| check.descriptor()))
+            .collect()
+    }
+
     /// Runs all synchronous checks and returns combined results.
     pub fn run_sync(
         &self,
@@ -201,19 +812,8 @@ impl CheckRunner {
         config: &CheckConfig,
     ) -> ValidationResult {
         info!("Running {} synchronous checks", self.checks.len());
-        let ctx = CheckContext::new(file, repo_path, config);
-        let mut result = ValidationResult::new();
-
-        for check in &self.checks {
-            debug!("Running check: {}", check.name());
-            let check_result = check.run(&ctx);
-            debug!(
-                "Check '{}' found {} issues",
-                check.name(),
-                check_result.errors.len()
-            );
-            result.merge(check_result);
-        }
+        let ctx = self.context(file, repo_path, config);
+        let result = combine(self.sync_results(&ctx), config);
 
         info!(
             "Synchronous checks complete: {} total issues",
@@ -222,6 +822,22 @@ impl CheckRunner {
         result
     }
 
+    /// Runs all synchronous checks and returns the results of each check,
+    /// keyed by check name.
+    ///
+    /// Suppressed codes and severity overrides apply to every result, as
+    /// with [`run_sync`](Self::run_sync).
+    pub fn run_sync_by_check(
+        &self,
+        file: &CodeownersFile,
+        repo_path: &Path,
+        config: &CheckConfig,
+    ) -> CheckResults {
+        info!("Running {} synchronous checks", self.checks.len());
+        let ctx = self.context(file, repo_path, config);
+        group(self.sync_results(&ctx), config)
+    }
+
     /// Runs all checks (both sync and async) and returns combined results.
     pub async fn run_all(
         &self,
@@ -230,38 +846,79 @@ impl CheckRunner {
         config: &CheckConfig,
         github_client: Option<&dyn GithubClient>,
     ) -> ValidationResult {
+        let results = self
+            .all_results(file, repo_path, config, github_client)
+            .await;
+        let result = combine(results, config);
+
+        info!("All checks complete: {} total issues", result.errors.len());
+        result
+    }
+
+    /// Runs all checks (both sync and async) and returns the results of
+    /// each check, keyed by check name.
+    ///
+    /// Asynchronous checks are missing from the map when no GitHub client
+    /// is provided, since they do not run.
+    pub async fn run_all_by_check(
+        &self,
+        file: &CodeownersFile,
+        repo_path: &Path,
+        config: &CheckConfig,
+        github_client: Option<&dyn GithubClient>,
+    ) -> CheckResults {
+        let results = self
+            .all_results(file, repo_path, config, github_client)
+            .await;
+        group(results, config)
+    }
+
+    /// Runs the synchronous checks, in order.
+    fn sync_results(&self, ctx: &CheckContext) -> Vec<(String, ValidationResult)> {
+        self.checks
+            .iter()
+            .map(|check| {
+                debug!("Running sync check: {}", check.name());
+                let check_result = run_isolated(check.as_ref(), ctx);
+                debug!(
+                    "Check '{}' found {} issues",
+                    check.name(),
+                    check_result.errors.len()
+                );
+                (check.name().to_string(), check_result)
+            })
+            .collect()
+    }
+
+    /// Runs the synchronous checks, then the asynchronous ones if a GitHub
+    /// client is provided, in order.
+    async fn all_results(
+        &self,
+        file: &CodeownersFile,
+        repo_path: &Path,
+        config: &CheckConfig,
+        github_client: Option<&dyn GithubClient>,
+    ) -> Vec<(String, ValidationResult)> {
         info!(
             "Running all checks ({} sync, {} async)",
             self.checks.len(),
             self.async_checks.len()
         );
-        let ctx = CheckContext::new(file, repo_path, config);
-        let mut result = ValidationResult::new();
-
-        // Run synchronous checks
-        for check in &self.checks {
-            debug!("Running sync check: {}", check.name());
-            let check_result = check.run(&ctx);
-            debug!(
-                "Check '{}' found {} issues",
-                check.name(),
-                check_result.errors.len()
-            );
-            result.merge(check_result);
-        }
+        let ctx = self.context(file, repo_path, config);
+        let mut results = self.sync_results(&ctx);
 
         // Run asynchronous checks if github_client is provided
         if let Some(client) = github_client {
             let async_ctx = AsyncCheckContext::new(file, repo_path, config, client);
             for check in &self.async_checks {
                 debug!("Running async check: {}", check.name());
-                let check_result = check.run(&async_ctx).await;
+                let check_result = run_isolated_async(check.as_ref(), &async_ctx).await;
                 debug!(
                     "Check '{}' found {} issues",
                     check.name(),
                     check_result.errors.len()
                 );
-                result.merge(check_result);
+                results.push((check.name().to_string(), check_result));
             }
         } else {
             debug!(
@@ -270,11 +927,42 @@ impl CheckRunner {
             );
         }
 
-        info!("All checks complete: {} total issues", result.errors.len());
-        result
+        results
     }
 }
 
+/// The results of a run, keyed by the name of the check that produced them.
+pub type CheckResults = BTreeMap<String, ValidationResult>;
+
+/// Applies suppressed codes and severity overrides to a result.
+fn finish(result: &mut ValidationResult, config: &CheckConfig) {
+    result.suppress(&config.suppressed_codes);
+    result.override_severities(&config.severity_overrides);
+}
+
+/// Merges check results, in run order.
+fn combine(results: Vec<(String, ValidationResult)>, config: &CheckConfig) -> ValidationResult {
+    let mut result = ValidationResult::new();
+    for (_, check_result) in results {
+        result.merge(check_result);
+    }
+    finish(&mut result, config);
+    result
+}
+
+/// Groups check results by check name; checks sharing a name share an
+/// entry.
+fn group(results: Vec<(String, ValidationResult)>, config: &CheckConfig) -> CheckResults {
+    let mut grouped = CheckResults::new();
+    for (name, check_result) in results {
+        grouped.entry(name).or_default().merge(check_result);
+    }
+    for result in grouped.values_mut() {
+        finish(result, config);
+    }
+    grouped
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -285,10 +973,30 @@ mod tests {
     fn check_config_builder() {
         let config = CheckConfig::new()
             .with_owners_must_be_teams(true)
-            .with_repository("owner/repo");
+            .with_repository("owner/repo")
+            .with_max_api_requests(25)
+            .with_min_team_size(2);
 
         assert!(config.owners_must_be_teams);
+        assert_eq!(config.min_team_size, Some(2));
         assert_eq!(config.repository, Some("owner/repo".to_string()));
+        assert_eq!(config.max_api_requests, Some(25));
+    }
+
+    #[test]
+    fn walker_config_applies_walk_settings() {
+        let config = CheckConfig::new()
+            .with_max_walk_depth(3)
+            .with_excluded_dirs(["vendor/".to_string()])
+            .with_max_walk_files(1000)
+            .with_walk_timeout(Duration::from_secs(30));
+        let walker = config.walker_config(FileWalkerConfig::for_files_check());
+
+        assert!(walker.include_directories);
+        assert_eq!(walker.max_depth, Some(3));
+        assert_eq!(walker.excluded_dirs, ["vendor"]);
+        assert_eq!(walker.max_files, Some(1000));
+        assert_eq!(walker.timeout, Some(Duration::from_secs(30)));
     }
 
     #[test]
@@ -311,7 +1019,146 @@ mod tests {
     #[test]
     fn check_runner_with_all_checks() {
         let runner = CheckRunner::with_all_checks();
-        assert_eq!(runner.checks.len(), 5); // syntax, dup, files, notowned, shadowing
+        assert_eq!(runner.checks.len(), 19); // syntax, dup, files, notowned, shadowing, case-collision, generated-regions, idp-groups, review-by, extension-override, anchoring, gitlab-sections, maintainability, complexity, ownership-manifest, self-ownership, owners-lock, escalation, annotations
         assert_eq!(runner.async_checks.len(), 1); // owners
     }
+
+    #[test]
+    fn check_runner_suppresses_codes() {
+        let file = parse_codeowners("*.rs @a\n*.rs @b\n").ast;
+        let path = PathBuf::from("/repo");
+        let mut runner = CheckRunner::new();
+        runner.add_check(DupPatternsCheck::new());
+
+        let result = runner.run_sync(&file, &path, &CheckConfig::new());
+        assert_eq!(result.errors[0].code(), "CO004");
+
+        let config = CheckConfig::new().with_suppressed_codes(HashSet::from(["co004".to_string()]));
+        assert!(runner.run_sync(&file, &path, &config).is_ok());
+    }
+
+    #[test]
+    fn check_runner_groups_results_by_check() {
+        let file = parse_codeowners(
+            "*.rs @a
+*.rs @b
+[unclosed\n",
+        )
+        .ast;
+        let path = PathBuf::from("/repo");
+        let mut runner = CheckRunner::new();
+        runner.add_check(SyntaxCheck::new());
+        runner.add_check(DupPatternsCheck::new());
+        runner.add_check(PanickingCheck);
+
+        let config = CheckConfig::new()
+            .with_severity_overrides(HashMap::from([("CO004".to_string(), Severity::Warning)]));
+        let results = runner.run_sync_by_check(&file, &path, &config);
+        assert_eq!(
+            results.keys().collect::<Vec<_>>(),
+            ["duppatterns", "panicking", "syntax"]
+        );
+        assert_eq!(results["duppatterns"].errors[0].code(), "CO004");
+        assert_eq!(
+            results["duppatterns"].errors[0].severity(),
+            Severity::Warning
+        );
+        assert!(results["panicking"].internal_error.is_some());
+        assert!(results["syntax"].internal_error.is_none());
+
+        let combined = runner.run_sync(&file, &path, &config);
+        let total: usize = results.values().map(|r| r.errors.len()).sum();
+        assert_eq!(combined.errors.len(), total);
+    }
+
+    struct PanickingCheck;
+
+    impl Check for PanickingCheck {
+        fn name(&self) -> &str {
+            "panicking"
+        }
+
+        fn run(&self, _ctx: &CheckContext) -> ValidationResult {
+            panic!("index out of bounds");
+        }
+    }
+
+    struct NoBotsCheck;
+
+    impl Check for NoBotsCheck {
+        fn name(&self) -> &str {
+            "no-bots"
+        }
+
+        fn descriptor(&self) -> CheckDescriptor {
+            CheckDescriptor::new("no-bots")
+                .with_default_severity(Severity::Warning)
+                .with_config_schema(serde_json::json!({
+                    "type": "object",
+                    "properties": { "suffix": { "type": "string" } }
+                }))
+        }
+
+        fn run(&self, ctx: &CheckContext) -> ValidationResult {
+            let suffix = ctx
+                .config
+                .settings_for(self.name())
+                .and_then(|settings| settings["suffix"].as_str())
+                .unwrap_or("[bot]");
+            let mut result = ValidationResult::new();
+            for (_, owners) in ctx.file.extract_rules() {
+                for owner in owners.iter().filter(|o| o.as_str().ends_with(suffix)) {
+                    let message = format!("bot owner {}", owner.as_str());
+                    result.add_error(self.descriptor().finding(message, *owner.span()));
+                }
+            }
+            result
+        }
+    }
+
+    #[test]
+    fn check_runner_runs_custom_checks() {
+        let file = parse_codeowners("*.rs @deploy[bot]\n/docs/ @docs-robot\n").ast;
+        let path = PathBuf::from("/repo");
+        let mut runner = CheckRunner::new();
+        runner.add_check(NoBotsCheck);
+        runner.add_check(DupPatternsCheck::new());
+
+        let descriptors = runner.descriptors();
+        assert_eq!(descriptors[0].name, "no-bots");
+        assert!(descriptors[0].config_schema.is_some());
+        assert_eq!(descriptors[1], CheckDescriptor::new("duppatterns"));
+
+        let result = runner.run_sync(&file, &path, &CheckConfig::new());
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].severity(), Severity::Warning);
+        assert!(matches!(
+            &result.errors[0],
+            ValidationError::CustomCheckFinding { check, .. } if check == "no-bots"
+        ));
+
+        let config = CheckConfig::new()
+            .with_check_settings("no-bots", serde_json::json!({ "suffix": "-robot" }));
+        let result = runner.run_sync(&file, &path, &config);
+        assert_eq!(
+            result.errors[0].to_string(),
+            "line 2: bot owner @docs-robot"
+        );
+    }
+
+    #[test]
+    fn check_runner_isolates_panics() {
+        let file = parse_codeowners("*.rs @a\n*.rs @b\n").ast;
+        let path = PathBuf::from("/repo");
+        let mut runner = CheckRunner::new();
+        runner.add_check(PanickingCheck);
+        runner.add_check(DupPatternsCheck::new());
+
+        let result = runner.run_sync(&file, &path, &CheckConfig::new());
+        assert_eq!(
+            result.internal_error.as_deref(),
+            Some("check 'panicking' panicked: index out of bounds")
+        );
+        assert_eq!(result.errors.len(), 1);
+    }
 }
@@ -4,15 +4,36 @@
 //! by any CODEOWNERS rule.
 
 use super::{Check, CheckContext};
-use crate::matching::Pattern;
-use crate::parse::{CodeownersFile, LineKind, Span};
-use crate::validate::file_walker::{FileWalkerConfig, list_files};
+use crate::matching::{Pattern, PatternSet, RuleSet};
+use crate::parse::{CodeownersFile, Span};
+use crate::validate::file_walker::{FileListing, FileWalkerConfig, NonUtf8Policy, SymlinkPolicy};
 use crate::validate::{ValidationError, ValidationResult};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::sync::Arc;
 
 /// A check that identifies files without CODEOWNERS coverage.
 ///
 /// This experimental check helps ensure that all files in the repository
 /// have designated owners, which is important for code review workflows.
+///
+/// The repository is walked on several threads, and with the `parallel`
+/// feature files are matched on rayon's thread pool too. Either way, files
+/// are reported in sorted order.
+///
+/// Files whose names are not valid UTF-8 are reported with a dedicated
+/// warning instead, unless [`CheckConfig::non_utf8_policy`](super::CheckConfig::non_utf8_policy)
+/// says otherwise. Likewise, with [`SymlinkPolicy::Report`] symbolic links
+/// are reported with a dedicated warning.
+///
+/// Files the repository's `.codeownersignore` file ignores (see
+/// [`CodeownersIgnore`](crate::validate::file_walker::CodeownersIgnore)) are
+/// skipped like
+/// [`CheckConfig::skip_patterns`](super::CheckConfig::skip_patterns).
+///
+/// With [`CheckConfig::changed_files`](super::CheckConfig::changed_files),
+/// only the changed paths are checked and the repository is not walked,
+/// which keeps pull request validation fast in large repositories.
 #[derive(Debug, Clone, Default)]
 pub struct NotOwnedCheck;
 
@@ -22,20 +43,10 @@ impl NotOwnedCheck {
         Self
     }
 
-    /// Checks if a file is covered by any of the patterns.
-    fn is_file_covered(file: &str, patterns: &[Pattern]) -> bool {
-        patterns.iter().any(|pattern| pattern.matches(file))
-    }
-
-    /// Checks if a file matches any skip pattern.
-    fn should_skip_file(file: &str, skip_patterns: &[Pattern]) -> bool {
-        skip_patterns.iter().any(|pattern| pattern.matches(file))
-    }
-
     /// Computes a zero-length span at the end of the CODEOWNERS file.
     ///
     /// This is used to indicate where a missing rule should be added.
-    fn eof_span(file: &CodeownersFile) -> Span {
+    pub(super) fn eof_span(file: &CodeownersFile) -> Span {
         if let Some(last_line) = file.lines.last() {
             // Create a point span at the end of the last line
             let last_span = &last_line.span;
@@ -49,53 +60,123 @@ impl NotOwnedCheck {
             Span::default()
         }
     }
+
+    /// Returns the changed paths to check: normalized, sorted, and without
+    /// deleted files. Paths are not looked up on disk when files are read
+    /// from a git revision.
+    fn changed_files(ctx: &CheckContext, paths: &[String]) -> Vec<String> {
+        let mut files: Vec<String> = paths
+            .iter()
+            .map(|path| path.trim_start_matches("./").trim_start_matches('/'))
+            .filter(|path| !path.is_empty())
+            .filter(|path| ctx.config.git_rev.is_some() || ctx.repo_path.join(path).is_file())
+            .map(str::to_string)
+            .collect();
+        files.sort_unstable();
+        files.dedup();
+        files
+    }
 }
 
 impl Check for NotOwnedCheck {
-    fn name(&self) -> &'static str {
+    fn name(&self) -> &str {
         "notowned"
     }
 
     fn run(&self, ctx: &CheckContext) -> ValidationResult {
         let mut result = ValidationResult::new();
 
-        // Compile all patterns from CODEOWNERS
-        let mut patterns: Vec<Pattern> = Vec::new();
-        for line in &ctx.file.lines {
-            if let LineKind::Rule { pattern, .. } = &line.kind
-                && let Some(compiled) = Pattern::new(&pattern.text)
-            {
-                patterns.push(compiled);
-            }
-        }
+        // Compile all rules into one automaton, so each file is matched
+        // against every pattern in a single pass
+        let rules = RuleSet::with_syntax(
+            ctx.file,
+            ctx.config.question_mark,
+            ctx.config.pattern_syntax,
+        );
 
-        // Compile skip patterns from config
-        let skip_patterns: Vec<Pattern> = ctx
+        // Compile skip patterns from config, leaving out invalid ones
+        let skip_patterns: Vec<&str> = ctx
             .config
             .skip_patterns
             .iter()
-            .filter_map(|p| Pattern::new(p))
+            .map(String::as_str)
+            .filter(|p| Pattern::with_question_mark(p, ctx.config.question_mark).is_some())
             .collect();
-
-        // List all files (includes hidden, respects gitignore)
-        let files = list_files(ctx.repo_path, &FileWalkerConfig::for_not_owned_check());
+        let skip_patterns =
+            PatternSet::with_question_mark(&skip_patterns, ctx.config.question_mark)
+                .filter(|set| !set.is_empty());
+        let ignore = ctx.codeowners_ignore();
+
+        // List all files (includes hidden, respects gitignore), or just the
+        // changed ones
+        let listing = match &ctx.config.changed_files {
+            Some(paths) => Ok(Arc::new(FileListing::from(Self::changed_files(ctx, paths)))),
+            None => ctx.list_files(
+                &ctx.config.walker_config(
+                    FileWalkerConfig::for_not_owned_check()
+                        .with_force_respect_gitignore(ctx.config.force_respect_gitignore),
+                ),
+            ),
+        };
+        let files = match listing {
+            Ok(files) => files,
+            Err(e) => {
+                let mut result = ValidationResult::new();
+                result.set_internal_error(e.to_string());
+                return result;
+            }
+        };
 
         // Compute EOF span once for all file-not-owned errors
         let eof_span = Self::eof_span(ctx.file);
 
         // Check each file
-        for file in files {
+        let check_file = |file: &String| {
             // Skip files matching skip patterns
-            if Self::should_skip_file(&file, &skip_patterns) {
-                continue;
+            if skip_patterns.as_ref().is_some_and(|set| set.is_match(file))
+                || ignore
+                    .as_ref()
+                    .is_some_and(|ignore| ignore.is_ignored(file, false))
+            {
+                return None;
             }
 
-            // Check if file is covered
-            if !Self::is_file_covered(&file, &patterns) {
-                result.add_error(ValidationError::file_not_owned(&file, eof_span));
+            // Names that are not valid UTF-8 were listed lossily, so matching
+            // them could report the wrong ownership
+            if ctx.config.non_utf8_policy == NonUtf8Policy::Warn
+                && file.contains(char::REPLACEMENT_CHARACTER)
+            {
+                return Some(ValidationError::non_utf8_path(file, eof_span));
             }
-        }
 
+            // Links are listed without being followed, so where their content
+            // lives and who owns it is unknown
+            if ctx.config.symlink_policy == SymlinkPolicy::Report
+                && ctx
+                    .repo_path
+                    .join(file)
+                    .symlink_metadata()
+                    .is_ok_and(|metadata| metadata.file_type().is_symlink())
+            {
+                return Some(ValidationError::symlink_path(file, eof_span));
+            }
+
+            // Check if file is covered
+            (!rules.is_match(file)).then(|| ValidationError::file_not_owned(file, eof_span))
+        };
+
+        // Errors are collected in listing order, with or without threads
+        #[cfg(feature = "parallel")]
+        let errors: Vec<_> = files.par_iter().filter_map(check_file).collect();
+        #[cfg(not(feature = "parallel"))]
+        let errors: Vec<_> = files.iter().filter_map(check_file).collect();
+
+        for error in errors {
+            result.add_error(error);
+        }
+        if let Some(reason) = &files.incomplete {
+            result.add_error(ValidationError::incomplete_listing(reason, eof_span));
+        }
         result
     }
 }
@@ -143,6 +224,130 @@ mod tests {
         NotOwnedCheck::new().run(&ctx)
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8_file_names() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let dir = setup_test_dir();
+        File::create(
+            dir.path()
+                .join("docs")
+                .join(OsStr::from_bytes(b"caf\xe9.md")),
+        )
+        .unwrap();
+        let input = "/src/ @owner\n*.toml @owner\n/tests/ @owner\n/docs/README.md @owner\n";
+
+        let result = run_check(input, dir.path());
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].code(), "CO029");
+        assert_eq!(result.errors[0].subject(), "docs/caf\u{FFFD}.md");
+
+        let config = CheckConfig::new().with_non_utf8_policy(NonUtf8Policy::Lossy);
+        let result = run_check_with_config(input, dir.path(), config);
+        assert_eq!(result.errors[0].code(), "CO013");
+
+        let config = CheckConfig::new().with_non_utf8_policy(NonUtf8Policy::Skip);
+        assert!(run_check_with_config(input, dir.path(), config).is_ok());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlinks_follow_the_policy() {
+        let dir = setup_test_dir();
+        std::os::unix::fs::symlink(dir.path().join("docs"), dir.path().join("manual")).unwrap();
+        let input = "/src/ @owner\n*.toml @owner\n/tests/ @owner\n/docs/ @owner\n";
+
+        assert!(run_check(input, dir.path()).is_ok());
+
+        let config = CheckConfig::new().with_symlink_policy(SymlinkPolicy::Follow);
+        let result = run_check_with_config(input, dir.path(), config);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].code(), "CO013");
+        assert_eq!(result.errors[0].subject(), "manual/README.md");
+
+        let config = CheckConfig::new().with_symlink_policy(SymlinkPolicy::Report);
+        let result = run_check_with_config(input, dir.path(), config);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].code(), "CO037");
+        assert_eq!(result.errors[0].subject(), "manual");
+    }
+
+    #[test]
+    fn walk_follows_depth_and_excluded_dirs() {
+        let dir = setup_test_dir();
+        let input = "*.toml @owner\n";
+
+        let config = CheckConfig::new()
+            .with_max_walk_depth(1)
+            .with_excluded_dirs(["src".to_string(), "docs".to_string()]);
+        assert!(run_check_with_config(input, dir.path(), config).is_ok());
+
+        let config = CheckConfig::new().with_excluded_dirs(["src".to_string()]);
+        let result = run_check_with_config(input, dir.path(), config);
+        let subjects: Vec<_> = result.errors.iter().map(|e| e.subject()).collect();
+        assert_eq!(subjects, ["docs/README.md", "tests/test.rs"]);
+    }
+
+    #[test]
+    fn failed_tracked_listing_is_an_internal_error() {
+        let dir = setup_test_dir();
+        fs::write(dir.path().join(".git"), "gitdir: missing\n").unwrap();
+
+        let config = CheckConfig::new().with_tracked_files_only(true);
+        let result = run_check_with_config("/src/ @owner\n", dir.path(), config);
+        assert!(result.errors.is_empty());
+        assert!(
+            result
+                .internal_error
+                .unwrap()
+                .starts_with("failed to list the files git tracks")
+        );
+    }
+
+    #[test]
+    fn incomplete_listing_is_reported() {
+        let dir = setup_test_dir();
+        let config = CheckConfig::new().with_max_walk_files(2);
+        let result = run_check_with_config("", dir.path(), config);
+
+        let incomplete: Vec<_> = result
+            .errors
+            .iter()
+            .filter(|e| matches!(e, ValidationError::IncompleteListing { .. }))
+            .collect();
+        assert_eq!(incomplete.len(), 1);
+        assert_eq!(incomplete[0].subject(), "reached the limit of 2 entries");
+        assert_eq!(result.errors.len(), 3);
+    }
+
+    #[test]
+    fn skips_files_in_codeownersignore() {
+        let dir = setup_test_dir();
+        fs::write(
+            dir.path().join(".codeownersignore"),
+            "# Not reviewed\n/docs/\n*.toml\n",
+        )
+        .unwrap();
+        let result = run_check("/src/ @owner\n/.codeownersignore @owner\n", dir.path());
+
+        let subjects: Vec<_> = result.errors.iter().map(|e| e.subject()).collect();
+        assert_eq!(subjects, ["tests/test.rs"]);
+    }
+
+    #[test]
+    fn checks_only_changed_files() {
+        let dir = setup_test_dir();
+        let changed = ["./src/main.rs", "Cargo.toml", "deleted.rs", "Cargo.toml"];
+        let config = CheckConfig::new().with_changed_files(changed.map(String::from));
+        let result = run_check_with_config("/src/ @owner\n", dir.path(), config);
+
+        // docs/ and tests/ are unowned too, but did not change
+        let subjects: Vec<_> = result.errors.iter().map(|e| e.subject()).collect();
+        assert_eq!(subjects, ["Cargo.toml"]);
+    }
+
     #[test]
     fn all_files_covered() {
         let dir = setup_test_dir();
@@ -181,6 +386,25 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn gitlab_exclusions_leave_files_unowned() {
+        use crate::matching::PatternSyntax;
+        use crate::parse::{Dialect, ParserConfig, parse_codeowners_with_config};
+
+        let dir = setup_test_dir();
+        let parser_config = ParserConfig::new().with_dialect(Dialect::GitLab);
+        let file = parse_codeowners_with_config("* @owner\n!/src/main.rs\n", &parser_config).ast;
+        let config = CheckConfig::new().with_pattern_syntax(PatternSyntax::GitLab);
+        let ctx = CheckContext::new(&file, dir.path(), &config);
+        let result = NotOwnedCheck::new().run(&ctx);
+
+        assert_eq!(result.errors.len(), 1);
+        assert!(matches!(
+            &result.errors[0],
+            ValidationError::FileNotOwned { path, .. } if path == "src/main.rs"
+        ));
+    }
+
     #[test]
     fn empty_codeowners_all_uncovered() {
         let dir = setup_test_dir();
@@ -206,6 +430,21 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn gitignore_forced_without_git_repo() {
+        let dir = setup_test_dir();
+        fs::write(dir.path().join(".gitignore"), "docs/\nCargo.toml\n").unwrap();
+        let input = "*.rs @rust\n/.gitignore @git\n";
+
+        // Not a git repo, so .gitignore is ignored by default
+        let result = run_check(input, dir.path());
+        assert_eq!(result.errors.len(), 2);
+
+        let config = CheckConfig::new().with_force_respect_gitignore(true);
+        let result = run_check_with_config(input, dir.path(), config);
+        assert!(result.is_ok());
+    }
+
     #[test]
     fn specific_patterns() {
         let dir = setup_test_dir();
@@ -4,13 +4,14 @@
 
 use super::{AsyncCheck, AsyncCheckContext};
 use crate::parse::{LineKind, Owner, Span};
-use crate::validate::github_client::{TeamExistsResult, UserExistsResult};
+use crate::validate::github_client::{GithubClientError, TeamExistsResult, UserExistsResult};
 use crate::validate::{ValidationError, ValidationResult};
 use async_trait::async_trait;
 use futures::future::join_all;
 use log::{debug, trace, warn};
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use tokio::sync::Semaphore;
 
 /// Represents the kind of validation failure for an owner, without span information.
@@ -21,23 +22,113 @@ enum OwnerValidationFailure {
     NotFound { owner: String, reason: String },
     /// Insufficient authorization to verify owner.
     Unauthorized { owner: String, reason: String },
-    /// Owner must be a team but is not.
-    MustBeTeam { owner: String },
+    /// Team exists but has no members.
+    TeamEmpty { owner: String },
+    /// Team has fewer members than the configured minimum.
+    TeamTooSmall {
+        owner: String,
+        members: usize,
+        min_size: usize,
+    },
+    /// Owner must be a team but is not, optionally with a replacement team.
+    MustBeTeam {
+        owner: String,
+        suggested_team: Option<String>,
+    },
+    /// Owner was not verified because the API request budget was exhausted.
+    Unchecked { owner: String, reason: String },
+    /// Owner could not be verified because the GitHub client returned an error.
+    /// `raised` is set when the client implementation itself raised (see
+    /// [`GithubClientError::Callback`]) rather than the GitHub API failing.
+    ClientError {
+        owner: String,
+        reason: String,
+        raised: bool,
+    },
 }
 
 impl OwnerValidationFailure {
     /// Creates a ValidationError from this failure with the given span.
-    fn to_error(&self, span: Span) -> ValidationError {
-        match self {
+    ///
+    /// Exceptions raised by the client say nothing about the owner, so they
+    /// yield no error; the check records them as its internal error instead.
+    fn to_error(&self, span: Span) -> Option<ValidationError> {
+        let error = match self {
             OwnerValidationFailure::NotFound { owner, reason } => {
                 ValidationError::owner_not_found(owner, reason, span)
             }
             OwnerValidationFailure::Unauthorized { owner, reason } => {
                 ValidationError::insufficient_authorization(owner, reason, span)
             }
-            OwnerValidationFailure::MustBeTeam { owner } => {
-                ValidationError::owner_must_be_team(owner, span)
+            OwnerValidationFailure::TeamEmpty { owner } => {
+                ValidationError::owner_team_empty(owner, span)
             }
+            OwnerValidationFailure::TeamTooSmall {
+                owner,
+                members,
+                min_size,
+            } => ValidationError::owner_team_too_small(owner, *members, *min_size, span),
+            OwnerValidationFailure::MustBeTeam {
+                owner,
+                suggested_team: Some(team),
+            } => ValidationError::owner_must_be_team_with_suggestion(owner, team, span),
+            OwnerValidationFailure::MustBeTeam {
+                owner,
+                suggested_team: None,
+            } => ValidationError::owner_must_be_team(owner, span),
+            OwnerValidationFailure::Unchecked { owner, reason } => {
+                ValidationError::owner_not_checked(owner, reason, span)
+            }
+            OwnerValidationFailure::ClientError { raised: true, .. } => return None,
+            OwnerValidationFailure::ClientError {
+                owner,
+                reason,
+                raised: false,
+            } => ValidationError::owner_not_found(owner, reason, span),
+        };
+        Some(error)
+    }
+}
+
+/// Tracks GitHub API requests made during a single owners check run.
+///
+/// When a limit is configured, each API call must acquire a slot first;
+/// once the limit is reached, remaining owners are left unchecked.
+#[derive(Debug)]
+struct ApiBudget {
+    limit: Option<usize>,
+    used: AtomicUsize,
+}
+
+impl ApiBudget {
+    fn new(limit: Option<usize>) -> Self {
+        Self {
+            limit,
+            used: AtomicUsize::new(0),
+        }
+    }
+
+    /// Reserves one API request, returning false if the budget is exhausted.
+    fn try_acquire(&self) -> bool {
+        match self.limit {
+            None => true,
+            Some(limit) => self
+                .used
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |used| {
+                    (used < limit).then_some(used + 1)
+                })
+                .is_ok(),
+        }
+    }
+
+    /// Builds the failure reported for an owner skipped due to the budget.
+    fn unchecked(&self, owner: String) -> OwnerValidationFailure {
+        OwnerValidationFailure::Unchecked {
+            owner,
+            reason: format!(
+                "GitHub API request budget of {} exhausted",
+                self.limit.unwrap_or_default()
+            ),
         }
     }
 }
@@ -46,6 +137,77 @@ impl OwnerValidationFailure {
 /// Conservative limit to leave headroom for other application requests.
 const MAX_CONCURRENT_REQUESTS: usize = 10;
 
+/// Maximum number of ancestors followed up a team hierarchy, guarding
+/// against cycles reported by a misbehaving client.
+const MAX_TEAM_DEPTH: usize = 16;
+
+/// Looks up the ancestors of nested teams during a single owners check run.
+///
+/// Each team's parent is looked up at most once per run, so teams sharing
+/// ancestors share the lookups.
+struct TeamTree<'a> {
+    ctx: &'a AsyncCheckContext<'a>,
+    budget: &'a ApiBudget,
+    parents: HashMap<(String, String), Option<String>>,
+}
+
+impl<'a> TeamTree<'a> {
+    fn new(ctx: &'a AsyncCheckContext<'a>, budget: &'a ApiBudget) -> Self {
+        Self {
+            ctx,
+            budget,
+            parents: HashMap::new(),
+        }
+    }
+
+    /// Returns the lowercased slug of a team's parent, or `None` if it has
+    /// none or it could not be looked up.
+    async fn parent(&mut self, org: &str, team: &str) -> Option<String> {
+        let key = (org.to_lowercase(), team.to_lowercase());
+        if let Some(parent) = self.parents.get(&key) {
+            return parent.clone();
+        }
+
+        let parent = if self.budget.try_acquire() {
+            trace!("Looking up parent of team @{}/{}", org, team);
+            match self.ctx.github_client.team_parent(org, team).await {
+                Ok(parent) => parent.map(|p| p.to_lowercase()),
+                Err(e) => {
+                    warn!(
+                        "GitHub client error looking up parent of @{}/{}: {}",
+                        org, team, e
+                    );
+                    None
+                }
+            }
+        } else {
+            debug!(
+                "Parent of @{}/{} left unchecked: API budget exhausted",
+                org, team
+            );
+            None
+        };
+        self.parents.insert(key, parent.clone());
+        parent
+    }
+
+    /// Returns the lowercased slugs of a team's ancestors, nearest first.
+    async fn ancestors(&mut self, org: &str, team: &str) -> Vec<String> {
+        let mut ancestors: Vec<String> = Vec::new();
+        let mut current = team.to_lowercase();
+        while ancestors.len() < MAX_TEAM_DEPTH {
+            match self.parent(org, &current).await {
+                Some(parent) if parent != team.to_lowercase() && !ancestors.contains(&parent) => {
+                    ancestors.push(parent.clone());
+                    current = parent;
+                }
+                _ => break,
+            }
+        }
+        ancestors
+    }
+}
+
 /// A check that validates owners exist on GitHub.
 ///
 /// For each owner in the CODEOWNERS file:
@@ -54,6 +216,18 @@ const MAX_CONCURRENT_REQUESTS: usize = 10;
 /// - `email@domain.com`: Skips validation (cannot verify via API)
 ///
 /// Reports authorization errors if the token lacks required permissions.
+/// When [`CheckConfig::check_empty_teams`](super::CheckConfig::check_empty_teams)
+/// is set, existing teams are also reported if they have no members, and
+/// [`CheckConfig::min_team_size`](super::CheckConfig::min_team_size) warns
+/// about teams smaller than the minimum.
+/// When [`CheckConfig::check_team_hierarchy`](super::CheckConfig::check_team_hierarchy)
+/// is set, rules naming a parent team along with one of its nested child
+/// teams produce warnings, since the child already owns the same paths.
+/// When [`CheckConfig::max_api_requests`](super::CheckConfig::max_api_requests)
+/// is set, owners beyond the budget are reported as unchecked warnings.
+/// When [`CheckConfig::derive_team_mapping`](super::CheckConfig::derive_team_mapping)
+/// is set along with `owners_must_be_teams`, users are offered the team
+/// listed in the file that they are a member of as a replacement.
 #[derive(Debug, Clone, Default)]
 pub struct OwnersCheck;
 
@@ -63,16 +237,181 @@ impl OwnersCheck {
         Self
     }
 
+    /// Builds the failure reported when the GitHub client itself fails.
+    ///
+    /// An exception raised by a client implementation (such as a Python
+    /// client) says nothing about whether the owner exists, so it is only
+    /// recorded as the check's internal error. Errors from the GitHub API
+    /// itself are also reported as errors on the owner. Either way, an
+    /// outage cannot make a run pass.
+    fn client_error(owner: String, error: &GithubClientError) -> OwnerValidationFailure {
+        warn!("GitHub client error checking {}: {}", owner, error);
+        if let Some(traceback) = error.traceback() {
+            debug!("Client traceback for {}:\n{}", owner, traceback);
+        }
+        let raised = matches!(error, GithubClientError::Callback { .. });
+        let reason = if raised {
+            format!("GitHub client error: {}", error)
+        } else {
+            format!("API error: {}", error)
+        };
+        OwnerValidationFailure::ClientError {
+            owner,
+            reason,
+            raised,
+        }
+    }
+
+    /// Looks up the members of an existing team and reports it if it is empty
+    /// or smaller than the configured minimum.
+    async fn validate_team_members(
+        org: &str,
+        team: &str,
+        ctx: &AsyncCheckContext<'_>,
+        budget: &ApiBudget,
+    ) -> Option<OwnerValidationFailure> {
+        let owner = format!("@{}/{}", org, team);
+        if !budget.try_acquire() {
+            debug!("Members of {} left unchecked: API budget exhausted", owner);
+            return Some(budget.unchecked(owner));
+        }
+
+        trace!("Checking members of team {}", owner);
+        match ctx.github_client.team_member_count(org, team).await {
+            Ok(Some(0)) if ctx.config.check_empty_teams => {
+                debug!("Team {} has no members", owner);
+                Some(OwnerValidationFailure::TeamEmpty { owner })
+            }
+            Ok(Some(count)) if ctx.config.min_team_size.is_some_and(|min| count < min) => {
+                debug!("Team {} has only {} member(s)", owner, count);
+                Some(OwnerValidationFailure::TeamTooSmall {
+                    owner,
+                    members: count,
+                    min_size: ctx.config.min_team_size.unwrap_or_default(),
+                })
+            }
+            Ok(Some(count)) => {
+                trace!("Team {} has {} member(s)", owner, count);
+                None
+            }
+            Ok(None) => {
+                debug!("Members of team {} could not be determined", owner);
+                None
+            }
+            Err(e) => Some(Self::client_error(owner, &e)),
+        }
+    }
+
+    /// Reports rules that name a parent team along with one of its child
+    /// teams.
+    ///
+    /// Only rules with several team owners need their teams' ancestors, so
+    /// other rules cost no API requests.
+    async fn check_team_hierarchy(
+        ctx: &AsyncCheckContext<'_>,
+        budget: &ApiBudget,
+        result: &mut ValidationResult,
+    ) {
+        let mut tree = TeamTree::new(ctx, budget);
+        for line in &ctx.file.lines {
+            let LineKind::Rule { owners, .. } = &line.kind else {
+                continue;
+            };
+            let teams: Vec<(&Owner, &str, &str)> = owners
+                .iter()
+                .filter_map(|owner| match owner {
+                    Owner::Team { org, team, .. } => Some((owner, org.as_str(), team.as_str())),
+                    _ => None,
+                })
+                .collect();
+            if teams.len() < 2 {
+                continue;
+            }
+
+            for (child, org, team) in &teams {
+                let ancestors = tree.ancestors(org, team).await;
+                for (parent, parent_org, parent_team) in &teams {
+                    if parent_org.eq_ignore_ascii_case(org)
+                        && ancestors.contains(&parent_team.to_lowercase())
+                    {
+                        debug!("{} is a parent of {}", parent.as_str(), child.as_str());
+                        result.add_error(ValidationError::redundant_parent_team(
+                            parent.as_str(),
+                            child.as_str(),
+                            *parent.span(),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Maps each user (lowercased, without the leading '@') to the one team
+    /// listed in the file that they are a member of.
+    ///
+    /// Users in several of the teams are left out, since no single
+    /// replacement can be suggested for them.
+    async fn derive_team_mapping(
+        owners: &[(String, Vec<&Owner>)],
+        ctx: &AsyncCheckContext<'_>,
+        budget: &ApiBudget,
+    ) -> HashMap<String, String> {
+        let mut teams_by_user: HashMap<String, Vec<&str>> = HashMap::new();
+        for (owner_str, occurrences) in owners {
+            let Owner::Team { org, team, .. } = occurrences[0] else {
+                continue;
+            };
+            if !budget.try_acquire() {
+                debug!(
+                    "Members of {} left unchecked: API budget exhausted",
+                    owner_str
+                );
+                break;
+            }
+            trace!("Listing members of {}", owner_str);
+            match ctx.github_client.team_members(org, team).await {
+                Ok(Some(logins)) => {
+                    for login in logins {
+                        let teams = teams_by_user.entry(login.to_lowercase()).or_default();
+                        if !teams.contains(&owner_str.as_str()) {
+                            teams.push(owner_str);
+                        }
+                    }
+                }
+                Ok(None) => debug!("Members of {} could not be determined", owner_str),
+                Err(e) => warn!(
+                    "GitHub client error listing members of {}: {}",
+                    owner_str, e
+                ),
+            }
+        }
+
+        teams_by_user
+            .into_iter()
+            .filter_map(|(user, teams)| match teams[..] {
+                [team] => Some((user, team.to_string())),
+                _ => None,
+            })
+            .collect()
+    }
+
     /// Validates a single owner and returns a failure description (without span).
     /// This allows us to validate once per unique owner and apply the result to all occurrences.
     async fn validate_owner_inner(
         &self,
         owner: &Owner,
         ctx: &AsyncCheckContext<'_>,
+        budget: &ApiBudget,
+        derived_teams: &HashMap<String, String>,
     ) -> Option<OwnerValidationFailure> {
         // Check if owner is in the ignored list
         let owner_str = owner.as_str();
-        if ctx.config.ignored_owners.contains(owner_str.as_ref()) {
+        if ctx
+            .config
+            .ignored_owners
+            .iter()
+            .any(|ignored| ignored.eq_ignore_ascii_case(&owner_str))
+        {
             trace!("Skipping ignored owner: {}", owner_str);
             return None;
         }
@@ -82,11 +421,25 @@ impl OwnersCheck {
                 // Check if owners must be teams
                 if ctx.config.owners_must_be_teams {
                     debug!("User @{} rejected: owners_must_be_teams is enabled", name);
+                    let owner = format!("@{}", name);
+                    let suggested_team = ctx
+                        .config
+                        .team_mapping
+                        .get(&owner)
+                        .or_else(|| ctx.config.team_mapping.get(name.as_str()))
+                        .or_else(|| derived_teams.get(&name.to_lowercase()))
+                        .cloned();
                     return Some(OwnerValidationFailure::MustBeTeam {
-                        owner: format!("@{}", name),
+                        owner,
+                        suggested_team,
                     });
                 }
 
+                if !budget.try_acquire() {
+                    debug!("User @{} left unchecked: API budget exhausted", name);
+                    return Some(budget.unchecked(format!("@{}", name)));
+                }
+
                 // Verify user exists using the GitHub client trait
                 trace!("Checking if user @{} exists", name);
                 match ctx.github_client.user_exists(name).await {
@@ -108,22 +461,28 @@ impl OwnersCheck {
                             reason: "may need additional token scopes".to_string(),
                         })
                     }
-                    Err(e) => {
-                        warn!("API error checking user @{}: {}", name, e);
-                        Some(OwnerValidationFailure::NotFound {
-                            owner: format!("@{}", name),
-                            reason: format!("API error: {}", e),
-                        })
-                    }
+                    Err(e) => Some(Self::client_error(format!("@{}", name), &e)),
                 }
             }
             Owner::Team { org, team, .. } => {
+                if !budget.try_acquire() {
+                    debug!(
+                        "Team @{}/{} left unchecked: API budget exhausted",
+                        org, team
+                    );
+                    return Some(budget.unchecked(format!("@{}/{}", org, team)));
+                }
+
                 // Verify team exists in organization using the GitHub client trait
                 trace!("Checking if team @{}/{} exists", org, team);
                 match ctx.github_client.team_exists(org, team).await {
                     Ok(TeamExistsResult::Exists) => {
                         trace!("Team @{}/{} exists", org, team);
-                        None
+                        if ctx.config.check_empty_teams || ctx.config.min_team_size.is_some() {
+                            Self::validate_team_members(org, team, ctx, budget).await
+                        } else {
+                            None
+                        }
                     }
                     Ok(TeamExistsResult::NotFound) => {
                         debug!("Team @{}/{} not found", org, team);
@@ -139,15 +498,14 @@ impl OwnersCheck {
                             reason: "may need read:org scope or team membership".to_string(),
                         })
                     }
-                    Err(e) => {
-                        warn!("API error checking team @{}/{}: {}", org, team, e);
-                        Some(OwnerValidationFailure::NotFound {
-                            owner: format!("@{}/{}", org, team),
-                            reason: format!("API error: {}", e),
-                        })
-                    }
+                    Err(e) => Some(Self::client_error(format!("@{}/{}", org, team), &e)),
                 }
             }
+            Owner::Invalid { .. } => {
+                // Malformed owners are reported by the syntax check
+                trace!("Skipping invalid owner: {}", owner.as_str());
+                None
+            }
             Owner::Email { .. } => {
                 // Cannot validate emails via GitHub API
                 trace!("Skipping email owner validation: {}", owner.as_str());
@@ -159,6 +517,7 @@ impl OwnersCheck {
                     );
                     return Some(OwnerValidationFailure::MustBeTeam {
                         owner: owner.as_str().into_owned(),
+                        suggested_team: None,
                     });
                 }
                 None
@@ -169,7 +528,7 @@ impl OwnersCheck {
 
 #[async_trait]
 impl AsyncCheck for OwnersCheck {
-    fn name(&self) -> &'static str {
+    fn name(&self) -> &str {
         "owners"
     }
 
@@ -177,16 +536,24 @@ impl AsyncCheck for OwnersCheck {
         debug!("Running owners check");
         let mut result = ValidationResult::new();
 
-        // Collect all owners grouped by their string representation.
-        // This allows us to make one API call per unique owner while tracking
-        // all occurrences so we can report errors for each line.
-        let mut owners_by_str: HashMap<String, Vec<&Owner>> = HashMap::new();
+        // Collect all owners grouped by their normalized form, in order of
+        // first appearance. This allows us to make one API call per unique owner
+        // while tracking all occurrences so we can report errors for each line.
+        // Keeping file order also means a limited API budget is spent on the
+        // owners that appear first.
+        let mut owner_index: HashMap<String, usize> = HashMap::new();
+        let mut owners_by_str: Vec<(String, Vec<&Owner>)> = Vec::new();
 
         for line in &ctx.file.lines {
             if let LineKind::Rule { owners, .. } = &line.kind {
                 for owner in owners {
-                    let owner_str = owner.as_str().into_owned();
-                    owners_by_str.entry(owner_str).or_default().push(owner);
+                    match owner_index.get(&owner.normalized()) {
+                        Some(&idx) => owners_by_str[idx].1.push(owner),
+                        None => {
+                            owner_index.insert(owner.normalized(), owners_by_str.len());
+                            owners_by_str.push((owner.as_str().into_owned(), vec![owner]));
+                        }
+                    }
                 }
             }
         }
@@ -194,7 +561,7 @@ impl AsyncCheck for OwnersCheck {
         debug!(
             "Collected {} unique owners to validate ({} total occurrences)",
             owners_by_str.len(),
-            owners_by_str.values().map(|v| v.len()).sum::<usize>()
+            owners_by_str.iter().map(|(_, v)| v.len()).sum::<usize>()
         );
 
         if owners_by_str.is_empty() {
@@ -203,20 +570,38 @@ impl AsyncCheck for OwnersCheck {
 
         // Use bounded concurrency to avoid rate limiting
         let semaphore = Arc::new(Semaphore::new(MAX_CONCURRENT_REQUESTS));
+        let budget = ApiBudget::new(ctx.config.max_api_requests);
+
+        // Team membership is only needed to suggest replacements for users
+        let derived_teams = if ctx.config.owners_must_be_teams
+            && ctx.config.derive_team_mapping
+            && owners_by_str
+                .iter()
+                .any(|(_, occurrences)| matches!(occurrences[0], Owner::User { .. }))
+        {
+            Self::derive_team_mapping(&owners_by_str, ctx, &budget).await
+        } else {
+            HashMap::new()
+        };
 
         // Create futures for all unique owner validations.
         // We validate using the first occurrence of each owner, but we'll
         // create errors for all occurrences if validation fails.
         let futures: Vec<_> = owners_by_str
             .iter()
-            .map(|(owner_str, occurrences)| {
+            .enumerate()
+            .map(|(idx, (_, occurrences))| {
                 let permit = semaphore.clone();
                 let first_occurrence = occurrences[0];
+                let budget = &budget;
+                let derived_teams = &derived_teams;
                 async move {
                     // Acquire semaphore permit before making API call
                     let _permit = permit.acquire().await.ok()?;
-                    let failure = self.validate_owner_inner(first_occurrence, ctx).await?;
-                    Some((owner_str.clone(), failure))
+                    let failure = self
+                        .validate_owner_inner(first_occurrence, ctx, budget, derived_teams)
+                        .await?;
+                    Some((idx, failure))
                 }
             })
             .collect();
@@ -224,29 +609,53 @@ impl AsyncCheck for OwnersCheck {
         // Run all validations concurrently (bounded by semaphore)
         let validation_results = join_all(futures).await;
 
-        // Collect validation failures
-        let failures: HashMap<String, OwnerValidationFailure> =
-            validation_results.into_iter().flatten().collect();
-
         // Create errors for ALL occurrences of each failed owner
-        for (owner_str, failure) in &failures {
+        let mut unchecked = 0;
+        let mut client_errors = Vec::new();
+        for (idx, failure) in validation_results.into_iter().flatten() {
+            let (owner_str, occurrences) = &owners_by_str[idx];
+
             // Check if it's an authorization error and log a warning (once per owner)
-            if matches!(failure, OwnerValidationFailure::Unauthorized { .. }) {
-                warn!(
+            match failure {
+                OwnerValidationFailure::Unauthorized { .. } => warn!(
                     "GitHub API authorization issue encountered for {}",
                     owner_str
-                );
+                ),
+                OwnerValidationFailure::Unchecked { .. } => unchecked += 1,
+                OwnerValidationFailure::ClientError { ref reason, .. } => {
+                    client_errors.push(reason.clone())
+                }
+                _ => {}
             }
 
-            // Get all occurrences of this owner and create an error for each
-            if let Some(occurrences) = owners_by_str.get(owner_str) {
-                for owner in occurrences {
-                    let error = failure.to_error(*owner.span());
+            for owner in occurrences {
+                if let Some(error) = failure.to_error(*owner.span()) {
                     result.add_error(error);
                 }
             }
         }
 
+        if ctx.config.check_team_hierarchy {
+            Self::check_team_hierarchy(ctx, &budget, &mut result).await;
+        }
+
+        if unchecked > 0 {
+            warn!(
+                "GitHub API request budget exhausted: {} owner(s) left unchecked",
+                unchecked
+            );
+        }
+
+        // Client failures make the check itself unreliable, beyond the
+        // individual owners it left unchecked
+        if let Some(reason) = client_errors.first() {
+            result.set_internal_error(format!(
+                "{} owner(s) could not be checked: {}",
+                client_errors.len(),
+                reason
+            ));
+        }
+
         debug!(
             "Owners check complete: {} errors found",
             result.errors.len()
@@ -260,7 +669,7 @@ mod tests {
     use super::*;
     use crate::parse::parse_codeowners;
     use crate::validate::checks::CheckConfig;
-    use crate::validate::github_client::{GithubClient, GithubClientError};
+    use crate::validate::github_client::GithubClient;
     use std::collections::HashSet;
     use std::path::PathBuf;
     use std::sync::atomic::{AtomicUsize, Ordering};
@@ -270,7 +679,12 @@ mod tests {
         users: HashSet<String>,
         teams: HashSet<(String, String)>,
         unauthorized_users: HashSet<String>,
+        failing_users: HashSet<String>,
+        failing_teams: HashSet<(String, String)>,
         unauthorized_teams: HashSet<(String, String)>,
+        team_members: HashMap<(String, String), usize>,
+        team_parents: HashMap<(String, String), String>,
+        member_logins: HashMap<(String, String), Vec<String>>,
         user_call_count: AtomicUsize,
         team_call_count: AtomicUsize,
     }
@@ -281,7 +695,12 @@ mod tests {
                 users: HashSet::new(),
                 teams: HashSet::new(),
                 unauthorized_users: HashSet::new(),
+                failing_users: HashSet::new(),
+                failing_teams: HashSet::new(),
                 unauthorized_teams: HashSet::new(),
+                team_members: HashMap::new(),
+                team_parents: HashMap::new(),
+                member_logins: HashMap::new(),
                 user_call_count: AtomicUsize::new(0),
                 team_call_count: AtomicUsize::new(0),
             }
@@ -297,22 +716,66 @@ mod tests {
             self
         }
 
+        fn with_failing_user(mut self, username: &str) -> Self {
+            self.failing_users.insert(username.to_string());
+            self
+        }
+
+        fn with_failing_team(mut self, org: &str, team: &str) -> Self {
+            self.failing_teams
+                .insert((org.to_string(), team.to_string()));
+            self
+        }
+
         fn with_unauthorized_team(mut self, org: &str, team: &str) -> Self {
             self.unauthorized_teams
                 .insert((org.to_string(), team.to_string()));
             self
         }
 
+        fn with_team_members(mut self, org: &str, team: &str, members: usize) -> Self {
+            self.teams.insert((org.to_string(), team.to_string()));
+            self.team_members
+                .insert((org.to_string(), team.to_string()), members);
+            self
+        }
+
+        fn with_team_parent(mut self, org: &str, team: &str, parent: &str) -> Self {
+            self.teams.insert((org.to_string(), team.to_string()));
+            self.team_parents
+                .insert((org.to_string(), team.to_string()), parent.to_string());
+            self
+        }
+
+        fn with_member_logins(mut self, org: &str, team: &str, logins: &[&str]) -> Self {
+            self.teams.insert((org.to_string(), team.to_string()));
+            self.member_logins.insert(
+                (org.to_string(), team.to_string()),
+                logins.iter().map(|login| login.to_string()).collect(),
+            );
+            self
+        }
+
         fn user_calls(&self) -> usize {
             self.user_call_count.load(Ordering::SeqCst)
         }
+
+        fn team_calls(&self) -> usize {
+            self.team_call_count.load(Ordering::SeqCst)
+        }
     }
 
     #[async_trait]
     impl GithubClient for MockGithubClient {
         async fn user_exists(&self, username: &str) -> Result<UserExistsResult, GithubClientError> {
             self.user_call_count.fetch_add(1, Ordering::SeqCst);
-            if self.unauthorized_users.contains(username) {
+            if self.failing_users.contains(username) {
+                Err(GithubClientError::Callback {
+                    method: "user_exists".to_string(),
+                    exception: "RuntimeError: connection reset".to_string(),
+                    traceback: Some("Traceback (most recent call last):\n".to_string()),
+                })
+            } else if self.unauthorized_users.contains(username) {
                 Ok(UserExistsResult::Unauthorized)
             } else if self.users.contains(username) {
                 Ok(UserExistsResult::Exists)
@@ -328,7 +791,11 @@ mod tests {
         ) -> Result<TeamExistsResult, GithubClientError> {
             self.team_call_count.fetch_add(1, Ordering::SeqCst);
             let key = (org.to_string(), team.to_string());
-            if self.unauthorized_teams.contains(&key) {
+            if self.failing_teams.contains(&key) {
+                Err(GithubClientError::NetworkError(
+                    "connection refused".to_string(),
+                ))
+            } else if self.unauthorized_teams.contains(&key) {
                 Ok(TeamExistsResult::Unauthorized)
             } else if self.teams.contains(&key) {
                 Ok(TeamExistsResult::Exists)
@@ -336,6 +803,42 @@ mod tests {
                 Ok(TeamExistsResult::NotFound)
             }
         }
+
+        async fn team_member_count(
+            &self,
+            org: &str,
+            team: &str,
+        ) -> Result<Option<usize>, GithubClientError> {
+            self.team_call_count.fetch_add(1, Ordering::SeqCst);
+            Ok(self
+                .team_members
+                .get(&(org.to_string(), team.to_string()))
+                .copied())
+        }
+
+        async fn team_members(
+            &self,
+            org: &str,
+            team: &str,
+        ) -> Result<Option<Vec<String>>, GithubClientError> {
+            self.team_call_count.fetch_add(1, Ordering::SeqCst);
+            Ok(self
+                .member_logins
+                .get(&(org.to_string(), team.to_string()))
+                .cloned())
+        }
+
+        async fn team_parent(
+            &self,
+            org: &str,
+            team: &str,
+        ) -> Result<Option<String>, GithubClientError> {
+            self.team_call_count.fetch_add(1, Ordering::SeqCst);
+            Ok(self
+                .team_parents
+                .get(&(org.to_string(), team.to_string()))
+                .cloned())
+        }
     }
 
     #[tokio::test]
@@ -419,6 +922,137 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn empty_team_reported_when_enabled() {
+        let client = MockGithubClient::new()
+            .with_team_members("org", "empty", 0)
+            .with_team_members("org", "full", 3)
+            .with_team("org", "unknown");
+        let file = parse_codeowners(
+            "*.rs @org/empty
+*.md @org/full @org/unknown
+",
+        )
+        .ast;
+        let path = PathBuf::from("/repo");
+
+        let config = CheckConfig::new();
+        let ctx = AsyncCheckContext::new(&file, &path, &config, &client);
+        assert!(OwnersCheck::new().run(&ctx).await.is_ok());
+        assert_eq!(client.team_calls(), 3);
+
+        let config = CheckConfig::new().with_check_empty_teams(true);
+        let ctx = AsyncCheckContext::new(&file, &path, &config, &client);
+        let result = OwnersCheck::new().run(&ctx).await;
+        assert_eq!(client.team_calls(), 9);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(
+            result.errors[0].to_string(),
+            "line 1: team '@org/empty' has no members and cannot review changes"
+        );
+        assert_eq!(
+            result.errors[0].severity(),
+            crate::validate::Severity::Error
+        );
+    }
+
+    #[tokio::test]
+    async fn small_team_warns_below_minimum() {
+        let client = MockGithubClient::new()
+            .with_team_members("org", "empty", 0)
+            .with_team_members("org", "pair", 2)
+            .with_team_members("org", "trio", 3);
+        let file = parse_codeowners(
+            "* @org/empty @org/pair @org/trio
+",
+        )
+        .ast;
+        let path = PathBuf::from("/repo");
+
+        let config = CheckConfig::new().with_min_team_size(3);
+        let ctx = AsyncCheckContext::new(&file, &path, &config, &client);
+        let messages: Vec<String> = OwnersCheck::new()
+            .run(&ctx)
+            .await
+            .errors
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "line 1: team '@org/empty' has 0 member(s), fewer than the minimum of 3",
+                "line 1: team '@org/pair' has 2 member(s), fewer than the minimum of 3",
+            ]
+        );
+
+        // Empty teams are errors when that check is enabled too
+        let config = config.with_check_empty_teams(true);
+        let ctx = AsyncCheckContext::new(&file, &path, &config, &client);
+        let result = OwnersCheck::new().run(&ctx).await;
+        assert!(matches!(
+            result.errors[0],
+            ValidationError::OwnerTeamEmpty { .. }
+        ));
+        assert_eq!(
+            result.errors[1].severity(),
+            crate::validate::Severity::Warning
+        );
+    }
+
+    #[tokio::test]
+    async fn redundant_parent_teams() {
+        // eng > eng-platform > eng-infra
+        let client = MockGithubClient::new()
+            .with_team("org", "eng")
+            .with_team("org", "docs")
+            .with_team_parent("org", "eng-platform", "eng")
+            .with_team_parent("org", "eng-infra", "eng-platform");
+        let file = parse_codeowners(
+            "/infra/ @org/eng @org/eng-infra
+/docs/ @org/docs @org/eng
+/ci/ @org/eng-platform
+",
+        )
+        .ast;
+        let path = PathBuf::from("/repo");
+
+        let config = CheckConfig::new();
+        let ctx = AsyncCheckContext::new(&file, &path, &config, &client);
+        assert!(OwnersCheck::new().run(&ctx).await.is_ok());
+
+        let config = CheckConfig::new().with_check_team_hierarchy(true);
+        let ctx = AsyncCheckContext::new(&file, &path, &config, &client);
+        let result = OwnersCheck::new().run(&ctx).await;
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].code(), "CO034");
+        assert_eq!(result.errors[0].subject(), "@org/eng");
+        assert_eq!(result.errors[0].span().column, 9);
+        assert!(
+            result.errors[0]
+                .to_string()
+                .contains("parent of '@org/eng-infra'")
+        );
+    }
+
+    #[tokio::test]
+    async fn team_hierarchy_survives_cycles() {
+        let client = MockGithubClient::new()
+            .with_team_parent("org", "a", "b")
+            .with_team_parent("org", "b", "a");
+        let file = parse_codeowners(
+            "* @org/a @org/b
+",
+        )
+        .ast;
+        let path = PathBuf::from("/repo");
+        let config = CheckConfig::new().with_check_team_hierarchy(true);
+        let ctx = AsyncCheckContext::new(&file, &path, &config, &client);
+
+        let result = OwnersCheck::new().run(&ctx).await;
+        assert_eq!(result.errors.len(), 2);
+    }
+
     #[tokio::test]
     async fn ignored_owner_skipped() {
         let client = MockGithubClient::new(); // No users - but should be skipped
@@ -452,6 +1086,75 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn user_must_be_team_suggests_mapped_team() {
+        let client = MockGithubClient::new();
+        let source = "*.rs @alice @bob\n";
+        let file = parse_codeowners(source).ast;
+        let path = PathBuf::from("/repo");
+        let mapping = HashMap::from([("@alice".to_string(), "@org/team".to_string())]);
+        let config = CheckConfig::new()
+            .with_owners_must_be_teams(true)
+            .with_team_mapping(mapping);
+        let ctx = AsyncCheckContext::new(&file, &path, &config, &client);
+
+        let result = OwnersCheck::new().run(&ctx).await;
+        assert_eq!(result.errors.len(), 2);
+
+        // Only the mapped user gets a fix
+        let fixes: Vec<_> = result.fixes().collect();
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(
+            crate::validate::apply_fixes(source, &fixes),
+            "*.rs @org/team @bob\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn user_must_be_team_suggests_derived_team() {
+        let client = MockGithubClient::new()
+            .with_member_logins("org", "core", &["Alice", "bob"])
+            .with_member_logins("org", "docs", &["bob"]);
+        let source = "*.rs @alice @bob @carol\n/src/ @org/core\n/docs/ @org/docs\n";
+        let file = parse_codeowners(source).ast;
+        let path = PathBuf::from("/repo");
+        let config = CheckConfig::new()
+            .with_owners_must_be_teams(true)
+            .with_derive_team_mapping(true);
+        let ctx = AsyncCheckContext::new(&file, &path, &config, &client);
+
+        let result = OwnersCheck::new().run(&ctx).await;
+        assert_eq!(result.errors.len(), 3);
+
+        // Bob is in both teams and Carol in neither, so only Alice gets a fix
+        let fixes: Vec<_> = result.fixes().collect();
+        assert_eq!(fixes.len(), 1);
+        assert!(
+            crate::validate::apply_fixes(source, &fixes)
+                .starts_with("*.rs @org/core @bob @carol\n")
+        );
+
+        // Without the setting, no membership is looked up
+        let client = MockGithubClient::new().with_member_logins("org", "core", &["alice"]);
+        let config = CheckConfig::new().with_owners_must_be_teams(true);
+        let ctx = AsyncCheckContext::new(&file, &path, &config, &client);
+        let result = OwnersCheck::new().run(&ctx).await;
+        assert_eq!(result.fixes().count(), 0);
+    }
+
+    #[tokio::test]
+    async fn owners_differing_in_case_checked_once() {
+        let client = MockGithubClient::new().with_team("org", "team");
+        let file = parse_codeowners("*.rs @org/team\n*.md @Org/Team\n").ast;
+        let path = PathBuf::from("/repo");
+        let config = CheckConfig::new();
+        let ctx = AsyncCheckContext::new(&file, &path, &config, &client);
+
+        let result = OwnersCheck::new().run(&ctx).await;
+        assert!(result.is_ok());
+        assert_eq!(client.team_calls(), 1);
+    }
+
     #[tokio::test]
     async fn email_skipped() {
         let client = MockGithubClient::new();
@@ -585,6 +1288,66 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn api_budget_leaves_remaining_owners_unchecked() {
+        let client = MockGithubClient::new()
+            .with_user("alice")
+            .with_user("bob")
+            .with_team("org", "team");
+        let file = parse_codeowners("*.rs @alice\n*.md @bob @org/team\n*.txt @bob\n").ast;
+        let path = PathBuf::from("/repo");
+        let config = CheckConfig::new().with_max_api_requests(1);
+        let ctx = AsyncCheckContext::new(&file, &path, &config, &client);
+
+        let result = OwnersCheck::new().run(&ctx).await;
+
+        // @alice is checked; @bob (2 occurrences) and @org/team are not
+        assert_eq!(client.user_calls(), 1);
+        assert_eq!(result.errors.len(), 3);
+        assert!(result.errors_only().next().is_none());
+        for error in &result.errors {
+            match error {
+                ValidationError::OwnerNotChecked { owner, reason, .. } => {
+                    assert_ne!(owner, "@alice");
+                    assert!(reason.contains("budget of 1"));
+                }
+                _ => panic!("Expected OwnerNotChecked error, got {:?}", error),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn api_budget_not_spent_on_skipped_owners() {
+        let client = MockGithubClient::new().with_user("alice");
+        let file = parse_codeowners("*.rs @ignored dev@example.com @alice\n").ast;
+        let path = PathBuf::from("/repo");
+        let ignored = HashSet::from(["@ignored".to_string()]);
+        let config = CheckConfig::new()
+            .with_ignored_owners(ignored)
+            .with_max_api_requests(1);
+        let ctx = AsyncCheckContext::new(&file, &path, &config, &client);
+
+        let result = OwnersCheck::new().run(&ctx).await;
+        assert!(result.is_ok());
+        assert_eq!(client.user_calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn zero_api_budget_checks_nothing() {
+        let client = MockGithubClient::new().with_user("alice");
+        let file = parse_codeowners("*.rs @alice\n").ast;
+        let path = PathBuf::from("/repo");
+        let config = CheckConfig::new().with_max_api_requests(0);
+        let ctx = AsyncCheckContext::new(&file, &path, &config, &client);
+
+        let result = OwnersCheck::new().run(&ctx).await;
+        assert_eq!(client.user_calls(), 0);
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ValidationError::OwnerNotChecked { .. }]
+        ));
+    }
+
     #[tokio::test]
     async fn mixed_valid_and_invalid_owners() {
         // Test that valid owners don't generate errors but invalid ones do for all occurrences
@@ -605,4 +1368,48 @@ mod tests {
         lines.sort();
         assert_eq!(lines, vec![1, 2]);
     }
+
+    #[tokio::test]
+    async fn client_exception_is_an_internal_error() {
+        let client = MockGithubClient::new()
+            .with_user("validuser")
+            .with_failing_user("flaky");
+        let file = parse_codeowners(
+            "*.rs @flaky @validuser
+*.md @flaky
+",
+        )
+        .ast;
+        let path = PathBuf::from("/repo");
+        let config = CheckConfig::new();
+        let ctx = AsyncCheckContext::new(&file, &path, &config, &client);
+
+        let result = OwnersCheck::new().run(&ctx).await;
+        assert!(result.errors.is_empty());
+        assert_eq!(
+            result.internal_error.as_deref(),
+            Some(
+                "1 owner(s) could not be checked: GitHub client error: \
+                 user_exists() raised RuntimeError: connection reset"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn api_error_stays_an_error() {
+        let client = MockGithubClient::new().with_failing_team("org", "core");
+        let file = parse_codeowners("*.rs @org/core\n").ast;
+        let path = PathBuf::from("/repo");
+        let config = CheckConfig::new();
+        let ctx = AsyncCheckContext::new(&file, &path, &config, &client);
+
+        let result = OwnersCheck::new().run(&ctx).await;
+        assert_eq!(result.errors_only().count(), 1);
+        assert!(matches!(
+            &result.errors[0],
+            ValidationError::OwnerNotFound { reason, .. }
+                if reason == "API error: Network error: connection refused"
+        ));
+        assert!(result.internal_error.is_some());
+    }
 }
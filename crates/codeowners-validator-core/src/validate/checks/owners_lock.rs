@@ -0,0 +1,155 @@
+//! Owners lockfile check.
+//!
+//! This check verifies that CODEOWNERS still assigns the owners pinned in an
+//! owners lockfile.
+
+use super::notowned::NotOwnedCheck;
+use super::{Check, CheckContext};
+use crate::matching::OwnersResolver;
+use crate::parse::Owner;
+use crate::validate::{ValidationError, ValidationResult};
+use log::debug;
+
+/// A check that verifies the owners of pinned paths did not change.
+///
+/// An [`OwnersLock`](crate::validate::lockfile::OwnersLock) pins the owners
+/// of critical paths. When CODEOWNERS assigns a pinned path other owners,
+/// the change must update the lockfile as well, so moving ownership of
+/// those paths never goes unnoticed in review. Owners are compared without
+/// regard to case or order.
+///
+/// Findings point at the rule that owns the path, or at the end of the
+/// file when no rule does. Runs offline against the lock set with
+/// [`CheckConfig::with_owners_lock`](super::CheckConfig::with_owners_lock),
+/// and does nothing without one.
+#[derive(Debug, Clone, Default)]
+pub struct OwnersLockCheck;
+
+impl OwnersLockCheck {
+    /// Creates a new owners lockfile check.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+/// Describes owners for a message, e.g. `@a, @b`.
+fn describe(owners: &[String]) -> String {
+    if owners.is_empty() {
+        "no owners".to_string()
+    } else {
+        owners.join(", ")
+    }
+}
+
+impl Check for OwnersLockCheck {
+    fn name(&self) -> &str {
+        "owners-lock"
+    }
+
+    fn run(&self, ctx: &CheckContext) -> ValidationResult {
+        let mut result = ValidationResult::new();
+
+        let Some(lock) = &ctx.config.owners_lock else {
+            debug!("No owners lockfile configured, skipping owners-lock check");
+            return result;
+        };
+
+        let resolver = OwnersResolver::with_syntax(
+            ctx.file,
+            ctx.config.question_mark,
+            ctx.config.pattern_syntax,
+        );
+        let eof_span = NotOwnedCheck::eof_span(ctx.file);
+
+        for entry in lock.entries() {
+            let rule = resolver.rule_for(entry.relative_path());
+            let mut actual: Vec<String> = rule
+                .as_ref()
+                .map(|rule| rule.owners.iter().map(Owner::normalized).collect())
+                .unwrap_or_default();
+            actual.sort_unstable();
+            actual.dedup();
+            if actual == entry.owners {
+                continue;
+            }
+
+            let span = rule.map_or(eof_span, |rule| rule.pattern.span);
+            result.add_error(ValidationError::pinned_owners_changed(
+                &entry.path,
+                describe(&entry.owners),
+                describe(&actual),
+                span,
+            ));
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_codeowners;
+    use crate::validate::checks::CheckConfig;
+    use crate::validate::lockfile::{LockEntry, OwnersLock};
+    use std::path::Path;
+
+    fn run_check(input: &str, lock: OwnersLock) -> ValidationResult {
+        let file = parse_codeowners(input).ast;
+        let config = CheckConfig::new().with_owners_lock(lock);
+        let ctx = CheckContext::new(&file, Path::new("/repo"), &config);
+        OwnersLockCheck::new().run(&ctx)
+    }
+
+    fn lock() -> OwnersLock {
+        OwnersLock::new([
+            LockEntry::new(
+                ".github/workflows/deploy.yml",
+                ["@acme/security".to_string(), "@acme/sre".to_string()],
+            ),
+            LockEntry::new("/infra/prod.tf", ["@acme/security".to_string()]),
+        ])
+    }
+
+    #[test]
+    fn accepts_pinned_owners() {
+        let input =
+            "* @acme/core\n/.github/workflows/ @Acme/SRE @acme/security\n/infra/ @acme/security\n";
+        assert!(run_check(input, lock()).is_ok());
+    }
+
+    #[test]
+    fn reports_changed_owners() {
+        let input = "* @acme/core\n/.github/workflows/ @acme/sre\n";
+        let result = run_check(input, lock());
+        assert_eq!(result.errors.len(), 2);
+        assert_eq!(
+            result.errors[0].to_string(),
+            "line 2: owners of protected path '.github/workflows/deploy.yml' changed from \
+             @acme/security, @acme/sre to @acme/sre without updating the owners lockfile"
+        );
+        assert_eq!(result.errors[1].line(), 1);
+        assert_eq!(result.errors[1].subject(), "/infra/prod.tf");
+    }
+
+    #[test]
+    fn reports_unowned_pinned_path() {
+        let result = run_check("/docs/ @acme/docs\n", lock());
+        assert_eq!(result.errors.len(), 2);
+        assert!(result.errors[1].to_string().contains("to no owners"));
+        assert_eq!(result.errors[1].span().column, 18); // end of the last line
+    }
+
+    #[test]
+    fn skipped_without_lock() {
+        let file = parse_codeowners("* @acme/core\n").ast;
+        let config = CheckConfig::new();
+        let ctx = CheckContext::new(&file, Path::new("/repo"), &config);
+        assert!(OwnersLockCheck::new().run(&ctx).is_ok());
+    }
+
+    #[test]
+    fn check_name() {
+        assert_eq!(OwnersLockCheck::new().name(), "owners-lock");
+    }
+}
@@ -0,0 +1,149 @@
+//! Ownership review check.
+//!
+//! This check flags rules whose `review-by` annotation has passed or is near.
+
+use super::{Check, CheckContext};
+use crate::parse::{LineKind, ReviewDate};
+use crate::validate::{ValidationError, ValidationResult};
+use log::debug;
+
+/// Days before a `review-by` date that a rule starts producing warnings,
+/// unless [`CheckConfig::review_warning_days`](super::CheckConfig::review_warning_days)
+/// is set.
+pub const DEFAULT_REVIEW_WARNING_DAYS: u32 = 30;
+
+/// A check that enforces periodic re-certification of ownership.
+///
+/// Rules can be annotated with `# review-by: YYYY-MM-DD` (see
+/// [`crate::parse::ReviewBy`]). Rules past their date are reported as errors,
+/// and rules due within the warning window, or with an unreadable date, as
+/// warnings.
+#[derive(Debug, Clone, Default)]
+pub struct ReviewByCheck {
+    /// The date to check against, or `None` for the current date.
+    today: Option<ReviewDate>,
+}
+
+impl ReviewByCheck {
+    /// Creates a new review check that uses the current date.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Checks against a fixed date instead of the current date.
+    pub fn with_today(mut self, today: ReviewDate) -> Self {
+        self.today = Some(today);
+        self
+    }
+}
+
+impl Check for ReviewByCheck {
+    fn name(&self) -> &str {
+        "review-by"
+    }
+
+    fn run(&self, ctx: &CheckContext) -> ValidationResult {
+        let mut result = ValidationResult::new();
+        let today = self.today.unwrap_or_else(ReviewDate::today);
+        let warning_days = ctx
+            .config
+            .review_warning_days
+            .unwrap_or(DEFAULT_REVIEW_WARNING_DAYS);
+        debug!(
+            "Checking review-by dates against {} ({} day warning window)",
+            today, warning_days
+        );
+
+        for (index, line) in ctx.file.lines.iter().enumerate() {
+            let LineKind::Rule { pattern, .. } = &line.kind else {
+                continue;
+            };
+            let Some(review) = ctx.file.review_by_of(index) else {
+                continue;
+            };
+
+            let (expired, reason) = match review.date {
+                None => (
+                    false,
+                    format!("has an invalid review-by date '{}'", review.value),
+                ),
+                Some(date) => match today.days_until(date) {
+                    days if days < 0 => (true, format!("expired on {}", date)),
+                    days if days <= i64::from(warning_days) => (
+                        false,
+                        format!("is due for review on {} (in {} day(s))", date, days),
+                    ),
+                    _ => continue,
+                },
+            };
+            result.add_error(ValidationError::ownership_review_due(
+                &pattern.text,
+                review.value,
+                expired,
+                reason,
+                pattern.span,
+            ));
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_codeowners;
+    use crate::validate::Severity;
+    use crate::validate::checks::CheckConfig;
+    use std::path::PathBuf;
+
+    const CODEOWNERS: &str = "\
+# review-by: 2025-12-31
+/expired/ @a
+# review-by: 2026-01-20
+/soon/ @b
+# review-by: 2026-06-30
+/later/ @c
+# review-by: next year
+/invalid/ @d
+/unannotated/ @e
+";
+
+    fn run_check(config: &CheckConfig) -> ValidationResult {
+        let file = parse_codeowners(CODEOWNERS).ast;
+        let path = PathBuf::from("/repo");
+        let ctx = CheckContext::new(&file, &path, config);
+        ReviewByCheck::new()
+            .with_today(ReviewDate::new(2026, 1, 10).unwrap())
+            .run(&ctx)
+    }
+
+    #[test]
+    fn flags_expired_and_due_rules() {
+        let result = run_check(&CheckConfig::new());
+
+        let messages: Vec<String> = result.errors.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "line 2: ownership of '/expired/' expired on 2025-12-31",
+                "line 4: ownership of '/soon/' is due for review on 2026-01-20 (in 10 day(s))",
+                "line 8: ownership of '/invalid/' has an invalid review-by date 'next year'",
+            ]
+        );
+        let severities: Vec<_> = result.errors.iter().map(|e| e.severity()).collect();
+        assert_eq!(
+            severities,
+            [Severity::Error, Severity::Warning, Severity::Warning]
+        );
+    }
+
+    #[test]
+    fn warning_window_is_configurable() {
+        let result = run_check(&CheckConfig::new().with_review_warning_days(5));
+        assert_eq!(result.errors.len(), 2);
+
+        let result = run_check(&CheckConfig::new().with_review_warning_days(365));
+        assert_eq!(result.errors.len(), 4);
+    }
+}
@@ -0,0 +1,159 @@
+//! CODEOWNERS self-ownership check.
+//!
+//! This check verifies that the CODEOWNERS file itself is owned by a rule,
+//! and optionally by an admin team.
+
+use super::notowned::NotOwnedCheck;
+use super::{Check, CheckContext};
+use crate::find_codeowners_file;
+use crate::matching::{OwnersResolver, to_repo_path};
+use crate::parse::Owner;
+use crate::validate::{ValidationError, ValidationResult};
+use log::debug;
+
+/// A check that verifies the CODEOWNERS file owns itself.
+///
+/// Without a rule covering the CODEOWNERS file, any contributor can change
+/// who must review what, and that change needs no code owner review. The
+/// file is located the way GitHub does, as the first of
+/// `.github/CODEOWNERS`, `CODEOWNERS` and `docs/CODEOWNERS` that exists.
+///
+/// Once admin teams are set with
+/// [`CheckConfig::with_admin_teams`](super::CheckConfig::with_admin_teams),
+/// the file must also be owned by at least one of them, such as a platform
+/// team; other owners alone only get a warning.
+#[derive(Debug, Clone, Default)]
+pub struct SelfOwnershipCheck;
+
+impl SelfOwnershipCheck {
+    /// Creates a new self-ownership check.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Check for SelfOwnershipCheck {
+    fn name(&self) -> &str {
+        "self-ownership"
+    }
+
+    fn run(&self, ctx: &CheckContext) -> ValidationResult {
+        let mut result = ValidationResult::new();
+
+        let Some(path) = find_codeowners_file(ctx.repo_path)
+            .and_then(|path| to_repo_path(path.strip_prefix(ctx.repo_path).ok()?))
+        else {
+            debug!("No CODEOWNERS file found, skipping self-ownership check");
+            return result;
+        };
+
+        let resolver = OwnersResolver::with_syntax(
+            ctx.file,
+            ctx.config.question_mark,
+            ctx.config.pattern_syntax,
+        );
+        let rule = resolver
+            .rule_for(&path)
+            .filter(|rule| !rule.owners.is_empty());
+        let Some(rule) = rule else {
+            result.add_error(ValidationError::codeowners_not_owned(
+                path,
+                NotOwnedCheck::eof_span(ctx.file),
+            ));
+            return result;
+        };
+
+        let admin_teams = &ctx.config.admin_teams;
+        if !admin_teams.is_empty()
+            && !rule
+                .owners
+                .iter()
+                .any(|owner| admin_teams.contains(&owner.normalized()))
+        {
+            let mut admins: Vec<_> = admin_teams.iter().cloned().collect();
+            admins.sort_unstable();
+            let owners: Vec<_> = rule.owners.iter().map(Owner::as_str).collect();
+            result.add_error(ValidationError::codeowners_not_admin_owned(
+                path,
+                owners.join(", "),
+                admins.join(", "),
+                rule.pattern.span,
+            ));
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_codeowners;
+    use crate::validate::checks::CheckConfig;
+    use std::collections::HashSet;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn run_check(input: &str, config: &CheckConfig) -> ValidationResult {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join(".github")).unwrap();
+        fs::write(dir.path().join(".github/CODEOWNERS"), input).unwrap();
+
+        let file = parse_codeowners(input).ast;
+        let ctx = CheckContext::new(&file, dir.path(), config);
+        SelfOwnershipCheck::new().run(&ctx)
+    }
+
+    #[test]
+    fn reports_unowned_codeowners() {
+        let result = run_check("/src/ @org/core\n", &CheckConfig::new());
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(
+            result.errors[0].to_string(),
+            "line 1: the CODEOWNERS file '.github/CODEOWNERS' is not owned by any rule, \
+             so anyone can change review requirements"
+        );
+
+        // Owning the directory of another CODEOWNERS location does not help
+        let result = run_check("/docs/ @org/docs\n", &CheckConfig::new());
+        assert_eq!(result.errors[0].code(), "CO030");
+    }
+
+    #[test]
+    fn accepts_owned_codeowners() {
+        let result = run_check("* @org/core\n", &CheckConfig::new());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn requires_admin_team_when_configured() {
+        let config = CheckConfig::new().with_admin_teams(HashSet::from([
+            "@Org/Platform".to_string(),
+            "@org/security".to_string(),
+        ]));
+
+        let result = run_check("* @org/core\n", &config);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(
+            result.errors[0].to_string(),
+            "line 1: the CODEOWNERS file '.github/CODEOWNERS' is owned by @org/core, \
+             but by none of the admin teams @org/platform, @org/security"
+        );
+
+        let input = "* @org/core\n/.github/CODEOWNERS @org/core @org/platform\n";
+        assert!(run_check(input, &config).is_ok());
+    }
+
+    #[test]
+    fn skipped_without_codeowners_file() {
+        let file = parse_codeowners("/src/ @org/core\n").ast;
+        let config = CheckConfig::new();
+        let ctx = CheckContext::new(&file, std::path::Path::new("/nonexistent"), &config);
+        assert!(SelfOwnershipCheck::new().run(&ctx).is_ok());
+    }
+
+    #[test]
+    fn check_name() {
+        assert_eq!(SelfOwnershipCheck::new().name(), "self-ownership");
+    }
+}
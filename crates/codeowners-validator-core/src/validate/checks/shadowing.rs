@@ -120,7 +120,7 @@ struct CompiledPattern {
 }
 
 impl Check for AvoidShadowingCheck {
-    fn name(&self) -> &'static str {
+    fn name(&self) -> &str {
         "avoid-shadowing"
     }
 
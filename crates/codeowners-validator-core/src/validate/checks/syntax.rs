@@ -4,13 +4,18 @@
 
 use super::{Check, CheckContext};
 use crate::validate::ValidationResult;
-use crate::validate::syntax::validate_syntax as validate_syntax_impl;
+use crate::validate::syntax::{
+    validate_email_policy, validate_question_mark_policy, validate_syntax_with,
+};
 
 /// A check that validates CODEOWNERS syntax.
 ///
 /// This includes:
 /// - Owner format validation (@user, @org/team, email)
-/// - Pattern syntax validation (no unsupported gitignore features)
+/// - Pattern syntax validation (no unsupported gitignore features, except
+///   character classes with [`PatternSyntax::Extended`](crate::matching::PatternSyntax::Extended))
+/// - Email owner trust policy (trusted domains, or rejecting emails entirely)
+/// - `?` policy (warns on `?` when configured as unsupported)
 #[derive(Debug, Clone, Default)]
 pub struct SyntaxCheck;
 
@@ -22,18 +27,29 @@ impl SyntaxCheck {
 }
 
 impl Check for SyntaxCheck {
-    fn name(&self) -> &'static str {
+    fn name(&self) -> &str {
         "syntax"
     }
 
     fn run(&self, ctx: &CheckContext) -> ValidationResult {
-        validate_syntax_impl(ctx.file)
+        let mut result = validate_syntax_with(ctx.file, ctx.config.pattern_syntax);
+        result.merge(validate_email_policy(
+            ctx.file,
+            &ctx.config.trusted_email_domains,
+            ctx.config.reject_email_owners,
+        ));
+        result.merge(validate_question_mark_policy(
+            ctx.file,
+            ctx.config.question_mark,
+        ));
+        result
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::matching::PatternSyntax;
     use crate::parse::parse_codeowners;
     use crate::validate::checks::CheckConfig;
     use std::path::PathBuf;
@@ -68,5 +84,27 @@ mod tests {
     fn character_class_not_supported() {
         let result = run_check("*.[ch] @owner\n");
         assert!(result.has_errors());
+
+        let file = parse_codeowners("*.[ch] @owner\n").ast;
+        let path = PathBuf::from("/repo");
+        let config = CheckConfig::new().with_pattern_syntax(PatternSyntax::Extended);
+        let ctx = CheckContext::new(&file, &path, &config);
+        assert!(SyntaxCheck::new().run(&ctx).is_ok());
+    }
+
+    #[test]
+    fn email_policy_from_config() {
+        let file = parse_codeowners("*.rs dev@other.com\n").ast;
+        let path = PathBuf::from("/repo");
+        let config = CheckConfig::new()
+            .with_trusted_email_domains(["example.com".to_string()].into_iter().collect());
+        let ctx = CheckContext::new(&file, &path, &config);
+        let result = SyntaxCheck::new().run(&ctx);
+        assert_eq!(result.warnings_only().count(), 1);
+
+        let config = CheckConfig::new().with_reject_email_owners(true);
+        let ctx = CheckContext::new(&file, &path, &config);
+        let result = SyntaxCheck::new().run(&ctx);
+        assert_eq!(result.errors_only().count(), 1);
     }
 }
@@ -0,0 +1,366 @@
+//! Shared validation engine for long-lived frontends.
+//!
+//! A [`ValidationEngine`] is bound to one repository and owns everything that
+//! can be reused between validations: the check configuration and runner, the
+//! repository file listings, parsed rule sets and GitHub owner lookups. It is
+//! `Send + Sync`, so a single engine behind an [`Arc`] can serve concurrent
+//! validations from several threads or tasks.
+//!
+//! [`SharedEngine`] holds the current engine for services whose configuration
+//! can change while they run. A reload swaps in a new engine atomically;
+//! validations already in flight finish on the engine they started with.
+//! With the `watch` feature, `validate::watch::EngineWatcher` reloads it
+//! whenever the CODEOWNERS file or the configuration changes on disk.
+
+use crate::parse::{CodeownersFile, ParserConfig, parse_codeowners_with_config};
+use crate::validate::ValidationResult;
+use crate::validate::checks::{CheckConfig, CheckRunner};
+use crate::validate::file_walker::FileIndex;
+use crate::validate::github_client::{CachingGithubClient, GithubClient};
+use log::{debug, info, trace};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
+
+/// Default number of parsed rule sets kept by an engine.
+pub const DEFAULT_RULE_CACHE_CAPACITY: usize = 32;
+
+/// A thread-safe validation engine bound to one repository.
+///
+/// Parsed rule sets are cached by content and shared as
+/// `Arc<CodeownersFile>`, so concurrent requests for the same CODEOWNERS
+/// revision parse it only once. Call [`ValidationEngine::clear_cache`] after
+/// the repository checkout changes.
+///
+/// # Example
+///
+/// ```rust
+/// use codeowners_validator_core::validate::checks::CheckRunner;
+/// use codeowners_validator_core::validate::engine::ValidationEngine;
+/// use std::sync::Arc;
+///
+/// let engine = Arc::new(ValidationEngine::new(".", CheckRunner::new()));
+/// let rules = engine.rules("*.rs @rustacean\n");
+/// assert!(Arc::ptr_eq(&rules, &engine.rules("*.rs @rustacean\n")));
+/// ```
+pub struct ValidationEngine {
+    repo_path: PathBuf,
+    config: CheckConfig,
+    parser_config: ParserConfig,
+    runner: CheckRunner,
+    file_index: Arc<FileIndex>,
+    github_client: Option<CachingGithubClient<Box<dyn GithubClient>>>,
+    rule_cache_capacity: usize,
+    rules: Mutex<HashMap<String, Arc<CodeownersFile>>>,
+}
+
+impl ValidationEngine {
+    /// Creates an engine that runs the given checks against a repository.
+    pub fn new(repo_path: impl Into<PathBuf>, runner: CheckRunner) -> Self {
+        let file_index = Arc::new(FileIndex::new());
+        Self {
+            repo_path: repo_path.into(),
+            config: CheckConfig::new(),
+            parser_config: ParserConfig::default(),
+            runner: runner.with_file_index(Arc::clone(&file_index)),
+            file_index,
+            github_client: None,
+            rule_cache_capacity: DEFAULT_RULE_CACHE_CAPACITY,
+            rules: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Sets the check configuration.
+    pub fn with_config(mut self, config: CheckConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Sets the parser configuration used for rule sets.
+    pub fn with_parser_config(mut self, config: ParserConfig) -> Self {
+        self.parser_config = config;
+        self
+    }
+
+    /// Sets the GitHub client used by asynchronous checks.
+    ///
+    /// The client is wrapped in a [`CachingGithubClient`] so owner lookups
+    /// are shared between validations.
+    pub fn with_github_client(mut self, client: impl GithubClient + 'static) -> Self {
+        self.github_client = Some(CachingGithubClient::new(Box::new(client)));
+        self
+    }
+
+    /// Sets how many parsed rule sets are kept. Zero disables the cache.
+    pub fn with_rule_cache_capacity(mut self, capacity: usize) -> Self {
+        self.rule_cache_capacity = capacity;
+        self
+    }
+
+    /// Returns the repository root.
+    pub fn repo_path(&self) -> &Path {
+        &self.repo_path
+    }
+
+    /// Returns the check configuration.
+    pub fn config(&self) -> &CheckConfig {
+        &self.config
+    }
+
+    /// Returns true if a GitHub client is configured.
+    pub fn has_github_client(&self) -> bool {
+        self.github_client.is_some()
+    }
+
+    /// Returns the parsed rules for CODEOWNERS content, parsing it on first use.
+    pub fn rules(&self, content: &str) -> Arc<CodeownersFile> {
+        if let Some(file) = self.lock_rules().get(content) {
+            trace!("Reusing parsed rules ({} bytes)", content.len());
+            return Arc::clone(file);
+        }
+
+        // Parse without holding the lock so other requests are not blocked
+        let file = Arc::new(parse_codeowners_with_config(content, &self.parser_config).ast);
+        if self.rule_cache_capacity > 0 {
+            let mut rules = self.lock_rules();
+            if rules.len() >= self.rule_cache_capacity {
+                debug!("Rule cache full, dropping {} entries", rules.len());
+                rules.clear();
+            }
+            rules.insert(content.to_string(), Arc::clone(&file));
+        }
+        file
+    }
+
+    /// Runs the synchronous checks on CODEOWNERS content.
+    pub fn validate_sync(&self, content: &str) -> ValidationResult {
+        let file = self.rules(content);
+        self.runner.run_sync(&file, &self.repo_path, &self.config)
+    }
+
+    /// Runs all checks on CODEOWNERS content.
+    ///
+    /// Asynchronous checks only run when a GitHub client is configured.
+    pub async fn validate(&self, content: &str) -> ValidationResult {
+        let file = self.rules(content);
+        let client = self
+            .github_client
+            .as_ref()
+            .map(|client| client as &dyn GithubClient);
+        self.runner
+            .run_all(&file, &self.repo_path, &self.config, client)
+            .await
+    }
+
+    /// Drops cached file listings, rule sets and owner lookups.
+    pub fn clear_cache(&self) {
+        debug!("Clearing validation engine caches");
+        self.file_index.clear();
+        self.lock_rules().clear();
+        if let Some(client) = &self.github_client {
+            client.clear();
+        }
+    }
+
+    fn lock_rules(&self) -> MutexGuard<'_, HashMap<String, Arc<CodeownersFile>>> {
+        self.rules
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl std::fmt::Debug for ValidationEngine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ValidationEngine")
+            .field("repo_path", &self.repo_path)
+            .field("config", &self.config)
+            .field("github_client", &self.github_client)
+            .field("cached_rules", &self.lock_rules().len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A handle to the current [`ValidationEngine`] that can be swapped at runtime.
+///
+/// Each reload bumps a generation counter and logs a reload event, so
+/// long-running services can pick up configuration changes without a restart.
+#[derive(Debug)]
+pub struct SharedEngine {
+    current: RwLock<Arc<ValidationEngine>>,
+    generation: AtomicU64,
+}
+
+impl SharedEngine {
+    /// Creates a handle holding the initial engine (generation 0).
+    pub fn new(engine: ValidationEngine) -> Self {
+        Self {
+            current: RwLock::new(Arc::new(engine)),
+            generation: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the engine new validations should use.
+    pub fn current(&self) -> Arc<ValidationEngine> {
+        let current = self
+            .current
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        Arc::clone(&current)
+    }
+
+    /// Returns how many times the engine has been replaced.
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::SeqCst)
+    }
+
+    /// Atomically replaces the engine and returns the new generation.
+    pub fn replace(&self, engine: ValidationEngine) -> u64 {
+        let repo_path = engine.repo_path().display().to_string();
+        let github_client = engine.has_github_client();
+
+        let mut current = self
+            .current
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        *current = Arc::new(engine);
+        let generation = self.generation.fetch_add(1, Ordering::SeqCst) + 1;
+        drop(current);
+
+        info!(
+            "event=engine_reload generation={} repo_path={:?} github_client={}",
+            generation, repo_path, github_client
+        );
+        generation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validate::checks::FilesCheck;
+    use crate::validate::github_client::{GithubClientError, TeamExistsResult, UserExistsResult};
+    use async_trait::async_trait;
+    use std::fs::{self, File};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tempfile::TempDir;
+
+    fn files_engine(dir: &TempDir) -> ValidationEngine {
+        let mut runner = CheckRunner::new();
+        runner.add_check(FilesCheck::new());
+        ValidationEngine::new(dir.path(), runner)
+    }
+
+    #[test]
+    fn engine_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<ValidationEngine>();
+    }
+
+    #[test]
+    fn rules_are_shared() {
+        let dir = TempDir::new().unwrap();
+        let engine = files_engine(&dir);
+
+        let first = engine.rules("*.rs @a\n");
+        assert!(Arc::ptr_eq(&first, &engine.rules("*.rs @a\n")));
+        assert!(!Arc::ptr_eq(&first, &engine.rules("*.md @a\n")));
+    }
+
+    #[test]
+    fn rule_cache_is_bounded() {
+        let dir = TempDir::new().unwrap();
+        let engine = files_engine(&dir).with_rule_cache_capacity(1);
+
+        let first = engine.rules("*.rs @a\n");
+        engine.rules("*.md @a\n");
+        assert!(!Arc::ptr_eq(&first, &engine.rules("*.rs @a\n")));
+    }
+
+    #[test]
+    fn clear_cache_refreshes_file_listing() {
+        let dir = TempDir::new().unwrap();
+        let engine = files_engine(&dir);
+        assert_eq!(engine.validate_sync("/docs/ @a\n").errors.len(), 1);
+
+        fs::create_dir(dir.path().join("docs")).unwrap();
+        File::create(dir.path().join("docs/README.md")).unwrap();
+        assert_eq!(engine.validate_sync("/docs/ @a\n").errors.len(), 1);
+
+        engine.clear_cache();
+        assert!(engine.validate_sync("/docs/ @a\n").is_ok());
+    }
+
+    /// Counts user lookups; every user exists.
+    #[derive(Clone, Default)]
+    struct CountingClient {
+        calls: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl GithubClient for CountingClient {
+        async fn user_exists(
+            &self,
+            _username: &str,
+        ) -> Result<UserExistsResult, GithubClientError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(UserExistsResult::Exists)
+        }
+
+        async fn team_exists(
+            &self,
+            _org: &str,
+            _team: &str,
+        ) -> Result<TeamExistsResult, GithubClientError> {
+            Ok(TeamExistsResult::Exists)
+        }
+    }
+
+    #[tokio::test]
+    async fn concurrent_validations_share_owner_lookups() {
+        let dir = TempDir::new().unwrap();
+        let client = CountingClient::default();
+        let engine = Arc::new(
+            ValidationEngine::new(dir.path(), CheckRunner::with_all_checks())
+                .with_github_client(client.clone()),
+        );
+
+        let tasks: Vec<_> = (0..4)
+            .map(|_| {
+                let engine = Arc::clone(&engine);
+                tokio::spawn(async move { engine.validate("* @alice\n").await })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        // Concurrent first lookups may race, but later ones hit the cache
+        let calls = client.calls.load(Ordering::SeqCst);
+        assert!((1..=4).contains(&calls));
+        engine.validate("* @alice\n").await;
+        assert_eq!(client.calls.load(Ordering::SeqCst), calls);
+    }
+
+    #[test]
+    fn shared_engine_swaps_atomically() {
+        let dir = TempDir::new().unwrap();
+        let shared = SharedEngine::new(files_engine(&dir));
+        let before = shared.current();
+        assert_eq!(shared.generation(), 0);
+
+        let generation = shared.replace(
+            ValidationEngine::new(dir.path(), CheckRunner::new())
+                .with_config(CheckConfig::new().with_repository("org/repo")),
+        );
+        assert_eq!(generation, 1);
+        assert_eq!(shared.generation(), 1);
+
+        // The old snapshot stays usable; new callers see the replacement
+        assert_eq!(before.validate_sync("/docs/ @a\n").errors.len(), 1);
+        let after = shared.current();
+        assert!(!Arc::ptr_eq(&before, &after));
+        assert_eq!(after.config().repository.as_deref(), Some("org/repo"));
+        assert!(after.validate_sync("/docs/ @a\n").is_ok());
+    }
+}
@@ -3,12 +3,16 @@
 //! This module defines validation error types that describe
 //! semantic issues found after parsing.
 
+use super::fix::Fix;
+use super::syntax::validate_owner_syntax;
 use crate::parse::span::Span;
-use serde::Serialize;
+use crate::parse::{Owner, OwnerKind, classify_owner};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use thiserror::Error;
 
 /// The severity of a validation issue.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Severity {
     /// A warning that doesn't prevent the file from working.
@@ -17,9 +21,87 @@ pub enum Severity {
     Error,
 }
 
+/// Gitignore syntax that CODEOWNERS patterns do not support.
+///
+/// Each kind has a stable code, shown in messages and machine-readable
+/// output, and an explanation of how GitHub treats the syntax.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum UnsupportedSyntax {
+    /// A leading `!` negating an earlier pattern.
+    Negation,
+    /// A `\` at the end of the pattern.
+    TrailingBackslash,
+    /// A `\` escaping the next character.
+    Escape,
+    /// A `[...]` character class.
+    CharacterClass,
+    /// A `?` single-character wildcard.
+    QuestionMark,
+}
+
+impl UnsupportedSyntax {
+    /// Returns the stable code identifying this syntax.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Negation => "negation",
+            Self::TrailingBackslash => "trailing-backslash",
+            Self::Escape => "escape",
+            Self::CharacterClass => "character-class",
+            Self::QuestionMark => "question-mark",
+        }
+    }
+
+    /// Explains how GitHub treats this syntax.
+    pub fn explanation(self) -> &'static str {
+        match self {
+            Self::Negation => {
+                "negation patterns (!) are not supported in CODEOWNERS; GitHub does not exclude \
+                 the paths, so to remove ownership from paths matched by an earlier rule, add a \
+                 later rule for them without owners"
+            }
+            Self::TrailingBackslash => {
+                "a trailing backslash is not an escape or directory marker in CODEOWNERS; \
+                 paths use '/' as the separator"
+            }
+            Self::Escape => {
+                "escape sequences (\\) are not supported in CODEOWNERS; GitHub does not treat \
+                 the next character literally, and paths use '/' as the separator"
+            }
+            Self::CharacterClass => {
+                "character classes ([abc]) are not supported in CODEOWNERS; GitHub does not \
+                 match them as a set of characters, so list each alternative as its own rule"
+            }
+            Self::QuestionMark => {
+                "the '?' wildcard is not supported by this dialect; use '*' or spell out the \
+                 character"
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for UnsupportedSyntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// The stable codes of all validation errors, as returned by
+/// [`ValidationError::code`].
+///
+/// Codes are never reused or renumbered; new kinds of errors get new codes.
+pub const ERROR_CODES: &[&str] = &[
+    "CO001", "CO002", "CO003", "CO004", "CO005", "CO006", "CO007", "CO008", "CO009", "CO010",
+    "CO011", "CO012", "CO013", "CO014", "CO015", "CO016", "CO017", "CO018", "CO019", "CO020",
+    "CO021", "CO022", "CO023", "CO024", "CO025", "CO026", "CO027", "CO028", "CO029", "CO030",
+    "CO031", "CO032", "CO033", "CO034", "CO035", "CO036", "CO037", "CO038",
+];
+
 /// A validation error found in a CODEOWNERS file.
 #[derive(Debug, Clone, Error, PartialEq, Eq, Serialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
 pub enum ValidationError {
     /// Invalid owner format.
     #[error("line {line}: invalid owner format '{owner}' - {reason}")]
@@ -48,14 +130,18 @@ pub enum ValidationError {
     },
 
     /// Pattern uses unsupported gitignore syntax.
-    #[error("line {line}: pattern '{pattern}' uses unsupported syntax - {reason}")]
+    #[error("line {line}: pattern '{pattern}' uses unsupported syntax ({syntax}) - {reason}")]
     UnsupportedPatternSyntax {
         /// The line number (1-based).
         line: usize,
         /// The pattern with unsupported syntax.
         pattern: String,
-        /// What syntax is not supported.
+        /// Which unsupported syntax the pattern uses.
+        syntax: UnsupportedSyntax,
+        /// How GitHub treats the syntax.
         reason: String,
+        /// An equivalent pattern without the unsupported syntax, if one exists.
+        suggestion: Option<String>,
         /// Location of the pattern.
         span: Span,
     },
@@ -71,6 +157,41 @@ pub enum ValidationError {
         first_line: usize,
         /// Location of the duplicate pattern.
         span: Span,
+        /// Region to delete to remove the previous occurrence, which the
+        /// duplicate overrides: its whole line, including the line break.
+        removal: Option<Span>,
+    },
+
+    /// The same owner is listed more than once on a rule line.
+    #[error("line {line}: owner '{owner}' is listed more than once")]
+    DuplicateOwner {
+        /// The line number (1-based).
+        line: usize,
+        /// The repeated owner.
+        owner: String,
+        /// Location of the repeated occurrence.
+        span: Span,
+        /// Region to delete to remove the repeated occurrence, including the
+        /// whitespace before it.
+        removal: Span,
+    },
+
+    /// An owner is spelled with different letter case than elsewhere in the
+    /// file.
+    #[error(
+        "line {line}: owner '{owner}' is spelled '{canonical}' on line {canonical_line}; owners are case-insensitive, so use one spelling"
+    )]
+    InconsistentOwnerCase {
+        /// The line number (1-based).
+        line: usize,
+        /// The owner as spelled here.
+        owner: String,
+        /// The spelling of the owner's first occurrence.
+        canonical: String,
+        /// The line of the owner's first occurrence.
+        canonical_line: usize,
+        /// Location of the owner.
+        span: Span,
     },
 
     /// Pattern doesn't match any files in the repository.
@@ -82,6 +203,9 @@ pub enum ValidationError {
         pattern: String,
         /// Location of the pattern.
         span: Span,
+        /// Region to delete to remove the rule: its whole line, including
+        /// the line break.
+        removal: Option<Span>,
     },
 
     /// Owner not found on GitHub.
@@ -110,6 +234,51 @@ pub enum ValidationError {
         span: Span,
     },
 
+    /// Team owner exists but has no members, so it can never review.
+    #[error("line {line}: team '{owner}' has no members and cannot review changes")]
+    OwnerTeamEmpty {
+        /// The line number (1-based).
+        line: usize,
+        /// The empty team.
+        owner: String,
+        /// Location of the owner.
+        span: Span,
+    },
+
+    /// Team owner has fewer members than the configured minimum.
+    #[error(
+        "line {line}: team '{owner}' has {members} member(s), fewer than the minimum of {min_size}"
+    )]
+    OwnerTeamTooSmall {
+        /// The line number (1-based).
+        line: usize,
+        /// The team owner.
+        owner: String,
+        /// The number of team members.
+        members: usize,
+        /// The configured minimum team size.
+        min_size: usize,
+        /// Location of the owner.
+        span: Span,
+    },
+
+    /// A pattern matches different files depending on how `?` is read.
+    #[error(
+        "line {line}: pattern '{pattern}' matches {wildcard_matches} file(s) if '?' is a wildcard but {literal_matches} if it is literal"
+    )]
+    AmbiguousQuestionMark {
+        /// The line number (1-based).
+        line: usize,
+        /// The pattern containing `?`.
+        pattern: String,
+        /// Number of files matched when `?` is a wildcard.
+        wildcard_matches: usize,
+        /// Number of files matched when `?` is a literal character.
+        literal_matches: usize,
+        /// Location of the pattern.
+        span: Span,
+    },
+
     /// File in repository has no CODEOWNERS coverage.
     #[error("line {line}: file '{path}' is not covered by any CODEOWNERS rule")]
     FileNotOwned {
@@ -138,6 +307,28 @@ pub enum ValidationError {
         span: Span,
     },
 
+    /// Two patterns differ only in letter case.
+    #[error(
+        "line {line}: pattern '{pattern}' differs only in case from '{other_pattern}' on line {other_line}; {}",
+        case_collision_detail(.pattern, *.matches_files, .other_pattern, *.other_matches_files)
+    )]
+    PatternCaseCollision {
+        /// The line number of the later pattern (1-based).
+        line: usize,
+        /// The later pattern.
+        pattern: String,
+        /// The line number of the earlier pattern.
+        other_line: usize,
+        /// The earlier pattern it collides with.
+        other_pattern: String,
+        /// Whether the later pattern matches files in the repository.
+        matches_files: bool,
+        /// Whether the earlier pattern matches files in the repository.
+        other_matches_files: bool,
+        /// Location of the later pattern.
+        span: Span,
+    },
+
     /// Owner must be a team but a user was specified.
     #[error("line {line}: owner '{owner}' must be a team (@org/team), not a user")]
     OwnerMustBeTeam {
@@ -145,9 +336,355 @@ pub enum ValidationError {
         line: usize,
         /// The owner that should be a team.
         owner: String,
+        /// A team that can replace the user, if one is known.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        suggested_team: Option<String>,
+        /// Location of the owner.
+        span: Span,
+    },
+
+    /// Email owner belongs to a domain that is not trusted.
+    #[error("line {line}: email owner '{owner}' is not in a trusted domain ('{domain}')")]
+    UntrustedEmailDomain {
+        /// The line number (1-based).
+        line: usize,
+        /// The email owner.
+        owner: String,
+        /// The untrusted email domain.
+        domain: String,
+        /// Location of the owner.
+        span: Span,
+    },
+
+    /// Email owners are not allowed by the configured policy.
+    #[error("line {line}: email owner '{owner}' is not allowed, use a GitHub user or team")]
+    EmailOwnerNotAllowed {
+        /// The line number (1-based).
+        line: usize,
+        /// The email owner.
+        owner: String,
+        /// Location of the owner.
+        span: Span,
+    },
+
+    /// A generated region no longer matches its recorded hash.
+    #[error(
+        "line {line}: region generated by '{generator}' was edited by hand (expected hash {expected_hash}, found {actual_hash})"
+    )]
+    GeneratedRegionModified {
+        /// The line number of the `generated-by` comment (1-based).
+        line: usize,
+        /// The generating tool.
+        generator: String,
+        /// The hash recorded in the region.
+        expected_hash: String,
+        /// The hash of the current region content.
+        actual_hash: String,
+        /// Location of the region, from the `generated-by` comment to its
+        /// last line.
+        span: Span,
+    },
+
+    /// Team owner is not backed by a usable identity provider group.
+    #[error("line {line}: team '{owner}' {reason}")]
+    IdpGroupMismatch {
+        /// The line number (1-based).
+        line: usize,
+        /// The team owner.
+        owner: String,
+        /// What is wrong with the backing group.
+        reason: String,
+        /// Location of the owner.
+        span: Span,
+    },
+
+    /// A rule's ownership is past, or close to, its `review-by` date.
+    #[error("line {line}: ownership of '{pattern}' {reason}")]
+    OwnershipReviewDue {
+        /// The line number (1-based).
+        line: usize,
+        /// The rule's pattern.
+        pattern: String,
+        /// The `review-by` value, as written.
+        review_by: String,
+        /// Whether the review date has passed.
+        expired: bool,
+        /// Details about the review date.
+        reason: String,
+        /// Location of the pattern.
+        span: Span,
+    },
+
+    /// A file-extension rule is overridden by later directory rules.
+    #[error("line {line}: extension rule '{pattern}' is overridden by later rule(s) {overriding}")]
+    ExtensionRuleOverridden {
+        /// The line number (1-based).
+        line: usize,
+        /// The extension rule's pattern.
+        pattern: String,
+        /// The overriding rules with their line numbers.
+        overriding: String,
+        /// Location of the pattern.
+        span: Span,
+    },
+
+    /// An unanchored directory pattern also matches nested directories.
+    #[error(
+        "line {line}: pattern '{pattern}' matches directories at any depth, including {nested}; use '{anchored}' for the root directory only, or '{recursive}' to match every level explicitly"
+    )]
+    UnanchoredDirectory {
+        /// The line number (1-based).
+        line: usize,
+        /// The unanchored pattern.
+        pattern: String,
+        /// The nested directories the pattern also matches.
+        nested: String,
+        /// The pattern anchored to the repository root.
+        anchored: String,
+        /// The pattern matching every level explicitly.
+        recursive: String,
+        /// Location of the pattern.
+        span: Span,
+    },
+
+    /// A GitLab section's required approval count is not a positive integer.
+    #[error(
+        "line {line}: section '{section}' has invalid approval count '{approvals}' - expected a positive integer"
+    )]
+    InvalidSectionApprovals {
+        /// The line number (1-based).
+        line: usize,
+        /// The section name.
+        section: String,
+        /// The approval count, as written.
+        approvals: String,
+        /// Location of the approval count.
+        span: Span,
+    },
+
+    /// The same owner list is repeated across many rules.
+    #[error("line {line}: owner list '{owners}' is repeated on lines {lines}; {suggestion}")]
+    RepeatedOwnerGroup {
+        /// The line number of the first rule with the list (1-based).
+        line: usize,
+        /// The owners, as written on the first rule.
+        owners: String,
+        /// The lines of all rules with the list.
+        lines: String,
+        /// How to consolidate the list.
+        suggestion: String,
+        /// Location of the owners on the first rule.
+        span: Span,
+    },
+
+    /// The file or a section is larger than its complexity budget.
+    #[error(
+        "line {line}: {scope} has {count} {unit}, over the budget of {budget}; consider consolidating rules or splitting ownership into sections"
+    )]
+    ComplexityBudgetExceeded {
+        /// The line number of the first rule or line past the budget, or of
+        /// the section header (1-based).
+        line: usize,
+        /// What is over budget, e.g. `file` or `section 'Docs'`.
+        scope: String,
+        /// The number of rules or lines.
+        count: usize,
+        /// What is counted, `rules` or `lines`.
+        unit: String,
+        /// The budget.
+        budget: usize,
+        /// Location of the first rule or line past the budget, or of the
+        /// section name.
+        span: Span,
+    },
+
+    /// A path is not owned by exactly the teams an ownership manifest declares.
+    #[error("line {line}: ownership manifest path '{path}' {reason}")]
+    OwnershipManifestMismatch {
+        /// The line number of the rule owning the path, or of the end of
+        /// the file (1-based).
+        line: usize,
+        /// The path, as declared in the manifest.
+        path: String,
+        /// How the owners differ from the declared teams.
+        reason: String,
+        /// Location of the owning rule's pattern, or the end of the file.
+        span: Span,
+    },
+
+    /// Owner was not verified because the GitHub API request budget ran out.
+    #[error("line {line}: owner '{owner}' was not checked - {reason}")]
+    OwnerNotChecked {
+        /// The line number (1-based).
+        line: usize,
+        /// The owner that was left unchecked.
+        owner: String,
+        /// Why the owner was not checked.
+        reason: String,
         /// Location of the owner.
         span: Span,
     },
+
+    /// A file name in the repository is not valid UTF-8, so its ownership
+    /// was not checked.
+    #[error(
+        "line {line}: file '{path}' has a name that is not valid UTF-8, so its ownership was not checked"
+    )]
+    NonUtf8Path {
+        /// The line number (1-based), pointing to EOF.
+        line: usize,
+        /// The file path, with invalid bytes replaced by U+FFFD.
+        path: String,
+        /// Location at the end of the CODEOWNERS file.
+        span: Span,
+    },
+
+    /// The CODEOWNERS file itself is not owned by any rule.
+    #[error(
+        "line {line}: the CODEOWNERS file '{path}' is not owned by any rule, so anyone can change review requirements"
+    )]
+    CodeownersNotOwned {
+        /// The line number (1-based), pointing to EOF.
+        line: usize,
+        /// The path of the CODEOWNERS file, relative to the repository root.
+        path: String,
+        /// Location at the end of the CODEOWNERS file.
+        span: Span,
+    },
+
+    /// The CODEOWNERS file itself is not owned by an admin team.
+    #[error(
+        "line {line}: the CODEOWNERS file '{path}' is owned by {owners}, but by none of the admin teams {admin_teams}"
+    )]
+    CodeownersNotAdminOwned {
+        /// The line number of the rule owning the file (1-based).
+        line: usize,
+        /// The path of the CODEOWNERS file, relative to the repository root.
+        path: String,
+        /// The owners of the file, e.g. `@alice, @bob`.
+        owners: String,
+        /// The admin teams, e.g. `@org/platform`.
+        admin_teams: String,
+        /// Location of the owning rule's pattern.
+        span: Span,
+    },
+
+    /// A finding reported by a check outside this crate.
+    #[error("line {line}: {message}")]
+    CustomCheckFinding {
+        /// The line number (1-based).
+        line: usize,
+        /// The name of the check that reported the finding.
+        check: String,
+        /// Human-readable description of the finding.
+        message: String,
+        /// The severity chosen by the check.
+        severity: Severity,
+        /// Location of the finding.
+        span: Span,
+    },
+
+    /// The owners of a path pinned in the owners lockfile changed.
+    #[error(
+        "line {line}: owners of protected path '{path}' changed from {pinned} to {actual} without updating the owners lockfile"
+    )]
+    PinnedOwnersChanged {
+        /// The line number (1-based).
+        line: usize,
+        /// The pinned path, as written in the lockfile.
+        path: String,
+        /// The owners pinned in the lockfile, e.g. `@org/security`.
+        pinned: String,
+        /// The owners CODEOWNERS assigns now.
+        actual: String,
+        /// Location of the owning rule's pattern, or the end of the file.
+        span: Span,
+    },
+
+    /// A rule names a parent team along with one of its child teams.
+    #[error(
+        "line {line}: team '{parent}' is a parent of '{child}', which already owns the same paths, so requesting both reviews is redundant"
+    )]
+    RedundantParentTeam {
+        /// The line number (1-based).
+        line: usize,
+        /// The parent team owner.
+        parent: String,
+        /// The child team owner on the same rule.
+        child: String,
+        /// Location of the parent team owner.
+        span: Span,
+    },
+
+    /// A rule's `escalation` annotation is not `provider:policy`.
+    #[error(
+        "line {line}: rule '{pattern}' has an invalid escalation annotation '{value}': {reason}"
+    )]
+    InvalidEscalation {
+        /// The line number (1-based).
+        line: usize,
+        /// The rule's pattern.
+        pattern: String,
+        /// The annotation value, as written.
+        value: String,
+        /// Why the value is invalid.
+        reason: String,
+        /// Location of the pattern.
+        span: Span,
+    },
+
+    /// A `codeowners:` directive annotation is misspelled or misplaced.
+    #[error("line {line}: invalid annotation '{annotation}': {reason}")]
+    InvalidAnnotation {
+        /// The line number (1-based).
+        line: usize,
+        /// The annotation, as written.
+        annotation: String,
+        /// Why the annotation is invalid.
+        reason: String,
+        /// Location of the line holding the annotation.
+        span: Span,
+    },
+
+    /// A listed file is a symbolic link, so its ownership was not checked.
+    #[error("line {line}: file '{path}' is a symbolic link, so its ownership was not checked")]
+    SymlinkPath {
+        /// The line number (1-based), pointing to EOF.
+        line: usize,
+        /// The path of the link.
+        path: String,
+        /// Location at the end of the CODEOWNERS file.
+        span: Span,
+    },
+
+    /// The repository walk stopped early, so files it did not reach were
+    /// not checked.
+    #[error(
+        "line {line}: the repository listing is incomplete because the walk {reason}, so not every file was checked"
+    )]
+    IncompleteListing {
+        /// The line number (1-based), pointing to EOF.
+        line: usize,
+        /// Why the walk stopped, e.g. `took longer than 30s`.
+        reason: String,
+        /// Location at the end of the CODEOWNERS file.
+        span: Span,
+    },
+}
+
+/// Describes which side of a case collision matches files in the repository.
+fn case_collision_detail(
+    pattern: &str,
+    matches_files: bool,
+    other_pattern: &str,
+    other_matches_files: bool,
+) -> String {
+    match (matches_files, other_matches_files) {
+        (true, true) => "both match files in the repository".to_string(),
+        (true, false) => format!("only '{pattern}' matches files in the repository"),
+        (false, true) => format!("only '{other_pattern}' matches files in the repository"),
+        (false, false) => "neither matches files in the repository".to_string(),
+    }
 }
 
 impl ValidationError {
@@ -159,120 +696,638 @@ impl ValidationError {
     ) -> Self {
         Self::InvalidOwnerFormat {
             line: span.line,
-            owner: owner.into(),
-            reason: reason.into(),
+            owner: owner.into(),
+            reason: reason.into(),
+            span,
+        }
+    }
+
+    /// Creates an invalid pattern syntax error.
+    pub fn invalid_pattern_syntax(
+        pattern: impl Into<String>,
+        reason: impl Into<String>,
+        span: Span,
+    ) -> Self {
+        Self::InvalidPatternSyntax {
+            line: span.line,
+            pattern: pattern.into(),
+            reason: reason.into(),
+            span,
+        }
+    }
+
+    /// Creates an unsupported pattern syntax error.
+    pub fn unsupported_pattern_syntax(
+        pattern: impl Into<String>,
+        syntax: UnsupportedSyntax,
+        span: Span,
+    ) -> Self {
+        Self::UnsupportedPatternSyntax {
+            line: span.line,
+            pattern: pattern.into(),
+            syntax,
+            reason: syntax.explanation().to_string(),
+            suggestion: None,
+            span,
+        }
+    }
+
+    /// Creates an unsupported pattern syntax error suggesting an equivalent pattern.
+    pub fn unsupported_pattern_syntax_with_suggestion(
+        pattern: impl Into<String>,
+        syntax: UnsupportedSyntax,
+        suggestion: impl Into<String>,
+        span: Span,
+    ) -> Self {
+        Self::UnsupportedPatternSyntax {
+            line: span.line,
+            pattern: pattern.into(),
+            syntax,
+            reason: syntax.explanation().to_string(),
+            suggestion: Some(suggestion.into()),
+            span,
+        }
+    }
+
+    /// Creates a duplicate pattern error.
+    pub fn duplicate_pattern(pattern: impl Into<String>, span: Span, first_line: usize) -> Self {
+        Self::DuplicatePattern {
+            line: span.line,
+            pattern: pattern.into(),
+            first_line,
+            span,
+            removal: None,
+        }
+    }
+
+    /// Creates a duplicate pattern error that can be fixed by deleting the
+    /// previous, overridden occurrence.
+    ///
+    /// `removal` covers the whole line of the previous occurrence, including
+    /// its line break, so deleting it leaves no blank line behind.
+    pub fn duplicate_pattern_with_removal(
+        pattern: impl Into<String>,
+        span: Span,
+        first_line: usize,
+        removal: Span,
+    ) -> Self {
+        Self::DuplicatePattern {
+            line: span.line,
+            pattern: pattern.into(),
+            first_line,
+            span,
+            removal: Some(removal),
+        }
+    }
+
+    /// Creates a duplicate owner error.
+    ///
+    /// `removal` covers the repeated owner plus the whitespace separating it
+    /// from the previous token, so deleting it leaves the line well-formed.
+    pub fn duplicate_owner(owner: impl Into<String>, span: Span, removal: Span) -> Self {
+        Self::DuplicateOwner {
+            line: span.line,
+            owner: owner.into(),
+            span,
+            removal,
+        }
+    }
+
+    /// Creates an inconsistent owner case warning.
+    pub fn inconsistent_owner_case(
+        owner: impl Into<String>,
+        span: Span,
+        canonical: impl Into<String>,
+        canonical_line: usize,
+    ) -> Self {
+        Self::InconsistentOwnerCase {
+            line: span.line,
+            owner: owner.into(),
+            canonical: canonical.into(),
+            canonical_line,
+            span,
+        }
+    }
+
+    /// Creates a pattern not matching error.
+    pub fn pattern_not_matching(pattern: impl Into<String>, span: Span) -> Self {
+        Self::PatternNotMatching {
+            line: span.line,
+            pattern: pattern.into(),
+            span,
+            removal: None,
+        }
+    }
+
+    /// Creates a pattern not matching error that can be fixed by deleting
+    /// the rule, whose line `removal` covers.
+    pub fn pattern_not_matching_with_removal(
+        pattern: impl Into<String>,
+        span: Span,
+        removal: Span,
+    ) -> Self {
+        Self::PatternNotMatching {
+            line: span.line,
+            pattern: pattern.into(),
+            span,
+            removal: Some(removal),
+        }
+    }
+
+    /// Creates an ambiguous question mark warning.
+    pub fn ambiguous_question_mark(
+        pattern: impl Into<String>,
+        wildcard_matches: usize,
+        literal_matches: usize,
+        span: Span,
+    ) -> Self {
+        Self::AmbiguousQuestionMark {
+            line: span.line,
+            pattern: pattern.into(),
+            wildcard_matches,
+            literal_matches,
+            span,
+        }
+    }
+
+    /// Creates an owner not found error.
+    pub fn owner_not_found(
+        owner: impl Into<String>,
+        reason: impl Into<String>,
+        span: Span,
+    ) -> Self {
+        Self::OwnerNotFound {
+            line: span.line,
+            owner: owner.into(),
+            reason: reason.into(),
+            span,
+        }
+    }
+
+    /// Creates an empty owner team error.
+    pub fn owner_team_empty(owner: impl Into<String>, span: Span) -> Self {
+        Self::OwnerTeamEmpty {
+            line: span.line,
+            owner: owner.into(),
+            span,
+        }
+    }
+
+    /// Creates an owner team too small error.
+    pub fn owner_team_too_small(
+        owner: impl Into<String>,
+        members: usize,
+        min_size: usize,
+        span: Span,
+    ) -> Self {
+        Self::OwnerTeamTooSmall {
+            line: span.line,
+            owner: owner.into(),
+            members,
+            min_size,
+            span,
+        }
+    }
+
+    /// Creates an insufficient authorization error.
+    pub fn insufficient_authorization(
+        owner: impl Into<String>,
+        reason: impl Into<String>,
+        span: Span,
+    ) -> Self {
+        Self::InsufficientAuthorization {
+            line: span.line,
+            owner: owner.into(),
+            reason: reason.into(),
+            span,
+        }
+    }
+
+    /// Creates a file not owned error.
+    ///
+    /// The span should point to the end of the CODEOWNERS file (EOF position).
+    pub fn file_not_owned(path: impl Into<String>, span: Span) -> Self {
+        Self::FileNotOwned {
+            line: span.line,
+            path: path.into(),
+            span,
+        }
+    }
+
+    /// Creates a pattern shadowed error.
+    pub fn pattern_shadowed(
+        pattern: impl Into<String>,
+        span: Span,
+        shadowing_pattern: impl Into<String>,
+        shadowing_line: usize,
+    ) -> Self {
+        Self::PatternShadowed {
+            line: span.line,
+            pattern: pattern.into(),
+            shadowing_line,
+            shadowing_pattern: shadowing_pattern.into(),
+            span,
+        }
+    }
+
+    /// Creates a pattern case collision error.
+    pub fn pattern_case_collision(
+        pattern: impl Into<String>,
+        span: Span,
+        other_pattern: impl Into<String>,
+        other_line: usize,
+        matches_files: bool,
+        other_matches_files: bool,
+    ) -> Self {
+        Self::PatternCaseCollision {
+            line: span.line,
+            pattern: pattern.into(),
+            other_line,
+            other_pattern: other_pattern.into(),
+            matches_files,
+            other_matches_files,
+            span,
+        }
+    }
+
+    /// Creates a generated region modified error.
+    pub fn generated_region_modified(
+        generator: impl Into<String>,
+        expected_hash: impl Into<String>,
+        actual_hash: impl Into<String>,
+        span: Span,
+    ) -> Self {
+        Self::GeneratedRegionModified {
+            line: span.line,
+            generator: generator.into(),
+            expected_hash: expected_hash.into(),
+            actual_hash: actual_hash.into(),
+            span,
+        }
+    }
+
+    /// Creates an IdP group mismatch error.
+    pub fn idp_group_mismatch(
+        owner: impl Into<String>,
+        reason: impl Into<String>,
+        span: Span,
+    ) -> Self {
+        Self::IdpGroupMismatch {
+            line: span.line,
+            owner: owner.into(),
+            reason: reason.into(),
+            span,
+        }
+    }
+
+    /// Creates an owner must be team error.
+    pub fn owner_must_be_team(owner: impl Into<String>, span: Span) -> Self {
+        Self::OwnerMustBeTeam {
+            line: span.line,
+            owner: owner.into(),
+            suggested_team: None,
+            span,
+        }
+    }
+
+    /// Creates an owner must be team error suggesting a replacement team.
+    pub fn owner_must_be_team_with_suggestion(
+        owner: impl Into<String>,
+        suggested_team: impl Into<String>,
+        span: Span,
+    ) -> Self {
+        Self::OwnerMustBeTeam {
+            line: span.line,
+            owner: owner.into(),
+            suggested_team: Some(suggested_team.into()),
+            span,
+        }
+    }
+
+    /// Creates an untrusted email domain error.
+    pub fn untrusted_email_domain(
+        owner: impl Into<String>,
+        domain: impl Into<String>,
+        span: Span,
+    ) -> Self {
+        Self::UntrustedEmailDomain {
+            line: span.line,
+            owner: owner.into(),
+            domain: domain.into(),
+            span,
+        }
+    }
+
+    /// Creates an email owner not allowed error.
+    pub fn email_owner_not_allowed(owner: impl Into<String>, span: Span) -> Self {
+        Self::EmailOwnerNotAllowed {
+            line: span.line,
+            owner: owner.into(),
+            span,
+        }
+    }
+
+    /// Creates an ownership review due error.
+    pub fn ownership_review_due(
+        pattern: impl Into<String>,
+        review_by: impl Into<String>,
+        expired: bool,
+        reason: impl Into<String>,
+        span: Span,
+    ) -> Self {
+        Self::OwnershipReviewDue {
+            line: span.line,
+            pattern: pattern.into(),
+            review_by: review_by.into(),
+            expired,
+            reason: reason.into(),
+            span,
+        }
+    }
+
+    /// Creates an extension rule overridden error.
+    pub fn extension_rule_overridden(
+        pattern: impl Into<String>,
+        overriding: impl Into<String>,
+        span: Span,
+    ) -> Self {
+        Self::ExtensionRuleOverridden {
+            line: span.line,
+            pattern: pattern.into(),
+            overriding: overriding.into(),
+            span,
+        }
+    }
+
+    /// Creates an unanchored directory error.
+    pub fn unanchored_directory(
+        pattern: impl Into<String>,
+        nested: impl Into<String>,
+        span: Span,
+    ) -> Self {
+        let pattern = pattern.into();
+        Self::UnanchoredDirectory {
+            line: span.line,
+            anchored: format!("/{}", pattern),
+            recursive: format!("**/{}", pattern),
+            pattern,
+            nested: nested.into(),
+            span,
+        }
+    }
+
+    /// Creates an invalid section approvals error.
+    pub fn invalid_section_approvals(
+        section: impl Into<String>,
+        approvals: impl Into<String>,
+        span: Span,
+    ) -> Self {
+        Self::InvalidSectionApprovals {
+            line: span.line,
+            section: section.into(),
+            approvals: approvals.into(),
+            span,
+        }
+    }
+
+    /// Creates a repeated owner group error.
+    pub fn repeated_owner_group(
+        owners: impl Into<String>,
+        lines: impl Into<String>,
+        suggestion: impl Into<String>,
+        span: Span,
+    ) -> Self {
+        Self::RepeatedOwnerGroup {
+            line: span.line,
+            owners: owners.into(),
+            lines: lines.into(),
+            suggestion: suggestion.into(),
+            span,
+        }
+    }
+
+    /// Creates a complexity budget exceeded error.
+    pub fn complexity_budget_exceeded(
+        scope: impl Into<String>,
+        count: usize,
+        unit: impl Into<String>,
+        budget: usize,
+        span: Span,
+    ) -> Self {
+        Self::ComplexityBudgetExceeded {
+            line: span.line,
+            scope: scope.into(),
+            count,
+            unit: unit.into(),
+            budget,
+            span,
+        }
+    }
+
+    /// Creates an ownership manifest mismatch error.
+    pub fn ownership_manifest_mismatch(
+        path: impl Into<String>,
+        reason: impl Into<String>,
+        span: Span,
+    ) -> Self {
+        Self::OwnershipManifestMismatch {
+            line: span.line,
+            path: path.into(),
+            reason: reason.into(),
+            span,
+        }
+    }
+
+    /// Creates an owner not checked error.
+    pub fn owner_not_checked(
+        owner: impl Into<String>,
+        reason: impl Into<String>,
+        span: Span,
+    ) -> Self {
+        Self::OwnerNotChecked {
+            line: span.line,
+            owner: owner.into(),
+            reason: reason.into(),
+            span,
+        }
+    }
+
+    /// Creates a non-UTF-8 path error.
+    pub fn non_utf8_path(path: impl Into<String>, span: Span) -> Self {
+        Self::NonUtf8Path {
+            line: span.line,
+            path: path.into(),
             span,
         }
     }
 
-    /// Creates an invalid pattern syntax error.
-    pub fn invalid_pattern_syntax(
-        pattern: impl Into<String>,
-        reason: impl Into<String>,
+    /// Creates a CODEOWNERS not owned error.
+    pub fn codeowners_not_owned(path: impl Into<String>, span: Span) -> Self {
+        Self::CodeownersNotOwned {
+            line: span.line,
+            path: path.into(),
+            span,
+        }
+    }
+
+    /// Creates a CODEOWNERS not owned by an admin team error.
+    pub fn codeowners_not_admin_owned(
+        path: impl Into<String>,
+        owners: impl Into<String>,
+        admin_teams: impl Into<String>,
         span: Span,
     ) -> Self {
-        Self::InvalidPatternSyntax {
+        Self::CodeownersNotAdminOwned {
             line: span.line,
-            pattern: pattern.into(),
-            reason: reason.into(),
+            path: path.into(),
+            owners: owners.into(),
+            admin_teams: admin_teams.into(),
             span,
         }
     }
 
-    /// Creates an unsupported pattern syntax error.
-    pub fn unsupported_pattern_syntax(
-        pattern: impl Into<String>,
-        reason: impl Into<String>,
+    /// Creates a finding of a custom check.
+    ///
+    /// Checks usually call
+    /// [`CheckDescriptor::finding`](super::checks::CheckDescriptor::finding)
+    /// instead, which fills in their name and default severity.
+    pub fn custom_check_finding(
+        check: impl Into<String>,
+        message: impl Into<String>,
+        severity: Severity,
         span: Span,
     ) -> Self {
-        Self::UnsupportedPatternSyntax {
+        Self::CustomCheckFinding {
             line: span.line,
-            pattern: pattern.into(),
-            reason: reason.into(),
+            check: check.into(),
+            message: message.into(),
+            severity,
             span,
         }
     }
 
-    /// Creates a duplicate pattern error.
-    pub fn duplicate_pattern(pattern: impl Into<String>, span: Span, first_line: usize) -> Self {
-        Self::DuplicatePattern {
+    /// Creates a pinned owners changed error.
+    pub fn pinned_owners_changed(
+        path: impl Into<String>,
+        pinned: impl Into<String>,
+        actual: impl Into<String>,
+        span: Span,
+    ) -> Self {
+        Self::PinnedOwnersChanged {
             line: span.line,
-            pattern: pattern.into(),
-            first_line,
+            path: path.into(),
+            pinned: pinned.into(),
+            actual: actual.into(),
             span,
         }
     }
 
-    /// Creates a pattern not matching error.
-    pub fn pattern_not_matching(pattern: impl Into<String>, span: Span) -> Self {
-        Self::PatternNotMatching {
+    /// Creates a redundant parent team error.
+    pub fn redundant_parent_team(
+        parent: impl Into<String>,
+        child: impl Into<String>,
+        span: Span,
+    ) -> Self {
+        Self::RedundantParentTeam {
             line: span.line,
-            pattern: pattern.into(),
+            parent: parent.into(),
+            child: child.into(),
             span,
         }
     }
 
-    /// Creates an owner not found error.
-    pub fn owner_not_found(
-        owner: impl Into<String>,
+    /// Creates an invalid escalation annotation error.
+    pub fn invalid_escalation(
+        pattern: impl Into<String>,
+        value: impl Into<String>,
         reason: impl Into<String>,
         span: Span,
     ) -> Self {
-        Self::OwnerNotFound {
+        Self::InvalidEscalation {
             line: span.line,
-            owner: owner.into(),
+            pattern: pattern.into(),
+            value: value.into(),
             reason: reason.into(),
             span,
         }
     }
 
-    /// Creates an insufficient authorization error.
-    pub fn insufficient_authorization(
-        owner: impl Into<String>,
+    /// Creates an invalid annotation error.
+    pub fn invalid_annotation(
+        annotation: impl Into<String>,
         reason: impl Into<String>,
         span: Span,
     ) -> Self {
-        Self::InsufficientAuthorization {
+        Self::InvalidAnnotation {
             line: span.line,
-            owner: owner.into(),
+            annotation: annotation.into(),
             reason: reason.into(),
             span,
         }
     }
 
-    /// Creates a file not owned error.
-    ///
-    /// The span should point to the end of the CODEOWNERS file (EOF position).
-    pub fn file_not_owned(path: impl Into<String>, span: Span) -> Self {
-        Self::FileNotOwned {
+    /// Creates a symbolic link path error.
+    pub fn symlink_path(path: impl Into<String>, span: Span) -> Self {
+        Self::SymlinkPath {
             line: span.line,
             path: path.into(),
             span,
         }
     }
 
-    /// Creates a pattern shadowed error.
-    pub fn pattern_shadowed(
-        pattern: impl Into<String>,
-        span: Span,
-        shadowing_pattern: impl Into<String>,
-        shadowing_line: usize,
-    ) -> Self {
-        Self::PatternShadowed {
+    /// Creates an incomplete listing error.
+    pub fn incomplete_listing(reason: impl Into<String>, span: Span) -> Self {
+        Self::IncompleteListing {
             line: span.line,
-            pattern: pattern.into(),
-            shadowing_line,
-            shadowing_pattern: shadowing_pattern.into(),
+            reason: reason.into(),
             span,
         }
     }
 
-    /// Creates an owner must be team error.
-    pub fn owner_must_be_team(owner: impl Into<String>, span: Span) -> Self {
-        Self::OwnerMustBeTeam {
-            line: span.line,
-            owner: owner.into(),
-            span,
+    /// Returns the stable code identifying this kind of error, e.g. `CO004`.
+    ///
+    /// Unlike messages, codes do not change between releases, so CI
+    /// policies and suppressions can rely on them.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ValidationError::InvalidOwnerFormat { .. } => "CO001",
+            ValidationError::InvalidPatternSyntax { .. } => "CO002",
+            ValidationError::UnsupportedPatternSyntax { .. } => "CO003",
+            ValidationError::DuplicatePattern { .. } => "CO004",
+            ValidationError::DuplicateOwner { .. } => "CO005",
+            ValidationError::InconsistentOwnerCase { .. } => "CO006",
+            ValidationError::PatternNotMatching { .. } => "CO007",
+            ValidationError::OwnerNotFound { .. } => "CO008",
+            ValidationError::InsufficientAuthorization { .. } => "CO009",
+            ValidationError::OwnerTeamEmpty { .. } => "CO010",
+            ValidationError::OwnerTeamTooSmall { .. } => "CO011",
+            ValidationError::AmbiguousQuestionMark { .. } => "CO012",
+            ValidationError::FileNotOwned { .. } => "CO013",
+            ValidationError::PatternShadowed { .. } => "CO014",
+            ValidationError::PatternCaseCollision { .. } => "CO015",
+            ValidationError::OwnerMustBeTeam { .. } => "CO016",
+            ValidationError::UntrustedEmailDomain { .. } => "CO017",
+            ValidationError::EmailOwnerNotAllowed { .. } => "CO018",
+            ValidationError::GeneratedRegionModified { .. } => "CO019",
+            ValidationError::IdpGroupMismatch { .. } => "CO020",
+            ValidationError::OwnershipReviewDue { .. } => "CO021",
+            ValidationError::ExtensionRuleOverridden { .. } => "CO022",
+            ValidationError::UnanchoredDirectory { .. } => "CO023",
+            ValidationError::InvalidSectionApprovals { .. } => "CO024",
+            ValidationError::RepeatedOwnerGroup { .. } => "CO025",
+            ValidationError::ComplexityBudgetExceeded { .. } => "CO026",
+            ValidationError::OwnershipManifestMismatch { .. } => "CO027",
+            ValidationError::OwnerNotChecked { .. } => "CO028",
+            ValidationError::NonUtf8Path { .. } => "CO029",
+            ValidationError::CodeownersNotOwned { .. } => "CO030",
+            ValidationError::CodeownersNotAdminOwned { .. } => "CO031",
+            ValidationError::CustomCheckFinding { .. } => "CO032",
+            ValidationError::PinnedOwnersChanged { .. } => "CO033",
+            ValidationError::RedundantParentTeam { .. } => "CO034",
+            ValidationError::InvalidEscalation { .. } => "CO035",
+            ValidationError::InvalidAnnotation { .. } => "CO036",
+            ValidationError::SymlinkPath { .. } => "CO037",
+            ValidationError::IncompleteListing { .. } => "CO038",
         }
     }
 
@@ -283,12 +1338,40 @@ impl ValidationError {
             | ValidationError::InvalidPatternSyntax { span, .. }
             | ValidationError::UnsupportedPatternSyntax { span, .. }
             | ValidationError::DuplicatePattern { span, .. }
+            | ValidationError::DuplicateOwner { span, .. }
+            | ValidationError::InconsistentOwnerCase { span, .. }
             | ValidationError::PatternNotMatching { span, .. }
+            | ValidationError::AmbiguousQuestionMark { span, .. }
             | ValidationError::OwnerNotFound { span, .. }
             | ValidationError::InsufficientAuthorization { span, .. }
+            | ValidationError::OwnerTeamEmpty { span, .. }
+            | ValidationError::OwnerTeamTooSmall { span, .. }
             | ValidationError::FileNotOwned { span, .. }
             | ValidationError::PatternShadowed { span, .. }
-            | ValidationError::OwnerMustBeTeam { span, .. } => span,
+            | ValidationError::PatternCaseCollision { span, .. }
+            | ValidationError::GeneratedRegionModified { span, .. }
+            | ValidationError::IdpGroupMismatch { span, .. }
+            | ValidationError::OwnershipReviewDue { span, .. }
+            | ValidationError::ExtensionRuleOverridden { span, .. }
+            | ValidationError::UnanchoredDirectory { span, .. }
+            | ValidationError::InvalidSectionApprovals { span, .. }
+            | ValidationError::RepeatedOwnerGroup { span, .. }
+            | ValidationError::ComplexityBudgetExceeded { span, .. }
+            | ValidationError::OwnershipManifestMismatch { span, .. }
+            | ValidationError::OwnerMustBeTeam { span, .. }
+            | ValidationError::UntrustedEmailDomain { span, .. }
+            | ValidationError::EmailOwnerNotAllowed { span, .. }
+            | ValidationError::OwnerNotChecked { span, .. }
+            | ValidationError::NonUtf8Path { span, .. }
+            | ValidationError::CodeownersNotOwned { span, .. }
+            | ValidationError::CodeownersNotAdminOwned { span, .. }
+            | ValidationError::CustomCheckFinding { span, .. }
+            | ValidationError::PinnedOwnersChanged { span, .. }
+            | ValidationError::RedundantParentTeam { span, .. }
+            | ValidationError::InvalidEscalation { span, .. }
+            | ValidationError::InvalidAnnotation { span, .. }
+            | ValidationError::SymlinkPath { span, .. }
+            | ValidationError::IncompleteListing { span, .. } => span,
         }
     }
 
@@ -299,12 +1382,185 @@ impl ValidationError {
             | ValidationError::InvalidPatternSyntax { line, .. }
             | ValidationError::UnsupportedPatternSyntax { line, .. }
             | ValidationError::DuplicatePattern { line, .. }
+            | ValidationError::DuplicateOwner { line, .. }
+            | ValidationError::InconsistentOwnerCase { line, .. }
             | ValidationError::PatternNotMatching { line, .. }
+            | ValidationError::AmbiguousQuestionMark { line, .. }
             | ValidationError::OwnerNotFound { line, .. }
             | ValidationError::InsufficientAuthorization { line, .. }
+            | ValidationError::OwnerTeamEmpty { line, .. }
+            | ValidationError::OwnerTeamTooSmall { line, .. }
             | ValidationError::FileNotOwned { line, .. }
             | ValidationError::PatternShadowed { line, .. }
-            | ValidationError::OwnerMustBeTeam { line, .. } => *line,
+            | ValidationError::PatternCaseCollision { line, .. }
+            | ValidationError::GeneratedRegionModified { line, .. }
+            | ValidationError::IdpGroupMismatch { line, .. }
+            | ValidationError::OwnershipReviewDue { line, .. }
+            | ValidationError::ExtensionRuleOverridden { line, .. }
+            | ValidationError::UnanchoredDirectory { line, .. }
+            | ValidationError::InvalidSectionApprovals { line, .. }
+            | ValidationError::RepeatedOwnerGroup { line, .. }
+            | ValidationError::ComplexityBudgetExceeded { line, .. }
+            | ValidationError::OwnershipManifestMismatch { line, .. }
+            | ValidationError::OwnerMustBeTeam { line, .. }
+            | ValidationError::UntrustedEmailDomain { line, .. }
+            | ValidationError::EmailOwnerNotAllowed { line, .. }
+            | ValidationError::OwnerNotChecked { line, .. }
+            | ValidationError::NonUtf8Path { line, .. }
+            | ValidationError::CodeownersNotOwned { line, .. }
+            | ValidationError::CodeownersNotAdminOwned { line, .. }
+            | ValidationError::CustomCheckFinding { line, .. }
+            | ValidationError::PinnedOwnersChanged { line, .. }
+            | ValidationError::RedundantParentTeam { line, .. }
+            | ValidationError::InvalidEscalation { line, .. }
+            | ValidationError::InvalidAnnotation { line, .. }
+            | ValidationError::SymlinkPath { line, .. }
+            | ValidationError::IncompleteListing { line, .. } => *line,
+        }
+    }
+
+    /// Returns what the error is about, independent of where it is: the
+    /// owner, pattern, path, section or generator it names.
+    pub fn subject(&self) -> &str {
+        match self {
+            ValidationError::InvalidOwnerFormat { owner, .. }
+            | ValidationError::DuplicateOwner { owner, .. }
+            | ValidationError::InconsistentOwnerCase { owner, .. }
+            | ValidationError::OwnerNotFound { owner, .. }
+            | ValidationError::InsufficientAuthorization { owner, .. }
+            | ValidationError::OwnerTeamEmpty { owner, .. }
+            | ValidationError::OwnerTeamTooSmall { owner, .. }
+            | ValidationError::IdpGroupMismatch { owner, .. }
+            | ValidationError::OwnerMustBeTeam { owner, .. }
+            | ValidationError::UntrustedEmailDomain { owner, .. }
+            | ValidationError::EmailOwnerNotAllowed { owner, .. }
+            | ValidationError::OwnerNotChecked { owner, .. } => owner,
+            ValidationError::RedundantParentTeam { parent, .. } => parent,
+            ValidationError::InvalidPatternSyntax { pattern, .. }
+            | ValidationError::UnsupportedPatternSyntax { pattern, .. }
+            | ValidationError::DuplicatePattern { pattern, .. }
+            | ValidationError::PatternNotMatching { pattern, .. }
+            | ValidationError::AmbiguousQuestionMark { pattern, .. }
+            | ValidationError::PatternShadowed { pattern, .. }
+            | ValidationError::PatternCaseCollision { pattern, .. }
+            | ValidationError::OwnershipReviewDue { pattern, .. }
+            | ValidationError::ExtensionRuleOverridden { pattern, .. }
+            | ValidationError::UnanchoredDirectory { pattern, .. }
+            | ValidationError::InvalidEscalation { pattern, .. } => pattern,
+            ValidationError::FileNotOwned { path, .. }
+            | ValidationError::OwnershipManifestMismatch { path, .. }
+            | ValidationError::NonUtf8Path { path, .. }
+            | ValidationError::SymlinkPath { path, .. }
+            | ValidationError::CodeownersNotOwned { path, .. }
+            | ValidationError::CodeownersNotAdminOwned { path, .. }
+            | ValidationError::PinnedOwnersChanged { path, .. } => path,
+            ValidationError::GeneratedRegionModified { generator, .. } => generator,
+            ValidationError::InvalidSectionApprovals { section, .. } => section,
+            ValidationError::RepeatedOwnerGroup { owners, .. } => owners,
+            ValidationError::ComplexityBudgetExceeded { scope, .. } => scope,
+            ValidationError::CustomCheckFinding { message, .. } => message,
+            ValidationError::InvalidAnnotation { annotation, .. } => annotation,
+            ValidationError::IncompleteListing { reason, .. } => reason,
+        }
+    }
+
+    /// Returns the pattern of the rule the error is about, if it names one.
+    pub fn pattern(&self) -> Option<&str> {
+        match self {
+            ValidationError::InvalidPatternSyntax { pattern, .. }
+            | ValidationError::UnsupportedPatternSyntax { pattern, .. }
+            | ValidationError::DuplicatePattern { pattern, .. }
+            | ValidationError::PatternNotMatching { pattern, .. }
+            | ValidationError::AmbiguousQuestionMark { pattern, .. }
+            | ValidationError::PatternShadowed { pattern, .. }
+            | ValidationError::PatternCaseCollision { pattern, .. }
+            | ValidationError::OwnershipReviewDue { pattern, .. }
+            | ValidationError::ExtensionRuleOverridden { pattern, .. }
+            | ValidationError::UnanchoredDirectory { pattern, .. }
+            | ValidationError::InvalidEscalation { pattern, .. } => Some(pattern),
+            _ => None,
+        }
+    }
+
+    /// Returns the owner the error is about, if it is about an owner of the
+    /// rule on its line.
+    pub fn owner(&self) -> Option<&str> {
+        match self {
+            ValidationError::InvalidOwnerFormat { owner, .. }
+            | ValidationError::DuplicateOwner { owner, .. }
+            | ValidationError::InconsistentOwnerCase { owner, .. }
+            | ValidationError::OwnerNotFound { owner, .. }
+            | ValidationError::InsufficientAuthorization { owner, .. }
+            | ValidationError::OwnerTeamEmpty { owner, .. }
+            | ValidationError::OwnerTeamTooSmall { owner, .. }
+            | ValidationError::IdpGroupMismatch { owner, .. }
+            | ValidationError::OwnerMustBeTeam { owner, .. }
+            | ValidationError::UntrustedEmailDomain { owner, .. }
+            | ValidationError::EmailOwnerNotAllowed { owner, .. }
+            | ValidationError::OwnerNotChecked { owner, .. } => Some(owner),
+            ValidationError::RedundantParentTeam { parent, .. } => Some(parent),
+            _ => None,
+        }
+    }
+
+    /// Returns a machine-applicable fix for this error, if one is known.
+    pub fn fix(&self) -> Option<Fix> {
+        match self {
+            ValidationError::OwnerMustBeTeam {
+                suggested_team: Some(team),
+                span,
+                ..
+            } => Some(Fix::replace(*span, team)),
+            ValidationError::DuplicateOwner { removal, .. } => Some(Fix::replace(*removal, "")),
+            ValidationError::DuplicatePattern {
+                removal: Some(removal),
+                ..
+            }
+            | ValidationError::PatternNotMatching {
+                removal: Some(removal),
+                ..
+            } => Some(Fix::replace(*removal, "")),
+            ValidationError::InconsistentOwnerCase {
+                canonical, span, ..
+            } => Some(Fix::replace(*span, canonical)),
+            ValidationError::UnsupportedPatternSyntax {
+                suggestion: Some(suggestion),
+                span,
+                ..
+            } => Some(Fix::replace(*span, suggestion)),
+            _ => None,
+        }
+    }
+
+    /// Returns alternative fixes for an error whose intent is ambiguous.
+    ///
+    /// Unlike [`fix`](Self::fix), these are never applied automatically;
+    /// editors offer each one as a separate action.
+    pub fn candidate_fixes(&self) -> Vec<Fix> {
+        match self {
+            ValidationError::InvalidOwnerFormat { owner, span, .. } if !owner.starts_with('@') => {
+                // A user or team written without its '@', e.g. `org/team`
+                let prefixed = format!("@{}", owner);
+                let candidate = match classify_owner(&prefixed) {
+                    OwnerKind::User(name) => Owner::user(name, *span),
+                    OwnerKind::Team { org, team } => Owner::team(org, team, *span),
+                    _ => return Vec::new(),
+                };
+                if validate_owner_syntax(&candidate).is_some() {
+                    return Vec::new();
+                }
+                vec![Fix::replace(*span, prefixed)]
+            }
+            ValidationError::UnanchoredDirectory {
+                anchored,
+                recursive,
+                span,
+                ..
+            } => vec![
+                Fix::replace(*span, anchored),
+                Fix::replace(*span, recursive),
+            ],
+            _ => Vec::new(),
         }
     }
 
@@ -315,12 +1571,41 @@ impl ValidationError {
             ValidationError::InvalidPatternSyntax { .. } => Severity::Error,
             ValidationError::UnsupportedPatternSyntax { .. } => Severity::Warning,
             ValidationError::DuplicatePattern { .. } => Severity::Warning,
+            ValidationError::DuplicateOwner { .. } => Severity::Warning,
+            ValidationError::InconsistentOwnerCase { .. } => Severity::Warning,
             ValidationError::PatternNotMatching { .. } => Severity::Warning,
+            ValidationError::AmbiguousQuestionMark { .. } => Severity::Warning,
             ValidationError::OwnerNotFound { .. } => Severity::Error,
             ValidationError::InsufficientAuthorization { .. } => Severity::Error,
+            ValidationError::OwnerTeamEmpty { .. } => Severity::Error,
+            ValidationError::OwnerTeamTooSmall { .. } => Severity::Warning,
             ValidationError::FileNotOwned { .. } => Severity::Warning,
             ValidationError::PatternShadowed { .. } => Severity::Warning,
+            ValidationError::PatternCaseCollision { .. } => Severity::Warning,
+            ValidationError::GeneratedRegionModified { .. } => Severity::Error,
+            ValidationError::IdpGroupMismatch { .. } => Severity::Error,
+            ValidationError::OwnershipReviewDue { expired: true, .. } => Severity::Error,
+            ValidationError::OwnershipReviewDue { expired: false, .. } => Severity::Warning,
+            ValidationError::ExtensionRuleOverridden { .. } => Severity::Warning,
+            ValidationError::UnanchoredDirectory { .. } => Severity::Warning,
+            ValidationError::InvalidSectionApprovals { .. } => Severity::Error,
+            ValidationError::RepeatedOwnerGroup { .. } => Severity::Warning,
+            ValidationError::ComplexityBudgetExceeded { .. } => Severity::Warning,
+            ValidationError::OwnershipManifestMismatch { .. } => Severity::Error,
             ValidationError::OwnerMustBeTeam { .. } => Severity::Error,
+            ValidationError::UntrustedEmailDomain { .. } => Severity::Warning,
+            ValidationError::EmailOwnerNotAllowed { .. } => Severity::Error,
+            ValidationError::OwnerNotChecked { .. } => Severity::Warning,
+            ValidationError::NonUtf8Path { .. } => Severity::Warning,
+            ValidationError::CodeownersNotOwned { .. } => Severity::Error,
+            ValidationError::CodeownersNotAdminOwned { .. } => Severity::Warning,
+            ValidationError::CustomCheckFinding { severity, .. } => *severity,
+            ValidationError::PinnedOwnersChanged { .. } => Severity::Error,
+            ValidationError::RedundantParentTeam { .. } => Severity::Warning,
+            ValidationError::InvalidEscalation { .. } => Severity::Error,
+            ValidationError::InvalidAnnotation { .. } => Severity::Error,
+            ValidationError::SymlinkPath { .. } => Severity::Warning,
+            ValidationError::IncompleteListing { .. } => Severity::Warning,
         }
     }
 }
@@ -330,6 +1615,15 @@ impl ValidationError {
 pub struct ValidationResult {
     /// All validation errors found.
     pub errors: Vec<ValidationError>,
+    /// Why the check could not run to completion, e.g. an unreachable API.
+    ///
+    /// Unlike findings, this is a problem with the check itself, not with
+    /// the CODEOWNERS file; findings may be incomplete.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub internal_error: Option<String>,
+    /// Severities replacing the default severity of findings, by error code.
+    #[serde(skip)]
+    pub severity_overrides: HashMap<String, Severity>,
 }
 
 impl ValidationResult {
@@ -340,7 +1634,15 @@ impl ValidationResult {
 
     /// Creates a validation result with the given errors.
     pub fn with_errors(errors: Vec<ValidationError>) -> Self {
-        Self { errors }
+        Self {
+            errors,
+            ..Self::default()
+        }
+    }
+
+    /// Records why the check could not run to completion.
+    pub fn set_internal_error(&mut self, error: impl Into<String>) {
+        self.internal_error = Some(error.into());
     }
 
     /// Returns true if validation passed with no errors.
@@ -357,14 +1659,33 @@ impl ValidationResult {
     pub fn errors_only(&self) -> impl Iterator<Item = &ValidationError> {
         self.errors
             .iter()
-            .filter(|e| e.severity() == Severity::Error)
+            .filter(|e| self.severity_of(e) == Severity::Error)
     }
 
     /// Returns only warnings.
     pub fn warnings_only(&self) -> impl Iterator<Item = &ValidationError> {
         self.errors
             .iter()
-            .filter(|e| e.severity() == Severity::Warning)
+            .filter(|e| self.severity_of(e) == Severity::Warning)
+    }
+
+    /// Returns the severity of one of this result's errors, honoring
+    /// [severity overrides](Self::override_severities).
+    pub fn severity_of(&self, error: &ValidationError) -> Severity {
+        self.severity_overrides
+            .get(error.code())
+            .copied()
+            .unwrap_or_else(|| error.severity())
+    }
+
+    /// Replaces the default severity of errors whose code is a key of
+    /// `overrides`.
+    pub fn override_severities(&mut self, overrides: &HashMap<String, Severity>) {
+        self.severity_overrides.extend(
+            overrides
+                .iter()
+                .map(|(code, severity)| (code.clone(), *severity)),
+        );
     }
 
     /// Adds an error to the result.
@@ -372,15 +1693,34 @@ impl ValidationResult {
         self.errors.push(error);
     }
 
+    /// Returns the fixes available for the errors in this result.
+    pub fn fixes(&self) -> impl Iterator<Item = Fix> + '_ {
+        self.errors.iter().filter_map(ValidationError::fix)
+    }
+
+    /// Removes the errors whose code is in `codes`.
+    pub fn suppress(&mut self, codes: &HashSet<String>) {
+        if !codes.is_empty() {
+            self.errors.retain(|error| !codes.contains(error.code()));
+        }
+    }
+
     /// Merges another validation result into this one.
+    ///
+    /// The first internal error is kept.
     pub fn merge(&mut self, other: ValidationResult) {
         self.errors.extend(other.errors);
+        self.severity_overrides.extend(other.severity_overrides);
+        if self.internal_error.is_none() {
+            self.internal_error = other.internal_error;
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::validate::apply_fixes;
 
     fn test_span() -> Span {
         Span::new(10, 2, 5, 15)
@@ -417,7 +1757,7 @@ mod tests {
     fn validation_error_unsupported_pattern_syntax() {
         let error = ValidationError::unsupported_pattern_syntax(
             "!ignore",
-            "negation not supported",
+            UnsupportedSyntax::Negation,
             test_span(),
         );
         assert!(matches!(
@@ -425,6 +1765,23 @@ mod tests {
             ValidationError::UnsupportedPatternSyntax { line: 2, .. }
         ));
         assert_eq!(error.severity(), Severity::Warning);
+        assert!(error.to_string().contains("unsupported syntax (negation)"));
+        assert!(error.fix().is_none());
+    }
+
+    #[test]
+    fn validation_error_unsupported_pattern_syntax_fix() {
+        let error = ValidationError::unsupported_pattern_syntax_with_suggestion(
+            "docs\\",
+            UnsupportedSyntax::TrailingBackslash,
+            "docs/",
+            test_span(),
+        );
+        assert_eq!(error.fix(), Some(Fix::replace(test_span(), "docs/")));
+
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(json["syntax"], "trailing-backslash");
+        assert_eq!(json["suggestion"], "docs/");
     }
 
     #[test]
@@ -443,6 +1800,39 @@ mod tests {
         assert!(error.to_string().contains("*.rs"));
     }
 
+    #[test]
+    fn validation_error_duplicate_pattern_fix() {
+        let source = "*.rs @a\n*.md @b\n*.rs @c\n";
+        let error = ValidationError::duplicate_pattern_with_removal(
+            "*.rs",
+            Span::new(16, 3, 1, 4),
+            1,
+            Span::new(0, 1, 1, 8),
+        );
+        let fix = error.fix().unwrap();
+        assert_eq!(apply_fixes(source, &[fix]), "*.md @b\n*.rs @c\n");
+
+        assert_eq!(
+            ValidationError::duplicate_pattern("*.rs", test_span(), 1).fix(),
+            None
+        );
+    }
+
+    #[test]
+    fn validation_error_invalid_owner_candidate_fix() {
+        let error = ValidationError::invalid_owner_format("org/team", "reason", test_span());
+        assert_eq!(
+            error.candidate_fixes(),
+            [Fix::replace(test_span(), "@org/team")]
+        );
+        assert_eq!(error.fix(), None);
+
+        for owner in ["@-bad", "not an owner", "bad--name"] {
+            let error = ValidationError::invalid_owner_format(owner, "reason", test_span());
+            assert!(error.candidate_fixes().is_empty(), "{owner}");
+        }
+    }
+
     #[test]
     fn validation_error_pattern_not_matching() {
         let error = ValidationError::pattern_not_matching("/nonexistent/", test_span());
@@ -451,6 +1841,26 @@ mod tests {
             ValidationError::PatternNotMatching { line: 2, .. }
         ));
         assert_eq!(error.severity(), Severity::Warning);
+        assert_eq!(error.fix(), None);
+
+        let removal = Span::new(0, 2, 1, 20);
+        let error = ValidationError::pattern_not_matching_with_removal(
+            "/nonexistent/",
+            test_span(),
+            removal,
+        );
+        assert_eq!(error.fix(), Some(Fix::replace(removal, "")));
+    }
+
+    #[test]
+    fn validation_error_inconsistent_owner_case() {
+        let error = ValidationError::inconsistent_owner_case("@Alice", test_span(), "@alice", 1);
+        assert_eq!(
+            error.to_string(),
+            "line 2: owner '@Alice' is spelled '@alice' on line 1; owners are case-insensitive, so use one spelling"
+        );
+        assert_eq!(error.severity(), Severity::Warning);
+        assert_eq!(error.fix(), Some(Fix::replace(test_span(), "@alice")));
     }
 
     #[test]
@@ -504,6 +1914,18 @@ mod tests {
         assert_eq!(error.severity(), Severity::Warning);
     }
 
+    #[test]
+    fn validation_error_duplicate_owner() {
+        let removal = Span::new(9, 2, 4, 16);
+        let error = ValidationError::duplicate_owner("@user", test_span(), removal);
+        assert!(matches!(
+            error,
+            ValidationError::DuplicateOwner { line: 2, .. }
+        ));
+        assert_eq!(error.severity(), Severity::Warning);
+        assert_eq!(error.fix(), Some(Fix::replace(removal, "")));
+    }
+
     #[test]
     fn validation_error_owner_must_be_team() {
         let error = ValidationError::owner_must_be_team("@user", test_span());
@@ -512,6 +1934,193 @@ mod tests {
             ValidationError::OwnerMustBeTeam { line: 2, .. }
         ));
         assert_eq!(error.severity(), Severity::Error);
+        assert!(error.fix().is_none());
+    }
+
+    #[test]
+    fn validation_error_owner_must_be_team_fix() {
+        let error =
+            ValidationError::owner_must_be_team_with_suggestion("@user", "@org/team", test_span());
+        assert_eq!(error.fix(), Some(Fix::replace(test_span(), "@org/team")));
+
+        let result = ValidationResult::with_errors(vec![error]);
+        assert_eq!(result.fixes().count(), 1);
+    }
+
+    #[test]
+    fn validation_error_untrusted_email_domain() {
+        let error =
+            ValidationError::untrusted_email_domain("dev@other.com", "other.com", test_span());
+        assert!(matches!(
+            error,
+            ValidationError::UntrustedEmailDomain { line: 2, .. }
+        ));
+        assert_eq!(error.severity(), Severity::Warning);
+        assert!(error.to_string().contains("trusted domain"));
+    }
+
+    #[test]
+    fn validation_error_email_owner_not_allowed() {
+        let error = ValidationError::email_owner_not_allowed("dev@example.com", test_span());
+        assert!(matches!(
+            error,
+            ValidationError::EmailOwnerNotAllowed { line: 2, .. }
+        ));
+        assert_eq!(error.severity(), Severity::Error);
+        assert!(error.to_string().contains("not allowed"));
+    }
+
+    #[test]
+    fn validation_error_owner_not_checked() {
+        let error = ValidationError::owner_not_checked(
+            "@user",
+            "API request budget of 10 exhausted",
+            test_span(),
+        );
+        assert!(matches!(
+            error,
+            ValidationError::OwnerNotChecked { line: 2, .. }
+        ));
+        assert_eq!(error.severity(), Severity::Warning);
+        assert!(error.to_string().contains("not checked"));
+    }
+
+    #[test]
+    fn validation_error_non_utf8_path() {
+        let error = ValidationError::non_utf8_path("caf\u{FFFD}.txt", test_span());
+        assert_eq!(error.code(), "CO029");
+        assert_eq!(error.severity(), Severity::Warning);
+        assert_eq!(error.subject(), "caf\u{FFFD}.txt");
+        assert_eq!(error.pattern(), None);
+        assert_eq!(error.owner(), None);
+        assert!(error.to_string().contains("not valid UTF-8"));
+    }
+
+    #[test]
+    fn validation_error_codeowners_ownership() {
+        let error = ValidationError::codeowners_not_owned(".github/CODEOWNERS", test_span());
+        assert_eq!(error.code(), "CO030");
+        assert_eq!(error.severity(), Severity::Error);
+        assert_eq!(error.subject(), ".github/CODEOWNERS");
+
+        let error = ValidationError::codeowners_not_admin_owned(
+            "CODEOWNERS",
+            "@alice",
+            "@org/platform",
+            test_span(),
+        );
+        assert_eq!(error.code(), "CO031");
+        assert_eq!(error.severity(), Severity::Warning);
+        assert_eq!(
+            error.to_string(),
+            "line 2: the CODEOWNERS file 'CODEOWNERS' is owned by @alice, but by none of the admin teams @org/platform"
+        );
+    }
+
+    #[test]
+    fn validation_error_custom_check_finding() {
+        let error = ValidationError::custom_check_finding(
+            "no-bots",
+            "bot owners are not allowed",
+            Severity::Warning,
+            test_span(),
+        );
+        assert_eq!(error.code(), "CO032");
+        assert_eq!(error.severity(), Severity::Warning);
+        assert_eq!(error.subject(), "bot owners are not allowed");
+        assert_eq!(error.to_string(), "line 2: bot owners are not allowed");
+    }
+
+    #[test]
+    fn validation_error_pinned_owners_changed() {
+        let error = ValidationError::pinned_owners_changed(
+            ".github/workflows/deploy.yml",
+            "@org/security",
+            "@alice",
+            test_span(),
+        );
+        assert_eq!(error.code(), "CO033");
+        assert_eq!(error.severity(), Severity::Error);
+        assert_eq!(error.subject(), ".github/workflows/deploy.yml");
+        assert_eq!(
+            error.to_string(),
+            "line 2: owners of protected path '.github/workflows/deploy.yml' changed from \
+             @org/security to @alice without updating the owners lockfile"
+        );
+    }
+
+    #[test]
+    fn validation_error_redundant_parent_team() {
+        let error =
+            ValidationError::redundant_parent_team("@org/eng", "@org/eng-core", test_span());
+        assert_eq!(error.code(), "CO034");
+        assert_eq!(error.severity(), Severity::Warning);
+        assert_eq!(error.subject(), "@org/eng");
+        assert_eq!(
+            error.to_string(),
+            "line 2: team '@org/eng' is a parent of '@org/eng-core', which already owns the \
+             same paths, so requesting both reviews is redundant"
+        );
+    }
+
+    #[test]
+    fn validation_error_invalid_escalation() {
+        let error = ValidationError::invalid_escalation(
+            "/payments/",
+            "payments",
+            "expected 'provider:policy'",
+            test_span(),
+        );
+        assert_eq!(error.code(), "CO035");
+        assert_eq!(error.severity(), Severity::Error);
+        assert_eq!(error.subject(), "/payments/");
+        assert_eq!(
+            error.to_string(),
+            "line 2: rule '/payments/' has an invalid escalation annotation 'payments': \
+             expected 'provider:policy'"
+        );
+    }
+
+    #[test]
+    fn validation_error_invalid_annotation() {
+        let error = ValidationError::invalid_annotation(
+            "codeowners: no-auto-requst",
+            "unknown directive 'no-auto-requst'",
+            test_span(),
+        );
+        assert_eq!(error.code(), "CO036");
+        assert_eq!(error.severity(), Severity::Error);
+        assert_eq!(error.subject(), "codeowners: no-auto-requst");
+        assert_eq!(
+            error.to_string(),
+            "line 2: invalid annotation 'codeowners: no-auto-requst': \
+             unknown directive 'no-auto-requst'"
+        );
+    }
+
+    #[test]
+    fn validation_error_symlink_path() {
+        let error = ValidationError::symlink_path("docs/latest", test_span());
+        assert_eq!(error.code(), "CO037");
+        assert_eq!(error.severity(), Severity::Warning);
+        assert_eq!(error.subject(), "docs/latest");
+        assert_eq!(
+            error.to_string(),
+            "line 2: file 'docs/latest' is a symbolic link, so its ownership was not checked"
+        );
+    }
+
+    #[test]
+    fn validation_error_incomplete_listing() {
+        let error = ValidationError::incomplete_listing("took longer than 5s", test_span());
+        assert_eq!(error.code(), "CO038");
+        assert_eq!(error.severity(), Severity::Warning);
+        assert_eq!(error.subject(), "took longer than 5s");
+        assert_eq!(
+            error.to_string(),
+            "line 2: the repository listing is incomplete because the walk took longer \
+             than 5s, so not every file was checked"
+        );
     }
 
     #[test]
@@ -519,6 +2128,62 @@ mod tests {
         assert!(Severity::Warning < Severity::Error);
     }
 
+    #[test]
+    fn error_codes_are_stable() {
+        let codes: HashSet<_> = ERROR_CODES.iter().collect();
+        assert_eq!(codes.len(), ERROR_CODES.len());
+        assert!(
+            ERROR_CODES
+                .iter()
+                .all(|code| code.len() == 5 && code.starts_with("CO"))
+        );
+
+        let invalid = ValidationError::invalid_owner_format("badowner", "reason", test_span());
+        let duplicate = ValidationError::duplicate_pattern("*.rs", test_span(), 1);
+        let incomplete = ValidationError::incomplete_listing("x", test_span());
+        assert_eq!(invalid.code(), "CO001");
+        assert_eq!(duplicate.code(), "CO004");
+        assert_eq!(incomplete.code(), ERROR_CODES[ERROR_CODES.len() - 1]);
+    }
+
+    #[test]
+    fn validation_result_severity_overrides() {
+        let mut result = ValidationResult::with_errors(vec![
+            ValidationError::duplicate_pattern("*.rs", test_span(), 1),
+            ValidationError::file_not_owned("a.rs", test_span()),
+        ]);
+        assert_eq!(result.errors_only().count(), 0);
+
+        result.override_severities(&HashMap::from([("CO013".to_string(), Severity::Error)]));
+        assert_eq!(result.severity_of(&result.errors[0]), Severity::Warning);
+        assert_eq!(result.severity_of(&result.errors[1]), Severity::Error);
+        assert_eq!(result.errors_only().count(), 1);
+        assert_eq!(result.warnings_only().count(), 1);
+    }
+
+    #[test]
+    fn validation_result_suppress() {
+        let mut result = ValidationResult::with_errors(vec![
+            ValidationError::duplicate_pattern("*.rs", test_span(), 1),
+            ValidationError::file_not_owned("a.rs", test_span()),
+        ]);
+        result.suppress(&HashSet::from(["CO004".to_string()]));
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].code(), "CO013");
+    }
+
+    #[test]
+    fn validation_result_internal_error() {
+        let mut result = ValidationResult::new();
+        assert_eq!(result.internal_error, None);
+
+        let mut failed = ValidationResult::new();
+        failed.set_internal_error("API unreachable");
+        result.merge(failed);
+        assert!(result.is_ok());
+        assert_eq!(result.internal_error.as_deref(), Some("API unreachable"));
+    }
+
     #[test]
     fn validation_result_empty() {
         let result = ValidationResult::new();
@@ -542,7 +2207,11 @@ mod tests {
     fn validation_result_filter_by_severity() {
         let errors = vec![
             ValidationError::invalid_owner_format("bad", "reason", test_span()),
-            ValidationError::unsupported_pattern_syntax("!x", "negation", test_span()),
+            ValidationError::unsupported_pattern_syntax(
+                "!x",
+                UnsupportedSyntax::Negation,
+                test_span(),
+            ),
         ];
         let result = ValidationResult::with_errors(errors);
 
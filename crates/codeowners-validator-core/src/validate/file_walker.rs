@@ -1,14 +1,153 @@
 //! Shared file walking utilities for validation checks.
 //!
 //! This module provides a configurable file walker that can be used by different
-//! validation checks with varying requirements.
+//! validation checks with varying requirements. Library users embedding the
+//! checks can tune the traversal through [`crate::CheckConfig`], or list
+//! files themselves:
+//!
+//! ```rust,no_run
+//! use codeowners_validator_core::validate::file_walker::{FileWalkerConfig, list_files};
+//! use std::path::Path;
+//!
+//! let config = FileWalkerConfig::new()
+//!     .with_hidden(true)
+//!     .with_gitignore(true)
+//!     .with_max_depth(Some(4))
+//!     .with_excluded_dirs(["node_modules", "third_party/vendor"]);
+//! for path in list_files(Path::new("."), &config) {
+//!     println!("{}", path);
+//! }
+//! ```
+
+use crate::matching::to_repo_path;
+use crate::vcs::{GitTreeSource, VcsError, detect_vcs};
+use ignore::gitignore::{Gitignore, GitignoreBuilder, Glob};
+use ignore::{DirEntry, Match, WalkBuilder, WalkState};
+use log::{debug, trace, warn};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::fs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// Errors from listing the files of a repository.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum ListError {
+    /// The version control system failed to list the files it tracks.
+    #[error("failed to list the files {vcs} tracks: {source}")]
+    Tracked {
+        /// The version control system, e.g. `git`.
+        vcs: &'static str,
+        /// The underlying error.
+        #[source]
+        source: VcsError,
+    },
+
+    /// The tree of [`FileWalkerConfig::git_rev`] could not be listed, e.g.
+    /// because the revision does not exist.
+    #[error("failed to list the files of revision '{rev}': {source}")]
+    Revision {
+        /// The revision.
+        rev: String,
+        /// The underlying error.
+        #[source]
+        source: VcsError,
+    },
+
+    /// The thread enforcing [`FileWalkerConfig::timeout`] could not be
+    /// started.
+    #[error("failed to start the file walk thread: {0}")]
+    Thread(#[source] std::io::Error),
+}
+
+/// The files listed in a repository.
+///
+/// Dereferences to the sorted paths, relative to the repository with
+/// forward slashes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileListing {
+    /// The listed paths, sorted.
+    pub files: Vec<String>,
+    /// Why the walk stopped before listing every file, if it did, e.g.
+    /// `took longer than 30s`.
+    pub incomplete: Option<String>,
+}
 
-use ignore::WalkBuilder;
-use log::{debug, trace};
-use std::path::Path;
+impl FileListing {
+    /// Returns true if every file was listed.
+    pub fn is_complete(&self) -> bool {
+        self.incomplete.is_none()
+    }
+}
+
+impl From<Vec<String>> for FileListing {
+    fn from(files: Vec<String>) -> Self {
+        Self {
+            files,
+            incomplete: None,
+        }
+    }
+}
+
+impl Deref for FileListing {
+    type Target = [String];
+
+    fn deref(&self) -> &[String] {
+        &self.files
+    }
+}
+
+/// How the file walker handles file names that are not valid UTF-8.
+///
+/// CODEOWNERS patterns are UTF-8 text, so such names cannot be matched
+/// exactly. Unless skipped, they are listed with each invalid byte sequence
+/// replaced by U+FFFD, the Unicode replacement character.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NonUtf8Policy {
+    /// List them; the not-owned check reports each one with a dedicated
+    /// warning instead of checking its ownership.
+    #[default]
+    Warn,
+    /// List them and match them like any other path.
+    Lossy,
+    /// Leave them out silently.
+    Skip,
+}
+
+/// How the file walker handles symbolic links.
+///
+/// Git tracks a symlink as a file holding its target, so by default links
+/// are neither listed nor followed. Following them lists the files behind
+/// them under the link's path, which can cover the same files twice.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SymlinkPolicy {
+    /// Leave them out; [`explain_skips`] lists them as skipped.
+    #[default]
+    Skip,
+    /// Follow them, listing linked files and the contents of linked
+    /// directories. Links that loop back to an ancestor are skipped.
+    Follow,
+    /// List the links themselves without following them; the not-owned
+    /// check reports each one with a dedicated warning instead of checking
+    /// its ownership.
+    Report,
+}
 
 /// Configuration for file walking behavior.
-#[derive(Debug, Clone, Default)]
+///
+/// Built with one of the constructors and the `with_*` methods, since new
+/// walk settings are added over time.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub struct FileWalkerConfig {
     /// Whether to include hidden files and directories (starting with `.`).
     /// Default: false
@@ -16,9 +155,55 @@ pub struct FileWalkerConfig {
     /// Whether to respect `.gitignore` rules (only works in git repos).
     /// Default: false
     pub respect_gitignore: bool,
+    /// Whether to respect `.gitignore` rules even outside a git repo, e.g. in
+    /// exported tarballs or CI checkouts without `.git`. Implies
+    /// `respect_gitignore`.
+    /// Default: false
+    pub force_respect_gitignore: bool,
     /// Whether to include directories in the output.
     /// Default: false (files only)
     pub include_directories: bool,
+    /// How file names that are not valid UTF-8 are handled.
+    /// Default: [`NonUtf8Policy::Warn`]
+    pub non_utf8: NonUtf8Policy,
+    /// How symbolic links are handled.
+    /// Default: [`SymlinkPolicy::Skip`]
+    pub symlinks: SymlinkPolicy,
+    /// Number of threads walking the repository; 0 picks a number based on
+    /// the available CPUs, and 1 walks on the calling thread.
+    /// Default: 0
+    pub threads: usize,
+    /// Whether to list the files the version control system tracks instead
+    /// of walking the file system, so untracked build artifacts are left
+    /// out and dirty worktrees list the same files as clean ones. Ignore
+    /// rules do not apply to tracked files. Outside a repository, the file
+    /// system is walked; if the version control system fails, the listing
+    /// fails (see [`try_list_files`]).
+    /// Default: false
+    pub tracked_only: bool,
+    /// A git revision whose tree is listed instead of the file system, e.g.
+    /// in a bare mirror without a checkout. Takes precedence over
+    /// `tracked_only`; ignore rules do not apply.
+    /// Default: None
+    pub git_rev: Option<String>,
+    /// How many directories deep to list; files directly in the repository
+    /// root are at depth 1.
+    /// Default: None (unlimited)
+    pub max_depth: Option<usize>,
+    /// Directories left out along with their contents, relative to the
+    /// repository root with forward slashes (e.g. `third_party/vendor`).
+    /// Default: none
+    pub excluded_dirs: Vec<String>,
+    /// How many entries a file system walk lists at most. Once reached, the
+    /// walk stops and the listing is [incomplete](FileListing::incomplete).
+    /// Default: None (unlimited)
+    pub max_files: Option<usize>,
+    /// How long a file system walk may take, e.g. when the repository
+    /// contains a mounted network share. Once elapsed, the walk is
+    /// abandoned, even in the middle of a hung directory read, and the
+    /// listing is [incomplete](FileListing::incomplete).
+    /// Default: None (unlimited)
+    pub timeout: Option<Duration>,
 }
 
 impl FileWalkerConfig {
@@ -39,18 +224,111 @@ impl FileWalkerConfig {
         self
     }
 
+    /// Sets whether to respect .gitignore rules even without a `.git` directory.
+    pub fn with_force_respect_gitignore(mut self, force: bool) -> Self {
+        self.force_respect_gitignore = force;
+        self
+    }
+
     /// Sets whether to include directories.
     pub fn with_directories(mut self, include: bool) -> Self {
         self.include_directories = include;
         self
     }
 
+    /// Sets how file names that are not valid UTF-8 are handled.
+    pub fn with_non_utf8(mut self, policy: NonUtf8Policy) -> Self {
+        self.non_utf8 = policy;
+        self
+    }
+
+    /// Sets how symbolic links are handled.
+    pub fn with_symlinks(mut self, policy: SymlinkPolicy) -> Self {
+        self.symlinks = policy;
+        self
+    }
+
+    /// Sets the number of threads walking the repository.
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    /// Sets whether to list only the files the version control system tracks.
+    pub fn with_tracked_only(mut self, tracked_only: bool) -> Self {
+        self.tracked_only = tracked_only;
+        self
+    }
+
+    /// Sets the git revision whose tree is listed, if any.
+    pub fn with_git_rev(mut self, rev: Option<String>) -> Self {
+        self.git_rev = rev;
+        self
+    }
+
+    /// Sets how many directories deep to list, if limited.
+    pub fn with_max_depth(mut self, depth: Option<usize>) -> Self {
+        self.max_depth = depth;
+        self
+    }
+
+    /// Sets the directories left out of the listing. Leading and trailing
+    /// slashes are ignored.
+    pub fn with_excluded_dirs<I, S>(mut self, dirs: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.excluded_dirs = dirs
+            .into_iter()
+            .map(|dir| dir.into().trim_matches('/').to_string())
+            .filter(|dir| !dir.is_empty())
+            .collect();
+        self
+    }
+
+    /// Sets how many entries a walk lists at most, if limited.
+    pub fn with_max_files(mut self, max: Option<usize>) -> Self {
+        self.max_files = max;
+        self
+    }
+
+    /// Sets how long a walk may take, if limited.
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Returns true if `path` is an excluded directory or inside one.
+    fn is_excluded(&self, path: &str) -> bool {
+        self.excluded_dirs.iter().any(|dir| {
+            path.strip_prefix(dir.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        })
+    }
+
+    /// Returns true if `path` is nested deeper than the maximum depth.
+    fn is_too_deep(&self, path: &str) -> bool {
+        self.max_depth
+            .is_some_and(|depth| path.split('/').count() > depth)
+    }
+
     /// Configuration for FilesCheck: excludes hidden, includes dirs.
     pub fn for_files_check() -> Self {
         Self {
             include_hidden: false,
             respect_gitignore: false,
+            force_respect_gitignore: false,
             include_directories: true,
+            non_utf8: NonUtf8Policy::Warn,
+            symlinks: SymlinkPolicy::Skip,
+            threads: 0,
+            tracked_only: false,
+            git_rev: None,
+            max_depth: None,
+            excluded_dirs: Vec::new(),
+            max_files: None,
+            timeout: None,
         }
     }
 
@@ -59,63 +337,693 @@ impl FileWalkerConfig {
         Self {
             include_hidden: true,
             respect_gitignore: true,
+            force_respect_gitignore: false,
             include_directories: false,
+            non_utf8: NonUtf8Policy::Warn,
+            symlinks: SymlinkPolicy::Skip,
+            threads: 0,
+            tracked_only: false,
+            git_rev: None,
+            max_depth: None,
+            excluded_dirs: Vec::new(),
+            max_files: None,
+            timeout: None,
         }
     }
 }
 
+/// Returns the directory to walk for a repository.
+///
+/// On Windows, this is the canonical path, with its `\\?\` prefix, so files
+/// nested deeper than the 260-character `MAX_PATH` limit can still be
+/// listed.
+#[cfg(windows)]
+fn walk_root(repo_path: &Path) -> PathBuf {
+    fs::canonicalize(repo_path).unwrap_or_else(|_| repo_path.to_path_buf())
+}
+
+/// Returns the directory to walk for a repository.
+#[cfg(not(windows))]
+fn walk_root(repo_path: &Path) -> PathBuf {
+    repo_path.to_path_buf()
+}
+
 /// Lists files (and optionally directories) in a repository.
 ///
-/// Returns paths relative to `repo_path` with forward slashes.
+/// Like [`try_list_files`], except that a listing that fails is logged and
+/// treated as empty, and an incomplete one is only logged.
 pub fn list_files(repo_path: &Path, config: &FileWalkerConfig) -> Vec<String> {
+    match try_list_files(repo_path, config) {
+        Ok(listing) => listing.files,
+        Err(e) => {
+            warn!("{}; listing no files", e);
+            Vec::new()
+        }
+    }
+}
+
+/// Lists files (and optionally directories) in a repository.
+///
+/// Returns paths relative to `repo_path` with forward slashes, sorted, so
+/// the listing does not depend on the order threads visit directories in.
+/// File names that are not valid UTF-8 are handled as set by
+/// [`FileWalkerConfig::non_utf8`].
+///
+/// Fails if the tree of [`FileWalkerConfig::git_rev`] cannot be listed,
+/// or if [`FileWalkerConfig::tracked_only`] is set and the version control
+/// system cannot list its files, rather than walking the file system
+/// instead, which would list untracked files. A walk stopped by
+/// [`FileWalkerConfig::max_files`] or [`FileWalkerConfig::timeout`]
+/// succeeds with an [incomplete](FileListing::incomplete) listing.
+pub fn try_list_files(
+    repo_path: &Path,
+    config: &FileWalkerConfig,
+) -> Result<FileListing, ListError> {
     debug!(
-        "Listing files in {:?} (hidden={}, gitignore={}, dirs={})",
-        repo_path, config.include_hidden, config.respect_gitignore, config.include_directories
+        "Listing files in {:?} (hidden={}, gitignore={}, force_gitignore={}, dirs={}, symlinks={:?}, threads={}, tracked={}, rev={:?}, max_depth={:?}, excluded={:?}, max_files={:?}, timeout={:?})",
+        repo_path,
+        config.include_hidden,
+        config.respect_gitignore,
+        config.force_respect_gitignore,
+        config.include_directories,
+        config.symlinks,
+        config.threads,
+        config.tracked_only,
+        config.git_rev,
+        config.max_depth,
+        config.excluded_dirs,
+        config.max_files,
+        config.timeout
     );
 
-    let mut files = Vec::new();
+    if let Some(rev) = &config.git_rev {
+        let paths = GitTreeSource::new(repo_path, rev)
+            .list_paths()
+            .map_err(|source| ListError::Revision {
+                rev: rev.clone(),
+                source,
+            })?;
+        let files = filter_listed(decode_paths(paths, config.non_utf8), config);
+        debug!("Found {} entries at '{}'", files.len(), rev);
+        trace!("Entries: {:?}", files);
+        return Ok(files.into());
+    }
+
+    if config.tracked_only
+        && let Some(files) = list_tracked_files(repo_path, config)?
+    {
+        debug!("Found {} tracked entries", files.len());
+        trace!("Entries: {:?}", files);
+        return Ok(files.into());
+    }
+
+    let root = walk_root(repo_path);
+    let limits = Arc::new(WalkLimits::new(config.max_files));
+    let (sender, receiver) = mpsc::channel();
+    let mut files: Vec<String> = match config.timeout {
+        None => {
+            walk(&root, config, &limits, sender);
+            receiver.into_iter().collect()
+        }
+        Some(timeout) => {
+            // A walk stuck on a hung mount never gets back to checking the
+            // clock, so the deadline is kept here and the walk abandoned
+            let deadline = Instant::now() + timeout;
+            let worker_limits = Arc::clone(&limits);
+            let worker_config = config.clone();
+            let worker_root = root.clone();
+            thread::Builder::new()
+                .name("codeowners-file-walk".to_string())
+                .spawn(move || walk(&worker_root, &worker_config, &worker_limits, sender))
+                .map_err(ListError::Thread)?;
+            collect_until(&receiver, deadline, || {
+                limits.stop(format!("took longer than {:?}", timeout));
+            })
+        }
+    };
+    files.sort_unstable();
+
+    let incomplete = limits.reached.get().cloned();
+    if let Some(reason) = &incomplete {
+        warn!(
+            "Stopped listing files in {:?} after {} entries because the walk {}; \
+             results are incomplete. Raise the limit or exclude the directories \
+             that are slow or large.",
+            repo_path,
+            files.len(),
+            reason
+        );
+    }
+
+    debug!("Found {} entries", files.len());
+    trace!("Entries: {:?}", files);
+    Ok(FileListing { files, incomplete })
+}
+
+/// Walks the file system under `root`, sending each listed path until the
+/// walk ends or `limits` stop it.
+fn walk(root: &Path, config: &FileWalkerConfig, limits: &WalkLimits, sender: mpsc::Sender<String>) {
+    let gitignore = config.respect_gitignore || config.force_respect_gitignore;
 
     // Use WalkBuilder from the `ignore` crate which:
-    // - Can respect .gitignore by default (when in a git repo)
+    // - Can respect .gitignore by default (when in a git repo, unless forced)
     // - Skips .git directory by default
     // - Can be configured to include/exclude hidden files
-    let walker = WalkBuilder::new(repo_path)
+    let mut builder = WalkBuilder::new(root);
+    builder
         .hidden(!config.include_hidden) // hidden(true) = skip hidden files
         .ignore(false) // Don't respect .ignore files (not a git standard)
-        .git_ignore(config.respect_gitignore)
-        .git_global(config.respect_gitignore)
-        .git_exclude(config.respect_gitignore)
-        .follow_links(false)
-        .build();
-
-    for entry in walker.filter_map(|e| e.ok()) {
-        // Skip the root directory itself
-        if entry.path() == repo_path {
+        .git_ignore(gitignore)
+        .git_global(gitignore)
+        .git_exclude(gitignore)
+        .require_git(!config.force_respect_gitignore)
+        .follow_links(config.symlinks == SymlinkPolicy::Follow)
+        .max_depth(config.max_depth)
+        .threads(config.threads);
+    if !config.excluded_dirs.is_empty() {
+        let walk_root = root.to_path_buf();
+        let excluded = config.clone();
+        builder.filter_entry(move |entry| {
+            !entry.file_type().is_some_and(|ft| ft.is_dir())
+                || entry
+                    .path()
+                    .strip_prefix(&walk_root)
+                    .ok()
+                    .and_then(to_repo_path)
+                    .is_none_or(|path| !excluded.is_excluded(&path))
+        });
+    }
+
+    if config.threads == 1 {
+        for entry in builder.build() {
+            if limits.stopped() {
+                break;
+            }
+            let Some(path) = entry.ok().and_then(|e| listed_path(root, &e, config)) else {
+                continue;
+            };
+            // A closed channel means the listing was abandoned
+            if !limits.admit() || sender.send(path).is_err() {
+                break;
+            }
+        }
+    } else {
+        builder.build_parallel().run(|| {
+            let sender = sender.clone();
+            Box::new(move |entry| {
+                if limits.stopped() {
+                    return WalkState::Quit;
+                }
+                if let Some(path) = entry.ok().and_then(|e| listed_path(root, &e, config))
+                    && (!limits.admit() || sender.send(path).is_err())
+                {
+                    return WalkState::Quit;
+                }
+                WalkState::Continue
+            })
+        });
+    }
+}
+
+/// Receives paths until the walk sending them ends, or until `deadline`,
+/// when `expire` is called and the walk is abandoned.
+fn collect_until(
+    receiver: &mpsc::Receiver<String>,
+    deadline: Instant,
+    expire: impl FnOnce(),
+) -> Vec<String> {
+    let mut files = Vec::new();
+    loop {
+        let remaining = deadline
+            .checked_duration_since(Instant::now())
+            .filter(|remaining| !remaining.is_zero());
+        let Some(remaining) = remaining else {
+            expire();
+            return files;
+        };
+        match receiver.recv_timeout(remaining) {
+            Ok(path) => files.push(path),
+            Err(RecvTimeoutError::Timeout) => {
+                expire();
+                return files;
+            }
+            Err(RecvTimeoutError::Disconnected) => return files,
+        }
+    }
+}
+
+/// Stops a file system walk once it is over its limits or abandoned,
+/// remembering why.
+///
+/// Shared with the thread walking, which checks [`WalkLimits::stopped`]
+/// between entries.
+struct WalkLimits {
+    max_files: Option<usize>,
+    listed: AtomicUsize,
+    stopped: AtomicBool,
+    reached: OnceLock<String>,
+}
+
+impl WalkLimits {
+    fn new(max_files: Option<usize>) -> Self {
+        Self {
+            max_files,
+            listed: AtomicUsize::new(0),
+            stopped: AtomicBool::new(false),
+            reached: OnceLock::new(),
+        }
+    }
+
+    /// Stops the walk, unless it was already stopped for another reason.
+    fn stop(&self, reason: String) {
+        let _ = self.reached.set(reason);
+        self.stopped.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true if the walk should stop.
+    fn stopped(&self) -> bool {
+        self.stopped.load(Ordering::Relaxed)
+    }
+
+    /// Counts an entry to list, returning false if it exceeds the maximum.
+    fn admit(&self) -> bool {
+        let Some(max) = self.max_files else {
+            return true;
+        };
+        let admitted = self.listed.fetch_add(1, Ordering::Relaxed) < max;
+        if !admitted {
+            self.stop(format!("reached the limit of {} entries", max));
+        }
+        admitted
+    }
+}
+
+/// Lists the files the version control system tracks, sorted, or `None`
+/// if the repository is not under version control and the file system
+/// should be walked instead.
+fn list_tracked_files(
+    repo_path: &Path,
+    config: &FileWalkerConfig,
+) -> Result<Option<Vec<String>>, ListError> {
+    let Some(vcs) = detect_vcs(repo_path) else {
+        warn!(
+            "{:?} is not in a repository, listing all files instead of tracked ones",
+            repo_path
+        );
+        return Ok(None);
+    };
+    let tracked = vcs.tracked_paths().map_err(|source| ListError::Tracked {
+        vcs: vcs.name(),
+        source,
+    })?;
+    Ok(Some(filter_listed(
+        decode_paths(tracked, config.non_utf8),
+        config,
+    )))
+}
+
+/// Converts paths listed by version control to strings, handling names
+/// that are not valid UTF-8 as set by `policy`.
+fn decode_paths(paths: Vec<Vec<u8>>, policy: NonUtf8Policy) -> Vec<String> {
+    paths
+        .into_iter()
+        .filter_map(|path| match String::from_utf8(path) {
+            Ok(path) => Some(path),
+            Err(e) => {
+                let lossy = String::from_utf8_lossy(e.as_bytes()).into_owned();
+                if policy == NonUtf8Policy::Skip {
+                    debug!("Skipping path that is not valid UTF-8: {:?}", lossy);
+                    return None;
+                }
+                Some(lossy)
+            }
+        })
+        .collect()
+}
+
+/// Filters paths listed by version control like a walk would, sorted.
+///
+/// Hidden files and directories are left out unless included, as are
+/// excluded directories; directories are those containing listed files.
+fn filter_listed(paths: Vec<String>, config: &FileWalkerConfig) -> Vec<String> {
+    let mut files = BTreeSet::new();
+    for path in paths {
+        if !config.include_hidden && path.split('/').any(|name| name.starts_with('.')) {
+            continue;
+        }
+        if config.is_excluded(&path) {
             continue;
         }
+        if config.include_directories {
+            files.extend(
+                path.match_indices('/')
+                    .map(|(end, _)| path[..end].to_string())
+                    .filter(|dir| !config.is_too_deep(dir)),
+            );
+        }
+        if !config.is_too_deep(&path) {
+            files.insert(path);
+        }
+    }
+    files.into_iter().collect()
+}
+
+/// Returns the path of a walked entry relative to `root`, with forward
+/// slashes, or `None` if the listing leaves it out.
+fn listed_path(root: &Path, entry: &DirEntry, config: &FileWalkerConfig) -> Option<String> {
+    // Skip the root directory itself
+    if entry.path() == root {
+        return None;
+    }
+
+    // Skip based on config: include files, or dirs if configured. Followed
+    // links have the type of their target, so only unfollowed ones remain.
+    let is_file = entry.file_type().is_some_and(|ft| {
+        ft.is_file() || (ft.is_symlink() && config.symlinks == SymlinkPolicy::Report)
+    });
+    let is_dir = entry.file_type().is_some_and(|ft| ft.is_dir());
+    if !(is_file || (config.include_directories && is_dir)) {
+        return None;
+    }
+
+    let relative = entry.path().strip_prefix(root).ok()?;
+    match to_repo_path(relative) {
+        Some(path) => Some(path),
+        None if config.non_utf8 == NonUtf8Policy::Skip => {
+            debug!("Skipping path that is not valid UTF-8: {:?}", relative);
+            None
+        }
+        None => Some(
+            relative
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/"),
+        ),
+    }
+}
+
+/// Why the file walker skipped a path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SkipReason {
+    /// The path is hidden (its name starts with `.`).
+    Hidden,
+    /// The path is a symbolic link, which is not listed.
+    Symlink,
+    /// The path is an excluded directory.
+    Excluded,
+    /// The path is nested deeper than the maximum depth.
+    TooDeep,
+    /// The path matched a pattern in an ignore file.
+    Ignored {
+        /// The ignore file, relative to the repository when it is inside it.
+        source: String,
+        /// The pattern that matched, as written in the ignore file.
+        pattern: String,
+    },
+}
+
+impl fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hidden => write!(f, "hidden"),
+            Self::Symlink => write!(f, "symbolic link"),
+            Self::Excluded => write!(f, "excluded directory"),
+            Self::TooDeep => write!(f, "deeper than the maximum depth"),
+            Self::Ignored { source, pattern } => {
+                write!(f, "ignored by '{}' in {}", pattern, source)
+            }
+        }
+    }
+}
 
-        // Check file type
-        let is_file = entry.file_type().is_some_and(|ft| ft.is_file());
-        let is_dir = entry.file_type().is_some_and(|ft| ft.is_dir());
+/// A path the file walker skipped, and why.
+///
+/// The walker does not descend into skipped directories, so their contents
+/// are not listed separately.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedPath {
+    /// The path, relative to the repository root with forward slashes.
+    pub path: String,
+    /// Whether the path is a directory.
+    pub is_dir: bool,
+    /// Why the path was skipped.
+    pub reason: SkipReason,
+}
+
+impl fmt::Display for SkippedPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let slash = if self.is_dir { "/" } else { "" };
+        write!(f, "{}{}: {}", self.path, slash, self.reason)
+    }
+}
 
-        // Skip based on config: include files, or dirs if configured
-        if !(is_file || (config.include_directories && is_dir)) {
+/// Lists the paths [`list_files`] skips with the same configuration, and the
+/// hidden rule or ignore file that excluded each of them.
+///
+/// Ignore files are consulted as git does: the `.gitignore` closest to a
+/// path takes precedence, then `.git/info/exclude`, then the global excludes
+/// file. Paths in `.git` itself are never reported.
+pub fn explain_skips(repo_path: &Path, config: &FileWalkerConfig) -> Vec<SkippedPath> {
+    let gitignore = config.force_respect_gitignore
+        || (config.respect_gitignore && repo_path.ancestors().any(|dir| dir.join(".git").exists()));
+
+    let mut matchers = Vec::new();
+    if gitignore {
+        matchers.push(GitignoreBuilder::new(repo_path).build_global().0);
+        let mut exclude = GitignoreBuilder::new(repo_path);
+        exclude.add(repo_path.join(".git/info/exclude"));
+        matchers.push(exclude.build().unwrap_or_else(|_| Gitignore::empty()));
+    }
+
+    let mut skipped = Vec::new();
+    explain_dir(
+        repo_path,
+        repo_path,
+        config,
+        gitignore,
+        &mut matchers,
+        &mut skipped,
+    );
+    debug!("Walker skips {} path(s)", skipped.len());
+    skipped
+}
+
+/// Collects the skipped entries of a directory, descending into the rest.
+///
+/// `matchers` holds the ignore files in effect, lowest precedence first.
+fn explain_dir(
+    repo_path: &Path,
+    dir: &Path,
+    config: &FileWalkerConfig,
+    gitignore: bool,
+    matchers: &mut Vec<Gitignore>,
+    skipped: &mut Vec<SkippedPath>,
+) {
+    let ignore_file = dir.join(".gitignore");
+    let pushed = gitignore && ignore_file.is_file();
+    if pushed {
+        matchers.push(Gitignore::new(ignore_file).0);
+    }
+
+    let mut entries: Vec<_> = match fs::read_dir(dir) {
+        Ok(entries) => entries.filter_map(Result::ok).collect(),
+        Err(e) => {
+            debug!("Failed to read {:?}: {}", dir, e);
+            Vec::new()
+        }
+    };
+    entries.sort_by_key(|entry| entry.file_name());
+
+    for entry in entries {
+        let path = entry.path();
+        let is_dir = entry.file_type().is_ok_and(|ft| ft.is_dir());
+        let is_symlink = entry.file_type().is_ok_and(|ft| ft.is_symlink());
+        let name = entry.file_name();
+        if is_dir && name == ".git" {
+            continue;
+        }
+
+        let relative = relative_path(repo_path, &path);
+        if config.is_too_deep(&relative) {
+            skipped.push(SkippedPath {
+                path: relative,
+                is_dir,
+                reason: SkipReason::TooDeep,
+            });
+            continue;
+        }
+        if is_dir && config.is_excluded(&relative) {
+            skipped.push(SkippedPath {
+                path: relative,
+                is_dir,
+                reason: SkipReason::Excluded,
+            });
             continue;
         }
 
-        // Get path relative to repo root
-        if let Ok(relative) = entry.path().strip_prefix(repo_path)
-            && let Some(path_str) = relative.to_str()
-        {
-            // Normalize to forward slashes
-            let normalized = path_str.replace('\\', "/");
-            files.push(normalized);
+        // As in the walker, a whitelisting pattern also keeps hidden paths
+        let reason = match matched(matchers, &path, is_dir) {
+            Match::Ignore(glob) => Some(SkipReason::Ignored {
+                source: glob
+                    .from()
+                    .map(|from| relative_path(repo_path, from))
+                    .unwrap_or_default(),
+                pattern: glob.original().to_string(),
+            }),
+            Match::Whitelist(_) => None,
+            Match::None if !config.include_hidden && name.to_string_lossy().starts_with('.') => {
+                Some(SkipReason::Hidden)
+            }
+            Match::None => None,
+        };
+        let reason = reason.or_else(|| {
+            (is_symlink && config.symlinks == SymlinkPolicy::Skip).then_some(SkipReason::Symlink)
+        });
+
+        match reason {
+            Some(reason) => skipped.push(SkippedPath {
+                path: relative,
+                is_dir,
+                reason,
+            }),
+            None if is_dir => explain_dir(repo_path, &path, config, gitignore, matchers, skipped),
+            None => {}
         }
     }
 
-    debug!("Found {} entries", files.len());
-    trace!("Entries: {:?}", files);
-    files
+    if pushed {
+        matchers.pop();
+    }
+}
+
+/// Matches a path against ignore files, the last (closest) one first.
+fn matched<'a>(matchers: &'a [Gitignore], path: &Path, is_dir: bool) -> Match<&'a Glob> {
+    matchers
+        .iter()
+        .rev()
+        .map(|matcher| matcher.matched(path, is_dir))
+        .find(|m| !m.is_none())
+        .unwrap_or(Match::None)
+}
+
+/// Returns a path relative to the repository with forward slashes, or as
+/// is when it is outside the repository.
+fn relative_path(repo_path: &Path, path: &Path) -> String {
+    path.strip_prefix(repo_path)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// The file at the repository root listing paths, in gitignore syntax, that
+/// the `files` and `notowned` checks leave out.
+pub const CODEOWNERS_IGNORE_FILE: &str = ".codeownersignore";
+
+/// The paths a repository's `.codeownersignore` file excludes from checks.
+///
+/// Patterns follow gitignore syntax, relative to the repository root:
+/// `build/` ignores a directory anywhere, `/docs/*.pdf` files in one
+/// directory, and `!keep.pdf` re-includes a path an earlier pattern ignored.
+/// Unlike [`CheckConfig::skip_patterns`](super::checks::CheckConfig::skip_patterns),
+/// the file is committed with the repository, so every invocation honours it.
+#[derive(Debug, Clone)]
+pub struct CodeownersIgnore {
+    matcher: Gitignore,
+}
+
+impl CodeownersIgnore {
+    /// Parses the content of a `.codeownersignore` file. Invalid patterns are
+    /// logged and left out.
+    pub fn parse(content: &str) -> Self {
+        let mut builder = GitignoreBuilder::new("");
+        for line in content.lines() {
+            if let Err(e) = builder.add_line(None, line) {
+                warn!("Ignoring invalid line in {}: {}", CODEOWNERS_IGNORE_FILE, e);
+            }
+        }
+        let matcher = builder.build().unwrap_or_else(|e| {
+            warn!("Failed to build {}: {}", CODEOWNERS_IGNORE_FILE, e);
+            Gitignore::empty()
+        });
+        Self { matcher }
+    }
+
+    /// Loads the `.codeownersignore` file of a repository, or returns `None`
+    /// if it has none.
+    pub fn load(repo_path: &Path) -> Option<Self> {
+        let path = repo_path.join(CODEOWNERS_IGNORE_FILE);
+        match fs::read_to_string(&path) {
+            Ok(content) => Some(Self::parse(&content)),
+            Err(e) => {
+                trace!("No {:?}: {}", path, e);
+                None
+            }
+        }
+    }
+
+    /// Returns true if `path`, relative to the repository root with forward
+    /// slashes, or one of its parent directories is ignored.
+    pub fn is_ignored(&self, path: &str, is_dir: bool) -> bool {
+        self.matcher
+            .matched_path_or_any_parents(path, is_dir)
+            .is_ignore()
+    }
+}
+
+/// Cached listings, keyed by repository root and walker configuration.
+type Listings = HashMap<(PathBuf, FileWalkerConfig), Arc<FileListing>>;
+
+/// A cache of repository listings, keyed by repository and walker configuration.
+///
+/// Walking the repository is the most expensive part of the file-based checks.
+/// Long-lived callers that validate many CODEOWNERS revisions against the same
+/// checkout can share one index so each listing is only built once. Call
+/// [`FileIndex::clear`] after the working tree changes.
+#[derive(Debug, Default)]
+pub struct FileIndex {
+    listings: Mutex<Listings>,
+}
+
+impl FileIndex {
+    /// Creates an empty index.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the listing for a repository, walking it on first use.
+    ///
+    /// Failed and incomplete listings are not cached, so the next call
+    /// tries again.
+    pub fn list_files(
+        &self,
+        repo_path: &Path,
+        config: &FileWalkerConfig,
+    ) -> Result<Arc<FileListing>, ListError> {
+        let key = (repo_path.to_path_buf(), config.clone());
+        if let Some(files) = self.lock().get(&key) {
+            trace!("Using cached listing for {:?}", repo_path);
+            return Ok(Arc::clone(files));
+        }
+
+        // Walk without holding the lock; a concurrent walk just does redundant work
+        let files = Arc::new(try_list_files(repo_path, config)?);
+        if files.is_complete() {
+            self.lock().insert(key, Arc::clone(&files));
+        }
+        Ok(files)
+    }
+
+    /// Drops all cached listings.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Listings> {
+        self.listings
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
 }
 
 #[cfg(test)]
@@ -172,6 +1080,21 @@ mod tests {
         assert!(files.contains(&"src/main.rs".to_string()));
     }
 
+    #[test]
+    fn parallel_listing_is_sorted() {
+        let dir = setup_test_dir();
+        for name in ["b", "a", "c"] {
+            fs::create_dir_all(dir.path().join(name).join("nested")).unwrap();
+            File::create(dir.path().join(name).join("nested/file.rs")).unwrap();
+        }
+
+        let config = FileWalkerConfig::new().with_hidden(true);
+        let parallel = list_files(dir.path(), &config.clone().with_threads(4));
+        assert!(parallel.is_sorted());
+        assert_eq!(parallel, list_files(dir.path(), &config.with_threads(1)));
+        assert_eq!(parallel.len(), 7);
+    }
+
     #[test]
     fn for_files_check_config() {
         let config = FileWalkerConfig::for_files_check();
@@ -187,4 +1110,397 @@ mod tests {
         assert!(config.respect_gitignore);
         assert!(!config.include_directories);
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8_file_names() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let dir = setup_test_dir();
+        File::create(
+            dir.path()
+                .join("src")
+                .join(OsStr::from_bytes(b"caf\xe9.rs")),
+        )
+        .unwrap();
+
+        let files = list_files(dir.path(), &FileWalkerConfig::new());
+        assert!(files.contains(&"src/caf\u{FFFD}.rs".to_string()));
+
+        let config = FileWalkerConfig::new().with_non_utf8(NonUtf8Policy::Skip);
+        let files = list_files(dir.path(), &config);
+        assert!(!files.iter().any(|f| f.contains('\u{FFFD}')));
+        assert!(files.contains(&"src/main.rs".to_string()));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8_tracked_file_names() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let dir = setup_test_dir();
+        let root = dir.path();
+        File::create(root.join("src").join(OsStr::from_bytes(b"caf\xe9.rs"))).unwrap();
+        for args in [&["init", "-q"][..], &["add", "src"]] {
+            let status = std::process::Command::new("git")
+                .arg("-C")
+                .arg(root)
+                .args(args)
+                .status()
+                .unwrap();
+            assert!(status.success(), "git {:?} failed", args);
+        }
+        // Untracked, so only listed if the file system were walked
+        File::create(root.join("untracked.txt")).unwrap();
+
+        let config = FileWalkerConfig::new().with_tracked_only(true);
+        assert_eq!(
+            try_list_files(root, &config).unwrap().files,
+            ["src/caf\u{FFFD}.rs", "src/main.rs"]
+        );
+
+        let config = config.with_non_utf8(NonUtf8Policy::Skip);
+        assert_eq!(
+            try_list_files(root, &config).unwrap().files,
+            ["src/main.rs"]
+        );
+    }
+
+    #[test]
+    fn failing_git_is_an_error() {
+        let dir = setup_test_dir();
+        // A repository whose git directory is missing
+        fs::write(dir.path().join(".git"), "gitdir: missing\n").unwrap();
+
+        let config = FileWalkerConfig::new().with_tracked_only(true);
+        let err = try_list_files(dir.path(), &config).unwrap_err();
+        assert!(matches!(err, ListError::Tracked { vcs: "git", .. }));
+        assert!(list_files(dir.path(), &config).is_empty());
+
+        let index = FileIndex::new();
+        assert!(index.list_files(dir.path(), &config).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlink_policies() {
+        use std::os::unix::fs::symlink;
+
+        let dir = setup_test_dir();
+        symlink(dir.path().join("src"), dir.path().join("linked")).unwrap();
+        symlink("../visible.txt", dir.path().join("src/manifest")).unwrap();
+        // A link back to an ancestor must not be followed forever
+        symlink("..", dir.path().join("src/up")).unwrap();
+
+        let files = list_files(dir.path(), &FileWalkerConfig::new());
+        assert!(
+            !files
+                .iter()
+                .any(|f| f.starts_with("linked") || f.ends_with("manifest"))
+        );
+        let skipped: Vec<_> = explain_skips(dir.path(), &FileWalkerConfig::new())
+            .into_iter()
+            .filter(|skip| skip.reason == SkipReason::Symlink)
+            .map(|skip| skip.path)
+            .collect();
+        assert_eq!(skipped, ["linked", "src/manifest", "src/up"]);
+
+        let config = FileWalkerConfig::new().with_symlinks(SymlinkPolicy::Follow);
+        let files = list_files(dir.path(), &config);
+        assert!(files.contains(&"linked/main.rs".to_string()));
+        assert!(files.contains(&"src/manifest".to_string()));
+        assert!(files.contains(&"src/main.rs".to_string()));
+
+        let config = FileWalkerConfig::new().with_symlinks(SymlinkPolicy::Report);
+        let files = list_files(dir.path(), &config);
+        assert!(files.contains(&"linked".to_string()));
+        assert!(files.contains(&"src/manifest".to_string()));
+        assert!(!files.contains(&"linked/main.rs".to_string()));
+    }
+
+    #[test]
+    fn gitignore_without_git_repo() {
+        let dir = setup_test_dir();
+        fs::write(dir.path().join(".gitignore"), "target/\n*.log\n").unwrap();
+        fs::create_dir_all(dir.path().join("target")).unwrap();
+        File::create(dir.path().join("target/out.bin")).unwrap();
+        File::create(dir.path().join("build.log")).unwrap();
+
+        // Without .git, .gitignore is only honored when forced
+        let config = FileWalkerConfig::for_not_owned_check();
+        let files = list_files(dir.path(), &config);
+        assert!(files.contains(&"target/out.bin".to_string()));
+        assert!(files.contains(&"build.log".to_string()));
+
+        let config = config.with_force_respect_gitignore(true);
+        let files = list_files(dir.path(), &config);
+        assert!(!files.contains(&"target/out.bin".to_string()));
+        assert!(!files.contains(&"build.log".to_string()));
+        assert!(files.contains(&"src/main.rs".to_string()));
+        assert!(files.contains(&".gitignore".to_string()));
+    }
+
+    #[test]
+    fn lists_files_known_to_git() {
+        let dir = setup_test_dir();
+        let root = dir.path();
+        let git = |args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .arg("-C")
+                .arg(root)
+                .args(args)
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {:?} failed", args);
+        };
+        git(&["init", "-q"]);
+        git(&["add", "src/main.rs", ".hidden_file"]);
+        fs::create_dir_all(root.join("target")).unwrap();
+        File::create(root.join("target/out.bin")).unwrap();
+
+        let config = FileWalkerConfig::for_not_owned_check().with_tracked_only(true);
+        assert_eq!(list_files(root, &config), [".hidden_file", "src/main.rs"]);
+
+        let config = FileWalkerConfig::for_files_check().with_tracked_only(true);
+        assert_eq!(list_files(root, &config), ["src", "src/main.rs"]);
+
+        // Outside a repository, the file system is walked
+        let outside = setup_test_dir();
+        let files = list_files(outside.path(), &config);
+        assert!(files.contains(&"visible.txt".to_string()));
+
+        // A revision's tree lists its committed files only
+        git(&[
+            "-c",
+            "user.name=Test",
+            "-c",
+            "user.email=test@example.com",
+            "commit",
+            "-qm",
+            "one",
+        ]);
+        fs::remove_file(root.join("src/main.rs")).unwrap();
+        let config = FileWalkerConfig::for_not_owned_check().with_git_rev(Some("HEAD".into()));
+        assert_eq!(list_files(root, &config), [".hidden_file", "src/main.rs"]);
+
+        // A revision that does not exist fails instead of listing nothing
+        let config = config.with_git_rev(Some("no-such-rev".into()));
+        let err = try_list_files(root, &config).unwrap_err();
+        assert!(matches!(&err, ListError::Revision { rev, .. } if rev == "no-such-rev"));
+        assert_eq!(
+            err.to_string(),
+            "failed to list the files of revision 'no-such-rev': unknown revision 'no-such-rev'"
+        );
+    }
+
+    #[test]
+    fn explain_skips_names_ignore_source() {
+        let dir = setup_test_dir();
+        fs::write(dir.path().join(".gitignore"), "target/\n*.log\n").unwrap();
+        fs::write(
+            dir.path().join("src/.gitignore"),
+            "generated.rs\n!keep.log\n",
+        )
+        .unwrap();
+        fs::create_dir_all(dir.path().join("target/debug")).unwrap();
+        File::create(dir.path().join("target/debug/out.bin")).unwrap();
+        File::create(dir.path().join("build.log")).unwrap();
+        File::create(dir.path().join("src/generated.rs")).unwrap();
+        File::create(dir.path().join("src/keep.log")).unwrap();
+
+        let config = FileWalkerConfig::new().with_force_respect_gitignore(true);
+        let skipped: Vec<String> = explain_skips(dir.path(), &config)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            skipped,
+            [
+                ".gitignore: hidden",
+                ".hidden_dir/: hidden",
+                ".hidden_file: hidden",
+                "build.log: ignored by '*.log' in .gitignore",
+                "src/.gitignore: hidden",
+                "src/generated.rs: ignored by 'generated.rs' in src/.gitignore",
+                "target/: ignored by 'target/' in .gitignore",
+            ]
+        );
+
+        // Every listed file is either walked or explained
+        let files = list_files(dir.path(), &config);
+        assert!(files.contains(&"src/keep.log".to_string()));
+        assert!(!files.contains(&"src/generated.rs".to_string()));
+    }
+
+    #[test]
+    fn explain_skips_ignores_gitignore_outside_git_repo() {
+        let dir = setup_test_dir();
+        fs::write(dir.path().join(".gitignore"), "*.txt\n").unwrap();
+
+        let config = FileWalkerConfig::for_not_owned_check();
+        assert!(explain_skips(dir.path(), &config).is_empty());
+
+        fs::create_dir_all(dir.path().join(".git/info")).unwrap();
+        fs::write(dir.path().join(".git/info/exclude"), "src/\n").unwrap();
+        let skipped = explain_skips(dir.path(), &config);
+        assert_eq!(skipped.len(), 2);
+        assert_eq!(
+            skipped[0].reason,
+            SkipReason::Ignored {
+                source: ".git/info/exclude".to_string(),
+                pattern: "src/".to_string(),
+            }
+        );
+        assert_eq!(
+            skipped[1].to_string(),
+            "visible.txt: ignored by '*.txt' in .gitignore"
+        );
+    }
+
+    #[test]
+    fn max_depth_and_excluded_dirs() {
+        let dir = setup_test_dir();
+        fs::create_dir_all(dir.path().join("src/deep/er")).unwrap();
+        File::create(dir.path().join("src/deep/er/mod.rs")).unwrap();
+        fs::create_dir_all(dir.path().join("third_party/vendor")).unwrap();
+        File::create(dir.path().join("third_party/vendor/lib.c")).unwrap();
+        File::create(dir.path().join("third_party/README")).unwrap();
+
+        let config = FileWalkerConfig::new()
+            .with_directories(true)
+            .with_max_depth(Some(2))
+            .with_excluded_dirs(["/third_party/vendor/"]);
+        let walked = list_files(dir.path(), &config);
+        assert_eq!(
+            walked,
+            [
+                "src",
+                "src/deep",
+                "src/main.rs",
+                "third_party",
+                "third_party/README",
+                "visible.txt"
+            ]
+        );
+
+        // Listings from version control are filtered the same way
+        let tracked = [
+            "src/deep/er/mod.rs",
+            "src/main.rs",
+            "third_party/README",
+            "third_party/vendor/lib.c",
+            "visible.txt",
+        ];
+        let listed = filter_listed(tracked.map(String::from).to_vec(), &config);
+        assert_eq!(listed, walked);
+
+        let skipped: Vec<String> = explain_skips(dir.path(), &config.with_hidden(true))
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            skipped,
+            [
+                "src/deep/er/: deeper than the maximum depth",
+                "third_party/vendor/: excluded directory",
+            ]
+        );
+    }
+
+    #[test]
+    fn codeowners_ignore_uses_gitignore_syntax() {
+        let ignore = CodeownersIgnore::parse("# Generated\nbuild/\n/docs/*.pdf\n!/docs/keep.pdf\n");
+        assert!(ignore.is_ignored("build", true));
+        assert!(ignore.is_ignored("web/build/app.js", false));
+        assert!(ignore.is_ignored("docs/manual.pdf", false));
+        assert!(!ignore.is_ignored("docs/keep.pdf", false));
+        assert!(!ignore.is_ignored("src/docs/manual.pdf", false));
+        assert!(!ignore.is_ignored("build.rs", false));
+
+        let dir = setup_test_dir();
+        assert!(CodeownersIgnore::load(dir.path()).is_none());
+        fs::write(dir.path().join(CODEOWNERS_IGNORE_FILE), "*.txt\n").unwrap();
+        let ignore = CodeownersIgnore::load(dir.path()).unwrap();
+        assert!(ignore.is_ignored("visible.txt", false));
+    }
+
+    #[test]
+    fn walk_stops_at_limits() {
+        let dir = setup_test_dir();
+        for name in ["a", "b", "c"] {
+            File::create(dir.path().join(name)).unwrap();
+        }
+
+        for threads in [1, 4] {
+            let config = FileWalkerConfig::new()
+                .with_threads(threads)
+                .with_max_files(Some(3));
+            let listing = try_list_files(dir.path(), &config).unwrap();
+            assert_eq!(listing.len(), 3);
+            assert_eq!(
+                listing.incomplete.as_deref(),
+                Some("reached the limit of 3 entries")
+            );
+
+            let config = config
+                .with_max_files(None)
+                .with_timeout(Some(Duration::ZERO));
+            let listing = try_list_files(dir.path(), &config).unwrap();
+            assert!(listing.is_empty());
+            assert_eq!(listing.incomplete.as_deref(), Some("took longer than 0ns"));
+
+            let config = config.with_timeout(Some(Duration::from_secs(60)));
+            let listing = try_list_files(dir.path(), &config).unwrap();
+            assert_eq!(listing.len(), 5);
+            assert!(listing.is_complete());
+        }
+        assert_eq!(list_files(dir.path(), &FileWalkerConfig::new()).len(), 5);
+    }
+
+    #[test]
+    fn hung_walk_is_abandoned_at_the_deadline() {
+        // A walk blocked in a directory read holds its sender without sending
+        let (sender, receiver) = mpsc::channel();
+        sender.send("first".to_string()).unwrap();
+        let limits = WalkLimits::new(None);
+
+        let started = Instant::now();
+        let files = collect_until(&receiver, started + Duration::from_millis(50), || {
+            limits.stop("took longer than 50ms".to_string());
+        });
+        assert_eq!(files, ["first"]);
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert!(limits.stopped());
+        assert_eq!(
+            limits.reached.get().map(String::as_str),
+            Some("took longer than 50ms")
+        );
+        drop(sender);
+    }
+
+    #[test]
+    fn file_index_caches_listing() {
+        let dir = setup_test_dir();
+        let index = FileIndex::new();
+        let config = FileWalkerConfig::new();
+
+        let first = index.list_files(dir.path(), &config).unwrap();
+        File::create(dir.path().join("added.txt")).unwrap();
+        let second = index.list_files(dir.path(), &config).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+
+        index.clear();
+        let refreshed = index.list_files(dir.path(), &config).unwrap();
+        assert!(refreshed.contains(&"added.txt".to_string()));
+
+        // Incomplete listings are walked again
+        let limited = config.with_max_files(Some(1));
+        let first = index.list_files(dir.path(), &limited).unwrap();
+        let second = index.list_files(dir.path(), &limited).unwrap();
+        assert!(!first.is_complete());
+        assert!(!Arc::ptr_eq(&first, &second));
+    }
 }
@@ -0,0 +1,192 @@
+//! Machine-applicable fixes for validation findings.
+//!
+//! A [`Fix`] replaces the source text covered by a span with new text.
+//! Fixes are produced by validation errors (see [`ValidationError::fix`])
+//! and applied to the original CODEOWNERS content with [`apply_fixes`].
+//!
+//! [`ValidationError::fix`]: super::ValidationError::fix
+
+use crate::parse::CodeownersFile;
+use crate::parse::span::Span;
+use serde::Serialize;
+
+/// A single text replacement that resolves a validation finding.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Fix {
+    /// The source region to replace.
+    pub span: Span,
+    /// The text to insert in place of the span.
+    pub replacement_text: String,
+}
+
+impl Fix {
+    /// Creates a fix replacing the given span with new text.
+    pub fn replace(span: Span, replacement_text: impl Into<String>) -> Self {
+        Self {
+            span,
+            replacement_text: replacement_text.into(),
+        }
+    }
+
+    /// Describes what the fix does to the source, e.g.
+    /// `Replace '@alice' with '@org/team'`.
+    pub fn describe(&self, source: &str) -> String {
+        let original = source
+            .get(self.span.offset..self.span.end_offset())
+            .unwrap_or_default()
+            .trim();
+        if self.replacement_text.is_empty() {
+            format!("Remove '{}'", original)
+        } else {
+            format!("Replace '{}' with '{}'", original, self.replacement_text)
+        }
+    }
+}
+
+/// Returns the fixes [`apply_fixes`] would apply, in source order.
+///
+/// A fix inside the span of another, such as an owner fix on a rule another
+/// fix deletes, is superseded by it. Of the remaining fixes, one that
+/// overlaps a later one, or whose span falls outside the source, is left out.
+pub fn applicable_fixes<'a>(source: &str, fixes: &'a [Fix]) -> Vec<&'a Fix> {
+    let contains = |outer: &Fix, inner: &Fix| {
+        outer.span.length > inner.span.length
+            && outer.span.offset <= inner.span.offset
+            && inner.span.end_offset() <= outer.span.end_offset()
+    };
+    let mut sorted: Vec<&Fix> = fixes
+        .iter()
+        .filter(|fix| !fixes.iter().any(|other| contains(other, fix)))
+        .collect();
+    sorted.sort_by_key(|fix| std::cmp::Reverse(fix.span.offset));
+
+    let mut applicable = Vec::new();
+    let mut applied_start = usize::MAX;
+
+    for fix in sorted {
+        let start = fix.span.offset;
+        let end = start + fix.span.length;
+        if end > applied_start
+            || end > source.len()
+            || !source.is_char_boundary(start)
+            || !source.is_char_boundary(end)
+        {
+            continue;
+        }
+        applicable.push(fix);
+        applied_start = start;
+    }
+
+    applicable.reverse();
+    applicable
+}
+
+/// Applies fixes to the source text, returning the updated content.
+///
+/// Fixes are applied from the end of the file backwards so earlier offsets
+/// stay valid. A fix that overlaps one already applied, or whose span falls
+/// outside the source, is skipped.
+pub fn apply_fixes(source: &str, fixes: &[Fix]) -> String {
+    let mut output = source.to_string();
+    for fix in applicable_fixes(source, fixes).into_iter().rev() {
+        output.replace_range(
+            fix.span.offset..fix.span.end_offset(),
+            &fix.replacement_text,
+        );
+    }
+    output
+}
+
+/// Returns the span that deletes the line at `index` (0-based) of a file.
+///
+/// The span covers the line and its line break, or, for the last line of a
+/// file, the line break before it, so no blank line is left behind.
+pub(crate) fn line_removal(file: &CodeownersFile, index: usize) -> Span {
+    let line = file.lines[index].span;
+    let (start, end) = match (file.lines.get(index + 1), index.checked_sub(1)) {
+        (Some(next), _) => (line.offset, next.span.offset),
+        (None, Some(previous)) => (file.lines[previous].span.end_offset(), line.end_offset()),
+        (None, None) => (line.offset, line.end_offset()),
+    };
+    Span::new(start, line.line, 1, end - start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_single_fix() {
+        let source = "*.rs @alice\n";
+        let fix = Fix::replace(Span::new(5, 1, 6, 6), "@org/team");
+        assert_eq!(apply_fixes(source, &[fix]), "*.rs @org/team\n");
+    }
+
+    #[test]
+    fn apply_fixes_in_any_order() {
+        let source = "*.rs @alice\n*.md @bob\n";
+        let fixes = vec![
+            Fix::replace(Span::new(5, 1, 6, 6), "@org/a"),
+            Fix::replace(Span::new(17, 2, 6, 4), "@org/b"),
+        ];
+        assert_eq!(apply_fixes(source, &fixes), "*.rs @org/a\n*.md @org/b\n");
+    }
+
+    #[test]
+    fn overlapping_fix_skipped() {
+        let source = "*.rs @alice\n";
+        let fixes = vec![
+            Fix::replace(Span::new(5, 1, 6, 6), "@org/team"),
+            Fix::replace(Span::new(3, 1, 4, 4), "xx"),
+        ];
+        assert_eq!(apply_fixes(source, &fixes), "*.rs @org/team\n");
+    }
+
+    #[test]
+    fn applicable_fixes_in_source_order() {
+        let source = "*.rs @alice\n*.md @bob\n";
+        let fixes = vec![
+            Fix::replace(Span::new(17, 2, 6, 4), "@org/b"),
+            Fix::replace(Span::new(5, 1, 6, 6), "@org/a"),
+            Fix::replace(Span::new(3, 1, 4, 4), "xx"),
+        ];
+        let applicable = applicable_fixes(source, &fixes);
+        assert_eq!(applicable, [&fixes[1], &fixes[0]]);
+        assert_eq!(
+            applicable[0].describe(source),
+            "Replace '@alice' with '@org/a'"
+        );
+    }
+
+    #[test]
+    fn enclosing_fix_supersedes() {
+        let source = "/gone/ @Alice\n* @alice\n";
+        let fixes = vec![
+            Fix::replace(Span::new(0, 1, 1, 14), ""),
+            Fix::replace(Span::new(7, 1, 8, 6), "@alice"),
+        ];
+        assert_eq!(apply_fixes(source, &fixes), "* @alice\n");
+    }
+
+    #[test]
+    fn line_removal_spans() {
+        let source = "* @a\n/docs/ @b\n/api/ @c";
+        let file = crate::parse::parse_codeowners(source).ast;
+        let remove = |index| apply_fixes(source, &[Fix::replace(line_removal(&file, index), "")]);
+
+        assert_eq!(remove(0), "/docs/ @b\n/api/ @c");
+        assert_eq!(remove(1), "* @a\n/api/ @c");
+        assert_eq!(remove(2), "* @a\n/docs/ @b");
+        assert_eq!(
+            Fix::replace(line_removal(&file, 1), "").describe(source),
+            "Remove '/docs/ @b'"
+        );
+    }
+
+    #[test]
+    fn out_of_bounds_fix_skipped() {
+        let source = "*.rs @alice\n";
+        let fix = Fix::replace(Span::new(50, 1, 51, 6), "@org/team");
+        assert_eq!(apply_fixes(source, &[fix]), source);
+    }
+}
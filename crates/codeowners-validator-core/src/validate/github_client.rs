@@ -5,12 +5,15 @@
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt;
+use std::sync::{Mutex, MutexGuard};
 use thiserror::Error;
 
 /// The result of checking if a team exists.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
+#[non_exhaustive]
 pub enum TeamExistsResult {
     /// The team exists and is accessible.
     Exists,
@@ -33,6 +36,7 @@ impl fmt::Display for TeamExistsResult {
 /// The result of checking if a user exists.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
+#[non_exhaustive]
 pub enum UserExistsResult {
     /// The user exists.
     Exists,
@@ -54,6 +58,7 @@ impl fmt::Display for UserExistsResult {
 
 /// Errors that can occur when interacting with the GitHub client.
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum GithubClientError {
     /// An API error occurred.
     #[error("GitHub API error: {0}")]
@@ -71,11 +76,35 @@ pub enum GithubClientError {
     #[error("Rate limit exceeded")]
     RateLimitExceeded,
 
+    /// The client implementation raised an exception while handling a call.
+    ///
+    /// Used by foreign-language clients (such as Python) so the original
+    /// exception and its traceback are kept for diagnostics.
+    #[error("{method}() raised {exception}")]
+    Callback {
+        /// The client method that failed.
+        method: String,
+        /// The exception type and message.
+        exception: String,
+        /// The formatted traceback, if one was available.
+        traceback: Option<String>,
+    },
+
     /// Other error.
     #[error("{0}")]
     Other(String),
 }
 
+impl GithubClientError {
+    /// Returns the traceback captured from the client, if any.
+    pub fn traceback(&self) -> Option<&str> {
+        match self {
+            GithubClientError::Callback { traceback, .. } => traceback.as_deref(),
+            _ => None,
+        }
+    }
+}
+
 /// Trait for GitHub API client implementations.
 ///
 /// This trait abstracts the GitHub API calls needed for owner validation,
@@ -137,6 +166,237 @@ pub trait GithubClient: Send + Sync {
         org: &str,
         team: &str,
     ) -> Result<TeamExistsResult, GithubClientError>;
+
+    /// Returns the number of members of a team, including members of child
+    /// teams.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(count))` - The team has `count` members
+    /// * `Ok(None)` - The membership could not be determined, e.g. because the
+    ///   client does not support it or lacks permissions
+    /// * `Err(GithubClientError)` - An error occurred
+    ///
+    /// The default implementation returns `Ok(None)`.
+    async fn team_member_count(
+        &self,
+        org: &str,
+        team: &str,
+    ) -> Result<Option<usize>, GithubClientError> {
+        let _ = (org, team);
+        Ok(None)
+    }
+
+    /// Returns the logins of a team's members, including members of child
+    /// teams, without the leading '@'.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(logins))` - The team's members
+    /// * `Ok(None)` - The membership could not be determined, e.g. because the
+    ///   client does not support it or lacks permissions
+    /// * `Err(GithubClientError)` - An error occurred
+    ///
+    /// The default implementation returns `Ok(None)`.
+    async fn team_members(
+        &self,
+        org: &str,
+        team: &str,
+    ) -> Result<Option<Vec<String>>, GithubClientError> {
+        let _ = (org, team);
+        Ok(None)
+    }
+
+    /// Returns the slug of a team's parent team, for nested teams.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(parent))` - The team is nested under `parent`, in the same
+    ///   organization
+    /// * `Ok(None)` - The team has no parent, or the client cannot tell
+    /// * `Err(GithubClientError)` - An error occurred
+    ///
+    /// The default implementation returns `Ok(None)`.
+    async fn team_parent(
+        &self,
+        org: &str,
+        team: &str,
+    ) -> Result<Option<String>, GithubClientError> {
+        let _ = (org, team);
+        Ok(None)
+    }
+}
+
+#[async_trait]
+impl<C: GithubClient + ?Sized> GithubClient for Box<C> {
+    async fn user_exists(&self, username: &str) -> Result<UserExistsResult, GithubClientError> {
+        (**self).user_exists(username).await
+    }
+
+    async fn team_exists(
+        &self,
+        org: &str,
+        team: &str,
+    ) -> Result<TeamExistsResult, GithubClientError> {
+        (**self).team_exists(org, team).await
+    }
+
+    async fn team_member_count(
+        &self,
+        org: &str,
+        team: &str,
+    ) -> Result<Option<usize>, GithubClientError> {
+        (**self).team_member_count(org, team).await
+    }
+
+    async fn team_members(
+        &self,
+        org: &str,
+        team: &str,
+    ) -> Result<Option<Vec<String>>, GithubClientError> {
+        (**self).team_members(org, team).await
+    }
+
+    async fn team_parent(
+        &self,
+        org: &str,
+        team: &str,
+    ) -> Result<Option<String>, GithubClientError> {
+        (**self).team_parent(org, team).await
+    }
+}
+
+/// A [`GithubClient`] wrapper that remembers lookup results.
+///
+/// Long-lived callers validating many CODEOWNERS revisions can keep one
+/// caching client so each user and team is only looked up once. Lookups are
+/// keyed case-insensitively, matching GitHub. Errors are never cached.
+pub struct CachingGithubClient<C> {
+    inner: C,
+    users: Mutex<HashMap<String, UserExistsResult>>,
+    teams: TeamCache<TeamExistsResult>,
+    members: TeamCache<Option<usize>>,
+    member_lists: TeamCache<Option<Vec<String>>>,
+    parents: TeamCache<Option<String>>,
+}
+
+/// Cached team lookups, keyed by lowercased organization and team.
+type TeamCache<T> = Mutex<HashMap<(String, String), T>>;
+
+impl<C: GithubClient> CachingGithubClient<C> {
+    /// Wraps a client with an empty cache.
+    pub fn new(inner: C) -> Self {
+        Self {
+            inner,
+            users: Mutex::new(HashMap::new()),
+            teams: Mutex::new(HashMap::new()),
+            members: Mutex::new(HashMap::new()),
+            member_lists: Mutex::new(HashMap::new()),
+            parents: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the wrapped client.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Drops all cached lookups.
+    pub fn clear(&self) {
+        lock(&self.users).clear();
+        lock(&self.teams).clear();
+        lock(&self.members).clear();
+        lock(&self.member_lists).clear();
+        lock(&self.parents).clear();
+    }
+}
+
+/// Locks a cache, recovering the data if another thread panicked.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[async_trait]
+impl<C: GithubClient> GithubClient for CachingGithubClient<C> {
+    async fn user_exists(&self, username: &str) -> Result<UserExistsResult, GithubClientError> {
+        let key = username.to_lowercase();
+        if let Some(result) = lock(&self.users).get(&key) {
+            return Ok(*result);
+        }
+        let result = self.inner.user_exists(username).await?;
+        lock(&self.users).insert(key, result);
+        Ok(result)
+    }
+
+    async fn team_exists(
+        &self,
+        org: &str,
+        team: &str,
+    ) -> Result<TeamExistsResult, GithubClientError> {
+        let key = (org.to_lowercase(), team.to_lowercase());
+        if let Some(result) = lock(&self.teams).get(&key) {
+            return Ok(*result);
+        }
+        let result = self.inner.team_exists(org, team).await?;
+        lock(&self.teams).insert(key, result);
+        Ok(result)
+    }
+
+    async fn team_member_count(
+        &self,
+        org: &str,
+        team: &str,
+    ) -> Result<Option<usize>, GithubClientError> {
+        let key = (org.to_lowercase(), team.to_lowercase());
+        if let Some(result) = lock(&self.members).get(&key) {
+            return Ok(*result);
+        }
+        let result = self.inner.team_member_count(org, team).await?;
+        lock(&self.members).insert(key, result);
+        Ok(result)
+    }
+
+    async fn team_members(
+        &self,
+        org: &str,
+        team: &str,
+    ) -> Result<Option<Vec<String>>, GithubClientError> {
+        let key = (org.to_lowercase(), team.to_lowercase());
+        if let Some(result) = lock(&self.member_lists).get(&key) {
+            return Ok(result.clone());
+        }
+        let result = self.inner.team_members(org, team).await?;
+        lock(&self.member_lists).insert(key, result.clone());
+        Ok(result)
+    }
+
+    async fn team_parent(
+        &self,
+        org: &str,
+        team: &str,
+    ) -> Result<Option<String>, GithubClientError> {
+        let key = (org.to_lowercase(), team.to_lowercase());
+        if let Some(result) = lock(&self.parents).get(&key) {
+            return Ok(result.clone());
+        }
+        let result = self.inner.team_parent(org, team).await?;
+        lock(&self.parents).insert(key, result.clone());
+        Ok(result)
+    }
+}
+
+impl<C> fmt::Debug for CachingGithubClient<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachingGithubClient")
+            .field("users", &lock(&self.users).len())
+            .field("teams", &lock(&self.teams).len())
+            .field("members", &lock(&self.members).len())
+            .field("member_lists", &lock(&self.member_lists).len())
+            .field("parents", &lock(&self.parents).len())
+            .finish_non_exhaustive()
+    }
 }
 
 #[cfg(test)]
@@ -162,4 +422,122 @@ mod tests {
         let err = GithubClientError::ApiError("test error".to_string());
         assert!(err.to_string().contains("test error"));
     }
+
+    #[test]
+    fn callback_error_keeps_traceback() {
+        let err = GithubClientError::Callback {
+            method: "user_exists".to_string(),
+            exception: "ValueError: boom".to_string(),
+            traceback: Some("Traceback (most recent call last):\n".to_string()),
+        };
+        assert_eq!(err.to_string(), "user_exists() raised ValueError: boom");
+        assert!(err.traceback().unwrap().starts_with("Traceback"));
+        assert!(GithubClientError::RateLimitExceeded.traceback().is_none());
+    }
+
+    /// Counts calls and fails for the user named "flaky".
+    #[derive(Default)]
+    struct CountingClient {
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait]
+    impl GithubClient for CountingClient {
+        async fn user_exists(&self, username: &str) -> Result<UserExistsResult, GithubClientError> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if username == "flaky" {
+                Err(GithubClientError::RateLimitExceeded)
+            } else {
+                Ok(UserExistsResult::Exists)
+            }
+        }
+
+        async fn team_exists(
+            &self,
+            _org: &str,
+            _team: &str,
+        ) -> Result<TeamExistsResult, GithubClientError> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(TeamExistsResult::NotFound)
+        }
+
+        async fn team_member_count(
+            &self,
+            _org: &str,
+            _team: &str,
+        ) -> Result<Option<usize>, GithubClientError> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(Some(0))
+        }
+
+        async fn team_members(
+            &self,
+            _org: &str,
+            _team: &str,
+        ) -> Result<Option<Vec<String>>, GithubClientError> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(Some(vec!["alice".to_string()]))
+        }
+
+        async fn team_parent(
+            &self,
+            _org: &str,
+            _team: &str,
+        ) -> Result<Option<String>, GithubClientError> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(Some("eng".to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn caching_client_reuses_results() {
+        let client = CachingGithubClient::new(CountingClient::default());
+        let calls = || {
+            client
+                .inner()
+                .calls
+                .load(std::sync::atomic::Ordering::SeqCst)
+        };
+
+        assert_eq!(
+            client.user_exists("Alice").await.unwrap(),
+            UserExistsResult::Exists
+        );
+        assert_eq!(
+            client.user_exists("alice").await.unwrap(),
+            UserExistsResult::Exists
+        );
+        assert_eq!(
+            client.team_exists("org", "t").await.unwrap(),
+            TeamExistsResult::NotFound
+        );
+        assert_eq!(
+            client.team_exists("Org", "T").await.unwrap(),
+            TeamExistsResult::NotFound
+        );
+        assert_eq!(calls(), 2);
+
+        // Errors are retried on the next lookup
+        assert!(client.user_exists("flaky").await.is_err());
+        assert!(client.user_exists("flaky").await.is_err());
+        assert_eq!(calls(), 4);
+
+        assert_eq!(client.team_member_count("org", "t").await.unwrap(), Some(0));
+        assert_eq!(client.team_member_count("ORG", "t").await.unwrap(), Some(0));
+        assert_eq!(calls(), 5);
+
+        let parent = Some("eng".to_string());
+        assert_eq!(client.team_parent("org", "core").await.unwrap(), parent);
+        assert_eq!(client.team_parent("org", "Core").await.unwrap(), parent);
+        assert_eq!(calls(), 6);
+
+        let members = Some(vec!["alice".to_string()]);
+        assert_eq!(client.team_members("org", "core").await.unwrap(), members);
+        assert_eq!(client.team_members("ORG", "core").await.unwrap(), members);
+        assert_eq!(calls(), 7);
+
+        client.clear();
+        client.user_exists("alice").await.unwrap();
+        assert_eq!(calls(), 8);
+    }
 }
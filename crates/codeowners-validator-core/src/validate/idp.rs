@@ -0,0 +1,293 @@
+//! Identity provider group exports.
+//!
+//! Organizations that sync GitHub teams from an identity provider (IdP) can
+//! export the IdP groups and check CODEOWNERS teams against them offline.
+//! Two export formats are supported:
+//!
+//! - **JSON**: a SCIM `ListResponse` (`{"Resources": [...]}`) or a bare array
+//!   of SCIM Group resources. Each group needs a `displayName`; `members` and
+//!   the non-standard `active` flag are optional.
+//! - **CSV**: a header row with a `group` column and optional `member` and
+//!   `active` columns, and one row per group membership.
+
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+use thiserror::Error;
+
+/// Errors that can occur while reading an IdP export.
+#[derive(Debug, Error)]
+pub enum IdpError {
+    /// The JSON export could not be parsed.
+    #[error("invalid JSON group export: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// The export does not have the expected structure.
+    #[error("invalid group export: {0}")]
+    Format(String),
+}
+
+/// A group exported from the identity provider.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdpGroup {
+    /// The group's display name.
+    pub name: String,
+    /// Whether the group is active.
+    pub active: bool,
+    /// The number of members in the group.
+    pub members: usize,
+}
+
+/// The groups of an identity provider export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdpDirectory {
+    /// Groups keyed by lowercased name.
+    groups: HashMap<String, IdpGroup>,
+    /// Minimum number of members a group needs to back an owner team.
+    min_members: usize,
+}
+
+impl IdpDirectory {
+    /// Creates a directory from a list of groups.
+    ///
+    /// By default a group needs at least one member.
+    pub fn new(groups: impl IntoIterator<Item = IdpGroup>) -> Self {
+        Self {
+            groups: groups
+                .into_iter()
+                .map(|group| (group.name.to_lowercase(), group))
+                .collect(),
+            min_members: 1,
+        }
+    }
+
+    /// Sets the minimum number of members a group needs.
+    pub fn with_min_members(mut self, min_members: usize) -> Self {
+        self.min_members = min_members;
+        self
+    }
+
+    /// Returns the minimum number of members a group needs.
+    pub fn min_members(&self) -> usize {
+        self.min_members
+    }
+
+    /// Returns the number of groups.
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Returns true if the directory has no groups.
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Parses a SCIM JSON group export.
+    pub fn from_json(content: &str) -> Result<Self, IdpError> {
+        let value: Value = serde_json::from_str(content)?;
+        let resources = match &value {
+            Value::Array(resources) => resources,
+            Value::Object(object) => object
+                .get("Resources")
+                .and_then(Value::as_array)
+                .ok_or_else(|| IdpError::Format("missing 'Resources' array".to_string()))?,
+            _ => {
+                return Err(IdpError::Format(
+                    "expected an object or an array of groups".to_string(),
+                ));
+            }
+        };
+
+        let groups = resources
+            .iter()
+            .enumerate()
+            .map(|(index, resource)| {
+                let name = resource
+                    .get("displayName")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| {
+                        IdpError::Format(format!("group {} has no 'displayName'", index + 1))
+                    })?;
+                Ok(IdpGroup {
+                    name: name.to_string(),
+                    active: resource
+                        .get("active")
+                        .and_then(Value::as_bool)
+                        .unwrap_or(true),
+                    members: resource
+                        .get("members")
+                        .and_then(Value::as_array)
+                        .map_or(0, Vec::len),
+                })
+            })
+            .collect::<Result<Vec<_>, IdpError>>()?;
+
+        Ok(Self::new(groups))
+    }
+
+    /// Parses a CSV group export.
+    pub fn from_csv(content: &str) -> Result<Self, IdpError> {
+        let mut rows = content.lines().filter(|line| !line.trim().is_empty());
+        let header = split_csv_row(
+            rows.next()
+                .ok_or_else(|| IdpError::Format("missing CSV header".to_string()))?,
+        );
+        let column = |name: &str| {
+            header
+                .iter()
+                .position(|column| column.eq_ignore_ascii_case(name))
+        };
+        let group_column = column("group")
+            .ok_or_else(|| IdpError::Format("missing 'group' column".to_string()))?;
+        let member_column = column("member");
+        let active_column = column("active");
+
+        // Group name -> (active, distinct members), in first-seen order
+        let mut order: Vec<String> = Vec::new();
+        let mut seen: HashMap<String, (bool, BTreeSet<String>)> = HashMap::new();
+
+        for (index, row) in rows.enumerate() {
+            let fields = split_csv_row(row);
+            let field =
+                |column: Option<usize>| column.and_then(|c| fields.get(c)).map(|f| f.trim());
+
+            let name = field(Some(group_column)).unwrap_or_default();
+            if name.is_empty() {
+                return Err(IdpError::Format(format!(
+                    "row {} has an empty 'group' field",
+                    index + 2
+                )));
+            }
+
+            let entry = seen.entry(name.to_string()).or_insert_with(|| {
+                order.push(name.to_string());
+                (true, BTreeSet::new())
+            });
+            if let Some(active) = field(active_column).filter(|value| !value.is_empty()) {
+                entry.0 &= parse_bool(active).ok_or_else(|| {
+                    IdpError::Format(format!(
+                        "row {} has an invalid 'active' value '{}'",
+                        index + 2,
+                        active
+                    ))
+                })?;
+            }
+            if let Some(member) = field(member_column).filter(|value| !value.is_empty()) {
+                entry.1.insert(member.to_string());
+            }
+        }
+
+        Ok(Self::new(order.into_iter().map(|name| {
+            let (active, members) = seen.remove(&name).unwrap_or_default();
+            IdpGroup {
+                name,
+                active,
+                members: members.len(),
+            }
+        })))
+    }
+
+    /// Finds the group backing a GitHub team.
+    ///
+    /// The group name is compared case-insensitively with the team slug and
+    /// with the `org/team` form.
+    pub fn group_for_team(&self, org: &str, team: &str) -> Option<&IdpGroup> {
+        self.groups
+            .get(&team.to_lowercase())
+            .or_else(|| self.groups.get(&format!("{}/{}", org, team).to_lowercase()))
+    }
+}
+
+/// Splits a CSV row into fields, honoring double-quoted fields.
+fn split_csv_row(row: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = row.trim_end_matches('\r').chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+/// Parses a CSV boolean.
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.to_ascii_lowercase().as_str() {
+        "true" | "yes" | "1" => Some(true),
+        "false" | "no" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_scim_list_response() {
+        let directory = IdpDirectory::from_json(
+            r#"{
+                "schemas": ["urn:ietf:params:scim:api:messages:2.0:ListResponse"],
+                "Resources": [
+                    {"displayName": "Platform", "members": [{"value": "1"}, {"value": "2"}]},
+                    {"displayName": "legacy", "active": false, "members": []}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(directory.len(), 2);
+        let platform = directory.group_for_team("org", "platform").unwrap();
+        assert_eq!(platform.members, 2);
+        assert!(platform.active);
+        assert!(!directory.group_for_team("org", "legacy").unwrap().active);
+        assert!(directory.group_for_team("org", "missing").is_none());
+    }
+
+    #[test]
+    fn parse_scim_array_and_errors() {
+        let directory = IdpDirectory::from_json(r#"[{"displayName": "org/core"}]"#).unwrap();
+        assert_eq!(directory.group_for_team("Org", "Core").unwrap().members, 0);
+
+        assert!(matches!(
+            IdpDirectory::from_json(r#"[{"id": "1"}]"#),
+            Err(IdpError::Format(_))
+        ));
+        assert!(matches!(
+            IdpDirectory::from_json("not json"),
+            Err(IdpError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn parse_csv_export() {
+        let directory = IdpDirectory::from_csv(
+            "Group,Member,Active\n\
+             platform,alice,true\n\
+             platform,bob,true\n\
+             platform,bob,true\n\
+             \"search, legacy\",,no\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            directory.group_for_team("org", "platform").unwrap().members,
+            2
+        );
+        let legacy = directory.group_for_team("org", "search, legacy").unwrap();
+        assert!(!legacy.active);
+        assert_eq!(legacy.members, 0);
+
+        assert!(IdpDirectory::from_csv("member\nalice\n").is_err());
+        assert!(IdpDirectory::from_csv("group,active\nx,maybe\n").is_err());
+    }
+}
@@ -0,0 +1,235 @@
+//! Owners lockfiles.
+//!
+//! Security teams often need to know when the owners of critical paths,
+//! such as deployment workflows or the CODEOWNERS file itself, change. An
+//! owners lockfile pins the owners of designated paths, so the `owners-lock`
+//! check fails when CODEOWNERS assigns them different owners:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "paths": [
+//!     { "path": ".github/workflows/deploy.yml", "owners": ["@acme/security"] },
+//!     { "path": "infra/prod.tf", "owners": ["@acme/security", "@acme/sre"] }
+//!   ]
+//! }
+//! ```
+//!
+//! A change that moves ownership of a pinned path must update the lockfile
+//! too, which makes it visible in review; owning the lockfile with the
+//! security team makes the control tamper-evident. Paths are files relative
+//! to the repository root. An entry without `owners` pins the path as
+//! unowned until [`OwnersLock::update`] fills in its current owners.
+
+use crate::matching::OwnersResolver;
+use crate::parse::Owner;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// The conventional name of an owners lockfile, at the repository root.
+pub const LOCKFILE_NAME: &str = "codeowners.lock";
+
+/// The lockfile format version written by this crate.
+pub const LOCKFILE_VERSION: u32 = 1;
+
+/// Errors that can occur while reading an owners lockfile.
+#[derive(Debug, Error)]
+pub enum LockfileError {
+    /// The lockfile could not be parsed.
+    #[error("invalid JSON owners lockfile: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// The lockfile was written in an unsupported format version.
+    #[error("unsupported owners lockfile version {0}, expected {LOCKFILE_VERSION}")]
+    Version(u32),
+
+    /// The lockfile has an invalid entry.
+    #[error("invalid owners lockfile: {0}")]
+    Format(String),
+}
+
+/// A path and the owners pinned for it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockEntry {
+    /// The file path, relative to the repository root.
+    pub path: String,
+    /// The pinned owners, lowercased and sorted.
+    #[serde(default)]
+    pub owners: Vec<String>,
+}
+
+impl LockEntry {
+    /// Creates an entry pinning `owners` for `path`.
+    pub fn new(path: impl Into<String>, owners: impl IntoIterator<Item = String>) -> Self {
+        let mut owners: Vec<String> = owners.into_iter().map(|o| o.to_lowercase()).collect();
+        owners.sort_unstable();
+        owners.dedup();
+        Self {
+            path: path.into(),
+            owners,
+        }
+    }
+
+    /// Returns the path without a leading slash.
+    pub fn relative_path(&self) -> &str {
+        self.path.trim_start_matches('/')
+    }
+}
+
+/// The serialized form of a lockfile.
+#[derive(Serialize, Deserialize)]
+struct LockFile {
+    version: u32,
+    paths: Vec<LockEntry>,
+}
+
+/// The pinned owners of critical paths, read from an owners lockfile.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OwnersLock {
+    entries: Vec<LockEntry>,
+}
+
+impl OwnersLock {
+    /// Creates a lock from a list of entries.
+    pub fn new(entries: impl IntoIterator<Item = LockEntry>) -> Self {
+        Self {
+            entries: entries
+                .into_iter()
+                .map(|entry| LockEntry::new(entry.path, entry.owners))
+                .collect(),
+        }
+    }
+
+    /// Parses a JSON owners lockfile.
+    pub fn from_json(content: &str) -> Result<Self, LockfileError> {
+        let file: LockFile = serde_json::from_str(content)?;
+        if file.version != LOCKFILE_VERSION {
+            return Err(LockfileError::Version(file.version));
+        }
+
+        for (index, entry) in file.paths.iter().enumerate() {
+            let number = index + 1;
+            if entry.relative_path().is_empty() {
+                return Err(LockfileError::Format(format!(
+                    "entry {} has no path",
+                    number
+                )));
+            }
+            if entry.path.ends_with('/') {
+                return Err(LockfileError::Format(format!(
+                    "entry {} ('{}') is a directory, but only files can be pinned",
+                    number, entry.path
+                )));
+            }
+        }
+
+        Ok(Self::new(file.paths))
+    }
+
+    /// Serializes the lock as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        let file = LockFile {
+            version: LOCKFILE_VERSION,
+            paths: self.entries.clone(),
+        };
+        let mut json = serde_json::to_string_pretty(&file).expect("lockfile is valid JSON");
+        json.push('\n');
+        json
+    }
+
+    /// Pins every path to the owners `resolver` currently assigns it, and
+    /// returns how many entries changed.
+    pub fn update(&mut self, resolver: &OwnersResolver) -> usize {
+        let mut changed = 0;
+        for entry in &mut self.entries {
+            let owners = resolver
+                .owners_for(entry.relative_path())
+                .unwrap_or_default()
+                .iter()
+                .map(Owner::normalized);
+            let updated = LockEntry::new(entry.path.clone(), owners);
+            if *entry != updated {
+                *entry = updated;
+                changed += 1;
+            }
+        }
+        changed
+    }
+
+    /// Returns the entries, in lockfile order.
+    pub fn entries(&self) -> &[LockEntry] {
+        &self.entries
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the lock has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_codeowners;
+
+    #[test]
+    fn json_round_trip() {
+        let lock = OwnersLock::from_json(
+            r#"{"version": 1, "paths": [
+                {"path": "/infra/prod.tf", "owners": ["@Acme/SRE", "@acme/security"]},
+                {"path": "deploy.yml"}
+            ]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(lock.len(), 2);
+        assert_eq!(lock.entries()[0].relative_path(), "infra/prod.tf");
+        assert_eq!(lock.entries()[0].owners, ["@acme/security", "@acme/sre"]);
+        assert!(lock.entries()[1].owners.is_empty());
+        assert_eq!(OwnersLock::from_json(&lock.to_json()).unwrap(), lock);
+    }
+
+    #[test]
+    fn rejects_invalid_lockfiles() {
+        for (content, message) in [
+            (
+                r#"{"version": 2, "paths": []}"#,
+                "unsupported owners lockfile version 2",
+            ),
+            (
+                r#"{"version": 1, "paths": [{"path": "/"}]}"#,
+                "entry 1 has no path",
+            ),
+            (
+                r#"{"version": 1, "paths": [{"path": "infra/"}]}"#,
+                "only files can be pinned",
+            ),
+        ] {
+            let error = OwnersLock::from_json(content).unwrap_err();
+            assert!(error.to_string().contains(message), "{error}");
+        }
+        assert!(matches!(
+            OwnersLock::from_json("[]"),
+            Err(LockfileError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn update_pins_current_owners() {
+        let file = parse_codeowners("* @acme/core\n/infra/ @Acme/SRE\n").ast;
+        let resolver = OwnersResolver::from_file(&file);
+        let mut lock = OwnersLock::new([
+            LockEntry::new("infra/prod.tf", Vec::new()),
+            LockEntry::new("README.md", ["@acme/core".to_string()]),
+        ]);
+
+        assert_eq!(lock.update(&resolver), 1);
+        assert_eq!(lock.entries()[0].owners, ["@acme/sre"]);
+        assert_eq!(lock.update(&resolver), 0);
+    }
+}
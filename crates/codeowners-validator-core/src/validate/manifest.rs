@@ -0,0 +1,196 @@
+//! Ownership manifests.
+//!
+//! Compliance regimes often require that specific paths are owned by exactly
+//! the teams responsible for responding to changes there, within a response
+//! SLA. An ownership manifest declares these requirements in JSON, so the
+//! `ownership-manifest` check can verify CODEOWNERS against them:
+//!
+//! ```json
+//! {
+//!   "paths": [
+//!     { "path": "/services/payments/", "teams": ["@acme/payments"], "sla": "4h" },
+//!     { "path": "/infra/prod.tf", "teams": ["@acme/sre", "@acme/security"] }
+//!   ]
+//! }
+//! ```
+//!
+//! A bare array of entries is accepted too. Paths are relative to the
+//! repository root; a trailing slash declares a directory, covering every
+//! file in it. The optional `sla` is a positive number followed by `m`, `h`,
+//! `d` or `w` (minutes, hours, days, weeks).
+
+use serde::Deserialize;
+use thiserror::Error;
+
+/// Errors that can occur while reading an ownership manifest.
+#[derive(Debug, Error)]
+pub enum ManifestError {
+    /// The manifest could not be parsed.
+    #[error("invalid JSON ownership manifest: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// The manifest has an invalid entry.
+    #[error("invalid ownership manifest: {0}")]
+    Format(String),
+}
+
+/// A path and the teams that must own it.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ManifestEntry {
+    /// The path, relative to the repository root. A trailing slash declares
+    /// a directory.
+    pub path: String,
+    /// The owners the path must have, e.g. `@org/team`.
+    pub teams: Vec<String>,
+    /// The response SLA, e.g. `4h`.
+    #[serde(default)]
+    pub sla: Option<String>,
+}
+
+impl ManifestEntry {
+    /// Returns the path without a leading slash.
+    pub fn relative_path(&self) -> &str {
+        self.path.trim_start_matches('/')
+    }
+
+    /// Returns true if the entry declares a directory.
+    pub fn is_directory(&self) -> bool {
+        self.path.ends_with('/')
+    }
+}
+
+/// The shapes a manifest file may take.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ManifestFile {
+    Object { paths: Vec<ManifestEntry> },
+    Array(Vec<ManifestEntry>),
+}
+
+/// The declared owners of paths, read from an ownership manifest.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OwnershipManifest {
+    entries: Vec<ManifestEntry>,
+}
+
+impl OwnershipManifest {
+    /// Creates a manifest from a list of entries.
+    pub fn new(entries: impl IntoIterator<Item = ManifestEntry>) -> Self {
+        Self {
+            entries: entries.into_iter().collect(),
+        }
+    }
+
+    /// Parses a JSON ownership manifest.
+    pub fn from_json(content: &str) -> Result<Self, ManifestError> {
+        let entries = match serde_json::from_str(content)? {
+            ManifestFile::Object { paths } => paths,
+            ManifestFile::Array(entries) => entries,
+        };
+
+        for (index, entry) in entries.iter().enumerate() {
+            let number = index + 1;
+            if entry.relative_path().is_empty() {
+                return Err(ManifestError::Format(format!(
+                    "entry {} has no path",
+                    number
+                )));
+            }
+            if entry.teams.is_empty() {
+                return Err(ManifestError::Format(format!(
+                    "entry {} ('{}') has no teams",
+                    number, entry.path
+                )));
+            }
+            if let Some(sla) = &entry.sla
+                && !is_valid_sla(sla)
+            {
+                return Err(ManifestError::Format(format!(
+                    "entry {} ('{}') has invalid SLA '{}', expected e.g. '30m', '4h' or '2d'",
+                    number, entry.path, sla
+                )));
+            }
+        }
+
+        Ok(Self::new(entries))
+    }
+
+    /// Returns the entries, in manifest order.
+    pub fn entries(&self) -> &[ManifestEntry] {
+        &self.entries
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the manifest has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Returns true if an SLA is a positive number followed by a unit.
+fn is_valid_sla(sla: &str) -> bool {
+    let Some(amount) = sla.strip_suffix(['m', 'h', 'd', 'w']) else {
+        return false;
+    };
+    amount.parse::<u32>().is_ok_and(|amount| amount > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_object_and_array() {
+        let manifest = OwnershipManifest::from_json(
+            r#"{"paths": [
+                {"path": "/services/payments/", "teams": ["@acme/payments"], "sla": "4h"},
+                {"path": "infra/prod.tf", "teams": ["@acme/sre", "@acme/security"]}
+            ]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(manifest.len(), 2);
+        let payments = &manifest.entries()[0];
+        assert_eq!(payments.relative_path(), "services/payments/");
+        assert!(payments.is_directory());
+        assert_eq!(payments.sla.as_deref(), Some("4h"));
+        assert!(!manifest.entries()[1].is_directory());
+
+        let manifest =
+            OwnershipManifest::from_json(r#"[{"path": "/a", "teams": ["@a"]}]"#).unwrap();
+        assert_eq!(manifest.entries()[0].sla, None);
+    }
+
+    #[test]
+    fn rejects_invalid_entries() {
+        for (content, message) in [
+            (r#"[{"path": "/", "teams": ["@a"]}]"#, "entry 1 has no path"),
+            (r#"[{"path": "/a", "teams": []}]"#, "has no teams"),
+            (
+                r#"[{"path": "/a", "teams": ["@a"], "sla": "soon"}]"#,
+                "invalid SLA 'soon'",
+            ),
+        ] {
+            let error = OwnershipManifest::from_json(content).unwrap_err();
+            assert!(error.to_string().contains(message), "{error}");
+        }
+
+        assert!(matches!(
+            OwnershipManifest::from_json(r#"{"entries": []}"#),
+            Err(ManifestError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn sla_format() {
+        assert!(is_valid_sla("30m"));
+        assert!(is_valid_sla("2w"));
+        assert!(!is_valid_sla("0h"));
+        assert!(!is_valid_sla("4"));
+        assert!(!is_valid_sla("h"));
+    }
+}
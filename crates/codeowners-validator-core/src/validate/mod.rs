@@ -24,15 +24,25 @@
 //! }
 //! ```
 
+pub mod baseline;
 pub mod checks;
+pub mod engine;
 mod error;
 pub mod file_walker;
+pub mod fix;
 pub mod github_client;
+pub mod idp;
+pub mod lockfile;
+pub mod manifest;
 mod syntax;
+#[cfg(feature = "watch")]
+pub mod watch;
 
 // Re-export public types
-pub use error::{Severity, ValidationError, ValidationResult};
+pub use engine::{SharedEngine, ValidationEngine};
+pub use error::{ERROR_CODES, Severity, UnsupportedSyntax, ValidationError, ValidationResult};
+pub use fix::{Fix, applicable_fixes, apply_fixes};
 pub use syntax::{
     validate_all_owners, validate_all_patterns, validate_owner_syntax, validate_pattern_syntax,
-    validate_syntax,
+    validate_syntax, validate_syntax_with,
 };
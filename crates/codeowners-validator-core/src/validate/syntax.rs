@@ -3,9 +3,11 @@
 //! This module implements validation rules for owner formats
 //! and pattern syntax.
 
-use super::error::{ValidationError, ValidationResult};
+use super::error::{UnsupportedSyntax, ValidationError, ValidationResult};
+use crate::matching::{self, PatternSyntax, QuestionMark};
 use crate::parse::span::Span;
 use crate::parse::{CodeownersFile, LineKind, Owner, Pattern};
+use std::collections::HashSet;
 
 /// Validates owner syntax according to GitHub CODEOWNERS rules.
 ///
@@ -18,6 +20,9 @@ pub fn validate_owner_syntax(owner: &Owner) -> Option<ValidationError> {
         Owner::User { name, span } => validate_username(name, span),
         Owner::Team { org, team, span } => validate_team(org, team, span),
         Owner::Email { email, span } => validate_email(email, span),
+        Owner::Invalid { raw, reason, span } => {
+            Some(ValidationError::invalid_owner_format(raw, reason, *span))
+        }
     }
 }
 
@@ -187,42 +192,43 @@ fn validate_email(email: &str, span: &Span) -> Option<ValidationError> {
 /// - `/` at start anchors to repo root
 /// - `/` at end matches only directories
 ///
-/// Not supported (will produce warnings):
+/// Not supported (will produce warnings, see [`UnsupportedSyntax`]):
 /// - `!` negation patterns
+/// - `\` escape sequences other than `\ ` (an escaped space), including a
+///   trailing `\`
 /// - `[abc]` character classes
-/// - `\` escape sequences
+///
+/// Where an equivalent pattern exists, the warning carries it as a fix.
 pub fn validate_pattern_syntax(pattern: &Pattern) -> Option<ValidationError> {
-    let text = &pattern.text;
-
-    // Check for negation (not supported)
-    if text.starts_with('!') {
-        return Some(ValidationError::unsupported_pattern_syntax(
-            text,
-            "negation patterns (!) are not supported in CODEOWNERS",
-            pattern.span,
-        ));
-    }
+    validate_pattern_syntax_with(pattern, PatternSyntax::GitHub)
+}
 
-    // Check for character classes (not supported)
-    if text.contains('[') || text.contains(']') {
-        return Some(ValidationError::unsupported_pattern_syntax(
-            text,
-            "character classes ([abc]) are not supported in CODEOWNERS",
-            pattern.span,
-        ));
-    }
+/// Validates pattern syntax, accepting what `pattern_syntax` allows.
+///
+/// With [`PatternSyntax::Extended`], well-formed character classes are
+/// accepted; unclosed brackets are still reported. [`PatternSyntax::GitLab`]
+/// also accepts `!pattern` exclusions, validating the excluded pattern.
+pub(crate) fn validate_pattern_syntax_with(
+    pattern: &Pattern,
+    pattern_syntax: PatternSyntax,
+) -> Option<ValidationError> {
+    let text = &pattern.text;
+    let (body, _) = pattern_syntax.split_negation(text);
 
-    // Check for escape sequences (not supported in the same way as gitignore)
-    if text.contains('\\') {
-        return Some(ValidationError::unsupported_pattern_syntax(
-            text,
-            "escape sequences (\\) are not supported in CODEOWNERS",
-            pattern.span,
-        ));
+    if let Some((syntax, suggestion)) = unsupported_syntax(text, true, pattern_syntax) {
+        return Some(match suggestion {
+            Some(suggestion) => ValidationError::unsupported_pattern_syntax_with_suggestion(
+                text,
+                syntax,
+                suggestion,
+                pattern.span,
+            ),
+            None => ValidationError::unsupported_pattern_syntax(text, syntax, pattern.span),
+        });
     }
 
     // Pattern cannot be empty
-    if text.is_empty() {
+    if body.is_empty() {
         return Some(ValidationError::invalid_pattern_syntax(
             text,
             "pattern cannot be empty",
@@ -231,7 +237,7 @@ pub fn validate_pattern_syntax(pattern: &Pattern) -> Option<ValidationError> {
     }
 
     // Pattern cannot be just whitespace
-    if text.trim().is_empty() {
+    if body.trim().is_empty() {
         return Some(ValidationError::invalid_pattern_syntax(
             text,
             "pattern cannot be only whitespace",
@@ -242,6 +248,100 @@ pub fn validate_pattern_syntax(pattern: &Pattern) -> Option<ValidationError> {
     None
 }
 
+/// Finds the first unsupported gitignore syntax in a pattern.
+///
+/// Returns the syntax and, if one exists, an equivalent pattern without it.
+fn unsupported_syntax(
+    text: &str,
+    allow_question_mark: bool,
+    pattern_syntax: PatternSyntax,
+) -> Option<(UnsupportedSyntax, Option<String>)> {
+    if text.starts_with('!') {
+        let (excluded, true) = pattern_syntax.split_negation(text) else {
+            return Some((UnsupportedSyntax::Negation, None));
+        };
+        return unsupported_syntax(excluded, allow_question_mark, pattern_syntax).map(
+            |(syntax, suggestion)| (syntax, suggestion.map(|pattern| format!("!{}", pattern))),
+        );
+    }
+
+    // Escaped spaces are how gitignore writes paths with spaces
+    if text.replace("\\ ", "").contains('\\') {
+        let syntax = if text.ends_with('\\') {
+            UnsupportedSyntax::TrailingBackslash
+        } else {
+            UnsupportedSyntax::Escape
+        };
+        return Some((syntax, backslashes_as_separators(text)));
+    }
+
+    if text.contains(['[', ']'])
+        && !(pattern_syntax.allows_character_classes() && matching::Pattern::new(text).is_some())
+    {
+        return Some((
+            UnsupportedSyntax::CharacterClass,
+            single_character_classes(text),
+        ));
+    }
+
+    if !allow_question_mark && text.contains('?') {
+        return Some((UnsupportedSyntax::QuestionMark, None));
+    }
+
+    None
+}
+
+/// Rewrites Windows-style `\` separators as `/`.
+///
+/// Returns `None` if any backslash escapes a character with special meaning,
+/// since there is no equivalent pattern for those.
+fn backslashes_as_separators(text: &str) -> Option<String> {
+    let mut chars = text.chars().peekable();
+    let mut rewritten = String::with_capacity(text.len());
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            rewritten.push(c);
+            continue;
+        }
+        match chars.peek() {
+            None => rewritten.push('/'),
+            Some(' ') => rewritten.push('\\'),
+            Some(next) if next.is_alphanumeric() || matches!(next, '.' | '_' | '-') => {
+                rewritten.push('/')
+            }
+            Some(_) => return None,
+        }
+    }
+
+    Some(rewritten)
+}
+
+/// Rewrites single-character classes such as `[.]` as the literal character.
+///
+/// Returns `None` if any class has more than one character, a range or a
+/// negation, or if the brackets are unbalanced.
+fn single_character_classes(text: &str) -> Option<String> {
+    let mut rewritten = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(open) = rest.find(['[', ']']) {
+        let mut class = rest[open..].chars();
+        let (Some('['), Some(c), Some(']')) = (class.next(), class.next(), class.next()) else {
+            return None;
+        };
+        if matches!(c, '!' | '^' | '-' | '[' | ']' | '*' | '?') {
+            return None;
+        }
+        rewritten.push_str(&rest[..open]);
+        rewritten.push(c);
+        rest = &rest[open + 2 + c.len_utf8()..];
+    }
+
+    rewritten.push_str(rest);
+    Some(rewritten)
+}
+
 /// Validates all owners in a CODEOWNERS file.
 pub fn validate_all_owners(file: &CodeownersFile) -> ValidationResult {
     let mut result = ValidationResult::new();
@@ -261,11 +361,20 @@ pub fn validate_all_owners(file: &CodeownersFile) -> ValidationResult {
 
 /// Validates all patterns in a CODEOWNERS file.
 pub fn validate_all_patterns(file: &CodeownersFile) -> ValidationResult {
+    validate_all_patterns_with(file, PatternSyntax::GitHub)
+}
+
+/// Validates all patterns in a CODEOWNERS file, accepting what
+/// `pattern_syntax` allows.
+pub(crate) fn validate_all_patterns_with(
+    file: &CodeownersFile,
+    pattern_syntax: PatternSyntax,
+) -> ValidationResult {
     let mut result = ValidationResult::new();
 
     for line in &file.lines {
         if let LineKind::Rule { pattern, .. } = &line.kind
-            && let Some(error) = validate_pattern_syntax(pattern)
+            && let Some(error) = validate_pattern_syntax_with(pattern, pattern_syntax)
         {
             result.add_error(error);
         }
@@ -274,10 +383,99 @@ pub fn validate_all_patterns(file: &CodeownersFile) -> ValidationResult {
     result
 }
 
+/// Applies the `?` policy to a CODEOWNERS file.
+///
+/// With [`QuestionMark::Unsupported`], patterns containing `?` produce
+/// warnings, unless the pattern is already reported for other unsupported
+/// syntax by [`validate_all_patterns`]. The other modes accept `?`.
+pub(crate) fn validate_question_mark_policy(
+    file: &CodeownersFile,
+    question_mark: QuestionMark,
+) -> ValidationResult {
+    let mut result = ValidationResult::new();
+
+    if question_mark != QuestionMark::Unsupported {
+        return result;
+    }
+
+    for line in &file.lines {
+        if let LineKind::Rule { pattern, .. } = &line.kind
+            && unsupported_syntax(&pattern.text, true, PatternSyntax::GitHub).is_none()
+            && let Some((syntax, _)) =
+                unsupported_syntax(&pattern.text, false, PatternSyntax::GitHub)
+        {
+            result.add_error(ValidationError::unsupported_pattern_syntax(
+                &pattern.text,
+                syntax,
+                pattern.span,
+            ));
+        }
+    }
+
+    result
+}
+
+/// Applies the email owner trust policy to a CODEOWNERS file.
+///
+/// When `reject_all` is set, every email owner is an error. Otherwise, if
+/// `trusted_domains` is non-empty, email owners whose domain is not in the
+/// list (compared case-insensitively) produce warnings. Malformed emails are
+/// skipped here since [`validate_all_owners`] already reports them.
+pub(crate) fn validate_email_policy(
+    file: &CodeownersFile,
+    trusted_domains: &HashSet<String>,
+    reject_all: bool,
+) -> ValidationResult {
+    let mut result = ValidationResult::new();
+
+    if !reject_all && trusted_domains.is_empty() {
+        return result;
+    }
+
+    for line in &file.lines {
+        if let LineKind::Rule { owners, .. } = &line.kind {
+            for owner in owners {
+                let Owner::Email { email, span } = owner else {
+                    continue;
+                };
+                if validate_email(email, span).is_some() {
+                    continue;
+                }
+
+                if reject_all {
+                    result.add_error(ValidationError::email_owner_not_allowed(email, *span));
+                    continue;
+                }
+
+                let domain = email.rsplit('@').next().unwrap_or_default();
+                if !trusted_domains
+                    .iter()
+                    .any(|trusted| trusted.eq_ignore_ascii_case(domain))
+                {
+                    result.add_error(ValidationError::untrusted_email_domain(
+                        email, domain, *span,
+                    ));
+                }
+            }
+        }
+    }
+
+    result
+}
+
 /// Performs all syntax validations on a CODEOWNERS file.
 pub fn validate_syntax(file: &CodeownersFile) -> ValidationResult {
+    validate_syntax_with(file, PatternSyntax::GitHub)
+}
+
+/// Performs all syntax validations on a CODEOWNERS file, accepting the
+/// patterns `pattern_syntax` allows.
+pub fn validate_syntax_with(
+    file: &CodeownersFile,
+    pattern_syntax: PatternSyntax,
+) -> ValidationResult {
     let mut result = validate_all_owners(file);
-    result.merge(validate_all_patterns(file));
+    result.merge(validate_all_patterns_with(file, pattern_syntax));
     result
 }
 
@@ -355,6 +553,13 @@ mod tests {
         assert!(err.unwrap().to_string().contains("39 characters"));
     }
 
+    #[test]
+    fn invalid_owner_reports_reason() {
+        let owner = Owner::invalid("@org/", "team name cannot be empty", test_span());
+        let err = validate_owner_syntax(&owner).unwrap();
+        assert!(err.to_string().contains("team name cannot be empty"));
+    }
+
     // Team validation tests
 
     #[test]
@@ -457,6 +662,20 @@ mod tests {
         assert!(validate_pattern_syntax(&pattern).is_none());
     }
 
+    #[test]
+    fn valid_pattern_escaped_space() {
+        let pattern = Pattern::new("docs/my\\ folder/", test_span());
+        assert!(validate_pattern_syntax(&pattern).is_none());
+
+        // Other escapes are still reported, keeping the escaped space in the fix
+        match validate_pattern_syntax(&Pattern::new("my\\ docs\\api", test_span())) {
+            Some(ValidationError::UnsupportedPatternSyntax { suggestion, .. }) => {
+                assert_eq!(suggestion.as_deref(), Some("my\\ docs/api"))
+            }
+            other => panic!("expected unsupported syntax, got {other:?}"),
+        }
+    }
+
     #[test]
     fn invalid_pattern_negation() {
         let pattern = Pattern::new("!*.log", test_span());
@@ -465,6 +684,27 @@ mod tests {
         assert!(err.unwrap().to_string().contains("negation"));
     }
 
+    #[test]
+    fn gitlab_syntax_accepts_negation() {
+        let validate = |text| {
+            validate_pattern_syntax_with(&Pattern::new(text, test_span()), PatternSyntax::GitLab)
+        };
+        assert!(validate("!/CHANGELOG.md").is_none());
+        assert!(validate("!*.[ch]").is_none());
+        assert!(
+            validate("!")
+                .unwrap()
+                .to_string()
+                .contains("pattern cannot be empty")
+        );
+        match validate("!docs\\") {
+            Some(ValidationError::UnsupportedPatternSyntax { suggestion, .. }) => {
+                assert_eq!(suggestion.as_deref(), Some("!docs/"))
+            }
+            other => panic!("expected unsupported syntax, got {other:?}"),
+        }
+    }
+
     #[test]
     fn invalid_pattern_character_class() {
         let pattern = Pattern::new("*.[ch]", test_span());
@@ -481,6 +721,94 @@ mod tests {
         assert!(err.unwrap().to_string().contains("escape"));
     }
 
+    fn unsupported(text: &str) -> (UnsupportedSyntax, Option<String>) {
+        match validate_pattern_syntax(&Pattern::new(text, test_span())) {
+            Some(ValidationError::UnsupportedPatternSyntax {
+                syntax, suggestion, ..
+            }) => (syntax, suggestion),
+            other => panic!("expected unsupported syntax for {text:?}, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn unsupported_syntax_codes_and_suggestions() {
+        assert_eq!(unsupported("!*.log"), (UnsupportedSyntax::Negation, None));
+        assert_eq!(
+            unsupported("docs\\"),
+            (UnsupportedSyntax::TrailingBackslash, Some("docs/".into()))
+        );
+        assert_eq!(
+            unsupported("src\\app\\main.rs"),
+            (UnsupportedSyntax::Escape, Some("src/app/main.rs".into()))
+        );
+        assert_eq!(unsupported("\\#file"), (UnsupportedSyntax::Escape, None));
+        assert_eq!(
+            unsupported("config[.]json"),
+            (
+                UnsupportedSyntax::CharacterClass,
+                Some("config.json".into())
+            )
+        );
+        assert_eq!(
+            unsupported("*.[ch]"),
+            (UnsupportedSyntax::CharacterClass, None)
+        );
+        assert_eq!(
+            unsupported("[!a]bc"),
+            (UnsupportedSyntax::CharacterClass, None)
+        );
+        assert_eq!(
+            unsupported("docs]"),
+            (UnsupportedSyntax::CharacterClass, None)
+        );
+    }
+
+    #[test]
+    fn extended_syntax_accepts_character_classes() {
+        let extended = |text| {
+            validate_pattern_syntax_with(&Pattern::new(text, test_span()), PatternSyntax::Extended)
+        };
+        assert!(extended("*.[ch]").is_none());
+        assert!(extended("[!a]bc").is_none());
+        assert!(matches!(
+            extended("docs/[abc"),
+            Some(ValidationError::UnsupportedPatternSyntax {
+                syntax: UnsupportedSyntax::CharacterClass,
+                ..
+            })
+        ));
+        assert!(extended("!*.[ch]").is_some());
+    }
+
+    #[test]
+    fn question_mark_depends_on_dialect_support() {
+        assert!(validate_pattern_syntax(&Pattern::new("file?.txt", test_span())).is_none());
+        assert_eq!(
+            unsupported_syntax("file?.txt", false, PatternSyntax::GitHub),
+            Some((UnsupportedSyntax::QuestionMark, None))
+        );
+    }
+
+    #[test]
+    fn question_mark_policy() {
+        use crate::parse::parse_codeowners;
+
+        let file = parse_codeowners("file?.txt @owner\n!tmp?.log @owner\n*.rs @owner\n").ast;
+
+        let result = validate_question_mark_policy(&file, QuestionMark::Unsupported);
+        assert_eq!(result.errors.len(), 1);
+        assert!(matches!(
+            &result.errors[0],
+            ValidationError::UnsupportedPatternSyntax {
+                syntax: UnsupportedSyntax::QuestionMark,
+                ..
+            }
+        ));
+
+        assert!(validate_question_mark_policy(&file, QuestionMark::Wildcard).is_ok());
+        assert!(validate_question_mark_policy(&file, QuestionMark::Literal).is_ok());
+    }
+
     // Full file validation tests
 
     #[test]
@@ -521,6 +849,22 @@ mod tests {
         assert!(validation.has_errors());
     }
 
+    #[test]
+    fn validate_file_with_unclassified_owner() {
+        use crate::parse::parse_codeowners;
+
+        let result = parse_codeowners("*.rs team-a @dev\n");
+        assert!(result.is_ok());
+        let validation = validate_syntax(&result.ast);
+
+        assert_eq!(validation.errors.len(), 1);
+        assert!(matches!(
+            &validation.errors[0],
+            ValidationError::InvalidOwnerFormat { owner, reason, .. }
+                if owner == "team-a" && reason.contains("starting with '@'")
+        ));
+    }
+
     #[test]
     fn validate_file_multiple_errors() {
         use crate::parse::parse_codeowners;
@@ -536,4 +880,57 @@ mod tests {
         // Should have multiple errors
         assert!(validation.errors.len() >= 2);
     }
+
+    // Email policy tests
+
+    fn trusted(domains: &[&str]) -> HashSet<String> {
+        domains.iter().map(|d| d.to_string()).collect()
+    }
+
+    #[test]
+    fn email_policy_disabled_by_default() {
+        use crate::parse::parse_codeowners;
+
+        let result = parse_codeowners("*.rs dev@anywhere.com\n");
+        let validation = validate_email_policy(&result.ast, &HashSet::new(), false);
+        assert!(validation.is_ok());
+    }
+
+    #[test]
+    fn email_policy_trusted_domain() {
+        use crate::parse::parse_codeowners;
+
+        let result = parse_codeowners("*.rs dev@Example.com @user\n");
+        let validation = validate_email_policy(&result.ast, &trusted(&["example.com"]), false);
+        assert!(validation.is_ok());
+    }
+
+    #[test]
+    fn email_policy_untrusted_domain() {
+        use crate::parse::parse_codeowners;
+
+        let result = parse_codeowners("*.rs dev@example.com\n*.md dev@other.com\n");
+        let validation = validate_email_policy(&result.ast, &trusted(&["example.com"]), false);
+
+        assert_eq!(validation.errors.len(), 1);
+        assert!(matches!(
+            &validation.errors[0],
+            ValidationError::UntrustedEmailDomain { line: 2, domain, .. } if domain == "other.com"
+        ));
+        assert_eq!(validation.errors_only().count(), 0);
+    }
+
+    #[test]
+    fn email_policy_reject_all() {
+        use crate::parse::parse_codeowners;
+
+        let result = parse_codeowners("*.rs dev@example.com @user\n");
+        let validation = validate_email_policy(&result.ast, &trusted(&["example.com"]), true);
+
+        assert_eq!(validation.errors.len(), 1);
+        assert!(matches!(
+            validation.errors[0],
+            ValidationError::EmailOwnerNotAllowed { .. }
+        ));
+    }
 }
@@ -0,0 +1,313 @@
+//! Reloading a [`SharedEngine`] when its inputs change on disk.
+//!
+//! An [`EngineWatcher`] watches a set of files, typically the CODEOWNERS
+//! file and the project configuration, and rebuilds the engine when any of
+//! them changes. The new engine is swapped in with [`SharedEngine::replace`],
+//! so validations already running finish on the engine they started with.
+//!
+//! The parent directories are watched rather than the files themselves,
+//! because editors and `git checkout` usually replace a file by renaming a
+//! new one over it, which ends a watch on the old inode.
+
+use crate::validate::engine::{SharedEngine, ValidationEngine};
+use log::{debug, warn};
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+use thiserror::Error;
+
+/// How long to wait for more changes before rebuilding the engine.
+///
+/// Saving a file often produces several events in quick succession; they
+/// are collapsed into a single reload.
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Errors from setting up an [`EngineWatcher`].
+#[derive(Debug, Error)]
+pub enum WatchError {
+    #[error("failed to start the file watcher: {0}")]
+    Init(#[source] notify::Error),
+
+    #[error("failed to watch '{path}': {source}")]
+    Watch {
+        path: PathBuf,
+        #[source]
+        source: notify::Error,
+    },
+
+    #[error("failed to start the reload thread: {0}")]
+    Thread(#[source] std::io::Error),
+}
+
+/// Rebuilds a [`SharedEngine`] whenever one of the watched files changes.
+///
+/// The watch stops when the watcher is dropped. A failed rebuild is logged
+/// and the previous engine stays in place.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use codeowners_validator_core::validate::checks::CheckRunner;
+/// use codeowners_validator_core::validate::engine::{SharedEngine, ValidationEngine};
+/// use codeowners_validator_core::validate::watch::EngineWatcher;
+/// use std::sync::Arc;
+///
+/// let build = || ValidationEngine::new(".", CheckRunner::new());
+/// let shared = Arc::new(SharedEngine::new(build()));
+/// let _watcher = EngineWatcher::spawn(
+///     Arc::clone(&shared),
+///     vec![".github/CODEOWNERS".into()],
+///     move || Ok(build()),
+/// )?;
+/// # Ok::<(), codeowners_validator_core::validate::watch::WatchError>(())
+/// ```
+pub struct EngineWatcher {
+    files: Vec<PathBuf>,
+    // Dropping the watcher closes the event channel, which ends the thread
+    _watcher: RecommendedWatcher,
+}
+
+impl EngineWatcher {
+    /// Starts watching `files` and rebuilding `shared` with `rebuild`.
+    ///
+    /// The files do not have to exist yet; creating one counts as a change.
+    pub fn spawn<F>(
+        shared: Arc<SharedEngine>,
+        files: Vec<PathBuf>,
+        rebuild: F,
+    ) -> Result<Self, WatchError>
+    where
+        F: FnMut() -> Result<ValidationEngine, String> + Send + 'static,
+    {
+        Self::spawn_with_debounce(shared, files, DEFAULT_DEBOUNCE, rebuild)
+    }
+
+    /// Like [`EngineWatcher::spawn`], waiting `debounce` for further changes
+    /// before each rebuild.
+    pub fn spawn_with_debounce<F>(
+        shared: Arc<SharedEngine>,
+        files: Vec<PathBuf>,
+        debounce: Duration,
+        rebuild: F,
+    ) -> Result<Self, WatchError>
+    where
+        F: FnMut() -> Result<ValidationEngine, String> + Send + 'static,
+    {
+        let files: Vec<PathBuf> = files.iter().map(|f| absolute(f)).collect();
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(tx).map_err(WatchError::Init)?;
+
+        let dirs: BTreeSet<&Path> = files.iter().filter_map(|f| f.parent()).collect();
+        for dir in dirs {
+            watcher
+                .watch(dir, RecursiveMode::NonRecursive)
+                .map_err(|source| WatchError::Watch {
+                    path: dir.to_path_buf(),
+                    source,
+                })?;
+        }
+
+        let watched = files.clone();
+        thread::Builder::new()
+            .name("codeowners-engine-watch".to_string())
+            .spawn(move || reload_loop(&shared, &watched, debounce, rx, rebuild))
+            .map_err(WatchError::Thread)?;
+
+        debug!("Watching {:?} for engine reloads", files);
+        Ok(Self {
+            files,
+            _watcher: watcher,
+        })
+    }
+
+    /// Returns the files being watched.
+    pub fn files(&self) -> &[PathBuf] {
+        &self.files
+    }
+}
+
+impl std::fmt::Debug for EngineWatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EngineWatcher")
+            .field("files", &self.files)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Resolves a path against the working directory and through symlinked
+/// parent directories, so it can be compared with the paths in watch events.
+fn absolute(path: &Path) -> PathBuf {
+    let path = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+    match (path.parent(), path.file_name()) {
+        (Some(parent), Some(name)) => parent
+            .canonicalize()
+            .map_or_else(|_| path.clone(), |parent| parent.join(name)),
+        _ => path,
+    }
+}
+
+/// Returns whether an event touches one of the watched files.
+fn is_relevant(event: &notify::Result<Event>, files: &[PathBuf]) -> bool {
+    match event {
+        Ok(event) => !event.kind.is_access() && event.paths.iter().any(|p| files.contains(p)),
+        Err(e) => {
+            warn!("event=engine_watch_error error={:?}", e.to_string());
+            false
+        }
+    }
+}
+
+/// Waits for changes to the watched files and swaps in rebuilt engines until
+/// the watcher is dropped.
+fn reload_loop<F>(
+    shared: &SharedEngine,
+    files: &[PathBuf],
+    debounce: Duration,
+    events: Receiver<notify::Result<Event>>,
+    mut rebuild: F,
+) where
+    F: FnMut() -> Result<ValidationEngine, String>,
+{
+    while let Ok(event) = events.recv() {
+        if !is_relevant(&event, files) {
+            continue;
+        }
+        // Collapse the burst of events a single save produces
+        loop {
+            match events.recv_timeout(debounce) {
+                Ok(_) => continue,
+                Err(RecvTimeoutError::Timeout) => break,
+                Err(RecvTimeoutError::Disconnected) => return,
+            }
+        }
+
+        match rebuild() {
+            Ok(engine) => {
+                shared.replace(engine);
+            }
+            Err(e) => warn!(
+                "event=engine_reload_failed generation={} error={:?}",
+                shared.generation(),
+                e
+            ),
+        }
+    }
+    debug!("Engine watcher stopped");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validate::checks::CheckRunner;
+    use std::fs;
+    use std::time::Instant;
+    use tempfile::TempDir;
+
+    const DEBOUNCE: Duration = Duration::from_millis(50);
+
+    fn wait_for(shared: &SharedEngine, generation: u64) -> bool {
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while Instant::now() < deadline {
+            if shared.generation() >= generation {
+                return true;
+            }
+            thread::sleep(Duration::from_millis(20));
+        }
+        false
+    }
+
+    #[test]
+    fn reloads_when_a_watched_file_changes() {
+        let dir = TempDir::new().unwrap();
+        let codeowners = dir.path().join("CODEOWNERS");
+        fs::write(&codeowners, "*.rs @rustacean\n").unwrap();
+
+        let repo = dir.path().to_path_buf();
+        let shared = Arc::new(SharedEngine::new(ValidationEngine::new(
+            &repo,
+            CheckRunner::new(),
+        )));
+        let _watcher = EngineWatcher::spawn_with_debounce(
+            Arc::clone(&shared),
+            vec![codeowners.clone()],
+            DEBOUNCE,
+            move || Ok(ValidationEngine::new(&repo, CheckRunner::new())),
+        )
+        .unwrap();
+
+        fs::write(&codeowners, "*.rs @ferris\n").unwrap();
+        assert!(wait_for(&shared, 1), "engine was not reloaded");
+    }
+
+    #[test]
+    fn ignores_other_files_in_the_directory() {
+        let dir = TempDir::new().unwrap();
+        let codeowners = dir.path().join("CODEOWNERS");
+        let repo = dir.path().to_path_buf();
+        let shared = Arc::new(SharedEngine::new(ValidationEngine::new(
+            &repo,
+            CheckRunner::new(),
+        )));
+        let _watcher = EngineWatcher::spawn_with_debounce(
+            Arc::clone(&shared),
+            vec![codeowners.clone()],
+            DEBOUNCE,
+            move || Ok(ValidationEngine::new(&repo, CheckRunner::new())),
+        )
+        .unwrap();
+
+        fs::write(dir.path().join("README.md"), "readme").unwrap();
+        thread::sleep(DEBOUNCE * 6);
+        assert_eq!(shared.generation(), 0);
+
+        // Creating a watched file counts as a change
+        fs::write(&codeowners, "*.rs @ferris\n").unwrap();
+        assert!(wait_for(&shared, 1), "engine was not reloaded");
+    }
+
+    #[test]
+    fn failed_rebuild_keeps_the_previous_engine() {
+        let dir = TempDir::new().unwrap();
+        let config = dir.path().join(".codeowners-validator.toml");
+        let shared = Arc::new(SharedEngine::new(ValidationEngine::new(
+            dir.path(),
+            CheckRunner::new(),
+        )));
+        let before = shared.current();
+        let (tx, rx) = mpsc::channel();
+        let _watcher = EngineWatcher::spawn_with_debounce(
+            Arc::clone(&shared),
+            vec![config.clone()],
+            DEBOUNCE,
+            move || {
+                let _ = tx.send(());
+                Err("invalid configuration".to_string())
+            },
+        )
+        .unwrap();
+
+        fs::write(&config, "not = [valid").unwrap();
+        rx.recv_timeout(Duration::from_secs(10))
+            .expect("rebuild was not attempted");
+        assert_eq!(shared.generation(), 0);
+        assert!(Arc::ptr_eq(&before, &shared.current()));
+    }
+
+    #[test]
+    fn missing_directory_is_an_error() {
+        let dir = TempDir::new().unwrap();
+        let shared = Arc::new(SharedEngine::new(ValidationEngine::new(
+            dir.path(),
+            CheckRunner::new(),
+        )));
+        let err = EngineWatcher::spawn(shared, vec![dir.path().join("missing/CODEOWNERS")], || {
+            Err("unused".to_string())
+        })
+        .unwrap_err();
+        assert!(matches!(err, WatchError::Watch { .. }));
+    }
+}
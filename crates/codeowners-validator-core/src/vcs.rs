@@ -0,0 +1,511 @@
+//! Version control systems.
+//!
+//! Features that need repository history, such as the files a change
+//! touches or a file's content at another revision, go through the [`Vcs`]
+//! trait instead of calling git directly, so repositories on other version
+//! control systems can be supported by adding an implementation.
+//!
+//! [`GitVcs`] runs the `git` executable. [`detect_vcs`] picks the
+//! implementation for a repository. [`GitTreeSource`] reads the files of a
+//! revision straight from git objects, for bare mirrors without a checkout.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use codeowners_validator_core::vcs::detect_vcs;
+//! use std::path::Path;
+//!
+//! if let Some(vcs) = detect_vcs(Path::new(".")) {
+//!     for path in vcs.changed_files("main", None).unwrap() {
+//!         println!("{}", path);
+//!     }
+//! }
+//! ```
+
+use crate::patch::CODEOWNERS_PATHS;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use thiserror::Error;
+
+/// Errors that can occur while querying a version control system.
+#[derive(Debug, Error)]
+pub enum VcsError {
+    /// The version control executable could not be run.
+    #[error("failed to run '{program}': {source}")]
+    Io {
+        /// The executable.
+        program: &'static str,
+        /// The underlying error.
+        source: std::io::Error,
+    },
+
+    /// A version control command failed.
+    #[error("'{command}' failed: {stderr}")]
+    Command {
+        /// The command, without the executable.
+        command: String,
+        /// What the command printed to stderr.
+        stderr: String,
+    },
+
+    /// A revision does not exist.
+    #[error("unknown revision '{0}'")]
+    UnknownRevision(String),
+
+    /// A version control command printed a path or file that is not UTF-8.
+    #[error("'{0}' produced output that is not valid UTF-8")]
+    NonUtf8(String),
+}
+
+/// A commit in the history of a file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CommitInfo {
+    /// The full commit id.
+    pub id: String,
+    /// The author's name.
+    pub author: String,
+    /// The author's email address.
+    pub email: String,
+    /// The author date, in ISO 8601 format.
+    pub date: String,
+    /// The first line of the commit message.
+    pub summary: String,
+}
+
+/// A version control system holding a repository.
+///
+/// Paths are relative to the repository path the implementation was
+/// created for, with `/` separators, like those
+/// [`OwnersResolver`](crate::matching::OwnersResolver) matches.
+pub trait Vcs: Send + Sync {
+    /// Returns the name of the version control system, e.g. `git`.
+    fn name(&self) -> &'static str;
+
+    /// Returns the repository path.
+    fn root(&self) -> &Path;
+
+    /// Lists the files tracked by the version control system.
+    fn tracked_files(&self) -> Result<Vec<String>, VcsError>;
+
+    /// Lists the files tracked by the version control system as raw bytes,
+    /// so names that are not valid UTF-8 can still be listed.
+    fn tracked_paths(&self) -> Result<Vec<Vec<u8>>, VcsError> {
+        Ok(self
+            .tracked_files()?
+            .into_iter()
+            .map(String::into_bytes)
+            .collect())
+    }
+
+    /// Reads a file at a revision, or returns `None` if it did not exist
+    /// there.
+    fn read_file(&self, rev: &str, path: &str) -> Result<Option<String>, VcsError>;
+
+    /// Lists the files changed between `base` and `head`, or between `base`
+    /// and the working copy if `head` is `None`.
+    ///
+    /// Renamed files are listed under both their old and new paths, since
+    /// the owners of both must review the change.
+    fn changed_files(&self, base: &str, head: Option<&str>) -> Result<Vec<String>, VcsError>;
+
+    /// Lists the commits that changed a file, newest first, up to the
+    /// checked-out revision.
+    fn file_history(&self, path: &str) -> Result<Vec<CommitInfo>, VcsError>;
+
+    /// Returns the name of the checked-out branch, or `None` if none is
+    /// checked out.
+    fn current_branch(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Returns the version control system holding the repository at
+/// `repo_path`, or `None` if it is not in a repository.
+///
+/// Only git is supported so far; implementations for systems such as
+/// Sapling or Mercurial are detected here too as they are added.
+pub fn detect_vcs(repo_path: &Path) -> Option<Box<dyn Vcs>> {
+    GitVcs::detect(repo_path).map(|git| Box::new(git) as Box<dyn Vcs>)
+}
+
+/// The git version control system, queried through the `git` executable.
+#[derive(Debug, Clone)]
+pub struct GitVcs {
+    root: PathBuf,
+}
+
+impl GitVcs {
+    /// Creates a git implementation for the repository at `root`.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Creates a git implementation if `repo_path` is in a git repository,
+    /// i.e. it or one of its parents has a `.git` directory or file.
+    pub fn detect(repo_path: &Path) -> Option<Self> {
+        repo_path
+            .ancestors()
+            .any(|dir| dir.join(".git").exists())
+            .then(|| Self::new(repo_path))
+    }
+
+    /// Runs git in the repository and returns its output, whether or not
+    /// it succeeded.
+    fn output(&self, args: &[&str]) -> Result<Output, VcsError> {
+        Command::new("git")
+            .arg("-C")
+            .arg(&self.root)
+            .args(args)
+            .output()
+            .map_err(|source| VcsError::Io {
+                program: "git",
+                source,
+            })
+    }
+
+    /// Runs git in the repository and returns its stdout, failing if git
+    /// does.
+    fn run(&self, args: &[&str]) -> Result<String, VcsError> {
+        String::from_utf8(self.stdout(args)?).map_err(|_| VcsError::NonUtf8(args.join(" ")))
+    }
+
+    /// Runs git in the repository and returns its raw stdout, failing if git
+    /// does.
+    fn stdout(&self, args: &[&str]) -> Result<Vec<u8>, VcsError> {
+        let output = self.output(args)?;
+        if !output.status.success() {
+            return Err(VcsError::Command {
+                command: args.join(" "),
+                stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            });
+        }
+        Ok(output.stdout)
+    }
+
+    /// Returns true if `rev` names a commit.
+    fn is_commit(&self, rev: &str) -> Result<bool, VcsError> {
+        let rev = format!("{}^{{commit}}", rev);
+        Ok(self
+            .output(&["rev-parse", "--verify", "--quiet", &rev])?
+            .status
+            .success())
+    }
+
+    /// Runs a git command that lists NUL-separated paths.
+    fn paths(&self, args: &[&str]) -> Result<Vec<String>, VcsError> {
+        self.raw_paths(args)?
+            .into_iter()
+            .map(|path| String::from_utf8(path).map_err(|_| VcsError::NonUtf8(args.join(" "))))
+            .collect()
+    }
+
+    /// Runs a git command that lists NUL-separated paths, keeping them as
+    /// bytes.
+    fn raw_paths(&self, args: &[&str]) -> Result<Vec<Vec<u8>>, VcsError> {
+        Ok(self
+            .stdout(args)?
+            .split(|&byte| byte == 0)
+            .filter(|path| !path.is_empty())
+            .map(<[u8]>::to_vec)
+            .collect())
+    }
+}
+
+impl Vcs for GitVcs {
+    fn name(&self) -> &'static str {
+        "git"
+    }
+
+    fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn tracked_files(&self) -> Result<Vec<String>, VcsError> {
+        self.paths(&["ls-files", "-z"])
+    }
+
+    fn tracked_paths(&self) -> Result<Vec<Vec<u8>>, VcsError> {
+        self.raw_paths(&["ls-files", "-z"])
+    }
+
+    fn read_file(&self, rev: &str, path: &str) -> Result<Option<String>, VcsError> {
+        if !self.is_commit(rev)? {
+            return Err(VcsError::UnknownRevision(rev.to_string()));
+        }
+        // `./` makes the path relative to the repository path rather than
+        // the top of the work tree
+        let object = format!("{}:./{}", rev, path.trim_start_matches('/'));
+        if !self.output(&["cat-file", "-e", &object])?.status.success() {
+            return Ok(None);
+        }
+        self.run(&["show", &object]).map(Some)
+    }
+
+    fn changed_files(&self, base: &str, head: Option<&str>) -> Result<Vec<String>, VcsError> {
+        for rev in std::iter::once(base).chain(head) {
+            if !self.is_commit(rev)? {
+                return Err(VcsError::UnknownRevision(rev.to_string()));
+            }
+        }
+        let mut args = vec![
+            "diff",
+            "--name-only",
+            "-z",
+            "--no-renames",
+            "--relative",
+            base,
+        ];
+        args.extend(head);
+        args.push("--");
+        self.paths(&args)
+    }
+
+    fn file_history(&self, path: &str) -> Result<Vec<CommitInfo>, VcsError> {
+        // Fields are separated by US and commits by RS, which commit
+        // messages and names do not contain
+        let log = self.run(&[
+            "log",
+            "--format=%H%x1f%an%x1f%ae%x1f%aI%x1f%s%x1e",
+            "--",
+            path.trim_start_matches('/'),
+        ])?;
+        Ok(log
+            .split('\x1e')
+            .filter_map(|record| {
+                let mut fields = record.trim_start_matches('\n').split('\x1f');
+                Some(CommitInfo {
+                    id: fields.next().filter(|id| !id.is_empty())?.to_string(),
+                    author: fields.next()?.to_string(),
+                    email: fields.next()?.to_string(),
+                    date: fields.next()?.to_string(),
+                    summary: fields.next()?.to_string(),
+                })
+            })
+            .collect())
+    }
+
+    fn current_branch(&self) -> Option<String> {
+        crate::config::current_branch(&self.root)
+    }
+}
+
+/// The tree of a git revision, read from git objects rather than a checkout.
+///
+/// CI systems often validate from bare mirrors that have no working tree;
+/// the file list and CODEOWNERS content come from the revision's tree
+/// instead. Paths are relative to the root of the tree.
+#[derive(Debug, Clone)]
+pub struct GitTreeSource {
+    git: GitVcs,
+    rev: String,
+}
+
+impl GitTreeSource {
+    /// Creates a source for `rev` (e.g. `origin/main`) of the repository or
+    /// bare repository at `repo_path`.
+    pub fn new(repo_path: impl Into<PathBuf>, rev: impl Into<String>) -> Self {
+        Self {
+            git: GitVcs::new(repo_path),
+            rev: rev.into(),
+        }
+    }
+
+    /// Returns the revision.
+    pub fn rev(&self) -> &str {
+        &self.rev
+    }
+
+    /// Fails with [`VcsError::UnknownRevision`] if the revision does not
+    /// name a commit, e.g. to reject a mistyped revision up front.
+    pub fn verify(&self) -> Result<(), VcsError> {
+        if self.git.is_commit(&self.rev)? {
+            Ok(())
+        } else {
+            Err(VcsError::UnknownRevision(self.rev.clone()))
+        }
+    }
+
+    /// Lists the files in the tree, sorted.
+    pub fn list_files(&self) -> Result<Vec<String>, VcsError> {
+        self.verify()?;
+        self.git.paths(&Self::ls_tree_args(&self.rev))
+    }
+
+    /// Lists the files in the tree as raw bytes, sorted, so names that are
+    /// not valid UTF-8 can still be listed.
+    pub fn list_paths(&self) -> Result<Vec<Vec<u8>>, VcsError> {
+        self.verify()?;
+        self.git.raw_paths(&Self::ls_tree_args(&self.rev))
+    }
+
+    fn ls_tree_args(rev: &str) -> [&str; 6] {
+        ["ls-tree", "-r", "-z", "--name-only", "--full-tree", rev]
+    }
+
+    /// Reads a file from the tree, or returns `None` if it is not there.
+    pub fn read_file(&self, path: &str) -> Result<Option<String>, VcsError> {
+        self.verify()?;
+        let object = format!("{}:{}", self.rev, path.trim_start_matches('/'));
+        if !self
+            .git
+            .output(&["cat-file", "-e", &object])?
+            .status
+            .success()
+        {
+            return Ok(None);
+        }
+        self.git.run(&["show", &object]).map(Some)
+    }
+
+    /// Finds the CODEOWNERS file in the tree, in the locations GitHub reads
+    /// it from, and returns its path and content.
+    pub fn find_codeowners(&self) -> Result<Option<(String, String)>, VcsError> {
+        for path in CODEOWNERS_PATHS {
+            if let Some(content) = self.read_file(path)? {
+                return Ok(Some((path.to_string(), content)));
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    /// Runs git in `dir`, with an identity for commits.
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {:?} failed", args);
+    }
+
+    /// Creates a repository with two commits, tagged `v1` and `v2`.
+    fn repo() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        git(root, &["init", "-q", "-b", "main"]);
+        fs::create_dir(root.join("src")).unwrap();
+        fs::write(root.join("CODEOWNERS"), "* @org/core\n").unwrap();
+        fs::write(root.join("src/lib.rs"), "").unwrap();
+        fs::write(root.join("old.txt"), "").unwrap();
+        git(root, &["add", "."]);
+        git(root, &["commit", "-qm", "one"]);
+        git(root, &["tag", "v1"]);
+
+        fs::write(root.join("CODEOWNERS"), "* @org/core\n/src/ @org/rust\n").unwrap();
+        git(root, &["mv", "old.txt", "new.txt"]);
+        git(root, &["commit", "-qam", "two"]);
+        git(root, &["tag", "v2"]);
+        dir
+    }
+
+    #[test]
+    fn detects_git_repositories() {
+        let dir = repo();
+        let vcs = detect_vcs(&dir.path().join("src")).unwrap();
+        assert_eq!(vcs.name(), "git");
+        assert_eq!(vcs.current_branch().as_deref(), Some("main"));
+
+        let outside = TempDir::new().unwrap();
+        assert!(GitVcs::detect(outside.path()).is_none());
+    }
+
+    #[test]
+    fn lists_tracked_files() {
+        let dir = repo();
+        fs::write(dir.path().join("untracked.txt"), "").unwrap();
+        let vcs = GitVcs::new(dir.path());
+        assert_eq!(
+            vcs.tracked_files().unwrap(),
+            ["CODEOWNERS", "new.txt", "src/lib.rs"]
+        );
+        assert_eq!(
+            GitVcs::new(dir.path().join("src")).tracked_files().unwrap(),
+            ["lib.rs"]
+        );
+    }
+
+    #[test]
+    fn reads_files_at_revisions() {
+        let dir = repo();
+        let vcs = GitVcs::new(dir.path());
+        assert_eq!(
+            vcs.read_file("v1", "CODEOWNERS").unwrap().as_deref(),
+            Some("* @org/core\n")
+        );
+        assert_eq!(vcs.read_file("v1", "new.txt").unwrap(), None);
+        assert!(matches!(
+            vcs.read_file("v3", "CODEOWNERS"),
+            Err(VcsError::UnknownRevision(_))
+        ));
+    }
+
+    #[test]
+    fn reads_trees_of_bare_repositories() {
+        let dir = repo();
+        let bare = TempDir::new().unwrap();
+        let mirror = bare.path().join("mirror.git");
+        let status = Command::new("git")
+            .args(["clone", "-q", "--mirror"])
+            .arg(dir.path())
+            .arg(&mirror)
+            .status()
+            .unwrap();
+        assert!(status.success());
+
+        let tree = GitTreeSource::new(&mirror, "v1");
+        assert_eq!(
+            tree.list_files().unwrap(),
+            ["CODEOWNERS", "old.txt", "src/lib.rs"]
+        );
+        assert_eq!(
+            tree.find_codeowners().unwrap(),
+            Some(("CODEOWNERS".to_string(), "* @org/core\n".to_string()))
+        );
+        assert!(matches!(
+            GitTreeSource::new(&mirror, "missing").list_files(),
+            Err(VcsError::UnknownRevision(_))
+        ));
+    }
+
+    #[test]
+    fn lists_file_history() {
+        let dir = repo();
+        let vcs = GitVcs::new(dir.path());
+        let history = vcs.file_history("CODEOWNERS").unwrap();
+        let summaries: Vec<_> = history.iter().map(|c| c.summary.as_str()).collect();
+        assert_eq!(summaries, ["two", "one"]);
+        assert_eq!(history[0].author, "Test");
+        assert_eq!(history[0].email, "test@example.com");
+        assert_eq!(history[0].id.len(), 40);
+        assert_eq!(vcs.file_history("src/lib.rs").unwrap().len(), 1);
+        assert!(vcs.file_history("missing.txt").unwrap().is_empty());
+    }
+
+    #[test]
+    fn lists_changed_files() {
+        let dir = repo();
+        let vcs = GitVcs::new(dir.path());
+        assert_eq!(
+            vcs.changed_files("v1", Some("v2")).unwrap(),
+            ["CODEOWNERS", "new.txt", "old.txt"]
+        );
+
+        fs::write(dir.path().join("src/lib.rs"), "fn main() {}\n").unwrap();
+        assert_eq!(vcs.changed_files("v2", None).unwrap(), ["src/lib.rs"]);
+        assert!(matches!(
+            vcs.changed_files("missing", None),
+            Err(VcsError::UnknownRevision(_))
+        ));
+    }
+}
@@ -0,0 +1,123 @@
+//! Corpus tests for the parser and synchronous checks.
+//!
+//! Each directory under `samples/` is a small repository containing a
+//! CODEOWNERS file. The harness parses it, runs every synchronous check
+//! against the sample, and snapshots the findings so changes in parser or
+//! check behavior show up as reviewable snapshot diffs.
+//!
+//! Set `CODEOWNERS_CORPUS_DIR` to also run over every `CODEOWNERS` file found
+//! under a local directory. Those files are only checked for panics, since
+//! their findings are not checked in.
+//!
+//! Update snapshots with `cargo insta review` or `INSTA_UPDATE=always`.
+
+use codeowners_validator_core::validate::checks::{CheckConfig, CheckRunner};
+use codeowners_validator_core::{find_codeowners_file, parse_codeowners};
+use std::fmt::Write;
+use std::fs;
+use std::panic;
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming an additional, user-supplied corpus directory.
+const CORPUS_DIR_ENV: &str = "CODEOWNERS_CORPUS_DIR";
+
+/// Parses a CODEOWNERS file and runs all synchronous checks, rendering the
+/// findings as a stable text report.
+fn run_sample(repo_path: &Path, codeowners_path: &Path) -> String {
+    let content = fs::read_to_string(codeowners_path).expect("failed to read CODEOWNERS");
+    let parse_result = parse_codeowners(&content);
+
+    let runner = CheckRunner::with_all_checks();
+    let result = runner.run_sync(&parse_result.ast, repo_path, &CheckConfig::new());
+
+    let mut report = String::new();
+    writeln!(report, "parse errors:").unwrap();
+    for error in &parse_result.errors {
+        writeln!(report, "  {error}").unwrap();
+    }
+
+    let mut findings: Vec<(usize, String)> = result
+        .errors
+        .iter()
+        .map(|error| (error.line(), format!("{:?}: {}", error.severity(), error)))
+        .collect();
+    findings.sort();
+    writeln!(report, "findings:").unwrap();
+    for (_, finding) in findings {
+        writeln!(report, "  {finding}").unwrap();
+    }
+    report
+}
+
+/// Returns the sample repositories checked in next to this file.
+fn checked_in_samples() -> Vec<PathBuf> {
+    let samples_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/corpus/samples");
+    let mut samples: Vec<PathBuf> = fs::read_dir(samples_dir)
+        .expect("missing tests/corpus/samples")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .collect();
+    samples.sort();
+    samples
+}
+
+/// Recursively collects files named `CODEOWNERS` under a directory.
+fn collect_codeowners_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_codeowners_files(&path, files);
+        } else if path.file_name().is_some_and(|name| name == "CODEOWNERS") {
+            files.push(path);
+        }
+    }
+}
+
+/// Returns the repository root for a CODEOWNERS file in one of the standard locations.
+fn repo_root_for(codeowners_path: &Path) -> &Path {
+    let parent = codeowners_path.parent().unwrap_or(Path::new("."));
+    match parent.file_name().and_then(|name| name.to_str()) {
+        Some(".github") | Some("docs") => parent.parent().unwrap_or(parent),
+        _ => parent,
+    }
+}
+
+#[test]
+fn corpus_snapshots() {
+    for sample in checked_in_samples() {
+        let name = sample.file_name().unwrap().to_string_lossy().into_owned();
+        let codeowners_path = find_codeowners_file(&sample)
+            .unwrap_or_else(|| panic!("sample '{name}' has no CODEOWNERS file"));
+
+        let report = run_sample(&sample, &codeowners_path);
+        insta::with_settings!({ prepend_module_to_snapshot => false }, {
+            insta::assert_snapshot!(name, report);
+        });
+    }
+}
+
+#[test]
+fn user_corpus_does_not_panic() {
+    let Some(dir) = std::env::var_os(CORPUS_DIR_ENV) else {
+        return;
+    };
+
+    let mut files = Vec::new();
+    collect_codeowners_files(Path::new(&dir), &mut files);
+    files.sort();
+
+    let panicked: Vec<&PathBuf> = files
+        .iter()
+        .filter(|path| panic::catch_unwind(|| run_sample(repo_root_for(path), path)).is_err())
+        .collect();
+    assert!(
+        panicked.is_empty(),
+        "{} of {} corpus files panicked: {:?}",
+        panicked.len(),
+        files.len(),
+        panicked
+    );
+}